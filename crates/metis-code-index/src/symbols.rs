@@ -34,6 +34,21 @@ pub enum SymbolKind {
 }
 
 impl SymbolKind {
+    /// Every kind, in declaration order.
+    pub const ALL: [SymbolKind; 11] = [
+        SymbolKind::File,
+        SymbolKind::Module,
+        SymbolKind::Class,
+        SymbolKind::Struct,
+        SymbolKind::Interface,
+        SymbolKind::Enum,
+        SymbolKind::Function,
+        SymbolKind::Method,
+        SymbolKind::Variable,
+        SymbolKind::Type,
+        SymbolKind::Macro,
+    ];
+
     /// Returns the string representation used in node IDs and queries.
     pub fn as_str(&self) -> &'static str {
         match self {
@@ -72,6 +87,19 @@ impl SymbolKind {
     }
 }
 
+impl std::str::FromStr for SymbolKind {
+    type Err = String;
+
+    /// Parses the string representation returned by [`SymbolKind::as_str`],
+    /// ignoring case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        SymbolKind::ALL
+            .into_iter()
+            .find(|kind| kind.as_str().eq_ignore_ascii_case(s.trim()))
+            .ok_or_else(|| format!("Unknown symbol kind: {}", s))
+    }
+}
+
 /// Visibility/accessibility of a symbol.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -223,6 +251,15 @@ mod tests {
         assert_eq!(SymbolKind::Interface.as_str(), "interface");
     }
 
+    #[test]
+    fn test_symbol_kind_from_str() {
+        for kind in SymbolKind::ALL {
+            assert_eq!(kind.as_str().parse::<SymbolKind>(), Ok(kind));
+        }
+        assert_eq!("Struct".parse::<SymbolKind>(), Ok(SymbolKind::Struct));
+        assert!("fn".parse::<SymbolKind>().is_err());
+    }
+
     #[test]
     fn test_symbol_kind_classification() {
         assert!(SymbolKind::Struct.is_type_definition());
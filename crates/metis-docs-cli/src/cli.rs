@@ -3,9 +3,15 @@ use clap::{Parser, Subcommand};
 use tracing_subscriber::filter::LevelFilter;
 
 use crate::commands::{
-    ArchiveCommand, ConfigCommand, CreateCommand, IndexCommand, InitCommand, ListCommand,
-    McpCommand, SearchCommand, StatusCommand, SyncCommand, TransitionCommand, ValidateCommand,
+    AdrCommand, ArchiveCommand, AssignCommand, BacklogCommand, BlockersCommand, BoardCommand,
+    BulkCommand, CheckoutCommand, ConfigCommand, CreateCommand, CriteriaCommand, DiffCommand,
+    DoctorCommand, DueCommand, ExportCommand, GraphCommand, GrepCommand, ImportCommand,
+    IndexCommand, InitCommand, LinkCommand, ListCommand, LogCommand, McpCommand, MoveCommand,
+    OpenCommand, ReportCommand, SearchCommand, StatsCommand, StatusCommand, SyncCommand,
+    TagCommand, TagsCommand, TemplateCommand, TransitionCommand, UnlinkCommand, ValidateCommand,
+    WatchCommand,
 };
+use crate::output::OutputOptions;
 
 #[derive(Parser)]
 #[command(name = "metis")]
@@ -16,6 +22,18 @@ pub struct Cli {
     #[arg(short, long, action = clap::ArgAction::Count)]
     pub verbose: u8,
 
+    /// Print JSON: command output where supported, and errors on stderr
+    #[arg(long, global = true)]
+    pub json: bool,
+
+    /// Only print errors and the data a command was asked for
+    #[arg(short, long, global = true)]
+    pub quiet: bool,
+
+    /// Disable colored output (also honours NO_COLOR)
+    #[arg(long, global = true)]
+    pub no_color: bool,
+
     #[command(subcommand)]
     pub command: Commands,
 }
@@ -26,24 +44,76 @@ pub enum Commands {
     Init(InitCommand),
     /// Synchronize workspace with file system
     Sync(SyncCommand),
+    /// Watch the workspace and sync as documents change
+    Watch(WatchCommand),
     /// Create new documents
     Create(CreateCommand),
+    /// Create ADRs numbered in sequence, linking the decisions they supersede
+    Adr(AdrCommand),
+    /// Open a document in your editor, then sync and validate it
+    Open(OpenCommand),
     /// Search documents in the workspace
     Search(SearchCommand),
+    /// Search document bodies with a regular expression
+    Grep(GrepCommand),
     /// Transition documents between phases
     Transition(TransitionCommand),
+    /// List and check off a document's exit criteria
+    Criteria(CriteriaCommand),
+    /// Move a task to another initiative or to the backlog
+    Move(MoveCommand),
+    /// List, triage and promote backlog items
+    Backlog(BacklogCommand),
+    /// Mark a document as blocked by or related to another
+    Link(LinkCommand),
+    /// Remove a blocked-by or related-to link
+    Unlink(UnlinkCommand),
+    /// Show everything that directly or indirectly blocks a document
+    Blockers(BlockersCommand),
+    /// Assign documents to a user, or unassign them
+    Assign(AssignCommand),
+    /// Set, show or list document due dates
+    Due(DueCommand),
+    /// Add, remove or list the tags on a document
+    Tag(TagCommand),
+    /// Show every tag in use with how many documents carry it
+    Tags(TagsCommand),
+    /// Set frontmatter fields on every document matching a filter
+    Bulk(BulkCommand),
     /// List documents in the workspace
     List(ListCommand),
     /// Show workspace status and actionable items
     Status(StatusCommand),
+    /// Print document counts by type, phase, tag and assignee without syncing
+    Stats(StatsCommand),
+    /// Show kanban boards as text columns
+    Board(BoardCommand),
+    /// Show the git history of a document or the whole workspace
+    Log(LogCommand),
+    /// Show how documents changed between commits or since the last commit
+    Diff(DiffCommand),
+    /// Write a read-only copy of the workspace as it was at a commit
+    Checkout(CheckoutCommand),
+    /// Show flow metrics: cycle time, throughput, WIP and aging
+    Report(ReportCommand),
+    /// Export the workspace for readers without Metis
+    Export(ExportCommand),
+    /// Print a Mermaid or DOT diagram of the document hierarchy
+    Graph(GraphCommand),
+    /// Create tasks from GitHub issues or a CSV file
+    Import(ImportCommand),
     /// Archive completed documents and move them to archived folder
     Archive(ArchiveCommand),
     /// Validate a document file
     Validate(ValidateCommand),
+    /// Check workspace database health and run maintenance
+    Doctor(DoctorCommand),
     /// Launch the MCP server for external integrations
     Mcp(McpCommand),
     /// Manage flight level configuration
     Config(ConfigCommand),
+    /// List, show or override document templates
+    Template(TemplateCommand),
     /// Generate code index for AI agent navigation
     Index(IndexCommand),
 }
@@ -51,6 +121,7 @@ pub enum Commands {
 impl Cli {
     pub fn init_logging(&self) {
         let level = match self.verbose {
+            _ if self.quiet => LevelFilter::ERROR,
             0 => LevelFilter::WARN,
             1 => LevelFilter::INFO,
             2 => LevelFilter::DEBUG,
@@ -60,22 +131,57 @@ impl Cli {
         tracing_subscriber::fmt()
             .with_max_level(level)
             .with_target(false)
+            .with_ansi(!self.no_color && std::env::var_os("NO_COLOR").is_none())
             .init();
     }
 
+    pub fn output_options(&self) -> OutputOptions {
+        OutputOptions {
+            json: self.json,
+            quiet: self.quiet,
+            no_color: self.no_color,
+        }
+    }
+
     pub async fn execute(&self) -> Result<()> {
         match &self.command {
             Commands::Init(cmd) => cmd.execute().await,
             Commands::Sync(cmd) => cmd.execute().await,
+            Commands::Watch(cmd) => cmd.execute().await,
             Commands::Create(cmd) => cmd.execute().await,
+            Commands::Adr(cmd) => cmd.execute().await,
+            Commands::Open(cmd) => cmd.execute().await,
             Commands::Search(cmd) => cmd.execute().await,
+            Commands::Grep(cmd) => cmd.execute().await,
             Commands::Transition(cmd) => cmd.execute().await,
+            Commands::Criteria(cmd) => cmd.execute().await,
+            Commands::Move(cmd) => cmd.execute().await,
+            Commands::Backlog(cmd) => cmd.execute().await,
+            Commands::Link(cmd) => cmd.execute().await,
+            Commands::Unlink(cmd) => cmd.execute().await,
+            Commands::Blockers(cmd) => cmd.execute().await,
+            Commands::Assign(cmd) => cmd.execute().await,
+            Commands::Due(cmd) => cmd.execute().await,
+            Commands::Tag(cmd) => cmd.execute().await,
+            Commands::Tags(cmd) => cmd.execute().await,
+            Commands::Bulk(cmd) => cmd.execute().await,
             Commands::List(cmd) => cmd.execute().await,
             Commands::Status(cmd) => cmd.execute().await,
+            Commands::Stats(cmd) => cmd.execute().await,
+            Commands::Board(cmd) => cmd.execute().await,
+            Commands::Log(cmd) => cmd.execute().await,
+            Commands::Diff(cmd) => cmd.execute().await,
+            Commands::Checkout(cmd) => cmd.execute().await,
+            Commands::Report(cmd) => cmd.execute().await,
+            Commands::Export(cmd) => cmd.execute().await,
+            Commands::Graph(cmd) => cmd.execute().await,
+            Commands::Import(cmd) => cmd.execute().await,
             Commands::Archive(cmd) => cmd.execute().await,
             Commands::Validate(cmd) => cmd.execute().await,
+            Commands::Doctor(cmd) => cmd.execute().await,
             Commands::Mcp(cmd) => cmd.execute().await,
             Commands::Config(cmd) => cmd.execute().await,
+            Commands::Template(cmd) => cmd.execute().await,
             Commands::Index(cmd) => cmd.execute().await,
         }
     }
@@ -106,6 +212,7 @@ mod tests {
             prefix: None,
             preset: None,
             initiatives: None,
+            remote: None,
         };
         init_cmd
             .execute()
@@ -123,7 +230,10 @@ mod tests {
         );
 
         // 2. Sync the workspace to populate database
-        let sync_cmd = SyncCommand {};
+        let sync_cmd = SyncCommand {
+            action: None,
+            dry_run: false,
+        };
         sync_cmd.execute().await.expect("Failed to sync workspace");
 
         // 3. Create an initiative under the vision
@@ -159,7 +269,10 @@ mod tests {
         assert!(!adr_files.is_empty(), "ADR file should be created");
 
         // 5. Sync after creating documents
-        let sync_cmd2 = SyncCommand {};
+        let sync_cmd2 = SyncCommand {
+            action: None,
+            dry_run: false,
+        };
         sync_cmd2
             .execute()
             .await
@@ -169,6 +282,8 @@ mod tests {
         let transition_vision_cmd = TransitionCommand {
             short_code: "TEST-V-0001".to_string(),
             phase: Some("review".to_string()),
+            cascade: false,
+            yes: false,
         };
         transition_vision_cmd
             .execute()
@@ -181,6 +296,8 @@ mod tests {
             let cmd = TransitionCommand {
                 short_code: "TEST-I-0001".to_string(),
                 phase: None,
+                cascade: false,
+                yes: false,
             };
             cmd.execute()
                 .await
@@ -203,6 +320,8 @@ mod tests {
         let transition_task_to_active_cmd = TransitionCommand {
             short_code: "TEST-T-0001".to_string(),
             phase: Some("active".to_string()),
+            cascade: false,
+            yes: false,
         };
         transition_task_to_active_cmd
             .execute()
@@ -212,6 +331,8 @@ mod tests {
         let transition_task_to_completed_cmd = TransitionCommand {
             short_code: "TEST-T-0001".to_string(),
             phase: Some("completed".to_string()),
+            cascade: false,
+            yes: false,
         };
         transition_task_to_completed_cmd
             .execute()
@@ -220,8 +341,10 @@ mod tests {
 
         // 10. Archive the completed task
         let archive_task_cmd = ArchiveCommand {
-            short_code: "TEST-T-0001".to_string(),
+            short_code: Some("TEST-T-0001".to_string()),
             document_type: Some("task".to_string()),
+            filter: None,
+            yes: false,
         };
         archive_task_cmd
             .execute()
@@ -234,7 +357,10 @@ mod tests {
             phase: None,
             all: true,
             include_archived: true,
-            format: OutputFormat::Table,
+            format: crate::commands::list::ListFormat::Table,
+            columns: vec!["type".parse().unwrap(), "code".parse().unwrap()],
+            sort: metis_core::dal::database::DocumentSort::Type,
+            desc: false,
         };
         list_cmd.execute().await.expect("Failed to list documents");
 
@@ -242,12 +368,16 @@ mod tests {
         let status_cmd = StatusCommand {
             include_archived: false,
             format: OutputFormat::Table,
+            summary: false,
         };
         status_cmd.execute().await.expect("Failed to get status");
 
         // 13. Search for content
         let search_cmd = SearchCommand {
             query: "test".to_string(),
+            document_type: None,
+            phase: None,
+            tags: Vec::new(),
             limit: 10,
             format: OutputFormat::Table,
         };
@@ -272,4 +402,24 @@ mod tests {
 
         println!("✓ Comprehensive CLI workflow test completed successfully");
     }
+
+    #[test]
+    fn test_global_output_flags() {
+        use clap::CommandFactory;
+        Cli::command().debug_assert();
+
+        let cli = Cli::try_parse_from(["metis", "--no-color", "list", "--json", "-q"]).unwrap();
+        assert_eq!(
+            cli.output_options(),
+            OutputOptions {
+                json: true,
+                quiet: true,
+                no_color: true,
+            }
+        );
+        assert!(matches!(cli.command, Commands::List(_)));
+
+        let cli = Cli::try_parse_from(["metis", "status"]).unwrap();
+        assert_eq!(cli.output_options(), OutputOptions::default());
+    }
 }
@@ -0,0 +1,212 @@
+use crate::output::{self, say, Failure};
+use crate::workspace;
+use anyhow::Result;
+use clap::{Args, Subcommand};
+use metis_core::application::services::document::creation::{
+    DocumentCreationConfig, DocumentCreationService,
+};
+use metis_core::application::services::workspace::{AdrService, LinkKind, LinkService};
+use metis_core::application::services::DatabaseService;
+use metis_core::{Adr, Database, Phase, Tag};
+
+#[derive(Args)]
+pub struct AdrCommand {
+    #[command(subcommand)]
+    pub action: AdrAction,
+}
+
+#[derive(Subcommand)]
+pub enum AdrAction {
+    /// Create a new ADR with the next number, optionally superseding an earlier one
+    New {
+        /// ADR title
+        title: String,
+        /// Decided ADR this one replaces, by number (e.g. 012) or short code
+        #[arg(long)]
+        supersedes: Option<String>,
+        /// Documents the decision relates to (e.g., PROJ-I-0003); repeatable
+        #[arg(long)]
+        relates_to: Vec<String>,
+    },
+}
+
+impl AdrCommand {
+    pub async fn execute(&self) -> Result<()> {
+        match &self.action {
+            AdrAction::New {
+                title,
+                supersedes,
+                relates_to,
+            } => new_adr(title, supersedes.as_deref(), relates_to).await,
+        }
+    }
+}
+
+async fn new_adr(title: &str, supersedes: Option<&str>, relates_to: &[String]) -> Result<()> {
+    // 1. Validate we're in a metis workspace
+    let (workspace_exists, metis_dir) = workspace::has_metis_vault();
+    if !workspace_exists {
+        return Err(Failure::NotAWorkspace.into());
+    }
+    let metis_dir = metis_dir.unwrap();
+
+    // 2. Check every link target before creating anything
+    let db = Database::new(&metis_dir.join("metis.db").to_string_lossy())
+        .map_err(|e| anyhow::anyhow!("Database initialization failed: {}", e))?;
+    let mut db_service = DatabaseService::new(db.into_repository());
+    workspace::sync(&metis_dir, &mut db_service).await?;
+
+    let adr_service = AdrService::new(&metis_dir);
+    let superseded = supersedes
+        .map(|reference| adr_service.find_supersedable(reference, &mut db_service))
+        .transpose()
+        .map_err(|e| anyhow::anyhow!("{}", e))?;
+    for target in relates_to {
+        db_service
+            .find_by_short_code(target)
+            .map_err(|e| anyhow::anyhow!("Database query failed: {}", e))?
+            .ok_or_else(|| anyhow::anyhow!("Document '{}' not found", target))?;
+    }
+
+    // 3. Create the ADR from the template
+    let config = DocumentCreationConfig {
+        title: title.to_string(),
+        description: None,
+        parent_id: None,
+        tags: vec![Tag::Label("adr".to_string()), Tag::Phase(Phase::Draft)],
+        phase: Some(Phase::Draft),
+        complexity: None,
+    };
+    let result = DocumentCreationService::new(&metis_dir)
+        .create_adr(config)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to create ADR: {}", e))?;
+    let number = Adr::from_file(&result.file_path)
+        .await
+        .map(|adr| adr.number())
+        .map_err(|e| anyhow::anyhow!("Failed to read the new ADR: {}", e))?;
+    workspace::sync(&metis_dir, &mut db_service).await?;
+
+    say!(
+        "✓ Created ADR {:03}: {}",
+        number,
+        result.file_path.display()
+    );
+    say!("  Short Code: {}", result.short_code);
+    output::quiet_result(&result.short_code);
+    say!("  Title: {}", title);
+
+    // 4. Apply the links, then sync so the database records them
+    let link_service = LinkService::new(&metis_dir);
+    for target in relates_to {
+        link_service
+            .link(
+                &result.short_code,
+                target,
+                LinkKind::RelatesTo,
+                &mut db_service,
+            )
+            .map_err(|e| anyhow::anyhow!("{}", e))?;
+        say!("  Relates to: {}", target);
+    }
+    if let Some(old) = superseded {
+        adr_service
+            .supersede(&result.short_code, &old.short_code, &mut db_service)
+            .await
+            .map_err(|e| anyhow::anyhow!("{}", e))?;
+        say!("  Supersedes: {} (now superseded)", old.short_code);
+    }
+    workspace::sync(&metis_dir, &mut db_service).await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::InitCommand;
+    use tempfile::tempdir;
+
+    fn new(title: &str, supersedes: Option<&str>, relates_to: &[&str]) -> AdrCommand {
+        AdrCommand {
+            action: AdrAction::New {
+                title: title.to_string(),
+                supersedes: supersedes.map(str::to_string),
+                relates_to: relates_to.iter().map(|code| code.to_string()).collect(),
+            },
+        }
+    }
+
+    #[tokio::test]
+    async fn test_adr_command_no_workspace() {
+        let temp_dir = tempdir().unwrap();
+        let original_dir = std::env::current_dir().ok();
+
+        if std::env::set_current_dir(temp_dir.path()).is_err() {
+            return;
+        }
+
+        let result = new("Use SQLite", None, &[]).execute().await;
+
+        if let Some(original) = original_dir {
+            let _ = std::env::set_current_dir(&original);
+        }
+
+        assert!(result.is_err());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Not in a Metis workspace"));
+    }
+
+    #[tokio::test]
+    async fn test_adr_new_with_links() {
+        let temp_dir = tempdir().unwrap();
+        let original_dir = std::env::current_dir().ok();
+
+        if std::env::set_current_dir(temp_dir.path()).is_err() {
+            return;
+        }
+
+        let init_cmd = InitCommand {
+            name: Some("Test Project".to_string()),
+            preset: None,
+            initiatives: None,
+            prefix: None,
+            remote: None,
+        };
+        init_cmd.execute().await.unwrap();
+
+        let first = new("Use SQLite", None, &["TEST-V-0001"]).execute().await;
+        // A draft ADR can't be superseded, and nothing is created when that fails
+        let refused = new("Use Postgres", Some("001"), &[]).execute().await;
+        let metis_dir = temp_dir.path().join(".metis");
+        let adr_count = std::fs::read_dir(metis_dir.join("adrs")).unwrap().count();
+
+        let first_path = metis_dir.join("adrs").join("TEST-A-0001.md");
+        let content = std::fs::read_to_string(&first_path).unwrap();
+        std::fs::write(
+            &first_path,
+            content.replace("#phase/draft", "#phase/decided"),
+        )
+        .unwrap();
+        let second = new("Use Postgres", Some("001"), &[]).execute().await;
+
+        if let Some(original) = original_dir {
+            let _ = std::env::set_current_dir(&original);
+        }
+
+        assert!(first.is_ok());
+        assert!(refused.unwrap_err().to_string().contains("decided"));
+        assert_eq!(adr_count, 1);
+        assert!(second.is_ok());
+        assert!(content.contains("references: [TEST-V-0001]"));
+
+        let second_content =
+            std::fs::read_to_string(metis_dir.join("adrs").join("TEST-A-0002.md")).unwrap();
+        assert!(second_content.contains("number: 2"));
+        assert!(second_content.contains("supersedes: [TEST-A-0001]"));
+        let first_content = std::fs::read_to_string(&first_path).unwrap();
+        assert!(first_content.contains("#phase/superseded"));
+    }
+}
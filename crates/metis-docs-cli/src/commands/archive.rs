@@ -1,16 +1,30 @@
+use crate::output::{say, Failure};
 use crate::workspace;
 use anyhow::Result;
 use clap::Args;
+use dialoguer::Confirm;
 use metis_core::application::services::workspace::ArchiveService;
+use metis_core::dal::database::models::Document;
+use metis_core::dal::database::DocumentFilter;
+use std::collections::BTreeMap;
 
 #[derive(Args)]
 pub struct ArchiveCommand {
     /// Document short code to archive (e.g., PROJ-V-0001)
-    pub short_code: String,
+    #[arg(required_unless_present = "filter", conflicts_with = "filter")]
+    pub short_code: Option<String>,
 
     /// Document type (vision, initiative, task, adr) - auto-detected if not provided
     #[arg(short = 't', long)]
     pub document_type: Option<String>,
+
+    /// Archive every document matching a query, e.g. 'type:task phase:completed updated<90d'
+    #[arg(long)]
+    pub filter: Option<String>,
+
+    /// Archive matches of --filter without asking for confirmation
+    #[arg(short = 'y', long, requires = "filter")]
+    pub yes: bool,
 }
 
 impl ArchiveCommand {
@@ -18,7 +32,7 @@ impl ArchiveCommand {
         // 1. Validate we're in a metis workspace
         let (workspace_exists, metis_dir) = workspace::has_metis_vault();
         if !workspace_exists {
-            anyhow::bail!("Not in a Metis workspace. Run 'metis init' to create one.");
+            return Err(Failure::NotAWorkspace.into());
         }
         let metis_dir = metis_dir.unwrap();
 
@@ -38,23 +52,84 @@ impl ArchiveCommand {
             .map_err(|e| anyhow::anyhow!("Failed to sync workspace: {}", e))?;
 
         // 3. Archive the document and its children using database-optimized method
-        let archive_result = archive_service
-            .archive_document_by_short_code(&self.short_code, &mut db_service)
-            .await?;
+        let archive_result = match (&self.short_code, &self.filter) {
+            (Some(short_code), _) => {
+                archive_service
+                    .archive_document_by_short_code(short_code, &mut db_service)
+                    .await?
+            }
+            (None, Some(query)) => {
+                let mut filter = DocumentFilter::parse(query).map_err(|e| anyhow::anyhow!(e))?;
+                if let Some(document_type) = &self.document_type {
+                    filter = filter.document_type(document_type.to_lowercase());
+                }
+                let matches = db_service
+                    .find_by_filter(&filter)
+                    .map_err(|e| anyhow::anyhow!("Database query failed: {}", e))?;
+                if matches.is_empty() {
+                    say!("No documents match '{}'.", query);
+                    return Ok(());
+                }
+
+                print_summary(&matches);
+                if !self.yes && !confirm(matches.len())? {
+                    say!("Archive cancelled.");
+                    return Ok(());
+                }
+
+                archive_service
+                    .archive_by_filter(&filter, &mut db_service)
+                    .await?
+            }
+            (None, None) => anyhow::bail!("Specify a short code or --filter"),
+        };
 
         // 4. Report results
-        println!("✓ Archived {} documents:", archive_result.total_archived);
+        say!("✓ Archived {} documents:", archive_result.total_archived);
         for doc in archive_result.archived_documents {
-            println!("  - {} ({})", doc.document_id, doc.document_type);
+            say!("  - {} ({})", doc.document_id, doc.document_type);
         }
 
         // 5. Archive completed
-        println!("Archive completed.");
+        say!("Archive completed.");
 
         Ok(())
     }
 }
 
+/// Show what a filtered archive is about to touch
+fn print_summary(matches: &[Document]) {
+    let mut by_type: BTreeMap<&str, usize> = BTreeMap::new();
+    for doc in matches {
+        *by_type.entry(doc.document_type.as_str()).or_default() += 1;
+    }
+    let counts: Vec<String> = by_type
+        .iter()
+        .map(|(doc_type, count)| format!("{} {}", count, doc_type))
+        .collect();
+
+    println!(
+        "{} document(s) match ({}):",
+        matches.len(),
+        counts.join(", ")
+    );
+    for doc in matches {
+        println!(
+            "  {} [{}] {} ({})",
+            doc.short_code, doc.document_type, doc.title, doc.phase
+        );
+    }
+    println!("Initiatives are archived together with their tasks.");
+}
+
+fn confirm(count: usize) -> Result<bool> {
+    Confirm::new()
+        .with_prompt(format!("Archive {} document(s)?", count))
+        .default(false)
+        .interact()
+        .map_err(|e| anyhow::anyhow!("Confirmation failed ({}); pass --yes to skip it", e))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -72,8 +147,10 @@ mod tests {
         }
 
         let cmd = ArchiveCommand {
-            short_code: "TEST-T-0001".to_string(),
+            short_code: Some("TEST-T-0001".to_string()),
             document_type: None,
+            filter: None,
+            yes: false,
         };
 
         let result = cmd.execute().await;
@@ -105,12 +182,15 @@ mod tests {
             preset: None,
 
             initiatives: None,
+            remote: None,
         };
         init_cmd.execute().await.unwrap();
 
         let cmd = ArchiveCommand {
-            short_code: "TEST-T-9999".to_string(),
+            short_code: Some("TEST-T-9999".to_string()),
             document_type: None,
+            filter: None,
+            yes: false,
         };
 
         let result = cmd.execute().await;
@@ -139,6 +219,7 @@ mod tests {
             preset: None,
 
             initiatives: None,
+            remote: None,
         };
         init_cmd.execute().await.unwrap();
 
@@ -151,8 +232,10 @@ mod tests {
         assert!(!archived_vision_path.exists());
 
         let cmd = ArchiveCommand {
-            short_code: "TEST-V-0001".to_string(),
+            short_code: Some("TEST-V-0001".to_string()),
             document_type: Some("vision".to_string()),
+            filter: None,
+            yes: false,
         };
 
         let result = cmd.execute().await;
@@ -172,4 +255,57 @@ mod tests {
         let archived_content = std::fs::read_to_string(&archived_vision_path).unwrap();
         assert!(archived_content.contains("archived: true"));
     }
+
+    #[tokio::test]
+    async fn test_archive_by_filter() {
+        let temp_dir = tempdir().unwrap();
+        let original_dir = std::env::current_dir().ok();
+
+        std::env::set_current_dir(temp_dir.path()).unwrap();
+
+        let init_cmd = InitCommand {
+            name: Some("Test Project".to_string()),
+            prefix: None,
+            preset: None,
+            initiatives: None,
+            remote: None,
+        };
+        init_cmd.execute().await.unwrap();
+
+        let invalid = ArchiveCommand {
+            short_code: None,
+            document_type: None,
+            filter: Some("updated<soon".to_string()),
+            yes: true,
+        };
+        let invalid_result = invalid.execute().await;
+
+        let no_match = ArchiveCommand {
+            short_code: None,
+            document_type: None,
+            filter: Some("type:vision phase:published".to_string()),
+            yes: true,
+        };
+        let no_match_result = no_match.execute().await;
+
+        let cmd = ArchiveCommand {
+            short_code: None,
+            document_type: None,
+            filter: Some("type:vision phase:draft".to_string()),
+            yes: true,
+        };
+        let result = cmd.execute().await;
+
+        // Always restore original directory first
+        if let Some(original) = original_dir {
+            let _ = std::env::set_current_dir(&original);
+        }
+
+        assert!(invalid_result.is_err());
+        assert!(no_match_result.is_ok());
+        assert!(result.is_ok(), "Archive failed: {:?}", result.err());
+        let metis_dir = temp_dir.path().join(".metis");
+        assert!(!metis_dir.join("vision.md").exists());
+        assert!(metis_dir.join("archived").join("vision.md").exists());
+    }
 }
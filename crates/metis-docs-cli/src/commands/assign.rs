@@ -0,0 +1,259 @@
+use crate::output::{say, Failure};
+use crate::workspace;
+use anyhow::Result;
+use clap::Args;
+use dialoguer::Confirm;
+use metis_core::application::services::workspace::{PropertyChange, PropertyService};
+use metis_core::application::services::DatabaseService;
+use metis_core::dal::database::DocumentFilter;
+use metis_core::Database;
+
+/// Frontmatter property holding the assignee
+const ASSIGNEE: &str = "assignee";
+
+#[derive(Args)]
+pub struct AssignCommand {
+    /// Document short code (e.g., PROJ-T-0001); omit when using --filter
+    pub short_code: Option<String>,
+
+    /// User to assign
+    pub user: Option<String>,
+
+    /// Assign to yourself ($USER)
+    #[arg(long, conflicts_with = "unassign")]
+    pub me: bool,
+
+    /// Remove the assignee instead
+    #[arg(long)]
+    pub unassign: bool,
+
+    /// Assign every document matching a query, e.g. 'type:task phase:todo tag:api'
+    #[arg(long)]
+    pub filter: Option<String>,
+
+    /// Update matches of --filter without asking for confirmation
+    #[arg(short = 'y', long, requires = "filter")]
+    pub yes: bool,
+}
+
+impl AssignCommand {
+    pub async fn execute(&self) -> Result<()> {
+        // 1. Validate we're in a metis workspace
+        let (workspace_exists, metis_dir) = workspace::has_metis_vault();
+        if !workspace_exists {
+            return Err(Failure::NotAWorkspace.into());
+        }
+        let metis_dir = metis_dir.unwrap();
+
+        // 2. Work out the documents and the new assignee
+        let (short_code, user) = self.positionals()?;
+        let assignee = self.assignee(user)?;
+
+        // 3. Sync so the current assignees are known
+        let db = Database::new(&metis_dir.join("metis.db").to_string_lossy())
+            .map_err(|e| anyhow::anyhow!("Database initialization failed: {}", e))?;
+        let mut db_service = DatabaseService::new(db.into_repository());
+        workspace::sync(&metis_dir, &mut db_service).await?;
+
+        // 4. Update the assignee and sync the files back in
+        let service = PropertyService::new(&metis_dir);
+        let assignee = assignee.as_deref();
+        let changes = match (short_code, &self.filter) {
+            (Some(short_code), _) => service
+                .set_property(short_code, ASSIGNEE, assignee, &mut db_service)
+                .map(|change| vec![change]),
+            (None, Some(query)) => {
+                let filter = DocumentFilter::parse(query).map_err(|e| anyhow::anyhow!(e))?;
+                let matches = db_service
+                    .find_by_filter(&filter)
+                    .map_err(|e| anyhow::anyhow!("Database query failed: {}", e))?;
+                if matches.is_empty() {
+                    say!("No documents match '{}'.", query);
+                    return Ok(());
+                }
+                if !self.yes && !confirm(matches.len(), assignee)? {
+                    say!("Assign cancelled.");
+                    return Ok(());
+                }
+                service.set_property_by_filter(&filter, ASSIGNEE, assignee, &mut db_service)
+            }
+            (None, None) => anyhow::bail!("Specify a short code or --filter"),
+        }
+        .map_err(|e| anyhow::anyhow!("{}", e))?;
+        workspace::sync(&metis_dir, &mut db_service).await?;
+
+        // 5. Report results
+        for line in changes.iter().map(change_line) {
+            say!("{}", line);
+        }
+
+        Ok(())
+    }
+
+    /// With --filter the only positional is the user
+    fn positionals(&self) -> Result<(Option<&str>, Option<&str>)> {
+        match (&self.filter, &self.short_code, &self.user) {
+            (Some(_), Some(_), Some(_)) => {
+                anyhow::bail!("Pass either a short code or --filter, not both")
+            }
+            (Some(_), user, None) => Ok((None, user.as_deref())),
+            (_, short_code, user) => Ok((short_code.as_deref(), user.as_deref())),
+        }
+    }
+
+    /// The new assignee, or None to unassign
+    fn assignee(&self, user: Option<&str>) -> Result<Option<String>> {
+        match (user, self.me, self.unassign) {
+            (Some(user), false, false) if !user.trim().is_empty() => {
+                Ok(Some(user.trim().to_string()))
+            }
+            (None, true, false) => current_user().map(Some),
+            (None, false, true) => Ok(None),
+            (None, false, false) => anyhow::bail!("Specify a user, --me or --unassign"),
+            _ => anyhow::bail!("Specify only one of a user, --me or --unassign"),
+        }
+    }
+}
+
+fn current_user() -> Result<String> {
+    ["USER", "USERNAME"]
+        .iter()
+        .find_map(|var| std::env::var(var).ok().filter(|user| !user.is_empty()))
+        .ok_or_else(|| anyhow::anyhow!("Could not determine the current user; pass a user name"))
+}
+
+fn change_line(change: &PropertyChange) -> String {
+    let status = match (&change.previous, &change.value, change.changed()) {
+        (_, Some(user), false) => format!("already assigned to {}", user),
+        (_, None, false) => "already unassigned".to_string(),
+        (Some(previous), Some(user), true) => format!("{} -> {}", previous, user),
+        (None, Some(user), true) => format!("assigned to {}", user),
+        (Some(previous), None, true) => format!("unassigned from {}", previous),
+        (None, None, true) => unreachable!("no change"),
+    };
+    let marker = if change.changed() { "✓" } else { "-" };
+    format!(
+        "{} {} {}: {}",
+        marker, change.short_code, change.title, status
+    )
+}
+
+fn confirm(count: usize, assignee: Option<&str>) -> Result<bool> {
+    let prompt = match assignee {
+        Some(user) => format!("Assign {} document(s) to {}?", count, user),
+        None => format!("Unassign {} document(s)?", count),
+    };
+    Confirm::new()
+        .with_prompt(prompt)
+        .default(false)
+        .interact()
+        .map_err(|e| anyhow::anyhow!("Confirmation failed ({}); pass --yes to skip it", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::InitCommand;
+    use tempfile::tempdir;
+
+    fn command(short_code: Option<&str>, user: Option<&str>) -> AssignCommand {
+        AssignCommand {
+            short_code: short_code.map(str::to_string),
+            user: user.map(str::to_string),
+            me: false,
+            unassign: false,
+            filter: None,
+            yes: false,
+        }
+    }
+
+    #[test]
+    fn test_assign_arguments() {
+        let cmd = command(Some("TEST-T-0001"), Some("alice"));
+        assert_eq!(
+            cmd.positionals().unwrap(),
+            (Some("TEST-T-0001"), Some("alice"))
+        );
+        assert_eq!(
+            cmd.assignee(Some("alice")).unwrap().as_deref(),
+            Some("alice")
+        );
+
+        // With --filter, the lone positional is the user
+        let mut cmd = command(Some("alice"), None);
+        cmd.filter = Some("type:task".to_string());
+        assert_eq!(cmd.positionals().unwrap(), (None, Some("alice")));
+
+        let mut cmd = command(Some("TEST-T-0001"), None);
+        assert!(cmd.assignee(None).is_err());
+        cmd.unassign = true;
+        assert_eq!(cmd.assignee(None).unwrap(), None);
+        assert!(cmd.assignee(Some("alice")).is_err());
+    }
+
+    #[test]
+    fn test_change_line() {
+        let change = PropertyChange {
+            short_code: "TEST-T-0001".to_string(),
+            title: "Fix the build".to_string(),
+            previous: Some("bob".to_string()),
+            value: Some("alice".to_string()),
+        };
+        assert_eq!(
+            change_line(&change),
+            "✓ TEST-T-0001 Fix the build: bob -> alice"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_assign_command_no_workspace() {
+        let temp_dir = tempdir().unwrap();
+        let original_dir = std::env::current_dir().ok();
+
+        if std::env::set_current_dir(temp_dir.path()).is_err() {
+            return;
+        }
+
+        let result = command(Some("TEST-T-0001"), Some("alice")).execute().await;
+
+        if let Some(original) = original_dir {
+            let _ = std::env::set_current_dir(&original);
+        }
+
+        assert!(result.is_err());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Not in a Metis workspace"));
+    }
+
+    #[tokio::test]
+    async fn test_assign_vision() {
+        let temp_dir = tempdir().unwrap();
+        let original_dir = std::env::current_dir().ok();
+
+        if std::env::set_current_dir(temp_dir.path()).is_err() {
+            return;
+        }
+
+        let init_cmd = InitCommand {
+            name: Some("Test Project".to_string()),
+            preset: None,
+            initiatives: None,
+            prefix: None,
+            remote: None,
+        };
+        init_cmd.execute().await.unwrap();
+
+        let result = command(Some("TEST-V-0001"), Some("alice")).execute().await;
+
+        if let Some(original) = original_dir {
+            let _ = std::env::set_current_dir(&original);
+        }
+
+        assert!(result.is_ok());
+        let content =
+            std::fs::read_to_string(temp_dir.path().join(".metis").join("vision.md")).unwrap();
+        assert!(content.contains("assignee: \"alice\""));
+    }
+}
@@ -0,0 +1,250 @@
+use crate::commands::list::OutputFormat;
+use crate::output::Failure;
+use crate::workspace;
+use anyhow::Result;
+use clap::Args;
+use metis_core::application::services::workspace::{Board, BoardKind, BoardService};
+use metis_core::application::services::DatabaseService;
+use metis_core::{Application, Database};
+
+/// Width of one board column in table output
+const COLUMN_WIDTH: usize = 28;
+
+fn parse_board(value: &str) -> Result<BoardKind, String> {
+    value
+        .parse()
+        .map_err(|e: metis_core::MetisError| e.to_string())
+}
+
+#[derive(Args)]
+pub struct BoardCommand {
+    /// Board to show (vision, initiative, task, backlog); all boards when omitted
+    #[arg(value_parser = parse_board)]
+    pub board: Option<BoardKind>,
+
+    /// Include archived documents on the boards
+    #[arg(long)]
+    pub include_archived: bool,
+
+    /// Output format (table, compact, json)
+    #[arg(short = 'f', long, value_enum, default_value = "table")]
+    pub format: OutputFormat,
+}
+
+impl BoardCommand {
+    pub async fn execute(&self) -> Result<()> {
+        // 1. Validate we're in a metis workspace
+        let (workspace_exists, metis_dir) = workspace::has_metis_vault();
+        if !workspace_exists {
+            return Err(Failure::NotAWorkspace.into());
+        }
+        let metis_dir = metis_dir.unwrap();
+
+        // 2. Sync before reading to catch external edits
+        let db_path = metis_dir.join("metis.db");
+        let database = Database::new(db_path.to_str().unwrap())
+            .map_err(|e| anyhow::anyhow!("Failed to open database for sync: {}", e))?;
+        let app = Application::new(database);
+        app.sync_directory(&metis_dir)
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to sync workspace: {}", e))?;
+
+        // 3. Build the requested boards
+        let db = Database::new(db_path.to_str().unwrap())
+            .map_err(|e| anyhow::anyhow!("Database connection failed: {}", e))?;
+        let mut db_service = DatabaseService::new(db.into_repository());
+
+        let kinds = match self.board {
+            Some(kind) => vec![kind],
+            None => BoardKind::ALL.to_vec(),
+        };
+        let boards = BoardService::boards(&kinds, self.include_archived, &mut db_service)
+            .map_err(|e| anyhow::anyhow!("Database query failed: {}", e))?;
+
+        // 4. Display results based on format
+        match self.format.or_json() {
+            OutputFormat::Table => display_table(&boards),
+            OutputFormat::Compact => display_compact(&boards),
+            OutputFormat::Json => match serde_json::to_string_pretty(&boards) {
+                Ok(json) => println!("{}", json),
+                Err(e) => eprintln!("Error serializing to JSON: {}", e),
+            },
+        }
+
+        Ok(())
+    }
+}
+
+/// Display each board as side-by-side phase columns
+fn display_table(boards: &[Board]) {
+    if boards.is_empty() {
+        println!("No documents found.");
+        return;
+    }
+
+    for board in boards {
+        println!("\n{} board", capitalize(&board.board));
+
+        let headers: Vec<String> = board
+            .columns
+            .iter()
+            .map(|c| format!("{} ({})", c.phase.to_uppercase(), c.cards.len()))
+            .collect();
+        println!("{}", render_row(&headers));
+        println!(
+            "{}",
+            "-".repeat(board.columns.len() * (COLUMN_WIDTH + 1) - 1)
+        );
+
+        let rows = board
+            .columns
+            .iter()
+            .map(|c| c.cards.len())
+            .max()
+            .unwrap_or(0);
+        for row in 0..rows {
+            let cells: Vec<String> = board
+                .columns
+                .iter()
+                .map(|c| {
+                    c.cards
+                        .get(row)
+                        .map(|card| format!("{} {}", card.code, card.title))
+                        .unwrap_or_default()
+                })
+                .collect();
+            println!("{}", render_row(&cells));
+        }
+    }
+}
+
+/// Display one line per card for scripts
+/// Format: BOARD PHASE CODE TITLE
+fn display_compact(boards: &[Board]) {
+    for board in boards {
+        for column in &board.columns {
+            for card in &column.cards {
+                println!(
+                    "{} {} {} {}",
+                    board.board, column.phase, card.code, card.title
+                );
+            }
+        }
+    }
+}
+
+fn render_row(cells: &[String]) -> String {
+    cells
+        .iter()
+        .map(|cell| {
+            format!(
+                "{:<width$}",
+                truncate(cell, COLUMN_WIDTH - 1),
+                width = COLUMN_WIDTH
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+        .trim_end()
+        .to_string()
+}
+
+fn truncate(s: &str, max_chars: usize) -> String {
+    if s.chars().count() <= max_chars {
+        s.to_string()
+    } else {
+        let kept: String = s.chars().take(max_chars.saturating_sub(3)).collect();
+        format!("{}...", kept)
+    }
+}
+
+fn capitalize(s: &str) -> String {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::InitCommand;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_truncate_counts_characters() {
+        assert_eq!(truncate("short", 10), "short");
+        assert_eq!(truncate("ééééééééééé", 6), "ééé...");
+    }
+
+    #[tokio::test]
+    async fn test_board_command_no_workspace() {
+        let temp_dir = tempdir().unwrap();
+        let original_dir = std::env::current_dir().ok();
+
+        if std::env::set_current_dir(temp_dir.path()).is_err() {
+            return;
+        }
+
+        let cmd = BoardCommand {
+            board: None,
+            include_archived: false,
+            format: OutputFormat::Table,
+        };
+        let result = cmd.execute().await;
+
+        if let Some(original) = original_dir {
+            let _ = std::env::set_current_dir(&original);
+        }
+
+        assert!(result.is_err());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Not in a Metis workspace"));
+    }
+
+    #[tokio::test]
+    async fn test_board_command_in_workspace() {
+        let temp_dir = tempdir().unwrap();
+        let original_dir = std::env::current_dir().ok();
+
+        if std::env::set_current_dir(temp_dir.path()).is_err() {
+            return;
+        }
+
+        let init_cmd = InitCommand {
+            name: Some("Test Project".to_string()),
+            preset: None,
+            initiatives: None,
+            prefix: None,
+            remote: None,
+        };
+        init_cmd.execute().await.unwrap();
+
+        let mut results = Vec::new();
+        for format in [OutputFormat::Table, OutputFormat::Json] {
+            let cmd = BoardCommand {
+                board: None,
+                include_archived: false,
+                format,
+            };
+            results.push(cmd.execute().await);
+        }
+        let single = BoardCommand {
+            board: Some(BoardKind::Backlog),
+            include_archived: false,
+            format: OutputFormat::Compact,
+        };
+        results.push(single.execute().await);
+
+        if let Some(original) = original_dir {
+            let _ = std::env::set_current_dir(&original);
+        }
+
+        for result in results {
+            assert!(result.is_ok(), "board failed: {:?}", result.err());
+        }
+    }
+}
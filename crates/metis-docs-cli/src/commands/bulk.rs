@@ -0,0 +1,285 @@
+use crate::output::{say, Failure};
+use crate::workspace;
+use anyhow::Result;
+use clap::{Args, Subcommand};
+use dialoguer::Confirm;
+use metis_core::application::services::workspace::{BulkEdit, PropertyService};
+use metis_core::application::services::DatabaseService;
+use metis_core::dal::database::DocumentFilter;
+use metis_core::Database;
+use std::path::Path;
+
+#[derive(Args)]
+pub struct BulkCommand {
+    #[command(subcommand)]
+    pub action: BulkAction,
+}
+
+#[derive(Subcommand)]
+pub enum BulkAction {
+    /// Set frontmatter fields on every document matching a filter
+    Set {
+        /// Documents to update, e.g. 'type:task tag:#sprint-14'
+        #[arg(long)]
+        filter: String,
+        /// Fields as key=value; an empty value (key=) removes the field
+        #[arg(required = true, value_name = "KEY=VALUE")]
+        fields: Vec<String>,
+        /// Show the changes without writing them
+        #[arg(long)]
+        dry_run: bool,
+        /// Apply without asking for confirmation
+        #[arg(short = 'y', long)]
+        yes: bool,
+    },
+}
+
+impl BulkCommand {
+    pub async fn execute(&self) -> Result<()> {
+        // 1. Validate we're in a metis workspace
+        let (workspace_exists, metis_dir) = workspace::has_metis_vault();
+        if !workspace_exists {
+            return Err(Failure::NotAWorkspace.into());
+        }
+        let metis_dir = metis_dir.unwrap();
+
+        match &self.action {
+            BulkAction::Set {
+                filter,
+                fields,
+                dry_run,
+                yes,
+            } => set(&metis_dir, filter, fields, *dry_run, *yes).await,
+        }
+    }
+}
+
+async fn set(
+    metis_dir: &Path,
+    filter: &str,
+    fields: &[String],
+    dry_run: bool,
+    yes: bool,
+) -> Result<()> {
+    // 2. Parse the query and the field updates before touching anything
+    let filter = DocumentFilter::parse(filter).map_err(|e| anyhow::anyhow!(e))?;
+    let updates = fields
+        .iter()
+        .map(|field| parse_field(field))
+        .collect::<Result<Vec<_>>>()?;
+
+    // 3. Sync so the current values are known, then preview
+    let db = Database::new(&metis_dir.join("metis.db").to_string_lossy())
+        .map_err(|e| anyhow::anyhow!("Database initialization failed: {}", e))?;
+    let mut db_service = DatabaseService::new(db.into_repository());
+    workspace::sync(metis_dir, &mut db_service).await?;
+
+    let service = PropertyService::new(metis_dir);
+    let plan = service
+        .plan_bulk(&filter, &updates, &mut db_service)
+        .map_err(|e| anyhow::anyhow!("{}", e))?;
+    if plan.is_empty() {
+        say!("No documents match the filter.");
+        return Ok(());
+    }
+
+    let to_change = plan.iter().filter(|edit| edit.changed()).count();
+    for line in plan.iter().flat_map(|edit| preview_lines(&updates, edit)) {
+        if dry_run {
+            println!("{}", line);
+        } else {
+            say!("{}", line);
+        }
+    }
+    if to_change == 0 {
+        say!("\nNothing to change.");
+        return Ok(());
+    }
+    if dry_run {
+        println!(
+            "\nWould update {} of {} document(s).",
+            to_change,
+            plan.len()
+        );
+        return Ok(());
+    }
+    if !yes && !confirm(to_change)? {
+        say!("Bulk edit cancelled.");
+        return Ok(());
+    }
+
+    // 4. Apply, then sync so the database follows the files
+    let edits = service
+        .apply_bulk(&filter, &updates, &mut db_service)
+        .map_err(|e| anyhow::anyhow!("{}", e))?;
+    workspace::sync(metis_dir, &mut db_service).await?;
+
+    // 5. Report each document
+    say!();
+    for edit in &edits {
+        match &edit.error {
+            Some(error) => println!("✗ {} {}: {}", edit.short_code, edit.title, error),
+            None if edit.changed() => say!("✓ {} {}", edit.short_code, edit.title),
+            None => say!("- {} {}: unchanged", edit.short_code, edit.title),
+        }
+    }
+    let failed = edits.iter().filter(|edit| edit.error.is_some()).count();
+    let updated = edits
+        .iter()
+        .filter(|edit| edit.changed() && edit.error.is_none())
+        .count();
+    say!("\nUpdated {} of {} document(s).", updated, edits.len());
+    if failed > 0 {
+        anyhow::bail!("{} document(s) could not be updated", failed);
+    }
+
+    Ok(())
+}
+
+/// `key=value` as a key and a value, `None` for `key=`
+fn parse_field(field: &str) -> Result<(String, Option<String>)> {
+    let (key, value) = field
+        .split_once('=')
+        .ok_or_else(|| anyhow::anyhow!("'{}' is not a field update; use key=value", field))?;
+    let key = key.trim();
+    if key.is_empty() {
+        anyhow::bail!("'{}' has no field name; use key=value", field);
+    }
+    let value = value.trim();
+    Ok((
+        key.to_string(),
+        (!value.is_empty()).then(|| value.to_string()),
+    ))
+}
+
+/// The document, then one line per field: `key: old -> new`
+fn preview_lines(updates: &[(String, Option<String>)], edit: &BulkEdit) -> Vec<String> {
+    let mut lines = vec![format!("{} {}", edit.short_code, edit.title)];
+    for ((key, _), change) in updates.iter().zip(&edit.changes) {
+        lines.push(if change.changed() {
+            format!(
+                "  {}: {} -> {}",
+                key,
+                shown(&change.previous),
+                shown(&change.value)
+            )
+        } else {
+            format!("  {}: {} (unchanged)", key, shown(&change.value))
+        });
+    }
+    lines
+}
+
+fn shown(value: &Option<String>) -> &str {
+    value.as_deref().unwrap_or("(unset)")
+}
+
+fn confirm(count: usize) -> Result<bool> {
+    Confirm::new()
+        .with_prompt(format!("Update {} document(s)?", count))
+        .default(false)
+        .interact()
+        .map_err(|e| anyhow::anyhow!("Confirmation failed ({}); pass --yes to skip it", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::InitCommand;
+    use tempfile::tempdir;
+
+    fn bulk_set(filter: &str, fields: &[&str], dry_run: bool) -> BulkCommand {
+        BulkCommand {
+            action: BulkAction::Set {
+                filter: filter.to_string(),
+                fields: fields.iter().map(|field| field.to_string()).collect(),
+                dry_run,
+                yes: true,
+            },
+        }
+    }
+
+    #[test]
+    fn test_parse_field() {
+        assert_eq!(
+            parse_field("assignee=alice").unwrap(),
+            ("assignee".to_string(), Some("alice".to_string()))
+        );
+        assert_eq!(
+            parse_field("note=a=b").unwrap(),
+            ("note".to_string(), Some("a=b".to_string()))
+        );
+        assert_eq!(
+            parse_field("priority=").unwrap(),
+            ("priority".to_string(), None)
+        );
+        assert!(parse_field("priority").is_err());
+        assert!(parse_field("=P1").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_bulk_command_no_workspace() {
+        let temp_dir = tempdir().unwrap();
+        let original_dir = std::env::current_dir().ok();
+
+        if std::env::set_current_dir(temp_dir.path()).is_err() {
+            return;
+        }
+
+        let result = bulk_set("type:task", &["assignee=alice"], false)
+            .execute()
+            .await;
+
+        if let Some(original) = original_dir {
+            let _ = std::env::set_current_dir(&original);
+        }
+
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Not in a Metis workspace"));
+    }
+
+    #[tokio::test]
+    async fn test_bulk_set() {
+        let temp_dir = tempdir().unwrap();
+        let original_dir = std::env::current_dir().ok();
+
+        if std::env::set_current_dir(temp_dir.path()).is_err() {
+            return;
+        }
+
+        let init_cmd = InitCommand {
+            name: Some("Test Project".to_string()),
+            preset: None,
+            initiatives: None,
+            prefix: None,
+            remote: None,
+        };
+        init_cmd.execute().await.unwrap();
+
+        let vision = temp_dir.path().join(".metis").join("vision.md");
+        let preview = bulk_set("type:vision", &["assignee=alice", "priority=P1"], true)
+            .execute()
+            .await;
+        let after_preview = std::fs::read_to_string(&vision).unwrap();
+        let applied = bulk_set("type:vision", &["assignee=alice", "priority=P1"], false)
+            .execute()
+            .await;
+        let after_apply = std::fs::read_to_string(&vision).unwrap();
+        let structural = bulk_set("type:vision", &["parent=TEST-V-0002"], false)
+            .execute()
+            .await;
+
+        if let Some(original) = original_dir {
+            let _ = std::env::set_current_dir(&original);
+        }
+
+        assert!(preview.is_ok());
+        assert!(!after_preview.contains("assignee"));
+        assert!(applied.is_ok());
+        assert!(after_apply.contains("assignee: alice"));
+        assert!(after_apply.contains("priority: P1"));
+        assert!(structural.is_err());
+    }
+}
@@ -0,0 +1,267 @@
+use crate::commands::log::run_git;
+use crate::output::{self, say, Failure};
+use crate::workspace;
+use anyhow::Result;
+use clap::Args;
+use metis_core::{Application, Database};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+#[derive(Args)]
+pub struct CheckoutCommand {
+    /// Commit, tag or branch to view (e.g., HEAD~20, v1.0, a1b2c3d)
+    pub revision: String,
+
+    /// Directory to write the workspace to (defaults to a temporary
+    /// directory named after the commit)
+    #[arg(short, long)]
+    pub dir: Option<PathBuf>,
+
+    /// Replace the directory if it already exists
+    #[arg(long)]
+    pub force: bool,
+}
+
+impl CheckoutCommand {
+    pub async fn execute(&self) -> Result<()> {
+        // 1. Validate we're in a metis workspace
+        let (workspace_exists, metis_dir) = workspace::has_metis_vault();
+        if !workspace_exists {
+            return Err(Failure::NotAWorkspace.into());
+        }
+        let metis_dir = metis_dir.unwrap();
+
+        // 2. Resolve the revision to a commit
+        let sha = run_git(
+            &metis_dir,
+            &[
+                "rev-parse".to_string(),
+                "--verify".to_string(),
+                "--quiet".to_string(),
+                format!("{}^{{commit}}", self.revision),
+            ],
+        )
+        .map_err(|_| anyhow::anyhow!("'{}' is not a commit", self.revision))?
+        .trim()
+        .to_string();
+        let commit = run_git(
+            &metis_dir,
+            &[
+                "log".to_string(),
+                "-1".to_string(),
+                "--format=%h %cs %s".to_string(),
+                sha.clone(),
+            ],
+        )?
+        .trim()
+        .to_string();
+
+        // 3. List the workspace files at that commit, relative to .metis
+        let files: Vec<String> = run_git(
+            &metis_dir,
+            &[
+                "ls-tree".to_string(),
+                "-r".to_string(),
+                "--name-only".to_string(),
+                "-z".to_string(),
+                sha.clone(),
+            ],
+        )?
+        .split('\0')
+        .filter(|path| !path.is_empty())
+        .map(str::to_string)
+        .collect();
+        if files.is_empty() {
+            anyhow::bail!("The workspace has no committed files at {}", commit);
+        }
+
+        // 4. Write them out, read-only, into a fresh directory
+        let root = self
+            .dir
+            .clone()
+            .unwrap_or_else(|| std::env::temp_dir().join(format!("metis-{}", &sha[..12])));
+        if root.exists() {
+            // Only ever replace an earlier checkout, never the live workspace
+            let existing = root.join(".metis");
+            let is_live = existing.canonicalize().ok() == metis_dir.canonicalize().ok();
+            if self.force && (is_live || !existing.is_dir()) {
+                anyhow::bail!(
+                    "{} is not an earlier checkout; refusing to replace it",
+                    root.display()
+                );
+            }
+            if !self.force {
+                anyhow::bail!(
+                    "{} already exists (use --force to replace it)",
+                    root.display()
+                );
+            }
+            std::fs::remove_dir_all(&root)
+                .map_err(|e| anyhow::anyhow!("Failed to remove {}: {}", root.display(), e))?;
+        }
+        let checkout_dir = root.join(".metis");
+        for path in &files {
+            let target = checkout_dir.join(path);
+            if let Some(parent) = target.parent() {
+                std::fs::create_dir_all(parent)
+                    .map_err(|e| anyhow::anyhow!("Failed to create {}: {}", parent.display(), e))?;
+            }
+            let content = show_file(&metis_dir, &sha, path)?;
+            std::fs::write(&target, content)
+                .map_err(|e| anyhow::anyhow!("Failed to write {}: {}", target.display(), e))?;
+            set_read_only(&target)?;
+        }
+
+        // 5. Build the checkout's own database so every read command works there
+        let db_path = checkout_dir.join("metis.db");
+        let database = Database::new(db_path.to_str().unwrap())
+            .map_err(|e| anyhow::anyhow!("Failed to open database for sync: {}", e))?;
+        let app = Application::new(database);
+        app.sync_directory(&checkout_dir)
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to sync the checkout: {}", e))?;
+
+        say!("✓ Checked out the workspace at {}", commit);
+        say!("  Directory: {}", root.display());
+        say!("  Documents are read-only. Explore with:");
+        say!("    cd {} && metis status", root.display());
+        output::quiet_result(root.display());
+
+        Ok(())
+    }
+}
+
+/// Raw contents of `path` (relative to .metis) at `sha`
+fn show_file(metis_dir: &Path, sha: &str, path: &str) -> Result<Vec<u8>> {
+    let output = Command::new("git")
+        .arg("show")
+        .arg(format!("{}:./{}", sha, path))
+        .current_dir(metis_dir)
+        .output()
+        .map_err(|e| anyhow::anyhow!("Failed to run git: {}", e))?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "Failed to read {} at {}: {}",
+            path,
+            sha,
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(output.stdout)
+}
+
+/// Make a checked-out file read-only, so the historical view isn't edited
+/// by mistake
+fn set_read_only(path: &Path) -> Result<()> {
+    let mut permissions = std::fs::metadata(path)
+        .map_err(|e| anyhow::anyhow!("Failed to read {}: {}", path.display(), e))?
+        .permissions();
+    permissions.set_readonly(true);
+    std::fs::set_permissions(path, permissions)
+        .map_err(|e| anyhow::anyhow!("Failed to protect {}: {}", path.display(), e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::InitCommand;
+    use tempfile::tempdir;
+
+    fn git(dir: &Path, args: &[&str]) -> bool {
+        Command::new("git")
+            .args(["-c", "user.name=Test", "-c", "user.email=test@example.com"])
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .map(|output| output.status.success())
+            .unwrap_or(false)
+    }
+
+    fn checkout(revision: &str, dir: &Path, force: bool) -> CheckoutCommand {
+        CheckoutCommand {
+            revision: revision.to_string(),
+            dir: Some(dir.to_path_buf()),
+            force,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_checkout_command_no_workspace() {
+        let temp_dir = tempdir().unwrap();
+        let original_dir = std::env::current_dir().ok();
+
+        if std::env::set_current_dir(temp_dir.path()).is_err() {
+            return;
+        }
+
+        let result = checkout("HEAD", &temp_dir.path().join("view"), false)
+            .execute()
+            .await;
+
+        if let Some(original) = original_dir {
+            let _ = std::env::set_current_dir(&original);
+        }
+
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Not in a Metis workspace"));
+    }
+
+    #[tokio::test]
+    async fn test_checkout_command_materializes_commit() {
+        let temp_dir = tempdir().unwrap();
+        let original_dir = std::env::current_dir().ok();
+
+        if std::env::set_current_dir(temp_dir.path()).is_err() {
+            return;
+        }
+
+        let init_cmd = InitCommand {
+            name: Some("Test Project".to_string()),
+            preset: None,
+            initiatives: None,
+            prefix: None,
+            remote: None,
+        };
+        init_cmd.execute().await.unwrap();
+
+        // Commit the workspace, then edit the vision after the commit
+        let vision = temp_dir.path().join(".metis").join("vision.md");
+        let committed = std::fs::read_to_string(&vision).unwrap();
+        if !git(temp_dir.path(), &["init", "-q"])
+            || !git(temp_dir.path(), &["add", "-A"])
+            || !git(temp_dir.path(), &["commit", "-q", "-m", "Plan"])
+        {
+            if let Some(original) = original_dir {
+                let _ = std::env::set_current_dir(&original);
+            }
+            return;
+        }
+        std::fs::write(&vision, committed.replace("Test Project", "Changed")).unwrap();
+
+        let view = temp_dir.path().join("view");
+        let result = checkout("HEAD", &view, false).execute().await;
+        let existing = checkout("HEAD", &view, false).execute().await;
+        let replaced = checkout("HEAD", &view, true).execute().await;
+        let unknown = checkout("no-such-rev", &temp_dir.path().join("other"), false)
+            .execute()
+            .await;
+
+        if let Some(original) = original_dir {
+            let _ = std::env::set_current_dir(&original);
+        }
+
+        assert!(result.is_ok(), "checkout failed: {:?}", result);
+        let checked_out = view.join(".metis").join("vision.md");
+        assert_eq!(std::fs::read_to_string(&checked_out).unwrap(), committed);
+        assert!(std::fs::metadata(&checked_out)
+            .unwrap()
+            .permissions()
+            .readonly());
+        assert!(view.join(".metis").join("metis.db").exists());
+        assert!(existing.unwrap_err().to_string().contains("already exists"));
+        assert!(replaced.is_ok());
+        assert!(unknown.unwrap_err().to_string().contains("is not a commit"));
+    }
+}
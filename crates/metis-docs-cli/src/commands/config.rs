@@ -1,7 +1,12 @@
+use crate::commands::open::run_editor;
+use crate::output::{say, Failure};
 use crate::workspace;
 use anyhow::Result;
 use clap::{Args, Subcommand};
+use metis_core::application::services::workspace::ConfigurationRecoveryService;
+use metis_core::domain::configuration::{ConfigFile, CONFIG_KEYS};
 use metis_core::{domain::configuration::FlightLevelConfig, Database};
+use std::path::Path;
 
 #[derive(Args)]
 pub struct ConfigCommand {
@@ -13,8 +18,13 @@ pub struct ConfigCommand {
 pub enum ConfigAction {
     /// Show current configuration
     Show,
-    /// Set configuration using preset or custom values
+    /// Set a config.toml setting, or the flight levels using a preset or custom values
     Set {
+        /// Setting to change, e.g. sync.remote or project.prefix
+        #[arg(conflicts_with_all = ["preset", "initiatives"], requires = "value")]
+        key: Option<String>,
+        /// New value for the setting
+        value: Option<String>,
         /// Configuration preset (streamlined, direct)
         #[arg(short, long)]
         preset: Option<String>,
@@ -27,6 +37,19 @@ pub enum ConfigAction {
         /// Configuration key to retrieve
         key: String,
     },
+    /// Remove an optional config.toml setting, such as sync.remote
+    Unset {
+        /// Setting to remove
+        key: String,
+    },
+    /// Open config.toml in your editor, then validate and apply it
+    Edit {
+        /// Editor to use instead of the configured viewer or $EDITOR
+        #[arg(long)]
+        editor: Option<String>,
+    },
+    /// Check config.toml and explain any problems
+    Validate,
 }
 
 impl ConfigCommand {
@@ -34,7 +57,7 @@ impl ConfigCommand {
         // 1. Validate we're in a metis workspace
         let (workspace_exists, metis_dir) = workspace::has_metis_vault();
         if !workspace_exists {
-            anyhow::bail!("Not in a Metis workspace. Run 'metis init' to create one.");
+            return Err(Failure::NotAWorkspace.into());
         }
         let metis_dir = metis_dir.unwrap();
 
@@ -48,15 +71,60 @@ impl ConfigCommand {
 
         // 3. Execute the requested action
         match &self.action {
-            ConfigAction::Show => self.show_config(&mut config_repo).await,
+            ConfigAction::Show => {
+                self.show_config(&mut config_repo).await?;
+                show_settings(&metis_dir);
+                Ok(())
+            }
             ConfigAction::Set {
+                key: Some(key),
+                value,
+                ..
+            } => set_setting(&metis_dir, key, value.as_deref()),
+            ConfigAction::Set {
+                key: None,
                 preset,
                 initiatives,
+                ..
             } => {
                 self.set_config(&mut config_repo, preset, *initiatives)
-                    .await
+                    .await?;
+                // config.toml is the source of truth; keep it in step so the
+                // next sync doesn't revert the change
+                let flight_levels = config_repo
+                    .get_flight_level_config()
+                    .map_err(|e| anyhow::anyhow!("Failed to read configuration: {}", e))?;
+                update_config_file(&metis_dir, |config| {
+                    config.flight_levels = flight_levels;
+                    Ok(())
+                })
+            }
+            ConfigAction::Get { key } if CONFIG_KEYS.contains(&key.as_str()) => {
+                let config = load_config_file(&metis_dir)?;
+                match config
+                    .get_value(key)
+                    .map_err(|e| anyhow::anyhow!("{}", e))?
+                {
+                    Some(value) => println!("{}", value),
+                    None => anyhow::bail!("'{}' is not set", key),
+                }
+                Ok(())
             }
             ConfigAction::Get { key } => self.get_config(&mut config_repo, key).await,
+            ConfigAction::Unset { key } => set_setting(&metis_dir, key, None),
+            ConfigAction::Edit { editor } => {
+                let config_path = metis_dir.join("config.toml");
+                run_editor(editor.as_deref(), &metis_dir, &config_path)?;
+                validate_config_file(&metis_dir)?;
+                apply_config_file(&metis_dir)?;
+                say!("✓ Configuration updated");
+                Ok(())
+            }
+            ConfigAction::Validate => {
+                validate_config_file(&metis_dir)?;
+                say!("✓ config.toml is valid");
+                Ok(())
+            }
         }
     }
 
@@ -118,11 +186,11 @@ impl ConfigCommand {
             .set_flight_level_config(&new_config)
             .map_err(|e| anyhow::anyhow!("Failed to save configuration: {}", e))?;
 
-        println!("Configuration updated successfully!");
-        println!("New configuration:");
-        println!("  Preset: {}", new_config.preset_name());
-        println!("  Initiatives enabled: {}", new_config.initiatives_enabled);
-        println!("  Hierarchy: {}", new_config.hierarchy_display());
+        say!("Configuration updated successfully!");
+        say!("New configuration:");
+        say!("  Preset: {}", new_config.preset_name());
+        say!("  Initiatives enabled: {}", new_config.initiatives_enabled);
+        say!("  Hierarchy: {}", new_config.hierarchy_display());
 
         Ok(())
     }
@@ -148,6 +216,92 @@ impl ConfigCommand {
     }
 }
 
+pub(crate) fn load_config_file(metis_dir: &Path) -> Result<ConfigFile> {
+    ConfigFile::load(metis_dir.join("config.toml"))
+        .map_err(|e| anyhow::anyhow!("{}. Run 'metis config validate' for details.", e))
+}
+
+/// Load config.toml, change it, save it and apply it to the database
+pub(crate) fn update_config_file(
+    metis_dir: &Path,
+    change: impl FnOnce(&mut ConfigFile) -> Result<()>,
+) -> Result<()> {
+    let mut config = load_config_file(metis_dir)?;
+    change(&mut config)?;
+    config
+        .save(metis_dir.join("config.toml"))
+        .map_err(|e| anyhow::anyhow!("Failed to save config.toml: {}", e))?;
+    apply_config_file(metis_dir)
+}
+
+/// Copy the prefix and flight levels from config.toml into the database
+fn apply_config_file(metis_dir: &Path) -> Result<()> {
+    ConfigurationRecoveryService::sync_config_to_database(
+        metis_dir.to_path_buf(),
+        metis_dir.join("metis.db"),
+    )
+    .map_err(|e| anyhow::anyhow!("Failed to apply config.toml: {}", e))?;
+    Ok(())
+}
+
+/// Set a config.toml setting, or unset it when `value` is None
+fn set_setting(metis_dir: &Path, key: &str, value: Option<&str>) -> Result<()> {
+    let mut previous = None;
+    update_config_file(metis_dir, |config| {
+        previous = config.get_value(key).ok().flatten();
+        config
+            .set_value(key, value)
+            .map_err(|e| anyhow::anyhow!("{}", e))
+    })?;
+
+    match value {
+        Some(_) => {
+            let config = load_config_file(metis_dir)?;
+            let value = config.get_value(key).ok().flatten().unwrap_or_default();
+            say!("✓ {} = {}", key, value);
+            if key == "project.prefix" && previous.as_deref() != Some(value.as_str()) {
+                say!(
+                    "  Existing documents keep their short codes; new documents will use {}-",
+                    value
+                );
+            }
+        }
+        None => say!("✓ Unset {}", key),
+    }
+    Ok(())
+}
+
+/// Print every problem in config.toml and fail if there are any
+fn validate_config_file(metis_dir: &Path) -> Result<()> {
+    let config_path = metis_dir.join("config.toml");
+    let content = std::fs::read_to_string(&config_path)
+        .map_err(|e| anyhow::anyhow!("Failed to read {}: {}", config_path.display(), e))?;
+    let problems = ConfigFile::problems(&content);
+    if problems.is_empty() {
+        return Ok(());
+    }
+
+    println!("✗ {}:", config_path.display());
+    for problem in &problems {
+        println!("  - {}", problem);
+    }
+    Err(Failure::Validation(format!("config.toml has {} problem(s)", problems.len())).into())
+}
+
+fn show_settings(metis_dir: &Path) {
+    let Ok(config) = ConfigFile::load(metis_dir.join("config.toml")) else {
+        println!();
+        println!("config.toml could not be read. Run 'metis config validate' for details.");
+        return;
+    };
+    println!();
+    println!("Settings (config.toml):");
+    for key in CONFIG_KEYS {
+        let value = config.get_value(key).ok().flatten();
+        println!("  {:<42} {}", key, value.as_deref().unwrap_or("(not set)"));
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -169,6 +323,7 @@ mod tests {
 
             initiatives: None,
             prefix: None,
+            remote: None,
         };
         init_cmd.execute().await.unwrap();
 
@@ -201,12 +356,15 @@ mod tests {
 
             initiatives: None,
             prefix: None,
+            remote: None,
         };
         init_cmd.execute().await.unwrap();
 
         // Test setting streamlined preset
         let config_cmd = ConfigCommand {
             action: ConfigAction::Set {
+                key: None,
+                value: None,
                 preset: Some("streamlined".to_string()),
                 initiatives: None,
             },
@@ -236,12 +394,15 @@ mod tests {
 
             initiatives: None,
             prefix: None,
+            remote: None,
         };
         init_cmd.execute().await.unwrap();
 
         // Test setting invalid preset
         let config_cmd = ConfigCommand {
             action: ConfigAction::Set {
+                key: None,
+                value: None,
                 preset: Some("invalid".to_string()),
                 initiatives: None,
             },
@@ -282,4 +443,65 @@ mod tests {
             std::env::set_current_dir(dir).unwrap();
         }
     }
+
+    #[tokio::test]
+    async fn test_config_set_and_validate_settings() {
+        let temp_dir = tempdir().unwrap();
+        let original_dir = std::env::current_dir().ok();
+
+        std::env::set_current_dir(temp_dir.path()).unwrap();
+
+        let init_cmd = InitCommand {
+            name: Some("Test Project".to_string()),
+            preset: None,
+            initiatives: None,
+            prefix: None,
+            remote: None,
+        };
+        init_cmd.execute().await.unwrap();
+
+        let run = |action| ConfigCommand { action };
+        let set = |key: &str, value: &str| {
+            run(ConfigAction::Set {
+                key: Some(key.to_string()),
+                value: Some(value.to_string()),
+                preset: None,
+                initiatives: None,
+            })
+        };
+        let set_remote = set("sync.remote", "git@github.com:org/plans.git")
+            .execute()
+            .await;
+        let set_preset = set("flight_levels.preset", "direct").execute().await;
+        let bad_remote = set("sync.remote", "not a url").execute().await;
+        let unknown = set("sync.branch", "main").execute().await;
+        let config_path = temp_dir.path().join(".metis").join("config.toml");
+        let saved = ConfigFile::load(&config_path).unwrap();
+        let unset = run(ConfigAction::Unset {
+            key: "sync.remote".to_string(),
+        })
+        .execute()
+        .await;
+        let valid = run(ConfigAction::Validate).execute().await;
+
+        std::fs::write(&config_path, "[project]\nprefix = \"TEST\"\n").unwrap();
+        let invalid = run(ConfigAction::Validate).execute().await;
+
+        if let Some(dir) = original_dir {
+            std::env::set_current_dir(dir).unwrap();
+        }
+
+        assert!(set_remote.is_ok());
+        assert!(set_preset.is_ok());
+        assert!(bad_remote.is_err());
+        assert!(unknown.unwrap_err().to_string().contains("Unknown setting"));
+        assert_eq!(
+            saved.sync().remote.as_deref(),
+            Some("git@github.com:org/plans.git")
+        );
+        assert!(!saved.flight_levels().initiatives_enabled);
+        assert!(unset.is_ok());
+        assert!(valid.is_ok());
+        assert!(invalid.unwrap_err().to_string().contains("1 problem"));
+    }
 }
@@ -1,3 +1,4 @@
+use crate::output::{self, say, Failure};
 use crate::workspace;
 use anyhow::Result;
 use metis_core::{
@@ -10,7 +11,7 @@ pub async fn create_new_adr(title: &str) -> Result<()> {
     // 1. Validate we're in a metis workspace
     let (workspace_exists, metis_dir) = workspace::has_metis_vault();
     if !workspace_exists {
-        anyhow::bail!("Not in a Metis workspace. Run 'metis init' to create one.");
+        return Err(Failure::NotAWorkspace.into());
     }
     let metis_dir = metis_dir.unwrap();
 
@@ -31,9 +32,10 @@ pub async fn create_new_adr(title: &str) -> Result<()> {
         .await
         .map_err(|e| anyhow::anyhow!("Failed to create ADR: {}", e))?;
 
-    println!("✓ Created ADR: {}", result.file_path.display());
-    println!("  ID: {}", result.document_id);
-    println!("  Short Code: {}", result.short_code);
+    say!("✓ Created ADR: {}", result.file_path.display());
+    say!("  ID: {}", result.document_id);
+    say!("  Short Code: {}", result.short_code);
+    output::quiet_result(&result.short_code);
     println!("  Title: {}", title);
 
     Ok(())
@@ -81,6 +83,7 @@ mod tests {
 
             initiatives: None,
             prefix: None,
+            remote: None,
         };
         init_cmd.execute().await.unwrap();
 
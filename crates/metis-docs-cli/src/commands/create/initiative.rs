@@ -1,3 +1,4 @@
+use crate::output::{self, say, Failure};
 use crate::workspace;
 use anyhow::Result;
 use dialoguer::Select;
@@ -12,7 +13,7 @@ pub async fn create_new_initiative(title: &str, vision_id: &str) -> Result<()> {
     // 1. Validate we're in a metis workspace
     let (workspace_exists, metis_dir) = workspace::has_metis_vault();
     if !workspace_exists {
-        anyhow::bail!("Not in a Metis workspace. Run 'metis init' to create one.");
+        return Err(Failure::NotAWorkspace.into());
     }
     let metis_dir = metis_dir.unwrap();
 
@@ -42,11 +43,12 @@ pub async fn create_new_initiative(title: &str, vision_id: &str) -> Result<()> {
         .await
         .map_err(|e| anyhow::anyhow!("Failed to create initiative: {}", e))?;
 
-    println!("✓ Created initiative: {}", result.file_path.display());
-    println!("  ID: {}", result.document_id);
-    println!("  Short Code: {}", result.short_code);
-    println!("  Title: {}", title);
-    println!("  Parent Vision: {}", vision_id);
+    say!("✓ Created initiative: {}", result.file_path.display());
+    say!("  ID: {}", result.document_id);
+    say!("  Short Code: {}", result.short_code);
+    output::quiet_result(&result.short_code);
+    say!("  Title: {}", title);
+    say!("  Parent Vision: {}", vision_id);
     println!("  Complexity: {:?}", complexity);
 
     Ok(())
@@ -172,6 +174,7 @@ mod tests {
             preset: None,
             initiatives: None,
             prefix: None,
+            remote: None,
         };
         if init_cmd.execute().await.is_err() {
             let _ = std::env::set_current_dir(original_dir);
@@ -206,6 +209,7 @@ mod tests {
             preset: None,
             initiatives: None,
             prefix: None,
+            remote: None,
         };
         init_cmd.execute().await.unwrap();
 
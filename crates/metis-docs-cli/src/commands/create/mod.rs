@@ -4,8 +4,11 @@ mod specification;
 mod task;
 
 use crate::commands::SyncCommand;
+use crate::output::say;
+use crate::workspace;
 use anyhow::Result;
 use clap::{Args, Subcommand};
+use metis_core::{application::services::QueryService, Database};
 
 #[derive(Args)]
 pub struct CreateCommand {
@@ -48,6 +51,8 @@ pub enum CreateCommands {
 
 impl CreateCommand {
     pub async fn execute(&self) -> Result<()> {
+        warn_possible_duplicates(self.title());
+
         match &self.document_type {
             CreateCommands::Initiative { title, vision } => {
                 initiative::create_new_initiative(title, vision).await?;
@@ -64,10 +69,50 @@ impl CreateCommand {
         }
 
         // Auto-sync after creating documents to update the database index
-        println!("\nSyncing workspace...");
-        let sync_cmd = SyncCommand {};
+        say!("\nSyncing workspace...");
+        let sync_cmd = SyncCommand {
+            action: None,
+            dry_run: false,
+        };
         sync_cmd.execute().await?;
 
         Ok(())
     }
+
+    fn title(&self) -> &str {
+        match &self.document_type {
+            CreateCommands::Initiative { title, .. }
+            | CreateCommands::Task { title, .. }
+            | CreateCommands::Adr { title }
+            | CreateCommands::Specification { title, .. } => title,
+        }
+    }
+}
+
+/// Print existing documents with similar titles. Best effort: any problem
+/// opening the workspace is left for the create command itself to report.
+fn warn_possible_duplicates(title: &str) {
+    let (workspace_exists, metis_dir) = workspace::has_metis_vault();
+    let db_path = match metis_dir {
+        Some(dir) if workspace_exists => dir.join("metis.db"),
+        _ => return,
+    };
+    if !db_path.exists() {
+        return;
+    }
+
+    let duplicates = Database::new(&db_path.to_string_lossy())
+        .ok()
+        .and_then(|db| db.repository().ok())
+        .and_then(|repo| QueryService::new(repo).possible_duplicates(title).ok())
+        .unwrap_or_default();
+
+    for duplicate in duplicates {
+        say!(
+            "⚠ Possible duplicate of {}: {} ({:.0}% similar)",
+            duplicate.document.short_code,
+            duplicate.document.title,
+            duplicate.score * 100.0
+        );
+    }
 }
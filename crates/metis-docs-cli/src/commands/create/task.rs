@@ -1,3 +1,4 @@
+use crate::output::{self, say, Failure};
 use crate::workspace;
 use anyhow::Result;
 use metis_core::{
@@ -11,7 +12,7 @@ pub async fn create_new_task(title: &str, initiative_id: &str) -> Result<()> {
     // 1. Validate we're in a metis workspace
     let (workspace_exists, metis_dir) = workspace::has_metis_vault();
     if !workspace_exists {
-        anyhow::bail!("Not in a Metis workspace. Run 'metis init' to create one.");
+        return Err(Failure::NotAWorkspace.into());
     }
     let metis_dir = metis_dir.unwrap();
 
@@ -35,10 +36,11 @@ pub async fn create_new_task(title: &str, initiative_id: &str) -> Result<()> {
         .await
         .map_err(|e| anyhow::anyhow!("Failed to create task: {}", e))?;
 
-    println!("✓ Created task: {}", result.file_path.display());
-    println!("  Short Code: {}", result.short_code);
-    println!("  Title: {}", title);
-    println!("  Parent Initiative: {}", initiative_id);
+    say!("✓ Created task: {}", result.file_path.display());
+    say!("  Short Code: {}", result.short_code);
+    output::quiet_result(&result.short_code);
+    say!("  Title: {}", title);
+    say!("  Parent Initiative: {}", initiative_id);
 
     Ok(())
 }
@@ -125,6 +127,7 @@ mod tests {
 
             initiatives: None,
             prefix: None,
+            remote: None,
         };
         if init_cmd.execute().await.is_err() {
             if let Some(original) = original_dir {
@@ -0,0 +1,236 @@
+use crate::output::{say, Failure};
+use crate::workspace;
+use anyhow::Result;
+use clap::{Args, Subcommand};
+use metis_core::application::services::workspace::{CriteriaService, CriteriaStatus};
+use metis_core::application::services::DatabaseService;
+use metis_core::Database;
+
+#[derive(Args)]
+pub struct CriteriaCommand {
+    #[command(subcommand)]
+    pub action: CriteriaAction,
+}
+
+#[derive(Subcommand)]
+pub enum CriteriaAction {
+    /// List a document's exit criteria, numbered
+    List {
+        /// Document short code (e.g., PROJ-T-0001)
+        short_code: String,
+    },
+    /// Check off a criterion by its number from `metis criteria list`
+    Check {
+        /// Document short code (e.g., PROJ-T-0001)
+        short_code: String,
+        /// Criterion number, starting at 1
+        number: usize,
+    },
+    /// Uncheck a criterion by its number
+    Uncheck {
+        /// Document short code (e.g., PROJ-T-0001)
+        short_code: String,
+        /// Criterion number, starting at 1
+        number: usize,
+    },
+    /// Show how many criteria are met
+    Status {
+        /// Document short code (e.g., PROJ-T-0001)
+        short_code: String,
+    },
+}
+
+impl CriteriaCommand {
+    pub async fn execute(&self) -> Result<()> {
+        // 1. Validate we're in a metis workspace
+        let (workspace_exists, metis_dir) = workspace::has_metis_vault();
+        if !workspace_exists {
+            return Err(Failure::NotAWorkspace.into());
+        }
+        let metis_dir = metis_dir.unwrap();
+
+        // 2. Sync so the criteria reflect the files on disk
+        let db = Database::new(&metis_dir.join("metis.db").to_string_lossy())
+            .map_err(|e| anyhow::anyhow!("Database initialization failed: {}", e))?;
+        let mut db_service = DatabaseService::new(db.into_repository());
+        workspace::sync(&metis_dir, &mut db_service).await?;
+        let service = CriteriaService::new(&metis_dir);
+
+        match &self.action {
+            CriteriaAction::List { short_code } => {
+                let status = service
+                    .status(short_code, &mut db_service)
+                    .map_err(|e| anyhow::anyhow!("{}", e))?;
+                if status.criteria.is_empty() {
+                    say!("{} has no exit criteria.", short_code);
+                    return Ok(());
+                }
+                for line in criteria_lines(&status) {
+                    println!("{}", line);
+                }
+            }
+            CriteriaAction::Check { short_code, number }
+            | CriteriaAction::Uncheck { short_code, number } => {
+                let checked = matches!(self.action, CriteriaAction::Check { .. });
+                let status = service
+                    .set_checked(short_code, *number, checked, &mut db_service)
+                    .map_err(|e| anyhow::anyhow!("{}", e))?;
+                workspace::sync(&metis_dir, &mut db_service).await?;
+
+                let criterion = &status.criteria[*number - 1];
+                say!(
+                    "✓ {} criterion {}: {}",
+                    if checked { "Checked" } else { "Unchecked" },
+                    number,
+                    criterion.text
+                );
+                say!("  {}", summary(&status));
+            }
+            CriteriaAction::Status { short_code } => {
+                let status = service
+                    .status(short_code, &mut db_service)
+                    .map_err(|e| anyhow::anyhow!("{}", e))?;
+                println!("{} {}", status.short_code, status.title);
+                println!("  {}", summary(&status));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// `[x] 1. Text` per criterion
+fn criteria_lines(status: &CriteriaStatus) -> Vec<String> {
+    status
+        .criteria
+        .iter()
+        .map(|criterion| {
+            format!(
+                "[{}] {}. {}",
+                if criterion.checked { "x" } else { " " },
+                criterion.number,
+                criterion.text
+            )
+        })
+        .collect()
+}
+
+fn summary(status: &CriteriaStatus) -> String {
+    if status.criteria.is_empty() {
+        return "No exit criteria".to_string();
+    }
+    format!(
+        "{}/{} criteria met{}",
+        status.checked(),
+        status.criteria.len(),
+        if status.met {
+            " - exit criteria met"
+        } else {
+            ""
+        }
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::InitCommand;
+    use tempfile::tempdir;
+
+    async fn run(action: CriteriaAction) -> Result<()> {
+        CriteriaCommand { action }.execute().await
+    }
+
+    #[tokio::test]
+    async fn test_criteria_command_no_workspace() {
+        let temp_dir = tempdir().unwrap();
+        let original_dir = std::env::current_dir().ok();
+
+        if std::env::set_current_dir(temp_dir.path()).is_err() {
+            return;
+        }
+
+        let result = run(CriteriaAction::Status {
+            short_code: "TEST-V-0001".to_string(),
+        })
+        .await;
+
+        if let Some(original) = original_dir {
+            let _ = std::env::set_current_dir(&original);
+        }
+
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Not in a Metis workspace"));
+    }
+
+    #[tokio::test]
+    async fn test_criteria_check_and_uncheck() {
+        let temp_dir = tempdir().unwrap();
+        let original_dir = std::env::current_dir().ok();
+
+        if std::env::set_current_dir(temp_dir.path()).is_err() {
+            return;
+        }
+
+        let init_cmd = InitCommand {
+            name: Some("Test Project".to_string()),
+            preset: None,
+            initiatives: None,
+            prefix: None,
+            remote: None,
+        };
+        init_cmd.execute().await.unwrap();
+
+        let code = || "TEST-V-0001".to_string();
+        let listed = run(CriteriaAction::List { short_code: code() }).await;
+        let checked = run(CriteriaAction::Check {
+            short_code: code(),
+            number: 2,
+        })
+        .await;
+        let out_of_range = run(CriteriaAction::Check {
+            short_code: code(),
+            number: 99,
+        })
+        .await;
+        let vision = temp_dir.path().join(".metis").join("vision.md");
+        let after_check = std::fs::read_to_string(&vision).unwrap();
+        let unchecked = run(CriteriaAction::Uncheck {
+            short_code: code(),
+            number: 2,
+        })
+        .await;
+        let status = run(CriteriaAction::Status { short_code: code() }).await;
+        let after_uncheck = std::fs::read_to_string(&vision).unwrap();
+
+        if let Some(original) = original_dir {
+            let _ = std::env::set_current_dir(&original);
+        }
+
+        assert!(listed.is_ok());
+        assert!(checked.is_ok());
+        assert!(out_of_range
+            .unwrap_err()
+            .to_string()
+            .contains("no criterion 99"));
+        assert!(unchecked.is_ok());
+        assert!(status.is_ok());
+        assert!(after_check.contains("- [x] Current and future states are documented"));
+        assert!(after_uncheck.contains("- [ ] Current and future states are documented"));
+        assert!(after_uncheck.contains("exit_criteria_met: false"));
+    }
+
+    #[test]
+    fn test_summary() {
+        let status = CriteriaStatus {
+            short_code: "TEST-T-0001".to_string(),
+            title: "Task".to_string(),
+            criteria: vec![],
+            met: false,
+        };
+        assert_eq!(summary(&status), "No exit criteria");
+        assert!(criteria_lines(&status).is_empty());
+    }
+}
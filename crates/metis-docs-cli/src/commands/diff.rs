@@ -0,0 +1,508 @@
+use crate::commands::log::run_git;
+use crate::output::Failure;
+use crate::workspace;
+use anyhow::Result;
+use clap::Args;
+use metis_core::{Application, Database};
+use std::path::Path;
+use std::process::Command;
+
+/// Unchanged lines shown around each change in body diffs
+const CONTEXT_LINES: usize = 3;
+
+#[derive(Args)]
+pub struct DiffCommand {
+    /// Document short code to diff; every changed document when omitted
+    pub short_code: Option<String>,
+
+    /// Commit to diff from (defaults to HEAD)
+    #[arg(long)]
+    pub from: Option<String>,
+
+    /// Commit to diff to (defaults to the files on disk)
+    #[arg(long)]
+    pub to: Option<String>,
+
+    /// Diff the files on disk against the upstream branch instead of HEAD
+    #[arg(long, conflicts_with = "from")]
+    pub remote: bool,
+}
+
+/// A document's path before and after, `None` when it didn't exist
+struct ChangedFile {
+    old_path: Option<String>,
+    new_path: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LineOp {
+    Equal,
+    Delete,
+    Insert,
+}
+
+/// A frontmatter field that differs between two versions
+#[derive(Debug, PartialEq, Eq)]
+enum FieldChange {
+    Added(String, String),
+    Removed(String, String),
+    Changed(String, String, String),
+}
+
+impl DiffCommand {
+    pub async fn execute(&self) -> Result<()> {
+        // 1. Validate we're in a metis workspace
+        let (workspace_exists, metis_dir) = workspace::has_metis_vault();
+        if !workspace_exists {
+            return Err(Failure::NotAWorkspace.into());
+        }
+        let metis_dir = metis_dir.unwrap();
+
+        // 2. Sync so short codes resolve to current paths
+        let db_path = metis_dir.join("metis.db");
+        let database = Database::new(db_path.to_str().unwrap())
+            .map_err(|e| anyhow::anyhow!("Failed to open database for sync: {}", e))?;
+        let app = Application::new(database);
+        app.sync_directory(&metis_dir)
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to sync workspace: {}", e))?;
+
+        // 3. Work out which documents changed between the two states
+        let from = if self.remote {
+            "@{upstream}".to_string()
+        } else {
+            self.from.clone().unwrap_or_else(|| "HEAD".to_string())
+        };
+
+        let files = if let Some(short_code) = &self.short_code {
+            let db = Database::new(db_path.to_str().unwrap())
+                .map_err(|e| anyhow::anyhow!("Database connection failed: {}", e))?;
+            let filepath = db
+                .into_repository()
+                .resolve_short_code_to_filepath(short_code)
+                .map_err(|e| anyhow::anyhow!("{}", e))?;
+            vec![ChangedFile {
+                old_path: Some(filepath.clone()),
+                new_path: Some(filepath),
+            }]
+        } else {
+            self.changed_files(&metis_dir, &from)?
+        };
+
+        // 4. Print a field diff of the frontmatter and a unified diff of the body
+        let mut differences = 0;
+        for file in files {
+            let old = match &file.old_path {
+                Some(path) => git_show(&metis_dir, &from, path),
+                None => None,
+            };
+            let new = match &file.new_path {
+                Some(path) => match &self.to {
+                    Some(to) => git_show(&metis_dir, to, path),
+                    None => std::fs::read_to_string(metis_dir.join(path)).ok(),
+                },
+                None => None,
+            };
+
+            let old = old.unwrap_or_default();
+            let new = new.unwrap_or_default();
+            if old == new {
+                continue;
+            }
+            differences += 1;
+
+            let label = file
+                .new_path
+                .as_deref()
+                .or(file.old_path.as_deref())
+                .unwrap_or_default();
+            print_document_diff(label, &file, &old, &new);
+        }
+
+        if differences == 0 {
+            println!("No differences.");
+        }
+
+        Ok(())
+    }
+
+    /// Markdown files that differ between `from` and the target state
+    fn changed_files(&self, metis_dir: &Path, from: &str) -> Result<Vec<ChangedFile>> {
+        let mut args = vec![
+            "diff".to_string(),
+            "--name-status".to_string(),
+            "--relative".to_string(),
+            "-M".to_string(),
+            from.to_string(),
+        ];
+        if let Some(to) = &self.to {
+            args.push(to.clone());
+        }
+        args.push("--".to_string());
+        args.push("*.md".to_string());
+
+        let output = run_git(metis_dir, &args)?;
+        let mut files: Vec<ChangedFile> = output
+            .lines()
+            .filter_map(|line| {
+                let parts: Vec<&str> = line.split('\t').collect();
+                let status = parts.first()?.chars().next()?;
+                Some(match (status, parts.as_slice()) {
+                    ('A', [_, path]) => ChangedFile {
+                        old_path: None,
+                        new_path: Some(path.to_string()),
+                    },
+                    ('D', [_, path]) => ChangedFile {
+                        old_path: Some(path.to_string()),
+                        new_path: None,
+                    },
+                    (_, [_, old, new]) => ChangedFile {
+                        old_path: Some(old.to_string()),
+                        new_path: Some(new.to_string()),
+                    },
+                    (_, [_, path]) => ChangedFile {
+                        old_path: Some(path.to_string()),
+                        new_path: Some(path.to_string()),
+                    },
+                    _ => return None,
+                })
+            })
+            .collect();
+
+        // git diff against the working tree doesn't list untracked files
+        if self.to.is_none() {
+            let untracked = run_git(
+                metis_dir,
+                &[
+                    "ls-files".to_string(),
+                    "--others".to_string(),
+                    "--exclude-standard".to_string(),
+                    "--".to_string(),
+                    "*.md".to_string(),
+                ],
+            )?;
+            files.extend(untracked.lines().map(|path| ChangedFile {
+                old_path: None,
+                new_path: Some(path.to_string()),
+            }));
+        }
+
+        Ok(files)
+    }
+}
+
+/// Contents of `path` (relative to .metis) at `revision`, if it existed there
+fn git_show(metis_dir: &Path, revision: &str, path: &str) -> Option<String> {
+    let output = Command::new("git")
+        .arg("show")
+        .arg(format!("{}:./{}", revision, path))
+        .current_dir(metis_dir)
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    Some(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+fn print_document_diff(label: &str, file: &ChangedFile, old: &str, new: &str) {
+    let (old_fields, old_body) = split_frontmatter(old);
+    let (new_fields, new_body) = split_frontmatter(new);
+
+    println!("=== {} ===", label);
+    match (&file.old_path, &file.new_path) {
+        (None, Some(_)) => println!("(new document)"),
+        (Some(_), None) => println!("(deleted)"),
+        (Some(old_path), Some(new_path)) if old_path != new_path => {
+            println!("(moved from {})", old_path)
+        }
+        _ => {}
+    }
+
+    let changes = frontmatter_changes(&old_fields, &new_fields);
+    if !changes.is_empty() {
+        println!("Frontmatter:");
+        for change in &changes {
+            match change {
+                FieldChange::Added(key, value) => println!("  + {}: {}", key, value),
+                FieldChange::Removed(key, value) => println!("  - {}: {}", key, value),
+                FieldChange::Changed(key, before, after) => {
+                    println!("  ~ {}: {} -> {}", key, before, after)
+                }
+            }
+        }
+    }
+
+    let hunks = unified_diff(&old_body, &new_body);
+    if !hunks.is_empty() {
+        println!("Body:");
+        println!("--- a/{}", file.old_path.as_deref().unwrap_or("/dev/null"));
+        println!("+++ b/{}", file.new_path.as_deref().unwrap_or("/dev/null"));
+        for line in hunks {
+            println!("{}", line);
+        }
+    }
+    println!();
+}
+
+/// Split a document into its top-level frontmatter fields, in order, and
+/// its body. Nested values (lists, maps) are kept as one value per field,
+/// items separated by ", ".
+fn split_frontmatter(content: &str) -> (Vec<(String, String)>, String) {
+    let mut lines = content.lines();
+    if lines.next().map(str::trim_end) != Some("---") {
+        return (Vec::new(), content.to_string());
+    }
+
+    let mut fields: Vec<(String, String)> = Vec::new();
+    let mut body = Vec::new();
+    let mut in_frontmatter = true;
+
+    for line in lines {
+        if !in_frontmatter {
+            body.push(line);
+            continue;
+        }
+        if line.trim_end() == "---" {
+            in_frontmatter = false;
+            continue;
+        }
+
+        let top_level = !line.starts_with([' ', '\t', '-']);
+        match line.split_once(':') {
+            Some((key, value)) if top_level => {
+                fields.push((key.trim().to_string(), value.trim().to_string()));
+            }
+            _ => {
+                if let Some((_, value)) = fields.last_mut() {
+                    let item = line.trim().trim_start_matches("- ");
+                    if !value.is_empty() {
+                        value.push_str(", ");
+                    }
+                    value.push_str(item);
+                }
+            }
+        }
+    }
+
+    (fields, body.join("\n"))
+}
+
+/// Field-level differences, in the order fields appear
+fn frontmatter_changes(old: &[(String, String)], new: &[(String, String)]) -> Vec<FieldChange> {
+    let lookup = |fields: &[(String, String)], key: &str| {
+        fields
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.clone())
+    };
+
+    let mut changes = Vec::new();
+    for (key, before) in old {
+        match lookup(new, key) {
+            None => changes.push(FieldChange::Removed(key.clone(), before.clone())),
+            Some(after) if &after != before => {
+                changes.push(FieldChange::Changed(key.clone(), before.clone(), after))
+            }
+            Some(_) => {}
+        }
+    }
+    for (key, after) in new {
+        if lookup(old, key).is_none() {
+            changes.push(FieldChange::Added(key.clone(), after.clone()));
+        }
+    }
+    changes
+}
+
+/// Line diff by longest common subsequence
+fn diff_lines<'a>(old: &[&'a str], new: &[&'a str]) -> Vec<(LineOp, &'a str)> {
+    let (n, m) = (old.len(), new.len());
+    let mut lcs = vec![vec![0u32; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let (mut i, mut j) = (0, 0);
+    let mut ops = Vec::with_capacity(n.max(m));
+    while i < n && j < m {
+        if old[i] == new[j] {
+            ops.push((LineOp::Equal, old[i]));
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            ops.push((LineOp::Delete, old[i]));
+            i += 1;
+        } else {
+            ops.push((LineOp::Insert, new[j]));
+            j += 1;
+        }
+    }
+    ops.extend(old[i..].iter().map(|line| (LineOp::Delete, *line)));
+    ops.extend(new[j..].iter().map(|line| (LineOp::Insert, *line)));
+    ops
+}
+
+/// Unified diff hunks (without file headers); empty when the texts match
+fn unified_diff(old: &str, new: &str) -> Vec<String> {
+    let old_lines: Vec<&str> = old.lines().collect();
+    let new_lines: Vec<&str> = new.lines().collect();
+    let ops = diff_lines(&old_lines, &new_lines);
+    let changes: Vec<usize> = ops
+        .iter()
+        .enumerate()
+        .filter(|(_, (op, _))| *op != LineOp::Equal)
+        .map(|(i, _)| i)
+        .collect();
+
+    let mut output = Vec::new();
+    let mut c = 0;
+    while c < changes.len() {
+        // Merge changes whose context would overlap into one hunk
+        let start = changes[c].saturating_sub(CONTEXT_LINES);
+        let mut last = changes[c];
+        while c + 1 < changes.len() && changes[c + 1] - last <= 2 * CONTEXT_LINES + 1 {
+            c += 1;
+            last = changes[c];
+        }
+        let end = (last + CONTEXT_LINES + 1).min(ops.len());
+
+        let count =
+            |ops: &[(LineOp, &str)], skip: LineOp| ops.iter().filter(|(op, _)| *op != skip).count();
+        let old_start = count(&ops[..start], LineOp::Insert);
+        let new_start = count(&ops[..start], LineOp::Delete);
+        let hunk = &ops[start..end];
+        let old_len = count(hunk, LineOp::Insert);
+        let new_len = count(hunk, LineOp::Delete);
+        // Empty ranges point at the line before, as in diff -u
+        let line_number = |start: usize, len: usize| if len == 0 { start } else { start + 1 };
+
+        output.push(format!(
+            "@@ -{},{} +{},{} @@",
+            line_number(old_start, old_len),
+            old_len,
+            line_number(new_start, new_len),
+            new_len
+        ));
+        for (op, line) in hunk {
+            let prefix = match op {
+                LineOp::Equal => ' ',
+                LineOp::Delete => '-',
+                LineOp::Insert => '+',
+            };
+            output.push(format!("{}{}", prefix, line));
+        }
+        c += 1;
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_split_frontmatter() {
+        let content = "---\ntitle: Auth\nphase: todo\ntags:\n  - \"#task\"\n  - \"#phase/todo\"\n---\n\n# Auth\n";
+        let (fields, body) = split_frontmatter(content);
+        assert_eq!(
+            fields,
+            vec![
+                ("title".to_string(), "Auth".to_string()),
+                ("phase".to_string(), "todo".to_string()),
+                ("tags".to_string(), "\"#task\", \"#phase/todo\"".to_string()),
+            ]
+        );
+        assert_eq!(body, "\n# Auth");
+
+        let (fields, body) = split_frontmatter("no frontmatter");
+        assert!(fields.is_empty());
+        assert_eq!(body, "no frontmatter");
+    }
+
+    #[test]
+    fn test_frontmatter_changes() {
+        let field = |k: &str, v: &str| (k.to_string(), v.to_string());
+        let old = vec![
+            field("title", "Auth"),
+            field("phase", "todo"),
+            field("owner", "ana"),
+        ];
+        let new = vec![
+            field("title", "Auth"),
+            field("phase", "active"),
+            field("due", "2025-01-01"),
+        ];
+
+        assert_eq!(
+            frontmatter_changes(&old, &new),
+            vec![
+                FieldChange::Changed("phase".into(), "todo".into(), "active".into()),
+                FieldChange::Removed("owner".into(), "ana".into()),
+                FieldChange::Added("due".into(), "2025-01-01".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unified_diff() {
+        let old = "a\nb\nc\nd\ne\nf\ng\nh\ni\nj";
+        let new = "a\nb\nc\nd\nE\nf\ng\nh\ni\nj\nk";
+
+        assert_eq!(
+            unified_diff(old, new),
+            vec![
+                "@@ -2,9 +2,10 @@",
+                " b",
+                " c",
+                " d",
+                "-e",
+                "+E",
+                " f",
+                " g",
+                " h",
+                " i",
+                " j",
+                "+k",
+            ]
+        );
+        assert!(unified_diff(old, old).is_empty());
+        assert_eq!(unified_diff("", "new"), vec!["@@ -0,0 +1,1 @@", "+new"]);
+    }
+
+    #[tokio::test]
+    async fn test_diff_command_no_workspace() {
+        let temp_dir = tempdir().unwrap();
+        let original_dir = std::env::current_dir().ok();
+
+        if std::env::set_current_dir(temp_dir.path()).is_err() {
+            return;
+        }
+
+        let cmd = DiffCommand {
+            short_code: None,
+            from: None,
+            to: None,
+            remote: false,
+        };
+        let result = cmd.execute().await;
+
+        if let Some(original) = original_dir {
+            let _ = std::env::set_current_dir(&original);
+        }
+
+        assert!(result.is_err());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Not in a Metis workspace"));
+    }
+}
@@ -0,0 +1,294 @@
+use crate::output::Failure;
+use crate::workspace;
+use anyhow::Result;
+use clap::Args;
+use metis_core::application::services::synchronization::SyncIssue;
+use metis_core::application::services::{DatabaseService, SyncService};
+use metis_core::dal::database::MaintenanceReport;
+use metis_core::{Application, Database};
+use std::path::Path;
+
+#[derive(Args)]
+pub struct DoctorCommand {
+    /// Repair the database: remove orphaned rows, rebuild the search index, VACUUM/ANALYZE
+    #[arg(long)]
+    pub db: bool,
+
+    /// Apply safe fixes: sync the database with the files (re-import changed
+    /// files, drop deleted ones, renumber duplicate short codes)
+    #[arg(long)]
+    pub fix: bool,
+}
+
+impl DoctorCommand {
+    pub async fn execute(&self) -> Result<()> {
+        // Check if we're in a workspace
+        let (workspace_exists, metis_dir) = workspace::has_metis_vault();
+        if !workspace_exists {
+            return Err(Failure::NotAWorkspace.into());
+        }
+        let metis_dir = metis_dir.unwrap();
+
+        let db_path = metis_dir.join("metis.db");
+        let database = Database::new(db_path.to_str().unwrap())
+            .map_err(|e| anyhow::anyhow!("Failed to initialize database: {}", e))?;
+
+        if let Ok(Some(version)) = database.schema_version() {
+            println!("Schema version: {}", version);
+        }
+
+        let report = if self.db {
+            println!("Running database maintenance: {}", db_path.display());
+            database
+                .maintain()
+                .map_err(|e| anyhow::anyhow!("Database maintenance failed: {}", e))?
+        } else {
+            println!("Checking database: {}", db_path.display());
+            database
+                .diagnose()
+                .map_err(|e| anyhow::anyhow!("Database check failed: {}", e))?
+        };
+
+        self.print_report(&report);
+
+        if !report.is_healthy() {
+            anyhow::bail!(
+                "Database integrity check failed with {} problem(s). Delete metis.db and run 'metis sync' to rebuild it.",
+                report.integrity_errors.len()
+            );
+        }
+
+        // Check the documents themselves against the database
+        println!("\nChecking documents: {}", metis_dir.display());
+        let mut issues = verify_workspace(&database, &metis_dir)?;
+
+        if self.fix && issues.iter().any(SyncIssue::fixed_by_sync) {
+            let fixable = issues.iter().filter(|i| i.fixed_by_sync()).count();
+            println!("Fixing {} problem(s) by syncing the workspace...", fixable);
+            let app = Application::new(
+                Database::new(db_path.to_str().unwrap())
+                    .map_err(|e| anyhow::anyhow!("Failed to open database for sync: {}", e))?,
+            );
+            app.sync_directory(&metis_dir)
+                .await
+                .map_err(|e| anyhow::anyhow!("Failed to sync workspace: {}", e))?;
+            issues = verify_workspace(&database, &metis_dir)?;
+        }
+
+        self.print_issues(&issues);
+
+        let needs_attention = issues.iter().filter(|i| !i.fixed_by_sync()).count();
+        if needs_attention > 0 {
+            return Err(Failure::Validation(format!(
+                "{} document problem(s) need to be fixed by hand",
+                needs_attention
+            ))
+            .into());
+        }
+
+        Ok(())
+    }
+
+    fn print_issues(&self, issues: &[SyncIssue]) {
+        if issues.is_empty() {
+            println!("[+] All documents are valid and in sync");
+            return;
+        }
+
+        for issue in issues {
+            match issue {
+                SyncIssue::InvalidDocument { filepath, error } => {
+                    println!("[-] Invalid document {}: {}", filepath, error);
+                }
+                SyncIssue::BrokenReference {
+                    filepath,
+                    short_code,
+                    kind,
+                } => {
+                    println!(
+                        "[-] {} has a {} link to {}, which doesn't exist",
+                        filepath, kind, short_code
+                    );
+                }
+                SyncIssue::DuplicateShortCode {
+                    short_code,
+                    filepaths,
+                } => {
+                    println!(
+                        "[!] Short code {} is used by {}",
+                        short_code,
+                        filepaths.join(", ")
+                    );
+                }
+                SyncIssue::MissingFromDatabase { filepath } => {
+                    println!("[!] Not in the database: {}", filepath);
+                }
+                SyncIssue::MissingFromFilesystem { filepath } => {
+                    println!("[!] In the database but the file is gone: {}", filepath);
+                }
+                SyncIssue::OutOfSync { filepath, reason } => {
+                    println!("[!] Out of sync: {} ({})", filepath, reason);
+                }
+            }
+        }
+
+        if !self.fix && issues.iter().any(SyncIssue::fixed_by_sync) {
+            println!("\nRun 'metis doctor --fix' to apply safe fixes.");
+        }
+        if issues.iter().any(|i| !i.fixed_by_sync()) {
+            println!(
+                "Fix invalid frontmatter and broken links in the files, then run 'metis sync'."
+            );
+        }
+    }
+
+    fn print_report(&self, report: &MaintenanceReport) {
+        if report.is_healthy() {
+            println!("[+] Integrity check passed");
+        } else {
+            for error in &report.integrity_errors {
+                println!("[-] Integrity: {}", error);
+            }
+        }
+
+        let verb = if self.db { "Removed" } else { "Found" };
+        for (table, count) in &report.orphaned_rows {
+            if *count > 0 {
+                println!("[!] {} {} orphaned row(s) in {}", verb, count, table);
+            }
+        }
+        if report.total_orphans() == 0 {
+            println!("[+] No orphaned rows");
+        }
+
+        if let Some(reindexed) = report.search_entries_reindexed {
+            println!("[+] Rebuilt search index ({} documents)", reindexed);
+        }
+        if report.vacuumed {
+            println!("[+] Ran VACUUM and ANALYZE");
+        }
+
+        if !self.db && report.total_orphans() > 0 {
+            println!("\nRun 'metis doctor --db' to repair.");
+        }
+    }
+}
+
+/// Run the document checks: frontmatter validity, database/file
+/// consistency, duplicate short codes and broken links
+fn verify_workspace(database: &Database, metis_dir: &Path) -> Result<Vec<SyncIssue>> {
+    let repository = database
+        .repository()
+        .map_err(|e| anyhow::anyhow!("Database connection failed: {}", e))?;
+    let mut db_service = DatabaseService::new(repository);
+    SyncService::new(&mut db_service)
+        .with_workspace_dir(metis_dir)
+        .verify_sync(metis_dir)
+        .map_err(|e| anyhow::anyhow!("Document check failed: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::InitCommand;
+    use tempfile::tempdir;
+
+    #[tokio::test]
+    async fn test_doctor_command_no_workspace() {
+        let temp_dir = tempdir().unwrap();
+        let original_dir = std::env::current_dir().ok();
+
+        std::env::set_current_dir(temp_dir.path()).unwrap();
+
+        let cmd = DoctorCommand {
+            db: false,
+            fix: false,
+        };
+        let result = cmd.execute().await;
+
+        assert!(result.is_err());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Not in a Metis workspace"));
+
+        if let Some(original) = original_dir {
+            let _ = std::env::set_current_dir(&original);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_doctor_command_db_maintenance() {
+        let temp_dir = tempdir().unwrap();
+        let original_dir = std::env::current_dir().ok();
+
+        std::env::set_current_dir(temp_dir.path()).unwrap();
+
+        let init_cmd = InitCommand {
+            name: Some("Test Project".to_string()),
+            preset: None,
+            initiatives: None,
+            prefix: None,
+            remote: None,
+        };
+        init_cmd.execute().await.unwrap();
+
+        let check = DoctorCommand {
+            db: false,
+            fix: false,
+        }
+        .execute()
+        .await;
+        let repair = DoctorCommand {
+            db: true,
+            fix: true,
+        }
+        .execute()
+        .await;
+
+        if let Some(original) = original_dir {
+            let _ = std::env::set_current_dir(&original);
+        }
+
+        assert!(check.is_ok(), "doctor failed: {:?}", check);
+        assert!(repair.is_ok(), "doctor --db failed: {:?}", repair);
+    }
+
+    #[tokio::test]
+    async fn test_doctor_command_reports_invalid_documents() {
+        let temp_dir = tempdir().unwrap();
+        let original_dir = std::env::current_dir().ok();
+
+        std::env::set_current_dir(temp_dir.path()).unwrap();
+
+        let init_cmd = InitCommand {
+            name: Some("Test Project".to_string()),
+            preset: None,
+            initiatives: None,
+            prefix: None,
+            remote: None,
+        };
+        init_cmd.execute().await.unwrap();
+        std::fs::write(
+            temp_dir.path().join(".metis").join("notes.md"),
+            "just some notes, no frontmatter",
+        )
+        .unwrap();
+
+        let result = DoctorCommand {
+            db: false,
+            fix: true,
+        }
+        .execute()
+        .await;
+
+        if let Some(original) = original_dir {
+            let _ = std::env::set_current_dir(&original);
+        }
+
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("need to be fixed by hand"));
+    }
+}
@@ -0,0 +1,307 @@
+use crate::commands::status::DONE_PHASES;
+use crate::output::{say, Failure};
+use crate::workspace;
+use anyhow::Result;
+use chrono::NaiveDate;
+use clap::Args;
+use metis_core::application::services::workspace::{PropertyChange, PropertyService};
+use metis_core::application::services::DatabaseService;
+use metis_core::dal::database::{parse_duration, DocumentFilter, DocumentSort, PropertyFilter};
+use metis_core::Database;
+
+/// Frontmatter property holding the due date
+const DUE_DATE: &str = "due_date";
+
+#[derive(Args)]
+pub struct DueCommand {
+    /// Document short code (e.g., PROJ-T-0001)
+    #[arg(conflicts_with = "list")]
+    pub short_code: Option<String>,
+
+    /// Due date (YYYY-MM-DD); omit to show the current one
+    #[arg(conflicts_with = "clear")]
+    pub date: Option<String>,
+
+    /// Remove the due date
+    #[arg(long)]
+    pub clear: bool,
+
+    /// List unfinished documents by due date
+    #[arg(long)]
+    pub list: bool,
+
+    /// Only list documents due within this window, e.g. 7d or 2w (includes overdue ones)
+    #[arg(long, requires = "list")]
+    pub within: Option<String>,
+}
+
+/// An unfinished document with a due date
+struct DueItem {
+    short_code: String,
+    title: String,
+    phase: String,
+    due_date: NaiveDate,
+}
+
+impl DueCommand {
+    pub async fn execute(&self) -> Result<()> {
+        // 1. Validate we're in a metis workspace
+        let (workspace_exists, metis_dir) = workspace::has_metis_vault();
+        if !workspace_exists {
+            return Err(Failure::NotAWorkspace.into());
+        }
+        let metis_dir = metis_dir.unwrap();
+
+        // 2. Validate arguments before touching anything
+        let date = self.date.as_deref().map(parse_date).transpose()?;
+        let today = chrono::Local::now().date_naive();
+        let until = match &self.within {
+            Some(within) => Some(today + parse_duration(within).map_err(|e| anyhow::anyhow!(e))?),
+            None => None,
+        };
+
+        // 3. Sync so the current due dates are known
+        let db = Database::new(&metis_dir.join("metis.db").to_string_lossy())
+            .map_err(|e| anyhow::anyhow!("Database initialization failed: {}", e))?;
+        let mut db_service = DatabaseService::new(db.into_repository());
+        workspace::sync(&metis_dir, &mut db_service).await?;
+
+        if self.list {
+            let items = due_items(&mut db_service, until)?;
+            if items.is_empty() {
+                match &self.within {
+                    Some(within) => say!("Nothing due within {}.", within),
+                    None => say!("No unfinished documents have a due date."),
+                }
+                return Ok(());
+            }
+            for item in &items {
+                println!("{}", item_line(item, today));
+            }
+            return Ok(());
+        }
+
+        let short_code = self
+            .short_code
+            .as_deref()
+            .ok_or_else(|| anyhow::anyhow!("Specify a short code or --list"))?;
+
+        // 4. Show the current due date when neither a date nor --clear is given
+        if date.is_none() && !self.clear {
+            let doc = db_service
+                .find_by_short_code(short_code)
+                .map_err(|e| anyhow::anyhow!("Database query failed: {}", e))?
+                .ok_or_else(|| anyhow::anyhow!("Document '{}' not found", short_code))?;
+            match due_date(&mut db_service, &doc.filepath)? {
+                Some(due) => println!("{} is due {} ({})", short_code, due, relative(due, today)),
+                None => println!("{} has no due date", short_code),
+            }
+            return Ok(());
+        }
+
+        // 5. Update the due date and sync the file back in
+        let value = date.map(|date| date.format("%Y-%m-%d").to_string());
+        let change = PropertyService::new(&metis_dir)
+            .set_property(short_code, DUE_DATE, value.as_deref(), &mut db_service)
+            .map_err(|e| anyhow::anyhow!("{}", e))?;
+        workspace::sync(&metis_dir, &mut db_service).await?;
+
+        say!("{}", change_line(&change));
+        Ok(())
+    }
+}
+
+fn parse_date(value: &str) -> Result<NaiveDate> {
+    NaiveDate::parse_from_str(value.trim(), "%Y-%m-%d")
+        .map_err(|_| anyhow::anyhow!("Invalid date '{}'; expected YYYY-MM-DD", value))
+}
+
+fn due_date(db_service: &mut DatabaseService, filepath: &str) -> Result<Option<NaiveDate>> {
+    let properties = db_service
+        .find_properties(filepath)
+        .map_err(|e| anyhow::anyhow!("Database query failed: {}", e))?;
+    Ok(properties
+        .into_iter()
+        .find(|property| property.key == DUE_DATE)
+        .and_then(|property| {
+            let value = property.value.trim();
+            NaiveDate::parse_from_str(value.get(..10).unwrap_or(value), "%Y-%m-%d").ok()
+        }))
+}
+
+/// Unfinished documents due on or before `until` (all dated ones when None),
+/// earliest first
+fn due_items(db_service: &mut DatabaseService, until: Option<NaiveDate>) -> Result<Vec<DueItem>> {
+    let filter = DocumentFilter::new()
+        .property(PropertyFilter::DateRange {
+            key: DUE_DATE.to_string(),
+            from: None,
+            to: until,
+        })
+        .sort_by(DocumentSort::DueDate, false);
+    let docs = db_service
+        .find_by_filter(&filter)
+        .map_err(|e| anyhow::anyhow!("Database query failed: {}", e))?;
+
+    let mut items = Vec::new();
+    for doc in docs {
+        if DONE_PHASES.contains(&doc.phase.as_str()) {
+            continue;
+        }
+        if let Some(due_date) = due_date(db_service, &doc.filepath)? {
+            items.push(DueItem {
+                short_code: doc.short_code,
+                title: doc.title,
+                phase: doc.phase,
+                due_date,
+            });
+        }
+    }
+    Ok(items)
+}
+
+fn relative(due: NaiveDate, today: NaiveDate) -> String {
+    match (due - today).num_days() {
+        0 => "today".to_string(),
+        1 => "tomorrow".to_string(),
+        -1 => "overdue by 1 day".to_string(),
+        days if days < 0 => format!("overdue by {} days", -days),
+        days => format!("in {} days", days),
+    }
+}
+
+fn item_line(item: &DueItem, today: NaiveDate) -> String {
+    format!(
+        "{}  {:<16} {} [{}] {}",
+        item.due_date,
+        relative(item.due_date, today),
+        item.short_code,
+        item.phase,
+        item.title
+    )
+}
+
+fn change_line(change: &PropertyChange) -> String {
+    match (&change.previous, &change.value, change.changed()) {
+        (_, Some(date), false) => format!("- {} is already due {}", change.short_code, date),
+        (_, None, false) => format!("- {} has no due date", change.short_code),
+        (Some(previous), Some(date), true) => {
+            format!(
+                "✓ {} is now due {} (was {})",
+                change.short_code, date, previous
+            )
+        }
+        (None, Some(date), true) => format!("✓ {} is now due {}", change.short_code, date),
+        (Some(previous), None, true) => {
+            format!(
+                "✓ {} no longer has a due date (was {})",
+                change.short_code, previous
+            )
+        }
+        (None, None, true) => unreachable!("no change"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::InitCommand;
+    use tempfile::tempdir;
+
+    fn command(short_code: Option<&str>, date: Option<&str>) -> DueCommand {
+        DueCommand {
+            short_code: short_code.map(str::to_string),
+            date: date.map(str::to_string),
+            clear: false,
+            list: false,
+            within: None,
+        }
+    }
+
+    #[test]
+    fn test_relative() {
+        let today = NaiveDate::from_ymd_opt(2025, 3, 1).unwrap();
+        assert_eq!(relative(today, today), "today");
+        assert_eq!(
+            relative(NaiveDate::from_ymd_opt(2025, 3, 4).unwrap(), today),
+            "in 3 days"
+        );
+        assert_eq!(
+            relative(NaiveDate::from_ymd_opt(2025, 2, 27).unwrap(), today),
+            "overdue by 2 days"
+        );
+        assert!(parse_date("2025-03-01").is_ok());
+        assert!(parse_date("03/01/2025").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_due_command_no_workspace() {
+        let temp_dir = tempdir().unwrap();
+        let original_dir = std::env::current_dir().ok();
+
+        if std::env::set_current_dir(temp_dir.path()).is_err() {
+            return;
+        }
+
+        let result = command(Some("TEST-T-0001"), Some("2025-03-01"))
+            .execute()
+            .await;
+
+        if let Some(original) = original_dir {
+            let _ = std::env::set_current_dir(&original);
+        }
+
+        assert!(result.is_err());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Not in a Metis workspace"));
+    }
+
+    #[tokio::test]
+    async fn test_due_set_and_list() {
+        let temp_dir = tempdir().unwrap();
+        let original_dir = std::env::current_dir().ok();
+
+        if std::env::set_current_dir(temp_dir.path()).is_err() {
+            return;
+        }
+
+        let init_cmd = InitCommand {
+            name: Some("Test Project".to_string()),
+            preset: None,
+            initiatives: None,
+            prefix: None,
+            remote: None,
+        };
+        init_cmd.execute().await.unwrap();
+
+        let invalid = command(Some("TEST-V-0001"), Some("next week"))
+            .execute()
+            .await;
+        let set = command(Some("TEST-V-0001"), Some("2025-03-01"))
+            .execute()
+            .await;
+        let mut list = command(None, None);
+        list.list = true;
+        list.within = Some("7d".to_string());
+        let listed = list.execute().await;
+
+        let metis_dir = temp_dir.path().join(".metis");
+        let db = Database::new(&metis_dir.join("metis.db").to_string_lossy()).unwrap();
+        let mut db_service = DatabaseService::new(db.into_repository());
+        let items = due_items(&mut db_service, None).unwrap();
+
+        if let Some(original) = original_dir {
+            let _ = std::env::set_current_dir(&original);
+        }
+
+        assert!(invalid.is_err());
+        assert!(set.is_ok());
+        assert!(listed.is_ok());
+        let content = std::fs::read_to_string(metis_dir.join("vision.md")).unwrap();
+        assert!(content.contains("due_date: \"2025-03-01\""));
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].short_code, "TEST-V-0001");
+    }
+}
@@ -0,0 +1,141 @@
+use crate::output::{say, Failure};
+use crate::workspace;
+use anyhow::Result;
+use clap::{Args, Subcommand};
+use metis_core::application::services::workspace::SiteExportService;
+use metis_core::application::services::DatabaseService;
+use metis_core::{Application, Database};
+use std::path::{Path, PathBuf};
+
+#[derive(Args)]
+pub struct ExportCommand {
+    #[command(subcommand)]
+    pub target: ExportTarget,
+}
+
+#[derive(Subcommand)]
+pub enum ExportTarget {
+    /// Render the workspace as a static, cross-linked HTML site
+    Site {
+        /// Directory to write the site to
+        #[arg(short, long, default_value = "docs-site")]
+        out: PathBuf,
+    },
+}
+
+impl ExportCommand {
+    pub async fn execute(&self) -> Result<()> {
+        // 1. Validate we're in a metis workspace
+        let (workspace_exists, metis_dir) = workspace::has_metis_vault();
+        if !workspace_exists {
+            return Err(Failure::NotAWorkspace.into());
+        }
+        let metis_dir = metis_dir.unwrap();
+
+        // 2. Sync so the export reflects the files on disk
+        let db_path = metis_dir.join("metis.db");
+        let database = Database::new(db_path.to_str().unwrap())
+            .map_err(|e| anyhow::anyhow!("Failed to open database for sync: {}", e))?;
+        let app = Application::new(database);
+        app.sync_directory(&metis_dir)
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to sync workspace: {}", e))?;
+
+        let db = Database::new(db_path.to_str().unwrap())
+            .map_err(|e| anyhow::anyhow!("Database connection failed: {}", e))?;
+        let mut db_service = DatabaseService::new(db.into_repository());
+
+        // 3. Export the requested target
+        match &self.target {
+            ExportTarget::Site { out } => self.export_site(&metis_dir, out, &mut db_service),
+        }
+    }
+
+    fn export_site(
+        &self,
+        metis_dir: &Path,
+        out: &Path,
+        db_service: &mut DatabaseService,
+    ) -> Result<()> {
+        let report = SiteExportService::new(metis_dir)
+            .export(out, db_service)
+            .map_err(|e| anyhow::anyhow!("Failed to export site: {}", e))?;
+
+        say!(
+            "✓ Exported {} documents and {} index pages to {}",
+            report.documents,
+            report.indexes,
+            report.out_dir.display()
+        );
+        say!("  Open {}", report.out_dir.join("index.html").display());
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::InitCommand;
+    use tempfile::tempdir;
+
+    #[tokio::test]
+    async fn test_export_command_no_workspace() {
+        let temp_dir = tempdir().unwrap();
+        let original_dir = std::env::current_dir().ok();
+
+        if std::env::set_current_dir(temp_dir.path()).is_err() {
+            return;
+        }
+
+        let cmd = ExportCommand {
+            target: ExportTarget::Site {
+                out: PathBuf::from("docs-site"),
+            },
+        };
+        let result = cmd.execute().await;
+
+        if let Some(original) = original_dir {
+            let _ = std::env::set_current_dir(&original);
+        }
+
+        assert!(result.is_err());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Not in a Metis workspace"));
+    }
+
+    #[tokio::test]
+    async fn test_export_site() {
+        let temp_dir = tempdir().unwrap();
+        let original_dir = std::env::current_dir().ok();
+
+        if std::env::set_current_dir(temp_dir.path()).is_err() {
+            return;
+        }
+
+        let init_cmd = InitCommand {
+            name: Some("Test Project".to_string()),
+            preset: None,
+            initiatives: None,
+            prefix: None,
+            remote: None,
+        };
+        init_cmd.execute().await.unwrap();
+
+        let out = temp_dir.path().join("docs-site");
+        let cmd = ExportCommand {
+            target: ExportTarget::Site { out: out.clone() },
+        };
+        let result = cmd.execute().await;
+
+        if let Some(original) = original_dir {
+            let _ = std::env::set_current_dir(&original);
+        }
+
+        assert!(result.is_ok());
+        assert!(out.join("index.html").exists());
+        assert!(out.join("docs").join("TEST-V-0001.html").exists());
+    }
+}
@@ -0,0 +1,181 @@
+use crate::output::{say, Failure};
+use crate::workspace;
+use anyhow::Result;
+use clap::{Args, ValueEnum};
+use metis_core::dal::database::models::DocumentGraph;
+use metis_core::{Application, Database};
+use std::path::PathBuf;
+
+#[derive(Args)]
+pub struct GraphCommand {
+    /// Diagram format
+    #[arg(short = 'f', long, value_enum, default_value = "mermaid")]
+    pub format: GraphFormat,
+
+    /// Also draw blocked-by relationships, as dashed edges
+    #[arg(long)]
+    pub blocked_by: bool,
+
+    /// Only draw this document and everything below it (e.g., PROJ-I-0001)
+    #[arg(long, value_name = "SHORT_CODE")]
+    pub root: Option<String>,
+
+    /// Include archived documents
+    #[arg(long)]
+    pub archived: bool,
+
+    /// Write the diagram to a file instead of stdout
+    #[arg(short, long)]
+    pub output: Option<PathBuf>,
+}
+
+/// Output format for `metis graph`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum GraphFormat {
+    /// Mermaid flowchart, for Markdown docs that render Mermaid
+    Mermaid,
+    /// Graphviz DOT, for `dot -Tsvg`
+    Dot,
+}
+
+impl GraphCommand {
+    pub async fn execute(&self) -> Result<()> {
+        // 1. Validate we're in a metis workspace
+        let (workspace_exists, metis_dir) = workspace::has_metis_vault();
+        if !workspace_exists {
+            return Err(Failure::NotAWorkspace.into());
+        }
+        let metis_dir = metis_dir.unwrap();
+
+        // 2. Sync so the diagram reflects the files on disk
+        let db_path = metis_dir.join("metis.db");
+        let database = Database::new(db_path.to_str().unwrap())
+            .map_err(|e| anyhow::anyhow!("Failed to open database for sync: {}", e))?;
+        let mut app = Application::new(database);
+        app.sync_directory(&metis_dir)
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to sync workspace: {}", e))?;
+
+        // 3. Build the diagram
+        let graph = app
+            .with_query(|query| query.graph(self.archived))
+            .map_err(|e| anyhow::anyhow!("Failed to load the document graph: {}", e))?;
+        let diagram = self.render(graph)?;
+
+        // 4. Write it out
+        match &self.output {
+            Some(path) => {
+                std::fs::write(path, &diagram)
+                    .map_err(|e| anyhow::anyhow!("Failed to write {}: {}", path.display(), e))?;
+                say!("✓ Wrote {}", path.display());
+            }
+            None => print!("{}", diagram),
+        }
+
+        Ok(())
+    }
+
+    fn render(&self, graph: DocumentGraph) -> Result<String> {
+        let mut graph = graph.hierarchy(self.blocked_by);
+        if let Some(root) = &self.root {
+            graph = graph.subtree(root).ok_or_else(|| {
+                anyhow::anyhow!(
+                    "'{}' is not a vision, initiative or task in this workspace",
+                    root
+                )
+            })?;
+        }
+
+        Ok(match self.format {
+            GraphFormat::Mermaid => graph.to_mermaid(),
+            GraphFormat::Dot => graph.to_dot(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::InitCommand;
+    use tempfile::tempdir;
+
+    fn graph(format: GraphFormat, root: Option<&str>, output: Option<PathBuf>) -> GraphCommand {
+        GraphCommand {
+            format,
+            blocked_by: true,
+            root: root.map(str::to_string),
+            archived: false,
+            output,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_graph_command_no_workspace() {
+        let temp_dir = tempdir().unwrap();
+        let original_dir = std::env::current_dir().ok();
+
+        if std::env::set_current_dir(temp_dir.path()).is_err() {
+            return;
+        }
+
+        let result = graph(GraphFormat::Mermaid, None, None).execute().await;
+
+        if let Some(original) = original_dir {
+            let _ = std::env::set_current_dir(&original);
+        }
+
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Not in a Metis workspace"));
+    }
+
+    #[tokio::test]
+    async fn test_graph_command_writes_diagram() {
+        let temp_dir = tempdir().unwrap();
+        let original_dir = std::env::current_dir().ok();
+
+        if std::env::set_current_dir(temp_dir.path()).is_err() {
+            return;
+        }
+
+        let init_cmd = InitCommand {
+            name: Some("Test Project".to_string()),
+            preset: None,
+            initiatives: None,
+            prefix: None,
+            remote: None,
+        };
+        init_cmd.execute().await.unwrap();
+
+        let mermaid_path = temp_dir.path().join("graph.mmd");
+        let dot_path = temp_dir.path().join("graph.dot");
+        let mermaid = graph(
+            GraphFormat::Mermaid,
+            Some("TEST-V-0001"),
+            Some(mermaid_path.clone()),
+        )
+        .execute()
+        .await;
+        let dot = graph(GraphFormat::Dot, None, Some(dot_path.clone()))
+            .execute()
+            .await;
+        let missing = graph(GraphFormat::Mermaid, Some("TEST-I-9999"), None)
+            .execute()
+            .await;
+
+        if let Some(original) = original_dir {
+            let _ = std::env::set_current_dir(&original);
+        }
+
+        assert!(mermaid.is_ok());
+        let content = std::fs::read_to_string(&mermaid_path).unwrap();
+        assert!(content.starts_with("flowchart TD"));
+        assert!(content.contains("TEST_V_0001[\"TEST-V-0001<br/>"));
+        assert!(dot.is_ok());
+        assert!(std::fs::read_to_string(&dot_path)
+            .unwrap()
+            .starts_with("digraph metis {"));
+        assert!(missing.unwrap_err().to_string().contains("TEST-I-9999"));
+    }
+}
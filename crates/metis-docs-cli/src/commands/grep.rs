@@ -0,0 +1,195 @@
+use crate::output::Failure;
+use crate::workspace;
+use anyhow::Result;
+use clap::Args;
+use metis_core::application::services::{GrepLine, GrepOptions, GrepResult};
+use metis_core::{Application, Database};
+
+#[derive(Args)]
+pub struct GrepCommand {
+    /// Regular expression to search document bodies for
+    pub pattern: String,
+
+    /// Match case-insensitively
+    #[arg(short = 'i', long)]
+    pub ignore_case: bool,
+
+    /// Lines of context to show around each match
+    #[arg(short = 'C', long, default_value = "0")]
+    pub context: usize,
+
+    /// Include archived documents
+    #[arg(long)]
+    pub archived: bool,
+}
+
+impl GrepCommand {
+    pub async fn execute(&self) -> Result<()> {
+        // 1. Validate we're in a metis workspace
+        let (workspace_exists, metis_dir) = workspace::has_metis_vault();
+        if !workspace_exists {
+            return Err(Failure::NotAWorkspace.into());
+        }
+        let metis_dir = metis_dir.unwrap();
+
+        // 2. Sync before searching to catch external edits
+        let db_path = metis_dir.join("metis.db");
+        let database = Database::new(db_path.to_str().unwrap())
+            .map_err(|e| anyhow::anyhow!("Failed to open database for sync: {}", e))?;
+        let mut app = Application::new(database);
+        app.sync_directory(&metis_dir)
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to sync workspace: {}", e))?;
+
+        // 3. Search the stored document bodies
+        let options = GrepOptions {
+            ignore_case: self.ignore_case,
+            context: self.context,
+            include_archived: self.archived,
+        };
+        let results = app
+            .with_query(|query| query.grep(&self.pattern, &options))
+            .map_err(|e| anyhow::anyhow!("{}", e))?;
+
+        if results.is_empty() {
+            println!("No matches found for /{}/", self.pattern);
+            return Ok(());
+        }
+
+        // 4. Print each document's hits under its short code
+        for (index, result) in results.iter().enumerate() {
+            if index > 0 {
+                println!();
+            }
+            for line in self.result_lines(result) {
+                println!("{}", line);
+            }
+        }
+        let matches: usize = results.iter().map(GrepResult::match_count).sum();
+        println!(
+            "\n{} matching line(s) in {} document(s)",
+            matches,
+            results.len()
+        );
+
+        Ok(())
+    }
+
+    /// A document header, then each hunk under its heading; hunks are
+    /// separated by `--` when showing context, as in ripgrep
+    fn result_lines(&self, result: &GrepResult) -> Vec<String> {
+        let document = &result.document;
+        let mut lines = vec![format!(
+            "{} {} ({})",
+            console::style(&document.short_code).bold(),
+            document.title,
+            document.filepath
+        )];
+        let mut heading = None;
+        for (index, hunk) in result.hunks.iter().enumerate() {
+            if index > 0 && self.context > 0 {
+                lines.push("  --".to_string());
+            }
+            if hunk.heading.is_some() && hunk.heading != heading {
+                heading = hunk.heading.clone();
+                lines.push(format!(
+                    "  {}",
+                    console::style(hunk.heading.as_deref().unwrap_or_default()).cyan()
+                ));
+            }
+            lines.extend(hunk.lines.iter().map(line_text));
+        }
+        lines
+    }
+}
+
+/// `12:` for matching lines and `12-` for context, with matches highlighted
+fn line_text(line: &GrepLine) -> String {
+    let mut text = String::new();
+    let mut last = 0;
+    for &(start, end) in &line.ranges {
+        text.push_str(&line.text[last..start]);
+        text.push_str(
+            &console::style(&line.text[start..end])
+                .red()
+                .bold()
+                .to_string(),
+        );
+        last = end;
+    }
+    text.push_str(&line.text[last..]);
+
+    let separator = if line.is_match { ':' } else { '-' };
+    format!("  {}{} {}", line.number, separator, text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::InitCommand;
+    use tempfile::tempdir;
+
+    fn grep(pattern: &str) -> GrepCommand {
+        GrepCommand {
+            pattern: pattern.to_string(),
+            ignore_case: true,
+            context: 1,
+            archived: false,
+        }
+    }
+
+    #[test]
+    fn test_line_text() {
+        console::set_colors_enabled(false);
+        let line = GrepLine {
+            number: 9,
+            text: "The cache may serve stale data.".to_string(),
+            is_match: true,
+            ranges: vec![(4, 9)],
+        };
+        assert_eq!(line_text(&line), "  9: The cache may serve stale data.");
+
+        let context = GrepLine {
+            number: 8,
+            text: String::new(),
+            is_match: false,
+            ranges: vec![],
+        };
+        assert_eq!(line_text(&context), "  8- ");
+    }
+
+    #[tokio::test]
+    async fn test_grep_command() {
+        let temp_dir = tempdir().unwrap();
+        let original_dir = std::env::current_dir().ok();
+
+        if std::env::set_current_dir(temp_dir.path()).is_err() {
+            return;
+        }
+
+        let no_workspace = grep("vision").execute().await;
+
+        let init_cmd = InitCommand {
+            name: Some("Test Project".to_string()),
+            preset: None,
+            initiatives: None,
+            prefix: None,
+            remote: None,
+        };
+        init_cmd.execute().await.unwrap();
+
+        let found = grep("purpose").execute().await;
+        let invalid = grep("(unclosed").execute().await;
+
+        if let Some(original) = original_dir {
+            let _ = std::env::set_current_dir(&original);
+        }
+
+        assert!(no_workspace
+            .unwrap_err()
+            .to_string()
+            .contains("Not in a Metis workspace"));
+        assert!(found.is_ok());
+        assert!(invalid.unwrap_err().to_string().contains("Invalid pattern"));
+    }
+}
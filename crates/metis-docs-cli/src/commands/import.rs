@@ -0,0 +1,335 @@
+use crate::output::{say, Failure};
+use crate::workspace;
+use anyhow::Result;
+use clap::{Args, Subcommand};
+use metis_core::application::services::workspace::import::{parse_field_map, records_from_csv};
+use metis_core::application::services::workspace::{
+    ImportDestination, ImportPlan, ImportRecord, ImportService, ImportedDocument,
+};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+#[derive(Args)]
+pub struct ImportCommand {
+    #[command(subcommand)]
+    pub source: ImportSource,
+}
+
+#[derive(Subcommand)]
+pub enum ImportSource {
+    /// Import GitHub issues as tasks (requires the `gh` CLI)
+    Github {
+        /// Repository to import from (e.g., org/name)
+        #[arg(long)]
+        repo: String,
+        /// Only issues with this label; repeat for several
+        #[arg(long)]
+        label: Vec<String>,
+        /// Issue state (open, closed, all)
+        #[arg(long, default_value = "open", value_parser = ["open", "closed", "all"])]
+        state: String,
+        /// Maximum number of issues to fetch
+        #[arg(long, default_value = "100")]
+        limit: usize,
+        #[command(flatten)]
+        options: ImportOptions,
+    },
+    /// Import the rows of a CSV file as tasks
+    Csv {
+        /// CSV file with a header row
+        file: PathBuf,
+        /// Map fields to columns (e.g., title=Summary,body=Description,labels=Tags,source=Link)
+        #[arg(long)]
+        map: Option<String>,
+        #[command(flatten)]
+        options: ImportOptions,
+    },
+}
+
+#[derive(Args)]
+pub struct ImportOptions {
+    /// Where to create the tasks: `backlog`, or an initiative short code
+    #[arg(long = "as", value_name = "DESTINATION")]
+    pub destination: String,
+
+    /// Show what would be created without creating anything
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
+impl ImportCommand {
+    pub async fn execute(&self) -> Result<()> {
+        // 1. Validate we're in a metis workspace
+        let (workspace_exists, metis_dir) = workspace::has_metis_vault();
+        if !workspace_exists {
+            return Err(Failure::NotAWorkspace.into());
+        }
+        let metis_dir = metis_dir.unwrap();
+
+        // 2. Read the records from the source
+        let (records, options) = match &self.source {
+            ImportSource::Github {
+                repo,
+                label,
+                state,
+                limit,
+                options,
+            } => (fetch_github_issues(repo, label, state, *limit)?, options),
+            ImportSource::Csv { file, map, options } => {
+                let field_map = match map {
+                    Some(spec) => parse_field_map(spec).map_err(|e| anyhow::anyhow!(e))?,
+                    None => HashMap::new(),
+                };
+                let text = std::fs::read_to_string(file)
+                    .map_err(|e| anyhow::anyhow!("Failed to read {}: {}", file.display(), e))?;
+                let records = records_from_csv(&text, &field_map)
+                    .map_err(|e| anyhow::anyhow!("{}: {}", file.display(), e))?;
+                (records, options)
+            }
+        };
+        let destination = if options.destination.eq_ignore_ascii_case("backlog") {
+            ImportDestination::Backlog
+        } else {
+            ImportDestination::Initiative(options.destination.clone())
+        };
+
+        // 3. Sync, then skip anything that already exists
+        let mut db_service = workspace::open_synced(&metis_dir).await?;
+        let service = ImportService::new(&metis_dir);
+        let plan = service
+            .plan(records, &destination, &mut db_service)
+            .map_err(|e| anyhow::anyhow!("{}", e))?;
+
+        if options.dry_run {
+            print_plan(&plan);
+            return Ok(());
+        }
+
+        // 4. Create the documents and sync them into the database
+        let imported = service
+            .apply(&plan, &destination)
+            .await
+            .map_err(|e| anyhow::anyhow!("Import failed: {}", e))?;
+        workspace::open_synced(&metis_dir).await?;
+        print_report(&metis_dir, &imported, &plan);
+
+        Ok(())
+    }
+}
+
+#[derive(Deserialize)]
+struct GithubIssue {
+    title: String,
+    #[serde(default)]
+    body: Option<String>,
+    #[serde(default)]
+    labels: Vec<GithubLabel>,
+    url: String,
+}
+
+#[derive(Deserialize)]
+struct GithubLabel {
+    name: String,
+}
+
+fn fetch_github_issues(
+    repo: &str,
+    labels: &[String],
+    state: &str,
+    limit: usize,
+) -> Result<Vec<ImportRecord>> {
+    let mut args = vec![
+        "issue".to_string(),
+        "list".to_string(),
+        "--repo".to_string(),
+        repo.to_string(),
+        "--state".to_string(),
+        state.to_string(),
+        "--limit".to_string(),
+        limit.to_string(),
+        "--json".to_string(),
+        "title,body,labels,url".to_string(),
+    ];
+    for label in labels {
+        args.push("--label".to_string());
+        args.push(label.clone());
+    }
+
+    let output = Command::new("gh").args(&args).output().map_err(|e| {
+        anyhow::anyhow!(
+            "Failed to run gh: {}. Install the GitHub CLI and run 'gh auth login'",
+            e
+        )
+    })?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "gh issue list failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+
+    parse_github_issues(&String::from_utf8_lossy(&output.stdout))
+}
+
+fn parse_github_issues(json: &str) -> Result<Vec<ImportRecord>> {
+    let issues: Vec<GithubIssue> = serde_json::from_str(json)
+        .map_err(|e| anyhow::anyhow!("Unexpected output from gh: {}", e))?;
+    Ok(issues
+        .into_iter()
+        .map(|issue| ImportRecord {
+            title: issue.title,
+            body: issue.body,
+            labels: issue.labels.into_iter().map(|label| label.name).collect(),
+            source: Some(issue.url),
+        })
+        .collect())
+}
+
+fn print_skipped(plan: &ImportPlan) {
+    if plan.skipped.is_empty() {
+        return;
+    }
+    println!("\nSkipped {}:", plan.skipped.len());
+    for skipped in &plan.skipped {
+        println!("  {} ({})", skipped.title, skipped.reason);
+    }
+}
+
+fn print_plan(plan: &ImportPlan) {
+    println!("Would create {} documents:", plan.create.len());
+    for record in &plan.create {
+        match &record.source {
+            Some(source) => println!("  {}  <- {}", record.title, source),
+            None => println!("  {}", record.title),
+        }
+    }
+    print_skipped(plan);
+}
+
+fn print_report(metis_dir: &Path, imported: &[ImportedDocument], plan: &ImportPlan) {
+    say!("✓ Created {} documents:", imported.len());
+    for doc in imported {
+        say!("  {}  {}", doc.short_code, doc.title);
+        say!(
+            "      {}",
+            doc.file_path
+                .strip_prefix(metis_dir)
+                .unwrap_or(&doc.file_path)
+                .display()
+        );
+    }
+    print_skipped(plan);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::InitCommand;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_parse_github_issues() {
+        let json = r#"[
+            {"title": "Crash on save", "body": "Trace", "labels": [{"name": "bug"}], "url": "https://github.com/org/name/issues/7"},
+            {"title": "No body", "body": null, "labels": [], "url": "https://github.com/org/name/issues/8"}
+        ]"#;
+        let records = parse_github_issues(json).unwrap();
+
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].labels, vec!["bug"]);
+        assert_eq!(
+            records[0].source.as_deref(),
+            Some("https://github.com/org/name/issues/7")
+        );
+        assert_eq!(records[1].body, None);
+        assert!(parse_github_issues("not json").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_import_command_no_workspace() {
+        let temp_dir = tempdir().unwrap();
+        let original_dir = std::env::current_dir().ok();
+
+        if std::env::set_current_dir(temp_dir.path()).is_err() {
+            return;
+        }
+
+        let cmd = ImportCommand {
+            source: ImportSource::Csv {
+                file: PathBuf::from("tasks.csv"),
+                map: None,
+                options: ImportOptions {
+                    destination: "backlog".to_string(),
+                    dry_run: true,
+                },
+            },
+        };
+        let result = cmd.execute().await;
+
+        if let Some(original) = original_dir {
+            let _ = std::env::set_current_dir(&original);
+        }
+
+        assert!(result.is_err());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Not in a Metis workspace"));
+    }
+
+    #[tokio::test]
+    async fn test_import_csv_to_backlog() {
+        let temp_dir = tempdir().unwrap();
+        let original_dir = std::env::current_dir().ok();
+
+        if std::env::set_current_dir(temp_dir.path()).is_err() {
+            return;
+        }
+
+        let init_cmd = InitCommand {
+            name: Some("Test Project".to_string()),
+            preset: None,
+            initiatives: None,
+            prefix: None,
+            remote: None,
+        };
+        init_cmd.execute().await.unwrap();
+
+        let csv = temp_dir.path().join("tasks.csv");
+        std::fs::write(
+            &csv,
+            "Summary,Tags\nFix the build,bug\nWrite docs,feature\n",
+        )
+        .unwrap();
+        let command = |dry_run| ImportCommand {
+            source: ImportSource::Csv {
+                file: csv.clone(),
+                map: Some("title=Summary,labels=Tags".to_string()),
+                options: ImportOptions {
+                    destination: "backlog".to_string(),
+                    dry_run,
+                },
+            },
+        };
+
+        let dry_run = command(true).execute().await;
+        let backlog = temp_dir.path().join(".metis").join("backlog");
+        let created_on_dry_run = backlog.join("bugs").exists();
+        let result = command(false).execute().await;
+
+        if let Some(original) = original_dir {
+            let _ = std::env::set_current_dir(&original);
+        }
+
+        assert!(dry_run.is_ok());
+        assert!(!created_on_dry_run);
+        assert!(result.is_ok());
+        assert_eq!(std::fs::read_dir(backlog.join("bugs")).unwrap().count(), 1);
+        assert_eq!(
+            std::fs::read_dir(backlog.join("features")).unwrap().count(),
+            1
+        );
+    }
+}
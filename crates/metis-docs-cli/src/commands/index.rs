@@ -18,6 +18,7 @@ use metis_code_index::{
     TypeScriptExtractor,
 };
 
+use crate::output::{say, Failure};
 use crate::workspace;
 
 #[derive(Args)]
@@ -38,7 +39,7 @@ impl IndexCommand {
         // Validate workspace
         let (workspace_exists, metis_dir) = workspace::has_metis_vault();
         if !workspace_exists {
-            anyhow::bail!("Not in a Metis workspace. Run 'metis init' to create one.");
+            return Err(Failure::NotAWorkspace.into());
         }
 
         let metis_dir = metis_dir.unwrap();
@@ -50,11 +51,11 @@ impl IndexCommand {
         let symbol_cache_path = metis_dir.join("code-index-symbols.json");
 
         // Step 1: Walk source files
-        println!("Scanning source files...");
+        say!("Scanning source files...");
         let walk_result = walk_directory(project_root)
             .map_err(|e| anyhow::anyhow!("Failed to walk directory: {}", e))?;
 
-        println!("  Found {} source files", walk_result.file_count());
+        say!("  Found {} source files", walk_result.file_count());
 
         // Report languages
         let by_lang = walk_result.by_language();
@@ -64,14 +65,14 @@ impl IndexCommand {
             .collect();
         lang_summary.sort_by(|a, b| b.1.cmp(&a.1));
         for (name, count) in &lang_summary {
-            println!("    {}: {} files", name, count);
+            say!("    {}: {} files", name, count);
         }
 
         // Step 2: Parse and extract symbols
         let symbols_by_file: BTreeMap<PathBuf, Vec<Symbol>>;
 
         if self.structure_only {
-            println!("Skipping symbol extraction (--structure-only)");
+            say!("Skipping symbol extraction (--structure-only)");
             symbols_by_file = BTreeMap::new();
         } else if self.incremental {
             symbols_by_file =
@@ -79,13 +80,13 @@ impl IndexCommand {
         } else {
             let (extracted, errors) = extract_all_symbols(&walk_result);
             let symbol_count: usize = extracted.values().map(|v| v.len()).sum();
-            println!(
+            say!(
                 "  Extracted {} symbols from {} files",
                 symbol_count,
                 extracted.len()
             );
             if errors > 0 {
-                println!("  {} files had parse errors (skipped)", errors);
+                say!("  {} files had parse errors (skipped)", errors);
             }
 
             // Save hash manifest and symbol cache for future incremental runs
@@ -110,7 +111,7 @@ impl IndexCommand {
         std::fs::write(&output_path, content)?;
 
         let elapsed = start.elapsed();
-        println!(
+        say!(
             "\nIndex written to {} ({:.1}s)",
             output_path.display(),
             elapsed.as_secs_f64()
@@ -131,13 +132,13 @@ impl IndexCommand {
         let diff = manifest.diff(walk_result);
 
         if diff.changed_count() == 0 && diff.deleted_count() == 0 {
-            println!("  No changes detected — loading cached symbols.");
+            say!("  No changes detected — loading cached symbols.");
             let cache = SymbolCache::load(symbol_cache_path)
                 .map_err(|e| anyhow::anyhow!("Failed to load symbol cache: {}", e))?;
             return Ok(cache.to_path_map());
         }
 
-        println!(
+        say!(
             "  Incremental: {} changed, {} unchanged, {} deleted",
             diff.changed_count(),
             diff.unchanged_count(),
@@ -149,7 +150,7 @@ impl IndexCommand {
             .map_err(|e| anyhow::anyhow!("Failed to load symbol cache: {}", e))?;
 
         // Parse only changed files
-        println!("Extracting symbols from changed files...");
+        say!("Extracting symbols from changed files...");
         let mut parser = Parser::new();
         let mut new_symbols: BTreeMap<PathBuf, Vec<Symbol>> = BTreeMap::new();
         let mut errors = 0;
@@ -182,14 +183,14 @@ impl IndexCommand {
         }
 
         let new_symbol_count: usize = new_symbols.values().map(|v| v.len()).sum();
-        println!(
+        say!(
             "  Extracted {} symbols from {} changed files (skipped {})",
             new_symbol_count,
             diff.changed_count(),
             diff.unchanged_count()
         );
         if errors > 0 {
-            println!("  {} files had parse errors (skipped)", errors);
+            say!("  {} files had parse errors (skipped)", errors);
         }
 
         // Update caches
@@ -234,7 +235,7 @@ fn extract_symbols_for_language(
 fn extract_all_symbols(
     walk_result: &metis_code_index::walker::WalkResult,
 ) -> (BTreeMap<PathBuf, Vec<Symbol>>, usize) {
-    println!("Extracting symbols...");
+    say!("Extracting symbols...");
     let mut parser = Parser::new();
     let mut symbols_by_file: BTreeMap<PathBuf, Vec<Symbol>> = BTreeMap::new();
     let mut errors = 0;
@@ -307,6 +308,7 @@ mod tests {
             preset: None,
 
             initiatives: None,
+            remote: None,
         };
         init_cmd.execute().await.unwrap();
 
@@ -352,6 +354,7 @@ mod tests {
             preset: None,
 
             initiatives: None,
+            remote: None,
         };
         init_cmd.execute().await.unwrap();
 
@@ -396,6 +399,7 @@ mod tests {
             preset: None,
 
             initiatives: None,
+            remote: None,
         };
         init_cmd.execute().await.unwrap();
 
@@ -434,6 +438,7 @@ mod tests {
             preset: None,
 
             initiatives: None,
+            remote: None,
         };
         init_cmd.execute().await.unwrap();
 
@@ -478,6 +483,7 @@ mod tests {
             preset: None,
 
             initiatives: None,
+            remote: None,
         };
         init_cmd.execute().await.unwrap();
 
@@ -1,14 +1,16 @@
+use crate::output::say;
 use crate::workspace;
 use anyhow::Result;
 use clap::Args;
+use dialoguer::{Input, Select};
 use metis_core::{
     application::services::workspace::WorkspaceInitializationService,
-    domain::configuration::FlightLevelConfig, Database,
+    domain::configuration::{ConfigFile, FlightLevelConfig, SyncConfig},
 };
 
 #[derive(Args)]
 pub struct InitCommand {
-    /// Project name for the vision document
+    /// Project name for the vision document. Omit it to be prompted for each setting
     #[arg(short, long)]
     pub name: Option<String>,
     /// Project prefix for document short codes, up to 6 characters (e.g., PROJ, ACME, TEST)
@@ -20,6 +22,17 @@ pub struct InitCommand {
     /// Enable/disable initiatives (true/false)
     #[arg(long)]
     pub initiatives: Option<bool>,
+    /// Git remote URL to sync the workspace with (e.g., git@github.com:org/plans.git)
+    #[arg(long)]
+    pub remote: Option<String>,
+}
+
+/// Settings for a new workspace, from flags or the interactive wizard
+struct InitSettings {
+    project_name: String,
+    project_prefix: String,
+    flight_config: FlightLevelConfig,
+    remote: Option<String>,
 }
 
 impl InitCommand {
@@ -34,68 +47,137 @@ impl InitCommand {
         // Get current directory for workspace creation
         let current_dir = std::env::current_dir()?;
 
-        // Determine project name and prefix
-        let project_name = self.name.as_deref().unwrap_or("Project Vision");
-        let project_prefix = self.determine_project_prefix(project_name);
+        // Prompt for whatever the flags leave out, or fall back to defaults
+        let settings = if self.name.is_none() && is_interactive() {
+            self.prompt_for_settings()?
+        } else {
+            self.settings_from_flags()?
+        };
 
-        // Use WorkspaceInitializationService to create workspace
-        let result = WorkspaceInitializationService::initialize_workspace_with_prefix(
+        // Write the full configuration in one pass
+        let config = ConfigFile::new(
+            settings.project_prefix.clone(),
+            settings.flight_config.clone(),
+        )
+        .and_then(|config| config.with_remote(settings.remote.clone()))
+        .map_err(|e| anyhow::anyhow!("Invalid configuration: {}", e))?;
+        let result = WorkspaceInitializationService::initialize_workspace_with_config(
             &current_dir,
-            project_name,
-            Some(&project_prefix),
+            &settings.project_name,
+            &config,
         )
         .await
         .map_err(|e| anyhow::anyhow!("Failed to initialize workspace: {}", e))?;
 
-        // If custom flight level config was specified, update it
-        let flight_config = self.determine_flight_config()?;
-        let db = Database::new(result.database_path.to_str().unwrap())
-            .map_err(|e| anyhow::anyhow!("Failed to open database: {}", e))?;
-        let mut config_repo = db
-            .configuration_repository()
-            .map_err(|e| anyhow::anyhow!("Failed to create configuration repository: {}", e))?;
-
-        // Update flight level config if it differs from default
-        let current_config = config_repo
-            .get_flight_level_config()
-            .map_err(|e| anyhow::anyhow!("Failed to get flight level config: {}", e))?;
-        if flight_config != current_config {
-            config_repo
-                .set_flight_level_config(&flight_config)
-                .map_err(|e| anyhow::anyhow!("Failed to set flight level configuration: {}", e))?;
-
-            // Update config.toml to match
-            let config_file_path = result.metis_dir.join("config.toml");
-            let config_file = metis_core::domain::configuration::ConfigFile::new(
-                project_prefix.clone(),
-                flight_config.clone(),
-            )
-            .map_err(|e| anyhow::anyhow!("Failed to create config file: {}", e))?;
-            config_file
-                .save(&config_file_path)
-                .map_err(|e| anyhow::anyhow!("Failed to save config.toml: {}", e))?;
-        }
-
         // Create/update .gitignore in .metis directory to ignore database
         let gitignore_path = result.metis_dir.join(".gitignore");
-        std::fs::write(&gitignore_path, "metis.db\nmetis-mcp-server.log\n")
+        std::fs::write(&gitignore_path, "metis.db\nmetis-mcp-server.log\ngui-session.json\n")
             .map_err(|e| anyhow::anyhow!("Failed to create .gitignore: {}", e))?;
 
-        println!(
+        say!(
             "[+] Initialized Metis workspace in {}",
             current_dir.display()
         );
-        println!("[+] Created vision.md with project template");
+        say!("[+] Created vision.md with project template");
         println!("[+] Created config.toml with project settings");
-        println!("[+] Set project prefix: {}", project_prefix);
-        println!(
+        say!("[+] Set project prefix: {}", settings.project_prefix);
+        say!(
             "[+] Set flight level configuration: {}",
-            flight_config.preset_name()
+            settings.flight_config.preset_name()
         );
+        if let Some(remote) = &settings.remote {
+            say!("[+] Set sync remote: {}", remote);
+        }
 
         Ok(())
     }
 
+    /// Build the settings from command arguments alone
+    fn settings_from_flags(&self) -> Result<InitSettings> {
+        let project_name = self
+            .name
+            .clone()
+            .unwrap_or_else(|| "Project Vision".to_string());
+        Ok(InitSettings {
+            project_prefix: self.determine_project_prefix(&project_name),
+            flight_config: self.determine_flight_config()?,
+            remote: self.remote.clone(),
+            project_name,
+        })
+    }
+
+    /// Walk through each setting the flags leave out, validating every answer
+    fn prompt_for_settings(&self) -> Result<InitSettings> {
+        println!("Setting up a new Metis workspace (press Enter to accept defaults)\n");
+
+        let project_name: String = Input::new()
+            .with_prompt("Project name")
+            .default("Project Vision".to_string())
+            .validate_with(|name: &String| -> Result<(), &str> {
+                if name.trim().is_empty() {
+                    Err("Project name cannot be empty")
+                } else {
+                    Ok(())
+                }
+            })
+            .interact_text()?;
+        let project_name = project_name.trim().to_string();
+
+        let flight_config = if self.preset.is_some() || self.initiatives.is_some() {
+            self.determine_flight_config()?
+        } else {
+            let presets = [
+                (
+                    "streamlined - Vision → Initiative → Task",
+                    FlightLevelConfig::streamlined(),
+                ),
+                ("direct - Vision → Task", FlightLevelConfig::direct()),
+            ];
+            let selection = Select::new()
+                .with_prompt("Flight level preset")
+                .default(0)
+                .items(&presets.iter().map(|(label, _)| label).collect::<Vec<_>>())
+                .interact()?;
+            presets[selection].1.clone()
+        };
+
+        let project_prefix = match &self.prefix {
+            Some(_) => self.determine_project_prefix(&project_name),
+            None => {
+                let default = self.determine_project_prefix(&project_name);
+                let mut input = Input::<String>::new()
+                    .with_prompt("Short-code prefix (2-6 letters)")
+                    .validate_with(|prefix: &String| validate_prefix(prefix).map(|_| ()));
+                if validate_prefix(&default).is_ok() {
+                    input = input.default(default);
+                }
+                let prefix = input.interact_text()?;
+                validate_prefix(&prefix).map_err(|e| anyhow::anyhow!(e))?
+            }
+        };
+
+        let remote = match &self.remote {
+            Some(remote) => Some(remote.clone()),
+            None => {
+                let remote: String = Input::new()
+                    .with_prompt("Sync remote URL (optional)")
+                    .allow_empty(true)
+                    .validate_with(|url: &String| validate_remote(url))
+                    .interact_text()?;
+                let remote = remote.trim();
+                (!remote.is_empty()).then(|| remote.to_string())
+            }
+        };
+
+        println!();
+        Ok(InitSettings {
+            project_name,
+            project_prefix,
+            flight_config,
+            remote,
+        })
+    }
+
     /// Determine the project prefix from command arguments or project name
     fn determine_project_prefix(&self, project_name: &str) -> String {
         if let Some(prefix) = &self.prefix {
@@ -149,6 +231,29 @@ impl InitCommand {
     }
 }
 
+/// Prompt only when a person is at the terminal, never under test
+pub(crate) fn is_interactive() -> bool {
+    !(cfg!(test) || std::env::var("RUST_TEST_THREADS").is_ok()) && console::user_attended()
+}
+
+/// Normalize a prefix typed at the wizard, which must be 2-6 letters
+fn validate_prefix(prefix: &str) -> Result<String, String> {
+    let prefix = prefix.trim().to_uppercase();
+    if (2..=6).contains(&prefix.len()) && prefix.chars().all(|c| c.is_ascii_uppercase()) {
+        Ok(prefix)
+    } else {
+        Err("Prefix must be 2-6 letters (e.g., PROJ, ACME)".to_string())
+    }
+}
+
+/// Accept an empty answer (no remote) or a valid git remote URL
+fn validate_remote(url: &str) -> Result<(), String> {
+    if url.trim().is_empty() {
+        return Ok(());
+    }
+    SyncConfig::validate_remote(url).map_err(|e| e.to_string())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -170,6 +275,7 @@ mod tests {
 
             initiatives: None,
             prefix: None,
+            remote: None,
         };
 
         let result = cmd.execute().await;
@@ -244,6 +350,7 @@ mod tests {
 
             initiatives: None,
             prefix: None,
+            remote: None,
         };
 
         let result = cmd.execute().await;
@@ -274,6 +381,7 @@ mod tests {
 
             initiatives: None,
             prefix: None,
+            remote: None,
         };
 
         let result = cmd.execute().await;
@@ -304,6 +412,7 @@ mod tests {
             preset: Some("direct".to_string()),
             initiatives: None,
             prefix: None,
+            remote: None,
         };
 
         let result = cmd.execute().await;
@@ -345,6 +454,7 @@ mod tests {
             preset: None,
             initiatives: Some(false),
             prefix: None,
+            remote: None,
         };
 
         let result = cmd.execute().await;
@@ -381,6 +491,7 @@ mod tests {
 
             initiatives: None,
             prefix: None,
+            remote: None,
         };
 
         let result = cmd.execute().await;
@@ -420,6 +531,7 @@ mod tests {
 
             initiatives: None,
             prefix: None,
+            remote: None,
         };
 
         let result = cmd.execute().await;
@@ -431,4 +543,60 @@ mod tests {
             let _ = std::env::set_current_dir(&original);
         }
     }
+
+    #[tokio::test]
+    async fn test_init_command_with_remote() {
+        let temp_dir = tempdir().unwrap();
+        let original_dir = std::env::current_dir().ok();
+
+        std::env::set_current_dir(temp_dir.path()).unwrap();
+
+        let cmd = InitCommand {
+            name: Some("Test Project".to_string()),
+            preset: Some("direct".to_string()),
+            initiatives: None,
+            prefix: None,
+            remote: Some("git@github.com:org/plans.git".to_string()),
+        };
+        let result = cmd.execute().await;
+
+        let bad_remote_dir = tempdir().unwrap();
+        std::env::set_current_dir(bad_remote_dir.path()).unwrap();
+        let bad_remote = InitCommand {
+            name: Some("Test Project".to_string()),
+            preset: None,
+            initiatives: None,
+            prefix: None,
+            remote: Some("not a url".to_string()),
+        }
+        .execute()
+        .await;
+
+        if let Some(original) = original_dir {
+            let _ = std::env::set_current_dir(&original);
+        }
+
+        assert!(result.is_ok());
+        let config = ConfigFile::load(temp_dir.path().join(".metis").join("config.toml")).unwrap();
+        assert_eq!(
+            config.sync().remote.as_deref(),
+            Some("git@github.com:org/plans.git")
+        );
+        assert_eq!(config.flight_levels(), &FlightLevelConfig::direct());
+
+        assert!(bad_remote.is_err());
+        assert!(!bad_remote_dir.path().join(".metis").exists());
+    }
+
+    #[test]
+    fn test_wizard_validators() {
+        assert_eq!(validate_prefix(" acme ").unwrap(), "ACME");
+        assert!(validate_prefix("A").is_err());
+        assert!(validate_prefix("TOOLONGX").is_err());
+        assert!(validate_prefix("AB1").is_err());
+
+        assert!(validate_remote("").is_ok());
+        assert!(validate_remote("https://github.com/org/plans.git").is_ok());
+        assert!(validate_remote("plans").is_err());
+    }
 }
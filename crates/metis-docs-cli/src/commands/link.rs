@@ -0,0 +1,234 @@
+use crate::output::{say, Failure};
+use crate::workspace;
+use anyhow::Result;
+use clap::Args;
+use metis_core::application::services::workspace::{Blocker, LinkKind, LinkService};
+use metis_core::application::services::DatabaseService;
+use metis_core::Database;
+use std::path::PathBuf;
+
+#[derive(Args)]
+pub struct LinkCommand {
+    /// Short code of the dependent document (e.g., PROJ-T-0001)
+    pub short_code: String,
+
+    #[command(flatten)]
+    pub target: LinkTarget,
+}
+
+#[derive(Args)]
+pub struct UnlinkCommand {
+    /// Short code of the dependent document (e.g., PROJ-T-0001)
+    pub short_code: String,
+
+    #[command(flatten)]
+    pub target: LinkTarget,
+}
+
+#[derive(Args)]
+#[group(required = true, multiple = false)]
+pub struct LinkTarget {
+    /// Short code of the document that blocks it
+    #[arg(long, value_name = "CODE")]
+    pub blocked_by: Option<String>,
+
+    /// Short code of a related document
+    #[arg(long, value_name = "CODE")]
+    pub relates_to: Option<String>,
+}
+
+impl LinkTarget {
+    fn resolve(&self) -> Result<(&str, LinkKind)> {
+        match (&self.blocked_by, &self.relates_to) {
+            (Some(code), _) => Ok((code, LinkKind::BlockedBy)),
+            (None, Some(code)) => Ok((code, LinkKind::RelatesTo)),
+            (None, None) => anyhow::bail!("Specify --blocked-by <code> or --relates-to <code>"),
+        }
+    }
+}
+
+#[derive(Args)]
+pub struct BlockersCommand {
+    /// Short code of the blocked document (e.g., PROJ-T-0001)
+    pub short_code: String,
+}
+
+impl LinkCommand {
+    pub async fn execute(&self) -> Result<()> {
+        let (target, kind) = self.target.resolve()?;
+        let (metis_dir, mut db_service) = open_workspace().await?;
+
+        let added = LinkService::new(&metis_dir)
+            .link(&self.short_code, target, kind, &mut db_service)
+            .map_err(|e| anyhow::anyhow!("{}", e))?;
+        if !added {
+            say!("{} is already {} {}", self.short_code, kind.label(), target);
+            return Ok(());
+        }
+        workspace::sync(&metis_dir, &mut db_service).await?;
+
+        say!("✓ {} is now {} {}", self.short_code, kind.label(), target);
+        Ok(())
+    }
+}
+
+impl UnlinkCommand {
+    pub async fn execute(&self) -> Result<()> {
+        let (target, kind) = self.target.resolve()?;
+        let (metis_dir, mut db_service) = open_workspace().await?;
+
+        let removed = LinkService::new(&metis_dir)
+            .unlink(&self.short_code, target, kind, &mut db_service)
+            .map_err(|e| anyhow::anyhow!("{}", e))?;
+        if !removed {
+            say!("{} is not {} {}", self.short_code, kind.label(), target);
+            return Ok(());
+        }
+        workspace::sync(&metis_dir, &mut db_service).await?;
+
+        say!(
+            "✓ {} is no longer {} {}",
+            self.short_code,
+            kind.label(),
+            target
+        );
+        Ok(())
+    }
+}
+
+impl BlockersCommand {
+    pub async fn execute(&self) -> Result<()> {
+        let (metis_dir, mut db_service) = open_workspace().await?;
+
+        let blockers = LinkService::new(&metis_dir)
+            .blockers(&self.short_code, &mut db_service)
+            .map_err(|e| anyhow::anyhow!("{}", e))?;
+        if blockers.is_empty() {
+            println!("{} has no blockers", self.short_code);
+            return Ok(());
+        }
+
+        println!("{} is blocked by:", self.short_code);
+        for line in blocker_lines(&blockers) {
+            println!("{}", line);
+        }
+        let open = blockers.iter().filter(|b| !is_done(b)).count();
+        println!("\n{} of {} blockers still open", open, blockers.len());
+
+        Ok(())
+    }
+}
+
+/// Sync, then open the database for the link service
+async fn open_workspace() -> Result<(PathBuf, DatabaseService)> {
+    let (workspace_exists, metis_dir) = workspace::has_metis_vault();
+    if !workspace_exists {
+        return Err(Failure::NotAWorkspace.into());
+    }
+    let metis_dir = metis_dir.unwrap();
+
+    let db = Database::new(&metis_dir.join("metis.db").to_string_lossy())
+        .map_err(|e| anyhow::anyhow!("Database initialization failed: {}", e))?;
+    let mut db_service = DatabaseService::new(db.into_repository());
+    workspace::sync(&metis_dir, &mut db_service).await?;
+
+    Ok((metis_dir, db_service))
+}
+
+fn is_done(blocker: &Blocker) -> bool {
+    blocker.document.phase == "completed" || blocker.document.archived
+}
+
+/// The blocker chain as an indented tree, one document per line
+fn blocker_lines(blockers: &[Blocker]) -> Vec<String> {
+    blockers
+        .iter()
+        .map(|blocker| {
+            format!(
+                "{}{} {} [{}] {}",
+                "  ".repeat(blocker.depth),
+                if is_done(blocker) { "✓" } else { "•" },
+                blocker.document.short_code,
+                blocker.document.phase,
+                blocker.document.title
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::InitCommand;
+    use tempfile::tempdir;
+
+    #[tokio::test]
+    async fn test_link_command_no_workspace() {
+        let temp_dir = tempdir().unwrap();
+        let original_dir = std::env::current_dir().ok();
+
+        if std::env::set_current_dir(temp_dir.path()).is_err() {
+            return;
+        }
+
+        let cmd = LinkCommand {
+            short_code: "TEST-T-0001".to_string(),
+            target: LinkTarget {
+                blocked_by: Some("TEST-T-0002".to_string()),
+                relates_to: None,
+            },
+        };
+        let result = cmd.execute().await;
+
+        if let Some(original) = original_dir {
+            let _ = std::env::set_current_dir(&original);
+        }
+
+        assert!(result.is_err());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Not in a Metis workspace"));
+    }
+
+    #[tokio::test]
+    async fn test_link_to_itself_fails() {
+        let temp_dir = tempdir().unwrap();
+        let original_dir = std::env::current_dir().ok();
+
+        if std::env::set_current_dir(temp_dir.path()).is_err() {
+            return;
+        }
+
+        let init_cmd = InitCommand {
+            name: Some("Test Project".to_string()),
+            preset: None,
+            initiatives: None,
+            prefix: None,
+            remote: None,
+        };
+        init_cmd.execute().await.unwrap();
+
+        let self_link = LinkCommand {
+            short_code: "TEST-V-0001".to_string(),
+            target: LinkTarget {
+                blocked_by: None,
+                relates_to: Some("TEST-V-0001".to_string()),
+            },
+        }
+        .execute()
+        .await;
+        let blockers = BlockersCommand {
+            short_code: "TEST-V-0001".to_string(),
+        }
+        .execute()
+        .await;
+
+        if let Some(original) = original_dir {
+            let _ = std::env::set_current_dir(&original);
+        }
+
+        assert!(self_link.unwrap_err().to_string().contains("itself"));
+        assert!(blockers.is_ok());
+    }
+}
@@ -1,8 +1,13 @@
+use crate::output::{self, Failure};
 use crate::workspace;
 use anyhow::Result;
 use clap::{Args, ValueEnum};
-use metis_core::{Application, Database, Result as MetisResult};
-use serde::Serialize;
+use metis_core::dal::database::models::Document;
+use metis_core::dal::database::{DocumentFilter, DocumentSort};
+use metis_core::{Application, Database};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::str::FromStr;
 
 /// Output format for CLI commands
 #[derive(Debug, Clone, Copy, ValueEnum, Default)]
@@ -16,6 +21,108 @@ pub enum OutputFormat {
     Json,
 }
 
+impl OutputFormat {
+    /// The format to print in: JSON when the global `--json` flag is set
+    pub fn or_json(self) -> Self {
+        if output::json() {
+            OutputFormat::Json
+        } else {
+            self
+        }
+    }
+}
+
+/// Output format for `metis list`, which adds spreadsheet and stream
+/// formats to the common ones
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum, Default)]
+pub enum ListFormat {
+    /// Human-readable table (default)
+    #[default]
+    Table,
+    /// Compact single-line per document for scripts
+    Compact,
+    /// JSON array
+    Json,
+    /// Comma-separated values with a header row
+    Csv,
+    /// One JSON object per line
+    Ndjson,
+}
+
+impl ListFormat {
+    /// The format to print in: JSON when the global `--json` flag is set,
+    /// unless NDJSON was asked for
+    pub fn or_json(self) -> Self {
+        match self {
+            ListFormat::Ndjson => self,
+            _ if output::json() => ListFormat::Json,
+            _ => self,
+        }
+    }
+}
+
+/// A column of list output
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Column {
+    Type,
+    Code,
+    Title,
+    Phase,
+    CreatedAt,
+    UpdatedAt,
+    Archived,
+    /// A custom frontmatter property, e.g. `assignee`
+    Property(String),
+}
+
+impl Column {
+    /// Name used for the header and JSON key
+    fn name(&self) -> &str {
+        match self {
+            Column::Type => "type",
+            Column::Code => "code",
+            Column::Title => "title",
+            Column::Phase => "phase",
+            Column::CreatedAt => "created_at",
+            Column::UpdatedAt => "updated_at",
+            Column::Archived => "archived",
+            Column::Property(key) => key,
+        }
+    }
+
+    /// Table column width
+    fn width(&self) -> usize {
+        match self {
+            Column::Type | Column::Phase => 12,
+            Column::Code => 14,
+            Column::Title => 50,
+            Column::CreatedAt | Column::UpdatedAt => 20,
+            Column::Archived => 8,
+            Column::Property(_) => 16,
+        }
+    }
+}
+
+impl FromStr for Column {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let key = s.trim().to_lowercase();
+        Ok(match key.as_str() {
+            "" => return Err("Empty column name".to_string()),
+            "type" | "document_type" => Column::Type,
+            "code" | "id" | "short_code" => Column::Code,
+            "title" => Column::Title,
+            "phase" => Column::Phase,
+            "created" | "created_at" => Column::CreatedAt,
+            "updated" | "updated_at" => Column::UpdatedAt,
+            "archived" => Column::Archived,
+            "due" => Column::Property("due_date".to_string()),
+            _ => Column::Property(key),
+        })
+    }
+}
+
 #[derive(Args)]
 pub struct ListCommand {
     /// Document type to filter by (vision, initiative, task, adr, specification)
@@ -34,19 +141,22 @@ pub struct ListCommand {
     #[arg(long)]
     pub include_archived: bool,
 
-    /// Output format (table, compact, json)
+    /// Output format (table, compact, json, csv, ndjson)
     #[arg(short = 'f', long, value_enum, default_value = "table")]
-    pub format: OutputFormat,
-}
+    pub format: ListFormat,
 
-/// JSON-serializable document for output
-#[derive(Serialize)]
-struct DocumentOutput {
-    #[serde(rename = "type")]
-    doc_type: String,
-    code: String,
-    title: String,
-    phase: String,
+    /// Comma-separated columns to show (type, code, title, phase, created_at,
+    /// updated_at, archived, or any frontmatter property such as assignee or due)
+    #[arg(long, value_delimiter = ',', default_value = "type,code,title,phase")]
+    pub columns: Vec<Column>,
+
+    /// Sort by: type (default), updated_at, title, due_date, priority
+    #[arg(short = 's', long, default_value = "type")]
+    pub sort: DocumentSort,
+
+    /// Sort in descending order
+    #[arg(long)]
+    pub desc: bool,
 }
 
 impl ListCommand {
@@ -54,7 +164,7 @@ impl ListCommand {
         // 1. Validate we're in a metis workspace
         let (workspace_exists, metis_dir) = workspace::has_metis_vault();
         if !workspace_exists {
-            anyhow::bail!("Not in a Metis workspace. Run 'metis init' to create one.");
+            return Err(Failure::NotAWorkspace.into());
         }
         let metis_dir = metis_dir.unwrap();
 
@@ -72,128 +182,139 @@ impl ListCommand {
             .map_err(|e| anyhow::anyhow!("Database connection failed: {}", e))?;
         let mut repo = db.into_repository();
 
-        // 4. Query documents based on filters
-        let documents = if self.all {
-            // Show all documents
-            self.list_all_documents(&mut repo).await?
-        } else if let Some(doc_type) = &self.document_type {
-            if let Some(phase) = &self.phase {
-                // Filter by both type and phase
-                repo.find_by_type_and_phase(doc_type, phase)
-                    .map_err(|e| anyhow::anyhow!("Database query failed: {}", e))?
-            } else {
-                // Filter by type only
-                repo.find_by_type(doc_type)
-                    .map_err(|e| anyhow::anyhow!("Database query failed: {}", e))?
-            }
-        } else if let Some(phase) = &self.phase {
-            // Filter by phase only
-            repo.find_by_phase(phase)
-                .map_err(|e| anyhow::anyhow!("Database query failed: {}", e))?
-        } else {
-            // Default: show all documents
-            self.list_all_documents(&mut repo).await?
-        };
+        // 4. Query documents; filtering and sorting happen in SQL
+        let documents = repo
+            .find_by_filter(&self.filter())
+            .map_err(|e| anyhow::anyhow!("Database query failed: {}", e))?;
+
+        // 5. Resolve the requested columns for each document
+        let rows = self.rows(&mut repo, &documents)?;
 
-        // 5. Display results based on format
+        // 6. Display results based on format
         if documents.is_empty() {
-            match self.format {
-                OutputFormat::Json => println!("[]"),
+            match self.format.or_json() {
+                ListFormat::Json => println!("[]"),
+                ListFormat::Ndjson => {}
+                ListFormat::Csv => println!("{}", self.csv_header()),
                 _ => println!("No documents found matching the criteria."),
             }
             return Ok(());
         }
 
-        match self.format {
-            OutputFormat::Table => self.display_table(&documents),
-            OutputFormat::Compact => self.display_compact(&documents),
-            OutputFormat::Json => self.display_json(&documents),
+        match self.format.or_json() {
+            ListFormat::Table => self.display_table(&rows),
+            ListFormat::Compact => self.display_compact(&documents),
+            ListFormat::Json => self.display_json(&rows),
+            ListFormat::Csv => self.display_csv(&rows),
+            ListFormat::Ndjson => self.display_ndjson(&rows),
         }
 
         Ok(())
     }
 
-    async fn list_all_documents(
-        &self,
-        repo: &mut metis_core::dal::database::repository::DocumentRepository,
-    ) -> MetisResult<Vec<metis_core::dal::database::models::Document>> {
-        // For listing all documents, we can query each type
-        let mut all_docs = Vec::new();
-
-        // Collect all document types in display order (matching MCP)
-        for doc_type in ["vision", "specification", "initiative", "task", "adr"] {
-            let mut docs = repo.find_by_type(doc_type)?;
-            all_docs.append(&mut docs);
-        }
+    fn filter(&self) -> DocumentFilter {
+        let mut filter = DocumentFilter::new()
+            .include_archived(self.include_archived)
+            .sort_by(self.sort, self.desc);
 
-        // Filter out archived documents unless requested
-        if !self.include_archived {
-            all_docs.retain(|doc| !doc.archived);
+        // --all overrides the type and phase filters
+        if !self.all {
+            if let Some(doc_type) = &self.document_type {
+                filter = filter.document_type(doc_type.to_lowercase());
+            }
+            if let Some(phase) = &self.phase {
+                filter = filter.phase(phase.to_lowercase());
+            }
         }
+        filter
+    }
 
-        // Sort by type order, then by short_code (matching MCP behavior)
-        let type_order = |t: &str| match t {
-            "vision" => 0,
-            "specification" => 1,
-            "initiative" => 2,
-            "task" => 3,
-            "adr" => 4,
-            _ => 5,
-        };
+    /// Cell values for every document, in column order. Missing properties
+    /// are empty strings.
+    fn rows(
+        &self,
+        repo: &mut metis_core::dal::database::repository::DocumentRepository,
+        documents: &[Document],
+    ) -> Result<Vec<Vec<String>>> {
+        let needs_properties = self
+            .columns
+            .iter()
+            .any(|c| matches!(c, Column::Property(_)));
 
-        all_docs.sort_by(|a, b| {
-            let type_cmp = type_order(&a.document_type).cmp(&type_order(&b.document_type));
-            if type_cmp != std::cmp::Ordering::Equal {
-                type_cmp
+        let mut rows = Vec::with_capacity(documents.len());
+        for doc in documents {
+            let properties: HashMap<String, String> = if needs_properties {
+                repo.find_properties(&doc.filepath)
+                    .map_err(|e| anyhow::anyhow!("Database query failed: {}", e))?
+                    .into_iter()
+                    .map(|p| (p.key, p.value))
+                    .collect()
             } else {
-                a.short_code.cmp(&b.short_code)
-            }
-        });
-
-        Ok(all_docs)
+                HashMap::new()
+            };
+
+            rows.push(
+                self.columns
+                    .iter()
+                    .map(|column| match column {
+                        Column::Type => doc.document_type.clone(),
+                        Column::Code => doc.short_code.clone(),
+                        Column::Title => doc.title.clone(),
+                        Column::Phase => doc.phase.clone(),
+                        Column::CreatedAt => format_timestamp(doc.created_at),
+                        Column::UpdatedAt => format_timestamp(doc.updated_at),
+                        Column::Archived => doc.archived.to_string(),
+                        Column::Property(key) => properties.get(key).cloned().unwrap_or_default(),
+                    })
+                    .collect(),
+            );
+        }
+        Ok(rows)
     }
 
     /// Display documents as a human-readable table
-    /// Columns match MCP list_documents: Type, Code, Title, Phase
-    fn display_table(&self, documents: &[metis_core::dal::database::models::Document]) {
-        println!(
-            "\n{:<12} {:<14} {:<50} {:<12}",
-            "Type", "Code", "Title", "Phase"
-        );
-        println!("{}", "-".repeat(90));
+    /// Default columns match MCP list_documents: Type, Code, Title, Phase
+    fn display_table(&self, rows: &[Vec<String>]) {
+        let line = |cells: Vec<String>| -> String {
+            cells
+                .iter()
+                .zip(&self.columns)
+                .map(|(cell, column)| format!("{:<width$}", cell, width = column.width()))
+                .collect::<Vec<_>>()
+                .join(" ")
+                .trim_end()
+                .to_string()
+        };
 
-        for doc in documents {
-            println!(
-                "{:<12} {:<14} {:<50} {:<12}",
-                doc.document_type,
-                doc.short_code,
-                self.truncate_string(&doc.title, 48),
-                doc.phase
-            );
+        let headers = self.columns.iter().map(|c| capitalize(c.name())).collect();
+        println!("\n{}", line(headers));
+        let total_width: usize = self.columns.iter().map(|c| c.width() + 1).sum();
+        println!("{}", "-".repeat(total_width.saturating_sub(1)));
+
+        for row in rows {
+            let cells = row
+                .iter()
+                .zip(&self.columns)
+                .map(|(cell, column)| self.truncate_string(cell, column.width() - 2))
+                .collect();
+            println!("{}", line(cells));
         }
 
-        println!("\nTotal: {} documents", documents.len());
+        println!("\nTotal: {} documents", rows.len());
     }
 
     /// Display documents in compact format (one line per document)
     /// Format: CODE PHASE TITLE
-    fn display_compact(&self, documents: &[metis_core::dal::database::models::Document]) {
+    fn display_compact(&self, documents: &[Document]) {
         for doc in documents {
             println!("{} {} {}", doc.short_code, doc.phase, doc.title);
         }
     }
 
-    /// Display documents as JSON array
-    fn display_json(&self, documents: &[metis_core::dal::database::models::Document]) {
-        let output: Vec<DocumentOutput> = documents
-            .iter()
-            .map(|doc| DocumentOutput {
-                doc_type: doc.document_type.clone(),
-                code: doc.short_code.clone(),
-                title: doc.title.clone(),
-                phase: doc.phase.clone(),
-            })
-            .collect();
+    /// Display documents as JSON array of objects keyed by column name
+    fn display_json(&self, rows: &[Vec<String>]) {
+        let output: Vec<Map<String, Value>> =
+            rows.iter().map(|row| self.json_object(row)).collect();
 
         match serde_json::to_string_pretty(&output) {
             Ok(json) => println!("{}", json),
@@ -201,21 +322,113 @@ impl ListCommand {
         }
     }
 
+    /// Display documents as newline-delimited JSON, one object per line
+    fn display_ndjson(&self, rows: &[Vec<String>]) {
+        for row in rows {
+            match serde_json::to_string(&self.json_object(row)) {
+                Ok(json) => println!("{}", json),
+                Err(e) => eprintln!("Error serializing to JSON: {}", e),
+            }
+        }
+    }
+
+    /// Display documents as CSV with a header row
+    fn display_csv(&self, rows: &[Vec<String>]) {
+        println!("{}", self.csv_header());
+        for row in rows {
+            let cells: Vec<String> = row.iter().map(|cell| csv_escape(cell)).collect();
+            println!("{}", cells.join(","));
+        }
+    }
+
+    fn csv_header(&self) -> String {
+        self.columns
+            .iter()
+            .map(|c| csv_escape(c.name()))
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
+    fn json_object(&self, row: &[String]) -> Map<String, Value> {
+        self.columns
+            .iter()
+            .zip(row)
+            .map(|(column, cell)| {
+                let value = match column {
+                    Column::Archived => Value::Bool(cell == "true"),
+                    _ => Value::String(cell.clone()),
+                };
+                (column.name().to_string(), value)
+            })
+            .collect()
+    }
+
     fn truncate_string(&self, s: &str, max_len: usize) -> String {
-        if s.len() <= max_len {
+        if s.chars().count() <= max_len {
             s.to_string()
         } else {
-            format!("{}...", &s[..max_len.saturating_sub(3)])
+            let kept: String = s.chars().take(max_len.saturating_sub(3)).collect();
+            format!("{}...", kept)
         }
     }
 }
 
+/// Unix timestamp as an RFC 3339 UTC date-time
+fn format_timestamp(timestamp: f64) -> String {
+    chrono::DateTime::from_timestamp(timestamp as i64, 0)
+        .map(|dt| dt.format("%Y-%m-%dT%H:%M:%SZ").to_string())
+        .unwrap_or_default()
+}
+
+/// Quote a CSV field when it contains a delimiter, quote or line break
+fn csv_escape(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+fn capitalize(s: &str) -> String {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::commands::InitCommand;
     use tempfile::tempdir;
 
+    fn default_columns() -> Vec<Column> {
+        vec![Column::Type, Column::Code, Column::Title, Column::Phase]
+    }
+
+    #[test]
+    fn test_column_parsing() {
+        assert_eq!("id".parse::<Column>().unwrap(), Column::Code);
+        assert_eq!("Phase".parse::<Column>().unwrap(), Column::Phase);
+        assert_eq!(
+            "due".parse::<Column>().unwrap(),
+            Column::Property("due_date".to_string())
+        );
+        assert_eq!(
+            "assignee".parse::<Column>().unwrap(),
+            Column::Property("assignee".to_string())
+        );
+        assert!(" ".parse::<Column>().is_err());
+    }
+
+    #[test]
+    fn test_csv_escape() {
+        assert_eq!(csv_escape("plain"), "plain");
+        assert_eq!(csv_escape("a, b"), "\"a, b\"");
+        assert_eq!(csv_escape("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+
     #[tokio::test]
     async fn test_list_command_no_workspace() {
         let temp_dir = tempdir().unwrap();
@@ -231,7 +444,10 @@ mod tests {
             phase: None,
             all: false,
             include_archived: false,
-            format: OutputFormat::Table,
+            format: ListFormat::Table,
+            columns: default_columns(),
+            sort: DocumentSort::Type,
+            desc: false,
         };
 
         let result = cmd.execute().await;
@@ -263,6 +479,7 @@ mod tests {
 
             initiatives: None,
             prefix: None,
+            remote: None,
         };
         init_cmd.execute().await.unwrap();
 
@@ -271,7 +488,10 @@ mod tests {
             phase: None,
             all: true,
             include_archived: false,
-            format: OutputFormat::Table,
+            format: ListFormat::Table,
+            columns: default_columns(),
+            sort: DocumentSort::Type,
+            desc: false,
         };
 
         let result = cmd.execute().await;
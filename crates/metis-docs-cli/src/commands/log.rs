@@ -0,0 +1,332 @@
+use crate::commands::list::OutputFormat;
+use crate::output::Failure;
+use crate::workspace;
+use anyhow::Result;
+use clap::Args;
+use metis_core::dal::database::DocumentFilter;
+use metis_core::{Application, Database};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::path::Path;
+use std::process::Command;
+
+/// Separates commits in the git log output
+const RECORD_SEPARATOR: char = '\u{1e}';
+/// Separates fields of a commit header
+const FIELD_SEPARATOR: char = '\u{1f}';
+
+#[derive(Args)]
+pub struct LogCommand {
+    /// Document short code to show history for (e.g., PROJ-T-0001)
+    #[arg(required_unless_present = "all", conflicts_with = "all")]
+    pub short_code: Option<String>,
+
+    /// Show recent activity across the whole workspace
+    #[arg(long)]
+    pub all: bool,
+
+    /// Only commits after this date (e.g. 2025-01-31 or "2 weeks ago")
+    #[arg(long)]
+    pub since: Option<String>,
+
+    /// Maximum number of commits to show
+    #[arg(short = 'n', long, default_value = "20")]
+    pub limit: usize,
+
+    /// Output format (table, compact, json)
+    #[arg(short = 'f', long, value_enum, default_value = "table")]
+    pub format: OutputFormat,
+}
+
+/// One change to one document in one commit
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+struct LogEntry {
+    commit: String,
+    author: String,
+    date: String,
+    message: String,
+    /// added, modified, deleted or renamed
+    change: String,
+    path: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    code: Option<String>,
+}
+
+impl LogCommand {
+    pub async fn execute(&self) -> Result<()> {
+        // 1. Validate we're in a metis workspace
+        let (workspace_exists, metis_dir) = workspace::has_metis_vault();
+        if !workspace_exists {
+            return Err(Failure::NotAWorkspace.into());
+        }
+        let metis_dir = metis_dir.unwrap();
+
+        // 2. Sync so short codes resolve to current paths
+        let db_path = metis_dir.join("metis.db");
+        let database = Database::new(db_path.to_str().unwrap())
+            .map_err(|e| anyhow::anyhow!("Failed to open database for sync: {}", e))?;
+        let app = Application::new(database);
+        app.sync_directory(&metis_dir)
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to sync workspace: {}", e))?;
+
+        let db = Database::new(db_path.to_str().unwrap())
+            .map_err(|e| anyhow::anyhow!("Database connection failed: {}", e))?;
+        let mut repo = db.into_repository();
+
+        // 3. History comes from git; document paths are relative to .metis
+        let mut args = vec![
+            "log".to_string(),
+            format!("--max-count={}", self.limit),
+            format!(
+                "--format={}%H{}%an{}%aI{}%s",
+                RECORD_SEPARATOR, FIELD_SEPARATOR, FIELD_SEPARATOR, FIELD_SEPARATOR
+            ),
+            "--name-status".to_string(),
+            // Report paths relative to .metis, matching the database
+            "--relative".to_string(),
+        ];
+        if let Some(since) = &self.since {
+            args.push(format!("--since={}", since));
+        }
+
+        let mut entries = if let Some(short_code) = &self.short_code {
+            let filepath = repo
+                .resolve_short_code_to_filepath(short_code)
+                .map_err(|e| anyhow::anyhow!("{}", e))?;
+            args.push("--follow".to_string());
+            args.push("--".to_string());
+            args.push(filepath);
+            let mut entries = parse_log(&run_git(&metis_dir, &args)?);
+            for entry in &mut entries {
+                entry.code = Some(short_code.clone());
+            }
+            entries
+        } else {
+            args.push("--".to_string());
+            args.push("*.md".to_string());
+            parse_log(&run_git(&metis_dir, &args)?)
+        };
+
+        // 4. Label workspace-wide entries with the short code of the file
+        if self.all {
+            let codes: HashMap<String, String> = repo
+                .find_by_filter(&DocumentFilter::new().include_archived(true))
+                .map_err(|e| anyhow::anyhow!("Database query failed: {}", e))?
+                .into_iter()
+                .map(|doc| (doc.filepath, doc.short_code))
+                .collect();
+            for entry in &mut entries {
+                entry.code = codes.get(&entry.path).cloned();
+            }
+        }
+
+        // 5. Display results based on format
+        if entries.is_empty() {
+            match self.format.or_json() {
+                OutputFormat::Json => println!("[]"),
+                _ => println!("No history found."),
+            }
+            return Ok(());
+        }
+
+        match self.format.or_json() {
+            OutputFormat::Table => display_table(&entries),
+            OutputFormat::Compact => display_compact(&entries),
+            OutputFormat::Json => match serde_json::to_string_pretty(&entries) {
+                Ok(json) => println!("{}", json),
+                Err(e) => eprintln!("Error serializing to JSON: {}", e),
+            },
+        }
+
+        Ok(())
+    }
+}
+
+/// Run git in the workspace directory and return its stdout
+pub(crate) fn run_git(metis_dir: &Path, args: &[String]) -> Result<String> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(metis_dir)
+        .output()
+        .map_err(|e| anyhow::anyhow!("Failed to run git: {}", e))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        if stderr.contains("not a git repository") {
+            anyhow::bail!("Document history needs the workspace to be in a git repository");
+        }
+        // A repository without commits has no history yet
+        if stderr.contains("does not have any commits") {
+            return Ok(String::new());
+        }
+        anyhow::bail!(
+            "git {} failed: {}",
+            args.first().map(String::as_str).unwrap_or_default(),
+            stderr.trim()
+        );
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Parse `git log --name-status` output produced with our record format
+/// into one entry per changed file
+fn parse_log(output: &str) -> Vec<LogEntry> {
+    let mut entries = Vec::new();
+
+    for record in output
+        .split(RECORD_SEPARATOR)
+        .filter(|r| !r.trim().is_empty())
+    {
+        let mut lines = record.lines();
+        let header = lines.next().unwrap_or_default();
+        let fields: Vec<&str> = header.splitn(4, FIELD_SEPARATOR).collect();
+        if fields.len() < 4 {
+            continue;
+        }
+
+        for line in lines.filter(|l| !l.trim().is_empty()) {
+            let mut parts = line.split('\t');
+            let status = parts.next().unwrap_or_default();
+            // Renames and copies list the old path first; keep the new one
+            let Some(path) = parts.last() else {
+                continue;
+            };
+            let change = match status.chars().next() {
+                Some('A') | Some('C') => "added",
+                Some('D') => "deleted",
+                Some('R') => "renamed",
+                _ => "modified",
+            };
+
+            entries.push(LogEntry {
+                commit: fields[0].chars().take(8).collect(),
+                author: fields[1].to_string(),
+                date: fields[2].to_string(),
+                message: fields[3].to_string(),
+                change: change.to_string(),
+                path: path.to_string(),
+                code: None,
+            });
+        }
+    }
+
+    entries
+}
+
+/// Display history as a human-readable table
+fn display_table(entries: &[LogEntry]) {
+    println!(
+        "\n{:<10} {:<20} {:<16} {:<14} {:<10} {}",
+        "Commit", "Date", "Author", "Code", "Change", "Message"
+    );
+    println!("{}", "-".repeat(110));
+
+    for entry in entries {
+        println!(
+            "{:<10} {:<20} {:<16} {:<14} {:<10} {}",
+            entry.commit,
+            entry
+                .date
+                .chars()
+                .take(19)
+                .collect::<String>()
+                .replace('T', " "),
+            truncate(&entry.author, 15),
+            entry.code.as_deref().unwrap_or("-"),
+            entry.change,
+            entry.message
+        );
+    }
+
+    println!("\nTotal: {} change(s)", entries.len());
+}
+
+/// Display history in compact format (one line per change)
+/// Format: COMMIT DATE CODE CHANGE MESSAGE
+fn display_compact(entries: &[LogEntry]) {
+    for entry in entries {
+        println!(
+            "{} {} {} {} {}",
+            entry.commit,
+            entry.date,
+            entry.code.as_deref().unwrap_or(&entry.path),
+            entry.change,
+            entry.message
+        );
+    }
+}
+
+fn truncate(s: &str, max_chars: usize) -> String {
+    if s.chars().count() <= max_chars {
+        s.to_string()
+    } else {
+        let kept: String = s.chars().take(max_chars.saturating_sub(3)).collect();
+        format!("{}...", kept)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_parse_log() {
+        let output = format!(
+            "{rs}abcdef0123456789{fs}Ada Lovelace{fs}2025-03-01T10:00:00+00:00{fs}Start auth work\n\n\
+             M\tstrategies/NULL/initiatives/I-1/tasks/T-1.md\n\
+             A\tbacklog/bugs/T-2.md\n\
+             {rs}0123456789abcdef{fs}Alan Turing{fs}2025-02-01T09:00:00+00:00{fs}Archive: done\n\n\
+             R100\tbacklog/T-3.md\tarchived/backlog/T-3.md\n",
+            rs = RECORD_SEPARATOR,
+            fs = FIELD_SEPARATOR
+        );
+
+        let entries = parse_log(&output);
+        assert_eq!(entries.len(), 3);
+        assert_eq!(entries[0].commit, "abcdef01");
+        assert_eq!(entries[0].author, "Ada Lovelace");
+        assert_eq!(entries[0].change, "modified");
+        assert_eq!(entries[1].change, "added");
+        assert_eq!(entries[1].path, "backlog/bugs/T-2.md");
+        assert_eq!(entries[2].change, "renamed");
+        assert_eq!(entries[2].path, "archived/backlog/T-3.md");
+        assert_eq!(entries[2].message, "Archive: done");
+    }
+
+    #[test]
+    fn test_parse_log_empty() {
+        assert!(parse_log("").is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_log_command_no_workspace() {
+        let temp_dir = tempdir().unwrap();
+        let original_dir = std::env::current_dir().ok();
+
+        if std::env::set_current_dir(temp_dir.path()).is_err() {
+            return;
+        }
+
+        let cmd = LogCommand {
+            short_code: None,
+            all: true,
+            since: None,
+            limit: 20,
+            format: OutputFormat::Table,
+        };
+        let result = cmd.execute().await;
+
+        if let Some(original) = original_dir {
+            let _ = std::env::set_current_dir(&original);
+        }
+
+        assert!(result.is_err());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Not in a Metis workspace"));
+    }
+}
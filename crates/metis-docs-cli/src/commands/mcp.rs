@@ -6,12 +6,51 @@ pub struct McpCommand {
     /// Log level for the MCP server (trace, debug, info, warn, error)
     #[arg(long, default_value = "info")]
     pub log_level: String,
+
+    /// Transport to serve on: stdio, or http for a long-lived server shared
+    /// by several clients. Overrides `mcp.transport` in config.toml
+    #[arg(long)]
+    pub transport: Option<String>,
+
+    /// Address the http transport listens on (default: 127.0.0.1)
+    #[arg(long)]
+    pub host: Option<String>,
+
+    /// Port the http transport listens on (default: 8080)
+    #[arg(long)]
+    pub port: Option<u16>,
+
+    /// Bearer token http clients must send; required for the http transport.
+    /// Prefer setting METIS_MCP_TOKEN, which doesn't show up in process lists
+    #[arg(long)]
+    pub token: Option<String>,
+
+    /// Only offer tools that read the workspace, refusing any that change it.
+    /// Overrides `mcp.read_only` in config.toml
+    #[arg(long)]
+    pub read_only: bool,
 }
 
 impl McpCommand {
     pub async fn execute(&self) -> Result<()> {
         // Set the log level environment variable
         std::env::set_var("METIS_LOG_LEVEL", &self.log_level);
+        // Transport settings reach the server the same way, overriding config.toml
+        if let Some(transport) = &self.transport {
+            std::env::set_var("METIS_MCP_TRANSPORT", transport);
+        }
+        if let Some(host) = &self.host {
+            std::env::set_var("METIS_MCP_HOST", host);
+        }
+        if let Some(port) = self.port {
+            std::env::set_var("METIS_MCP_PORT", port.to_string());
+        }
+        if let Some(token) = &self.token {
+            std::env::set_var("METIS_MCP_TOKEN", token);
+        }
+        if self.read_only {
+            std::env::set_var("METIS_MCP_READ_ONLY", "true");
+        }
 
         // Call the MCP server main function directly
         metis_mcp_server::run().await
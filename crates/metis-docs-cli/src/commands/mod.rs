@@ -1,25 +1,71 @@
+pub mod adr;
 pub mod archive;
+pub mod assign;
+pub mod backlog;
+pub mod board;
+pub mod bulk;
+pub mod checkout;
 pub mod config;
 pub mod create;
+pub mod criteria;
+pub mod diff;
+pub mod doctor;
+pub mod due;
+pub mod export;
+pub mod graph;
+pub mod grep;
+pub mod import;
 pub mod index;
 pub mod init;
+pub mod link;
 pub mod list;
+pub mod log;
 pub mod mcp;
+pub mod open;
+pub mod reparent;
+pub mod report;
 pub mod search;
+pub mod stats;
 pub mod status;
 pub mod sync;
+pub mod tag;
+pub mod template;
 pub mod transition;
 pub mod validate;
+pub mod watch;
 
+pub use adr::AdrCommand;
 pub use archive::ArchiveCommand;
+pub use assign::AssignCommand;
+pub use backlog::BacklogCommand;
+pub use board::BoardCommand;
+pub use bulk::BulkCommand;
+pub use checkout::CheckoutCommand;
 pub use config::ConfigCommand;
 pub use create::CreateCommand;
+pub use criteria::CriteriaCommand;
+pub use diff::DiffCommand;
+pub use doctor::DoctorCommand;
+pub use due::DueCommand;
+pub use export::ExportCommand;
+pub use graph::GraphCommand;
+pub use grep::GrepCommand;
+pub use import::ImportCommand;
 pub use index::IndexCommand;
 pub use init::InitCommand;
+pub use link::{BlockersCommand, LinkCommand, UnlinkCommand};
 pub use list::ListCommand;
+pub use log::LogCommand;
 pub use mcp::McpCommand;
+pub use open::OpenCommand;
+pub use reparent::MoveCommand;
+pub use report::ReportCommand;
 pub use search::SearchCommand;
+pub use stats::StatsCommand;
 pub use status::StatusCommand;
 pub use sync::SyncCommand;
+pub use tag::{TagCommand, TagsCommand};
+pub use template::TemplateCommand;
 pub use transition::TransitionCommand;
 pub use validate::ValidateCommand;
+pub use watch::WatchCommand;
@@ -0,0 +1,224 @@
+use crate::output::{say, Failure};
+use crate::workspace;
+use anyhow::Result;
+use clap::Args;
+use metis_core::application::services::document::DocumentValidationService;
+use metis_core::application::services::DatabaseService;
+use metis_core::domain::configuration::{ConfigFile, ViewerBackend, ViewerConfig};
+use metis_core::Database;
+use std::path::Path;
+use std::process::Command;
+
+#[derive(Args)]
+pub struct OpenCommand {
+    /// Document short code (e.g., PROJ-T-0001)
+    pub short_code: String,
+
+    /// Editor to use instead of the configured viewer or $EDITOR, e.g. "code --wait"
+    #[arg(long)]
+    pub editor: Option<String>,
+
+    /// Print the document's path instead of opening it
+    #[arg(long)]
+    pub path: bool,
+}
+
+impl OpenCommand {
+    pub async fn execute(&self) -> Result<()> {
+        // 1. Validate we're in a metis workspace
+        let (workspace_exists, metis_dir) = workspace::has_metis_vault();
+        if !workspace_exists {
+            return Err(Failure::NotAWorkspace.into());
+        }
+        let metis_dir = metis_dir.unwrap();
+
+        // 2. Resolve the short code to its file
+        let db = Database::new(&metis_dir.join("metis.db").to_string_lossy())
+            .map_err(|e| anyhow::anyhow!("Database initialization failed: {}", e))?;
+        let mut db_service = DatabaseService::new(db.into_repository());
+        workspace::sync(&metis_dir, &mut db_service).await?;
+        let doc = db_service
+            .find_by_short_code(&self.short_code)
+            .map_err(|e| anyhow::anyhow!("Database query failed: {}", e))?
+            .ok_or_else(|| anyhow::anyhow!("Document '{}' not found", self.short_code))?;
+        let file_path = metis_dir.join(&doc.filepath);
+
+        if self.path {
+            println!("{}", file_path.display());
+            return Ok(());
+        }
+
+        // 3. Edit the file and wait for the editor to exit
+        let before = std::fs::read(&file_path).ok();
+        run_editor(self.editor.as_deref(), &metis_dir, &file_path)?;
+        if std::fs::read(&file_path).ok() == before {
+            say!("No changes to {}", self.short_code);
+            return Ok(());
+        }
+
+        // 4. Pick up the edits and check the document still parses
+        workspace::sync(&metis_dir, &mut db_service).await?;
+        let result = DocumentValidationService::new()
+            .validate_document(&file_path)
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to validate {}: {}", file_path.display(), e))?;
+        if !result.is_valid {
+            println!("✗ {} is no longer a valid document:", self.short_code);
+            for error in &result.errors {
+                println!("  - {}", error);
+            }
+            return Err(Failure::Validation("Document validation failed".to_string()).into());
+        }
+
+        say!("✓ Updated {}", self.short_code);
+        Ok(())
+    }
+}
+
+/// Open `file_path` in the editor chosen by [`editor_command`] and wait for
+/// it to exit
+pub(crate) fn run_editor(explicit: Option<&str>, metis_dir: &Path, file_path: &Path) -> Result<()> {
+    let viewer = ConfigFile::load(metis_dir.join("config.toml"))
+        .map(|config| config.viewer().clone())
+        .unwrap_or_default();
+    let env_editor = ["VISUAL", "EDITOR"].iter().find_map(|var| {
+        std::env::var(var)
+            .ok()
+            .filter(|value| !value.trim().is_empty())
+    });
+    let editor = editor_command(explicit, &viewer, env_editor).ok_or_else(|| {
+        anyhow::anyhow!("No editor configured. Set $EDITOR or pass --editor <command>.")
+    })?;
+
+    let status = Command::new(&editor[0])
+        .args(&editor[1..])
+        .arg(file_path)
+        .status()
+        .map_err(|e| anyhow::anyhow!("Failed to run '{}': {}", editor.join(" "), e))?;
+    if !status.success() {
+        anyhow::bail!("'{}' exited with {}", editor.join(" "), status);
+    }
+    Ok(())
+}
+
+/// The editor command line, split into program and arguments.
+///
+/// Resolution order: `--editor`, then the `[viewer].default` from
+/// config.toml when it names a CLI editor (`code` runs as `code --wait`),
+/// then `$VISUAL`/`$EDITOR`.
+fn editor_command(
+    explicit: Option<&str>,
+    viewer: &ViewerConfig,
+    env_editor: Option<String>,
+) -> Option<Vec<String>> {
+    let command = match (explicit, &viewer.default) {
+        (Some(editor), _) => editor.to_string(),
+        (None, Some(ViewerBackend::Code)) => "code --wait".to_string(),
+        // The GUI can't be waited on, so it falls back to $EDITOR too
+        (None, _) => env_editor?,
+    };
+    let parts: Vec<String> = command.split_whitespace().map(str::to_string).collect();
+    if parts.is_empty() {
+        None
+    } else {
+        Some(parts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::InitCommand;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_editor_command() {
+        let mut viewer = ViewerConfig::default();
+        assert_eq!(
+            editor_command(None, &viewer, Some("vim".to_string())),
+            Some(vec!["vim".to_string()])
+        );
+        assert_eq!(editor_command(None, &viewer, None), None);
+
+        viewer.default = Some(ViewerBackend::Code);
+        assert_eq!(
+            editor_command(None, &viewer, Some("vim".to_string())),
+            Some(vec!["code".to_string(), "--wait".to_string()])
+        );
+        assert_eq!(
+            editor_command(Some("nano -w"), &viewer, None),
+            Some(vec!["nano".to_string(), "-w".to_string()])
+        );
+        assert_eq!(editor_command(Some("  "), &viewer, None), None);
+    }
+
+    #[tokio::test]
+    async fn test_open_command_no_workspace() {
+        let temp_dir = tempdir().unwrap();
+        let original_dir = std::env::current_dir().ok();
+
+        if std::env::set_current_dir(temp_dir.path()).is_err() {
+            return;
+        }
+
+        let cmd = OpenCommand {
+            short_code: "TEST-T-0001".to_string(),
+            editor: None,
+            path: false,
+        };
+        let result = cmd.execute().await;
+
+        if let Some(original) = original_dir {
+            let _ = std::env::set_current_dir(&original);
+        }
+
+        assert!(result.is_err());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Not in a Metis workspace"));
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_open_with_editor() {
+        let temp_dir = tempdir().unwrap();
+        let original_dir = std::env::current_dir().ok();
+
+        if std::env::set_current_dir(temp_dir.path()).is_err() {
+            return;
+        }
+
+        let init_cmd = InitCommand {
+            name: Some("Test Project".to_string()),
+            preset: None,
+            initiatives: None,
+            prefix: None,
+            remote: None,
+        };
+        init_cmd.execute().await.unwrap();
+
+        // `true` exits successfully without touching the file
+        let unchanged = OpenCommand {
+            short_code: "TEST-V-0001".to_string(),
+            editor: Some("true".to_string()),
+            path: false,
+        }
+        .execute()
+        .await;
+        let missing = OpenCommand {
+            short_code: "TEST-V-0099".to_string(),
+            editor: Some("true".to_string()),
+            path: false,
+        }
+        .execute()
+        .await;
+
+        if let Some(original) = original_dir {
+            let _ = std::env::set_current_dir(&original);
+        }
+
+        assert!(unchanged.is_ok());
+        assert!(missing.unwrap_err().to_string().contains("not found"));
+    }
+}
@@ -0,0 +1,193 @@
+use crate::output::{say, Failure};
+use crate::workspace;
+use anyhow::Result;
+use clap::Args;
+use metis_core::application::services::workspace::{
+    BacklogCategory, ReassignmentResult, ReassignmentService,
+};
+use metis_core::application::services::DatabaseService;
+use metis_core::Database;
+use std::path::Path;
+
+#[derive(Args)]
+pub struct MoveCommand {
+    /// Short code of the task to move (e.g., PROJ-T-0001)
+    pub short_code: String,
+
+    /// Short code of the new parent initiative (e.g., PROJ-I-0002)
+    #[arg(long, required_unless_present = "backlog", conflicts_with = "backlog")]
+    pub to: Option<String>,
+
+    /// Move the task to the backlog instead (bug, feature, tech-debt)
+    #[arg(long, value_parser = parse_backlog_category)]
+    pub backlog: Option<BacklogCategory>,
+
+    /// Show where the task would go without moving it
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
+pub(crate) fn parse_backlog_category(s: &str) -> std::result::Result<BacklogCategory, String> {
+    BacklogCategory::from_str(s).ok_or_else(|| {
+        format!(
+            "invalid backlog category '{}'. Valid options: bug, feature, tech-debt",
+            s
+        )
+    })
+}
+
+impl MoveCommand {
+    pub async fn execute(&self) -> Result<()> {
+        // 1. Validate we're in a metis workspace
+        let (workspace_exists, metis_dir) = workspace::has_metis_vault();
+        if !workspace_exists {
+            return Err(Failure::NotAWorkspace.into());
+        }
+        let metis_dir = metis_dir.unwrap();
+
+        // 2. Sync so the task and its new parent are current in the database
+        let db = Database::new(&metis_dir.join("metis.db").to_string_lossy())
+            .map_err(|e| anyhow::anyhow!("Database initialization failed: {}", e))?;
+        let mut db_service = DatabaseService::new(db.into_repository());
+        workspace::sync(&metis_dir, &mut db_service).await?;
+
+        // 3. Validate the move and work out the destination
+        let service = ReassignmentService::new(&metis_dir);
+        let plan = match (&self.to, self.backlog) {
+            (Some(parent), _) => {
+                service.plan_to_initiative(&self.short_code, parent, &mut db_service)
+            }
+            (None, Some(category)) => {
+                service.plan_to_backlog(&self.short_code, category, &mut db_service)
+            }
+            (None, None) => anyhow::bail!("Specify --to <initiative> or --backlog <category>"),
+        }
+        .map_err(|e| anyhow::anyhow!("{}", e))?;
+
+        if self.dry_run {
+            println!("Would move {}:", self.short_code);
+            for line in plan_lines(&metis_dir, &plan) {
+                println!("{}", line);
+            }
+            return Ok(());
+        }
+
+        // 4. Move the file, then sync so the database follows it
+        let result = match (&self.to, self.backlog) {
+            (Some(parent), _) => {
+                service
+                    .reassign_to_initiative(&self.short_code, parent, &mut db_service)
+                    .await
+            }
+            (None, Some(category)) => {
+                service
+                    .reassign_to_backlog(&self.short_code, category, &mut db_service)
+                    .await
+            }
+            (None, None) => unreachable!("validated while planning"),
+        }
+        .map_err(|e| anyhow::anyhow!("{}", e))?;
+        workspace::sync(&metis_dir, &mut db_service).await?;
+
+        say!("✓ Moved {}:", self.short_code);
+        for line in plan_lines(&metis_dir, &result) {
+            say!("{}", line);
+        }
+
+        Ok(())
+    }
+}
+
+fn plan_lines(metis_dir: &Path, plan: &ReassignmentResult) -> Vec<String> {
+    let relative = |path: &Path| {
+        path.strip_prefix(metis_dir)
+            .unwrap_or(path)
+            .display()
+            .to_string()
+    };
+    vec![
+        format!("  from:   {}", relative(&plan.old_path)),
+        format!("  to:     {}", relative(&plan.new_path)),
+        format!(
+            "  parent: {}",
+            plan.new_parent.as_deref().unwrap_or("(backlog)")
+        ),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::InitCommand;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_parse_backlog_category() {
+        assert_eq!(
+            parse_backlog_category("tech-debt").unwrap(),
+            BacklogCategory::TechDebt
+        );
+        assert!(parse_backlog_category("chore").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_move_command_no_workspace() {
+        let temp_dir = tempdir().unwrap();
+        let original_dir = std::env::current_dir().ok();
+
+        if std::env::set_current_dir(temp_dir.path()).is_err() {
+            return;
+        }
+
+        let cmd = MoveCommand {
+            short_code: "TEST-T-0001".to_string(),
+            to: Some("TEST-I-0001".to_string()),
+            backlog: None,
+            dry_run: false,
+        };
+        let result = cmd.execute().await;
+
+        if let Some(original) = original_dir {
+            let _ = std::env::set_current_dir(&original);
+        }
+
+        assert!(result.is_err());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Not in a Metis workspace"));
+    }
+
+    #[tokio::test]
+    async fn test_move_non_task_fails() {
+        let temp_dir = tempdir().unwrap();
+        let original_dir = std::env::current_dir().ok();
+
+        if std::env::set_current_dir(temp_dir.path()).is_err() {
+            return;
+        }
+
+        let init_cmd = InitCommand {
+            name: Some("Test Project".to_string()),
+            preset: None,
+            initiatives: None,
+            prefix: None,
+            remote: None,
+        };
+        init_cmd.execute().await.unwrap();
+
+        let cmd = MoveCommand {
+            short_code: "TEST-V-0001".to_string(),
+            to: None,
+            backlog: Some(BacklogCategory::Feature),
+            dry_run: true,
+        };
+        let result = cmd.execute().await;
+
+        if let Some(original) = original_dir {
+            let _ = std::env::set_current_dir(&original);
+        }
+
+        assert!(result.unwrap_err().to_string().contains("Only tasks"));
+    }
+}
@@ -0,0 +1,325 @@
+use crate::output::{self, Failure};
+use crate::workspace;
+use anyhow::Result;
+use chrono::Utc;
+use clap::Args;
+use metis_core::application::services::DatabaseService;
+use metis_core::dal::database::{parse_when, FlowReport};
+use metis_core::{Application, Database, DocumentType};
+
+/// Aging items shown in text and markdown output
+const AGING_LIMIT: usize = 10;
+
+#[derive(Args)]
+pub struct ReportCommand {
+    /// Document type to report on (task, initiative)
+    #[arg(short = 't', long = "type", default_value = "task", value_parser = ["task", "initiative"])]
+    pub document_type: String,
+
+    /// Start of the reporting window (e.g. 2025-01-31, 30d, 2w)
+    #[arg(long, default_value = "30d")]
+    pub since: String,
+
+    /// Output as markdown tables
+    #[arg(long)]
+    pub markdown: bool,
+}
+
+impl ReportCommand {
+    pub async fn execute(&self) -> Result<()> {
+        // 1. Validate we're in a metis workspace
+        let (workspace_exists, metis_dir) = workspace::has_metis_vault();
+        if !workspace_exists {
+            return Err(Failure::NotAWorkspace.into());
+        }
+        let metis_dir = metis_dir.unwrap();
+
+        // 2. Sync so the latest phase changes are recorded
+        let db_path = metis_dir.join("metis.db");
+        let database = Database::new(db_path.to_str().unwrap())
+            .map_err(|e| anyhow::anyhow!("Failed to open database for sync: {}", e))?;
+        let app = Application::new(database);
+        app.sync_directory(&metis_dir)
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to sync workspace: {}", e))?;
+
+        // 3. Compute the report over the window
+        let document_type: DocumentType = self
+            .document_type
+            .parse()
+            .map_err(|e: String| anyhow::anyhow!(e))?;
+        let now = Utc::now();
+        let since = parse_when(&self.since, now).map_err(|e| anyhow::anyhow!(e))?;
+
+        let db = Database::new(db_path.to_str().unwrap())
+            .map_err(|e| anyhow::anyhow!("Database connection failed: {}", e))?;
+        let mut db_service = DatabaseService::new(db.into_repository());
+        let report = db_service
+            .flow_report(document_type, since, now.timestamp() as f64)
+            .map_err(|e| anyhow::anyhow!("Failed to compute report: {}", e))?;
+
+        // 4. Display
+        if output::json() {
+            println!("{}", serde_json::to_string_pretty(&report)?);
+        } else if self.markdown {
+            print!("{}", render_markdown(&report));
+        } else {
+            print!("{}", render_text(&report));
+        }
+
+        Ok(())
+    }
+}
+
+fn format_date(timestamp: f64) -> String {
+    chrono::DateTime::from_timestamp(timestamp as i64, 0)
+        .map(|dt| dt.format("%Y-%m-%d").to_string())
+        .unwrap_or_else(|| "-".to_string())
+}
+
+fn format_days(days: f64) -> String {
+    format!("{:.1}d", days)
+}
+
+fn render_text(report: &FlowReport) -> String {
+    let mut out = format!(
+        "\nFLOW REPORT: {}s, {} to {}\n\n",
+        report.document_type,
+        format_date(report.since),
+        format_date(report.until)
+    );
+
+    out.push_str("Cycle time (active -> completed)\n");
+    match &report.cycle_time {
+        Some(cycle) => {
+            out.push_str(&format!("  {:<10} {:>8}\n", "count", cycle.count));
+            out.push_str(&format!(
+                "  {:<10} {:>8}\n",
+                "mean",
+                format_days(cycle.mean_days)
+            ));
+            out.push_str(&format!(
+                "  {:<10} {:>8}\n",
+                "median",
+                format_days(cycle.median_days)
+            ));
+            out.push_str(&format!(
+                "  {:<10} {:>8}\n",
+                "p85",
+                format_days(cycle.p85_days)
+            ));
+            out.push_str(&format!(
+                "  {:<10} {:>8}\n",
+                "max",
+                format_days(cycle.max_days)
+            ));
+        }
+        None => out.push_str("  No completions with a recorded start\n"),
+    }
+
+    out.push_str(&format!("\nThroughput ({} completed)\n", report.completed));
+    for week in &report.throughput {
+        out.push_str(&format!(
+            "  {}  {:>3}  {}\n",
+            week.week_start,
+            week.completed,
+            "#".repeat(week.completed)
+        ));
+    }
+
+    out.push_str("\nWork in progress\n");
+    if report.wip.is_empty() {
+        out.push_str("  Nothing in progress\n");
+    }
+    for phase in &report.wip {
+        out.push_str(&format!("  {:<10} {:>8}\n", phase.phase, phase.count));
+    }
+
+    if !report.aging.is_empty() {
+        out.push_str("\nAging\n");
+        out.push_str(&format!(
+            "  {:<16} {:<10} {:>8}  {}\n",
+            "CODE", "PHASE", "AGE", "TITLE"
+        ));
+        for item in report.aging.iter().take(AGING_LIMIT) {
+            out.push_str(&format!(
+                "  {:<16} {:<10} {:>8}  {}\n",
+                item.short_code,
+                item.phase,
+                format_days(item.days_in_phase),
+                item.title
+            ));
+        }
+    }
+
+    out
+}
+
+fn render_markdown(report: &FlowReport) -> String {
+    let mut out = format!(
+        "# Flow report: {}s, {} to {}\n\n",
+        report.document_type,
+        format_date(report.since),
+        format_date(report.until)
+    );
+
+    out.push_str("## Cycle time\n\n");
+    match &report.cycle_time {
+        Some(cycle) => {
+            out.push_str("| Count | Mean | Median | p85 | Max |\n");
+            out.push_str("|------:|-----:|-------:|----:|----:|\n");
+            out.push_str(&format!(
+                "| {} | {} | {} | {} | {} |\n",
+                cycle.count,
+                format_days(cycle.mean_days),
+                format_days(cycle.median_days),
+                format_days(cycle.p85_days),
+                format_days(cycle.max_days)
+            ));
+        }
+        None => out.push_str("No completions with a recorded start.\n"),
+    }
+
+    out.push_str("\n## Throughput\n\n| Week | Completed |\n|------|----------:|\n");
+    for week in &report.throughput {
+        out.push_str(&format!("| {} | {} |\n", week.week_start, week.completed));
+    }
+
+    out.push_str("\n## Work in progress\n\n| Phase | Count |\n|-------|------:|\n");
+    for phase in &report.wip {
+        out.push_str(&format!("| {} | {} |\n", phase.phase, phase.count));
+    }
+
+    if !report.aging.is_empty() {
+        out.push_str(
+            "\n## Aging\n\n| Code | Phase | Age | Title |\n|------|-------|----:|-------|\n",
+        );
+        for item in report.aging.iter().take(AGING_LIMIT) {
+            out.push_str(&format!(
+                "| {} | {} | {} | {} |\n",
+                item.short_code,
+                item.phase,
+                format_days(item.days_in_phase),
+                item.title.replace('|', "\\|")
+            ));
+        }
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::InitCommand;
+    use metis_core::dal::database::analytics::{AgingItem, PhaseCount, ThroughputWeek};
+    use tempfile::tempdir;
+
+    fn sample_report() -> FlowReport {
+        FlowReport {
+            document_type: "task".to_string(),
+            since: 1_740_960_000.0,
+            until: 1_741_564_800.0,
+            completed: 1,
+            cycle_time: None,
+            throughput: vec![ThroughputWeek {
+                week_start: chrono::NaiveDate::from_ymd_opt(2025, 3, 3).unwrap(),
+                completed: 1,
+            }],
+            wip: vec![PhaseCount {
+                phase: "active".to_string(),
+                count: 1,
+            }],
+            aging: vec![AgingItem {
+                short_code: "TEST-T-0001".to_string(),
+                title: "Pipe | title".to_string(),
+                phase: "active".to_string(),
+                days_in_phase: 2.5,
+            }],
+        }
+    }
+
+    #[test]
+    fn test_render_text() {
+        let text = render_text(&sample_report());
+        assert!(text.contains("FLOW REPORT: tasks, 2025-03-03 to 2025-03-10"));
+        assert!(text.contains("No completions with a recorded start"));
+        assert!(text.contains("2025-03-03    1  #"));
+        assert!(text.contains("TEST-T-0001"));
+    }
+
+    #[test]
+    fn test_render_markdown() {
+        let markdown = render_markdown(&sample_report());
+        assert!(markdown.starts_with("# Flow report: tasks"));
+        assert!(markdown.contains("| 2025-03-03 | 1 |"));
+        assert!(markdown.contains("| TEST-T-0001 | active | 2.5d | Pipe \\| title |"));
+    }
+
+    #[tokio::test]
+    async fn test_report_command_no_workspace() {
+        let temp_dir = tempdir().unwrap();
+        let original_dir = std::env::current_dir().ok();
+
+        if std::env::set_current_dir(temp_dir.path()).is_err() {
+            return;
+        }
+
+        let cmd = ReportCommand {
+            document_type: "task".to_string(),
+            since: "30d".to_string(),
+            markdown: false,
+        };
+        let result = cmd.execute().await;
+
+        if let Some(original) = original_dir {
+            let _ = std::env::set_current_dir(&original);
+        }
+
+        assert!(result.is_err());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Not in a Metis workspace"));
+    }
+
+    #[tokio::test]
+    async fn test_report_command_in_workspace() {
+        let temp_dir = tempdir().unwrap();
+        let original_dir = std::env::current_dir().ok();
+
+        if std::env::set_current_dir(temp_dir.path()).is_err() {
+            return;
+        }
+
+        let init_cmd = InitCommand {
+            name: Some("Test Project".to_string()),
+            preset: None,
+            initiatives: None,
+            prefix: None,
+            remote: None,
+        };
+        init_cmd.execute().await.unwrap();
+
+        let cmd = ReportCommand {
+            document_type: "initiative".to_string(),
+            since: "2w".to_string(),
+            markdown: true,
+        };
+        let result = cmd.execute().await;
+
+        let bad_since = ReportCommand {
+            document_type: "task".to_string(),
+            since: "last tuesday".to_string(),
+            markdown: false,
+        };
+        let bad_result = bad_since.execute().await;
+
+        if let Some(original) = original_dir {
+            let _ = std::env::set_current_dir(&original);
+        }
+
+        assert!(result.is_ok());
+        assert!(bad_result.is_err());
+    }
+}
@@ -1,8 +1,10 @@
 use crate::commands::list::OutputFormat;
+use crate::output::Failure;
 use crate::workspace;
 use anyhow::Result;
 use clap::Args;
-use metis_core::dal::database::models::Document;
+use metis_core::dal::database::models::SearchHit;
+use metis_core::dal::database::DocumentFilter;
 use metis_core::{Application, Database};
 use serde::Serialize;
 
@@ -11,6 +13,18 @@ pub struct SearchCommand {
     /// Search query for full-text search across document content
     pub query: String,
 
+    /// Only documents of this type (vision, initiative, task, adr, specification)
+    #[arg(short = 't', long = "type")]
+    pub document_type: Option<String>,
+
+    /// Only documents in this phase
+    #[arg(short = 'p', long)]
+    pub phase: Option<String>,
+
+    /// Only documents carrying this tag (e.g. '#team/api'); repeat to require several
+    #[arg(long = "tag")]
+    pub tags: Vec<String>,
+
     /// Maximum number of results to show
     #[arg(short = 'l', long, default_value = "20")]
     pub limit: usize,
@@ -27,6 +41,8 @@ struct SearchResultOutput {
     title: String,
     #[serde(rename = "type")]
     doc_type: String,
+    phase: String,
+    snippet: String,
 }
 
 impl SearchCommand {
@@ -34,7 +50,7 @@ impl SearchCommand {
         // 1. Validate we're in a metis workspace
         let (workspace_exists, metis_dir) = workspace::has_metis_vault();
         if !workspace_exists {
-            anyhow::bail!("Not in a Metis workspace. Run 'metis init' to create one.");
+            return Err(Failure::NotAWorkspace.into());
         }
         let metis_dir = metis_dir.unwrap();
 
@@ -52,73 +68,97 @@ impl SearchCommand {
             .map_err(|e| anyhow::anyhow!("Failed to open database: {}", e))?;
         let mut app = Application::new(database);
 
-        // 4. Perform full-text search
-        let results = self.perform_search(&mut app, &self.query)?;
+        // 4. Perform full-text search, filtered and limited in SQL
+        let filter = self.filter();
+        let results = app
+            .with_query(|query| query.search(&self.query, &filter))
+            .map_err(|e| anyhow::anyhow!("Search failed: {}", e))?;
 
-        // 5. Limit results
-        let limited_results: Vec<_> = results.into_iter().take(self.limit).collect();
+        // 5. Display results based on format
+        let format = self.format.or_json();
 
-        // 6. Display results based on format
-        if limited_results.is_empty() {
-            match self.format {
+        if results.is_empty() {
+            match format {
                 OutputFormat::Json => println!("[]"),
                 _ => println!("No documents found for query: \"{}\"", self.query),
             }
             return Ok(());
         }
 
-        match self.format {
-            OutputFormat::Table => self.display_table(&limited_results),
-            OutputFormat::Compact => self.display_compact(&limited_results),
-            OutputFormat::Json => self.display_json(&limited_results),
+        match format {
+            OutputFormat::Table => self.display_table(&results),
+            OutputFormat::Compact => self.display_compact(&results),
+            OutputFormat::Json => self.display_json(&results),
         }
 
         Ok(())
     }
 
-    fn perform_search(&self, app: &mut Application, query: &str) -> Result<Vec<Document>> {
-        app.with_database(|db_service| db_service.search_documents(query))
-            .map_err(|e| anyhow::anyhow!("Search failed: {}", e))
+    /// Field filters from the command line. Archived documents stay
+    /// searchable, as they always have been.
+    fn filter(&self) -> DocumentFilter {
+        let mut filter = DocumentFilter::new()
+            .include_archived(true)
+            .page(self.limit, 0);
+        if let Some(document_type) = &self.document_type {
+            filter = filter.document_type(document_type.to_lowercase());
+        }
+        if let Some(phase) = &self.phase {
+            filter = filter.phase(phase.to_lowercase());
+        }
+        for tag in &self.tags {
+            filter = filter.tag(normalize_tag(tag));
+        }
+        filter
     }
 
-    /// Display results as a human-readable table
-    /// Columns match MCP search_documents: Code, Title, Type
-    fn display_table(&self, documents: &[Document]) {
-        println!("\n{:<14} {:<60} {:<12}", "Code", "Title", "Type");
+    /// Display results as a human-readable table, each followed by its snippet
+    /// Columns: Code, Title, Phase
+    fn display_table(&self, results: &[SearchHit]) {
+        println!("\n{:<14} {:<60} {:<12}", "Code", "Title", "Phase");
         println!("{}", "-".repeat(88));
 
-        for doc in documents {
+        for hit in results {
             println!(
                 "{:<14} {:<60} {:<12}",
-                doc.short_code,
-                truncate(&doc.title, 58),
-                doc.document_type
+                hit.document.short_code,
+                truncate(&hit.document.title, 58),
+                hit.document.phase
             );
+            let snippet = single_line(&hit.snippet);
+            if !snippet.is_empty() {
+                println!("{:<14} {}", "", highlight(&snippet));
+            }
         }
 
         println!(
             "\nFound {} document(s) for \"{}\"",
-            documents.len(),
+            results.len(),
             self.query
         );
     }
 
     /// Display results in compact format (one line per document)
-    /// Format: CODE TYPE TITLE
-    fn display_compact(&self, documents: &[Document]) {
-        for doc in documents {
-            println!("{} {} {}", doc.short_code, doc.document_type, doc.title);
+    /// Format: CODE PHASE TITLE
+    fn display_compact(&self, results: &[SearchHit]) {
+        for hit in results {
+            println!(
+                "{} {} {}",
+                hit.document.short_code, hit.document.phase, hit.document.title
+            );
         }
     }
 
     /// Display results as JSON array
-    fn display_json(&self, documents: &[Document]) {
-        let output: Vec<SearchResultOutput> = documents
+    fn display_json(&self, results: &[SearchHit]) {
+        let output: Vec<SearchResultOutput> = results
             .iter()
-            .map(|doc| SearchResultOutput {
-                code: doc.short_code.clone(),
-                title: doc.title.clone(),
-                doc_type: doc.document_type.clone(),
+            .map(|hit| SearchResultOutput {
+                code: hit.document.short_code.clone(),
+                title: hit.document.title.clone(),
+                doc_type: hit.document.document_type.clone(),
+                phase: hit.document.phase.clone(),
+                snippet: single_line(&hit.snippet),
             })
             .collect();
 
@@ -129,6 +169,35 @@ impl SearchCommand {
     }
 }
 
+/// Tags are stored with their leading `#`; accept them with or without
+fn normalize_tag(tag: &str) -> String {
+    if tag.starts_with('#') {
+        tag.to_string()
+    } else {
+        format!("#{}", tag)
+    }
+}
+
+/// Collapse the snippet's whitespace and line breaks into single spaces
+fn single_line(snippet: &str) -> String {
+    snippet.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Render `**term**` markers as bold on terminals that support it
+fn highlight(snippet: &str) -> String {
+    snippet
+        .split("**")
+        .enumerate()
+        .map(|(i, part)| {
+            if i % 2 == 1 {
+                console::style(part).bold().to_string()
+            } else {
+                part.to_string()
+            }
+        })
+        .collect()
+}
+
 // Helper function
 fn truncate(s: &str, max_len: usize) -> String {
     if s.len() <= max_len {
@@ -148,4 +217,18 @@ mod tests {
         assert_eq!(truncate("this is a very long string", 10), "this is...");
         assert_eq!(truncate("exactly_10", 10), "exactly_10");
     }
+
+    #[test]
+    fn test_normalize_tag() {
+        assert_eq!(normalize_tag("#team/api"), "#team/api");
+        assert_eq!(normalize_tag("team/api"), "#team/api");
+    }
+
+    #[test]
+    fn test_single_line() {
+        assert_eq!(
+            single_line("rate\n  **limiting**\tdocs"),
+            "rate **limiting** docs"
+        );
+    }
 }
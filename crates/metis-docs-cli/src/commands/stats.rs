@@ -0,0 +1,253 @@
+use crate::commands::list::OutputFormat;
+use crate::output::Failure;
+use crate::workspace;
+use anyhow::Result;
+use clap::{Args, ValueEnum};
+use metis_core::application::services::QueryService;
+use metis_core::dal::database::models::GroupCount;
+use metis_core::dal::database::DocumentGroup;
+use metis_core::Database;
+use serde::Serialize;
+
+/// Field to count documents by
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum StatsGroup {
+    Type,
+    Phase,
+    /// Tags other than phase tags
+    Tag,
+    Assignee,
+}
+
+impl StatsGroup {
+    fn document_group(&self) -> DocumentGroup {
+        match self {
+            StatsGroup::Type => DocumentGroup::Type,
+            StatsGroup::Phase => DocumentGroup::Phase,
+            StatsGroup::Tag => DocumentGroup::Tag,
+            StatsGroup::Assignee => DocumentGroup::Assignee,
+        }
+    }
+}
+
+#[derive(Args)]
+pub struct StatsCommand {
+    /// Groups to count by, comma separated
+    #[arg(short, long, value_enum, value_delimiter = ',', default_values_t = [
+        StatsGroup::Type,
+        StatsGroup::Phase,
+        StatsGroup::Tag,
+        StatsGroup::Assignee,
+    ])]
+    pub group_by: Vec<StatsGroup>,
+
+    /// Output format
+    #[arg(short, long, value_enum, default_value = "table")]
+    pub format: OutputFormat,
+}
+
+/// Counts for one group
+#[derive(Debug, Serialize)]
+struct GroupStats {
+    group: &'static str,
+    counts: Vec<GroupCount>,
+}
+
+#[derive(Debug, Serialize)]
+struct StatsOutput {
+    total: i64,
+    groups: Vec<GroupStats>,
+}
+
+impl StatsCommand {
+    pub async fn execute(&self) -> Result<()> {
+        // 1. Validate we're in a metis workspace
+        let (workspace_exists, metis_dir) = workspace::has_metis_vault();
+        if !workspace_exists {
+            return Err(Failure::NotAWorkspace.into());
+        }
+        let metis_dir = metis_dir.unwrap();
+
+        // 2. Count straight from the database. This deliberately skips the
+        // sync other commands do, so it stays fast enough for shell prompts.
+        let db = Database::new(&metis_dir.join("metis.db").to_string_lossy())
+            .map_err(|e| anyhow::anyhow!("Database initialization failed: {}", e))?;
+        let stats = collect(&mut QueryService::new(db.into_repository()), &self.group_by)?;
+
+        // 3. Display results based on format
+        match self.format.or_json() {
+            OutputFormat::Table => display_table(&stats),
+            OutputFormat::Compact => {
+                for group in &stats.groups {
+                    println!("{}", compact_line(group));
+                }
+            }
+            OutputFormat::Json => match serde_json::to_string_pretty(&stats) {
+                Ok(json) => println!("{}", json),
+                Err(e) => eprintln!("Error serializing to JSON: {}", e),
+            },
+        }
+
+        Ok(())
+    }
+}
+
+fn collect(query: &mut QueryService, groups: &[StatsGroup]) -> Result<StatsOutput> {
+    let count = |query: &mut QueryService, group: DocumentGroup| {
+        query
+            .count_by(group)
+            .map_err(|e| anyhow::anyhow!("Database query failed: {}", e))
+    };
+
+    let total = count(query, DocumentGroup::Type)?
+        .iter()
+        .map(|count| count.count)
+        .sum();
+    let mut stats = StatsOutput {
+        total,
+        groups: Vec::new(),
+    };
+    for group in groups {
+        let group = group.document_group();
+        if stats
+            .groups
+            .iter()
+            .any(|stats| stats.group == group.as_str())
+        {
+            continue;
+        }
+        stats.groups.push(GroupStats {
+            group: group.as_str(),
+            counts: count(query, group)?,
+        });
+    }
+    Ok(stats)
+}
+
+/// Documents without a value, e.g. unassigned ones, are counted under "none"
+fn label(count: &GroupCount) -> &str {
+    if count.value.is_empty() {
+        "none"
+    } else {
+        &count.value
+    }
+}
+
+fn display_table(stats: &StatsOutput) {
+    println!("{} documents", stats.total);
+    for group in &stats.groups {
+        println!();
+        println!("By {}:", group.group);
+        if group.counts.is_empty() {
+            println!("  (none)");
+        }
+        let width = group
+            .counts
+            .iter()
+            .map(|count| label(count).len())
+            .max()
+            .unwrap_or(0);
+        for count in &group.counts {
+            println!("  {:<width$}  {}", label(count), count.count, width = width);
+        }
+    }
+}
+
+/// One line per group, e.g. `type task=12 initiative=3`
+fn compact_line(group: &GroupStats) -> String {
+    let mut line = group.group.to_string();
+    for count in &group.counts {
+        line.push_str(&format!(" {}={}", label(count), count.count));
+    }
+    line
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::{InitCommand, SyncCommand};
+    use tempfile::tempdir;
+
+    #[tokio::test]
+    async fn test_stats_command_no_workspace() {
+        let temp_dir = tempdir().unwrap();
+        let original_dir = std::env::current_dir().ok();
+
+        if std::env::set_current_dir(temp_dir.path()).is_err() {
+            return;
+        }
+
+        let cmd = StatsCommand {
+            group_by: vec![StatsGroup::Type],
+            format: OutputFormat::Compact,
+        };
+        let result = cmd.execute().await;
+
+        if let Some(original) = original_dir {
+            let _ = std::env::set_current_dir(&original);
+        }
+
+        assert!(result.is_err());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Not in a Metis workspace"));
+    }
+
+    #[tokio::test]
+    async fn test_stats_counts() {
+        let temp_dir = tempdir().unwrap();
+        let original_dir = std::env::current_dir().ok();
+
+        if std::env::set_current_dir(temp_dir.path()).is_err() {
+            return;
+        }
+
+        let init_cmd = InitCommand {
+            name: Some("Test Project".to_string()),
+            preset: None,
+            initiatives: None,
+            prefix: None,
+            remote: None,
+        };
+        init_cmd.execute().await.unwrap();
+        SyncCommand {
+            action: None,
+            dry_run: false,
+        }
+        .execute()
+        .await
+        .unwrap();
+
+        let mut results = Vec::new();
+        for format in [
+            OutputFormat::Table,
+            OutputFormat::Compact,
+            OutputFormat::Json,
+        ] {
+            let cmd = StatsCommand {
+                group_by: vec![StatsGroup::Type, StatsGroup::Assignee],
+                format,
+            };
+            results.push(cmd.execute().await);
+        }
+
+        let metis_dir = temp_dir.path().join(".metis");
+        let db = Database::new(&metis_dir.join("metis.db").to_string_lossy()).unwrap();
+        let stats = collect(
+            &mut QueryService::new(db.into_repository()),
+            &[StatsGroup::Type, StatsGroup::Type, StatsGroup::Assignee],
+        )
+        .unwrap();
+
+        if let Some(original) = original_dir {
+            let _ = std::env::set_current_dir(&original);
+        }
+
+        assert!(results.iter().all(|result| result.is_ok()));
+        assert_eq!(stats.total, 1);
+        assert_eq!(stats.groups.len(), 2);
+        assert_eq!(compact_line(&stats.groups[0]), "type vision=1");
+        assert_eq!(compact_line(&stats.groups[1]), "assignee none=1");
+    }
+}
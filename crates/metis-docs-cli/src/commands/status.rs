@@ -1,9 +1,18 @@
 use crate::commands::list::OutputFormat;
+use crate::commands::log::run_git;
+use crate::output::Failure;
 use crate::workspace;
 use anyhow::Result;
 use clap::Args;
+use metis_core::application::services::workspace::RemoteSyncService;
+use metis_core::dal::database::{DocumentFilter, PropertyFilter};
 use metis_core::{Application, Database, Result as MetisResult};
 use serde::Serialize;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// Phases in which a document no longer counts as overdue
+pub(crate) const DONE_PHASES: &[&str] = &["completed", "published", "decided", "superseded"];
 
 #[derive(Args)]
 pub struct StatusCommand {
@@ -14,6 +23,10 @@ pub struct StatusCommand {
     /// Output format (table, compact, json)
     #[arg(short = 'f', long, value_enum, default_value = "table")]
     pub format: OutputFormat,
+
+    /// Show a health summary (counts per type and phase, blocked and overdue items) instead of the document list
+    #[arg(long)]
+    pub summary: bool,
 }
 
 /// JSON-serializable status row for output
@@ -29,6 +42,38 @@ struct StatusOutput {
     updated: String,
 }
 
+/// Workspace health at a glance, for `--summary`
+#[derive(Serialize)]
+struct HealthSummary {
+    total: usize,
+    /// Document counts keyed by type, then phase
+    by_type: BTreeMap<String, BTreeMap<String, usize>>,
+    blocked: Vec<String>,
+    overdue: Vec<OverdueItem>,
+    sync: SyncState,
+}
+
+/// Where the workspace stands with git; `None` when unknown, e.g. outside a
+/// git repository or without an upstream branch
+#[derive(Serialize, Default)]
+struct SyncState {
+    /// Last commit that changed the workspace
+    last_sha: Option<String>,
+    /// Commits on this branch not on its upstream
+    ahead: Option<usize>,
+    /// Commits on the upstream not on this branch
+    behind: Option<usize>,
+}
+
+/// A document whose `due_date` has passed before it was finished
+#[derive(Serialize)]
+struct OverdueItem {
+    code: String,
+    title: String,
+    phase: String,
+    due_date: String,
+}
+
 impl StatusCommand {
     // Helper methods to reduce complexity
 
@@ -42,7 +87,7 @@ impl StatusCommand {
     ) -> Result<metis_core::dal::database::repository::DocumentRepository> {
         let (workspace_exists, metis_dir) = workspace::has_metis_vault();
         if !workspace_exists {
-            anyhow::bail!("Not in a Metis workspace. Run 'metis init' to create one.");
+            return Err(Failure::NotAWorkspace.into());
         }
         let metis_dir = metis_dir.unwrap();
 
@@ -106,7 +151,7 @@ impl StatusCommand {
         // 1. Validate workspace and sync before reading
         let (workspace_exists, metis_dir) = workspace::has_metis_vault();
         if !workspace_exists {
-            anyhow::bail!("Not in a Metis workspace. Run 'metis init' to create one.");
+            return Err(Failure::NotAWorkspace.into());
         }
         let metis_dir = metis_dir.unwrap();
 
@@ -126,16 +171,26 @@ impl StatusCommand {
         let mut documents = self.fetch_documents(&mut repo).await?;
         self.sort_documents_by_priority(&mut documents);
 
+        if self.summary {
+            let mut summary = self.build_summary(&mut repo, &documents)?;
+            summary.sync = sync_state(&metis_dir);
+            match self.format.or_json() {
+                OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&summary)?),
+                _ => self.display_summary(&summary),
+            }
+            return Ok(());
+        }
+
         // 5. Display results
         if documents.is_empty() {
-            match self.format {
+            match self.format.or_json() {
                 OutputFormat::Json => println!("[]"),
                 _ => println!("No documents found in workspace."),
             }
             return Ok(());
         }
 
-        match self.format {
+        match self.format.or_json() {
             OutputFormat::Table => self.display_table(&documents),
             OutputFormat::Compact => self.display_compact(&documents),
             OutputFormat::Json => self.display_json(&documents),
@@ -143,6 +198,126 @@ impl StatusCommand {
         Ok(())
     }
 
+    /// Collect counts per type/phase, blocked documents and overdue documents
+    fn build_summary(
+        &self,
+        repo: &mut metis_core::dal::database::repository::DocumentRepository,
+        documents: &[metis_core::dal::database::models::Document],
+    ) -> MetisResult<HealthSummary> {
+        let mut by_type: BTreeMap<String, BTreeMap<String, usize>> = BTreeMap::new();
+        for doc in documents {
+            *by_type
+                .entry(doc.document_type.clone())
+                .or_default()
+                .entry(doc.phase.clone())
+                .or_default() += 1;
+        }
+
+        let blocked = documents
+            .iter()
+            .filter(|doc| doc.phase == "blocked")
+            .map(|doc| doc.short_code.clone())
+            .collect();
+
+        // Due before today and not yet finished
+        let yesterday = chrono::Local::now().date_naive().pred_opt();
+        let past_due = DocumentFilter::new()
+            .include_archived(self.include_archived)
+            .property(PropertyFilter::DateRange {
+                key: "due_date".to_string(),
+                from: None,
+                to: yesterday,
+            });
+        let mut overdue = Vec::new();
+        for doc in repo.find_by_filter(&past_due)? {
+            if DONE_PHASES.contains(&doc.phase.as_str()) {
+                continue;
+            }
+            let due_date = repo
+                .find_properties(&doc.filepath)?
+                .into_iter()
+                .find(|property| property.key == "due_date")
+                .map(|property| property.value)
+                .unwrap_or_default();
+            overdue.push(OverdueItem {
+                code: doc.short_code,
+                title: doc.title,
+                phase: doc.phase,
+                due_date,
+            });
+        }
+        overdue.sort_by(|a, b| a.due_date.cmp(&b.due_date));
+
+        Ok(HealthSummary {
+            total: documents.len(),
+            by_type,
+            blocked,
+            overdue,
+            sync: SyncState::default(),
+        })
+    }
+
+    /// Display the health summary as text
+    fn display_summary(&self, summary: &HealthSummary) {
+        println!("\nWORKSPACE HEALTH\n");
+
+        for (doc_type, phases) in &summary.by_type {
+            let total: usize = phases.values().sum();
+            let breakdown: Vec<String> = phases
+                .iter()
+                .map(|(phase, count)| format!("{} {}", count, phase))
+                .collect();
+            println!(
+                "  {:<14} {:>4}  ({})",
+                doc_type,
+                total,
+                breakdown.join(", ")
+            );
+        }
+        println!("  {:<14} {:>4}", "total", summary.total);
+
+        if summary.blocked.is_empty() {
+            println!("\n[+] Nothing blocked");
+        } else {
+            println!(
+                "\n[!] {} blocked: {}",
+                summary.blocked.len(),
+                summary.blocked.join(", ")
+            );
+        }
+
+        if summary.overdue.is_empty() {
+            println!("[+] Nothing overdue");
+        } else {
+            println!("[!] {} overdue:", summary.overdue.len());
+            for item in &summary.overdue {
+                println!(
+                    "    {:<14} due {:<12} {:<10} {}",
+                    item.code,
+                    item.due_date,
+                    item.phase,
+                    self.truncate_string(&item.title, 40)
+                );
+            }
+        }
+
+        let unknown = || "unknown".to_string();
+        println!(
+            "\nLast sync:  {}",
+            summary
+                .sync
+                .last_sha
+                .as_deref()
+                .map(|sha| sha.chars().take(12).collect())
+                .unwrap_or_else(unknown)
+        );
+        println!(
+            "Remote:     {} ahead, {} behind",
+            summary.sync.ahead.map_or_else(unknown, |n| n.to_string()),
+            summary.sync.behind.map_or_else(unknown, |n| n.to_string())
+        );
+    }
+
     fn get_action_priority(&self, doc: &metis_core::dal::database::models::Document) -> u8 {
         // Lower numbers = higher priority (more actionable)
         match doc.phase.as_str() {
@@ -307,6 +482,42 @@ impl StatusCommand {
     }
 }
 
+/// The last commit that changed the workspace, and how far the branch is
+/// from its upstream as last fetched
+fn sync_state(metis_dir: &Path) -> SyncState {
+    let last_sha = RemoteSyncService::new(metis_dir)
+        .last_commit()
+        .ok()
+        .flatten()
+        .map(|commit| commit.sha);
+    let counts = run_git(
+        metis_dir,
+        &[
+            "rev-list".to_string(),
+            "--left-right".to_string(),
+            "--count".to_string(),
+            "HEAD...@{upstream}".to_string(),
+        ],
+    )
+    .ok()
+    .and_then(|output| parse_ahead_behind(&output));
+
+    SyncState {
+        last_sha,
+        ahead: counts.map(|(ahead, _)| ahead),
+        behind: counts.map(|(_, behind)| behind),
+    }
+}
+
+/// Parse `git rev-list --left-right --count` output: `<ahead>\t<behind>`
+fn parse_ahead_behind(output: &str) -> Option<(usize, usize)> {
+    let mut counts = output.split_whitespace().map(str::parse);
+    match (counts.next(), counts.next()) {
+        (Some(Ok(ahead)), Some(Ok(behind))) => Some((ahead, behind)),
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -326,6 +537,7 @@ mod tests {
         let cmd = StatusCommand {
             include_archived: false,
             format: OutputFormat::Table,
+            summary: false,
         };
 
         let result = cmd.execute().await;
@@ -357,12 +569,14 @@ mod tests {
 
             initiatives: None,
             prefix: None,
+            remote: None,
         };
         init_cmd.execute().await.unwrap();
 
         let cmd = StatusCommand {
             include_archived: false,
             format: OutputFormat::Table,
+            summary: false,
         };
 
         let result = cmd.execute().await;
@@ -376,11 +590,68 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[tokio::test]
+    async fn test_status_command_summary() {
+        let temp_dir = tempdir().unwrap();
+        let original_dir = std::env::current_dir().ok();
+
+        std::env::set_current_dir(temp_dir.path()).unwrap();
+
+        let init_cmd = InitCommand {
+            name: Some("Test Project".to_string()),
+            preset: None,
+            initiatives: None,
+            prefix: None,
+            remote: None,
+        };
+        init_cmd.execute().await.unwrap();
+
+        let table = StatusCommand {
+            include_archived: false,
+            format: OutputFormat::Table,
+            summary: true,
+        }
+        .execute()
+        .await;
+        let json = StatusCommand {
+            include_archived: false,
+            format: OutputFormat::Json,
+            summary: true,
+        }
+        .execute()
+        .await;
+
+        if let Some(original) = original_dir {
+            let _ = std::env::set_current_dir(&original);
+        }
+
+        assert!(table.is_ok(), "status --summary failed: {:?}", table);
+        assert!(json.is_ok(), "status --summary -f json failed: {:?}", json);
+    }
+
+    #[test]
+    fn test_parse_ahead_behind() {
+        assert_eq!(parse_ahead_behind("2\t5\n"), Some((2, 5)));
+        assert_eq!(parse_ahead_behind(""), None);
+        assert_eq!(parse_ahead_behind("fatal"), None);
+    }
+
+    #[test]
+    fn test_sync_state_outside_git() {
+        let temp_dir = tempdir().unwrap();
+        let state = sync_state(temp_dir.path());
+
+        assert!(state.last_sha.is_none());
+        assert!(state.ahead.is_none());
+        assert!(state.behind.is_none());
+    }
+
     #[test]
     fn test_action_priority() {
         let cmd = StatusCommand {
             include_archived: false,
             format: OutputFormat::Table,
+            summary: false,
         };
 
         // Create mock documents with different phases
@@ -400,6 +671,7 @@ mod tests {
             initiative_id: Some("test-initiative".to_string()),
             short_code: "TEST-T-0001".to_string(),
             parent_id: None,
+            workspace: String::new(),
         };
 
         let todo_doc = metis_core::dal::database::models::Document {
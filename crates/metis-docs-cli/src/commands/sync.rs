@@ -1,23 +1,95 @@
+use crate::commands::config::{load_config_file, update_config_file};
+use crate::output::{say, Failure};
 use crate::workspace;
 use anyhow::Result;
-use clap::Args;
+use clap::{Args, Subcommand};
+use metis_core::application::services::workspace::{Change, RemoteSyncService};
+use metis_core::domain::configuration::{
+    ConfigFile, ConfigurationError, RemoteEntry, SyncConfig, PRIMARY_REMOTE,
+};
 use metis_core::{Application, Database};
+use std::collections::BTreeMap;
+use std::path::Path;
 
 #[derive(Args)]
-pub struct SyncCommand {}
+pub struct SyncCommand {
+    #[command(subcommand)]
+    pub action: Option<SyncAction>,
+
+    /// Show which documents would be pulled, pushed or conflict with the
+    /// remote, without changing anything; exits non-zero on conflicts
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
+#[derive(Subcommand)]
+pub enum SyncAction {
+    /// Manage the remotes this workspace syncs with
+    Remote {
+        #[command(subcommand)]
+        action: RemoteAction,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum RemoteAction {
+    /// Add a remote, or replace one with the same name. `origin` is the
+    /// workspace's own remote; other names are sibling workspaces.
+    Add {
+        /// Remote name
+        name: String,
+        /// Git URL (https://, ssh://, file:// or git@host:path)
+        url: String,
+        /// Workspace prefix for a sibling's documents; defaults to the name
+        #[arg(long)]
+        workspace: Option<String>,
+        /// Branch to sync; defaults to the current or default branch
+        #[arg(long)]
+        branch: Option<String>,
+    },
+    /// Remove a remote
+    Remove {
+        /// Remote name
+        name: String,
+    },
+    /// List the configured remotes
+    List,
+    /// Change the branch a remote syncs
+    SetBranch {
+        /// Remote name
+        name: String,
+        /// Branch to sync; omit to go back to the default
+        branch: Option<String>,
+    },
+}
+
+/// A colored one-letter marker for how a document changed
+fn marker(change: Change) -> String {
+    match change {
+        Change::Added => console::style("A").green().to_string(),
+        Change::Modified => console::style("M").yellow().to_string(),
+        Change::Deleted => console::style("D").red().to_string(),
+    }
+}
 
 impl SyncCommand {
     pub async fn execute(&self) -> Result<()> {
         // Check if we're in a workspace
         let (workspace_exists, metis_dir) = workspace::has_metis_vault();
         if !workspace_exists {
-            anyhow::bail!("Not in a Metis workspace. Run 'metis init' to create one.");
+            return Err(Failure::NotAWorkspace.into());
         }
 
         let metis_dir = metis_dir.unwrap();
+        if let Some(SyncAction::Remote { action }) = &self.action {
+            return remote(&metis_dir, action);
+        }
+        if self.dry_run {
+            return dry_run(&metis_dir);
+        }
         let workspace_root = &metis_dir;
 
-        println!("Syncing workspace: {}", workspace_root.display());
+        say!("Syncing workspace: {}", workspace_root.display());
 
         // Initialize application with database
         let db_path = metis_dir.join("metis.db");
@@ -40,31 +112,31 @@ impl SyncCommand {
                 metis_core::application::services::synchronization::SyncResult::Imported {
                     filepath,
                 } => {
-                    println!("[+] Imported: {}", filepath);
+                    say!("[+] Imported: {}", filepath);
                     imported += 1;
                 }
                 metis_core::application::services::synchronization::SyncResult::Updated {
                     filepath,
                 } => {
-                    println!("[+] Updated: {}", filepath);
+                    say!("[+] Updated: {}", filepath);
                     updated += 1;
                 }
                 metis_core::application::services::synchronization::SyncResult::Deleted {
                     filepath,
                 } => {
-                    println!("[+] Deleted: {}", filepath);
+                    say!("[+] Deleted: {}", filepath);
                     deleted += 1;
                 }
                 metis_core::application::services::synchronization::SyncResult::UpToDate {
                     filepath,
                 } => {
-                    println!("[.] Up to date: {}", filepath);
+                    say!("[.] Up to date: {}", filepath);
                     up_to_date += 1;
                 }
                 metis_core::application::services::synchronization::SyncResult::NotFound {
                     filepath,
                 } => {
-                    println!("[?] Not found: {}", filepath);
+                    say!("[?] Not found: {}", filepath);
                 }
                 metis_core::application::services::synchronization::SyncResult::Error {
                     filepath,
@@ -77,7 +149,7 @@ impl SyncCommand {
                     from,
                     to,
                 } => {
-                    println!("[>] Moved: {} -> {}", from, to);
+                    say!("[>] Moved: {} -> {}", from, to);
                     updated += 1;
                 }
                 metis_core::application::services::synchronization::SyncResult::Renumbered {
@@ -85,22 +157,24 @@ impl SyncCommand {
                     old_short_code,
                     new_short_code,
                 } => {
-                    println!(
+                    say!(
                         "[!] Renumbered: {} ({} -> {})",
-                        filepath, old_short_code, new_short_code
+                        filepath,
+                        old_short_code,
+                        new_short_code
                     );
                     updated += 1;
                 }
             }
         }
 
-        println!("\nSync complete:");
-        println!("  Imported: {}", imported);
-        println!("  Updated: {}", updated);
-        println!("  Deleted: {}", deleted);
-        println!("  Up to date: {}", up_to_date);
+        say!("\nSync complete:");
+        say!("  Imported: {}", imported);
+        say!("  Updated: {}", updated);
+        say!("  Deleted: {}", deleted);
+        say!("  Up to date: {}", up_to_date);
         if errors > 0 {
-            println!("  Errors: {}", errors);
+            say!("  Errors: {}", errors);
         }
 
         if errors > 0 {
@@ -111,6 +185,110 @@ impl SyncCommand {
     }
 }
 
+/// Edit the remotes in config.toml
+fn remote(metis_dir: &Path, action: &RemoteAction) -> Result<()> {
+    match action {
+        RemoteAction::Add {
+            name,
+            url,
+            workspace,
+            branch,
+        } => {
+            edit_sync(metis_dir, |sync| {
+                sync.add_remote(name, url, workspace.as_deref(), branch.as_deref())
+            })?;
+            say!("✓ Added remote {} ({})", name, url.trim());
+        }
+        RemoteAction::Remove { name } => {
+            edit_sync(metis_dir, |sync| sync.remove_remote(name))?;
+            say!("✓ Removed remote {}", name);
+        }
+        RemoteAction::List => {
+            let config = load_config_file(metis_dir)?;
+            let entries = config.sync().entries();
+            if entries.is_empty() {
+                say!("No remotes configured. Add one with 'metis sync remote add'.");
+            }
+            for entry in entries {
+                println!("{}", remote_line(&entry));
+            }
+        }
+        RemoteAction::SetBranch { name, branch } => {
+            edit_sync(metis_dir, |sync| sync.set_branch(name, branch.as_deref()))?;
+            match branch {
+                Some(branch) => say!("✓ {} now syncs {}", name, branch),
+                None => say!("✓ {} now syncs its default branch", name),
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Change the `[sync]` settings in config.toml
+fn edit_sync(
+    metis_dir: &Path,
+    change: impl FnOnce(&mut SyncConfig) -> Result<(), ConfigurationError>,
+) -> Result<()> {
+    update_config_file(metis_dir, |config| {
+        change(&mut config.sync).map_err(|e| anyhow::anyhow!("{}", e))
+    })
+}
+
+fn remote_line(entry: &RemoteEntry) -> String {
+    let workspace = match &entry.workspace {
+        Some(workspace) => format!("@{}", workspace),
+        None if entry.name == PRIMARY_REMOTE => "(this workspace)".to_string(),
+        None => "-".to_string(),
+    };
+    format!(
+        "{:<12} {:<18} {:<12} {}",
+        entry.name,
+        workspace,
+        entry.branch.as_deref().unwrap_or("(default)"),
+        entry.url
+    )
+}
+
+/// Fetch, then print the plan for syncing with the remote without touching
+/// the working tree or the remote
+fn dry_run(metis_dir: &Path) -> Result<()> {
+    let sync = ConfigFile::load(metis_dir.join("config.toml"))
+        .map(|config| config.sync().clone())
+        .unwrap_or_default();
+
+    let (plan, label) = RemoteSyncService::new(metis_dir).plan(&sync, |_| {})?;
+    println!("Sync plan against {} (dry run, nothing changed):", label);
+    if plan.is_empty() {
+        println!("  Already in sync");
+        return Ok(());
+    }
+    print_section("Pull", &plan.pull);
+    print_section("Push", &plan.push);
+    if !plan.conflicts.is_empty() {
+        println!("\nConflicts ({}):", plan.conflicts.len());
+        for (path, (theirs, ours)) in &plan.conflicts {
+            println!("  {}/{} {}", marker(*theirs), marker(*ours), path);
+        }
+        return Err(Failure::Conflict(format!(
+            "{} document(s) changed both locally and on the remote",
+            plan.conflicts.len()
+        ))
+        .into());
+    }
+
+    Ok(())
+}
+
+fn print_section(title: &str, changes: &BTreeMap<String, Change>) {
+    if changes.is_empty() {
+        return;
+    }
+    println!("\n{} ({}):", title, changes.len());
+    for (path, change) in changes {
+        println!("  {} {}", marker(*change), path);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -126,7 +304,10 @@ mod tests {
         // Change to temp directory without workspace
         std::env::set_current_dir(temp_dir.path()).unwrap();
 
-        let cmd = SyncCommand {};
+        let cmd = SyncCommand {
+            action: None,
+            dry_run: false,
+        };
         let result = cmd.execute().await;
 
         assert!(result.is_err());
@@ -156,11 +337,15 @@ mod tests {
 
             initiatives: None,
             prefix: None,
+            remote: None,
         };
         init_cmd.execute().await.unwrap();
 
         // Run sync command
-        let cmd = SyncCommand {};
+        let cmd = SyncCommand {
+            action: None,
+            dry_run: false,
+        };
         let result = cmd.execute().await;
 
         // The command should succeed and sync the vision.md created by init
@@ -171,4 +356,107 @@ mod tests {
             let _ = std::env::set_current_dir(&original);
         }
     }
+
+    #[tokio::test]
+    async fn test_sync_dry_run_without_remote() {
+        let temp_dir = tempdir().unwrap();
+        let original_dir = std::env::current_dir().ok();
+
+        std::env::set_current_dir(temp_dir.path()).unwrap();
+
+        let init_cmd = InitCommand {
+            name: Some("Test Project".to_string()),
+            preset: None,
+            initiatives: None,
+            prefix: None,
+            remote: None,
+        };
+        init_cmd.execute().await.unwrap();
+        let result = SyncCommand {
+            action: None,
+            dry_run: true,
+        }
+        .execute()
+        .await;
+
+        if let Some(original) = original_dir {
+            let _ = std::env::set_current_dir(&original);
+        }
+
+        // Without a git remote there's nothing to plan against
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_sync_remote_management() {
+        let temp_dir = tempdir().unwrap();
+        let original_dir = std::env::current_dir().ok();
+
+        std::env::set_current_dir(temp_dir.path()).unwrap();
+
+        let init_cmd = InitCommand {
+            name: Some("Test Project".to_string()),
+            preset: None,
+            initiatives: None,
+            prefix: None,
+            remote: None,
+        };
+        init_cmd.execute().await.unwrap();
+
+        let run = |action: RemoteAction| SyncCommand {
+            action: Some(SyncAction::Remote { action }),
+            dry_run: false,
+        };
+        let add = |name: &str, url: &str, workspace: Option<&str>| RemoteAction::Add {
+            name: name.to_string(),
+            url: url.to_string(),
+            workspace: workspace.map(str::to_string),
+            branch: None,
+        };
+        let results = vec![
+            run(add("origin", "git@github.com:org/plans.git", None))
+                .execute()
+                .await,
+            run(add(
+                "ops",
+                "https://github.com/org/ops.git",
+                Some("platform"),
+            ))
+            .execute()
+            .await,
+            run(add("web", "not a url", None)).execute().await,
+            run(RemoteAction::SetBranch {
+                name: "origin".to_string(),
+                branch: Some("main".to_string()),
+            })
+            .execute()
+            .await,
+            run(RemoteAction::List).execute().await,
+            run(RemoteAction::Remove {
+                name: "web".to_string(),
+            })
+            .execute()
+            .await,
+        ];
+        let config = ConfigFile::load(temp_dir.path().join(".metis").join("config.toml"));
+
+        if let Some(original) = original_dir {
+            let _ = std::env::set_current_dir(&original);
+        }
+
+        let ok: Vec<bool> = results.iter().map(Result::is_ok).collect();
+        assert_eq!(ok, vec![true, true, false, true, true, false]);
+        let config = config.unwrap();
+        let sync = config.sync();
+        assert_eq!(sync.remote.as_deref(), Some("git@github.com:org/plans.git"));
+        assert_eq!(sync.branch.as_deref(), Some("main"));
+        assert_eq!(sync.remotes["ops"].workspace.as_deref(), Some("platform"));
+
+        let entries = sync.entries();
+        let origin = remote_line(&entries[0]);
+        assert!(origin.starts_with("origin"));
+        assert!(origin.contains("(this workspace)"));
+        assert!(origin.contains(" main "));
+        assert!(remote_line(&entries[1]).contains("@platform"));
+    }
 }
@@ -0,0 +1,246 @@
+use crate::output::{say, Failure};
+use crate::workspace;
+use anyhow::Result;
+use clap::{Args, Subcommand};
+use metis_core::application::services::workspace::TagService;
+use metis_core::application::services::{DatabaseService, QueryService};
+use metis_core::dal::database::models::TagCount;
+use metis_core::Database;
+use std::path::PathBuf;
+
+#[derive(Args)]
+pub struct TagCommand {
+    #[command(subcommand)]
+    pub action: TagAction,
+}
+
+#[derive(Subcommand)]
+pub enum TagAction {
+    /// Add tags to a document
+    Add {
+        /// Document short code (e.g., PROJ-T-0001)
+        short_code: String,
+        /// Tags to add, with or without the leading '#'
+        #[arg(required = true)]
+        tags: Vec<String>,
+    },
+    /// Remove tags from a document
+    Remove {
+        /// Document short code (e.g., PROJ-T-0001)
+        short_code: String,
+        /// Tags to remove, with or without the leading '#'
+        #[arg(required = true)]
+        tags: Vec<String>,
+    },
+    /// List the tags on a document
+    List {
+        /// Document short code (e.g., PROJ-T-0001)
+        short_code: String,
+    },
+}
+
+#[derive(Args)]
+pub struct TagsCommand {
+    /// Include phase tags (#phase/...)
+    #[arg(long)]
+    pub all: bool,
+}
+
+impl TagCommand {
+    pub async fn execute(&self) -> Result<()> {
+        let (metis_dir, _, mut db_service) = open_workspace().await?;
+        let service = TagService::new(&metis_dir);
+
+        match &self.action {
+            TagAction::Add { short_code, tags } => {
+                let tags = normalize(tags)?;
+                let mut added = Vec::new();
+                for tag in &tags {
+                    if service
+                        .add_tag(short_code, tag, &mut db_service)
+                        .map_err(|e| anyhow::anyhow!("{}", e))?
+                    {
+                        added.push(tag.as_str());
+                    } else {
+                        say!("- {} already has {}", short_code, tag);
+                    }
+                }
+                if !added.is_empty() {
+                    workspace::sync(&metis_dir, &mut db_service).await?;
+                    say!("✓ Tagged {} with {}", short_code, added.join(", "));
+                }
+            }
+            TagAction::Remove { short_code, tags } => {
+                let tags = normalize(tags)?;
+                let mut removed = Vec::new();
+                for tag in &tags {
+                    if service
+                        .remove_tag(short_code, tag, &mut db_service)
+                        .map_err(|e| anyhow::anyhow!("{}", e))?
+                    {
+                        removed.push(tag.as_str());
+                    } else {
+                        say!("- {} doesn't have {}", short_code, tag);
+                    }
+                }
+                if !removed.is_empty() {
+                    workspace::sync(&metis_dir, &mut db_service).await?;
+                    say!("✓ Removed {} from {}", removed.join(", "), short_code);
+                }
+            }
+            TagAction::List { short_code } => {
+                let tags = service
+                    .tags(short_code, &mut db_service)
+                    .map_err(|e| anyhow::anyhow!("{}", e))?;
+                if tags.is_empty() {
+                    println!("{} has no tags", short_code);
+                }
+                for tag in tags {
+                    println!("{}", tag);
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl TagsCommand {
+    pub async fn execute(&self) -> Result<()> {
+        let (_, db, _) = open_workspace().await?;
+
+        let counts = QueryService::new(db.into_repository())
+            .tag_counts()
+            .map_err(|e| anyhow::anyhow!("Database query failed: {}", e))?;
+        let counts: Vec<TagCount> = counts
+            .into_iter()
+            .filter(|count| self.all || !count.tag.starts_with("#phase/"))
+            .collect();
+        if counts.is_empty() {
+            println!("No tags in use.");
+            return Ok(());
+        }
+
+        let width = counts
+            .iter()
+            .map(|count| count.count.to_string().len())
+            .max()
+            .unwrap_or(1);
+        for count in &counts {
+            println!("{:>width$}  {}", count.count, count.tag, width = width);
+        }
+
+        Ok(())
+    }
+}
+
+/// Validate every tag up front so a bad one doesn't leave a partial update
+fn normalize(tags: &[String]) -> Result<Vec<String>> {
+    tags.iter()
+        .map(|tag| TagService::normalize(tag).map_err(|e| anyhow::anyhow!("{}", e)))
+        .collect()
+}
+
+/// Sync, then open the database
+async fn open_workspace() -> Result<(PathBuf, Database, DatabaseService)> {
+    let (workspace_exists, metis_dir) = workspace::has_metis_vault();
+    if !workspace_exists {
+        return Err(Failure::NotAWorkspace.into());
+    }
+    let metis_dir = metis_dir.unwrap();
+
+    let db = Database::new(&metis_dir.join("metis.db").to_string_lossy())
+        .map_err(|e| anyhow::anyhow!("Database initialization failed: {}", e))?;
+    let repository = db
+        .repository()
+        .map_err(|e| anyhow::anyhow!("Database initialization failed: {}", e))?;
+    let mut db_service = DatabaseService::new(repository);
+    workspace::sync(&metis_dir, &mut db_service).await?;
+
+    Ok((metis_dir, db, db_service))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::InitCommand;
+    use tempfile::tempdir;
+
+    async fn run(action: TagAction) -> Result<()> {
+        TagCommand { action }.execute().await
+    }
+
+    #[tokio::test]
+    async fn test_tag_command_no_workspace() {
+        let temp_dir = tempdir().unwrap();
+        let original_dir = std::env::current_dir().ok();
+
+        if std::env::set_current_dir(temp_dir.path()).is_err() {
+            return;
+        }
+
+        let result = TagsCommand { all: false }.execute().await;
+
+        if let Some(original) = original_dir {
+            let _ = std::env::set_current_dir(&original);
+        }
+
+        assert!(result.is_err());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Not in a Metis workspace"));
+    }
+
+    #[tokio::test]
+    async fn test_tag_add_and_remove() {
+        let temp_dir = tempdir().unwrap();
+        let original_dir = std::env::current_dir().ok();
+
+        if std::env::set_current_dir(temp_dir.path()).is_err() {
+            return;
+        }
+
+        let init_cmd = InitCommand {
+            name: Some("Test Project".to_string()),
+            preset: None,
+            initiatives: None,
+            prefix: None,
+            remote: None,
+        };
+        init_cmd.execute().await.unwrap();
+
+        let added = run(TagAction::Add {
+            short_code: "TEST-V-0001".to_string(),
+            tags: vec!["strategy".to_string(), "#q3".to_string()],
+        })
+        .await;
+        let phase = run(TagAction::Add {
+            short_code: "TEST-V-0001".to_string(),
+            tags: vec!["#phase/published".to_string()],
+        })
+        .await;
+        let content =
+            std::fs::read_to_string(temp_dir.path().join(".metis").join("vision.md")).unwrap();
+        let removed = run(TagAction::Remove {
+            short_code: "TEST-V-0001".to_string(),
+            tags: vec!["q3".to_string()],
+        })
+        .await;
+        let counts = TagsCommand { all: true }.execute().await;
+        let after =
+            std::fs::read_to_string(temp_dir.path().join(".metis").join("vision.md")).unwrap();
+
+        if let Some(original) = original_dir {
+            let _ = std::env::set_current_dir(&original);
+        }
+
+        assert!(added.is_ok());
+        assert!(phase.is_err());
+        assert!(content.contains("  - \"#strategy\"\n  - \"#q3\"\n"));
+        assert!(removed.is_ok());
+        assert!(counts.is_ok());
+        assert!(after.contains("#strategy"));
+        assert!(!after.contains("#q3"));
+    }
+}
@@ -0,0 +1,195 @@
+use crate::output::{say, Failure};
+use crate::workspace;
+use anyhow::Result;
+use clap::{Args, Subcommand};
+use metis_core::application::services::{
+    TemplateLoader, TemplateSource, TemplateType, TEMPLATE_DOCUMENT_TYPES,
+};
+
+#[derive(Args)]
+pub struct TemplateCommand {
+    #[command(subcommand)]
+    pub action: TemplateAction,
+}
+
+#[derive(Subcommand)]
+pub enum TemplateAction {
+    /// List each document type's templates, where they come from and whether they render
+    List,
+    /// Print the template used for a document type
+    Show {
+        /// Document type (vision, initiative, task, adr, specification)
+        doc_type: String,
+        /// Show the exit criteria template instead of the content template
+        #[arg(long)]
+        exit_criteria: bool,
+    },
+    /// Copy the built-in templates for a document type into .metis/templates/ for editing
+    Override {
+        /// Document type (vision, initiative, task, adr, specification)
+        doc_type: String,
+        /// Replace existing overrides with the built-in templates
+        #[arg(long)]
+        force: bool,
+    },
+}
+
+const TEMPLATE_TYPES: [TemplateType; 2] = [TemplateType::Content, TemplateType::ExitCriteria];
+
+impl TemplateCommand {
+    pub async fn execute(&self) -> Result<()> {
+        // Global and built-in templates are available outside a workspace too
+        let (workspace_exists, metis_dir) = workspace::has_metis_vault();
+        let loader = TemplateLoader::new(metis_dir.filter(|_| workspace_exists));
+
+        match &self.action {
+            TemplateAction::List => {
+                for doc_type in TEMPLATE_DOCUMENT_TYPES {
+                    for template_type in TEMPLATE_TYPES {
+                        println!("{}", list_line(&loader, doc_type, template_type));
+                    }
+                }
+            }
+            TemplateAction::Show {
+                doc_type,
+                exit_criteria,
+            } => {
+                validate_doc_type(doc_type)?;
+                let template_type = if *exit_criteria {
+                    TemplateType::ExitCriteria
+                } else {
+                    TemplateType::Content
+                };
+                let template = loader.load_template(doc_type, template_type).map_err(|e| {
+                    anyhow::anyhow!(
+                        "{} ({})",
+                        e,
+                        loader.template_source(doc_type, template_type)
+                    )
+                })?;
+                print!("{}", template);
+            }
+            TemplateAction::Override { doc_type, force } => {
+                if !workspace_exists {
+                    return Err(Failure::NotAWorkspace.into());
+                }
+                validate_doc_type(doc_type)?;
+                for template_type in TEMPLATE_TYPES {
+                    let existing = loader
+                        .project_template_path(doc_type, template_type)
+                        .filter(|path| path.exists());
+                    if let (Some(path), false) = (existing, *force) {
+                        say!(
+                            "- {} already exists (use --force to replace it)",
+                            path.display()
+                        );
+                        continue;
+                    }
+                    let path = loader
+                        .override_template(doc_type, template_type, *force)
+                        .map_err(|e| anyhow::anyhow!("{}", e))?;
+                    say!("✓ Created {}", path.display());
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn validate_doc_type(doc_type: &str) -> Result<()> {
+    if !TEMPLATE_DOCUMENT_TYPES.contains(&doc_type) {
+        anyhow::bail!(
+            "Unknown document type '{}'. Expected one of: {}",
+            doc_type,
+            TEMPLATE_DOCUMENT_TYPES.join(", ")
+        );
+    }
+    Ok(())
+}
+
+/// One row of `metis template list`; custom templates are rendered with
+/// sample data so broken overrides show up before they're used
+fn list_line(loader: &TemplateLoader, doc_type: &str, template_type: TemplateType) -> String {
+    let source = loader.template_source(doc_type, template_type);
+    let status = match (&source, loader.load_template(doc_type, template_type)) {
+        (TemplateSource::Embedded, _) => String::new(),
+        (_, Ok(_)) => "  ✓".to_string(),
+        (_, Err(e)) => format!("  ✗ {}", e),
+    };
+    format!(
+        "{:<14} {:<14} {}{}",
+        doc_type,
+        template_type.filename(),
+        source,
+        status
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::InitCommand;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_list_line() {
+        let temp_dir = tempdir().unwrap();
+        let loader = TemplateLoader::for_workspace(temp_dir.path());
+        let template_dir = temp_dir.path().join("templates").join("task");
+        std::fs::create_dir_all(&template_dir).unwrap();
+        std::fs::write(template_dir.join("content.md"), "# {{ title }\n").unwrap();
+
+        let line = list_line(&loader, "task", TemplateType::Content);
+        assert!(line.starts_with("task"));
+        assert!(line.contains("project:"));
+        assert!(line.contains("✗ Template parse error"));
+
+        assert!(validate_doc_type("task").is_ok());
+        assert!(validate_doc_type("epic").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_template_override() {
+        let temp_dir = tempdir().unwrap();
+        let original_dir = std::env::current_dir().ok();
+
+        if std::env::set_current_dir(temp_dir.path()).is_err() {
+            return;
+        }
+
+        let init_cmd = InitCommand {
+            name: Some("Test Project".to_string()),
+            preset: None,
+            initiatives: None,
+            prefix: None,
+            remote: None,
+        };
+        init_cmd.execute().await.unwrap();
+
+        let run = |doc_type: &str, force| TemplateCommand {
+            action: TemplateAction::Override {
+                doc_type: doc_type.to_string(),
+                force,
+            },
+        };
+        let created = run("task", false).execute().await;
+        let repeated = run("task", false).execute().await;
+        let unknown = run("epic", false).execute().await;
+
+        if let Some(original) = original_dir {
+            let _ = std::env::set_current_dir(&original);
+        }
+
+        assert!(created.is_ok());
+        assert!(repeated.is_ok());
+        assert!(unknown.is_err());
+        let template_dir = temp_dir
+            .path()
+            .join(".metis")
+            .join("templates")
+            .join("task");
+        assert!(template_dir.join("content.md").exists());
+        assert!(template_dir.join("exit_criteria.md").exists());
+    }
+}
@@ -1,10 +1,13 @@
+use crate::output::{say, Failure};
 use crate::workspace;
 use anyhow::Result;
 use clap::Args;
+use dialoguer::Confirm;
 use metis_core::{
-    application::services::workspace::PhaseTransitionService, domain::documents::types::Phase,
-    Application, Database,
+    application::services::workspace::{CascadePlan, CascadeService, PhaseTransitionService},
+    domain::documents::types::{DocumentType, Phase},
 };
+use std::path::Path;
 
 #[derive(Args)]
 pub struct TransitionCommand {
@@ -13,6 +16,14 @@ pub struct TransitionCommand {
 
     /// Target phase to transition to (optional - if not provided, transitions to next phase)
     pub phase: Option<String>,
+
+    /// Also transition dependent documents (e.g. complete an initiative's open tasks)
+    #[arg(long)]
+    pub cascade: bool,
+
+    /// Apply cascaded transitions without asking for confirmation
+    #[arg(short = 'y', long, requires = "cascade")]
+    pub yes: bool,
 }
 
 impl TransitionCommand {
@@ -20,16 +31,37 @@ impl TransitionCommand {
         // 1. Validate we're in a metis workspace
         let (workspace_exists, metis_dir) = workspace::has_metis_vault();
         if !workspace_exists {
-            anyhow::bail!("Not in a Metis workspace. Run 'metis init' to create one.");
+            return Err(Failure::NotAWorkspace.into());
         }
         let metis_dir = metis_dir.unwrap();
 
         // 2. Create the phase transition service
         let transition_service = PhaseTransitionService::new(&metis_dir);
 
-        // 3. Perform the transition
-        let result = if let Some(phase_str) = &self.phase {
-            let target_phase = self.parse_phase(phase_str)?;
+        // 3. Preview dependent transitions before changing anything
+        let target_phase = match &self.phase {
+            Some(phase_str) => Some(self.parse_phase(phase_str)?),
+            None => None,
+        };
+        let cascade_service = CascadeService::new(&metis_dir);
+        let cascade_plan = if self.cascade {
+            let plan = self
+                .plan_cascade(&metis_dir, &cascade_service, target_phase)
+                .await?;
+            if !plan.is_empty() {
+                print_cascade_plan(&plan);
+                if !plan.steps.is_empty() && !self.yes && !confirm_cascade(plan.steps.len())? {
+                    say!("Transition cancelled.");
+                    return Ok(());
+                }
+            }
+            Some(plan)
+        } else {
+            None
+        };
+
+        // 4. Perform the transition
+        let result = if let Some(target_phase) = target_phase {
             transition_service
                 .transition_document(&self.short_code, target_phase)
                 .await?
@@ -39,24 +71,80 @@ impl TransitionCommand {
                 .await?
         };
 
-        // 4. Report success
-        println!(
+        // 5. Report success
+        say!(
             "[+] Transitioned {} '{}' from {} to {}",
-            result.document_type, result.document_id, result.from_phase, result.to_phase
+            result.document_type,
+            result.document_id,
+            result.from_phase,
+            result.to_phase
         );
 
-        // 5. Auto-sync workspace after transition
-        let db_path = metis_dir.join("metis.db");
-        let database = Database::new(db_path.to_str().unwrap())
-            .map_err(|e| anyhow::anyhow!("Failed to open database for sync: {}", e))?;
-        let app = Application::new(database);
-        app.sync_directory(&metis_dir)
-            .await
-            .map_err(|e| anyhow::anyhow!("Failed to sync workspace: {}", e))?;
+        // 6. Apply the cascade
+        if let Some(plan) = &cascade_plan {
+            for step in cascade_service.apply(plan).await? {
+                say!(
+                    "[+] Transitioned {} '{}' from {} to {}",
+                    step.document_type,
+                    step.document_id,
+                    step.from_phase,
+                    step.to_phase
+                );
+            }
+        }
+
+        // 7. Auto-sync workspace after transition
+        let mut db_service = workspace::open_synced(&metis_dir).await?;
+
+        // 8. Point out dependents left behind when not cascading
+        if cascade_plan.is_none() {
+            let open = cascade_service
+                .plan(&self.short_code, result.to_phase, &mut db_service)
+                .map(|plan| plan.steps.len() + plan.stuck.len())
+                .unwrap_or(0);
+            if open > 0 {
+                say!(
+                    "Note: {} dependent document(s) are not {}. Use --cascade to transition them too.",
+                    open, result.to_phase
+                );
+            }
+        }
 
         Ok(())
     }
 
+    /// Plan the cascade for the requested (or next) phase of the document
+    async fn plan_cascade(
+        &self,
+        metis_dir: &Path,
+        cascade_service: &CascadeService,
+        target_phase: Option<Phase>,
+    ) -> Result<CascadePlan> {
+        let mut db_service = workspace::open_synced(metis_dir).await?;
+
+        let target_phase = match target_phase {
+            Some(phase) => phase,
+            None => {
+                let doc = db_service
+                    .find_by_short_code(&self.short_code)
+                    .map_err(|e| anyhow::anyhow!("{}", e))?
+                    .ok_or_else(|| anyhow::anyhow!("Document '{}' not found", self.short_code))?;
+                let doc_type: DocumentType = doc
+                    .document_type
+                    .parse()
+                    .map_err(|e: String| anyhow::anyhow!(e))?;
+                let current = self.parse_phase(&doc.phase)?;
+                doc_type.next_phase(current).ok_or_else(|| {
+                    anyhow::anyhow!("{} '{}' has no next phase", doc_type, self.short_code)
+                })?
+            }
+        };
+
+        cascade_service
+            .plan(&self.short_code, target_phase, &mut db_service)
+            .map_err(|e| anyhow::anyhow!("{}", e))
+    }
+
     fn parse_phase(&self, phase_str: &str) -> Result<Phase> {
         match phase_str.to_lowercase().as_str() {
             "draft" => Ok(Phase::Draft),
@@ -79,6 +167,35 @@ impl TransitionCommand {
     }
 }
 
+fn print_cascade_plan(plan: &CascadePlan) {
+    println!("Cascaded transitions:");
+    for step in &plan.steps {
+        let path: Vec<String> = step.path.iter().map(|p| p.to_string()).collect();
+        println!(
+            "  {} {} '{}': {} -> {}",
+            step.document_type,
+            step.short_code,
+            step.title,
+            step.from_phase,
+            path.join(" -> ")
+        );
+    }
+    for short_code in &plan.stuck {
+        println!(
+            "  {} cannot reach the target phase and is left as is",
+            short_code
+        );
+    }
+}
+
+fn confirm_cascade(count: usize) -> Result<bool> {
+    Confirm::new()
+        .with_prompt(format!("Apply {} cascaded transition(s)?", count))
+        .default(false)
+        .interact()
+        .map_err(|e| anyhow::anyhow!("Confirmation failed ({}); pass --yes to skip it", e))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -91,6 +208,8 @@ mod tests {
         let cmd = TransitionCommand {
             short_code: "test".to_string(),
             phase: Some("active".to_string()),
+            cascade: false,
+            yes: false,
         };
 
         assert_eq!(cmd.parse_phase("draft").unwrap(), Phase::Draft);
@@ -112,6 +231,8 @@ mod tests {
         let cmd = TransitionCommand {
             short_code: "test-doc".to_string(),
             phase: Some("active".to_string()),
+            cascade: false,
+            yes: false,
         };
 
         let result = cmd.execute().await;
@@ -143,12 +264,15 @@ mod tests {
 
             initiatives: None,
             prefix: None,
+            remote: None,
         };
         init_cmd.execute().await.unwrap();
 
         let cmd = TransitionCommand {
             short_code: "TEST-T-9999".to_string(),
             phase: Some("active".to_string()),
+            cascade: false,
+            yes: false,
         };
 
         let result = cmd.execute().await;
@@ -177,6 +301,7 @@ mod tests {
 
             initiatives: None,
             prefix: None,
+            remote: None,
         };
         init_cmd.execute().await.unwrap();
 
@@ -191,6 +316,8 @@ mod tests {
         let cmd = TransitionCommand {
             short_code: short_code.clone(),
             phase: None, // Auto transition
+            cascade: false,
+            yes: false,
         };
         cmd.execute().await.unwrap();
 
@@ -201,6 +328,8 @@ mod tests {
         let cmd = TransitionCommand {
             short_code: short_code.clone(),
             phase: None, // Auto transition
+            cascade: false,
+            yes: false,
         };
         cmd.execute().await.unwrap();
 
@@ -211,6 +340,8 @@ mod tests {
         let cmd = TransitionCommand {
             short_code: short_code.clone(),
             phase: None, // Auto transition
+            cascade: false,
+            yes: false,
         };
         let result = cmd.execute().await;
         assert!(result.is_err()); // Should fail as Published is final
@@ -236,6 +367,7 @@ mod tests {
 
             initiatives: None,
             prefix: None,
+            remote: None,
         };
         init_cmd.execute().await.unwrap();
 
@@ -261,6 +393,8 @@ mod tests {
             let cmd = TransitionCommand {
                 short_code: short_code.clone(),
                 phase: None, // Auto transition
+                cascade: false,
+                yes: false,
             };
             cmd.execute().await.unwrap();
         }
@@ -286,6 +420,7 @@ mod tests {
 
             initiatives: None,
             prefix: None,
+            remote: None,
         };
         init_cmd.execute().await.unwrap();
 
@@ -304,6 +439,8 @@ mod tests {
             let cmd = TransitionCommand {
                 short_code: "TEST-I-0001".to_string(),
                 phase: None,
+                cascade: false,
+                yes: false,
             };
             cmd.execute().await.unwrap();
         }
@@ -328,6 +465,8 @@ mod tests {
         let cmd = TransitionCommand {
             short_code: short_code.clone(),
             phase: Some("active".to_string()),
+            cascade: false,
+            yes: false,
         };
         cmd.execute().await.unwrap();
 
@@ -335,6 +474,8 @@ mod tests {
         let cmd = TransitionCommand {
             short_code: short_code.clone(),
             phase: Some("completed".to_string()),
+            cascade: false,
+            yes: false,
         };
         cmd.execute().await.unwrap();
 
@@ -354,6 +495,8 @@ mod tests {
         let cmd = TransitionCommand {
             short_code: blocked_doc_id.to_string(),
             phase: Some("blocked".to_string()),
+            cascade: false,
+            yes: false,
         };
         cmd.execute().await.unwrap();
 
@@ -361,13 +504,95 @@ mod tests {
         let cmd = TransitionCommand {
             short_code: blocked_doc_id.to_string(),
             phase: Some("active".to_string()),
+            cascade: false,
+            yes: false,
+        };
+        cmd.execute().await.unwrap();
+
+        // Always restore original directory
+        if let Some(original) = original_dir {
+            let _ = std::env::set_current_dir(&original);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_cascade_completes_open_tasks() {
+        let temp_dir = tempdir().unwrap();
+        let original_dir = std::env::current_dir().ok();
+
+        // Change to temp directory
+        std::env::set_current_dir(temp_dir.path()).unwrap();
+
+        let init_cmd = InitCommand {
+            name: Some("Test Project".to_string()),
+            preset: None,
+            initiatives: None,
+            prefix: None,
+            remote: None,
+        };
+        init_cmd.execute().await.unwrap();
+
+        let create_initiative_cmd = crate::commands::CreateCommand {
+            document_type: crate::commands::create::CreateCommands::Initiative {
+                title: "Parent Initiative".to_string(),
+                vision: "TEST-V-0001".to_string(),
+            },
+        };
+        create_initiative_cmd.execute().await.unwrap();
+
+        // Discovery → Design → Ready → Decompose
+        for _i in 0..3 {
+            let cmd = TransitionCommand {
+                short_code: "TEST-I-0001".to_string(),
+                phase: None,
+                cascade: false,
+                yes: false,
+            };
+            cmd.execute().await.unwrap();
+        }
+
+        for title in ["First Task", "Second Task"] {
+            let create_task_cmd = crate::commands::CreateCommand {
+                document_type: crate::commands::create::CreateCommands::Task {
+                    title: title.to_string(),
+                    initiative: "TEST-I-0001".to_string(),
+                },
+            };
+            create_task_cmd.execute().await.unwrap();
+        }
+
+        // Decompose → Active, then complete with cascade
+        let cmd = TransitionCommand {
+            short_code: "TEST-I-0001".to_string(),
+            phase: Some("active".to_string()),
+            cascade: false,
+            yes: false,
         };
         cmd.execute().await.unwrap();
+        let cmd = TransitionCommand {
+            short_code: "TEST-I-0001".to_string(),
+            phase: None,
+            cascade: true,
+            yes: true,
+        };
+        let result = cmd.execute().await;
+
+        let tasks_dir = temp_dir.path().join(".metis/initiatives/TEST-I-0001/tasks");
+        let mut phases = Vec::new();
+        for code in ["TEST-T-0001", "TEST-T-0002"] {
+            let task = Task::from_file(tasks_dir.join(format!("{}.md", code)))
+                .await
+                .unwrap();
+            phases.push(task.phase().unwrap());
+        }
 
         // Always restore original directory
         if let Some(original) = original_dir {
             let _ = std::env::set_current_dir(&original);
         }
+
+        result.unwrap();
+        assert_eq!(phases, vec![Phase::Completed, Phase::Completed]);
     }
 
     #[tokio::test]
@@ -385,6 +610,7 @@ mod tests {
 
             initiatives: None,
             prefix: None,
+            remote: None,
         };
         init_cmd.execute().await.unwrap();
 
@@ -405,6 +631,8 @@ mod tests {
         let cmd = TransitionCommand {
             short_code: short_code.clone(),
             phase: None, // Auto transition
+            cascade: false,
+            yes: false,
         };
         cmd.execute().await.unwrap();
 
@@ -412,6 +640,8 @@ mod tests {
         let cmd = TransitionCommand {
             short_code: short_code.clone(),
             phase: None, // Auto transition
+            cascade: false,
+            yes: false,
         };
         cmd.execute().await.unwrap();
 
@@ -419,6 +649,8 @@ mod tests {
         let cmd = TransitionCommand {
             short_code: short_code.clone(),
             phase: Some("superseded".to_string()),
+            cascade: false,
+            yes: false,
         };
         cmd.execute().await.unwrap(); // Should succeed as Decided → Superseded is valid
 
@@ -426,6 +658,8 @@ mod tests {
         let cmd = TransitionCommand {
             short_code: short_code.clone(),
             phase: None, // Auto transition
+            cascade: false,
+            yes: false,
         };
         let result = cmd.execute().await;
         assert!(result.is_err()); // Should fail as Superseded has no valid transitions
@@ -451,6 +685,7 @@ mod tests {
 
             initiatives: None,
             prefix: None,
+            remote: None,
         };
         init_cmd.execute().await.unwrap();
 
@@ -463,6 +698,8 @@ mod tests {
         let cmd = TransitionCommand {
             short_code: short_code.clone(),
             phase: Some("published".to_string()),
+            cascade: false,
+            yes: false,
         };
         let result = cmd.execute().await;
         assert!(result.is_err());
@@ -475,6 +712,8 @@ mod tests {
         let cmd = TransitionCommand {
             short_code: short_code.clone(),
             phase: Some("invalid-phase".to_string()),
+            cascade: false,
+            yes: false,
         };
         let result = cmd.execute().await;
         assert!(result.is_err());
@@ -501,6 +740,7 @@ mod tests {
 
             initiatives: None,
             prefix: None,
+            remote: None,
         };
         init_cmd.execute().await.unwrap();
 
@@ -514,6 +754,8 @@ mod tests {
         let cmd = TransitionCommand {
             short_code: short_code.clone(),
             phase: None, // Auto transition
+            cascade: false,
+            yes: false,
         };
         cmd.execute().await.unwrap();
 
@@ -524,6 +766,8 @@ mod tests {
         let cmd = TransitionCommand {
             short_code: short_code.clone(),
             phase: None, // Auto transition
+            cascade: false,
+            yes: false,
         };
         cmd.execute().await.unwrap();
 
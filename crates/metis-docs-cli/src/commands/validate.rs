@@ -1,3 +1,4 @@
+use crate::output::{say, Failure};
 use anyhow::Result;
 use clap::Args;
 use metis_core::application::services::document::DocumentValidationService;
@@ -18,7 +19,7 @@ impl ValidateCommand {
         match result {
             Ok(validation_result) => {
                 if validation_result.is_valid {
-                    println!(
+                    say!(
                         "✓ Valid {} document: {}",
                         validation_result.document_type,
                         self.file_path.display()
@@ -29,7 +30,7 @@ impl ValidateCommand {
                     for error in &validation_result.errors {
                         println!("  - {}", error);
                     }
-                    anyhow::bail!("Document validation failed")
+                    Err(Failure::Validation("Document validation failed".to_string()).into())
                 }
             }
             Err(e) => {
@@ -89,6 +90,7 @@ mod tests {
 
             initiatives: None,
             prefix: None,
+            remote: None,
         };
         init_cmd.execute().await.unwrap();
 
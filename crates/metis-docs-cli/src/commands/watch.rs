@@ -0,0 +1,262 @@
+use crate::commands::log::run_git;
+use crate::output::{say, Failure};
+use crate::workspace;
+use anyhow::Result;
+use clap::Args;
+use metis_core::application::services::synchronization::SyncResult;
+use metis_core::domain::configuration::ConfigFile;
+use metis_core::{Application, Database};
+use notify::{Event, EventKind, RecursiveMode, Watcher};
+use std::path::Path;
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+#[derive(Args)]
+pub struct WatchCommand {
+    /// Milliseconds to wait for changes to settle before syncing
+    #[arg(long, default_value = "300")]
+    pub debounce: u64,
+
+    /// Pull every N seconds, then sync; uses the `[sync] remote` from
+    /// config.toml when set, otherwise the branch's upstream
+    #[arg(long, value_name = "SECONDS")]
+    pub pull_every: Option<u64>,
+}
+
+impl WatchCommand {
+    pub async fn execute(&self) -> Result<()> {
+        // 1. Validate we're in a metis workspace
+        let (workspace_exists, metis_dir) = workspace::has_metis_vault();
+        if !workspace_exists {
+            return Err(Failure::NotAWorkspace.into());
+        }
+        let metis_dir = metis_dir.unwrap();
+        if self.pull_every == Some(0) {
+            anyhow::bail!("--pull-every must be at least 1 second");
+        }
+
+        // 2. Start from a synced database
+        sync(&metis_dir).await?;
+
+        // 3. Forward filesystem events for documents into the async loop
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let mut watcher = notify::recommended_watcher(move |res: notify::Result<Event>| {
+            if let Ok(event) = res {
+                if is_document_change(&event) {
+                    let _ = tx.send(());
+                }
+            }
+        })
+        .map_err(|e| anyhow::anyhow!("Failed to start file watcher: {}", e))?;
+        watcher
+            .watch(&metis_dir, RecursiveMode::Recursive)
+            .map_err(|e| anyhow::anyhow!("Failed to watch {}: {}", metis_dir.display(), e))?;
+
+        say!("Watching {} (Ctrl+C to stop)", metis_dir.display());
+
+        let pull_args = pull_args(&metis_dir);
+        let debounce = Duration::from_millis(self.debounce);
+        let mut pull_timer = self
+            .pull_every
+            .map(|secs| tokio::time::interval(Duration::from_secs(secs)));
+        if let Some(timer) = pull_timer.as_mut() {
+            // The first tick fires immediately; we just synced
+            timer.tick().await;
+        }
+
+        // 4. Sync after each burst of changes until interrupted
+        let ctrl_c = tokio::signal::ctrl_c();
+        tokio::pin!(ctrl_c);
+        loop {
+            tokio::select! {
+                _ = &mut ctrl_c => break,
+                Some(()) = rx.recv() => {
+                    // Editors often write several times per save; wait for quiet
+                    while let Ok(Some(())) = tokio::time::timeout(debounce, rx.recv()).await {}
+                    report(sync(&metis_dir).await);
+                }
+                _ = tick(&mut pull_timer) => {
+                    match run_git(&metis_dir, &pull_args) {
+                        Ok(output) if output.contains("Already up to date") => {}
+                        Ok(_) => {
+                            say!("{} [<] Pulled from remote", timestamp());
+                            report(sync(&metis_dir).await);
+                        }
+                        Err(e) => println!("{} [-] Pull failed: {}", timestamp(), e),
+                    }
+                }
+            }
+        }
+
+        say!("Stopped watching");
+        Ok(())
+    }
+}
+
+/// Wait for the next pull, or forever when pulling is off
+async fn tick(timer: &mut Option<tokio::time::Interval>) {
+    match timer {
+        Some(timer) => {
+            timer.tick().await;
+        }
+        None => std::future::pending().await,
+    }
+}
+
+/// Arguments for `git pull`, fast-forward only
+fn pull_args(metis_dir: &Path) -> Vec<String> {
+    let mut args = vec!["pull".to_string(), "--ff-only".to_string()];
+    if let Ok(config) = ConfigFile::load(metis_dir.join("config.toml")) {
+        let sync = config.sync();
+        if let Some(remote) = &sync.remote {
+            args.push(remote.clone());
+            args.extend(sync.branch.clone());
+        }
+    }
+    args
+}
+
+async fn sync(metis_dir: &Path) -> Result<Vec<SyncResult>> {
+    let db_path = metis_dir.join("metis.db");
+    let database = Database::new(db_path.to_str().unwrap())
+        .map_err(|e| anyhow::anyhow!("Failed to open database for sync: {}", e))?;
+    let app = Application::new(database);
+    app.sync_directory(metis_dir)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to sync workspace: {}", e))
+}
+
+/// Whether an event touches a document, as opposed to the database, its
+/// journal files or access-only events
+fn is_document_change(event: &Event) -> bool {
+    !matches!(event.kind, EventKind::Access(_))
+        && event
+            .paths
+            .iter()
+            .any(|path| path.extension().is_some_and(|ext| ext == "md"))
+}
+
+/// Print the change feed for a sync; a failed sync is reported and the
+/// watcher keeps going
+fn report(results: Result<Vec<SyncResult>>) {
+    match results {
+        Ok(results) => {
+            for line in results.iter().filter_map(change_line) {
+                say!("{} {}", timestamp(), line);
+            }
+        }
+        Err(e) => println!("{} [-] {}", timestamp(), e),
+    }
+}
+
+fn timestamp() -> String {
+    chrono::Local::now().format("%H:%M:%S").to_string()
+}
+
+/// One line of the change feed, using the result codes of `metis sync`;
+/// unchanged documents are left out
+fn change_line(result: &SyncResult) -> Option<String> {
+    Some(match result {
+        SyncResult::Imported { filepath } => format!("[+] Imported: {}", filepath),
+        SyncResult::Updated { filepath } => format!("[+] Updated: {}", filepath),
+        SyncResult::Deleted { filepath } => format!("[+] Deleted: {}", filepath),
+        SyncResult::Moved { from, to } => format!("[>] Moved: {} -> {}", from, to),
+        SyncResult::Renumbered {
+            filepath,
+            old_short_code,
+            new_short_code,
+        } => format!(
+            "[!] Renumbered: {} ({} -> {})",
+            filepath, old_short_code, new_short_code
+        ),
+        SyncResult::Error { filepath, error } => {
+            format!("[-] Error syncing {}: {}", filepath, error)
+        }
+        SyncResult::UpToDate { .. } | SyncResult::NotFound { .. } => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use notify::event::{AccessKind, CreateKind};
+    use std::path::PathBuf;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_is_document_change() {
+        let event = |kind, path: &str| Event::new(kind).add_path(PathBuf::from(path));
+
+        assert!(is_document_change(&event(
+            EventKind::Create(CreateKind::File),
+            "/w/.metis/backlog/T-1.md"
+        )));
+        assert!(!is_document_change(&event(
+            EventKind::Create(CreateKind::File),
+            "/w/.metis/metis.db-wal"
+        )));
+        assert!(!is_document_change(&event(
+            EventKind::Access(AccessKind::Any),
+            "/w/.metis/vision.md"
+        )));
+    }
+
+    #[test]
+    fn test_change_line() {
+        assert_eq!(
+            change_line(&SyncResult::Moved {
+                from: "a.md".to_string(),
+                to: "b.md".to_string(),
+            }),
+            Some("[>] Moved: a.md -> b.md".to_string())
+        );
+        assert_eq!(
+            change_line(&SyncResult::UpToDate {
+                filepath: "a.md".to_string(),
+            }),
+            None
+        );
+    }
+
+    #[test]
+    fn test_pull_args_use_configured_remote() {
+        let temp_dir = tempdir().unwrap();
+        assert_eq!(pull_args(temp_dir.path()), vec!["pull", "--ff-only"]);
+
+        ConfigFile::default()
+            .with_remote(Some("https://github.com/org/plans.git".to_string()))
+            .unwrap()
+            .save(temp_dir.path().join("config.toml"))
+            .unwrap();
+        assert_eq!(
+            pull_args(temp_dir.path()),
+            vec!["pull", "--ff-only", "https://github.com/org/plans.git"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_watch_command_no_workspace() {
+        let temp_dir = tempdir().unwrap();
+        let original_dir = std::env::current_dir().ok();
+
+        if std::env::set_current_dir(temp_dir.path()).is_err() {
+            return;
+        }
+
+        let cmd = WatchCommand {
+            debounce: 300,
+            pull_every: None,
+        };
+        let result = cmd.execute().await;
+
+        if let Some(original) = original_dir {
+            let _ = std::env::set_current_dir(&original);
+        }
+
+        assert!(result.is_err());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Not in a Metis workspace"));
+    }
+}
@@ -1,20 +1,24 @@
 mod cli;
 mod commands;
+mod output;
 mod utils;
 mod workspace;
 
-use anyhow::Result;
 use clap::Parser;
 use cli::Cli;
 
 #[tokio::main]
-async fn main() -> Result<()> {
+async fn main() {
     // Parse CLI arguments first to get verbosity level
     let cli = Cli::parse();
 
-    // Initialize tracing based on verbosity
+    // Initialize tracing based on verbosity, and the global output flags
     cli.init_logging();
+    output::init(cli.output_options());
 
-    // Execute the command
-    cli.execute().await
+    // Execute the command, exiting with the code for its failure
+    if let Err(error) = cli.execute().await {
+        output::report_error(&error);
+        std::process::exit(output::exit_code_for(&error));
+    }
 }
@@ -0,0 +1,142 @@
+//! Global output flags (`--json`, `--quiet`, `--no-color`) and the exit
+//! codes scripts can rely on.
+
+use std::sync::OnceLock;
+
+/// Exit codes returned by `metis`
+pub mod exit_code {
+    /// Any error without a more specific code
+    pub const FAILURE: i32 = 1;
+    /// A document or the configuration failed validation. Invalid
+    /// command-line arguments also exit with 2.
+    pub const VALIDATION: i32 = 2;
+    /// Local and remote changes conflict
+    pub const CONFLICT: i32 = 3;
+    /// The current directory is not inside a Metis workspace
+    pub const NOT_A_WORKSPACE: i32 = 4;
+}
+
+/// The global flags, shared by every subcommand
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct OutputOptions {
+    pub json: bool,
+    pub quiet: bool,
+    pub no_color: bool,
+}
+
+static OPTIONS: OnceLock<OutputOptions> = OnceLock::new();
+
+/// Record the global flags for this run. Colors are also turned off when
+/// `NO_COLOR` is set.
+pub fn init(options: OutputOptions) {
+    if options.no_color || std::env::var_os("NO_COLOR").is_some() {
+        console::set_colors_enabled(false);
+        console::set_colors_enabled_stderr(false);
+        colored::control::set_override(false);
+    }
+    let _ = OPTIONS.set(options);
+}
+
+fn options() -> OutputOptions {
+    OPTIONS.get().copied().unwrap_or_default()
+}
+
+/// Whether `--json` was given
+pub fn json() -> bool {
+    options().json
+}
+
+/// Whether `--quiet` was given
+pub fn quiet() -> bool {
+    options().quiet
+}
+
+/// `println!` that stays silent under `--quiet`, for confirmations and
+/// progress rather than the data a command was asked for
+macro_rules! say {
+    ($($arg:tt)*) => {
+        if !$crate::output::quiet() {
+            println!($($arg)*);
+        }
+    };
+}
+pub(crate) use say;
+
+/// Print just the value a script needs from a command, such as a new short
+/// code, when `--quiet` hides the usual confirmation
+pub fn quiet_result(value: impl std::fmt::Display) {
+    if quiet() {
+        println!("{}", value);
+    }
+}
+
+/// Failures with their own exit code
+#[derive(Debug, thiserror::Error)]
+pub enum Failure {
+    #[error("Not in a Metis workspace. Run 'metis init' to create one.")]
+    NotAWorkspace,
+    #[error("{0}")]
+    Validation(String),
+    #[error("{0}")]
+    Conflict(String),
+}
+
+impl Failure {
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Failure::NotAWorkspace => exit_code::NOT_A_WORKSPACE,
+            Failure::Validation(_) => exit_code::VALIDATION,
+            Failure::Conflict(_) => exit_code::CONFLICT,
+        }
+    }
+}
+
+/// The exit code for an error: the code of the first [`Failure`] in its
+/// chain, otherwise [`exit_code::FAILURE`]
+pub fn exit_code_for(error: &anyhow::Error) -> i32 {
+    error
+        .chain()
+        .find_map(|cause| cause.downcast_ref::<Failure>())
+        .map(Failure::exit_code)
+        .unwrap_or(exit_code::FAILURE)
+}
+
+/// Print an error to stderr, as `{"error": ..., "code": ...}` under `--json`
+pub fn report_error(error: &anyhow::Error) {
+    if json() {
+        let report = serde_json::json!({
+            "error": format!("{:#}", error),
+            "code": exit_code_for(error),
+        });
+        eprintln!("{}", report);
+    } else {
+        eprintln!("Error: {:?}", error);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exit_code_for() {
+        let not_a_workspace: anyhow::Error = Failure::NotAWorkspace.into();
+        assert_eq!(exit_code_for(&not_a_workspace), exit_code::NOT_A_WORKSPACE);
+        assert_eq!(
+            not_a_workspace.to_string(),
+            "Not in a Metis workspace. Run 'metis init' to create one."
+        );
+
+        let validation = anyhow::Error::from(Failure::Validation("bad".to_string()))
+            .context("Checking TEST-T-0001");
+        assert_eq!(exit_code_for(&validation), exit_code::VALIDATION);
+
+        let conflict: anyhow::Error = Failure::Conflict("2 conflicts".to_string()).into();
+        assert_eq!(exit_code_for(&conflict), exit_code::CONFLICT);
+
+        assert_eq!(
+            exit_code_for(&anyhow::anyhow!("Failed to sync workspace")),
+            exit_code::FAILURE
+        );
+    }
+}
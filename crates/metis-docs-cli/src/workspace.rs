@@ -1,4 +1,7 @@
-use std::path::PathBuf;
+use anyhow::Result;
+use metis_core::application::services::{DatabaseService, SyncService};
+use metis_core::{Application, Database};
+use std::path::{Path, PathBuf};
 
 /// Check if we're in a Metis workspace by walking up the directory tree
 ///
@@ -32,6 +35,31 @@ pub fn has_metis_vault() -> (bool, Option<PathBuf>) {
     (false, None)
 }
 
+/// Bring the database up to date with the workspace's files
+pub async fn sync(metis_dir: &Path, db_service: &mut DatabaseService) -> Result<()> {
+    SyncService::new(db_service)
+        .with_workspace_dir(metis_dir)
+        .sync_directory(metis_dir)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to sync workspace: {}", e))?;
+    Ok(())
+}
+
+/// Sync the workspace and open the database for queries
+pub async fn open_synced(metis_dir: &Path) -> Result<DatabaseService> {
+    let db_path = metis_dir.join("metis.db");
+    let database = Database::new(db_path.to_str().unwrap())
+        .map_err(|e| anyhow::anyhow!("Failed to open database for sync: {}", e))?;
+    let app = Application::new(database);
+    app.sync_directory(metis_dir)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to sync workspace: {}", e))?;
+
+    let db = Database::new(db_path.to_str().unwrap())
+        .map_err(|e| anyhow::anyhow!("Database connection failed: {}", e))?;
+    Ok(DatabaseService::new(db.into_repository()))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
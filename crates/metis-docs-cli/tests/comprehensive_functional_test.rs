@@ -24,6 +24,7 @@ mod cli_helpers {
             prefix: prefix.map(|s| s.to_string()),
             preset: preset.map(|s| s.to_string()),
             initiatives: None,
+            remote: None,
         };
 
         let result = cmd.execute().await;
@@ -29,6 +29,19 @@ impl Application {
         f(&mut service)
     }
 
+    /// Execute a relationship graph query
+    pub fn with_query<F, R>(&mut self, f: F) -> R
+    where
+        F: FnOnce(&mut services::QueryService) -> R,
+    {
+        let repository = self
+            .database
+            .repository()
+            .expect("Failed to get database repository");
+        let mut service = services::QueryService::new(repository);
+        f(&mut service)
+    }
+
     /// Execute a sync operation
     pub fn with_sync<F, R>(&mut self, f: F) -> R
     where
@@ -1,4 +1,4 @@
-use crate::dal::database::{models::*, repository::DocumentRepository};
+use crate::dal::database::{models::*, repository::DocumentRepository, DocumentFilter, FlowReport};
 use crate::domain::documents::types::DocumentType;
 use crate::Result;
 
@@ -95,6 +95,112 @@ impl DatabaseService {
         self.repository.create_relationship(relationship)
     }
 
+    /// Replace the relationship edges declared by the document at `origin_filepath`
+    pub fn replace_edges(&mut self, origin_filepath: &str, edges: &[DocumentEdge]) -> Result<()> {
+        self.repository
+            .replace_edges_for_origin(origin_filepath, edges)
+    }
+
+    /// Replace the tags recorded for a document
+    pub fn replace_tags(&mut self, filepath: &str, tags: &[String]) -> Result<()> {
+        self.repository.replace_tags(filepath, tags)
+    }
+
+    /// Replace the custom properties recorded for a document
+    pub fn replace_properties(
+        &mut self,
+        filepath: &str,
+        properties: &[DocumentProperty],
+    ) -> Result<()> {
+        self.repository.replace_properties(filepath, properties)
+    }
+
+    /// Find documents matching a composite filter
+    pub fn find_by_filter(&mut self, filter: &DocumentFilter) -> Result<Vec<Document>> {
+        self.repository.find_by_filter(filter)
+    }
+
+    /// Get the custom properties recorded for a document
+    pub fn find_properties(&mut self, filepath: &str) -> Result<Vec<DocumentProperty>> {
+        self.repository.find_properties(filepath)
+    }
+
+    /// Record that a document entered a phase
+    pub fn record_phase(&mut self, change: &PhaseChange) -> Result<bool> {
+        self.repository.record_phase(change)
+    }
+
+    /// Get the recorded phase history for a document, oldest first
+    pub fn find_phase_history(&mut self, short_code: &str) -> Result<Vec<PhaseChange>> {
+        self.repository.find_phase_history(short_code)
+    }
+
+    /// Compute flow metrics for one document type between two Unix timestamps
+    pub fn flow_report(
+        &mut self,
+        document_type: DocumentType,
+        since: f64,
+        until: f64,
+    ) -> Result<FlowReport> {
+        self.repository
+            .flow_report(&document_type.to_string(), since, until)
+    }
+
+    /// Recompute cached task completion rollups
+    pub fn refresh_rollups(&mut self) -> Result<usize> {
+        self.repository.refresh_rollups()
+    }
+
+    /// Record the size and mtime a document was imported from
+    pub fn record_file_stat(&mut self, filepath: &str, file_size: u64, mtime: f64) -> Result<()> {
+        self.repository.upsert_file_stat(FileStat {
+            filepath: filepath.to_string(),
+            file_size: file_size as i64,
+            mtime,
+        })
+    }
+
+    /// Get the recorded size and mtime for a document
+    pub fn find_file_stat(&mut self, filepath: &str) -> Result<Option<FileStat>> {
+        self.repository.find_file_stat(filepath)
+    }
+
+    /// Replace the documents projected from a sibling workspace
+    pub fn replace_workspace_documents(
+        &mut self,
+        workspace: &str,
+        documents: &[Document],
+        edges: &[DocumentEdge],
+    ) -> Result<usize> {
+        self.repository
+            .replace_workspace_documents(workspace, documents, edges)
+    }
+
+    /// Names of sibling workspaces with projected documents
+    pub fn list_foreign_workspaces(&mut self) -> Result<Vec<String>> {
+        self.repository.list_foreign_workspaces()
+    }
+
+    /// Projected documents from other workspaces that block local documents
+    pub fn find_foreign_blockers(&mut self) -> Result<Vec<Document>> {
+        self.repository.find_foreign_blockers()
+    }
+
+    /// Local edges pointing at or from a short code with no document
+    pub fn find_dangling_edges(&mut self) -> Result<Vec<DocumentEdge>> {
+        self.repository.find_dangling_edges()
+    }
+
+    /// Edges originating at a short code
+    pub fn find_edges_from(&mut self, short_code: &str) -> Result<Vec<DocumentEdge>> {
+        self.repository.find_edges_from(short_code)
+    }
+
+    /// Edges pointing at a short code
+    pub fn find_edges_to(&mut self, short_code: &str) -> Result<Vec<DocumentEdge>> {
+        self.repository.find_edges_to(short_code)
+    }
+
     /// Check if a document exists by filepath
     pub fn document_exists(&mut self, filepath: &str) -> Result<bool> {
         Ok(self.repository.find_by_filepath(filepath)?.is_some())
@@ -1,12 +1,12 @@
 use crate::application::services::template::TemplateLoader;
 use crate::dal::database::configuration_repository::ConfigurationRepository;
+use crate::dal::database::establish;
 use crate::domain::configuration::FlightLevelConfig;
 use crate::domain::documents::initiative::Complexity;
 use crate::domain::documents::traits::Document;
 use crate::domain::documents::types::{DocumentId, DocumentType, ParentReference, Phase, Tag};
 use crate::Result;
 use crate::{Adr, Database, Initiative, MetisError, Specification, Task, Vision};
-use diesel::{sqlite::SqliteConnection, Connection};
 use std::fs;
 use std::path::{Path, PathBuf};
 
@@ -53,7 +53,7 @@ impl DocumentCreationService {
     /// Generate a short code for a document type
     fn generate_short_code(&self, doc_type: &str) -> Result<String> {
         let mut config_repo = ConfigurationRepository::new(
-            SqliteConnection::establish(&self.db_path.to_string_lossy()).map_err(|e| {
+            establish(&self.db_path.to_string_lossy()).map_err(|e| {
                 MetisError::ConfigurationError(
                     crate::domain::configuration::ConfigurationError::InvalidValue(e.to_string()),
                 )
@@ -596,22 +596,41 @@ impl DocumentCreationService {
             let filename = entry.file_name().to_string_lossy().to_string();
 
             if filename.ends_with(".md") {
-                // Parse number from filename like "001-title.md"
-                if let Some(number_str) = filename.split('-').next() {
-                    if let Ok(number) = number_str.parse::<u32>() {
-                        max_number = max_number.max(number);
-                    }
+                // ADRs are named by short code, so the number comes from the
+                // frontmatter; older workspaces named them like "001-title.md"
+                let number = filename
+                    .split('-')
+                    .next()
+                    .and_then(|number_str| number_str.parse::<u32>().ok())
+                    .or_else(|| Self::adr_number(&entry.path()));
+                if let Some(number) = number {
+                    max_number = max_number.max(number);
                 }
             }
         }
 
         Ok(max_number + 1)
     }
+
+    /// The `number` field of an ADR file's frontmatter
+    pub(crate) fn adr_number(path: &Path) -> Option<u32> {
+        use gray_matter::{engine::YAML, Matter};
+
+        let content = fs::read_to_string(path).ok()?;
+        match Matter::<YAML>::new().parse(&content).data? {
+            gray_matter::Pod::Hash(map) => match map.get("number")? {
+                gray_matter::Pod::Integer(number) => u32::try_from(*number).ok(),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use diesel::{sqlite::SqliteConnection, Connection};
     use tempfile::tempdir;
 
     #[tokio::test]
@@ -706,6 +725,14 @@ mod tests {
 
         // Should return 3 as next number
         assert_eq!(service.get_next_adr_number().unwrap(), 3);
+
+        // Short-code named ADRs are numbered by their frontmatter
+        fs::write(
+            adrs_dir.join("TEST-A-0007.md"),
+            "---\nlevel: adr\nnumber: 7\n---\n# ADR\n",
+        )
+        .unwrap();
+        assert_eq!(service.get_next_adr_number().unwrap(), 8);
     }
 
     // Flexible flight levels tests
@@ -56,6 +56,12 @@ impl FilesystemService {
         Ok(mtime.as_secs_f64())
     }
 
+    /// Get file size in bytes
+    pub fn get_file_size<P: AsRef<Path>>(path: P) -> Result<u64> {
+        let metadata = fs::metadata(path).map_err(MetisError::Io)?;
+        Ok(metadata.len())
+    }
+
     /// Delete a file
     pub fn delete_file<P: AsRef<Path>>(path: P) -> Result<()> {
         fs::remove_file(path).map_err(MetisError::Io)
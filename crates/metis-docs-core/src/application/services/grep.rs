@@ -0,0 +1,221 @@
+use crate::dal::database::models::Document;
+use crate::{MetisError, Result};
+use regex::{Regex, RegexBuilder};
+use serde::Serialize;
+
+/// Options for a regex search over document bodies
+#[derive(Debug, Clone, Default)]
+pub struct GrepOptions {
+    pub ignore_case: bool,
+    /// Lines of context before and after each match
+    pub context: usize,
+    pub include_archived: bool,
+}
+
+/// A line of a document body, numbered as in the file
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct GrepLine {
+    pub number: usize,
+    pub text: String,
+    /// False for context lines
+    pub is_match: bool,
+    /// Byte ranges of the matches within `text`
+    pub ranges: Vec<(usize, usize)>,
+}
+
+/// A run of matching lines and their context. Matches whose context
+/// overlaps share a hunk.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct GrepHunk {
+    /// The closest markdown heading above the first match
+    pub heading: Option<String>,
+    pub lines: Vec<GrepLine>,
+}
+
+/// Every match in one document
+#[derive(Debug, Clone, Serialize)]
+pub struct GrepResult {
+    pub document: Document,
+    pub hunks: Vec<GrepHunk>,
+}
+
+impl GrepResult {
+    /// Number of matching lines
+    pub fn match_count(&self) -> usize {
+        self.hunks
+            .iter()
+            .flat_map(|hunk| &hunk.lines)
+            .filter(|line| line.is_match)
+            .count()
+    }
+}
+
+/// Compile a search pattern, reporting a bad one as a validation error
+pub fn compile(pattern: &str, ignore_case: bool) -> Result<Regex> {
+    RegexBuilder::new(pattern)
+        .case_insensitive(ignore_case)
+        .build()
+        .map_err(|e| MetisError::ValidationFailed {
+            message: format!("Invalid pattern '{}': {}", pattern, e),
+        })
+}
+
+/// Search a document's body, skipping its frontmatter. Returns `None` when
+/// nothing matches.
+pub fn grep_document(document: Document, regex: &Regex, context: usize) -> Option<GrepResult> {
+    let content = document.content.clone().unwrap_or_default();
+    let lines: Vec<&str> = content.lines().collect();
+    let body_start = body_start(&lines);
+
+    // The heading each line falls under, ignoring `#` lines in code blocks
+    let mut headings = Vec::with_capacity(lines.len());
+    let mut heading: Option<String> = None;
+    let mut in_code = false;
+    for (index, line) in lines.iter().enumerate() {
+        let trimmed = line.trim_start();
+        if index >= body_start {
+            if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+                in_code = !in_code;
+            } else if !in_code && is_heading(trimmed) {
+                heading = Some(trimmed.to_string());
+            }
+        }
+        headings.push(heading.clone());
+    }
+
+    let matches: Vec<usize> = (body_start..lines.len())
+        .filter(|&index| regex.is_match(lines[index]))
+        .collect();
+    if matches.is_empty() {
+        return None;
+    }
+
+    let mut hunks: Vec<GrepHunk> = Vec::new();
+    let mut last_end = 0;
+    for &index in &matches {
+        let start = index.saturating_sub(context).max(body_start);
+        let end = (index + context + 1).min(lines.len());
+        let overlaps = !hunks.is_empty() && start <= last_end;
+        if !overlaps {
+            hunks.push(GrepHunk {
+                heading: headings[index].clone(),
+                lines: Vec::new(),
+            });
+        }
+        let hunk = hunks.last_mut().expect("a hunk was just pushed");
+        for (line_index, text) in lines.iter().enumerate().take(end).skip(start.max(last_end)) {
+            let ranges: Vec<(usize, usize)> = if matches.binary_search(&line_index).is_ok() {
+                regex
+                    .find_iter(text)
+                    .map(|m| (m.start(), m.end()))
+                    .collect()
+            } else {
+                Vec::new()
+            };
+            hunk.lines.push(GrepLine {
+                number: line_index + 1,
+                text: text.to_string(),
+                is_match: !ranges.is_empty(),
+                ranges,
+            });
+        }
+        last_end = last_end.max(end);
+    }
+
+    Some(GrepResult { document, hunks })
+}
+
+/// Index of the first body line, after any `---` frontmatter block
+fn body_start(lines: &[&str]) -> usize {
+    if lines.first().map(|line| line.trim()) != Some("---") {
+        return 0;
+    }
+    lines
+        .iter()
+        .skip(1)
+        .position(|line| line.trim() == "---")
+        .map(|end| end + 2)
+        .unwrap_or(0)
+}
+
+fn is_heading(line: &str) -> bool {
+    let level = line.chars().take_while(|c| *c == '#').count();
+    (1..=6).contains(&level) && line[level..].starts_with(' ')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn document(content: &str) -> Document {
+        Document {
+            filepath: "strategies/TEST-S-0001.md".to_string(),
+            id: "test".to_string(),
+            title: "Test".to_string(),
+            document_type: "strategy".to_string(),
+            created_at: 0.0,
+            updated_at: 0.0,
+            archived: false,
+            exit_criteria_met: false,
+            file_hash: String::new(),
+            frontmatter_json: "{}".to_string(),
+            content: Some(content.to_string()),
+            phase: "shaping".to_string(),
+            initiative_id: None,
+            short_code: "TEST-S-0001".to_string(),
+            parent_id: None,
+            workspace: String::new(),
+        }
+    }
+
+    const CONTENT: &str = "---\ntitle: Cache rollout\n---\n\n# Cache rollout\n\n## Risks\n\nThe cache may serve stale data.\nMitigate with short TTLs.\n\n```\n# cache settings\n```\n\n## Plan\n\nWarm the CACHE first.\n";
+
+    #[test]
+    fn test_grep_document() {
+        let regex = compile("cache", true).unwrap();
+        let result = grep_document(document(CONTENT), &regex, 0).unwrap();
+
+        // The title in the frontmatter isn't searched
+        let numbers: Vec<usize> = result
+            .hunks
+            .iter()
+            .flat_map(|hunk| hunk.lines.iter().map(|line| line.number))
+            .collect();
+        assert_eq!(numbers, vec![5, 9, 13, 18]);
+        assert_eq!(result.match_count(), 4);
+
+        let headings: Vec<Option<&str>> = result
+            .hunks
+            .iter()
+            .map(|hunk| hunk.heading.as_deref())
+            .collect();
+        // A `#` line inside a code block is not a heading
+        assert_eq!(
+            headings,
+            vec![
+                Some("# Cache rollout"),
+                Some("## Risks"),
+                Some("## Risks"),
+                Some("## Plan")
+            ]
+        );
+        assert_eq!(result.hunks[3].lines[0].ranges, vec![(9, 14)]);
+    }
+
+    #[test]
+    fn test_grep_context_merges_hunks() {
+        let regex = compile("stale|TTL", false).unwrap();
+        let result = grep_document(document(CONTENT), &regex, 1).unwrap();
+
+        assert_eq!(result.hunks.len(), 1);
+        let lines: Vec<(usize, bool)> = result.hunks[0]
+            .lines
+            .iter()
+            .map(|line| (line.number, line.is_match))
+            .collect();
+        assert_eq!(lines, vec![(8, false), (9, true), (10, true), (11, false)]);
+
+        assert!(grep_document(document(CONTENT), &compile("nothing", false).unwrap(), 2).is_none());
+        assert!(compile("(unclosed", false).is_err());
+    }
+}
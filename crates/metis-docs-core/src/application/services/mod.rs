@@ -1,11 +1,17 @@
 pub mod database;
 pub mod document;
 pub mod filesystem;
+pub mod grep;
+pub mod query;
 pub mod synchronization;
 pub mod template;
 pub mod workspace;
 
 pub use database::DatabaseService;
 pub use filesystem::FilesystemService;
+pub use grep::{GrepHunk, GrepLine, GrepOptions, GrepResult};
+pub use query::{ForeignDocument, QueryService, SimilarDocument};
 pub use synchronization::SyncService;
-pub use template::{TemplateError, TemplateLoader, TemplateSource, TemplateType};
+pub use template::{
+    TemplateError, TemplateLoader, TemplateSource, TemplateType, TEMPLATE_DOCUMENT_TYPES,
+};
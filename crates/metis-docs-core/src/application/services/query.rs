@@ -0,0 +1,433 @@
+use crate::application::services::grep::{self, GrepOptions, GrepResult};
+use crate::dal::database::{
+    models::*, repository::DocumentRepository, DocumentFilter, DocumentGroup, DocumentPage,
+};
+use crate::{MetisError, Result};
+use serde::Serialize;
+use std::collections::HashSet;
+
+/// Minimum title similarity (0-1) for a document to be reported as a
+/// possible duplicate
+pub const DUPLICATE_THRESHOLD: f64 = 0.45;
+
+/// Most possible duplicates reported for a single title
+const MAX_DUPLICATES: usize = 5;
+
+/// A document projected from a sibling workspace.
+///
+/// Foreign documents have no file in this workspace and are owned by another
+/// team, so front-ends must treat them as read-only: no edits, transitions
+/// or archiving. `read_only` is always true and is serialized so JSON
+/// consumers can disable editing without knowing about workspaces.
+#[derive(Debug, Clone, Serialize)]
+pub struct ForeignDocument {
+    pub workspace: String,
+    pub document: Document,
+    read_only: bool,
+}
+
+impl ForeignDocument {
+    fn new(document: Document) -> Self {
+        Self {
+            workspace: document.workspace.clone(),
+            document,
+            read_only: true,
+        }
+    }
+
+    /// Always true - foreign documents cannot be edited from this workspace
+    pub fn read_only(&self) -> bool {
+        self.read_only
+    }
+}
+
+/// An existing document whose title resembles a proposed one
+#[derive(Debug, Clone, Serialize)]
+pub struct SimilarDocument {
+    pub document: Document,
+    /// Trigram similarity of the titles, from 0 (unrelated) to 1 (identical)
+    pub score: f64,
+}
+
+/// Query service - read-only relationship graph queries shared by all front-ends
+pub struct QueryService {
+    repository: DocumentRepository,
+}
+
+impl QueryService {
+    pub fn new(repository: DocumentRepository) -> Self {
+        Self { repository }
+    }
+
+    /// All documents below `short_code` in the parent hierarchy
+    pub fn descendants_of(&mut self, short_code: &str) -> Result<Vec<Document>> {
+        self.repository.find_descendants(short_code)
+    }
+
+    /// All documents above `short_code` in the parent hierarchy
+    pub fn ancestors_of(&mut self, short_code: &str) -> Result<Vec<Document>> {
+        self.repository.find_ancestors(short_code)
+    }
+
+    /// All documents that directly or transitively block `short_code`
+    pub fn transitive_blockers(&mut self, short_code: &str) -> Result<Vec<Document>> {
+        self.repository.find_transitive_blockers(short_code)
+    }
+
+    /// All documents affected by a change to `short_code`: its descendants
+    /// and everything it directly or transitively blocks
+    pub fn impact_of(&mut self, short_code: &str) -> Result<Vec<Document>> {
+        self.repository.find_impact(short_code)
+    }
+
+    /// Cached task completion for a parent document (initiative or vision)
+    pub fn rollup_of(&mut self, short_code: &str) -> Result<Option<DocumentRollup>> {
+        self.repository.find_rollup(short_code)
+    }
+
+    /// Cached task completion for every parent document
+    pub fn rollups(&mut self) -> Result<Vec<DocumentRollup>> {
+        self.repository.list_rollups()
+    }
+
+    /// Local documents carrying `tag`
+    pub fn documents_by_tag(&mut self, tag: &str) -> Result<Vec<Document>> {
+        self.repository.find_by_tag(tag)
+    }
+
+    /// How many unarchived local documents carry each tag
+    pub fn tag_counts(&mut self) -> Result<Vec<TagCount>> {
+        self.repository.tag_counts()
+    }
+
+    /// How many unarchived local documents have each value of `group`
+    pub fn count_by(&mut self, group: DocumentGroup) -> Result<Vec<GroupCount>> {
+        self.repository.count_by(group)
+    }
+
+    /// Tags most often used together with `tag`
+    pub fn co_occurring_tags(&mut self, tag: &str) -> Result<Vec<TagCount>> {
+        self.repository.co_occurring_tags(tag)
+    }
+
+    /// Local documents matching a composite type/phase/property filter
+    pub fn find_by_filter(&mut self, filter: &DocumentFilter) -> Result<Vec<Document>> {
+        self.repository.find_by_filter(filter)
+    }
+
+    /// One sorted page of local documents matching a filter, with the total
+    pub fn list_page(&mut self, filter: &DocumentFilter) -> Result<DocumentPage> {
+        self.repository.find_page(filter)
+    }
+
+    /// Full-text search over local documents matching a filter, best match
+    /// first, each hit with a highlighted snippet
+    pub fn search(&mut self, query: &str, filter: &DocumentFilter) -> Result<Vec<SearchHit>> {
+        self.repository.search_filtered(query, filter)
+    }
+
+    /// Regex search over the bodies of local documents, line by line, in
+    /// filepath order
+    pub fn grep(&mut self, pattern: &str, options: &GrepOptions) -> Result<Vec<GrepResult>> {
+        let regex = grep::compile(pattern, options.ignore_case)?;
+        let filter = DocumentFilter::new().include_archived(options.include_archived);
+        let mut documents = self.repository.find_by_filter(&filter)?;
+        documents.sort_by(|a, b| a.filepath.cmp(&b.filepath));
+        Ok(documents
+            .into_iter()
+            .filter_map(|document| grep::grep_document(document, &regex, options.context))
+            .collect())
+    }
+
+    /// Custom frontmatter properties of a local document
+    pub fn properties_of(&mut self, short_code: &str) -> Result<Vec<DocumentProperty>> {
+        let filepath = self.repository.resolve_short_code_to_filepath(short_code)?;
+        self.repository.find_properties(&filepath)
+    }
+
+    /// Unarchived local documents whose titles closely resemble `title`,
+    /// most similar first. Front-ends call this before creating a document
+    /// to warn about likely duplicates.
+    pub fn possible_duplicates(&mut self, title: &str) -> Result<Vec<SimilarDocument>> {
+        // Narrow candidates with full-text search on any word of the title,
+        // then rank them by title similarity
+        let words: Vec<String> = title
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|word| word.chars().count() >= 3)
+            .map(|word| format!("\"{}\"", word))
+            .collect();
+        if words.is_empty() {
+            return Ok(Vec::new());
+        }
+        let candidates = self
+            .repository
+            .search_documents_unarchived(&words.join(" OR "))?;
+
+        let mut similar: Vec<SimilarDocument> = candidates
+            .into_iter()
+            .map(|document| SimilarDocument {
+                score: title_similarity(title, &document.title),
+                document,
+            })
+            .filter(|candidate| candidate.score >= DUPLICATE_THRESHOLD)
+            .collect();
+        similar.sort_by(|a, b| {
+            b.score
+                .partial_cmp(&a.score)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| a.document.short_code.cmp(&b.document.short_code))
+        });
+        similar.truncate(MAX_DUPLICATES);
+        Ok(similar)
+    }
+
+    /// Sibling workspaces that have projected documents
+    pub fn foreign_workspaces(&mut self) -> Result<Vec<String>> {
+        self.repository.list_foreign_workspaces()
+    }
+
+    /// All documents projected from a sibling workspace
+    pub fn list_foreign(&mut self, workspace: &str) -> Result<Vec<ForeignDocument>> {
+        Self::ensure_foreign(workspace)?;
+        Ok(self
+            .repository
+            .find_by_workspace(workspace)?
+            .into_iter()
+            .map(ForeignDocument::new)
+            .collect())
+    }
+
+    /// Full-text search within a sibling workspace
+    pub fn search_foreign(&mut self, workspace: &str, query: &str) -> Result<Vec<ForeignDocument>> {
+        Self::ensure_foreign(workspace)?;
+        Ok(self
+            .repository
+            .search_documents_in_workspace(workspace, query)?
+            .into_iter()
+            .map(ForeignDocument::new)
+            .collect())
+    }
+
+    /// A single document from a sibling workspace by short code
+    pub fn get_foreign(&mut self, workspace: &str, short_code: &str) -> Result<ForeignDocument> {
+        Self::ensure_foreign(workspace)?;
+        self.repository
+            .find_by_short_code_in_workspace(workspace, short_code)?
+            .map(ForeignDocument::new)
+            .ok_or_else(|| {
+                MetisError::NotFound(format!(
+                    "Document '{}' not found in workspace '{}'",
+                    short_code, workspace
+                ))
+            })
+    }
+
+    /// Projected documents from other workspaces that block local documents
+    pub fn foreign_blockers(&mut self) -> Result<Vec<ForeignDocument>> {
+        Ok(self
+            .repository
+            .find_foreign_blockers()?
+            .into_iter()
+            .map(ForeignDocument::new)
+            .collect())
+    }
+
+    fn ensure_foreign(workspace: &str) -> Result<()> {
+        if workspace == LOCAL_WORKSPACE {
+            return Err(MetisError::ValidationFailed {
+                message: "Workspace name is required for foreign document queries".to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    /// The whole local document graph, for graph views and external tools
+    pub fn graph(&mut self, include_archived: bool) -> Result<DocumentGraph> {
+        self.repository.export_graph(include_archived)
+    }
+
+    /// Outgoing relationship edges for `short_code`
+    pub fn edges_from(&mut self, short_code: &str) -> Result<Vec<DocumentEdge>> {
+        self.repository.find_edges_from(short_code)
+    }
+
+    /// Incoming relationship edges for `short_code`
+    pub fn edges_to(&mut self, short_code: &str) -> Result<Vec<DocumentEdge>> {
+        self.repository.find_edges_to(short_code)
+    }
+}
+
+/// Case-insensitive trigram similarity of two titles (Jaccard index of their
+/// word trigrams, as in PostgreSQL's pg_trgm)
+pub fn title_similarity(a: &str, b: &str) -> f64 {
+    let (a, b) = (trigrams(a), trigrams(b));
+    let union = a.union(&b).count();
+    if union == 0 {
+        return 0.0;
+    }
+    a.intersection(&b).count() as f64 / union as f64
+}
+
+fn trigrams(text: &str) -> HashSet<String> {
+    let mut trigrams = HashSet::new();
+    for word in text
+        .to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+    {
+        let padded: Vec<char> = format!("  {} ", word).chars().collect();
+        for window in padded.windows(3) {
+            trigrams.insert(window.iter().collect());
+        }
+    }
+    trigrams
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dal::Database;
+
+    fn insert(repo: &mut DocumentRepository, code: &str, doc_type: &str, parent: Option<&str>) {
+        let filepath = format!("/test/{}.md", code);
+        repo.create_document(NewDocument {
+            filepath: filepath.clone(),
+            id: code.to_lowercase(),
+            title: code.to_string(),
+            document_type: doc_type.to_string(),
+            created_at: 1609459200.0,
+            updated_at: 1609459200.0,
+            archived: false,
+            exit_criteria_met: false,
+            file_hash: "abc123".to_string(),
+            frontmatter_json: "{}".to_string(),
+            content: None,
+            phase: "todo".to_string(),
+            initiative_id: None,
+            short_code: code.to_string(),
+            parent_id: parent.map(str::to_string),
+        })
+        .expect("Failed to create document");
+
+        if let Some(parent) = parent {
+            repo.create_edge(DocumentEdge {
+                from_code: code.to_string(),
+                to_code: parent.to_string(),
+                kind: "parent".to_string(),
+                origin_filepath: filepath,
+                workspace: LOCAL_WORKSPACE.to_string(),
+            })
+            .expect("Failed to create edge");
+        }
+    }
+
+    #[test]
+    fn test_hierarchy_queries() {
+        let db = Database::new(":memory:").expect("Failed to create test database");
+        let mut repo = db.into_repository();
+        insert(&mut repo, "TEST-I-0001", "initiative", None);
+        insert(&mut repo, "TEST-T-0001", "task", Some("TEST-I-0001"));
+
+        let mut service = QueryService::new(repo);
+
+        let descendants = service.descendants_of("TEST-I-0001").unwrap();
+        assert_eq!(descendants.len(), 1);
+        assert_eq!(descendants[0].short_code, "TEST-T-0001");
+
+        let ancestors = service.ancestors_of("TEST-T-0001").unwrap();
+        assert_eq!(ancestors.len(), 1);
+        assert_eq!(ancestors[0].short_code, "TEST-I-0001");
+
+        assert!(service
+            .transitive_blockers("TEST-T-0001")
+            .unwrap()
+            .is_empty());
+        assert_eq!(service.edges_to("TEST-I-0001").unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_foreign_documents_are_read_only() {
+        let db = Database::new(":memory:").expect("Failed to create test database");
+        let mut repo = db.into_repository();
+        insert(&mut repo, "TEST-T-0001", "task", None);
+        let template = repo.find_by_short_code("TEST-T-0001").unwrap().unwrap();
+        repo.replace_workspace_documents(
+            "ops",
+            &[Document {
+                short_code: "OPS-T-0001".to_string(),
+                content: Some("Rotate the certificates".to_string()),
+                ..template
+            }],
+            &[],
+        )
+        .unwrap();
+
+        let mut service = QueryService::new(repo);
+
+        assert_eq!(service.foreign_workspaces().unwrap(), vec!["ops"]);
+
+        let listed = service.list_foreign("ops").unwrap();
+        assert_eq!(listed.len(), 1);
+        assert!(listed[0].read_only());
+        assert_eq!(listed[0].workspace, "ops");
+
+        let found = service.search_foreign("ops", "certificates").unwrap();
+        assert_eq!(found.len(), 1);
+
+        let doc = service.get_foreign("ops", "OPS-T-0001").unwrap();
+        let json = serde_json::to_value(&doc).unwrap();
+        assert_eq!(json["read_only"], true);
+
+        assert!(service.get_foreign("ops", "TEST-T-0001").is_err());
+        assert!(service.list_foreign("").is_err());
+    }
+
+    #[test]
+    fn test_title_similarity() {
+        assert_eq!(title_similarity("Fix login crash", "fix LOGIN crash"), 1.0);
+        assert!(title_similarity("Fix login crash on Safari", "Fix login crash in Safari") > 0.8);
+        assert!(title_similarity("Database migration fails", "database migrations failing") > 0.6);
+        assert!(title_similarity("Fix login crash on Safari", "Add dark mode to settings") < 0.1);
+        assert_eq!(title_similarity("", "Anything"), 0.0);
+    }
+
+    #[test]
+    fn test_possible_duplicates() {
+        let db = Database::new(":memory:").expect("Failed to create test database");
+        let mut repo = db.into_repository();
+        for (code, title) in [
+            ("TEST-T-0001", "Fix login crash on Safari"),
+            ("TEST-T-0002", "Add dark mode to settings"),
+            ("TEST-T-0003", "Login crash on Safari 17"),
+        ] {
+            insert(&mut repo, code, "task", None);
+            let doc = repo.find_by_short_code(code).unwrap().unwrap();
+            repo.update_document(
+                &doc.filepath.clone(),
+                &Document {
+                    title: title.to_string(),
+                    ..doc
+                },
+            )
+            .unwrap();
+        }
+
+        let mut service = QueryService::new(repo);
+
+        let duplicates = service
+            .possible_duplicates("Fix login crash in Safari")
+            .unwrap();
+        let codes: Vec<&str> = duplicates
+            .iter()
+            .map(|d| d.document.short_code.as_str())
+            .collect();
+        assert_eq!(codes, vec!["TEST-T-0001", "TEST-T-0003"]);
+        assert!(duplicates[0].score > duplicates[1].score);
+
+        assert!(service
+            .possible_duplicates("Write release notes")
+            .unwrap()
+            .is_empty());
+        assert!(service.possible_duplicates("a b").unwrap().is_empty());
+    }
+}
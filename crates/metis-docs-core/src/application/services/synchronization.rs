@@ -1,13 +1,38 @@
 use crate::application::services::{DatabaseService, FilesystemService};
-use crate::dal::database::models::{Document, NewDocument};
+use crate::dal::database::models::{
+    Document, DocumentEdge, DocumentProperty, NewDocument, PhaseChange, LOCAL_WORKSPACE,
+};
 use crate::domain::documents::{
-    factory::DocumentFactory, traits::Document as DocumentTrait, types::DocumentId,
+    factory::DocumentFactory,
+    helpers::FrontmatterParser,
+    traits::Document as DocumentTrait,
+    types::{DocumentId, DocumentType, RelationshipKind},
 };
 use crate::{MetisError, Result};
 use serde_json;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
 
+/// Frontmatter keys the document model owns; everything else is a
+/// free-form property
+pub(crate) const STRUCTURAL_KEYS: &[&str] = &[
+    "id",
+    "title",
+    "level",
+    "short_code",
+    "tags",
+    "archived",
+    "created_at",
+    "updated_at",
+    "exit_criteria_met",
+    "parent",
+    "blocked_by",
+    "initiative_id",
+    "strategy_id",
+    "supersedes",
+    "references",
+];
+
 /// Synchronization service - bridges filesystem and database
 pub struct SyncService<'a> {
     db_service: &'a mut DatabaseService,
@@ -74,8 +99,15 @@ impl<'a> SyncService<'a> {
         // Get file metadata
         let file_hash = FilesystemService::compute_file_hash(&file_path)?;
         let updated_at = FilesystemService::get_file_mtime(&file_path)?;
+        let file_size = FilesystemService::get_file_size(&file_path)?;
         let content = FilesystemService::read_file(&file_path)?;
 
+        // Collect relationship edges and custom properties declared by this document
+        let frontmatter = Self::parse_frontmatter(&content);
+        let edges = Self::extract_edges(document_obj.as_ref(), &frontmatter, &path_str);
+        let properties = Self::extract_properties(&frontmatter, &path_str);
+        let tags: Vec<String> = document_obj.tags().iter().map(|tag| tag.to_str()).collect();
+
         // Convert domain object to database model
         let new_doc = self.domain_to_database_model(
             document_obj.as_ref(),
@@ -86,7 +118,121 @@ impl<'a> SyncService<'a> {
         )?;
 
         // Store in database
-        self.db_service.create_document(new_doc)
+        let document = self.db_service.create_document(new_doc)?;
+        self.db_service.replace_edges(&path_str, &edges)?;
+        self.db_service.replace_tags(&path_str, &tags)?;
+        self.db_service.replace_properties(&path_str, &properties)?;
+        self.db_service
+            .record_file_stat(&path_str, file_size, updated_at)?;
+
+        // Track phase entries for flow analytics; the frontmatter timestamp
+        // is bumped on every transition
+        self.db_service.record_phase(&PhaseChange {
+            short_code: document.short_code.clone(),
+            document_type: document.document_type.clone(),
+            phase: document.phase.clone(),
+            entered_at: document_obj.metadata().updated_at.timestamp() as f64,
+        })?;
+
+        Ok(document)
+    }
+
+    /// Extract the relationship edges a document declares in its frontmatter.
+    ///
+    /// `parent` becomes a parent edge (a references edge for ADRs, whose parent
+    /// is informational), each `blocked_by` entry becomes a blocks edge pointing
+    /// at this document, and the optional `supersedes` / `references` keys
+    /// accept either a single short code or a list.
+    fn extract_edges(
+        document_obj: &dyn DocumentTrait,
+        frontmatter: &HashMap<String, gray_matter::Pod>,
+        origin_filepath: &str,
+    ) -> Vec<DocumentEdge> {
+        let own_code = document_obj.metadata().short_code.clone();
+        let mut edges = Vec::new();
+        let mut push = |from: &str, to: &str, kind: RelationshipKind| {
+            if from.is_empty() || to.is_empty() || to == "NULL" || from == to {
+                return;
+            }
+            edges.push(DocumentEdge {
+                from_code: from.to_string(),
+                to_code: to.to_string(),
+                kind: kind.to_string(),
+                origin_filepath: origin_filepath.to_string(),
+                workspace: LOCAL_WORKSPACE.to_string(),
+            });
+        };
+
+        if let Some(parent) = document_obj.parent_id() {
+            let kind = if document_obj.document_type() == DocumentType::Adr {
+                RelationshipKind::References
+            } else {
+                RelationshipKind::Parent
+            };
+            push(&own_code, &parent.to_string(), kind);
+        }
+
+        for blocker in document_obj.blocked_by() {
+            push(&blocker.to_string(), &own_code, RelationshipKind::Blocks);
+        }
+
+        for (key, kind) in [
+            ("supersedes", RelationshipKind::Supersedes),
+            ("references", RelationshipKind::References),
+        ] {
+            let targets = match frontmatter.get(key) {
+                Some(gray_matter::Pod::String(s)) => vec![s.clone()],
+                Some(gray_matter::Pod::Array(_)) => {
+                    FrontmatterParser::extract_string_array(frontmatter, key).unwrap_or_default()
+                }
+                _ => Vec::new(),
+            };
+            for target in targets {
+                push(&own_code, target.trim(), kind);
+            }
+        }
+
+        edges
+    }
+
+    /// Extract custom frontmatter fields as text properties.
+    ///
+    /// Structural keys that already have dedicated columns or edges are
+    /// skipped, as are lists and nested maps.
+    fn extract_properties(
+        frontmatter: &HashMap<String, gray_matter::Pod>,
+        filepath: &str,
+    ) -> Vec<DocumentProperty> {
+        let mut properties: Vec<DocumentProperty> = frontmatter
+            .iter()
+            .filter(|(key, _)| !STRUCTURAL_KEYS.contains(&key.as_str()))
+            .filter_map(|(key, value)| {
+                let value = match value {
+                    gray_matter::Pod::String(s) => s.clone(),
+                    gray_matter::Pod::Integer(i) => i.to_string(),
+                    gray_matter::Pod::Float(f) => f.to_string(),
+                    gray_matter::Pod::Boolean(b) => b.to_string(),
+                    _ => return None,
+                };
+                Some(DocumentProperty {
+                    document_filepath: filepath.to_string(),
+                    key: key.clone(),
+                    value,
+                })
+            })
+            .collect();
+        properties.sort_by(|a, b| a.key.cmp(&b.key));
+        properties
+    }
+
+    /// Parse the frontmatter of raw document content into a map
+    fn parse_frontmatter(raw_content: &str) -> HashMap<String, gray_matter::Pod> {
+        use gray_matter::{engine::YAML, Matter};
+        let matter = Matter::<YAML>::new();
+        match matter.parse(raw_content).data {
+            Some(gray_matter::Pod::Hash(map)) => map,
+            _ => HashMap::new(),
+        }
     }
 
     /// Direction 2: Database → DocumentObject → File
@@ -291,24 +437,21 @@ impl<'a> SyncService<'a> {
         Ok(())
     }
 
-    /// Detect and resolve short code collisions across all markdown files
-    /// Returns list of renumbering results
-    async fn resolve_short_code_collisions<P: AsRef<Path>>(
+    /// Short codes claimed by more than one markdown file, with the files
+    /// claiming them
+    fn find_short_code_collisions<P: AsRef<Path>>(
         &mut self,
         dir_path: P,
-    ) -> Result<Vec<SyncResult>> {
-        let mut results = Vec::new();
-
-        // Step 0: Update counters from filesystem FIRST
-        // This ensures the counter knows about all existing short codes before we generate new ones
-        self.update_counters_from_filesystem(&dir_path)?;
-
-        // Step 1: Scan all markdown files and group by short code
+    ) -> Result<Vec<(String, Vec<PathBuf>)>> {
         let files = FilesystemService::find_markdown_files(&dir_path)?;
         let mut short_code_map: HashMap<String, Vec<PathBuf>> = HashMap::new();
 
         for file_path in files {
-            match Self::extract_document_short_code(&file_path) {
+            let short_code = match self.cached_short_code(&file_path) {
+                Some(short_code) => Ok(short_code),
+                None => Self::extract_document_short_code(&file_path),
+            };
+            match short_code {
                 Ok(short_code) => {
                     short_code_map
                         .entry(short_code)
@@ -321,11 +464,26 @@ impl<'a> SyncService<'a> {
             }
         }
 
-        // Step 2: Find collisions (short codes with multiple files)
-        let mut collision_groups: Vec<(String, Vec<PathBuf>)> = short_code_map
+        Ok(short_code_map
             .into_iter()
             .filter(|(_, paths)| paths.len() > 1)
-            .collect();
+            .collect())
+    }
+
+    /// Detect and resolve short code collisions across all markdown files
+    /// Returns list of renumbering results
+    async fn resolve_short_code_collisions<P: AsRef<Path>>(
+        &mut self,
+        dir_path: P,
+    ) -> Result<Vec<SyncResult>> {
+        let mut results = Vec::new();
+
+        // Step 0: Update counters from filesystem FIRST
+        // This ensures the counter knows about all existing short codes before we generate new ones
+        self.update_counters_from_filesystem(&dir_path)?;
+
+        // Steps 1-2: Group files by short code and keep the collisions
+        let mut collision_groups = self.find_short_code_collisions(&dir_path)?;
 
         if collision_groups.is_empty() {
             return Ok(results);
@@ -424,16 +582,14 @@ impl<'a> SyncService<'a> {
             .to_string();
 
         use crate::dal::database::configuration_repository::ConfigurationRepository;
-        use diesel::sqlite::SqliteConnection;
-        use diesel::Connection;
+        use crate::dal::database::establish;
 
-        let mut config_repo = ConfigurationRepository::new(
-            SqliteConnection::establish(&db_path_str).map_err(|e| {
+        let mut config_repo =
+            ConfigurationRepository::new(establish(&db_path_str).map_err(|e| {
                 MetisError::ConfigurationError(
                     crate::domain::configuration::ConfigurationError::InvalidValue(e.to_string()),
                 )
-            })?,
-        );
+            })?);
 
         let new_short_code = config_repo.generate_short_code(doc_type)?;
 
@@ -548,6 +704,39 @@ impl<'a> SyncService<'a> {
         Ok(())
     }
 
+    /// Whether a file's size and mtime still match what was recorded when it
+    /// was last imported, meaning it can be skipped without hashing
+    fn file_stats_unchanged<P: AsRef<Path>>(
+        &mut self,
+        file_path: P,
+        relative_path: &str,
+    ) -> Result<bool> {
+        let stat = match self.db_service.find_file_stat(relative_path)? {
+            Some(stat) => stat,
+            None => return Ok(false),
+        };
+        let file_size = FilesystemService::get_file_size(&file_path)?;
+        let mtime = FilesystemService::get_file_mtime(&file_path)?;
+
+        Ok(stat.file_size == file_size as i64 && stat.mtime == mtime)
+    }
+
+    /// Short code from the database for a file that hasn't changed since import
+    fn cached_short_code(&mut self, file_path: &str) -> Option<String> {
+        let relative_path = self.to_relative_path(file_path);
+        if !self
+            .file_stats_unchanged(file_path, &relative_path)
+            .unwrap_or(false)
+        {
+            return None;
+        }
+        self.db_service
+            .find_by_filepath(&relative_path)
+            .ok()
+            .flatten()
+            .map(|doc| doc.short_code)
+    }
+
     /// Synchronize a single file between filesystem and database using directional methods
     pub async fn sync_file<P: AsRef<Path>>(&mut self, file_path: P) -> Result<SyncResult> {
         // Convert absolute path to relative for database queries
@@ -594,6 +783,13 @@ impl<'a> SyncService<'a> {
 
             // Both exist - check if file changed
             (true, Some(db_doc)) => {
+                // Size and mtime unchanged since import - skip reading the file
+                if self.file_stats_unchanged(&file_path, &relative_path_str)? {
+                    return Ok(SyncResult::UpToDate {
+                        filepath: relative_path_str,
+                    });
+                }
+
                 let current_hash = FilesystemService::compute_file_hash(&file_path)?;
 
                 if db_doc.file_hash != current_hash {
@@ -604,6 +800,11 @@ impl<'a> SyncService<'a> {
                         filepath: relative_path_str,
                     })
                 } else {
+                    // Touched but content identical - refresh stats so the next sync can skip it
+                    let file_size = FilesystemService::get_file_size(&file_path)?;
+                    let mtime = FilesystemService::get_file_mtime(&file_path)?;
+                    self.db_service
+                        .record_file_stat(&relative_path_str, file_size, mtime)?;
                     Ok(SyncResult::UpToDate {
                         filepath: relative_path_str,
                     })
@@ -629,6 +830,7 @@ impl<'a> SyncService<'a> {
         // Step 2: Re-scan all markdown files AFTER renumbering
         // This picks up renamed files with new short codes
         let files = FilesystemService::find_markdown_files(&dir_path)?;
+        let seen: HashSet<String> = files.iter().map(|f| self.to_relative_path(f)).collect();
 
         // Step 3: Sync each file
         for file_path in files {
@@ -644,6 +846,9 @@ impl<'a> SyncService<'a> {
         // Step 4: Check for orphaned database entries (files that were deleted)
         let db_pairs = self.db_service.get_all_id_filepath_pairs()?;
         for (_, relative_filepath) in db_pairs {
+            if seen.contains(&relative_filepath) {
+                continue;
+            }
             // Convert relative path from DB to absolute for filesystem check
             let absolute_path = self.to_absolute_path(&relative_filepath);
             if !FilesystemService::file_exists(&absolute_path) {
@@ -663,10 +868,17 @@ impl<'a> SyncService<'a> {
         // Step 5: Update counters based on max seen values
         self.update_counters_from_filesystem(&dir_path)?;
 
+        // Step 6: Rebuild completion rollups if anything changed
+        if results.iter().any(SyncResult::is_change) {
+            self.db_service.refresh_rollups()?;
+        }
+
         Ok(results)
     }
 
-    /// Verify database and filesystem are in sync
+    /// Verify database and filesystem are in sync, and that every document
+    /// is well formed: frontmatter parses, short codes are unique and
+    /// references point at existing documents
     pub fn verify_sync<P: AsRef<Path>>(&mut self, dir_path: P) -> Result<Vec<SyncIssue>> {
         let mut issues = Vec::new();
 
@@ -678,6 +890,25 @@ impl<'a> SyncService<'a> {
             // Convert absolute path to relative for DB query
             let relative_path = self.to_relative_path(file_path);
 
+            // Project templates live alongside documents but aren't documents
+            if relative_path.starts_with("templates/") {
+                continue;
+            }
+
+            // Files that don't parse can't be imported; report why instead
+            let parsed = std::fs::read_to_string(file_path)
+                .map_err(|e| e.to_string())
+                .and_then(|raw| {
+                    DocumentFactory::from_content(&raw, file_path).map_err(|e| e.to_string())
+                });
+            if let Err(error) = parsed {
+                issues.push(SyncIssue::InvalidDocument {
+                    filepath: relative_path,
+                    error,
+                });
+                continue;
+            }
+
             if let Some(db_doc) = self.db_service.find_by_filepath(&relative_path)? {
                 let current_hash = FilesystemService::compute_file_hash(file_path)?;
                 if db_doc.file_hash != current_hash {
@@ -706,6 +937,35 @@ impl<'a> SyncService<'a> {
             }
         }
 
+        // Check for short codes claimed by several files
+        let mut collisions = self.find_short_code_collisions(&dir_path)?;
+        collisions.sort();
+        for (short_code, paths) in collisions {
+            let mut filepaths: Vec<String> =
+                paths.iter().map(|p| self.to_relative_path(p)).collect();
+            filepaths.sort();
+            issues.push(SyncIssue::DuplicateShortCode {
+                short_code,
+                filepaths,
+            });
+        }
+
+        // Check for parents, blockers and references that don't exist
+        for edge in self.db_service.find_dangling_edges()? {
+            // The origin document is the blocked end of a blocks edge and
+            // the source end of every other kind
+            let missing = if edge.kind == RelationshipKind::Blocks.to_string() {
+                edge.from_code
+            } else {
+                edge.to_code
+            };
+            issues.push(SyncIssue::BrokenReference {
+                filepath: edge.origin_filepath,
+                short_code: missing,
+                kind: edge.kind,
+            });
+        }
+
         Ok(issues)
     }
 
@@ -724,16 +984,14 @@ impl<'a> SyncService<'a> {
             .to_string();
 
         use crate::dal::database::configuration_repository::ConfigurationRepository;
-        use diesel::sqlite::SqliteConnection;
-        use diesel::Connection;
+        use crate::dal::database::establish;
 
-        let mut config_repo = ConfigurationRepository::new(
-            SqliteConnection::establish(&db_path_str).map_err(|e| {
+        let mut config_repo =
+            ConfigurationRepository::new(establish(&db_path_str).map_err(|e| {
                 MetisError::ConfigurationError(
                     crate::domain::configuration::ConfigurationError::InvalidValue(e.to_string()),
                 )
-            })?,
-        );
+            })?);
 
         for (doc_type, max_counter) in counters {
             // Set counter to max seen value (get_next_short_code_number adds 1)
@@ -968,9 +1226,44 @@ impl SyncResult {
 /// Issues found during sync verification
 #[derive(Debug, Clone)]
 pub enum SyncIssue {
-    MissingFromDatabase { filepath: String },
-    MissingFromFilesystem { filepath: String },
-    OutOfSync { filepath: String, reason: String },
+    MissingFromDatabase {
+        filepath: String,
+    },
+    MissingFromFilesystem {
+        filepath: String,
+    },
+    OutOfSync {
+        filepath: String,
+        reason: String,
+    },
+    /// The file's frontmatter or content doesn't parse as any document type
+    InvalidDocument {
+        filepath: String,
+        error: String,
+    },
+    /// Several files claim the same short code
+    DuplicateShortCode {
+        short_code: String,
+        filepaths: Vec<String>,
+    },
+    /// A parent, blocker or reference names a short code with no document
+    BrokenReference {
+        filepath: String,
+        short_code: String,
+        kind: String,
+    },
+}
+
+impl SyncIssue {
+    /// Whether syncing the workspace resolves this issue. Sync re-imports
+    /// changed files, drops rows for deleted files and renumbers duplicate
+    /// short codes; invalid documents and broken references need a human.
+    pub fn fixed_by_sync(&self) -> bool {
+        !matches!(
+            self,
+            SyncIssue::InvalidDocument { .. } | SyncIssue::BrokenReference { .. }
+        )
+    }
 }
 
 #[cfg(test)]
@@ -1032,6 +1325,14 @@ mod tests {
         assert!(db_service
             .document_exists(&file_path.to_string_lossy())
             .expect("Failed to check"));
+
+        // Tags are materialized alongside the document
+        assert_eq!(
+            db_service
+                .get_tags_for_document(&file_path.to_string_lossy())
+                .expect("Failed to get tags"),
+            vec!["#phase/draft".to_string()]
+        );
     }
 
     #[tokio::test]
@@ -1117,6 +1418,135 @@ mod tests {
             .expect("Failed to check"));
     }
 
+    #[tokio::test]
+    async fn test_sync_file_refreshes_stale_stats() {
+        let (temp_dir, mut db_service) = setup_services();
+        let file_path = temp_dir.path().join("test.md");
+        let path_str = file_path.to_string_lossy().to_string();
+        FilesystemService::write_file(&file_path, &create_test_document_content())
+            .expect("Failed to write file");
+
+        SyncService::new(&mut db_service)
+            .import_from_file(&file_path)
+            .await
+            .expect("Failed to import");
+        let recorded = db_service
+            .find_file_stat(&path_str)
+            .expect("Failed to query stats")
+            .expect("Stats not recorded on import");
+
+        // Simulate a touch: stats differ but content is identical
+        db_service
+            .record_file_stat(&path_str, recorded.file_size as u64, 0.0)
+            .expect("Failed to record stats");
+
+        let result = SyncService::new(&mut db_service)
+            .sync_file(&file_path)
+            .await
+            .expect("Failed to sync");
+        assert_eq!(
+            result,
+            SyncResult::UpToDate {
+                filepath: path_str.clone()
+            }
+        );
+
+        let refreshed = db_service
+            .find_file_stat(&path_str)
+            .expect("Failed to query stats")
+            .expect("Stats missing after sync");
+        assert_eq!(refreshed, recorded);
+    }
+
+    #[tokio::test]
+    async fn test_verify_sync_reports_document_problems() {
+        let (temp_dir, mut db_service) = setup_services();
+        let workspace = temp_dir.path().to_path_buf();
+        let content =
+            create_test_document_content().replace("tags:\n", "references: TEST-V-9999\ntags:\n");
+
+        FilesystemService::write_file(workspace.join("a.md"), &content)
+            .expect("Failed to write file");
+        FilesystemService::write_file(workspace.join("copy.md"), &content)
+            .expect("Failed to write file");
+        FilesystemService::write_file(workspace.join("bad.md"), "no frontmatter here")
+            .expect("Failed to write file");
+
+        let mut sync_service = SyncService::new(&mut db_service).with_workspace_dir(&workspace);
+        sync_service
+            .import_from_file(workspace.join("a.md"))
+            .await
+            .expect("Failed to import");
+
+        let issues = sync_service
+            .verify_sync(&workspace)
+            .expect("Failed to verify");
+
+        assert!(issues.iter().any(|issue| matches!(
+            issue,
+            SyncIssue::InvalidDocument { filepath, .. } if filepath == "bad.md"
+        )));
+        assert!(issues.iter().any(|issue| matches!(
+            issue,
+            SyncIssue::DuplicateShortCode { short_code, filepaths }
+                if short_code == "TEST-V-9003" && filepaths == &["a.md", "copy.md"]
+        )));
+        assert!(issues.iter().any(|issue| matches!(
+            issue,
+            SyncIssue::BrokenReference { filepath, short_code, kind }
+                if filepath == "a.md" && short_code == "TEST-V-9999" && kind == "references"
+        )));
+        assert!(issues.iter().any(|issue| matches!(
+            issue,
+            SyncIssue::MissingFromDatabase { filepath } if filepath == "copy.md"
+        )));
+
+        // The bad file is only reported as invalid, not as missing from the DB
+        assert!(!issues.iter().any(|issue| matches!(
+            issue,
+            SyncIssue::MissingFromDatabase { filepath } if filepath == "bad.md"
+        )));
+        assert!(issues
+            .iter()
+            .filter(|issue| !issue.fixed_by_sync())
+            .all(|issue| matches!(
+                issue,
+                SyncIssue::InvalidDocument { .. } | SyncIssue::BrokenReference { .. }
+            )));
+    }
+
+    #[tokio::test]
+    async fn test_import_records_custom_properties() {
+        let (temp_dir, mut db_service) = setup_services();
+        let file_path = temp_dir.path().join("test.md");
+        let path_str = file_path.to_string_lossy().to_string();
+        let content = create_test_document_content().replace(
+            "tags:\n",
+            "estimate: 5\nbillable: true\ndue_date: \"2024-06-01\"\nowners:\n  - alice\ntags:\n",
+        );
+        FilesystemService::write_file(&file_path, &content).expect("Failed to write file");
+
+        SyncService::new(&mut db_service)
+            .import_from_file(&file_path)
+            .await
+            .expect("Failed to import");
+
+        let properties: Vec<(String, String)> = db_service
+            .find_properties(&path_str)
+            .expect("Failed to query properties")
+            .into_iter()
+            .map(|p| (p.key, p.value))
+            .collect();
+        assert_eq!(
+            properties,
+            vec![
+                ("billable".to_string(), "true".to_string()),
+                ("due_date".to_string(), "2024-06-01".to_string()),
+                ("estimate".to_string(), "5".to_string()),
+            ]
+        );
+    }
+
     #[tokio::test]
     async fn test_sync_directory() {
         let (temp_dir, mut db_service) = setup_services();
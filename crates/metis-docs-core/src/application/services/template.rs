@@ -41,6 +41,10 @@ mod defaults {
     }
 }
 
+/// Document types that have templates
+pub const TEMPLATE_DOCUMENT_TYPES: &[&str] =
+    &["vision", "initiative", "task", "adr", "specification"];
+
 /// Error type for template loading operations
 #[derive(Debug, Clone)]
 pub enum TemplateError {
@@ -75,7 +79,8 @@ pub enum TemplateType {
 }
 
 impl TemplateType {
-    fn filename(&self) -> &'static str {
+    /// File name of this template within a document type's template directory
+    pub fn filename(&self) -> &'static str {
         match self {
             TemplateType::Content => "content.md",
             TemplateType::ExitCriteria => "exit_criteria.md",
@@ -130,7 +135,7 @@ impl TemplateLoader {
     }
 
     /// Load a template with the fallback chain.
-    fn load_template(
+    pub fn load_template(
         &self,
         doc_type: &str,
         template_type: TemplateType,
@@ -173,7 +178,7 @@ impl TemplateLoader {
     }
 
     /// Get the embedded default template for a document type.
-    fn get_embedded_template(
+    pub fn get_embedded_template(
         &self,
         doc_type: &str,
         template_type: TemplateType,
@@ -284,6 +289,51 @@ impl TemplateLoader {
         global_template.exists()
     }
 
+    /// Path of the project-level override for a template, whether or not it
+    /// exists. None when the loader has no project workspace.
+    pub fn project_template_path(
+        &self,
+        doc_type: &str,
+        template_type: TemplateType,
+    ) -> Option<PathBuf> {
+        self.project_path.as_ref().map(|project_path| {
+            project_path
+                .join("templates")
+                .join(doc_type)
+                .join(template_type.filename())
+        })
+    }
+
+    /// Copy the embedded default template into the project's
+    /// `.metis/templates/` directory so it can be customized.
+    ///
+    /// An existing override is only replaced when `force` is set.
+    pub fn override_template(
+        &self,
+        doc_type: &str,
+        template_type: TemplateType,
+        force: bool,
+    ) -> Result<PathBuf, TemplateError> {
+        let template = self.get_embedded_template(doc_type, template_type)?;
+        let path = self
+            .project_template_path(doc_type, template_type)
+            .ok_or_else(|| {
+                TemplateError::IoError("No project workspace to override templates in".to_string())
+            })?;
+        if path.exists() && !force {
+            return Err(TemplateError::IoError(format!(
+                "{} already exists",
+                path.display()
+            )));
+        }
+
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).map_err(|e| TemplateError::IoError(e.to_string()))?;
+        }
+        std::fs::write(&path, template).map_err(|e| TemplateError::IoError(e.to_string()))?;
+        Ok(path)
+    }
+
     /// Get the source of a template (for debugging/info).
     pub fn template_source(&self, doc_type: &str, template_type: TemplateType) -> TemplateSource {
         // Check project-level
@@ -354,7 +404,7 @@ mod tests {
         let loader = TemplateLoader::new(None);
 
         // All document types should have embedded templates
-        for doc_type in &["vision", "initiative", "task", "adr", "specification"] {
+        for doc_type in TEMPLATE_DOCUMENT_TYPES {
             let content = loader.load_content_template(doc_type);
             assert!(content.is_ok(), "Failed to load content for {}", doc_type);
             assert!(!content.unwrap().is_empty());
@@ -469,4 +519,47 @@ mod tests {
         assert!(loader.has_custom_template("task", TemplateType::Content));
         assert!(!loader.has_custom_template("task", TemplateType::ExitCriteria));
     }
+
+    #[test]
+    fn test_override_template() {
+        let temp_dir = tempdir().unwrap();
+        let loader = TemplateLoader::for_workspace(temp_dir.path());
+
+        let path = loader
+            .override_template("adr", TemplateType::Content, false)
+            .unwrap();
+        assert_eq!(
+            path,
+            temp_dir
+                .path()
+                .join("templates")
+                .join("adr")
+                .join("content.md")
+        );
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            defaults::adr::CONTENT
+        );
+        assert_eq!(
+            loader.template_source("adr", TemplateType::Content),
+            TemplateSource::Project(path.clone())
+        );
+
+        // Existing overrides are kept unless forced
+        std::fs::write(&path, "# {{ title }}").unwrap();
+        assert!(loader
+            .override_template("adr", TemplateType::Content, false)
+            .is_err());
+        loader
+            .override_template("adr", TemplateType::Content, true)
+            .unwrap();
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            defaults::adr::CONTENT
+        );
+
+        assert!(TemplateLoader::new(None)
+            .override_template("adr", TemplateType::Content, false)
+            .is_err());
+    }
 }
@@ -0,0 +1,175 @@
+use crate::application::services::document::DocumentCreationService;
+use crate::application::services::workspace::transition::TransitionResult;
+use crate::application::services::workspace::{LinkKind, LinkService, PhaseTransitionService};
+use crate::application::services::DatabaseService;
+use crate::dal::database::models::Document;
+use crate::domain::documents::types::{DocumentType, Phase};
+use crate::{MetisError, Result};
+use std::path::{Path, PathBuf};
+
+/// Service for ADR-specific workflows: finding ADRs by number and
+/// superseding one decision with another.
+pub struct AdrService {
+    workspace_dir: PathBuf,
+}
+
+impl AdrService {
+    /// Create a new ADR service for a workspace
+    pub fn new<P: AsRef<Path>>(workspace_dir: P) -> Self {
+        let path = workspace_dir.as_ref();
+        let absolute_path = if path.is_absolute() {
+            path.to_path_buf()
+        } else {
+            std::env::current_dir().unwrap_or_default().join(path)
+        };
+
+        Self {
+            workspace_dir: absolute_path,
+        }
+    }
+
+    /// Find an ADR by short code, or by its number (e.g. `12` or `012`)
+    pub fn find_adr(&self, reference: &str, db_service: &mut DatabaseService) -> Result<Document> {
+        let reference = reference.trim();
+        if let Ok(number) = reference.parse::<u32>() {
+            let mut matches: Vec<Document> = db_service
+                .find_by_type(DocumentType::Adr)?
+                .into_iter()
+                .filter(|doc| {
+                    doc.workspace.is_empty()
+                        && DocumentCreationService::adr_number(
+                            &self.workspace_dir.join(&doc.filepath),
+                        ) == Some(number)
+                })
+                .collect();
+            return match matches.len() {
+                0 => Err(MetisError::NotFound(format!("ADR {:03} not found", number))),
+                1 => Ok(matches.remove(0)),
+                _ => Err(MetisError::ValidationFailed {
+                    message: format!(
+                        "Several ADRs are numbered {:03} ({}); use a short code instead",
+                        number,
+                        matches
+                            .iter()
+                            .map(|doc| doc.short_code.as_str())
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ),
+                }),
+            };
+        }
+
+        let doc = db_service
+            .find_by_short_code(reference)?
+            .ok_or_else(|| MetisError::NotFound(format!("Document '{}' not found", reference)))?;
+        if doc.document_type != "adr" {
+            return Err(MetisError::ValidationFailed {
+                message: format!("'{}' is a {}, not an ADR", reference, doc.document_type),
+            });
+        }
+        Ok(doc)
+    }
+
+    /// Find an ADR that can be superseded. Only decided ADRs can be, since
+    /// `decided` is the only phase that moves on to `superseded`.
+    pub fn find_supersedable(
+        &self,
+        reference: &str,
+        db_service: &mut DatabaseService,
+    ) -> Result<Document> {
+        let doc = self.find_adr(reference, db_service)?;
+        if doc.phase != Phase::Decided.to_string() {
+            return Err(MetisError::ValidationFailed {
+                message: format!(
+                    "{} is in the '{}' phase; only decided ADRs can be superseded",
+                    doc.short_code, doc.phase
+                ),
+            });
+        }
+        Ok(doc)
+    }
+
+    /// Record that `short_code` supersedes `superseded` (a short code or
+    /// number): add it to the new ADR's `supersedes` list and move the old
+    /// ADR to the `superseded` phase.
+    pub async fn supersede(
+        &self,
+        short_code: &str,
+        superseded: &str,
+        db_service: &mut DatabaseService,
+    ) -> Result<TransitionResult> {
+        let old = self.find_supersedable(superseded, db_service)?;
+        LinkService::new(&self.workspace_dir).link(
+            short_code,
+            &old.short_code,
+            LinkKind::Supersedes,
+            db_service,
+        )?;
+        PhaseTransitionService::new(&self.workspace_dir)
+            .transition_document(&old.short_code, Phase::Superseded)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::services::document::creation::DocumentCreationConfig;
+    use crate::application::services::workspace::test_support::TestWorkspace;
+
+    #[tokio::test]
+    async fn test_supersede_adr() {
+        let workspace = TestWorkspace::new();
+
+        let creation = DocumentCreationService::new(&workspace.metis_dir);
+        let adr = |title: &str, phase| DocumentCreationConfig {
+            title: title.to_string(),
+            description: None,
+            parent_id: None,
+            tags: vec![],
+            phase: Some(phase),
+            complexity: None,
+        };
+        let old = creation
+            .create_adr(adr("Use SQLite", Phase::Decided))
+            .await
+            .unwrap();
+        let draft = creation
+            .create_adr(adr("Use Postgres", Phase::Draft))
+            .await
+            .unwrap();
+
+        let mut db_service = workspace.synced().await;
+
+        let service = AdrService::new(&workspace.metis_dir);
+        assert_eq!(
+            service.find_adr("001", &mut db_service).unwrap().short_code,
+            old.short_code
+        );
+        assert_eq!(
+            service.find_adr("2", &mut db_service).unwrap().short_code,
+            draft.short_code
+        );
+        assert!(service.find_adr("9", &mut db_service).is_err());
+        assert!(service
+            .find_supersedable(&draft.short_code, &mut db_service)
+            .is_err());
+
+        let result = service
+            .supersede(&draft.short_code, "1", &mut db_service)
+            .await
+            .unwrap();
+        assert_eq!(result.to_phase, Phase::Superseded);
+        workspace.sync(&mut db_service).await;
+
+        let old_doc = db_service
+            .find_by_short_code(&old.short_code)
+            .unwrap()
+            .unwrap();
+        assert_eq!(old_doc.phase, "superseded");
+        let edges = db_service.find_edges_from(&draft.short_code).unwrap();
+        assert!(edges
+            .iter()
+            .any(|edge| edge.kind == "supersedes" && edge.to_code == old.short_code));
+    }
+}
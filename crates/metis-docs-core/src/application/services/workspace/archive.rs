@@ -1,5 +1,6 @@
 use crate::application::services::document::DocumentDiscoveryService;
 use crate::application::services::DatabaseService;
+use crate::dal::database::{DocumentFilter, DocumentSort};
 use crate::domain::documents::traits::Document;
 use crate::domain::documents::types::DocumentType;
 use crate::Result;
@@ -21,6 +22,13 @@ pub struct ArchiveResult {
     pub total_archived: usize,
 }
 
+/// Result of unarchive operation
+#[derive(Debug)]
+pub struct UnarchiveResult {
+    pub restored_documents: Vec<ArchivedDocument>,
+    pub total_restored: usize,
+}
+
 /// Information about an archived document
 #[derive(Debug)]
 pub struct ArchivedDocument {
@@ -33,18 +41,19 @@ pub struct ArchivedDocument {
 impl ArchiveService {
     // Helper methods to reduce duplication
 
-    /// Common helper for loading and marking a document as archived
-    async fn mark_as_archived_helper(
+    /// Common helper for loading a document and setting its archived flag
+    async fn set_archived_helper(
         &self,
         file_path: &Path,
         doc_type: DocumentType,
+        archived: bool,
     ) -> Result<()> {
         match doc_type {
             DocumentType::Vision => {
                 let mut vision = Vision::from_file(file_path)
                     .await
                     .map_err(|e| MetisError::InvalidDocument(e.to_string()))?;
-                vision.core_mut().archived = true;
+                vision.core_mut().archived = archived;
                 vision
                     .to_file(file_path)
                     .await
@@ -54,7 +63,7 @@ impl ArchiveService {
                 let mut initiative = Initiative::from_file(file_path)
                     .await
                     .map_err(|e| MetisError::InvalidDocument(e.to_string()))?;
-                initiative.core_mut().archived = true;
+                initiative.core_mut().archived = archived;
                 initiative
                     .to_file(file_path)
                     .await
@@ -64,7 +73,7 @@ impl ArchiveService {
                 let mut task = Task::from_file(file_path)
                     .await
                     .map_err(|e| MetisError::InvalidDocument(e.to_string()))?;
-                task.core_mut().archived = true;
+                task.core_mut().archived = archived;
                 task.to_file(file_path)
                     .await
                     .map_err(|e| MetisError::InvalidDocument(e.to_string()))?;
@@ -73,7 +82,7 @@ impl ArchiveService {
                 let mut adr = Adr::from_file(file_path)
                     .await
                     .map_err(|e| MetisError::InvalidDocument(e.to_string()))?;
-                adr.core_mut().archived = true;
+                adr.core_mut().archived = archived;
                 adr.to_file(file_path)
                     .await
                     .map_err(|e| MetisError::InvalidDocument(e.to_string()))?;
@@ -82,7 +91,7 @@ impl ArchiveService {
                 let mut spec = Specification::from_file(file_path)
                     .await
                     .map_err(|e| MetisError::InvalidDocument(e.to_string()))?;
-                spec.core_mut().archived = true;
+                spec.core_mut().archived = archived;
                 spec.to_file(file_path)
                     .await
                     .map_err(|e| MetisError::InvalidDocument(e.to_string()))?;
@@ -161,7 +170,7 @@ impl ArchiveService {
                             message: format!("Invalid document type: {}", e),
                         }
                     })?;
-                    self.mark_as_archived_helper(&absolute_path, dt).await?;
+                    self.set_archived_helper(&absolute_path, dt, true).await?;
                 }
 
                 // Archive the initiative directory (which moves everything intact)
@@ -212,7 +221,7 @@ impl ArchiveService {
         }
 
         // Mark as archived in frontmatter before moving
-        self.mark_as_archived_helper(file_path, doc_type).await?;
+        self.set_archived_helper(file_path, doc_type, true).await?;
 
         // Get document ID before moving
         let document_id = self.get_document_id(file_path, doc_type).await?;
@@ -271,7 +280,7 @@ impl ArchiveService {
         };
 
         // Mark as archived in frontmatter before moving
-        self.mark_as_archived_helper(&main_file, doc_type).await?;
+        self.set_archived_helper(&main_file, doc_type, true).await?;
 
         let document_id = self.get_document_id(&main_file, doc_type).await?;
 
@@ -518,7 +527,7 @@ impl ArchiveService {
                             message: format!("Invalid document type: {}", e),
                         }
                     })?;
-                    self.mark_as_archived_helper(&absolute_path, dt).await?;
+                    self.set_archived_helper(&absolute_path, dt, true).await?;
                 }
 
                 // Archive the initiative directory
@@ -530,7 +539,7 @@ impl ArchiveService {
             }
         }
 
-        // Documents are already marked as archived in frontmatter via mark_as_archived_helper
+        // Documents are already marked as archived in frontmatter via set_archived_helper
         // Database will be synced by the caller (MCP tool auto-sync)
 
         let total_archived = archived_documents.len();
@@ -540,6 +549,176 @@ impl ArchiveService {
         })
     }
 
+    /// Archive every unarchived document matching `filter`, each with its
+    /// children. Parents are archived before the documents they contain, and
+    /// documents already moved along with a parent are skipped.
+    pub async fn archive_by_filter(
+        &self,
+        filter: &DocumentFilter,
+        db_service: &mut DatabaseService,
+    ) -> Result<ArchiveResult> {
+        let filter = filter
+            .clone()
+            .include_archived(false)
+            .sort_by(DocumentSort::Type, false);
+        let matches = db_service.find_by_filter(&filter)?;
+
+        let mut archived_documents = Vec::new();
+        for doc in matches {
+            // The database is not synced between archives, so the file tells
+            // us whether an earlier parent already took this document along
+            if !self.workspace_dir.join(&doc.filepath).exists() {
+                continue;
+            }
+            let result = self
+                .archive_document_by_short_code(&doc.short_code, db_service)
+                .await?;
+            archived_documents.extend(result.archived_documents);
+        }
+
+        let total_archived = archived_documents.len();
+        Ok(ArchiveResult {
+            archived_documents,
+            total_archived,
+        })
+    }
+
+    /// Restore an archived document, by its short code, to where it was
+    /// archived from. An initiative comes back with everything archived in
+    /// its directory. A document whose parent is still archived, or whose
+    /// original path is taken, is left where it is.
+    pub async fn unarchive_document_by_short_code(
+        &self,
+        short_code: &str,
+        db_service: &mut DatabaseService,
+    ) -> Result<UnarchiveResult> {
+        let doc = db_service.find_by_short_code(short_code)?.ok_or_else(|| {
+            MetisError::DocumentNotFound {
+                id: short_code.to_string(),
+            }
+        })?;
+        if !doc.archived {
+            return Err(MetisError::ValidationFailed {
+                message: format!("{} is not archived", short_code),
+            });
+        }
+        if let Some(parent_code) = &doc.parent_id {
+            if let Some(parent) = db_service.find_by_short_code(parent_code)? {
+                if parent.archived {
+                    return Err(MetisError::ValidationFailed {
+                        message: format!(
+                            "{} belongs to {}, which is archived; restore {} first",
+                            short_code, parent_code, parent_code
+                        ),
+                    });
+                }
+            }
+        }
+
+        let doc_type = DocumentType::from_str(&doc.document_type).map_err(|e| {
+            MetisError::ValidationFailed {
+                message: format!("Invalid document type: {}", e),
+            }
+        })?;
+        let relative_path = Path::new(&doc.filepath)
+            .strip_prefix("archived")
+            .map_err(|_| {
+                MetisError::FileSystem(format!(
+                    "{} is not in the archived folder: {}",
+                    short_code, doc.filepath
+                ))
+            })?;
+        let archived_path = self.workspace_dir.join(&doc.filepath);
+        let original_path = self.workspace_dir.join(relative_path);
+
+        let restored = match doc_type {
+            DocumentType::Vision
+            | DocumentType::Task
+            | DocumentType::Adr
+            | DocumentType::Specification => {
+                if original_path.exists() {
+                    return Err(MetisError::FileSystem(format!(
+                        "Cannot restore {}: {} already exists",
+                        short_code,
+                        original_path.display()
+                    )));
+                }
+                if let Some(parent) = original_path.parent() {
+                    fs::create_dir_all(parent)
+                        .map_err(|e| MetisError::FileSystem(e.to_string()))?;
+                }
+                self.set_archived_helper(&archived_path, doc_type, false)
+                    .await?;
+                fs::rename(&archived_path, &original_path)
+                    .map_err(|e| MetisError::FileSystem(e.to_string()))?;
+                ArchivedDocument {
+                    document_id: doc.id,
+                    document_type: doc_type,
+                    original_path,
+                    archived_path,
+                }
+            }
+
+            DocumentType::Initiative => {
+                let archived_dir = archived_path.parent().unwrap();
+                let original_dir = original_path.parent().unwrap();
+                if original_path.exists() {
+                    return Err(MetisError::FileSystem(format!(
+                        "Cannot restore {}: {} already exists",
+                        short_code,
+                        original_path.display()
+                    )));
+                }
+
+                // Clear the flag on the initiative and everything archived with it
+                self.unmark_directory(archived_dir).await?;
+
+                if original_dir.exists() {
+                    self.merge_directory_contents(archived_dir, original_dir)
+                        .await?;
+                    fs::remove_dir_all(archived_dir)
+                        .map_err(|e| MetisError::FileSystem(e.to_string()))?;
+                } else {
+                    if let Some(parent) = original_dir.parent() {
+                        fs::create_dir_all(parent)
+                            .map_err(|e| MetisError::FileSystem(e.to_string()))?;
+                    }
+                    fs::rename(archived_dir, original_dir)
+                        .map_err(|e| MetisError::FileSystem(e.to_string()))?;
+                }
+                ArchivedDocument {
+                    document_id: doc.id,
+                    document_type: doc_type,
+                    original_path: original_dir.to_path_buf(),
+                    archived_path: archived_dir.to_path_buf(),
+                }
+            }
+        };
+
+        // The database will be synced by the caller
+        Ok(UnarchiveResult {
+            restored_documents: vec![restored],
+            total_restored: 1,
+        })
+    }
+
+    /// Clear the archived flag on every document in a directory tree
+    async fn unmark_directory(&self, dir: &Path) -> Result<()> {
+        for entry in fs::read_dir(dir).map_err(|e| MetisError::FileSystem(e.to_string()))? {
+            let path = entry
+                .map_err(|e| MetisError::FileSystem(e.to_string()))?
+                .path();
+            if path.is_dir() {
+                Box::pin(self.unmark_directory(&path)).await?;
+            } else if path.extension().is_some_and(|ext| ext == "md") {
+                if let Ok(doc_type) = self.determine_document_type(&path).await {
+                    self.set_archived_helper(&path, doc_type, false).await?;
+                }
+            }
+        }
+        Ok(())
+    }
+
     /// Check if a document is archived by its short code
     pub async fn is_document_archived_by_short_code(&self, short_code: &str) -> Result<bool> {
         // Create a temporary database service to resolve the short code
@@ -620,6 +799,162 @@ mod tests {
         assert!(!creation_result.file_path.exists());
     }
 
+    #[tokio::test]
+    async fn test_archive_by_filter_skips_documents_taken_by_parent() {
+        let temp_dir = tempdir().unwrap();
+        let workspace_dir = temp_dir.path().join(".metis");
+        fs::create_dir_all(&workspace_dir).unwrap();
+
+        let db_path = workspace_dir.join("metis.db");
+        let _db = crate::Database::new(&db_path.to_string_lossy()).unwrap();
+        let mut config_repo =
+            crate::dal::database::configuration_repository::ConfigurationRepository::new(
+                SqliteConnection::establish(&db_path.to_string_lossy()).unwrap(),
+            );
+        config_repo.set_project_prefix("TEST").unwrap();
+
+        let config = |title: &str| DocumentCreationConfig {
+            title: title.to_string(),
+            description: None,
+            parent_id: None,
+            tags: vec![],
+            phase: None,
+            complexity: None,
+        };
+        let creation_service = DocumentCreationService::new(&workspace_dir);
+        creation_service
+            .create_vision(config("Test Vision"))
+            .await
+            .unwrap();
+        let initiative = creation_service
+            .create_initiative(config("Test Initiative"))
+            .await
+            .unwrap();
+
+        let db = Database::new(&db_path.to_string_lossy()).unwrap();
+        let mut db_service =
+            crate::application::services::DatabaseService::new(db.into_repository());
+        crate::application::services::SyncService::new(&mut db_service)
+            .with_workspace_dir(&workspace_dir)
+            .sync_directory(&workspace_dir)
+            .await
+            .unwrap();
+        let task = creation_service
+            .create_task(config("Test Task"), &initiative.short_code)
+            .await
+            .unwrap();
+        crate::application::services::SyncService::new(&mut db_service)
+            .with_workspace_dir(&workspace_dir)
+            .sync_directory(&workspace_dir)
+            .await
+            .unwrap();
+
+        let filter = DocumentFilter::new().document_type("task").phase("todo");
+        let preview = db_service.find_by_filter(&filter).unwrap();
+        assert_eq!(preview.len(), 1);
+
+        let archive_service = ArchiveService::new(&workspace_dir);
+        let result = archive_service
+            .archive_by_filter(
+                &DocumentFilter::parse("type:initiative").unwrap(),
+                &mut db_service,
+            )
+            .await
+            .unwrap();
+        assert_eq!(result.total_archived, 1);
+
+        // The task went along with its initiative; the stale database row
+        // still matches but there is nothing left to archive
+        let result = archive_service
+            .archive_by_filter(&filter, &mut db_service)
+            .await
+            .unwrap();
+        assert_eq!(result.total_archived, 0);
+        assert!(!task.file_path.exists());
+        assert!(!initiative.file_path.exists());
+    }
+
+    #[tokio::test]
+    async fn test_unarchive_initiative_with_its_tasks() {
+        let temp_dir = tempdir().unwrap();
+        let workspace_dir = temp_dir.path().join(".metis");
+        fs::create_dir_all(&workspace_dir).unwrap();
+
+        let db_path = workspace_dir.join("metis.db");
+        let _db = crate::Database::new(&db_path.to_string_lossy()).unwrap();
+        let mut config_repo =
+            crate::dal::database::configuration_repository::ConfigurationRepository::new(
+                SqliteConnection::establish(&db_path.to_string_lossy()).unwrap(),
+            );
+        config_repo.set_project_prefix("TEST").unwrap();
+
+        let config = |title: &str| DocumentCreationConfig {
+            title: title.to_string(),
+            description: None,
+            parent_id: None,
+            tags: vec![],
+            phase: None,
+            complexity: None,
+        };
+        let creation_service = DocumentCreationService::new(&workspace_dir);
+        creation_service
+            .create_vision(config("Test Vision"))
+            .await
+            .unwrap();
+        let initiative = creation_service
+            .create_initiative(config("Test Initiative"))
+            .await
+            .unwrap();
+
+        let db = Database::new(&db_path.to_string_lossy()).unwrap();
+        let mut db_service =
+            crate::application::services::DatabaseService::new(db.into_repository());
+        async fn sync(db_service: &mut DatabaseService, workspace_dir: &Path) {
+            crate::application::services::SyncService::new(db_service)
+                .with_workspace_dir(workspace_dir)
+                .sync_directory(workspace_dir)
+                .await
+                .unwrap();
+        }
+        sync(&mut db_service, &workspace_dir).await;
+        let task = creation_service
+            .create_task(config("Test Task"), &initiative.short_code)
+            .await
+            .unwrap();
+        sync(&mut db_service, &workspace_dir).await;
+
+        let archive_service = ArchiveService::new(&workspace_dir);
+        archive_service
+            .archive_document_by_short_code(&initiative.short_code, &mut db_service)
+            .await
+            .unwrap();
+        sync(&mut db_service, &workspace_dir).await;
+
+        // The task can't come back while its initiative is archived
+        let error = archive_service
+            .unarchive_document_by_short_code(&task.short_code, &mut db_service)
+            .await
+            .unwrap_err();
+        assert!(error.to_string().contains(&initiative.short_code));
+
+        let result = archive_service
+            .unarchive_document_by_short_code(&initiative.short_code, &mut db_service)
+            .await
+            .unwrap();
+        assert_eq!(result.total_restored, 1);
+        assert!(initiative.file_path.exists());
+        assert!(task.file_path.exists());
+        let restored = Task::from_file(&task.file_path).await.unwrap();
+        assert!(!restored.core().archived);
+
+        sync(&mut db_service, &workspace_dir).await;
+        let error = archive_service
+            .unarchive_document_by_short_code(&initiative.short_code, &mut db_service)
+            .await
+            .unwrap_err();
+        assert!(error.to_string().contains("not archived"));
+    }
+
     #[tokio::test]
     async fn test_get_archived_documents() {
         let temp_dir = tempdir().unwrap();
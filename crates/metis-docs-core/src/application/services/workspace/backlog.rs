@@ -0,0 +1,314 @@
+use crate::application::services::workspace::{
+    BacklogCategory, PhaseTransitionService, PropertyChange, PropertyService, ReassignmentResult,
+    ReassignmentService, TagService,
+};
+use crate::application::services::DatabaseService;
+use crate::dal::database::models::Document;
+use crate::domain::documents::types::{DocumentType, Phase};
+use crate::{MetisError, Result};
+use std::path::{Path, PathBuf};
+
+/// Frontmatter property holding a backlog item's severity
+pub const SEVERITY: &str = "severity";
+
+/// How urgent a backlog item is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    Low,
+    Medium,
+    High,
+    Critical,
+}
+
+impl Severity {
+    /// Every severity, most urgent first
+    pub const ALL: [Severity; 4] = [Self::Critical, Self::High, Self::Medium, Self::Low];
+
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "low" => Some(Self::Low),
+            "medium" => Some(Self::Medium),
+            "high" => Some(Self::High),
+            "critical" => Some(Self::Critical),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Low => "low",
+            Self::Medium => "medium",
+            Self::High => "high",
+            Self::Critical => "critical",
+        }
+    }
+}
+
+/// An unfinished task filed under `backlog/`
+#[derive(Debug, Clone)]
+pub struct BacklogItem {
+    pub document: Document,
+    /// From the `backlog/<category>/` directory, or a `#bug`, `#feature` or
+    /// `#tech-debt` tag for items filed directly under `backlog/`
+    pub category: Option<BacklogCategory>,
+    /// From the `severity` property; unrecognized values count as unset
+    pub severity: Option<Severity>,
+}
+
+impl BacklogItem {
+    /// Items need triage until they have both a category and a severity
+    pub fn is_untriaged(&self) -> bool {
+        self.category.is_none() || self.severity.is_none()
+    }
+}
+
+/// Service for grooming the backlog: listing items, categorizing them,
+/// setting their severity and promoting them into initiatives.
+///
+/// Like the other workspace services it edits files only; callers sync
+/// afterwards so the database follows.
+pub struct BacklogService {
+    workspace_dir: PathBuf,
+}
+
+impl BacklogService {
+    /// Create a new backlog service for a workspace
+    pub fn new<P: AsRef<Path>>(workspace_dir: P) -> Self {
+        let path = workspace_dir.as_ref();
+        let absolute_path = if path.is_absolute() {
+            path.to_path_buf()
+        } else {
+            std::env::current_dir().unwrap_or_default().join(path)
+        };
+
+        Self {
+            workspace_dir: absolute_path,
+        }
+    }
+
+    /// Unfinished backlog items, optionally only those in `category`,
+    /// ordered by short code
+    pub fn items(
+        &self,
+        category: Option<BacklogCategory>,
+        db_service: &mut DatabaseService,
+    ) -> Result<Vec<BacklogItem>> {
+        let mut items = Vec::new();
+        for doc in db_service.find_by_type(DocumentType::Task)? {
+            if doc.archived
+                || !doc.workspace.is_empty()
+                || doc.phase == Phase::Completed.to_string()
+                || !Self::in_backlog(&doc)
+            {
+                continue;
+            }
+            let item = Self::item(doc, db_service)?;
+            if category.is_none() || item.category == category {
+                items.push(item);
+            }
+        }
+        items.sort_by(|a, b| a.document.short_code.cmp(&b.document.short_code));
+        Ok(items)
+    }
+
+    /// Put an item in `category`: retag it and move it into the category's
+    /// directory. Returns the move, or `None` if it was already filed there.
+    pub async fn set_category(
+        &self,
+        short_code: &str,
+        category: BacklogCategory,
+        db_service: &mut DatabaseService,
+    ) -> Result<Option<ReassignmentResult>> {
+        let item = self.find(short_code, db_service)?;
+
+        // Retag first: the tag service edits the file at its current path
+        let tags = TagService::new(&self.workspace_dir);
+        for other in BacklogCategory::ALL {
+            if other != category {
+                tags.remove_tag(short_code, other.label(), db_service)?;
+            }
+        }
+        tags.add_tag(short_code, category.label(), db_service)?;
+
+        if Self::directory_category(&item.document) == Some(category) {
+            return Ok(None);
+        }
+        ReassignmentService::new(&self.workspace_dir)
+            .reassign_to_backlog(short_code, category, db_service)
+            .await
+            .map(Some)
+    }
+
+    /// Set an item's severity, or clear it when `severity` is `None`
+    pub fn set_severity(
+        &self,
+        short_code: &str,
+        severity: Option<Severity>,
+        db_service: &mut DatabaseService,
+    ) -> Result<PropertyChange> {
+        self.find(short_code, db_service)?;
+        PropertyService::new(&self.workspace_dir).set_property(
+            short_code,
+            SEVERITY,
+            severity.map(|severity| severity.as_str()),
+            db_service,
+        )
+    }
+
+    /// Move an item under an initiative. Items still in the `backlog` phase
+    /// move on to `todo`, since they're now planned work.
+    pub async fn promote(
+        &self,
+        short_code: &str,
+        initiative: &str,
+        db_service: &mut DatabaseService,
+    ) -> Result<ReassignmentResult> {
+        let item = self.find(short_code, db_service)?;
+        let result = ReassignmentService::new(&self.workspace_dir)
+            .reassign_to_initiative(short_code, initiative, db_service)
+            .await?;
+
+        if item.document.phase == Phase::Backlog.to_string() {
+            PhaseTransitionService::new(&self.workspace_dir)
+                .transition_document(short_code, Phase::Todo)
+                .await?;
+        }
+        Ok(result)
+    }
+
+    /// A backlog item by short code
+    fn find(&self, short_code: &str, db_service: &mut DatabaseService) -> Result<BacklogItem> {
+        let doc = db_service
+            .find_by_short_code(short_code)?
+            .ok_or_else(|| MetisError::NotFound(format!("Document '{}' not found", short_code)))?;
+        if doc.document_type != "task" || !Self::in_backlog(&doc) {
+            return Err(MetisError::ValidationFailed {
+                message: format!("'{}' is not a backlog item", short_code),
+            });
+        }
+        Self::item(doc, db_service)
+    }
+
+    fn item(document: Document, db_service: &mut DatabaseService) -> Result<BacklogItem> {
+        let category = match Self::directory_category(&document) {
+            Some(category) => Some(category),
+            None => db_service
+                .get_tags_for_document(&document.filepath)?
+                .iter()
+                .find_map(|tag| BacklogCategory::from_str(tag.trim_start_matches('#'))),
+        };
+        let severity = db_service
+            .find_properties(&document.filepath)?
+            .into_iter()
+            .find(|property| property.key == SEVERITY)
+            .and_then(|property| Severity::from_str(&property.value));
+
+        Ok(BacklogItem {
+            document,
+            category,
+            severity,
+        })
+    }
+
+    fn in_backlog(doc: &Document) -> bool {
+        Path::new(&doc.filepath).starts_with("backlog")
+    }
+
+    /// The category implied by a `backlog/<category>/` path
+    fn directory_category(doc: &Document) -> Option<BacklogCategory> {
+        let mut components = Path::new(&doc.filepath).components();
+        if components.next()?.as_os_str() != "backlog" {
+            return None;
+        }
+        let directory = components.next()?.as_os_str().to_str()?;
+        BacklogCategory::ALL
+            .into_iter()
+            .find(|category| category.directory_name() == directory)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::services::document::creation::DocumentCreationConfig;
+    use crate::application::services::document::DocumentCreationService;
+    use crate::application::services::workspace::test_support::TestWorkspace;
+    use crate::domain::documents::types::Tag;
+
+    #[test]
+    fn test_severity() {
+        assert_eq!(Severity::from_str("High"), Some(Severity::High));
+        assert_eq!(Severity::from_str("urgent"), None);
+        assert!(Severity::Critical > Severity::Low);
+        assert_eq!(Severity::ALL[0].as_str(), "critical");
+    }
+
+    #[tokio::test]
+    async fn test_categorize_backlog_items() {
+        let workspace = TestWorkspace::new();
+
+        let creation = DocumentCreationService::new(&workspace.metis_dir);
+        let backlog_item = |title: &str, tags: Vec<Tag>| DocumentCreationConfig {
+            title: title.to_string(),
+            description: None,
+            parent_id: None,
+            tags,
+            phase: None,
+            complexity: None,
+        };
+        let bug = creation
+            .create_backlog_item(backlog_item(
+                "Crash on save",
+                vec![Tag::Label("bug".to_string())],
+            ))
+            .await
+            .unwrap();
+        let idea = creation
+            .create_backlog_item(backlog_item("Dark mode", vec![]))
+            .await
+            .unwrap();
+
+        let mut db_service = workspace.synced().await;
+
+        let service = BacklogService::new(&workspace.metis_dir);
+        let items = service.items(None, &mut db_service).unwrap();
+        assert_eq!(items.len(), 2);
+        assert!(items.iter().all(|item| item.is_untriaged()));
+        let bugs = service
+            .items(Some(BacklogCategory::Bug), &mut db_service)
+            .unwrap();
+        assert_eq!(bugs.len(), 1);
+        assert_eq!(bugs[0].document.short_code, bug.short_code);
+
+        let moved = service
+            .set_category(&idea.short_code, BacklogCategory::Feature, &mut db_service)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(moved
+            .new_path
+            .ends_with(format!("backlog/features/{}.md", idea.short_code)));
+        service
+            .set_severity(&idea.short_code, Some(Severity::High), &mut db_service)
+            .unwrap();
+        workspace.sync(&mut db_service).await;
+
+        let features = service
+            .items(Some(BacklogCategory::Feature), &mut db_service)
+            .unwrap();
+        assert_eq!(features.len(), 1);
+        assert_eq!(features[0].severity, Some(Severity::High));
+        assert!(!features[0].is_untriaged());
+        let tags = db_service
+            .get_tags_for_document(&features[0].document.filepath)
+            .unwrap();
+        assert!(tags.contains(&"#feature".to_string()));
+
+        // Already filed under bugs, so only the tag is (re)applied
+        assert!(service
+            .set_category(&bug.short_code, BacklogCategory::Bug, &mut db_service)
+            .await
+            .unwrap()
+            .is_none());
+    }
+}
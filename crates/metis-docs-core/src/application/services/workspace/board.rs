@@ -0,0 +1,238 @@
+use crate::application::services::DatabaseService;
+use crate::dal::database::models::Document;
+use crate::domain::documents::criteria::parse_criteria;
+use crate::domain::documents::types::DocumentType;
+use crate::{MetisError, Result};
+use serde::Serialize;
+
+/// A kanban board: the documents of one type laid out by phase
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BoardKind {
+    Vision,
+    Initiative,
+    Task,
+    /// Tasks filed under backlog/ rather than an initiative
+    Backlog,
+}
+
+impl BoardKind {
+    pub const ALL: [BoardKind; 4] = [
+        BoardKind::Vision,
+        BoardKind::Initiative,
+        BoardKind::Task,
+        BoardKind::Backlog,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            BoardKind::Vision => "vision",
+            BoardKind::Initiative => "initiative",
+            BoardKind::Task => "task",
+            BoardKind::Backlog => "backlog",
+        }
+    }
+
+    pub fn document_type(&self) -> DocumentType {
+        match self {
+            BoardKind::Vision => DocumentType::Vision,
+            BoardKind::Initiative => DocumentType::Initiative,
+            BoardKind::Task | BoardKind::Backlog => DocumentType::Task,
+        }
+    }
+
+    /// Whether a document of this board's type belongs on the board
+    pub fn includes(&self, doc: &Document) -> bool {
+        let in_backlog = doc.filepath.starts_with("backlog/");
+        match self {
+            BoardKind::Task => !in_backlog,
+            BoardKind::Backlog => in_backlog,
+            _ => true,
+        }
+    }
+}
+
+impl std::str::FromStr for BoardKind {
+    type Err = MetisError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        BoardKind::ALL
+            .into_iter()
+            .find(|kind| kind.name() == s.trim().to_lowercase())
+            .ok_or_else(|| MetisError::ValidationFailed {
+                message: format!(
+                    "'{}' is not a board (expected vision, initiative, task or backlog)",
+                    s
+                ),
+            })
+    }
+}
+
+/// One board, its columns in phase order
+#[derive(Debug, Clone, Serialize)]
+pub struct Board {
+    pub board: String,
+    pub columns: Vec<BoardColumn>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BoardColumn {
+    pub phase: String,
+    pub cards: Vec<BoardCard>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BoardCard {
+    pub code: String,
+    pub title: String,
+    pub phase: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assignee: Option<String>,
+    /// Checked and total exit criteria; absent when the document has none
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub criteria: Option<CriteriaProgress>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct CriteriaProgress {
+    pub checked: usize,
+    pub total: usize,
+}
+
+/// Service laying the workspace out as kanban boards, the same view
+/// `metis board` shows.
+///
+/// Everything is read from the database, so callers sync first.
+pub struct BoardService;
+
+impl BoardService {
+    /// The boards of `kinds`, in that order. Empty boards are left out
+    /// unless only one board was asked for.
+    pub fn boards(
+        kinds: &[BoardKind],
+        include_archived: bool,
+        db_service: &mut DatabaseService,
+    ) -> Result<Vec<Board>> {
+        let mut boards = Vec::new();
+        for kind in kinds {
+            let mut documents: Vec<Document> = db_service
+                .find_by_type(kind.document_type())?
+                .into_iter()
+                .filter(|doc| (include_archived || !doc.archived) && kind.includes(doc))
+                .collect();
+
+            // Empty boards are noise when showing several
+            if kinds.len() > 1 && documents.is_empty() {
+                continue;
+            }
+            documents.sort_by(|a, b| a.short_code.cmp(&b.short_code));
+            let mut cards = Vec::with_capacity(documents.len());
+            for doc in &documents {
+                cards.push(Self::card(doc, db_service)?);
+            }
+            boards.push(Self::layout(*kind, cards));
+        }
+        Ok(boards)
+    }
+
+    /// Lay cards out in the type's phase order. Phases outside the normal
+    /// sequence (e.g. blocked tasks) get their own columns at the end.
+    pub fn layout(kind: BoardKind, cards: Vec<BoardCard>) -> Board {
+        let mut phases: Vec<String> = kind
+            .document_type()
+            .phase_sequence()
+            .iter()
+            .map(|p| p.to_string())
+            .collect();
+        let mut extra: Vec<String> = cards
+            .iter()
+            .filter(|card| !phases.contains(&card.phase))
+            .map(|card| card.phase.clone())
+            .collect();
+        extra.sort();
+        extra.dedup();
+        phases.extend(extra);
+
+        let columns = phases
+            .into_iter()
+            .map(|phase| BoardColumn {
+                cards: cards
+                    .iter()
+                    .filter(|card| card.phase == phase)
+                    .cloned()
+                    .collect(),
+                phase,
+            })
+            .collect();
+
+        Board {
+            board: kind.name().to_string(),
+            columns,
+        }
+    }
+
+    fn card(doc: &Document, db_service: &mut DatabaseService) -> Result<BoardCard> {
+        let assignee = db_service
+            .find_properties(&doc.filepath)?
+            .into_iter()
+            .find(|property| property.key == "assignee")
+            .map(|property| property.value)
+            .filter(|assignee| !assignee.is_empty());
+        let criteria = doc
+            .content
+            .as_deref()
+            .map(parse_criteria)
+            .filter(|criteria| !criteria.is_empty())
+            .map(|criteria| CriteriaProgress {
+                checked: criteria.iter().filter(|c| c.checked).count(),
+                total: criteria.len(),
+            });
+
+        Ok(BoardCard {
+            code: doc.short_code.clone(),
+            title: doc.title.clone(),
+            phase: doc.phase.clone(),
+            assignee,
+            criteria,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn card(code: &str, phase: &str) -> BoardCard {
+        BoardCard {
+            code: code.to_string(),
+            title: format!("Task {}", code),
+            phase: phase.to_string(),
+            assignee: None,
+            criteria: None,
+        }
+    }
+
+    #[test]
+    fn test_layout_orders_columns_by_phase() {
+        let board = BoardService::layout(
+            BoardKind::Task,
+            vec![
+                card("TEST-T-0001", "blocked"),
+                card("TEST-T-0002", "active"),
+            ],
+        );
+        let phases: Vec<&str> = board.columns.iter().map(|c| c.phase.as_str()).collect();
+        assert_eq!(
+            phases,
+            vec!["backlog", "todo", "active", "completed", "blocked"]
+        );
+        assert_eq!(board.columns[2].cards[0].code, "TEST-T-0002");
+        assert_eq!(board.columns[4].cards[0].code, "TEST-T-0001");
+        assert!(board.columns[0].cards.is_empty());
+    }
+
+    #[test]
+    fn test_board_kind_from_str() {
+        assert_eq!("Backlog".parse::<BoardKind>().unwrap(), BoardKind::Backlog);
+        assert!("sprint".parse::<BoardKind>().is_err());
+    }
+}
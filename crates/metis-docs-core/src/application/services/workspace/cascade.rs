@@ -0,0 +1,245 @@
+use super::transition::{PhaseTransitionService, TransitionResult};
+use crate::application::services::DatabaseService;
+use crate::domain::documents::types::{DocumentType, Phase, Tag};
+use crate::MetisError;
+use crate::Result;
+use std::collections::VecDeque;
+use std::path::Path;
+
+/// Service for the transitions that follow from transitioning a parent
+/// document, e.g. completing the open tasks of a completed initiative
+pub struct CascadeService {
+    transition_service: PhaseTransitionService,
+}
+
+/// A dependent document and the phases it has to pass through
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CascadeStep {
+    pub short_code: String,
+    pub title: String,
+    pub document_type: DocumentType,
+    pub from_phase: Phase,
+    /// Phases to transition through, in order; the last one is the target
+    pub path: Vec<Phase>,
+}
+
+/// Dependent transitions for one parent transition
+#[derive(Debug, Clone, Default)]
+pub struct CascadePlan {
+    pub steps: Vec<CascadeStep>,
+    /// Dependent documents that cannot reach the target phase
+    pub stuck: Vec<String>,
+}
+
+impl CascadePlan {
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty() && self.stuck.is_empty()
+    }
+}
+
+impl CascadeService {
+    /// Create a new cascade service for a workspace
+    pub fn new<P: AsRef<Path>>(workspace_dir: P) -> Self {
+        Self {
+            transition_service: PhaseTransitionService::new(workspace_dir),
+        }
+    }
+
+    /// Work out which dependent documents follow a parent into
+    /// `target_phase`, without changing anything
+    pub fn plan(
+        &self,
+        short_code: &str,
+        target_phase: Phase,
+        db_service: &mut DatabaseService,
+    ) -> Result<CascadePlan> {
+        let parent = db_service
+            .find_by_short_code(short_code)?
+            .ok_or_else(|| MetisError::NotFound(format!("Document '{}' not found", short_code)))?;
+        let parent_type: DocumentType = parent
+            .document_type
+            .parse()
+            .map_err(|e: String| MetisError::InvalidDocument(e))?;
+
+        let mut plan = CascadePlan::default();
+        let Some((child_type, child_phase)) = cascade_rule(parent_type, target_phase) else {
+            return Ok(plan);
+        };
+
+        let mut children: Vec<_> = db_service
+            .find_initiative_hierarchy_by_short_code(short_code)?
+            .into_iter()
+            .filter(|d| !d.archived && d.short_code != parent.short_code)
+            .filter(|d| d.document_type == child_type.to_string())
+            .collect();
+        children.sort_by(|a, b| a.short_code.cmp(&b.short_code));
+
+        for child in children {
+            let Some(from_phase) = parse_phase(&child.phase) else {
+                plan.stuck.push(child.short_code);
+                continue;
+            };
+            if from_phase == child_phase {
+                continue;
+            }
+            match transition_path(child_type, from_phase, child_phase) {
+                Some(path) => plan.steps.push(CascadeStep {
+                    short_code: child.short_code,
+                    title: child.title,
+                    document_type: child_type,
+                    from_phase,
+                    path,
+                }),
+                None => plan.stuck.push(child.short_code),
+            }
+        }
+
+        Ok(plan)
+    }
+
+    /// Apply a plan, transitioning each document one phase at a time
+    pub async fn apply(&self, plan: &CascadePlan) -> Result<Vec<TransitionResult>> {
+        let mut results = Vec::new();
+        for step in &plan.steps {
+            for phase in &step.path {
+                results.push(
+                    self.transition_service
+                        .transition_document(&step.short_code, *phase)
+                        .await?,
+                );
+            }
+        }
+        Ok(results)
+    }
+}
+
+/// The document type and phase that children of a parent moving into
+/// `phase` must follow into, if any
+fn cascade_rule(parent_type: DocumentType, phase: Phase) -> Option<(DocumentType, Phase)> {
+    match (parent_type, phase) {
+        (DocumentType::Initiative, Phase::Completed) => {
+            Some((DocumentType::Task, Phase::Completed))
+        }
+        _ => None,
+    }
+}
+
+/// Shortest sequence of valid transitions from one phase to another
+fn transition_path(doc_type: DocumentType, from: Phase, to: Phase) -> Option<Vec<Phase>> {
+    let mut queue = VecDeque::from([vec![from]]);
+    while let Some(path) = queue.pop_front() {
+        let current = *path.last()?;
+        for next in doc_type.valid_transitions_from(current) {
+            if path.contains(&next) {
+                continue;
+            }
+            let mut extended = path.clone();
+            extended.push(next);
+            if next == to {
+                return Some(extended.split_off(1));
+            }
+            queue.push_back(extended);
+        }
+    }
+    None
+}
+
+fn parse_phase(phase: &str) -> Option<Phase> {
+    match format!("#phase/{}", phase).parse::<Tag>() {
+        Ok(Tag::Phase(phase)) => Some(phase),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::services::document::DocumentCreationService;
+    use crate::application::services::workspace::test_support::{config, TestWorkspace};
+
+    #[tokio::test]
+    async fn test_completing_initiative_cascades_to_open_tasks() {
+        let workspace = TestWorkspace::new();
+
+        let creation_service = DocumentCreationService::new(&workspace.metis_dir);
+        creation_service
+            .create_vision(config("Vision"))
+            .await
+            .unwrap();
+        let initiative = creation_service
+            .create_initiative(config("Initiative"))
+            .await
+            .unwrap();
+        workspace.synced().await;
+        let first = creation_service
+            .create_task(config("First"), &initiative.short_code)
+            .await
+            .unwrap();
+        let second = creation_service
+            .create_task(config("Second"), &initiative.short_code)
+            .await
+            .unwrap();
+        PhaseTransitionService::new(&workspace.metis_dir)
+            .transition_document(&second.short_code, Phase::Active)
+            .await
+            .unwrap();
+
+        let mut db_service = workspace.synced().await;
+        let service = CascadeService::new(&workspace.metis_dir);
+
+        // Only completing the initiative has dependents
+        let plan = service
+            .plan(&initiative.short_code, Phase::Design, &mut db_service)
+            .unwrap();
+        assert!(plan.is_empty());
+
+        let plan = service
+            .plan(&initiative.short_code, Phase::Completed, &mut db_service)
+            .unwrap();
+        assert_eq!(plan.steps.len(), 2);
+        assert_eq!(plan.steps[0].short_code, first.short_code);
+        assert_eq!(plan.steps[0].path, vec![Phase::Active, Phase::Completed]);
+        assert_eq!(plan.steps[1].path, vec![Phase::Completed]);
+
+        let results = service.apply(&plan).await.unwrap();
+        assert_eq!(results.len(), 3);
+
+        let mut db_service = workspace.synced().await;
+        let plan = service
+            .plan(&initiative.short_code, Phase::Completed, &mut db_service)
+            .unwrap();
+        assert!(plan.is_empty());
+    }
+
+    #[test]
+    fn test_transition_path() {
+        assert_eq!(
+            transition_path(DocumentType::Task, Phase::Backlog, Phase::Completed),
+            Some(vec![Phase::Todo, Phase::Active, Phase::Completed])
+        );
+        assert_eq!(
+            transition_path(DocumentType::Task, Phase::Blocked, Phase::Completed),
+            Some(vec![Phase::Active, Phase::Completed])
+        );
+        assert_eq!(
+            transition_path(DocumentType::Task, Phase::Completed, Phase::Active),
+            None
+        );
+    }
+
+    #[test]
+    fn test_cascade_rule() {
+        assert_eq!(
+            cascade_rule(DocumentType::Initiative, Phase::Completed),
+            Some((DocumentType::Task, Phase::Completed))
+        );
+        assert_eq!(cascade_rule(DocumentType::Initiative, Phase::Active), None);
+        assert_eq!(cascade_rule(DocumentType::Vision, Phase::Published), None);
+    }
+
+    #[test]
+    fn test_parse_phase() {
+        assert_eq!(parse_phase("blocked"), Some(Phase::Blocked));
+        assert_eq!(parse_phase("unknown"), None);
+    }
+}
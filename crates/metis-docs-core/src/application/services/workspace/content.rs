@@ -0,0 +1,107 @@
+use crate::application::services::DatabaseService;
+use crate::domain::documents::helpers::replace_document_body;
+use crate::{MetisError, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Service for editing the body of a document, everything after its
+/// frontmatter.
+///
+/// The frontmatter is re-read from the file on every update and written back
+/// unchanged, so an editor holding an older copy of the document can't undo
+/// a phase transition or property change made in the meantime. Frontmatter
+/// fields go through [`super::PropertyService`] instead.
+pub struct ContentService {
+    workspace_dir: PathBuf,
+}
+
+impl ContentService {
+    /// Create a new content service for a workspace
+    pub fn new<P: AsRef<Path>>(workspace_dir: P) -> Self {
+        let path = workspace_dir.as_ref();
+        let absolute_path = if path.is_absolute() {
+            path.to_path_buf()
+        } else {
+            std::env::current_dir().unwrap_or_default().join(path)
+        };
+
+        Self {
+            workspace_dir: absolute_path,
+        }
+    }
+
+    /// Replace the body of a document, keeping its frontmatter. Returns
+    /// whether the file changed.
+    pub fn update_document_content(
+        &self,
+        short_code: &str,
+        body: &str,
+        db_service: &mut DatabaseService,
+    ) -> Result<bool> {
+        let doc = db_service
+            .find_by_short_code(short_code)?
+            .ok_or_else(|| MetisError::NotFound(format!("Document '{}' not found", short_code)))?;
+        let path = self.workspace_dir.join(&doc.filepath);
+        let content = fs::read_to_string(&path).map_err(|e| {
+            MetisError::FileSystem(format!("Failed to read {}: {}", path.display(), e))
+        })?;
+
+        let updated =
+            replace_document_body(&content, body).ok_or_else(|| MetisError::ValidationFailed {
+                message: format!("{} has unterminated frontmatter", short_code),
+            })?;
+        if updated == content {
+            return Ok(false);
+        }
+        fs::write(&path, &updated).map_err(|e| {
+            MetisError::FileSystem(format!("Failed to update {}: {}", path.display(), e))
+        })?;
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::services::document::DocumentCreationService;
+    use crate::application::services::workspace::test_support::{config, TestWorkspace};
+
+    #[tokio::test]
+    async fn test_update_document_content() {
+        let workspace = TestWorkspace::new();
+
+        let vision = DocumentCreationService::new(&workspace.metis_dir)
+            .create_vision(config("Test Vision"))
+            .await
+            .unwrap();
+
+        let mut db_service = workspace.synced().await;
+
+        let original = std::fs::read_to_string(&vision.file_path).unwrap();
+        let frontmatter_end = original.find("\n---").unwrap() + 4;
+
+        let service = ContentService::new(&workspace.metis_dir);
+        assert!(service
+            .update_document_content(
+                &vision.short_code,
+                "# Test Vision\n\nNew body",
+                &mut db_service
+            )
+            .unwrap());
+        let updated = std::fs::read_to_string(&vision.file_path).unwrap();
+        assert_eq!(updated[..frontmatter_end], original[..frontmatter_end]);
+        assert!(updated.ends_with("\n\n# Test Vision\n\nNew body\n"));
+
+        // Saving the same body again leaves the file alone
+        assert!(!service
+            .update_document_content(
+                &vision.short_code,
+                "# Test Vision\n\nNew body",
+                &mut db_service
+            )
+            .unwrap());
+        assert!(service
+            .update_document_content("TEST-V-9999", "Body", &mut db_service)
+            .is_err());
+    }
+}
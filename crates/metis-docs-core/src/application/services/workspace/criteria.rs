@@ -0,0 +1,176 @@
+use crate::application::services::DatabaseService;
+use crate::dal::database::models::Document;
+use crate::domain::documents::criteria::{all_met, parse_criteria, set_criterion, Criterion};
+use crate::domain::documents::helpers::set_frontmatter_value;
+use crate::{MetisError, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// A document's exit criteria and whether they are all met
+#[derive(Debug, Clone)]
+pub struct CriteriaStatus {
+    pub short_code: String,
+    pub title: String,
+    pub criteria: Vec<Criterion>,
+    /// True when there is at least one criterion and all are checked
+    pub met: bool,
+}
+
+impl CriteriaStatus {
+    /// Number of checked criteria
+    pub fn checked(&self) -> usize {
+        self.criteria
+            .iter()
+            .filter(|criterion| criterion.checked)
+            .count()
+    }
+}
+
+/// Service for reading and checking off the exit/acceptance criteria
+/// checkboxes in a document.
+///
+/// Checking a criterion also keeps the `exit_criteria_met` frontmatter field
+/// in step; the database picks both up on the next sync.
+pub struct CriteriaService {
+    workspace_dir: PathBuf,
+}
+
+impl CriteriaService {
+    /// Create a new criteria service for a workspace
+    pub fn new<P: AsRef<Path>>(workspace_dir: P) -> Self {
+        let path = workspace_dir.as_ref();
+        let absolute_path = if path.is_absolute() {
+            path.to_path_buf()
+        } else {
+            std::env::current_dir().unwrap_or_default().join(path)
+        };
+
+        Self {
+            workspace_dir: absolute_path,
+        }
+    }
+
+    /// The criteria of a document
+    pub fn status(
+        &self,
+        short_code: &str,
+        db_service: &mut DatabaseService,
+    ) -> Result<CriteriaStatus> {
+        let doc = Self::find(short_code, db_service)?;
+        let content = self.read(&doc)?;
+        Ok(Self::status_of(&doc, &content))
+    }
+
+    /// Check or uncheck criterion `number` (starting at 1)
+    pub fn set_checked(
+        &self,
+        short_code: &str,
+        number: usize,
+        checked: bool,
+        db_service: &mut DatabaseService,
+    ) -> Result<CriteriaStatus> {
+        let doc = Self::find(short_code, db_service)?;
+        let content = self.read(&doc)?;
+        let count = parse_criteria(&content).len();
+        let updated = set_criterion(&content, number, checked).ok_or_else(|| {
+            MetisError::ValidationFailed {
+                message: match count {
+                    0 => format!("{} has no exit criteria", short_code),
+                    _ => format!(
+                        "{} has {} criteria; there is no criterion {}",
+                        short_code, count, number
+                    ),
+                },
+            }
+        })?;
+
+        let met = all_met(&parse_criteria(&updated)).to_string();
+        let updated = set_frontmatter_value(&updated, "exit_criteria_met", Some(&met));
+        if updated != content {
+            let path = self.workspace_dir.join(&doc.filepath);
+            fs::write(&path, &updated).map_err(|e| {
+                MetisError::FileSystem(format!("Failed to update {}: {}", path.display(), e))
+            })?;
+        }
+
+        Ok(Self::status_of(&doc, &updated))
+    }
+
+    fn find(short_code: &str, db_service: &mut DatabaseService) -> Result<Document> {
+        db_service
+            .find_by_short_code(short_code)?
+            .ok_or_else(|| MetisError::NotFound(format!("Document '{}' not found", short_code)))
+    }
+
+    fn read(&self, doc: &Document) -> Result<String> {
+        let path = self.workspace_dir.join(&doc.filepath);
+        fs::read_to_string(&path).map_err(|e| {
+            MetisError::FileSystem(format!("Failed to read {}: {}", path.display(), e))
+        })
+    }
+
+    fn status_of(doc: &Document, content: &str) -> CriteriaStatus {
+        let criteria = parse_criteria(content);
+        CriteriaStatus {
+            short_code: doc.short_code.clone(),
+            title: doc.title.clone(),
+            met: all_met(&criteria),
+            criteria,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::services::document::DocumentCreationService;
+    use crate::application::services::workspace::test_support::{config, TestWorkspace};
+
+    #[tokio::test]
+    async fn test_check_criteria() {
+        let workspace = TestWorkspace::new();
+
+        let vision = DocumentCreationService::new(&workspace.metis_dir)
+            .create_vision(config("Test Vision"))
+            .await
+            .unwrap();
+
+        let mut db_service = workspace.synced().await;
+
+        let service = CriteriaService::new(&workspace.metis_dir);
+        let status = service.status(&vision.short_code, &mut db_service).unwrap();
+        assert!(!status.criteria.is_empty());
+        assert!(!status.met);
+        assert_eq!(status.checked(), 0);
+
+        let total = status.criteria.len();
+        for number in 1..=total {
+            service
+                .set_checked(&vision.short_code, number, true, &mut db_service)
+                .unwrap();
+        }
+        assert!(service
+            .set_checked(&vision.short_code, total + 1, true, &mut db_service)
+            .is_err());
+        workspace.sync(&mut db_service).await;
+
+        let status = service.status(&vision.short_code, &mut db_service).unwrap();
+        assert!(status.met);
+        assert_eq!(status.checked(), total);
+        let doc = db_service
+            .find_by_short_code(&vision.short_code)
+            .unwrap()
+            .unwrap();
+        assert!(doc.exit_criteria_met);
+
+        service
+            .set_checked(&vision.short_code, 1, false, &mut db_service)
+            .unwrap();
+        workspace.sync(&mut db_service).await;
+        let doc = db_service
+            .find_by_short_code(&vision.short_code)
+            .unwrap()
+            .unwrap();
+        assert!(!doc.exit_criteria_met);
+    }
+}
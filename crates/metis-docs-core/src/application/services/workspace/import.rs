@@ -0,0 +1,430 @@
+use crate::application::services::document::creation::DocumentCreationConfig;
+use crate::application::services::document::DocumentCreationService;
+use crate::application::services::DatabaseService;
+use crate::domain::documents::types::{DocumentType, Tag};
+use crate::{MetisError, Result};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// One document to create, whatever the source (CSV row, GitHub issue, ...)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ImportRecord {
+    pub title: String,
+    /// Markdown appended to the created document
+    pub body: Option<String>,
+    /// Labels become tags; `bug`, `feature` and `tech-debt` also pick the
+    /// backlog folder
+    pub labels: Vec<String>,
+    /// Where the record came from, e.g. an issue URL
+    pub source: Option<String>,
+}
+
+/// Where imported documents are created
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ImportDestination {
+    /// Backlog tasks
+    Backlog,
+    /// Tasks under the initiative with this short code
+    Initiative(String),
+}
+
+/// A record that will not be imported
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SkippedRecord {
+    pub title: String,
+    pub reason: String,
+}
+
+/// The records an import will create and the ones it will skip
+#[derive(Debug, Clone, Default)]
+pub struct ImportPlan {
+    pub create: Vec<ImportRecord>,
+    pub skipped: Vec<SkippedRecord>,
+}
+
+/// A document created by an import
+#[derive(Debug, Clone)]
+pub struct ImportedDocument {
+    pub short_code: String,
+    pub title: String,
+    pub file_path: PathBuf,
+    pub source: Option<String>,
+}
+
+/// Service for creating tasks in bulk from external records
+pub struct ImportService {
+    creation_service: DocumentCreationService,
+}
+
+impl ImportService {
+    /// Create a new import service for a workspace
+    pub fn new<P: AsRef<Path>>(workspace_dir: P) -> Self {
+        Self {
+            creation_service: DocumentCreationService::new(workspace_dir),
+        }
+    }
+
+    /// Check the destination and work out which records to create, without
+    /// changing anything. Records without a title, or whose title matches an
+    /// existing task or an earlier record, are skipped.
+    pub fn plan(
+        &self,
+        records: Vec<ImportRecord>,
+        destination: &ImportDestination,
+        db_service: &mut DatabaseService,
+    ) -> Result<ImportPlan> {
+        if let ImportDestination::Initiative(short_code) = destination {
+            let initiative = db_service.find_by_short_code(short_code)?.ok_or_else(|| {
+                MetisError::NotFound(format!("Initiative '{}' not found", short_code))
+            })?;
+            if initiative.document_type != DocumentType::Initiative.to_string() {
+                return Err(MetisError::ValidationFailed {
+                    message: format!(
+                        "'{}' is a {}, not an initiative",
+                        short_code, initiative.document_type
+                    ),
+                });
+            }
+        }
+
+        let mut existing: HashMap<String, String> = db_service
+            .find_by_type(DocumentType::Task)?
+            .into_iter()
+            .map(|doc| (normalize_title(&doc.title), doc.short_code))
+            .collect();
+
+        let mut plan = ImportPlan::default();
+        for mut record in records {
+            record.title = record.title.trim().to_string();
+            if record.title.is_empty() {
+                plan.skipped.push(SkippedRecord {
+                    title: record.source.clone().unwrap_or_default(),
+                    reason: "no title".to_string(),
+                });
+                continue;
+            }
+
+            let key = normalize_title(&record.title);
+            if let Some(short_code) = existing.get(&key) {
+                plan.skipped.push(SkippedRecord {
+                    title: record.title,
+                    reason: format!("already exists as {}", short_code),
+                });
+                continue;
+            }
+            existing.insert(key, "an earlier record".to_string());
+            plan.create.push(record);
+        }
+
+        Ok(plan)
+    }
+
+    /// Create a task for every record in the plan
+    pub async fn apply(
+        &self,
+        plan: &ImportPlan,
+        destination: &ImportDestination,
+    ) -> Result<Vec<ImportedDocument>> {
+        let mut imported = Vec::new();
+        for record in &plan.create {
+            let config = DocumentCreationConfig {
+                title: record.title.clone(),
+                description: None,
+                parent_id: None,
+                tags: label_tags(&record.labels),
+                phase: None,
+                complexity: None,
+            };
+            let result = match destination {
+                ImportDestination::Backlog => {
+                    self.creation_service.create_backlog_item(config).await?
+                }
+                ImportDestination::Initiative(short_code) => {
+                    self.creation_service
+                        .create_task(config, short_code)
+                        .await?
+                }
+            };
+
+            if record.body.is_some() || record.source.is_some() {
+                append_imported_section(&result.file_path, record)?;
+            }
+            imported.push(ImportedDocument {
+                short_code: result.short_code,
+                title: record.title.clone(),
+                file_path: result.file_path,
+                source: record.source.clone(),
+            });
+        }
+        Ok(imported)
+    }
+}
+
+fn normalize_title(title: &str) -> String {
+    title
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+/// Labels as tags: lowercase, with whitespace replaced by `-`
+fn label_tags(labels: &[String]) -> Vec<Tag> {
+    let mut seen = HashSet::new();
+    labels
+        .iter()
+        .map(|label| {
+            label
+                .split_whitespace()
+                .collect::<Vec<_>>()
+                .join("-")
+                .to_lowercase()
+        })
+        .filter(|label| !label.is_empty() && seen.insert(label.clone()))
+        .map(Tag::Label)
+        .collect()
+}
+
+/// Keep the original description and where it came from at the end of the
+/// created document
+fn append_imported_section(path: &Path, record: &ImportRecord) -> Result<()> {
+    let mut content = fs::read_to_string(path)?;
+    if !content.ends_with('\n') {
+        content.push('\n');
+    }
+    content.push_str("\n## Imported\n\n");
+    if let Some(source) = &record.source {
+        content.push_str(&format!("Source: {}\n\n", source));
+    }
+    if let Some(body) = record
+        .body
+        .as_deref()
+        .map(str::trim)
+        .filter(|b| !b.is_empty())
+    {
+        content.push_str(body);
+        content.push('\n');
+    }
+    fs::write(path, content)?;
+    Ok(())
+}
+
+/// Record fields a CSV column can be mapped to
+pub const CSV_FIELDS: &[&str] = &["title", "body", "labels", "source"];
+
+/// Parse a `field=Column,...` mapping, e.g. `title=Summary,labels=Tags`.
+/// Unmapped fields use the column with the same name as the field.
+pub fn parse_field_map(spec: &str) -> std::result::Result<HashMap<String, String>, String> {
+    let mut map = HashMap::new();
+    for pair in spec.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+        let (field, column) = pair
+            .split_once('=')
+            .ok_or_else(|| format!("Invalid mapping '{}'; use field=Column", pair))?;
+        let field = field.trim().to_lowercase();
+        if !CSV_FIELDS.contains(&field.as_str()) {
+            return Err(format!(
+                "Unknown field '{}'. Valid fields: {}",
+                field,
+                CSV_FIELDS.join(", ")
+            ));
+        }
+        map.insert(field, column.trim().to_string());
+    }
+    Ok(map)
+}
+
+/// Turn CSV text with a header row into records. Column names are matched
+/// case-insensitively; labels are split on commas and semicolons.
+pub fn records_from_csv(
+    text: &str,
+    field_map: &HashMap<String, String>,
+) -> std::result::Result<Vec<ImportRecord>, String> {
+    let mut rows = parse_csv(text)?.into_iter();
+    let header: Vec<String> = rows
+        .next()
+        .ok_or_else(|| "CSV file is empty".to_string())?
+        .iter()
+        .map(|h| h.trim().to_lowercase())
+        .collect();
+
+    let column = |field: &str| {
+        let name = field_map
+            .get(field)
+            .map(|c| c.to_lowercase())
+            .unwrap_or_else(|| field.to_string());
+        header.iter().position(|h| *h == name)
+    };
+    let title_column = column("title").ok_or_else(|| {
+        format!(
+            "No title column; map one with --map title=<Column>. Columns: {}",
+            header.join(", ")
+        )
+    })?;
+    for field in CSV_FIELDS {
+        if let Some(name) = field_map.get(*field) {
+            if column(field).is_none() {
+                return Err(format!("Column '{}' not found for {}", name, field));
+            }
+        }
+    }
+    let (body_column, labels_column, source_column) =
+        (column("body"), column("labels"), column("source"));
+
+    let cell = |row: &[String], index: Option<usize>| {
+        index
+            .and_then(|i| row.get(i))
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+    };
+    Ok(rows
+        .filter(|row| row.iter().any(|value| !value.trim().is_empty()))
+        .map(|row| ImportRecord {
+            title: cell(&row, Some(title_column)).unwrap_or_default(),
+            body: cell(&row, body_column),
+            labels: cell(&row, labels_column)
+                .map(|labels| {
+                    labels
+                        .split([',', ';'])
+                        .map(|l| l.trim().to_string())
+                        .filter(|l| !l.is_empty())
+                        .collect()
+                })
+                .unwrap_or_default(),
+            source: cell(&row, source_column),
+        })
+        .collect())
+}
+
+/// Split CSV text into rows of fields. Quoted fields may contain commas,
+/// newlines and doubled quotes.
+fn parse_csv(text: &str) -> std::result::Result<Vec<Vec<String>>, String> {
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = text.trim_start_matches('\u{feff}').chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match (c, in_quotes) {
+            ('"', true) if chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            ('"', true) => in_quotes = false,
+            ('"', false) if field.is_empty() => in_quotes = true,
+            (',', false) => row.push(std::mem::take(&mut field)),
+            ('\r', false) if chars.peek() == Some(&'\n') => {}
+            ('\n', false) => {
+                row.push(std::mem::take(&mut field));
+                rows.push(std::mem::take(&mut row));
+            }
+            _ => field.push(c),
+        }
+    }
+    if in_quotes {
+        return Err("Unterminated quoted field".to_string());
+    }
+    if !field.is_empty() || !row.is_empty() {
+        row.push(field);
+        rows.push(row);
+    }
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::services::workspace::test_support::TestWorkspace;
+
+    #[test]
+    fn test_parse_csv() {
+        let rows = parse_csv("a,b\r\n\"x, y\",\"say \"\"hi\"\"\nline two\"\n").unwrap();
+        assert_eq!(
+            rows,
+            vec![
+                vec!["a".to_string(), "b".to_string()],
+                vec!["x, y".to_string(), "say \"hi\"\nline two".to_string()],
+            ]
+        );
+        assert!(parse_csv("\"open").is_err());
+    }
+
+    #[test]
+    fn test_records_from_csv() {
+        let map = parse_field_map("title=Summary, labels=Tags").unwrap();
+        let csv = "Summary,Tags,Body\nFix login,\"bug; Auth\",Steps\n,,\n  ,x,\n";
+        let records = records_from_csv(csv, &map).unwrap();
+
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].title, "Fix login");
+        assert_eq!(records[0].labels, vec!["bug", "Auth"]);
+        assert_eq!(records[0].body.as_deref(), Some("Steps"));
+        assert_eq!(records[1].title, "");
+
+        let missing = parse_field_map("source=Link").unwrap();
+        assert!(records_from_csv(csv, &missing).is_err());
+        assert!(parse_field_map("owner=Assignee").is_err());
+        assert!(parse_field_map("title").is_err());
+    }
+
+    #[test]
+    fn test_label_tags() {
+        let tags = label_tags(&["Tech Debt".to_string(), "tech-debt".to_string()]);
+        assert_eq!(tags, vec![Tag::Label("tech-debt".to_string())]);
+    }
+
+    #[tokio::test]
+    async fn test_import_to_backlog() {
+        let workspace = TestWorkspace::new();
+
+        let records = vec![
+            ImportRecord {
+                title: "Login fails".to_string(),
+                body: Some("Steps to reproduce".to_string()),
+                labels: vec!["bug".to_string()],
+                source: Some("https://github.com/org/name/issues/1".to_string()),
+            },
+            ImportRecord {
+                title: "login  FAILS".to_string(),
+                ..Default::default()
+            },
+            ImportRecord::default(),
+        ];
+
+        let service = ImportService::new(&workspace.metis_dir);
+        let mut db_service = workspace.synced().await;
+        let plan = service
+            .plan(
+                records.clone(),
+                &ImportDestination::Backlog,
+                &mut db_service,
+            )
+            .unwrap();
+        assert_eq!(plan.create.len(), 1);
+        assert_eq!(plan.skipped.len(), 2);
+
+        let imported = service
+            .apply(&plan, &ImportDestination::Backlog)
+            .await
+            .unwrap();
+        assert_eq!(imported.len(), 1);
+        assert!(imported[0]
+            .file_path
+            .starts_with(workspace.metis_dir.join("backlog/bugs")));
+        let content = fs::read_to_string(&imported[0].file_path).unwrap();
+        assert!(content.contains("Source: https://github.com/org/name/issues/1"));
+        assert!(content.contains("Steps to reproduce"));
+
+        // Importing again finds the task that now exists
+        let mut db_service = workspace.synced().await;
+        let plan = service
+            .plan(records, &ImportDestination::Backlog, &mut db_service)
+            .unwrap();
+        assert!(plan.create.is_empty());
+        assert!(plan.skipped[0].reason.contains(&imported[0].short_code));
+
+        let missing = ImportDestination::Initiative("TEST-I-0099".to_string());
+        assert!(service.plan(Vec::new(), &missing, &mut db_service).is_err());
+    }
+}
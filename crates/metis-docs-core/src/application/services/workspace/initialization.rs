@@ -1,7 +1,7 @@
 use crate::dal::database::configuration_repository::ConfigurationRepository;
+use crate::dal::database::establish;
 use crate::domain::configuration::ConfigFile;
 use crate::{Database, MetisError, Phase, Result, Tag, Vision};
-use diesel::{sqlite::SqliteConnection, Connection};
 use std::path::{Path, PathBuf};
 
 /// Service for initializing new Metis workspaces
@@ -30,8 +30,31 @@ impl WorkspaceInitializationService {
         project_name: &str,
         prefix: Option<&str>,
     ) -> Result<WorkspaceInitializationResult> {
-        let base_path = base_path.as_ref();
+        Self::initialize(base_path.as_ref(), project_name, prefix, None).await
+    }
 
+    /// Initialize a new Metis workspace from a complete configuration, storing
+    /// its prefix and flight levels and writing it to config.toml as given
+    pub async fn initialize_workspace_with_config<P: AsRef<Path>>(
+        base_path: P,
+        project_name: &str,
+        config: &ConfigFile,
+    ) -> Result<WorkspaceInitializationResult> {
+        Self::initialize(
+            base_path.as_ref(),
+            project_name,
+            Some(config.prefix()),
+            Some(config),
+        )
+        .await
+    }
+
+    async fn initialize(
+        base_path: &Path,
+        project_name: &str,
+        prefix: Option<&str>,
+        config: Option<&ConfigFile>,
+    ) -> Result<WorkspaceInitializationResult> {
         // Create .metis directory
         let metis_dir = base_path.join(".metis");
         std::fs::create_dir_all(&metis_dir)?;
@@ -55,7 +78,10 @@ impl WorkspaceInitializationService {
                  code-index-symbols.json\n\
                  \n\
                  # Index dirty flag\n\
-                 .index-dirty\n",
+                 .index-dirty\n\
+                 \n\
+                 # Desktop app session (where the boards were left)\n\
+                 gui-session.json\n",
             )?;
         }
 
@@ -70,7 +96,7 @@ impl WorkspaceInitializationService {
             Ok(_db) => {
                 // Database is valid, set up project configuration
                 let mut config_repo = ConfigurationRepository::new(
-                    SqliteConnection::establish(db_path.to_str().unwrap()).map_err(|e| {
+                    establish(db_path.to_str().unwrap()).map_err(|e| {
                         MetisError::ConfigurationError(
                             crate::domain::configuration::ConfigurationError::InvalidValue(
                                 e.to_string(),
@@ -95,12 +121,21 @@ impl WorkspaceInitializationService {
                     config_repo.get_project_prefix()?.unwrap()
                 };
 
+                if let Some(config) = config {
+                    config_repo.set_flight_level_config(config.flight_levels())?;
+                }
+
                 // Create config.toml file if it doesn't exist
                 let config_file_path = metis_dir.join("config.toml");
                 if !config_file_path.exists() {
-                    let flight_levels = config_repo.get_flight_level_config()?;
-                    let config_file = ConfigFile::new(project_prefix, flight_levels)
-                        .map_err(MetisError::ConfigurationError)?;
+                    let config_file = match config {
+                        Some(config) => config.clone(),
+                        None => {
+                            let flight_levels = config_repo.get_flight_level_config()?;
+                            ConfigFile::new(project_prefix, flight_levels)
+                                .map_err(MetisError::ConfigurationError)?
+                        }
+                    };
                     config_file
                         .save(&config_file_path)
                         .map_err(MetisError::ConfigurationError)?;
@@ -150,13 +185,12 @@ impl WorkspaceInitializationService {
     async fn create_default_vision(workspace_dir: &Path, title: &str) -> Result<PathBuf> {
         // Generate short code for vision using the database
         let db_path = workspace_dir.join("metis.db");
-        let mut config_repo = ConfigurationRepository::new(
-            SqliteConnection::establish(&db_path.to_string_lossy()).map_err(|e| {
+        let mut config_repo =
+            ConfigurationRepository::new(establish(&db_path.to_string_lossy()).map_err(|e| {
                 MetisError::ConfigurationError(
                     crate::domain::configuration::ConfigurationError::InvalidValue(e.to_string()),
                 )
-            })?,
-        );
+            })?);
         let short_code = config_repo.generate_short_code("vision")?;
 
         // Create Vision with defaults
@@ -281,6 +315,41 @@ mod tests {
         assert!(new_size >= original_size / 2 && new_size <= original_size * 2);
     }
 
+    #[tokio::test]
+    async fn test_initialize_workspace_with_config() {
+        use crate::domain::configuration::FlightLevelConfig;
+
+        let temp_dir = tempdir().unwrap();
+        let base_path = temp_dir.path();
+        let config = ConfigFile::new("ACME".to_string(), FlightLevelConfig::direct())
+            .unwrap()
+            .with_remote(Some("git@github.com:acme/plans.git".to_string()))
+            .unwrap();
+
+        let result = WorkspaceInitializationService::initialize_workspace_with_config(
+            base_path, "Acme", &config,
+        )
+        .await
+        .unwrap();
+
+        // config.toml is written once, exactly as given
+        let saved = ConfigFile::load(result.metis_dir.join("config.toml")).unwrap();
+        assert_eq!(saved, config);
+
+        // The database agrees with the file
+        let mut config_repo = ConfigurationRepository::new(
+            establish(result.database_path.to_str().unwrap()).unwrap(),
+        );
+        assert_eq!(
+            config_repo.get_project_prefix().unwrap().as_deref(),
+            Some("ACME")
+        );
+        assert_eq!(
+            config_repo.get_flight_level_config().unwrap(),
+            FlightLevelConfig::direct()
+        );
+    }
+
     #[test]
     fn test_is_workspace() {
         let temp_dir = tempdir().unwrap();
@@ -0,0 +1,390 @@
+use crate::application::services::DatabaseService;
+use crate::dal::database::models::Document;
+use crate::domain::documents::helpers::{set_frontmatter_list, FrontmatterParser};
+use crate::domain::documents::types::RelationshipKind;
+use crate::{MetisError, Result};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Kind of dependency between two documents
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LinkKind {
+    /// The document can't finish until the target does (`blocked_by`)
+    BlockedBy,
+    /// The documents are related, with no ordering (`references`)
+    RelatesTo,
+    /// An ADR replaces an earlier decision (`supersedes`)
+    Supersedes,
+}
+
+impl LinkKind {
+    /// The frontmatter list that declares this kind of link
+    pub fn frontmatter_key(&self) -> &'static str {
+        match self {
+            Self::BlockedBy => "blocked_by",
+            Self::RelatesTo => "references",
+            Self::Supersedes => "supersedes",
+        }
+    }
+
+    /// Human-readable name for messages
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::BlockedBy => "blocked by",
+            Self::RelatesTo => "related to",
+            Self::Supersedes => "superseding",
+        }
+    }
+}
+
+/// A document in the transitive blocker chain of another
+#[derive(Debug, Clone)]
+pub struct Blocker {
+    pub document: Document,
+    /// Distance from the blocked document; direct blockers are at depth 1
+    pub depth: usize,
+    /// Short code of the document this one blocks
+    pub blocks: String,
+}
+
+/// Service for adding and removing dependency links between documents.
+///
+/// Links are declared in the frontmatter of the dependent document, so they
+/// are written to the file and the database picks them up on the next sync.
+pub struct LinkService {
+    workspace_dir: PathBuf,
+}
+
+impl LinkService {
+    /// Create a new link service for a workspace
+    pub fn new<P: AsRef<Path>>(workspace_dir: P) -> Self {
+        let path = workspace_dir.as_ref();
+        let absolute_path = if path.is_absolute() {
+            path.to_path_buf()
+        } else {
+            std::env::current_dir().unwrap_or_default().join(path)
+        };
+
+        Self {
+            workspace_dir: absolute_path,
+        }
+    }
+
+    /// Link `short_code` to `target`. Returns false if the link already exists.
+    ///
+    /// Blocking links are refused when `target` is already (transitively)
+    /// blocked by `short_code`, since that would create a cycle.
+    pub fn link(
+        &self,
+        short_code: &str,
+        target: &str,
+        kind: LinkKind,
+        db_service: &mut DatabaseService,
+    ) -> Result<bool> {
+        let doc = Self::find(short_code, db_service)?;
+        let target_doc = Self::find(target, db_service)?;
+
+        if doc.short_code == target_doc.short_code {
+            return Err(MetisError::ValidationFailed {
+                message: format!("{} cannot be linked to itself", short_code),
+            });
+        }
+
+        if kind == LinkKind::BlockedBy {
+            if doc.document_type != "task" && doc.document_type != "initiative" {
+                return Err(MetisError::ValidationFailed {
+                    message: format!(
+                        "Only tasks and initiatives can be blocked. '{}' is a {}.",
+                        short_code, doc.document_type
+                    ),
+                });
+            }
+            if let Some(path) =
+                Self::blocking_path(&doc.short_code, &target_doc.short_code, db_service)?
+            {
+                return Err(MetisError::ValidationFailed {
+                    message: format!(
+                        "{} cannot be blocked by {}: that would create a cycle ({} blocks {})",
+                        doc.short_code,
+                        target_doc.short_code,
+                        path.join(" blocks "),
+                        doc.short_code
+                    ),
+                });
+            }
+        }
+
+        if kind == LinkKind::Supersedes
+            && (doc.document_type != "adr" || target_doc.document_type != "adr")
+        {
+            return Err(MetisError::ValidationFailed {
+                message: format!(
+                    "Only ADRs can supersede each other. '{}' is a {} and '{}' is a {}.",
+                    short_code, doc.document_type, target, target_doc.document_type
+                ),
+            });
+        }
+
+        let path = self.workspace_dir.join(&doc.filepath);
+        let content = Self::read(&path)?;
+        let mut links = Self::current_links(&content, kind);
+        if links.contains(&target_doc.short_code) {
+            return Ok(false);
+        }
+        links.push(target_doc.short_code);
+        Self::write(
+            &path,
+            &set_frontmatter_list(&content, kind.frontmatter_key(), &links),
+        )?;
+
+        Ok(true)
+    }
+
+    /// Remove the link from `short_code` to `target`. Returns false if there
+    /// was no such link. The target doesn't have to exist, so dangling links
+    /// can be cleaned up.
+    pub fn unlink(
+        &self,
+        short_code: &str,
+        target: &str,
+        kind: LinkKind,
+        db_service: &mut DatabaseService,
+    ) -> Result<bool> {
+        let doc = Self::find(short_code, db_service)?;
+
+        let path = self.workspace_dir.join(&doc.filepath);
+        let content = Self::read(&path)?;
+        let mut links = Self::current_links(&content, kind);
+        let before = links.len();
+        links.retain(|link| !link.eq_ignore_ascii_case(target));
+        if links.len() == before {
+            return Ok(false);
+        }
+        Self::write(
+            &path,
+            &set_frontmatter_list(&content, kind.frontmatter_key(), &links),
+        )?;
+
+        Ok(true)
+    }
+
+    /// Every document that directly or indirectly blocks `short_code`, in
+    /// depth-first order so each blocker follows the document it blocks.
+    /// Blockers reached along several paths are listed once.
+    pub fn blockers(
+        &self,
+        short_code: &str,
+        db_service: &mut DatabaseService,
+    ) -> Result<Vec<Blocker>> {
+        let doc = Self::find(short_code, db_service)?;
+
+        let mut chain = Vec::new();
+        let mut seen = HashSet::from([doc.short_code.clone()]);
+        Self::collect_blockers(&doc.short_code, 1, &mut seen, &mut chain, db_service)?;
+        Ok(chain)
+    }
+
+    fn collect_blockers(
+        short_code: &str,
+        depth: usize,
+        seen: &mut HashSet<String>,
+        chain: &mut Vec<Blocker>,
+        db_service: &mut DatabaseService,
+    ) -> Result<()> {
+        for code in Self::blocking_edges_to(short_code, db_service)? {
+            if !seen.insert(code.clone()) {
+                continue;
+            }
+            // Blockers that don't exist are reported as broken references on sync
+            let Some(document) = db_service.find_by_short_code(&code)? else {
+                continue;
+            };
+            chain.push(Blocker {
+                document,
+                depth,
+                blocks: short_code.to_string(),
+            });
+            Self::collect_blockers(&code, depth + 1, seen, chain, db_service)?;
+        }
+        Ok(())
+    }
+
+    /// Short codes of the documents with a blocking edge to `short_code`
+    fn blocking_edges_to(
+        short_code: &str,
+        db_service: &mut DatabaseService,
+    ) -> Result<Vec<String>> {
+        let blocks = RelationshipKind::Blocks.to_string();
+        Ok(db_service
+            .find_edges_to(short_code)?
+            .into_iter()
+            .filter(|edge| edge.kind == blocks)
+            .map(|edge| edge.from_code)
+            .collect())
+    }
+
+    /// A chain of blocking edges from `from` to `to`, if one exists
+    fn blocking_path(
+        from: &str,
+        to: &str,
+        db_service: &mut DatabaseService,
+    ) -> Result<Option<Vec<String>>> {
+        let blocks = RelationshipKind::Blocks.to_string();
+        let mut previous: HashMap<String, String> = HashMap::new();
+        let mut queue = VecDeque::from([from.to_string()]);
+
+        while let Some(code) = queue.pop_front() {
+            if code == to {
+                let mut path = vec![code];
+                while let Some(prev) = previous.get(path.last().unwrap()) {
+                    path.push(prev.clone());
+                }
+                path.reverse();
+                return Ok(Some(path));
+            }
+            for edge in db_service.find_edges_from(&code)? {
+                if edge.kind == blocks
+                    && edge.to_code != from
+                    && !previous.contains_key(&edge.to_code)
+                {
+                    previous.insert(edge.to_code.clone(), code.clone());
+                    queue.push_back(edge.to_code);
+                }
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// The links of a kind currently declared in a document's frontmatter
+    fn current_links(content: &str, kind: LinkKind) -> Vec<String> {
+        use gray_matter::{engine::YAML, Matter};
+
+        let frontmatter = match Matter::<YAML>::new().parse(content).data {
+            Some(gray_matter::Pod::Hash(map)) => map,
+            _ => return Vec::new(),
+        };
+        let links = match frontmatter.get(kind.frontmatter_key()) {
+            Some(gray_matter::Pod::String(s)) => vec![s.clone()],
+            Some(gray_matter::Pod::Array(_)) => {
+                FrontmatterParser::extract_string_array(&frontmatter, kind.frontmatter_key())
+                    .unwrap_or_default()
+            }
+            _ => Vec::new(),
+        };
+        links
+            .into_iter()
+            .map(|link| link.trim().to_string())
+            .filter(|link| !link.is_empty() && link != "NULL")
+            .collect()
+    }
+
+    fn find(short_code: &str, db_service: &mut DatabaseService) -> Result<Document> {
+        db_service
+            .find_by_short_code(short_code)?
+            .ok_or_else(|| MetisError::NotFound(format!("Document '{}' not found", short_code)))
+    }
+
+    fn read(path: &Path) -> Result<String> {
+        fs::read_to_string(path).map_err(|e| {
+            MetisError::FileSystem(format!("Failed to read {}: {}", path.display(), e))
+        })
+    }
+
+    fn write(path: &Path, content: &str) -> Result<()> {
+        fs::write(path, content).map_err(|e| {
+            MetisError::FileSystem(format!("Failed to update {}: {}", path.display(), e))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::services::document::DocumentCreationService;
+    use crate::application::services::workspace::test_support::{config, TestWorkspace};
+
+    #[tokio::test]
+    async fn test_link_unlink_and_blockers() {
+        let workspace = TestWorkspace::new();
+
+        let creation_service = DocumentCreationService::new(&workspace.metis_dir);
+        let vision = creation_service
+            .create_vision(config("Test Vision"))
+            .await
+            .unwrap();
+        let mut tasks = Vec::new();
+        for title in ["Task A", "Task B", "Task C"] {
+            let task = creation_service
+                .create_backlog_item(config(title))
+                .await
+                .unwrap();
+            tasks.push(task.short_code);
+        }
+        let (a, b, c) = (&tasks[0], &tasks[1], &tasks[2]);
+
+        let mut db_service = workspace.synced().await;
+
+        // A is blocked by B, which is blocked by C
+        let service = LinkService::new(&workspace.metis_dir);
+        assert!(service
+            .link(a, b, LinkKind::BlockedBy, &mut db_service)
+            .unwrap());
+        assert!(!service
+            .link(a, b, LinkKind::BlockedBy, &mut db_service)
+            .unwrap());
+        assert!(service
+            .link(b, c, LinkKind::BlockedBy, &mut db_service)
+            .unwrap());
+        assert!(service
+            .link(a, &vision.short_code, LinkKind::RelatesTo, &mut db_service)
+            .unwrap());
+        workspace.sync(&mut db_service).await;
+
+        let blockers = service.blockers(a, &mut db_service).unwrap();
+        let chain: Vec<(&str, usize, &str)> = blockers
+            .iter()
+            .map(|b| (b.document.short_code.as_str(), b.depth, b.blocks.as_str()))
+            .collect();
+        assert_eq!(
+            chain,
+            vec![(b.as_str(), 1, a.as_str()), (c.as_str(), 2, b.as_str())]
+        );
+
+        // C blocked by A would close the loop
+        let cycle = service
+            .link(c, a, LinkKind::BlockedBy, &mut db_service)
+            .unwrap_err()
+            .to_string();
+        assert!(cycle.contains("cycle"), "{}", cycle);
+        assert!(
+            cycle.contains(&format!("{} blocks {} blocks {}", a, b, c)),
+            "{}",
+            cycle
+        );
+
+        // Visions can't be blocked, and nothing links to itself
+        assert!(service
+            .link(&vision.short_code, a, LinkKind::BlockedBy, &mut db_service)
+            .is_err());
+        assert!(service
+            .link(a, a, LinkKind::RelatesTo, &mut db_service)
+            .is_err());
+
+        // Unlinking removes the edge on the next sync
+        assert!(service
+            .unlink(a, b, LinkKind::BlockedBy, &mut db_service)
+            .unwrap());
+        assert!(!service
+            .unlink(a, b, LinkKind::BlockedBy, &mut db_service)
+            .unwrap());
+        workspace.sync(&mut db_service).await;
+        assert!(service.blockers(a, &mut db_service).unwrap().is_empty());
+
+        let edges = db_service.find_edges_from(a).unwrap();
+        assert!(edges
+            .iter()
+            .any(|edge| edge.kind == "references" && edge.to_code == vision.short_code));
+    }
+}
@@ -1,15 +1,47 @@
+pub mod adr;
 pub mod archive;
+pub mod backlog;
+pub mod board;
+pub mod cascade;
+pub mod content;
+pub mod criteria;
 pub mod detection;
+pub mod import;
 pub mod initialization;
+pub mod link;
 pub mod migration;
+pub mod property;
 pub mod reassignment;
 pub mod recovery;
+pub mod remote;
+pub mod site;
+pub mod tag;
+#[cfg(test)]
+mod test_support;
 pub mod transition;
+pub mod validation;
 
+pub use adr::AdrService;
 pub use archive::ArchiveService;
+pub use backlog::{BacklogItem, BacklogService, Severity};
+pub use board::{Board, BoardCard, BoardColumn, BoardKind, BoardService, CriteriaProgress};
+pub use cascade::{CascadePlan, CascadeService, CascadeStep};
+pub use content::ContentService;
+pub use criteria::{CriteriaService, CriteriaStatus};
 pub use detection::WorkspaceDetectionService;
+pub use import::{
+    ImportDestination, ImportPlan, ImportRecord, ImportService, ImportedDocument, SkippedRecord,
+};
 pub use initialization::{WorkspaceInitializationResult, WorkspaceInitializationService};
+pub use link::{Blocker, LinkKind, LinkService};
 pub use migration::{MigrationReport, WorkspaceMigrationService};
+pub use property::{BulkEdit, PropertyChange, PropertyService};
 pub use reassignment::{BacklogCategory, ReassignmentResult, ReassignmentService};
 pub use recovery::{ConfigurationRecoveryService, RecoveryReport};
+pub use remote::{Change, RemoteSyncService, RemoteSyncStage, SyncPlan, WorkspaceCommit};
+pub use site::{SiteExportReport, SiteExportService};
+pub use tag::TagService;
 pub use transition::PhaseTransitionService;
+pub use validation::{
+    DocumentReport, ValidationProblem, ValidationReport, WorkspaceValidationService,
+};
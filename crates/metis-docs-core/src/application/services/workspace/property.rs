@@ -0,0 +1,369 @@
+use crate::application::services::synchronization::STRUCTURAL_KEYS;
+use crate::application::services::DatabaseService;
+use crate::dal::database::models::Document;
+use crate::dal::database::DocumentFilter;
+use crate::domain::documents::helpers::set_frontmatter_value;
+use crate::{MetisError, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Service for setting free-form frontmatter properties such as `assignee`
+/// or `due_date`.
+///
+/// Properties are written to the document's frontmatter; the database picks
+/// them up on the next sync.
+pub struct PropertyService {
+    workspace_dir: PathBuf,
+}
+
+/// A property update on one document
+#[derive(Debug, Clone)]
+pub struct PropertyChange {
+    pub short_code: String,
+    pub title: String,
+    pub previous: Option<String>,
+    pub value: Option<String>,
+}
+
+impl PropertyChange {
+    /// Whether the update changed anything
+    pub fn changed(&self) -> bool {
+        self.previous != self.value
+    }
+}
+
+/// Every property update for one document in a bulk edit
+#[derive(Debug, Clone)]
+pub struct BulkEdit {
+    pub short_code: String,
+    pub title: String,
+    /// Path relative to the workspace directory
+    pub filepath: String,
+    /// One change per updated key, in the order given
+    pub changes: Vec<PropertyChange>,
+    /// Why the document couldn't be updated, when applying failed
+    pub error: Option<String>,
+}
+
+impl BulkEdit {
+    /// Whether any of the updates changes the document
+    pub fn changed(&self) -> bool {
+        self.changes.iter().any(PropertyChange::changed)
+    }
+}
+
+impl PropertyService {
+    /// Create a new property service for a workspace
+    pub fn new<P: AsRef<Path>>(workspace_dir: P) -> Self {
+        let path = workspace_dir.as_ref();
+        let absolute_path = if path.is_absolute() {
+            path.to_path_buf()
+        } else {
+            std::env::current_dir().unwrap_or_default().join(path)
+        };
+
+        Self {
+            workspace_dir: absolute_path,
+        }
+    }
+
+    /// Set `key` on one document, or remove it when `value` is `None`
+    pub fn set_property(
+        &self,
+        short_code: &str,
+        key: &str,
+        value: Option<&str>,
+        db_service: &mut DatabaseService,
+    ) -> Result<PropertyChange> {
+        Self::validate_key(key)?;
+        let doc = db_service
+            .find_by_short_code(short_code)?
+            .ok_or_else(|| MetisError::NotFound(format!("Document '{}' not found", short_code)))?;
+        self.apply(&doc, key, value, db_service)
+    }
+
+    /// Set `key` on every document matching `filter`, or remove it when
+    /// `value` is `None`
+    pub fn set_property_by_filter(
+        &self,
+        filter: &DocumentFilter,
+        key: &str,
+        value: Option<&str>,
+        db_service: &mut DatabaseService,
+    ) -> Result<Vec<PropertyChange>> {
+        Self::validate_key(key)?;
+        let docs = db_service.find_by_filter(filter)?;
+        docs.iter()
+            .map(|doc| self.apply(doc, key, value, db_service))
+            .collect()
+    }
+
+    /// Preview setting several properties on every document matching
+    /// `filter`. A `None` value removes the key. Nothing is written.
+    pub fn plan_bulk(
+        &self,
+        filter: &DocumentFilter,
+        updates: &[(String, Option<String>)],
+        db_service: &mut DatabaseService,
+    ) -> Result<Vec<BulkEdit>> {
+        for (index, (key, _)) in updates.iter().enumerate() {
+            Self::validate_key(key)?;
+            if updates[..index].iter().any(|(earlier, _)| earlier == key) {
+                return Err(MetisError::ValidationFailed {
+                    message: format!("'{}' is set more than once", key),
+                });
+            }
+        }
+
+        let docs = db_service.find_by_filter(filter)?;
+        docs.iter()
+            .map(|doc| {
+                let properties = db_service.find_properties(&doc.filepath)?;
+                let changes = updates
+                    .iter()
+                    .map(|(key, value)| PropertyChange {
+                        short_code: doc.short_code.clone(),
+                        title: doc.title.clone(),
+                        previous: properties
+                            .iter()
+                            .find(|property| &property.key == key)
+                            .map(|property| property.value.clone()),
+                        value: value.clone(),
+                    })
+                    .collect();
+                Ok(BulkEdit {
+                    short_code: doc.short_code.clone(),
+                    title: doc.title.clone(),
+                    filepath: doc.filepath.clone(),
+                    changes,
+                    error: None,
+                })
+            })
+            .collect()
+    }
+
+    /// Set several properties on every document matching `filter`, writing
+    /// each document once. A document that can't be updated gets an
+    /// `error` and the rest are still applied.
+    pub fn apply_bulk(
+        &self,
+        filter: &DocumentFilter,
+        updates: &[(String, Option<String>)],
+        db_service: &mut DatabaseService,
+    ) -> Result<Vec<BulkEdit>> {
+        let mut edits = self.plan_bulk(filter, updates, db_service)?;
+        for edit in edits.iter_mut().filter(|edit| edit.changed()) {
+            if let Err(e) = self.write_edit(edit, updates) {
+                edit.error = Some(e.to_string());
+            }
+        }
+        Ok(edits)
+    }
+
+    fn write_edit(&self, edit: &BulkEdit, updates: &[(String, Option<String>)]) -> Result<()> {
+        let path = self.workspace_dir.join(&edit.filepath);
+        let content = fs::read_to_string(&path).map_err(|e| {
+            MetisError::FileSystem(format!("Failed to read {}: {}", path.display(), e))
+        })?;
+        let updated = updates
+            .iter()
+            .zip(&edit.changes)
+            .filter(|(_, change)| change.changed())
+            .fold(content, |content, ((key, value), _)| {
+                set_frontmatter_value(&content, key, value.as_deref())
+            });
+        fs::write(&path, updated).map_err(|e| {
+            MetisError::FileSystem(format!("Failed to update {}: {}", path.display(), e))
+        })
+    }
+
+    fn apply(
+        &self,
+        doc: &Document,
+        key: &str,
+        value: Option<&str>,
+        db_service: &mut DatabaseService,
+    ) -> Result<PropertyChange> {
+        let previous = db_service
+            .find_properties(&doc.filepath)?
+            .into_iter()
+            .find(|property| property.key == key)
+            .map(|property| property.value);
+        let change = PropertyChange {
+            short_code: doc.short_code.clone(),
+            title: doc.title.clone(),
+            previous,
+            value: value.map(str::to_string),
+        };
+        if !change.changed() {
+            return Ok(change);
+        }
+
+        let path = self.workspace_dir.join(&doc.filepath);
+        let content = fs::read_to_string(&path).map_err(|e| {
+            MetisError::FileSystem(format!("Failed to read {}: {}", path.display(), e))
+        })?;
+        fs::write(&path, set_frontmatter_value(&content, key, value)).map_err(|e| {
+            MetisError::FileSystem(format!("Failed to update {}: {}", path.display(), e))
+        })?;
+
+        Ok(change)
+    }
+
+    fn validate_key(key: &str) -> Result<()> {
+        let valid = !key.is_empty()
+            && key
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+        if !valid || STRUCTURAL_KEYS.contains(&key) {
+            return Err(MetisError::ValidationFailed {
+                message: format!("'{}' is not a property that can be set", key),
+            });
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::services::document::DocumentCreationService;
+    use crate::application::services::workspace::test_support::{config, TestWorkspace};
+
+    #[tokio::test]
+    async fn test_set_property() {
+        let workspace = TestWorkspace::new();
+
+        let creation_service = DocumentCreationService::new(&workspace.metis_dir);
+        let first = creation_service
+            .create_backlog_item(config("First"))
+            .await
+            .unwrap();
+        creation_service
+            .create_backlog_item(config("Second"))
+            .await
+            .unwrap();
+
+        let mut db_service = workspace.synced().await;
+
+        let service = PropertyService::new(&workspace.metis_dir);
+        let change = service
+            .set_property(
+                &first.short_code,
+                "assignee",
+                Some("alice"),
+                &mut db_service,
+            )
+            .unwrap();
+        assert!(change.changed());
+        assert_eq!(change.previous, None);
+        workspace.sync(&mut db_service).await;
+
+        // Both tasks, one of which already has the value
+        let filter = DocumentFilter::parse("type:task").unwrap();
+        let changes = service
+            .set_property_by_filter(&filter, "assignee", Some("alice"), &mut db_service)
+            .unwrap();
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes.iter().filter(|c| c.changed()).count(), 1);
+        workspace.sync(&mut db_service).await;
+
+        let assigned = db_service
+            .find_by_filter(&DocumentFilter::parse("assignee:alice").unwrap())
+            .unwrap();
+        assert_eq!(assigned.len(), 2);
+
+        let change = service
+            .set_property(&first.short_code, "assignee", None, &mut db_service)
+            .unwrap();
+        assert_eq!(change.previous.as_deref(), Some("alice"));
+        workspace.sync(&mut db_service).await;
+        let content = fs::read_to_string(&first.file_path).unwrap();
+        assert!(!content.contains("assignee"));
+
+        // Structural keys belong to the document model
+        assert!(service
+            .set_property(&first.short_code, "parent", Some("x"), &mut db_service)
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_bulk_edit() {
+        let workspace = TestWorkspace::new();
+
+        let creation_service = DocumentCreationService::new(&workspace.metis_dir);
+        let first = creation_service
+            .create_backlog_item(config("First"))
+            .await
+            .unwrap();
+        creation_service
+            .create_backlog_item(config("Second"))
+            .await
+            .unwrap();
+
+        let mut db_service = workspace.synced().await;
+
+        let service = PropertyService::new(&workspace.metis_dir);
+        service
+            .set_property(
+                &first.short_code,
+                "assignee",
+                Some("alice"),
+                &mut db_service,
+            )
+            .unwrap();
+        workspace.sync(&mut db_service).await;
+
+        let filter = DocumentFilter::parse("type:task").unwrap();
+        let updates = vec![
+            ("assignee".to_string(), Some("alice".to_string())),
+            ("priority".to_string(), Some("P1".to_string())),
+        ];
+
+        // The preview writes nothing
+        let plan = service
+            .plan_bulk(&filter, &updates, &mut db_service)
+            .unwrap();
+        assert_eq!(plan.len(), 2);
+        assert!(plan.iter().all(BulkEdit::changed));
+        let first_plan = plan
+            .iter()
+            .find(|edit| edit.short_code == first.short_code)
+            .unwrap();
+        assert!(!first_plan.changes[0].changed());
+        assert!(!fs::read_to_string(&first.file_path)
+            .unwrap()
+            .contains("priority"));
+
+        let edits = service
+            .apply_bulk(&filter, &updates, &mut db_service)
+            .unwrap();
+        assert!(edits.iter().all(|edit| edit.error.is_none()));
+        workspace.sync(&mut db_service).await;
+        let updated = db_service
+            .find_by_filter(&DocumentFilter::parse("assignee:alice").unwrap())
+            .unwrap();
+        assert_eq!(updated.len(), 2);
+        assert!(fs::read_to_string(&first.file_path)
+            .unwrap()
+            .contains("priority: P1"));
+
+        // Applying again changes nothing
+        let edits = service
+            .apply_bulk(&filter, &updates, &mut db_service)
+            .unwrap();
+        assert!(!edits.iter().any(BulkEdit::changed));
+
+        let repeated = vec![
+            ("priority".to_string(), Some("P1".to_string())),
+            ("priority".to_string(), None),
+        ];
+        assert!(service
+            .plan_bulk(&filter, &repeated, &mut db_service)
+            .is_err());
+        let structural = vec![("parent".to_string(), Some("TEST-I-0001".to_string()))];
+        assert!(service
+            .plan_bulk(&filter, &structural, &mut db_service)
+            .is_err());
+    }
+}
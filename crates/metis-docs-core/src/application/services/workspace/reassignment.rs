@@ -37,6 +37,18 @@ impl BacklogCategory {
         }
     }
 
+    /// Every category, in display order
+    pub const ALL: [BacklogCategory; 3] = [Self::Bug, Self::Feature, Self::TechDebt];
+
+    /// The tag label marking an item as this category (without the `#`)
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Bug => "bug",
+            Self::Feature => "feature",
+            Self::TechDebt => "tech-debt",
+        }
+    }
+
     pub fn directory_name(&self) -> &'static str {
         match self {
             Self::Bug => "bugs",
@@ -67,6 +79,31 @@ impl ReassignmentService {
         short_code: &str,
         new_parent_id: &str,
         db_service: &mut DatabaseService,
+    ) -> Result<ReassignmentResult> {
+        let plan = self.plan_to_initiative(short_code, new_parent_id, db_service)?;
+        self.apply(&plan)?;
+        Ok(plan)
+    }
+
+    /// Move a task to the backlog
+    pub async fn reassign_to_backlog(
+        &self,
+        short_code: &str,
+        category: BacklogCategory,
+        db_service: &mut DatabaseService,
+    ) -> Result<ReassignmentResult> {
+        let plan = self.plan_to_backlog(short_code, category, db_service)?;
+        self.apply(&plan)?;
+        Ok(plan)
+    }
+
+    /// Validate a move to a new parent initiative and compute where the task
+    /// would end up, without touching the filesystem
+    pub fn plan_to_initiative(
+        &self,
+        short_code: &str,
+        new_parent_id: &str,
+        db_service: &mut DatabaseService,
     ) -> Result<ReassignmentResult> {
         // Find source document
         let source_doc = self.find_task_by_short_code(short_code, db_service)?;
@@ -77,20 +114,19 @@ impl ReassignmentService {
         // Determine paths
         let source_path = self.workspace_dir.join(&source_doc.filepath);
         let dest_path = self.compute_initiative_task_path(&parent_doc, &source_doc)?;
-
-        // Perform the move
-        self.move_file(&source_path, &dest_path)?;
+        self.validate_move(&source_path, &dest_path)?;
 
         Ok(ReassignmentResult {
             short_code: short_code.to_string(),
             old_path: source_path,
             new_path: dest_path,
-            new_parent: Some(new_parent_id.to_string()),
+            new_parent: Some(parent_doc.short_code),
         })
     }
 
-    /// Move a task to the backlog
-    pub async fn reassign_to_backlog(
+    /// Validate a move to the backlog and compute where the task would end
+    /// up, without touching the filesystem
+    pub fn plan_to_backlog(
         &self,
         short_code: &str,
         category: BacklogCategory,
@@ -113,9 +149,7 @@ impl ReassignmentService {
             .join("backlog")
             .join(category.directory_name())
             .join(filename);
-
-        // Perform the move
-        self.move_file(&source_path, &dest_path)?;
+        self.validate_move(&source_path, &dest_path)?;
 
         Ok(ReassignmentResult {
             short_code: short_code.to_string(),
@@ -125,6 +159,13 @@ impl ReassignmentService {
         })
     }
 
+    /// Move the file and point its `parent` field at the new parent.
+    /// The database picks up the new location on the next sync.
+    fn apply(&self, plan: &ReassignmentResult) -> Result<()> {
+        self.move_file(&plan.old_path, &plan.new_path)?;
+        Self::set_parent_field(&plan.new_path, plan.new_parent.as_deref())
+    }
+
     /// Find a task by short code and validate it's a task
     fn find_task_by_short_code(
         &self,
@@ -210,8 +251,8 @@ impl ReassignmentService {
             .join(filename))
     }
 
-    /// Move a file from source to destination
-    fn move_file(&self, source: &Path, dest: &Path) -> Result<()> {
+    /// Check that a file can be moved from source to destination
+    fn validate_move(&self, source: &Path, dest: &Path) -> Result<()> {
         // Validate source exists
         if !source.exists() {
             return Err(MetisError::NotFound(format!(
@@ -220,20 +261,27 @@ impl ReassignmentService {
             )));
         }
 
-        // Check destination doesn't exist
-        if dest.exists() {
+        // Same location check
+        if source == dest {
             return Err(MetisError::ValidationFailed {
-                message: format!("Destination already exists: {}", dest.display()),
+                message: "Task is already at the target location".to_string(),
             });
         }
 
-        // Same location check
-        if source == dest {
+        // Check destination doesn't exist
+        if dest.exists() {
             return Err(MetisError::ValidationFailed {
-                message: "Task is already at the target location".to_string(),
+                message: format!("Destination already exists: {}", dest.display()),
             });
         }
 
+        Ok(())
+    }
+
+    /// Move a file from source to destination
+    fn move_file(&self, source: &Path, dest: &Path) -> Result<()> {
+        self.validate_move(source, dest)?;
+
         // Create destination directory if needed
         if let Some(parent_dir) = dest.parent() {
             if !parent_dir.exists() {
@@ -249,6 +297,50 @@ impl ReassignmentService {
 
         Ok(())
     }
+
+    /// Rewrite the `parent:` line of a document's frontmatter. Sync derives
+    /// the initiative from the path but takes the parent from frontmatter,
+    /// so a moved task would otherwise keep pointing at its old parent.
+    fn set_parent_field(path: &Path, parent: Option<&str>) -> Result<()> {
+        let content = fs::read_to_string(path)
+            .map_err(|e| MetisError::FileSystem(format!("Failed to read moved task: {}", e)))?;
+        let updated = replace_parent_line(&content, parent.unwrap_or_default());
+        if updated != content {
+            fs::write(path, updated).map_err(|e| {
+                MetisError::FileSystem(format!("Failed to update moved task: {}", e))
+            })?;
+        }
+        Ok(())
+    }
+}
+
+/// Replace the value of the top-level `parent:` field inside the frontmatter
+/// block, leaving the rest of the document byte-for-byte unchanged
+fn replace_parent_line(content: &str, parent: &str) -> String {
+    let mut result = String::with_capacity(content.len());
+    let mut delimiters = 0;
+    let mut replaced = false;
+
+    for line in content.split_inclusive('\n') {
+        let trimmed = line.trim_end_matches(['\r', '\n']);
+        if trimmed == "---" {
+            delimiters += 1;
+        } else if delimiters == 1 && !replaced && trimmed.starts_with("parent:") {
+            let ending = &line[trimmed.len()..];
+            if parent.is_empty() {
+                result.push_str("parent:");
+            } else {
+                result.push_str("parent: ");
+                result.push_str(parent);
+            }
+            result.push_str(ending);
+            replaced = true;
+            continue;
+        }
+        result.push_str(line);
+    }
+
+    result
 }
 
 #[cfg(test)]
@@ -283,4 +375,17 @@ mod tests {
         assert_eq!(BacklogCategory::Feature.directory_name(), "features");
         assert_eq!(BacklogCategory::TechDebt.directory_name(), "tech-debt");
     }
+
+    #[test]
+    fn test_replace_parent_line() {
+        let content = "---\nid: t\nparent: PROJ-I-0001\nblocked_by: []\n---\n\nparent: body text\n";
+        assert_eq!(
+            replace_parent_line(content, "PROJ-I-0002"),
+            "---\nid: t\nparent: PROJ-I-0002\nblocked_by: []\n---\n\nparent: body text\n"
+        );
+        assert_eq!(
+            replace_parent_line(content, ""),
+            "---\nid: t\nparent:\nblocked_by: []\n---\n\nparent: body text\n"
+        );
+    }
 }
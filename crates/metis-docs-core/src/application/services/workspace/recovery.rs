@@ -1,8 +1,8 @@
 use crate::application::services::SyncService;
+use crate::dal::database;
 use crate::dal::database::configuration_repository::ConfigurationRepository;
 use crate::domain::configuration::ConfigFile;
 use crate::{Database, MetisError, Result};
-use diesel::{sqlite::SqliteConnection, Connection};
 use std::path::Path;
 
 /// Service for recovering workspace configuration from filesystem
@@ -55,7 +55,7 @@ impl ConfigurationRecoveryService {
 
         // Step 2: Sync configuration to database
         let mut config_repo = ConfigurationRepository::new(
-            SqliteConnection::establish(db_path.to_str().unwrap()).map_err(|e| {
+            database::establish(db_path.to_str().unwrap()).map_err(|e| {
                 MetisError::ConfigurationError(
                     crate::domain::configuration::ConfigurationError::InvalidValue(e.to_string()),
                 )
@@ -115,7 +115,7 @@ impl ConfigurationRecoveryService {
             ConfigFile::load(&config_file_path).map_err(MetisError::ConfigurationError)?;
 
         let mut config_repo = ConfigurationRepository::new(
-            SqliteConnection::establish(db_path.to_str().unwrap()).map_err(|e| {
+            database::establish(db_path.to_str().unwrap()).map_err(|e| {
                 MetisError::ConfigurationError(
                     crate::domain::configuration::ConfigurationError::InvalidValue(e.to_string()),
                 )
@@ -144,7 +144,7 @@ impl ConfigurationRecoveryService {
     /// Create config.toml from existing database (migration path)
     fn create_config_from_database(config_file_path: &Path, db_path: &Path) -> Result<ConfigFile> {
         let mut config_repo = ConfigurationRepository::new(
-            SqliteConnection::establish(db_path.to_str().unwrap()).map_err(|e| {
+            database::establish(db_path.to_str().unwrap()).map_err(|e| {
                 MetisError::ConfigurationError(
                     crate::domain::configuration::ConfigurationError::InvalidValue(e.to_string()),
                 )
@@ -156,8 +156,8 @@ impl ConfigurationRecoveryService {
             .unwrap_or_else(|| "PROJ".to_string());
         let flight_levels = config_repo.get_flight_level_config()?;
 
-        let config_file = ConfigFile::new(prefix, flight_levels)
-            .map_err(MetisError::ConfigurationError)?;
+        let config_file =
+            ConfigFile::new(prefix, flight_levels).map_err(MetisError::ConfigurationError)?;
 
         config_file
             .save(config_file_path)
@@ -0,0 +1,374 @@
+use crate::domain::configuration::SyncConfig;
+use crate::{MetisError, Result};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// How a document changed on one side of a sync
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Change {
+    Added,
+    Modified,
+    Deleted,
+}
+
+impl Change {
+    fn from_status(status: char) -> Option<Self> {
+        match status {
+            'A' => Some(Self::Added),
+            'M' | 'T' => Some(Self::Modified),
+            'D' => Some(Self::Deleted),
+            _ => None,
+        }
+    }
+}
+
+/// What a sync with the remote would do, keyed by path relative to `.metis/`
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct SyncPlan {
+    pub pull: BTreeMap<String, Change>,
+    pub push: BTreeMap<String, Change>,
+    /// Changed on both sides: (remote change, local change)
+    pub conflicts: BTreeMap<String, (Change, Change)>,
+}
+
+impl SyncPlan {
+    /// Split remote and local changes since the merge base; a document
+    /// changed on both sides is a conflict
+    pub fn new(remote: BTreeMap<String, Change>, mut local: BTreeMap<String, Change>) -> Self {
+        let mut plan = Self::default();
+        for (path, theirs) in remote {
+            match local.remove(&path) {
+                // Both sides deleted it; nothing to reconcile
+                Some(Change::Deleted) if theirs == Change::Deleted => {}
+                Some(ours) => {
+                    plan.conflicts.insert(path, (theirs, ours));
+                }
+                None => {
+                    plan.pull.insert(path, theirs);
+                }
+            }
+        }
+        plan.push = local;
+        plan
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pull.is_empty() && self.push.is_empty() && self.conflicts.is_empty()
+    }
+}
+
+/// A commit that changed the workspace
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkspaceCommit {
+    pub sha: String,
+    pub committed_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// A step of working out a sync plan, reported as it starts
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RemoteSyncStage {
+    /// Fetching the remote branch, named by its label
+    Fetching(String),
+    /// Diffing both sides against where they diverged
+    Comparing,
+    /// Listing new documents not yet known to git
+    ScanningUntracked,
+    /// Committing local changes to push
+    Committing,
+    /// Merging the remote's changes, named by the branch's label
+    Pulling(String),
+    /// Pushing to the remote branch, named by its label
+    Pushing(String),
+}
+
+/// Service for comparing the workspace with the git remote it syncs with
+pub struct RemoteSyncService {
+    workspace_dir: PathBuf,
+}
+
+impl RemoteSyncService {
+    /// Create a new remote sync service for a workspace
+    pub fn new<P: AsRef<Path>>(workspace_dir: P) -> Self {
+        Self {
+            workspace_dir: workspace_dir.as_ref().to_path_buf(),
+        }
+    }
+
+    /// Fetch the remote branch and work out which documents would be pulled,
+    /// pushed or conflict, without touching the working tree or the remote.
+    /// `on_progress` is called as each stage starts. Returns the plan and a
+    /// label for the branch compared against.
+    pub fn plan(
+        &self,
+        sync: &SyncConfig,
+        mut on_progress: impl FnMut(RemoteSyncStage),
+    ) -> Result<(SyncPlan, String)> {
+        // 1. Find the branch to compare against and bring it up to date
+        let (upstream, label) = self.fetch_upstream(sync, &mut on_progress)?;
+
+        // 2. Compare both sides with where they diverged
+        Ok((self.compare(&upstream, &mut on_progress)?, label))
+    }
+
+    /// Fetch the remote branch, then pull its changes and commit and push
+    /// the local ones. When a document changed on both sides nothing is
+    /// changed, and the returned plan lists the conflicts. Returns the plan
+    /// and a label for the branch synced with; the caller re-syncs the
+    /// database.
+    pub fn apply(
+        &self,
+        sync: &SyncConfig,
+        mut on_progress: impl FnMut(RemoteSyncStage),
+    ) -> Result<(SyncPlan, String)> {
+        // 1. Work out the plan against the freshly fetched remote
+        let (upstream, label) = self.fetch_upstream(sync, &mut on_progress)?;
+        let plan = self.compare(&upstream, &mut on_progress)?;
+        if !plan.conflicts.is_empty() {
+            return Ok((plan, label));
+        }
+
+        // 2. Commit local changes, so the pull merges commits rather than
+        //    writing into a dirty working tree
+        if !plan.push.is_empty() {
+            on_progress(RemoteSyncStage::Committing);
+            let mut add = vec!["add", "-A", "--"];
+            add.extend(plan.push.keys().map(String::as_str));
+            self.git(&add)?;
+
+            let mut staged = vec!["diff", "--cached", "--name-only", "--"];
+            staged.extend(plan.push.keys().map(String::as_str));
+            if !self.git(&staged)?.trim().is_empty() {
+                let message = format!("Sync {} Metis document(s)", plan.push.len());
+                self.git(&["commit", "--quiet", "-m", message.as_str()])?;
+            }
+        }
+
+        // 3. Merge the remote's changes; no path changed on both sides
+        if !plan.pull.is_empty() {
+            on_progress(RemoteSyncStage::Pulling(label.clone()));
+            self.git(&["merge", "--quiet", "--no-edit", upstream.as_str()])?;
+        }
+
+        // 4. Publish the local changes, merged with the remote's
+        if !plan.push.is_empty() {
+            on_progress(RemoteSyncStage::Pushing(label.clone()));
+            match &sync.remote {
+                Some(remote) => {
+                    let refspec = format!("HEAD:{}", self.branch(sync)?);
+                    self.git(&["push", "--quiet", remote.as_str(), refspec.as_str()])?;
+                }
+                None => {
+                    self.git(&["push", "--quiet"])?;
+                }
+            }
+        }
+
+        Ok((plan, label))
+    }
+
+    /// Like [`plan`](Self::plan), but against the remote branch as it was
+    /// last fetched. Nothing is fetched, so it's cheap enough for status
+    /// checks, but misses remote changes not fetched yet.
+    pub fn plan_fetched(&self, sync: &SyncConfig) -> Result<(SyncPlan, String)> {
+        let (upstream, label) = match &sync.remote {
+            Some(remote) => {
+                let branch = self.branch(sync)?;
+                (
+                    format!("refs/remotes/{}/{}", remote, branch),
+                    format!("{} {}", remote, branch),
+                )
+            }
+            None => {
+                let upstream = self.upstream()?;
+                (upstream.clone(), upstream)
+            }
+        };
+        Ok((self.compare(&upstream, &mut |_| {})?, label))
+    }
+
+    /// The last commit that changed the workspace: what others get when
+    /// they pull it. `None` when no commit touches the workspace yet.
+    pub fn last_commit(&self) -> Result<Option<WorkspaceCommit>> {
+        let log = self.git(&["log", "-1", "--format=%H %ct", "--", "."])?;
+        Ok(log.trim().split_once(' ').and_then(|(sha, time)| {
+            Some(WorkspaceCommit {
+                sha: sha.to_string(),
+                committed_at: chrono::DateTime::from_timestamp(time.parse().ok()?, 0)?,
+            })
+        }))
+    }
+
+    /// Diff both sides against where they diverged from `upstream`
+    fn compare(
+        &self,
+        upstream: &str,
+        on_progress: &mut impl FnMut(RemoteSyncStage),
+    ) -> Result<SyncPlan> {
+        on_progress(RemoteSyncStage::Comparing);
+        let base = self.git(&["merge-base", "HEAD", upstream])?;
+        let base = base.trim();
+        let remote_changes = parse_name_status(&self.git(&diff_args(base, Some(upstream)))?);
+        let mut local_changes = parse_name_status(&self.git(&diff_args(base, None))?);
+
+        on_progress(RemoteSyncStage::ScanningUntracked);
+        let untracked = self.git(&["ls-files", "--others", "--exclude-standard", "--", "*.md"])?;
+        for path in untracked.lines() {
+            local_changes.insert(path.to_string(), Change::Added);
+        }
+
+        Ok(SyncPlan::new(remote_changes, local_changes))
+    }
+
+    /// Fetch the remote branch to sync with: the `[sync] remote` from
+    /// config.toml when set, otherwise the branch's upstream. Returns the
+    /// revision to compare against and a label for it.
+    fn fetch_upstream(
+        &self,
+        sync: &SyncConfig,
+        on_progress: &mut impl FnMut(RemoteSyncStage),
+    ) -> Result<(String, String)> {
+        if let Some(remote) = &sync.remote {
+            let branch = self.branch(sync)?;
+            let label = format!("{} {}", remote, branch);
+            on_progress(RemoteSyncStage::Fetching(label.clone()));
+            self.git(&["fetch", "--quiet", remote.as_str(), branch.as_str()])?;
+            return Ok(("FETCH_HEAD".to_string(), label));
+        }
+
+        let upstream = self.upstream()?;
+        on_progress(RemoteSyncStage::Fetching(upstream.clone()));
+        self.git(&["fetch", "--quiet"])?;
+        Ok((upstream.clone(), upstream))
+    }
+
+    /// The branch to sync: `[sync] branch`, or the one checked out
+    fn branch(&self, sync: &SyncConfig) -> Result<String> {
+        match &sync.branch {
+            Some(branch) => Ok(branch.clone()),
+            None => Ok(self
+                .git(&["rev-parse", "--abbrev-ref", "HEAD"])?
+                .trim()
+                .to_string()),
+        }
+    }
+
+    /// The checked out branch's upstream, e.g. `origin/main`
+    fn upstream(&self) -> Result<String> {
+        self.git(&["rev-parse", "--abbrev-ref", "@{upstream}"])
+            .map(|upstream| upstream.trim().to_string())
+            .map_err(|_| MetisError::ValidationFailed {
+                message: "No remote to compare with; set an upstream branch or run 'metis sync remote add origin <url>'".to_string(),
+            })
+    }
+
+    /// Run git in the workspace directory and return its stdout
+    fn git<S: AsRef<str>>(&self, args: &[S]) -> Result<String> {
+        let args: Vec<&str> = args.iter().map(AsRef::as_ref).collect();
+        let output = Command::new("git")
+            .args(&args)
+            .current_dir(&self.workspace_dir)
+            .output()
+            .map_err(|e| MetisError::FileSystem(format!("Failed to run git: {}", e)))?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            if stderr.contains("not a git repository") {
+                return Err(MetisError::ValidationFailed {
+                    message: "Syncing with a remote needs the workspace to be in a git repository"
+                        .to_string(),
+                });
+            }
+            return Err(MetisError::FileSystem(format!(
+                "git {} failed: {}",
+                args.first().copied().unwrap_or_default(),
+                stderr.trim()
+            )));
+        }
+
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    }
+}
+
+/// `git diff` of markdown files from `base` to `to`, or to the working tree
+fn diff_args(base: &str, to: Option<&str>) -> Vec<String> {
+    let mut args = vec![
+        "diff".to_string(),
+        "--name-status".to_string(),
+        "--relative".to_string(),
+        "--no-renames".to_string(),
+        base.to_string(),
+    ];
+    args.extend(to.map(str::to_string));
+    args.push("--".to_string());
+    args.push("*.md".to_string());
+    args
+}
+
+fn parse_name_status(output: &str) -> BTreeMap<String, Change> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let (status, path) = line.split_once('\t')?;
+            let change = Change::from_status(status.chars().next()?)?;
+            Some((path.to_string(), change))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_sync_plan() {
+        let remote = parse_name_status("A\tinitiatives/new.md\nM\tvision.md\nD\tadrs/old.md\n");
+        let local = parse_name_status("M\tvision.md\nD\tadrs/old.md\nM\tbacklog/bug.md\n");
+        let plan = SyncPlan::new(remote, local);
+
+        assert_eq!(plan.pull.len(), 1);
+        assert_eq!(plan.pull["initiatives/new.md"], Change::Added);
+        assert_eq!(plan.push.len(), 1);
+        assert_eq!(plan.push["backlog/bug.md"], Change::Modified);
+        // Deleted on both sides agrees, so only vision.md conflicts
+        assert_eq!(plan.conflicts.len(), 1);
+        assert_eq!(
+            plan.conflicts["vision.md"],
+            (Change::Modified, Change::Modified)
+        );
+    }
+
+    #[test]
+    fn test_plan_without_remote_fails_before_fetching() {
+        let temp_dir = tempdir().unwrap();
+        let mut stages = Vec::new();
+
+        let result = RemoteSyncService::new(temp_dir.path())
+            .plan(&SyncConfig::default(), |stage| stages.push(stage));
+
+        assert!(result.is_err());
+        assert!(stages.is_empty());
+    }
+
+    #[test]
+    fn test_apply_without_remote_fails_before_fetching() {
+        let temp_dir = tempdir().unwrap();
+        let mut stages = Vec::new();
+
+        let result = RemoteSyncService::new(temp_dir.path())
+            .apply(&SyncConfig::default(), |stage| stages.push(stage));
+
+        assert!(result.is_err());
+        assert!(stages.is_empty());
+    }
+
+    #[test]
+    fn test_plan_fetched_without_remote_fails() {
+        let temp_dir = tempdir().unwrap();
+        let remote = RemoteSyncService::new(temp_dir.path());
+
+        assert!(remote.plan_fetched(&SyncConfig::default()).is_err());
+        assert!(remote.last_commit().is_err());
+    }
+}
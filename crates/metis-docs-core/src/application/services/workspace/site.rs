@@ -0,0 +1,431 @@
+use crate::application::services::DatabaseService;
+use crate::dal::database::models::Document;
+use crate::dal::database::{DocumentFilter, DocumentSort};
+use crate::domain::documents::types::DocumentType;
+use crate::Result;
+use gray_matter::{engine::YAML, Matter};
+use pulldown_cmark::{html, Event, Options, Parser};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Levels that get an index page, in hierarchy order
+const LEVELS: &[(DocumentType, &str)] = &[
+    (DocumentType::Vision, "Vision"),
+    (DocumentType::Specification, "Specifications"),
+    (DocumentType::Initiative, "Initiatives"),
+    (DocumentType::Task, "Tasks"),
+    (DocumentType::Adr, "ADRs"),
+];
+
+const STYLESHEET: &str = "\
+body { font-family: system-ui, sans-serif; max-width: 56rem; margin: 2rem auto; padding: 0 1rem; color: #222; line-height: 1.5; }
+nav { margin-bottom: 1.5rem; font-size: 0.9rem; }
+nav a { margin-right: 1rem; }
+a { color: #1d5fa8; text-decoration: none; }
+a:hover { text-decoration: underline; }
+table { border-collapse: collapse; width: 100%; }
+th, td { text-align: left; padding: 0.35rem 0.6rem; border-bottom: 1px solid #e3e3e3; }
+code, pre { background: #f5f5f5; border-radius: 3px; }
+pre { padding: 0.75rem; overflow-x: auto; }
+.code { font-family: monospace; color: #666; }
+.badge { display: inline-block; padding: 0.1rem 0.5rem; border-radius: 999px; font-size: 0.8rem; background: #e3e3e3; color: #333; }
+.phase-active, .phase-published, .phase-decided { background: #d4f0d4; color: #1e6b1e; }
+.phase-blocked { background: #f8d7d7; color: #8a1f1f; }
+.phase-completed { background: #dbe7f7; color: #1d4f8a; }
+.phase-review, .phase-discussion, .phase-decompose { background: #fff1c9; color: #7a5a00; }
+.phase-superseded { background: #eee; color: #888; text-decoration: line-through; }
+.breadcrumbs { color: #666; font-size: 0.9rem; }
+";
+
+/// Service for rendering the workspace as a static, cross-linked HTML site
+pub struct SiteExportService {
+    workspace_dir: PathBuf,
+}
+
+/// Result of a site export
+#[derive(Debug)]
+pub struct SiteExportReport {
+    pub out_dir: PathBuf,
+    /// Document pages written
+    pub documents: usize,
+    /// Index pages written, including the home page
+    pub indexes: usize,
+}
+
+impl SiteExportService {
+    /// Create a new site export service for a workspace
+    pub fn new<P: AsRef<Path>>(workspace_dir: P) -> Self {
+        Self {
+            workspace_dir: workspace_dir.as_ref().to_path_buf(),
+        }
+    }
+
+    /// Render every unarchived local document into `out_dir`: a home page,
+    /// one index per level and one page per document under `docs/`
+    pub fn export<P: AsRef<Path>>(
+        &self,
+        out_dir: P,
+        db_service: &mut DatabaseService,
+    ) -> Result<SiteExportReport> {
+        let out_dir = out_dir.as_ref().to_path_buf();
+        let docs = db_service.find_by_filter(
+            &DocumentFilter::new()
+                .include_archived(false)
+                .sort_by(DocumentSort::Type, false),
+        )?;
+        let site = Site::new(&docs);
+
+        fs::create_dir_all(out_dir.join("docs"))?;
+        fs::write(out_dir.join("style.css"), STYLESHEET)?;
+
+        fs::write(out_dir.join("index.html"), site.home_page())?;
+        let mut indexes = 1;
+        // Every level gets a page, even an empty one, so navigation never
+        // links to a missing file
+        for (doc_type, label) in LEVELS {
+            let level: Vec<&Document> = site.of_type(*doc_type).collect();
+            fs::write(
+                out_dir.join(format!("{}.html", level_slug(*doc_type))),
+                site.level_page(label, &level),
+            )?;
+            indexes += 1;
+        }
+
+        for doc in &docs {
+            let body = self.body_markdown(doc)?;
+            fs::write(
+                out_dir
+                    .join("docs")
+                    .join(format!("{}.html", doc.short_code)),
+                site.document_page(doc, &body),
+            )?;
+        }
+
+        Ok(SiteExportReport {
+            out_dir,
+            documents: docs.len(),
+            indexes,
+        })
+    }
+
+    /// Document body without its frontmatter, read from disk if the
+    /// database has no content for it
+    fn body_markdown(&self, doc: &Document) -> Result<String> {
+        let raw = match &doc.content {
+            Some(content) => content.clone(),
+            None => fs::read_to_string(self.workspace_dir.join(&doc.filepath))?,
+        };
+        Ok(Matter::<YAML>::new().parse(&raw).content)
+    }
+}
+
+/// The documents being exported, indexed for cross-linking
+struct Site<'a> {
+    docs: &'a [Document],
+    /// Document ID and short code both resolve, since `parent` may hold either
+    by_key: HashMap<&'a str, &'a Document>,
+    children: HashMap<&'a str, Vec<&'a Document>>,
+}
+
+impl<'a> Site<'a> {
+    fn new(docs: &'a [Document]) -> Self {
+        let mut by_key = HashMap::new();
+        for doc in docs {
+            by_key.insert(doc.id.as_str(), doc);
+            by_key.insert(doc.short_code.as_str(), doc);
+        }
+
+        let mut site = Self {
+            docs,
+            by_key,
+            children: HashMap::new(),
+        };
+        for doc in docs {
+            if let Some(parent) = site.parent_of(doc) {
+                site.children
+                    .entry(parent.short_code.as_str())
+                    .or_default()
+                    .push(doc);
+            }
+        }
+        site
+    }
+
+    fn of_type(&self, doc_type: DocumentType) -> impl Iterator<Item = &'a Document> {
+        let doc_type = doc_type.to_string();
+        self.docs
+            .iter()
+            .filter(move |doc| doc.document_type == doc_type)
+    }
+
+    fn parent_of(&self, doc: &Document) -> Option<&'a Document> {
+        doc.parent_id
+            .as_deref()
+            .filter(|parent| !parent.is_empty())
+            .and_then(|parent| self.by_key.get(parent).copied())
+    }
+
+    fn home_page(&self) -> String {
+        let mut body = String::from("<h1>Overview</h1>\n");
+        for vision in self.of_type(DocumentType::Vision) {
+            body.push_str(&format!(
+                "<h2>{} {}</h2>\n",
+                link(vision, "docs/"),
+                phase_badge(&vision.phase)
+            ));
+        }
+
+        body.push_str("<table>\n<tr><th>Level</th><th>Documents</th><th>By phase</th></tr>\n");
+        for (doc_type, label) in LEVELS {
+            let level: Vec<&Document> = self.of_type(*doc_type).collect();
+            if level.is_empty() {
+                continue;
+            }
+            let mut phases: Vec<(&str, usize)> = Vec::new();
+            for doc in &level {
+                match phases.iter_mut().find(|(phase, _)| *phase == doc.phase) {
+                    Some((_, count)) => *count += 1,
+                    None => phases.push((doc.phase.as_str(), 1)),
+                }
+            }
+            let phases: Vec<String> = phases
+                .iter()
+                .map(|(phase, count)| format!("{} {}", phase_badge(phase), count))
+                .collect();
+            body.push_str(&format!(
+                "<tr><td><a href=\"{}.html\">{}</a></td><td>{}</td><td>{}</td></tr>\n",
+                level_slug(*doc_type),
+                label,
+                level.len(),
+                phases.join(" ")
+            ));
+        }
+        body.push_str("</table>\n");
+
+        page("Overview", "", &body)
+    }
+
+    fn level_page(&self, label: &str, level: &[&Document]) -> String {
+        let mut body = format!(
+            "<h1>{}</h1>\n<table>\n<tr><th>Code</th><th>Title</th><th>Phase</th><th>Parent</th></tr>\n",
+            escape(label)
+        );
+        for doc in level {
+            body.push_str(&format!(
+                "<tr><td class=\"code\">{}</td><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+                escape(&doc.short_code),
+                link(doc, "docs/"),
+                phase_badge(&doc.phase),
+                self.parent_of(doc)
+                    .map(|parent| link(parent, "docs/"))
+                    .unwrap_or_default()
+            ));
+        }
+        body.push_str("</table>\n");
+        if level.is_empty() {
+            body.push_str("<p>No documents.</p>\n");
+        }
+
+        page(label, "", &body)
+    }
+
+    fn document_page(&self, doc: &Document, markdown: &str) -> String {
+        let mut ancestors = Vec::new();
+        let mut current = self.parent_of(doc);
+        while let Some(parent) = current {
+            // Guard against parent cycles in hand-edited frontmatter
+            if ancestors.len() >= self.docs.len() {
+                break;
+            }
+            ancestors.push(parent);
+            current = self.parent_of(parent);
+        }
+
+        let mut body = String::new();
+        if !ancestors.is_empty() {
+            let crumbs: Vec<String> = ancestors.iter().rev().map(|a| link(a, "")).collect();
+            body.push_str(&format!(
+                "<p class=\"breadcrumbs\">{} &rsaquo;</p>\n",
+                crumbs.join(" &rsaquo; ")
+            ));
+        }
+        body.push_str(&format!(
+            "<h1>{} {}</h1>\n<p class=\"code\">{} &middot; {}</p>\n",
+            escape(&doc.title),
+            phase_badge(&doc.phase),
+            escape(&doc.short_code),
+            escape(&doc.document_type)
+        ));
+        body.push_str(&render_markdown(markdown));
+
+        if let Some(children) = self.children.get(doc.short_code.as_str()) {
+            body.push_str("<h2>Children</h2>\n<ul>\n");
+            for child in children {
+                body.push_str(&format!(
+                    "<li><span class=\"code\">{}</span> {} {}</li>\n",
+                    escape(&child.short_code),
+                    link(child, ""),
+                    phase_badge(&child.phase)
+                ));
+            }
+            body.push_str("</ul>\n");
+        }
+
+        page(&doc.title, "../", &body)
+    }
+}
+
+fn level_slug(doc_type: DocumentType) -> String {
+    format!("{}s", doc_type)
+}
+
+/// Wrap page content with the shared layout; `root` is the relative path
+/// back to the site root
+fn page(title: &str, root: &str, body: &str) -> String {
+    let mut nav = format!("<a href=\"{}index.html\">Overview</a>", root);
+    for (doc_type, label) in LEVELS {
+        nav.push_str(&format!(
+            "<a href=\"{}{}.html\">{}</a>",
+            root,
+            level_slug(*doc_type),
+            label
+        ));
+    }
+
+    format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n\
+         <title>{}</title>\n<link rel=\"stylesheet\" href=\"{}style.css\">\n</head>\n\
+         <body>\n<nav>{}</nav>\n{}</body>\n</html>\n",
+        escape(title),
+        root,
+        nav,
+        body
+    )
+}
+
+/// Link to a document page; `prefix` is the path to the `docs/` directory
+fn link(doc: &Document, prefix: &str) -> String {
+    format!(
+        "<a href=\"{}{}.html\">{}</a>",
+        prefix,
+        escape(&doc.short_code),
+        escape(&doc.title)
+    )
+}
+
+fn phase_badge(phase: &str) -> String {
+    format!(
+        "<span class=\"badge phase-{}\">{}</span>",
+        escape(phase),
+        escape(phase)
+    )
+}
+
+/// Render a document body. Raw HTML in the markdown is shown as text rather
+/// than passed through, so a document can't inject scripts into the site.
+fn render_markdown(markdown: &str) -> String {
+    let options =
+        Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TASKLISTS;
+    let events = Parser::new_ext(markdown, options).map(|event| match event {
+        Event::Html(raw) => Event::Text(raw),
+        event => event,
+    });
+    let mut out = String::new();
+    html::push_html(&mut out, events);
+    out
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::services::document::DocumentCreationService;
+    use crate::application::services::workspace::test_support::{config, TestWorkspace};
+
+    #[tokio::test]
+    async fn test_export_site() {
+        let workspace = TestWorkspace::new();
+
+        let creation_service = DocumentCreationService::new(&workspace.metis_dir);
+        let vision = creation_service
+            .create_vision(config("Vision"))
+            .await
+            .unwrap();
+        let initiative = creation_service
+            .create_initiative(config("Ship <it>"))
+            .await
+            .unwrap();
+        workspace.synced().await;
+        let task = creation_service
+            .create_task(config("First"), &initiative.short_code)
+            .await
+            .unwrap();
+
+        let mut db_service = workspace.synced().await;
+        let out_dir = workspace.temp_dir.path().join("site");
+        let report = SiteExportService::new(&workspace.metis_dir)
+            .export(&out_dir, &mut db_service)
+            .unwrap();
+
+        assert_eq!(report.documents, 3);
+        assert_eq!(report.indexes, 6);
+        assert!(out_dir.join("adrs.html").exists());
+        assert!(out_dir.join("style.css").exists());
+
+        let index = fs::read_to_string(out_dir.join("index.html")).unwrap();
+        assert!(index.contains(&format!("docs/{}.html", vision.short_code)));
+        assert!(index.contains("<a href=\"tasks.html\">Tasks</a>"));
+
+        let initiatives = fs::read_to_string(out_dir.join("initiatives.html")).unwrap();
+        assert!(initiatives.contains("Ship &lt;it&gt;"));
+
+        let task_page = fs::read_to_string(
+            out_dir
+                .join("docs")
+                .join(format!("{}.html", task.short_code)),
+        )
+        .unwrap();
+        assert!(task_page.contains("class=\"breadcrumbs\""));
+        assert!(task_page.contains(&format!("href=\"{}.html\"", initiative.short_code)));
+        assert!(task_page.contains("badge phase-todo"));
+        assert!(!task_page.contains("short_code:"));
+
+        let initiative_page = fs::read_to_string(
+            out_dir
+                .join("docs")
+                .join(format!("{}.html", initiative.short_code)),
+        )
+        .unwrap();
+        assert!(initiative_page.contains("<h2>Children</h2>"));
+        assert!(initiative_page.contains(&format!("href=\"{}.html\"", task.short_code)));
+    }
+
+    #[test]
+    fn test_render_markdown() {
+        let rendered = render_markdown("## Goals\n\n| a | b |\n|---|---|\n| 1 | 2 |\n");
+        assert!(rendered.contains("<h2>Goals</h2>"));
+        assert!(rendered.contains("<table>"));
+    }
+
+    #[test]
+    fn test_render_markdown_escapes_raw_html() {
+        let rendered =
+            render_markdown("<script>alert(1)</script>\n\nSee <img src=x onerror=alert(2)> here\n");
+        assert!(!rendered.contains("<script>"));
+        assert!(!rendered.contains("<img"));
+        assert!(rendered.contains("&lt;script&gt;alert(1)&lt;/script&gt;"));
+        assert!(rendered.contains("See &lt;img src=x onerror=alert(2)&gt; here"));
+    }
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(escape("a < b & \"c\""), "a &lt; b &amp; &quot;c&quot;");
+    }
+}
@@ -0,0 +1,194 @@
+use crate::application::services::DatabaseService;
+use crate::dal::database::models::Document;
+use crate::domain::documents::helpers::{set_frontmatter_block_list, FrontmatterParser};
+use crate::{MetisError, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Service for adding and removing document tags.
+///
+/// Tags live in the `tags` list of the document's frontmatter, so they are
+/// written to the file and the database picks them up on the next sync. Phase
+/// tags (`#phase/...`) are managed by phase transitions and can't be changed
+/// here.
+pub struct TagService {
+    workspace_dir: PathBuf,
+}
+
+impl TagService {
+    /// Create a new tag service for a workspace
+    pub fn new<P: AsRef<Path>>(workspace_dir: P) -> Self {
+        let path = workspace_dir.as_ref();
+        let absolute_path = if path.is_absolute() {
+            path.to_path_buf()
+        } else {
+            std::env::current_dir().unwrap_or_default().join(path)
+        };
+
+        Self {
+            workspace_dir: absolute_path,
+        }
+    }
+
+    /// Normalize a tag as it's stored, with a leading `#`
+    pub fn normalize(tag: &str) -> Result<String> {
+        let tag = tag.trim();
+        let label = tag.strip_prefix('#').unwrap_or(tag);
+        if label.is_empty() || label.chars().any(|c| c.is_whitespace() || c == '"') {
+            return Err(MetisError::ValidationFailed {
+                message: format!("'{}' is not a valid tag", tag),
+            });
+        }
+        // Tags match case-insensitively, so #Phase/active would reach the
+        // real phase tag too
+        if label.to_lowercase().starts_with("phase/") {
+            return Err(MetisError::ValidationFailed {
+                message: format!(
+                    "'{}' is a phase tag; use 'metis transition' to change phases",
+                    tag
+                ),
+            });
+        }
+        Ok(format!("#{}", label))
+    }
+
+    /// Add `tag` to a document. Returns false if it already has the tag.
+    pub fn add_tag(
+        &self,
+        short_code: &str,
+        tag: &str,
+        db_service: &mut DatabaseService,
+    ) -> Result<bool> {
+        let tag = Self::normalize(tag)?;
+        let doc = Self::find(short_code, db_service)?;
+
+        let path = self.workspace_dir.join(&doc.filepath);
+        let content = Self::read(&path)?;
+        let mut tags = Self::current_tags(&content);
+        if tags.iter().any(|existing| Self::same_tag(existing, &tag)) {
+            return Ok(false);
+        }
+        tags.push(tag);
+        Self::write(&path, &set_frontmatter_block_list(&content, "tags", &tags))?;
+
+        Ok(true)
+    }
+
+    /// Remove `tag` from a document. Returns false if it didn't have the tag.
+    pub fn remove_tag(
+        &self,
+        short_code: &str,
+        tag: &str,
+        db_service: &mut DatabaseService,
+    ) -> Result<bool> {
+        let tag = Self::normalize(tag)?;
+        let doc = Self::find(short_code, db_service)?;
+
+        let path = self.workspace_dir.join(&doc.filepath);
+        let content = Self::read(&path)?;
+        let mut tags = Self::current_tags(&content);
+        let before = tags.len();
+        tags.retain(|existing| !Self::same_tag(existing, &tag));
+        if tags.len() == before {
+            return Ok(false);
+        }
+        Self::write(&path, &set_frontmatter_block_list(&content, "tags", &tags))?;
+
+        Ok(true)
+    }
+
+    /// The tags declared in a document's frontmatter, in file order
+    pub fn tags(&self, short_code: &str, db_service: &mut DatabaseService) -> Result<Vec<String>> {
+        let doc = Self::find(short_code, db_service)?;
+        let content = Self::read(&self.workspace_dir.join(&doc.filepath))?;
+        Ok(Self::current_tags(&content))
+    }
+
+    /// Tags compare case-insensitively, with or without the leading `#`
+    fn same_tag(a: &str, b: &str) -> bool {
+        a.trim_start_matches('#')
+            .eq_ignore_ascii_case(b.trim_start_matches('#'))
+    }
+
+    fn current_tags(content: &str) -> Vec<String> {
+        use gray_matter::{engine::YAML, Matter};
+
+        match Matter::<YAML>::new().parse(content).data {
+            Some(gray_matter::Pod::Hash(map)) => {
+                FrontmatterParser::extract_string_array(&map, "tags").unwrap_or_default()
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    fn find(short_code: &str, db_service: &mut DatabaseService) -> Result<Document> {
+        db_service
+            .find_by_short_code(short_code)?
+            .ok_or_else(|| MetisError::NotFound(format!("Document '{}' not found", short_code)))
+    }
+
+    fn read(path: &Path) -> Result<String> {
+        fs::read_to_string(path).map_err(|e| {
+            MetisError::FileSystem(format!("Failed to read {}: {}", path.display(), e))
+        })
+    }
+
+    fn write(path: &Path, content: &str) -> Result<()> {
+        fs::write(path, content).map_err(|e| {
+            MetisError::FileSystem(format!("Failed to update {}: {}", path.display(), e))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::services::document::DocumentCreationService;
+    use crate::application::services::workspace::test_support::{config, TestWorkspace};
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(TagService::normalize("api").unwrap(), "#api");
+        assert_eq!(TagService::normalize(" #api ").unwrap(), "#api");
+        assert!(TagService::normalize("#").is_err());
+        assert!(TagService::normalize("two words").is_err());
+        assert!(TagService::normalize("#phase/active").is_err());
+        assert!(TagService::normalize("Phase/Active").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_add_and_remove_tag() {
+        let workspace = TestWorkspace::new();
+
+        let task = DocumentCreationService::new(&workspace.metis_dir)
+            .create_backlog_item(config("Tagged"))
+            .await
+            .unwrap();
+
+        let mut db_service = workspace.synced().await;
+
+        let service = TagService::new(&workspace.metis_dir);
+        assert!(service
+            .add_tag(&task.short_code, "api", &mut db_service)
+            .unwrap());
+        assert!(!service
+            .add_tag(&task.short_code, "#API", &mut db_service)
+            .unwrap());
+        workspace.sync(&mut db_service).await;
+
+        let tags = service.tags(&task.short_code, &mut db_service).unwrap();
+        assert!(tags.contains(&"#api".to_string()));
+        assert!(tags.iter().any(|tag| tag.starts_with("#phase/")));
+        let tagged = db_service.find_by_tag("#api").unwrap();
+        assert_eq!(tagged.len(), 1);
+
+        assert!(service
+            .remove_tag(&task.short_code, "api", &mut db_service)
+            .unwrap());
+        assert!(!service
+            .remove_tag(&task.short_code, "api", &mut db_service)
+            .unwrap());
+        workspace.sync(&mut db_service).await;
+        assert!(db_service.find_by_tag("#api").unwrap().is_empty());
+    }
+}
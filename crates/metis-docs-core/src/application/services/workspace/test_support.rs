@@ -0,0 +1,74 @@
+//! Fixtures shared by the workspace service tests
+
+use crate::application::services::document::creation::DocumentCreationConfig;
+use crate::application::services::{DatabaseService, SyncService};
+use crate::dal::database::configuration_repository::ConfigurationRepository;
+use crate::Database;
+use diesel::{sqlite::SqliteConnection, Connection};
+use std::path::PathBuf;
+use tempfile::{tempdir, TempDir};
+
+/// An empty `.metis` workspace using the `TEST` prefix, removed on drop
+pub struct TestWorkspace {
+    pub temp_dir: TempDir,
+    pub metis_dir: PathBuf,
+    pub db_path: PathBuf,
+}
+
+impl TestWorkspace {
+    pub fn new() -> Self {
+        let temp_dir = tempdir().unwrap();
+        let metis_dir = temp_dir.path().join(".metis");
+        std::fs::create_dir_all(&metis_dir).unwrap();
+
+        let db_path = metis_dir.join("metis.db");
+        let _db = Database::new(&db_path.to_string_lossy()).unwrap();
+        let mut config_repo = ConfigurationRepository::new(
+            SqliteConnection::establish(&db_path.to_string_lossy()).unwrap(),
+        );
+        config_repo.set_project_prefix("TEST").unwrap();
+
+        Self {
+            temp_dir,
+            metis_dir,
+            db_path,
+        }
+    }
+
+    /// A new service over the workspace database
+    pub fn db_service(&self) -> DatabaseService {
+        DatabaseService::new(
+            Database::new(&self.db_path.to_string_lossy())
+                .unwrap()
+                .into_repository(),
+        )
+    }
+
+    /// Sync the workspace files into the database behind `db_service`
+    pub async fn sync(&self, db_service: &mut DatabaseService) {
+        SyncService::new(db_service)
+            .with_workspace_dir(&self.metis_dir)
+            .sync_directory(&self.metis_dir)
+            .await
+            .unwrap();
+    }
+
+    /// Sync the workspace files and return a service over the result
+    pub async fn synced(&self) -> DatabaseService {
+        let mut db_service = self.db_service();
+        self.sync(&mut db_service).await;
+        db_service
+    }
+}
+
+/// Creation config for a document with just a title
+pub fn config(title: &str) -> DocumentCreationConfig {
+    DocumentCreationConfig {
+        title: title.to_string(),
+        description: None,
+        parent_id: None,
+        tags: vec![],
+        phase: None,
+        complexity: None,
+    }
+}
@@ -1,4 +1,5 @@
 use crate::application::services::document::DocumentDiscoveryService;
+use crate::domain::documents::helpers::carry_over_frontmatter_fields;
 use crate::domain::documents::traits::Document;
 use crate::domain::documents::types::{DocumentType, Phase};
 use crate::Result;
@@ -142,6 +143,9 @@ impl PhaseTransitionService {
         doc_type: DocumentType,
         target_phase: Phase,
     ) -> Result<()> {
+        let original = std::fs::read_to_string(file_path)
+            .map_err(|e| MetisError::FileSystem(format!("Failed to read document: {}", e)))?;
+
         match doc_type {
             DocumentType::Vision => {
                 let mut vision = Vision::from_file(file_path)
@@ -221,6 +225,16 @@ impl PhaseTransitionService {
                     .map_err(|e| MetisError::InvalidDocument(e.to_string()))?;
             }
         }
+
+        // Documents are re-rendered from their templates, which would drop
+        // custom fields such as `assignee` or `supersedes`
+        let updated = std::fs::read_to_string(file_path)
+            .map_err(|e| MetisError::FileSystem(format!("Failed to read document: {}", e)))?;
+        let carried = carry_over_frontmatter_fields(&original, &updated);
+        if carried != updated {
+            std::fs::write(file_path, carried)
+                .map_err(|e| MetisError::FileSystem(format!("Failed to update document: {}", e)))?;
+        }
         Ok(())
     }
 
@@ -0,0 +1,279 @@
+use crate::application::services::{DatabaseService, FilesystemService};
+use crate::dal::database::models::{Document, DocumentEdge};
+use crate::dal::database::DocumentFilter;
+use crate::domain::documents::criteria::parse_criteria;
+use crate::domain::documents::factory::DocumentFactory;
+use crate::domain::documents::types::RelationshipKind;
+use crate::{MetisError, Result};
+use serde::Serialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Something wrong with a document
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ValidationProblem {
+    /// The frontmatter or content doesn't parse as a document
+    Schema { error: String },
+    /// Exit criteria not checked off yet, by their text
+    UnmetCriteria { unchecked: Vec<String> },
+    /// A blocker or reference naming a document that doesn't exist
+    DanglingReference {
+        relationship: String,
+        short_code: String,
+    },
+    /// The parent is missing, or archived while this document isn't
+    OrphanedParent { parent: String, reason: String },
+}
+
+impl ValidationProblem {
+    /// Unmet criteria are expected while work is under way, so they only
+    /// warn; everything else is an error
+    pub fn is_error(&self) -> bool {
+        !matches!(self, ValidationProblem::UnmetCriteria { .. })
+    }
+}
+
+impl std::fmt::Display for ValidationProblem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ValidationProblem::Schema { error } => write!(f, "invalid document: {}", error),
+            ValidationProblem::UnmetCriteria { unchecked } => {
+                write!(f, "{} exit criteria unmet", unchecked.len())
+            }
+            ValidationProblem::DanglingReference {
+                relationship,
+                short_code,
+            } => write!(f, "{} {} doesn't exist", relationship, short_code),
+            ValidationProblem::OrphanedParent { parent, reason } => {
+                write!(f, "parent {} {}", parent, reason)
+            }
+        }
+    }
+}
+
+/// The problems found with one document
+#[derive(Debug, Clone, Serialize)]
+pub struct DocumentReport {
+    /// Absent when the file doesn't parse far enough to have one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub short_code: Option<String>,
+    /// Path relative to `.metis/`
+    pub filepath: String,
+    pub problems: Vec<ValidationProblem>,
+}
+
+/// The outcome of validating a document or the whole workspace
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ValidationReport {
+    pub documents_checked: usize,
+    pub errors: usize,
+    pub warnings: usize,
+    /// Only documents with problems
+    pub documents: Vec<DocumentReport>,
+}
+
+impl ValidationReport {
+    /// True when nothing is an error; unmet criteria may remain
+    pub fn is_valid(&self) -> bool {
+        self.errors == 0
+    }
+
+    fn add(&mut self, report: DocumentReport) {
+        self.documents_checked += 1;
+        if report.problems.is_empty() {
+            return;
+        }
+        let errors = report.problems.iter().filter(|p| p.is_error()).count();
+        self.errors += errors;
+        self.warnings += report.problems.len() - errors;
+        self.documents.push(report);
+    }
+}
+
+/// Service checking documents before they move on: that they parse, that
+/// their exit criteria are met, and that their parent, blockers and
+/// references exist.
+///
+/// Everything but parsing is read from the database, so callers sync first.
+pub struct WorkspaceValidationService {
+    workspace_dir: PathBuf,
+}
+
+impl WorkspaceValidationService {
+    /// Create a new validation service for a workspace
+    pub fn new<P: AsRef<Path>>(workspace_dir: P) -> Self {
+        let path = workspace_dir.as_ref();
+        let absolute_path = if path.is_absolute() {
+            path.to_path_buf()
+        } else {
+            std::env::current_dir().unwrap_or_default().join(path)
+        };
+
+        Self {
+            workspace_dir: absolute_path,
+        }
+    }
+
+    /// Validate one document
+    pub fn validate_document(
+        &self,
+        short_code: &str,
+        db_service: &mut DatabaseService,
+    ) -> Result<ValidationReport> {
+        let doc = db_service
+            .find_by_short_code(short_code)?
+            .ok_or_else(|| MetisError::NotFound(format!("Document '{}' not found", short_code)))?;
+        let dangling = db_service.find_dangling_edges()?;
+
+        let mut report = ValidationReport::default();
+        report.add(self.check(&doc, &dangling, db_service)?);
+        Ok(report)
+    }
+
+    /// Validate every unarchived document, and every file that should be a
+    /// document but doesn't parse
+    pub fn validate_workspace(&self, db_service: &mut DatabaseService) -> Result<ValidationReport> {
+        let mut documents = db_service.find_by_filter(&DocumentFilter::new())?;
+        documents.sort_by(|a, b| a.short_code.cmp(&b.short_code));
+        let dangling = db_service.find_dangling_edges()?;
+
+        let mut report = ValidationReport::default();
+        let mut checked = Vec::with_capacity(documents.len());
+        for doc in &documents {
+            report.add(self.check(doc, &dangling, db_service)?);
+            checked.push(self.workspace_dir.join(&doc.filepath));
+        }
+
+        // Files that don't parse never made it into the database
+        let mut files = FilesystemService::find_markdown_files(&self.workspace_dir)?;
+        files.sort();
+        for file in files {
+            let path = PathBuf::from(&file);
+            let filepath = path
+                .strip_prefix(&self.workspace_dir)
+                .unwrap_or(&path)
+                .to_string_lossy()
+                .to_string();
+            // Project templates live alongside documents but aren't documents
+            if checked.contains(&path)
+                || filepath.starts_with("templates/")
+                || filepath.starts_with("archived/")
+            {
+                continue;
+            }
+            if let Err(error) = Self::parse(&path) {
+                report.add(DocumentReport {
+                    short_code: None,
+                    filepath,
+                    problems: vec![ValidationProblem::Schema { error }],
+                });
+            }
+        }
+
+        Ok(report)
+    }
+
+    fn check(
+        &self,
+        doc: &Document,
+        dangling: &[DocumentEdge],
+        db_service: &mut DatabaseService,
+    ) -> Result<DocumentReport> {
+        let mut problems = Vec::new();
+
+        if let Err(error) = Self::parse(&self.workspace_dir.join(&doc.filepath)) {
+            problems.push(ValidationProblem::Schema { error });
+        }
+
+        let unchecked: Vec<String> = parse_criteria(doc.content.as_deref().unwrap_or_default())
+            .into_iter()
+            .filter(|criterion| !criterion.checked)
+            .map(|criterion| criterion.text)
+            .collect();
+        if !unchecked.is_empty() {
+            problems.push(ValidationProblem::UnmetCriteria { unchecked });
+        }
+
+        // The document declares its edges, so it is the blocked end of a
+        // blocks edge and the source end of every other kind
+        for edge in dangling
+            .iter()
+            .filter(|edge| edge.origin_filepath == doc.filepath)
+        {
+            if edge.kind == RelationshipKind::Parent.to_string() {
+                problems.push(ValidationProblem::OrphanedParent {
+                    parent: edge.to_code.clone(),
+                    reason: "doesn't exist".to_string(),
+                });
+            } else {
+                let missing = if edge.kind == RelationshipKind::Blocks.to_string() {
+                    &edge.from_code
+                } else {
+                    &edge.to_code
+                };
+                problems.push(ValidationProblem::DanglingReference {
+                    relationship: edge.kind.clone(),
+                    short_code: missing.clone(),
+                });
+            }
+        }
+
+        if let Some(parent_code) = &doc.parent_id {
+            if let Some(parent) = db_service.find_by_short_code(parent_code)? {
+                if parent.archived && !doc.archived {
+                    problems.push(ValidationProblem::OrphanedParent {
+                        parent: parent.short_code,
+                        reason: "is archived".to_string(),
+                    });
+                }
+            }
+        }
+
+        Ok(DocumentReport {
+            short_code: Some(doc.short_code.clone()),
+            filepath: doc.filepath.clone(),
+            problems,
+        })
+    }
+
+    fn parse(path: &Path) -> std::result::Result<(), String> {
+        let raw = fs::read_to_string(path).map_err(|e| e.to_string())?;
+        DocumentFactory::from_content(&raw, &path.to_string_lossy())
+            .map(|_| ())
+            .map_err(|e| e.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_report_counts_errors_and_warnings() {
+        let mut report = ValidationReport::default();
+        report.add(DocumentReport {
+            short_code: Some("TEST-T-0001".to_string()),
+            filepath: "backlog/a.md".to_string(),
+            problems: vec![],
+        });
+        report.add(DocumentReport {
+            short_code: Some("TEST-T-0002".to_string()),
+            filepath: "backlog/b.md".to_string(),
+            problems: vec![
+                ValidationProblem::UnmetCriteria {
+                    unchecked: vec!["Tests pass".to_string()],
+                },
+                ValidationProblem::DanglingReference {
+                    relationship: "blocks".to_string(),
+                    short_code: "TEST-T-0009".to_string(),
+                },
+            ],
+        });
+
+        assert_eq!(report.documents_checked, 2);
+        assert_eq!((report.errors, report.warnings), (1, 1));
+        assert_eq!(report.documents.len(), 1);
+        assert!(!report.is_valid());
+    }
+}
@@ -0,0 +1,321 @@
+//! Flow analytics over phase history: cycle time, throughput, work in
+//! progress and aging.
+//!
+//! Sync records every phase a local document enters in `phase_history`.
+//! Reports combine that history with the current documents. History starts
+//! the first time a workspace syncs with phase tracking, so completions from
+//! before then are not counted.
+
+use super::models::{Document, PhaseChange};
+use chrono::{DateTime, Datelike, Duration, NaiveDate};
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+const SECONDS_PER_DAY: f64 = 86_400.0;
+
+/// Phase in which work on a document starts; cycle time is measured from
+/// the first time a document enters it
+pub const START_PHASE: &str = "active";
+/// Phase in which work on a document is done
+pub const DONE_PHASE: &str = "completed";
+/// Phases counted as work in progress
+pub const WIP_PHASES: &[&str] = &["active", "blocked"];
+
+/// Days from start to completion over the completions in a report
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CycleTimeStats {
+    pub count: usize,
+    pub mean_days: f64,
+    pub median_days: f64,
+    /// 85% of documents completed within this many days
+    pub p85_days: f64,
+    pub max_days: f64,
+}
+
+/// Completions in one calendar week
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ThroughputWeek {
+    /// Monday the week starts on
+    pub week_start: NaiveDate,
+    pub completed: usize,
+}
+
+/// Documents currently in one work-in-progress phase
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PhaseCount {
+    pub phase: String,
+    pub count: usize,
+}
+
+/// A document in progress and how long it has been in its phase
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AgingItem {
+    pub short_code: String,
+    pub title: String,
+    pub phase: String,
+    pub days_in_phase: f64,
+}
+
+/// Flow metrics for one document type over a time window
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FlowReport {
+    pub document_type: String,
+    /// Window start and end as Unix timestamps
+    pub since: f64,
+    pub until: f64,
+    /// Completions in the window, including ones with no recorded start
+    pub completed: usize,
+    /// None when no completion in the window has a recorded start
+    pub cycle_time: Option<CycleTimeStats>,
+    pub throughput: Vec<ThroughputWeek>,
+    pub wip: Vec<PhaseCount>,
+    /// Documents in progress, longest in their phase first
+    pub aging: Vec<AgingItem>,
+}
+
+impl FlowReport {
+    /// Compute the report from the phase history and the current unarchived
+    /// documents of one type
+    pub fn compute(
+        document_type: &str,
+        history: &[PhaseChange],
+        current: &[Document],
+        since: f64,
+        until: f64,
+    ) -> Self {
+        let mut by_code: BTreeMap<&str, Vec<&PhaseChange>> = BTreeMap::new();
+        for change in history {
+            by_code.entry(&change.short_code).or_default().push(change);
+        }
+
+        // Walk each document's history; a reopened document starts a new
+        // cycle after each completion
+        let mut completions = Vec::new();
+        let mut cycle_days = Vec::new();
+        for changes in by_code.values_mut() {
+            changes.sort_by(|a, b| a.entered_at.total_cmp(&b.entered_at));
+            let mut started = None;
+            for change in changes.iter() {
+                if change.phase == START_PHASE && started.is_none() {
+                    started = Some(change.entered_at);
+                } else if change.phase == DONE_PHASE {
+                    if change.entered_at >= since && change.entered_at <= until {
+                        completions.push(change.entered_at);
+                        if let Some(start) = started {
+                            cycle_days.push((change.entered_at - start) / SECONDS_PER_DAY);
+                        }
+                    }
+                    started = None;
+                }
+            }
+        }
+
+        let mut wip: BTreeMap<&str, usize> = BTreeMap::new();
+        let mut aging = Vec::new();
+        for doc in current {
+            if !WIP_PHASES.contains(&doc.phase.as_str()) {
+                continue;
+            }
+            *wip.entry(&doc.phase).or_default() += 1;
+
+            let entered = by_code
+                .get(doc.short_code.as_str())
+                .and_then(|changes| changes.iter().rev().find(|c| c.phase == doc.phase))
+                .map(|change| change.entered_at)
+                .unwrap_or(doc.updated_at);
+            aging.push(AgingItem {
+                short_code: doc.short_code.clone(),
+                title: doc.title.clone(),
+                phase: doc.phase.clone(),
+                days_in_phase: ((until - entered) / SECONDS_PER_DAY).max(0.0),
+            });
+        }
+        aging.sort_by(|a, b| b.days_in_phase.total_cmp(&a.days_in_phase));
+
+        Self {
+            document_type: document_type.to_string(),
+            since,
+            until,
+            completed: completions.len(),
+            cycle_time: cycle_time_stats(cycle_days),
+            throughput: weekly_throughput(&completions, since, until),
+            wip: WIP_PHASES
+                .iter()
+                .filter_map(|phase| {
+                    wip.get(phase).map(|count| PhaseCount {
+                        phase: phase.to_string(),
+                        count: *count,
+                    })
+                })
+                .collect(),
+            aging,
+        }
+    }
+}
+
+fn cycle_time_stats(mut days: Vec<f64>) -> Option<CycleTimeStats> {
+    if days.is_empty() {
+        return None;
+    }
+    days.sort_by(f64::total_cmp);
+
+    let count = days.len();
+    let median_days = if count % 2 == 0 {
+        (days[count / 2 - 1] + days[count / 2]) / 2.0
+    } else {
+        days[count / 2]
+    };
+    // Nearest-rank percentile
+    let p85_rank = ((0.85 * count as f64).ceil() as usize).max(1);
+
+    Some(CycleTimeStats {
+        count,
+        mean_days: days.iter().sum::<f64>() / count as f64,
+        median_days,
+        p85_days: days[p85_rank - 1],
+        max_days: days[count - 1],
+    })
+}
+
+/// Completions per week, with empty weeks included so gaps show up
+fn weekly_throughput(completions: &[f64], since: f64, until: f64) -> Vec<ThroughputWeek> {
+    let (Some(first), Some(last)) = (week_start(since), week_start(until)) else {
+        return Vec::new();
+    };
+
+    let mut weeks = Vec::new();
+    let mut week = first;
+    while week <= last {
+        weeks.push(ThroughputWeek {
+            week_start: week,
+            completed: completions
+                .iter()
+                .filter(|at| week_start(**at) == Some(week))
+                .count(),
+        });
+        week += Duration::weeks(1);
+    }
+    weeks
+}
+
+/// Monday (UTC) of the week containing a Unix timestamp
+fn week_start(timestamp: f64) -> Option<NaiveDate> {
+    let date = DateTime::from_timestamp(timestamp as i64, 0)?.date_naive();
+    Some(date - Duration::days(date.weekday().num_days_from_monday() as i64))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAY: f64 = SECONDS_PER_DAY;
+    /// Monday 2025-03-03 00:00 UTC
+    const MONDAY: f64 = 1_740_960_000.0;
+
+    fn change(code: &str, phase: &str, at: f64) -> PhaseChange {
+        PhaseChange {
+            short_code: code.to_string(),
+            document_type: "task".to_string(),
+            phase: phase.to_string(),
+            entered_at: at,
+        }
+    }
+
+    fn doc(code: &str, phase: &str, updated_at: f64) -> Document {
+        Document {
+            filepath: format!("{}.md", code),
+            id: code.to_lowercase(),
+            title: format!("Task {}", code),
+            document_type: "task".to_string(),
+            created_at: 0.0,
+            updated_at,
+            archived: false,
+            exit_criteria_met: false,
+            file_hash: String::new(),
+            frontmatter_json: "{}".to_string(),
+            content: None,
+            phase: phase.to_string(),
+            initiative_id: None,
+            short_code: code.to_string(),
+            parent_id: None,
+            workspace: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_flow_report() {
+        let history = vec![
+            change("T-1", "todo", MONDAY),
+            change("T-1", "active", MONDAY + DAY),
+            change("T-1", "completed", MONDAY + 3.0 * DAY),
+            change("T-2", "active", MONDAY),
+            change("T-2", "completed", MONDAY + 8.0 * DAY),
+            // Completed without a recorded start
+            change("T-3", "completed", MONDAY + 9.0 * DAY),
+            change("T-4", "active", MONDAY + 2.0 * DAY),
+            change("T-4", "blocked", MONDAY + 4.0 * DAY),
+        ];
+        let current = vec![
+            doc("T-4", "blocked", MONDAY + 4.0 * DAY),
+            doc("T-5", "active", MONDAY + 10.0 * DAY),
+        ];
+
+        let report = FlowReport::compute("task", &history, &current, MONDAY, MONDAY + 13.0 * DAY);
+
+        assert_eq!(report.completed, 3);
+        let cycle = report.cycle_time.unwrap();
+        assert_eq!(cycle.count, 2);
+        assert_eq!(cycle.median_days, 5.0);
+        assert_eq!(cycle.p85_days, 8.0);
+
+        let weekly: Vec<usize> = report.throughput.iter().map(|w| w.completed).collect();
+        assert_eq!(weekly, vec![1, 2]);
+        assert_eq!(
+            report.throughput[0].week_start,
+            NaiveDate::from_ymd_opt(2025, 3, 3).unwrap()
+        );
+
+        assert_eq!(
+            report.wip,
+            vec![
+                PhaseCount {
+                    phase: "active".to_string(),
+                    count: 1
+                },
+                PhaseCount {
+                    phase: "blocked".to_string(),
+                    count: 1
+                },
+            ]
+        );
+        assert_eq!(report.aging[0].short_code, "T-4");
+        assert_eq!(report.aging[0].days_in_phase, 9.0);
+        // No history: falls back to the last update
+        assert_eq!(report.aging[1].days_in_phase, 3.0);
+    }
+
+    #[test]
+    fn test_reopened_document_starts_a_new_cycle() {
+        let history = vec![
+            change("T-1", "active", MONDAY),
+            change("T-1", "completed", MONDAY + DAY),
+            change("T-1", "active", MONDAY + 5.0 * DAY),
+            change("T-1", "completed", MONDAY + 7.0 * DAY),
+        ];
+
+        let report = FlowReport::compute("task", &history, &[], MONDAY, MONDAY + 7.0 * DAY);
+
+        let cycle = report.cycle_time.unwrap();
+        assert_eq!(cycle.count, 2);
+        assert_eq!(cycle.max_days, 2.0);
+    }
+
+    #[test]
+    fn test_empty_history() {
+        let report = FlowReport::compute("task", &[], &[], MONDAY, MONDAY + DAY);
+        assert_eq!(report.completed, 0);
+        assert!(report.cycle_time.is_none());
+        assert_eq!(report.throughput.len(), 1);
+        assert!(report.wip.is_empty());
+    }
+}
@@ -0,0 +1,288 @@
+//! Snapshots of the workspace database using SQLite's online backup API.
+//!
+//! The backup API copies the database page by page while other connections
+//! keep reading and writing, so a snapshot can be taken before risky
+//! operations (imports, migrations) without closing the workspace.
+
+use super::{maintenance, migration, open_connection, Database};
+use diesel::prelude::*;
+use diesel::sql_types::BigInt;
+use diesel::sqlite::SqliteConnection;
+use libsqlite3_sys as ffi;
+use std::ffi::{CStr, CString};
+use std::os::raw::c_int;
+use std::path::Path;
+use std::time::Duration;
+
+type BackupResult<T> = Result<T, Box<dyn std::error::Error + Send + Sync>>;
+
+/// Pages copied per backup step; other connections can take the lock between steps
+const PAGES_PER_STEP: c_int = 256;
+
+/// How long to wait before retrying a step when the source is locked
+const BUSY_RETRY_DELAY: Duration = Duration::from_millis(50);
+
+/// Give up after this many consecutive busy/locked steps
+const MAX_BUSY_RETRIES: u32 = 200;
+
+#[derive(QueryableByName)]
+struct CountRow {
+    #[diesel(sql_type = BigInt)]
+    count: i64,
+}
+
+/// A raw SQLite handle, used only to drive the backup API which diesel
+/// doesn't expose
+struct RawConnection(*mut ffi::sqlite3);
+
+impl RawConnection {
+    fn open(path: &Path, flags: c_int, key: Option<&str>) -> BackupResult<Self> {
+        let c_path = CString::new(path.to_string_lossy().as_bytes())?;
+        let mut handle = std::ptr::null_mut();
+        let rc =
+            unsafe { ffi::sqlite3_open_v2(c_path.as_ptr(), &mut handle, flags, std::ptr::null()) };
+
+        // The handle must be closed even when opening failed
+        let connection = Self(handle);
+        if rc != ffi::SQLITE_OK {
+            return Err(format!(
+                "Unable to open {}: {}",
+                path.display(),
+                connection.error_message()
+            )
+            .into());
+        }
+
+        if let Some(key) = key {
+            connection.execute(&format!("PRAGMA key = '{}'", key.replace('\'', "''")))?;
+        }
+
+        Ok(connection)
+    }
+
+    fn execute(&self, sql: &str) -> BackupResult<()> {
+        let c_sql = CString::new(sql)?;
+        let rc = unsafe {
+            ffi::sqlite3_exec(
+                self.0,
+                c_sql.as_ptr(),
+                None,
+                std::ptr::null_mut(),
+                std::ptr::null_mut(),
+            )
+        };
+        if rc != ffi::SQLITE_OK {
+            return Err(self.error_message().into());
+        }
+        Ok(())
+    }
+
+    fn error_message(&self) -> String {
+        unsafe { CStr::from_ptr(ffi::sqlite3_errmsg(self.0)) }
+            .to_string_lossy()
+            .into_owned()
+    }
+}
+
+impl Drop for RawConnection {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::sqlite3_close(self.0);
+        }
+    }
+}
+
+/// Copy the main database of `source` over the main database of `destination`
+fn copy_database(source: &RawConnection, destination: &RawConnection) -> BackupResult<()> {
+    let main = CString::new("main")?;
+    let backup =
+        unsafe { ffi::sqlite3_backup_init(destination.0, main.as_ptr(), source.0, main.as_ptr()) };
+    if backup.is_null() {
+        return Err(destination.error_message().into());
+    }
+
+    let mut busy_retries = 0;
+    loop {
+        match unsafe { ffi::sqlite3_backup_step(backup, PAGES_PER_STEP) } {
+            ffi::SQLITE_DONE => break,
+            ffi::SQLITE_OK => busy_retries = 0,
+            ffi::SQLITE_BUSY | ffi::SQLITE_LOCKED if busy_retries < MAX_BUSY_RETRIES => {
+                busy_retries += 1;
+                std::thread::sleep(BUSY_RETRY_DELAY);
+            }
+            _ => break,
+        }
+    }
+
+    // Errors from any step are reported by finish
+    let rc = unsafe { ffi::sqlite3_backup_finish(backup) };
+    if rc != ffi::SQLITE_OK {
+        return Err(destination.error_message().into());
+    }
+
+    Ok(())
+}
+
+/// Check that a snapshot is an intact metis database this build can read
+fn validate_snapshot(path: &Path, key: Option<&str>) -> BackupResult<()> {
+    if !path.is_file() {
+        return Err(format!("Backup not found: {}", path.display()).into());
+    }
+
+    let mut connection = SqliteConnection::establish(&path.to_string_lossy())?;
+    if let Some(key) = key {
+        diesel::sql_query(format!("PRAGMA key = '{}'", key.replace('\'', "''")))
+            .execute(&mut connection)?;
+    }
+
+    let errors = maintenance::integrity_errors(&mut connection)
+        .map_err(|e| format!("{} is not a readable database: {}", path.display(), e))?;
+    if !errors.is_empty() {
+        return Err(format!(
+            "Backup {} failed its integrity check: {}",
+            path.display(),
+            errors.join("; ")
+        )
+        .into());
+    }
+
+    // Check for our tables before asking diesel, which would create its
+    // migrations table in the snapshot
+    let tables = diesel::sql_query(
+        "SELECT COUNT(*) AS count FROM sqlite_master WHERE type = 'table' \
+         AND name IN ('__diesel_schema_migrations', 'documents')",
+    )
+    .get_result::<CountRow>(&mut connection)?;
+    if tables.count != 2 || migration::applied_versions(&mut connection)?.is_empty() {
+        return Err(format!("{} is not a metis database", path.display()).into());
+    }
+    migration::ensure_not_newer(&mut connection)?;
+
+    Ok(())
+}
+
+impl Database {
+    /// Write a consistent snapshot of this database to `path`, replacing any
+    /// existing file. Encrypted databases produce an encrypted snapshot under
+    /// the same key.
+    pub fn backup(&self, path: &Path) -> BackupResult<()> {
+        if self.connection_string == ":memory:" {
+            return Err("In-memory databases cannot be backed up".into());
+        }
+
+        if path.exists() {
+            std::fs::remove_file(path)?;
+        }
+
+        let key = self.key.as_deref();
+        let source = RawConnection::open(
+            Path::new(&self.connection_string),
+            ffi::SQLITE_OPEN_READONLY,
+            key,
+        )?;
+        let destination = RawConnection::open(
+            path,
+            ffi::SQLITE_OPEN_READWRITE | ffi::SQLITE_OPEN_CREATE,
+            key,
+        )?;
+        copy_database(&source, &destination)
+    }
+
+    /// Replace the contents of this database with a snapshot taken by
+    /// [`Database::backup`].
+    ///
+    /// The snapshot is validated first (integrity check, metis schema, not
+    /// written by a newer metis), so a bad file leaves the database untouched.
+    /// Snapshots from older versions are migrated after restoring.
+    pub fn restore(&self, path: &Path) -> BackupResult<()> {
+        if self.connection_string == ":memory:" {
+            return Err("In-memory databases cannot be restored".into());
+        }
+
+        let key = self.key.as_deref();
+        validate_snapshot(path, key)?;
+
+        {
+            let source = RawConnection::open(path, ffi::SQLITE_OPEN_READONLY, key)?;
+            let destination = RawConnection::open(
+                Path::new(&self.connection_string),
+                ffi::SQLITE_OPEN_READWRITE,
+                key,
+            )?;
+            copy_database(&source, &destination)?;
+        }
+
+        // Bring an older snapshot up to the current schema
+        let mut connection = open_connection(&self.connection_string, key)?;
+        migration::migrate_connection(&mut connection, &self.connection_string)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_backup_and_restore_round_trip() {
+        let temp_dir = tempdir().unwrap();
+        let db_path = temp_dir.path().join("metis.db");
+        let backup_path = temp_dir.path().join("metis.db.snapshot");
+        let db = Database::new(db_path.to_str().unwrap()).unwrap();
+
+        let mut config = db.configuration_repository().unwrap();
+        config.set("marker", "before").unwrap();
+
+        db.backup(&backup_path).unwrap();
+        assert!(backup_path.is_file());
+
+        config.set("marker", "after").unwrap();
+        drop(config);
+
+        db.restore(&backup_path).unwrap();
+
+        let mut config = db.configuration_repository().unwrap();
+        assert_eq!(config.get("marker").unwrap().as_deref(), Some("before"));
+    }
+
+    #[test]
+    fn test_restore_rejects_invalid_snapshot() {
+        let temp_dir = tempdir().unwrap();
+        let db_path = temp_dir.path().join("metis.db");
+        let db = Database::new(db_path.to_str().unwrap()).unwrap();
+        db.configuration_repository()
+            .unwrap()
+            .set("marker", "kept")
+            .unwrap();
+
+        let missing = db.restore(&temp_dir.path().join("missing.db"));
+        assert!(missing
+            .unwrap_err()
+            .to_string()
+            .contains("Backup not found"));
+
+        let garbage_path = temp_dir.path().join("garbage.db");
+        std::fs::write(&garbage_path, "not a database at all").unwrap();
+        assert!(db.restore(&garbage_path).is_err());
+
+        let empty_path = temp_dir.path().join("empty.db");
+        SqliteConnection::establish(empty_path.to_str().unwrap()).unwrap();
+        let empty = db.restore(&empty_path);
+        assert!(empty
+            .unwrap_err()
+            .to_string()
+            .contains("is not a metis database"));
+
+        let mut config = db.configuration_repository().unwrap();
+        assert_eq!(config.get("marker").unwrap().as_deref(), Some("kept"));
+    }
+
+    #[test]
+    fn test_in_memory_database_cannot_be_backed_up() {
+        let temp_dir = tempdir().unwrap();
+        let db = Database::new(":memory:").unwrap();
+        assert!(db.backup(&temp_dir.path().join("backup.db")).is_err());
+    }
+}
@@ -0,0 +1,68 @@
+//! SQLCipher key handling for encrypted workspace databases.
+//!
+//! The key is read from the `METIS_DB_KEY` environment variable, falling back
+//! to the OS keychain (service `metis`, account = database path). When no key
+//! is configured the database is opened unencrypted, so enabling the feature
+//! doesn't change behaviour for existing workspaces. Keys are added to the
+//! keychain with the OS tools, e.g. `security add-generic-password -s metis
+//! -a <path> -w` on macOS or `secret-tool store service metis username
+//! <path>` on Linux.
+
+use diesel::prelude::*;
+use diesel::sqlite::SqliteConnection;
+
+/// Environment variable holding the database key
+pub const KEY_ENV_VAR: &str = "METIS_DB_KEY";
+
+/// Keychain service name under which database keys are stored
+pub const KEYCHAIN_SERVICE: &str = "metis";
+
+/// Resolve the key for a database, if one is configured
+pub fn resolve_key(connection_string: &str) -> Option<String> {
+    if connection_string == ":memory:" {
+        return None;
+    }
+
+    if let Ok(key) = std::env::var(KEY_ENV_VAR) {
+        if !key.is_empty() {
+            return Some(key);
+        }
+    }
+
+    keyring::Entry::new(KEYCHAIN_SERVICE, &keychain_account(connection_string))
+        .and_then(|entry| entry.get_password())
+        .ok()
+        .filter(|key| !key.is_empty())
+}
+
+/// Keychain account for a database: its canonical path where available
+fn keychain_account(connection_string: &str) -> String {
+    std::fs::canonicalize(connection_string)
+        .map(|path| path.to_string_lossy().to_string())
+        .unwrap_or_else(|_| connection_string.to_string())
+}
+
+/// Apply a database key to a freshly opened connection.
+///
+/// Must run before any other statement on the connection. Verifies the key
+/// by reading the schema so a wrong key fails here with a clear message
+/// rather than later as "file is not a database".
+pub fn apply_key(
+    connection: &mut SqliteConnection,
+    connection_string: &str,
+    key: &str,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    diesel::sql_query(format!("PRAGMA key = '{}'", key.replace('\'', "''"))).execute(connection)?;
+
+    diesel::sql_query("SELECT count(*) FROM sqlite_master")
+        .execute(connection)
+        .map_err(|_| {
+            format!(
+                "Unable to decrypt {}: the key from {} or the OS keychain is wrong, \
+                 or the database is not encrypted",
+                connection_string, KEY_ENV_VAR
+            )
+        })?;
+
+    Ok(())
+}
@@ -0,0 +1,246 @@
+use super::Database;
+use diesel::prelude::*;
+use diesel::sql_types::{BigInt, Text};
+use diesel::sqlite::SqliteConnection;
+use serde::Serialize;
+
+type MaintenanceResult<T> = Result<T, Box<dyn std::error::Error + Send + Sync>>;
+
+/// Side tables keyed by document filepath, with the condition that marks a
+/// row as orphaned (its document no longer exists).
+const ORPHAN_CHECKS: &[(&str, &str)] = &[
+    (
+        "document_tags",
+        "document_filepath NOT IN (SELECT filepath FROM documents)",
+    ),
+    (
+        "document_relationships",
+        "child_filepath NOT IN (SELECT filepath FROM documents) \
+         OR parent_filepath NOT IN (SELECT filepath FROM documents)",
+    ),
+    (
+        "document_edges",
+        "origin_filepath NOT IN (SELECT filepath FROM documents)",
+    ),
+    (
+        "document_properties",
+        "document_filepath NOT IN (SELECT filepath FROM documents)",
+    ),
+    (
+        "document_rollups",
+        "short_code NOT IN (SELECT short_code FROM documents)",
+    ),
+    (
+        "file_stats",
+        "filepath NOT IN (SELECT filepath FROM documents)",
+    ),
+    (
+        "document_search",
+        "document_filepath NOT IN (SELECT filepath FROM documents)",
+    ),
+];
+
+#[derive(QueryableByName)]
+struct IntegrityRow {
+    #[diesel(sql_type = Text)]
+    integrity_check: String,
+}
+
+#[derive(QueryableByName)]
+struct CountRow {
+    #[diesel(sql_type = BigInt)]
+    count: i64,
+}
+
+/// Outcome of a database maintenance pass
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct MaintenanceReport {
+    /// Problems reported by `PRAGMA integrity_check` (empty when healthy)
+    pub integrity_errors: Vec<String>,
+    /// Orphaned rows found (or removed, when repairing) per table
+    pub orphaned_rows: Vec<(String, usize)>,
+    /// Number of documents written back into the full-text index
+    pub search_entries_reindexed: Option<usize>,
+    /// Whether VACUUM and ANALYZE were run
+    pub vacuumed: bool,
+}
+
+impl MaintenanceReport {
+    /// True when the integrity check passed
+    pub fn is_healthy(&self) -> bool {
+        self.integrity_errors.is_empty()
+    }
+
+    /// Total orphaned rows across all tables
+    pub fn total_orphans(&self) -> usize {
+        self.orphaned_rows.iter().map(|(_, count)| count).sum()
+    }
+}
+
+/// Run `PRAGMA integrity_check` on an open connection
+pub(super) fn integrity_errors(
+    connection: &mut SqliteConnection,
+) -> MaintenanceResult<Vec<String>> {
+    let rows = diesel::sql_query("PRAGMA integrity_check").load::<IntegrityRow>(connection)?;
+
+    Ok(rows
+        .into_iter()
+        .map(|row| row.integrity_check)
+        .filter(|message| message != "ok")
+        .collect())
+}
+
+impl Database {
+    /// Run `PRAGMA integrity_check`, returning any reported problems
+    pub fn integrity_check(&self) -> MaintenanceResult<Vec<String>> {
+        let mut connection = self.get_connection()?;
+        integrity_errors(&mut connection)
+    }
+
+    /// Count rows in side tables that point at documents which no longer exist
+    pub fn count_orphans(&self) -> MaintenanceResult<Vec<(String, usize)>> {
+        let mut connection = self.get_connection()?;
+        let mut counts = Vec::new();
+
+        for (table, condition) in ORPHAN_CHECKS {
+            let row = diesel::sql_query(format!(
+                "SELECT COUNT(*) AS count FROM {} WHERE {}",
+                table, condition
+            ))
+            .get_result::<CountRow>(&mut connection)?;
+            counts.push((table.to_string(), row.count as usize));
+        }
+
+        Ok(counts)
+    }
+
+    /// Delete rows in side tables that point at documents which no longer exist
+    pub fn remove_orphans(&self) -> MaintenanceResult<Vec<(String, usize)>> {
+        let mut connection = self.get_connection()?;
+
+        let counts = connection.transaction::<_, diesel::result::Error, _>(|conn| {
+            let mut counts = Vec::new();
+            for (table, condition) in ORPHAN_CHECKS {
+                let removed =
+                    diesel::sql_query(format!("DELETE FROM {} WHERE {}", table, condition))
+                        .execute(conn)?;
+                counts.push((table.to_string(), removed));
+            }
+            Ok(counts)
+        })?;
+
+        Ok(counts)
+    }
+
+    /// Rebuild the full-text search index from the documents table
+    pub fn rebuild_search_index(&self) -> MaintenanceResult<usize> {
+        let mut connection = self.get_connection()?;
+
+        let reindexed = connection.transaction::<_, diesel::result::Error, _>(|conn| {
+            diesel::sql_query("DELETE FROM document_search").execute(conn)?;
+            let reindexed = diesel::sql_query(
+                "INSERT INTO document_search(document_filepath, content, title, document_type)
+                 SELECT filepath, content, title, document_type FROM documents",
+            )
+            .execute(conn)?;
+            diesel::sql_query("INSERT INTO document_search(document_search) VALUES('optimize')")
+                .execute(conn)?;
+            Ok(reindexed)
+        })?;
+
+        Ok(reindexed)
+    }
+
+    /// Reclaim free pages and refresh query planner statistics
+    pub fn vacuum(&self) -> MaintenanceResult<()> {
+        let mut connection = self.get_connection()?;
+        diesel::sql_query("VACUUM").execute(&mut connection)?;
+        diesel::sql_query("ANALYZE").execute(&mut connection)?;
+        Ok(())
+    }
+
+    /// Inspect the database without modifying it
+    pub fn diagnose(&self) -> MaintenanceResult<MaintenanceReport> {
+        Ok(MaintenanceReport {
+            integrity_errors: self.integrity_check()?,
+            orphaned_rows: self.count_orphans()?,
+            search_entries_reindexed: None,
+            vacuumed: false,
+        })
+    }
+
+    /// Run every maintenance operation: integrity check, orphan cleanup,
+    /// search index rebuild, then VACUUM/ANALYZE.
+    ///
+    /// Repairs are skipped when the integrity check fails, since rewriting a
+    /// corrupt database can make recovery harder.
+    pub fn maintain(&self) -> MaintenanceResult<MaintenanceReport> {
+        let integrity_errors = self.integrity_check()?;
+        if !integrity_errors.is_empty() {
+            return Ok(MaintenanceReport {
+                integrity_errors,
+                ..Default::default()
+            });
+        }
+
+        let orphaned_rows = self.remove_orphans()?;
+        let reindexed = self.rebuild_search_index()?;
+        self.vacuum()?;
+
+        Ok(MaintenanceReport {
+            integrity_errors,
+            orphaned_rows,
+            search_entries_reindexed: Some(reindexed),
+            vacuumed: true,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn orphans_in(counts: &[(String, usize)], table: &str) -> usize {
+        counts
+            .iter()
+            .find(|(name, _)| name == table)
+            .map(|(_, count)| *count)
+            .unwrap_or(0)
+    }
+
+    #[test]
+    fn test_maintenance_removes_orphans_and_reindexes() {
+        let temp_dir = tempdir().expect("Failed to create temp dir");
+        let db_path = temp_dir.path().join("metis.db");
+        let db = Database::new(db_path.to_str().unwrap()).expect("Failed to create database");
+
+        let mut connection = db.get_connection().unwrap();
+        diesel::sql_query(
+            "INSERT INTO document_tags (document_filepath, tag) VALUES ('missing.md', '#phase/todo')",
+        )
+        .execute(&mut connection)
+        .unwrap();
+        diesel::sql_query(
+            "INSERT INTO document_edges (from_code, to_code, kind, origin_filepath) \
+             VALUES ('TEST-T-0001', 'TEST-I-0001', 'parent', 'missing.md')",
+        )
+        .execute(&mut connection)
+        .unwrap();
+        drop(connection);
+
+        let diagnosis = db.diagnose().unwrap();
+        assert!(diagnosis.is_healthy());
+        assert_eq!(orphans_in(&diagnosis.orphaned_rows, "document_tags"), 1);
+        assert_eq!(orphans_in(&diagnosis.orphaned_rows, "document_edges"), 1);
+        assert_eq!(diagnosis.total_orphans(), 2);
+
+        let report = db.maintain().unwrap();
+        assert!(report.is_healthy());
+        assert_eq!(report.total_orphans(), 2);
+        assert_eq!(report.search_entries_reindexed, Some(0));
+        assert!(report.vacuumed);
+
+        assert_eq!(db.diagnose().unwrap().total_orphans(), 0);
+    }
+}
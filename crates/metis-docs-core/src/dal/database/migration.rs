@@ -0,0 +1,204 @@
+use super::{Database, MIGRATIONS};
+use crate::MetisError;
+use diesel::migration::MigrationSource;
+use diesel::prelude::*;
+use diesel::sqlite::{Sqlite, SqliteConnection};
+use diesel_migrations::MigrationHarness;
+use std::path::{Path, PathBuf};
+
+type MigrationResult<T> = Result<T, Box<dyn std::error::Error + Send + Sync>>;
+
+/// Outcome of applying pending migrations
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MigrationOutcome {
+    /// Names of the migrations that were applied, oldest first
+    pub applied: Vec<String>,
+    /// Copy of the database taken before migrating, if one was needed
+    pub backup: Option<PathBuf>,
+}
+
+/// Versions of every migration embedded in this build, oldest first
+pub fn known_versions() -> MigrationResult<Vec<String>> {
+    let mut versions: Vec<String> = MigrationSource::<Sqlite>::migrations(&MIGRATIONS)?
+        .iter()
+        .map(|migration| migration.name().version().to_string())
+        .collect();
+    versions.sort();
+    Ok(versions)
+}
+
+/// Newest schema version this build knows how to read and write
+pub fn supported_schema_version() -> MigrationResult<Option<String>> {
+    Ok(known_versions()?.pop())
+}
+
+pub(super) fn applied_versions(connection: &mut SqliteConnection) -> MigrationResult<Vec<String>> {
+    let mut versions: Vec<String> = connection
+        .applied_migrations()?
+        .iter()
+        .map(|version| version.to_string())
+        .collect();
+    versions.sort();
+    Ok(versions)
+}
+
+/// Refuse to touch a database that has migrations applied which this build
+/// doesn't know about - it was written by a newer metis.
+pub(super) fn ensure_not_newer(connection: &mut SqliteConnection) -> MigrationResult<()> {
+    let known = known_versions()?;
+    let applied = applied_versions(connection)?;
+
+    if let Some(unknown) = applied.iter().filter(|v| !known.contains(v)).max() {
+        return Err(Box::new(MetisError::SchemaTooNew {
+            database_version: unknown.clone(),
+            supported_version: known.last().cloned().unwrap_or_default(),
+        }));
+    }
+
+    Ok(())
+}
+
+/// Copy the database to `<path>.backup-<version>` using `VACUUM INTO`, which
+/// produces a consistent snapshot even in WAL mode
+fn backup_database(
+    connection: &mut SqliteConnection,
+    db_path: &Path,
+    version: &str,
+) -> MigrationResult<PathBuf> {
+    let mut backup_name = db_path.as_os_str().to_owned();
+    backup_name.push(format!(".backup-{}", version));
+    let backup_path = PathBuf::from(backup_name);
+
+    if backup_path.exists() {
+        std::fs::remove_file(&backup_path)?;
+    }
+
+    diesel::sql_query("VACUUM INTO ?")
+        .bind::<diesel::sql_types::Text, _>(backup_path.to_string_lossy().to_string())
+        .execute(connection)?;
+
+    Ok(backup_path)
+}
+
+/// Check the schema version and apply pending migrations on an open
+/// connection, backing up file databases that already hold data first
+pub(super) fn migrate_connection(
+    connection: &mut SqliteConnection,
+    connection_string: &str,
+) -> MigrationResult<MigrationOutcome> {
+    ensure_not_newer(connection)?;
+
+    let pending = connection.pending_migrations(MIGRATIONS)?;
+    if pending.is_empty() {
+        return Ok(MigrationOutcome::default());
+    }
+
+    let db_path = Path::new(connection_string);
+    let backup = match applied_versions(connection)?.last() {
+        Some(current) if db_path.is_file() => Some(backup_database(connection, db_path, current)?),
+        _ => None,
+    };
+
+    let applied = pending
+        .iter()
+        .map(|migration| migration.name().to_string())
+        .collect();
+    connection.run_pending_migrations(MIGRATIONS)?;
+
+    if let Some(path) = &backup {
+        tracing::info!(
+            "Migrated database; previous copy saved to {}",
+            path.display()
+        );
+    }
+
+    Ok(MigrationOutcome { applied, backup })
+}
+
+impl Database {
+    /// Version of the newest migration applied to this database
+    pub fn schema_version(&self) -> MigrationResult<Option<String>> {
+        let mut connection = self.get_connection()?;
+        Ok(applied_versions(&mut connection)?.pop())
+    }
+
+    /// Names of migrations embedded in this build that have not been applied
+    pub fn pending_migrations(&self) -> MigrationResult<Vec<String>> {
+        let mut connection = self.get_connection()?;
+        Ok(connection
+            .pending_migrations(MIGRATIONS)?
+            .iter()
+            .map(|migration| migration.name().to_string())
+            .collect())
+    }
+
+    /// Apply pending migrations, backing up the database file first
+    pub fn migrate(&self) -> MigrationResult<MigrationOutcome> {
+        let mut connection = self.get_connection()?;
+        migrate_connection(&mut connection, &self.connection_string)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_fresh_database_is_current() {
+        let temp_dir = tempdir().unwrap();
+        let db_path = temp_dir.path().join("metis.db");
+        let db = Database::new(db_path.to_str().unwrap()).unwrap();
+
+        assert_eq!(
+            db.schema_version().unwrap(),
+            supported_schema_version().unwrap()
+        );
+        assert!(db.pending_migrations().unwrap().is_empty());
+        assert_eq!(db.migrate().unwrap(), MigrationOutcome::default());
+    }
+
+    #[test]
+    fn test_migrate_backs_up_existing_database() {
+        let temp_dir = tempdir().unwrap();
+        let db_path = temp_dir.path().join("metis.db");
+        let db = Database::new(db_path.to_str().unwrap()).unwrap();
+
+        let mut connection = db.get_connection().unwrap();
+        connection.revert_last_migration(MIGRATIONS).unwrap();
+        drop(connection);
+        assert_eq!(db.pending_migrations().unwrap().len(), 1);
+
+        let previous = db.schema_version().unwrap().unwrap();
+        let outcome = db.migrate().unwrap();
+
+        assert_eq!(outcome.applied.len(), 1);
+        let backup = outcome.backup.expect("Expected a backup");
+        assert!(backup.exists());
+        assert!(backup
+            .to_string_lossy()
+            .ends_with(&format!(".backup-{}", previous)));
+        assert_eq!(
+            db.schema_version().unwrap(),
+            supported_schema_version().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_newer_database_is_rejected() {
+        let temp_dir = tempdir().unwrap();
+        let db_path = temp_dir.path().join("metis.db");
+        let db = Database::new(db_path.to_str().unwrap()).unwrap();
+
+        let mut connection = db.get_connection().unwrap();
+        diesel::sql_query("INSERT INTO __diesel_schema_migrations (version) VALUES ('999')")
+            .execute(&mut connection)
+            .unwrap();
+        drop(connection);
+
+        let err = Database::new(db_path.to_str().unwrap())
+            .err()
+            .expect("Expected newer schema to be rejected");
+        assert!(err.to_string().contains("newer than this version of metis"));
+    }
+}
@@ -0,0 +1,5 @@
+DROP TRIGGER IF EXISTS documents_edges_ad;
+DROP INDEX IF EXISTS idx_edges_origin;
+DROP INDEX IF EXISTS idx_edges_to_code;
+DROP INDEX IF EXISTS idx_edges_from_code;
+DROP TABLE IF EXISTS document_edges;
@@ -0,0 +1,30 @@
+-- First-class relationship edges between documents
+-- Edges are keyed by short code so they survive file moves and renames.
+-- origin_filepath is the document whose frontmatter declared the edge; it is
+-- used to drop stale edges when that document is deleted or re-imported.
+-- workspace is the workspace the edge was declared in, '' for the local one;
+-- projected sibling workspaces may reuse local short codes (e.g. the default
+-- PROJ prefix), so edges are keyed by workspace as well as by short code.
+CREATE TABLE document_edges (
+    from_code TEXT NOT NULL,
+    to_code TEXT NOT NULL,
+    kind TEXT NOT NULL,
+    origin_filepath TEXT NOT NULL,
+    workspace TEXT NOT NULL DEFAULT '',
+    PRIMARY KEY (workspace, from_code, to_code, kind)
+);
+
+CREATE INDEX idx_edges_from_code ON document_edges(workspace, from_code, kind);
+CREATE INDEX idx_edges_to_code ON document_edges(workspace, to_code, kind);
+CREATE INDEX idx_edges_origin ON document_edges(origin_filepath);
+
+-- Seed parent edges from the existing parent_id column
+INSERT OR IGNORE INTO document_edges (from_code, to_code, kind, origin_filepath)
+SELECT short_code, parent_id, 'parent', filepath
+FROM documents
+WHERE parent_id IS NOT NULL AND parent_id != '' AND parent_id != 'NULL';
+
+-- Keep edges in step with the documents that declare them
+CREATE TRIGGER documents_edges_ad AFTER DELETE ON documents BEGIN
+    DELETE FROM document_edges WHERE origin_filepath = old.filepath;
+END;
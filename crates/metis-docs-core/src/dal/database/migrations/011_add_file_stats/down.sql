@@ -0,0 +1,2 @@
+DROP TRIGGER IF EXISTS documents_file_stats_ad;
+DROP TABLE IF EXISTS file_stats;
@@ -0,0 +1,11 @@
+-- Filesystem stats recorded at import time so unchanged files can be
+-- skipped during sync without reading or hashing them.
+CREATE TABLE file_stats (
+    filepath TEXT PRIMARY KEY NOT NULL,
+    file_size BIGINT NOT NULL,
+    mtime DOUBLE NOT NULL
+);
+
+CREATE TRIGGER documents_file_stats_ad AFTER DELETE ON documents BEGIN
+    DELETE FROM file_stats WHERE filepath = old.filepath;
+END;
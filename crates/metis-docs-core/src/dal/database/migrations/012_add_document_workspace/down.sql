@@ -0,0 +1,3 @@
+DELETE FROM documents WHERE workspace != '';
+DROP INDEX IF EXISTS idx_documents_workspace;
+ALTER TABLE documents DROP COLUMN workspace;
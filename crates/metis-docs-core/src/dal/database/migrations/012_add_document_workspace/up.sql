@@ -0,0 +1,5 @@
+-- Workspace a document belongs to. '' is the local workspace; projected
+-- sibling workspaces use their own name and namespaced filepaths
+-- ('@<workspace>/<path>') so they never collide with local documents.
+ALTER TABLE documents ADD COLUMN workspace TEXT NOT NULL DEFAULT '';
+CREATE INDEX idx_documents_workspace ON documents(workspace, short_code);
@@ -0,0 +1,3 @@
+DROP TRIGGER IF EXISTS documents_properties_ad;
+DROP INDEX IF EXISTS idx_properties_key_value;
+DROP TABLE IF EXISTS document_properties;
@@ -0,0 +1,14 @@
+-- Custom frontmatter fields, one row per (document, key). Values are stored
+-- as text; typed comparisons cast at query time.
+CREATE TABLE document_properties (
+    document_filepath TEXT NOT NULL,
+    key TEXT NOT NULL,
+    value TEXT NOT NULL,
+    PRIMARY KEY (document_filepath, key)
+);
+
+CREATE INDEX idx_properties_key_value ON document_properties(key, value);
+
+CREATE TRIGGER documents_properties_ad AFTER DELETE ON documents BEGIN
+    DELETE FROM document_properties WHERE document_filepath = old.filepath;
+END;
@@ -0,0 +1 @@
+DROP TRIGGER IF EXISTS documents_tags_ad;
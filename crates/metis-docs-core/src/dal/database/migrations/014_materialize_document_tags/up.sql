@@ -0,0 +1,9 @@
+-- Tags are now written to document_tags when a document is stored.
+CREATE TRIGGER documents_tags_ad AFTER DELETE ON documents BEGIN
+    DELETE FROM document_tags WHERE document_filepath = old.filepath;
+END;
+
+-- Existing rows were stored before tags were materialized. Clear their
+-- hashes and file stats so the next sync re-imports them.
+DELETE FROM file_stats;
+UPDATE documents SET file_hash = '' WHERE workspace = '';
@@ -0,0 +1,10 @@
+-- Cached task completion per parent document (initiative or vision), counting
+-- every task below it in the parent hierarchy. Rebuilt after a sync changes
+-- any document.
+CREATE TABLE document_rollups (
+    short_code TEXT PRIMARY KEY NOT NULL,
+    total_tasks INTEGER NOT NULL,
+    completed_tasks INTEGER NOT NULL,
+    estimate_remaining DOUBLE NOT NULL,
+    refreshed_at DOUBLE NOT NULL
+);
@@ -0,0 +1,2 @@
+DROP INDEX IF EXISTS idx_phase_history_type;
+DROP TABLE IF EXISTS phase_history;
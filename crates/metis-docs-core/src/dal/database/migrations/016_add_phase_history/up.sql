@@ -0,0 +1,13 @@
+-- Phases each local document has entered, as observed by sync. Keyed by short
+-- code rather than filepath so history survives moves, re-imports and
+-- archiving. entered_at is the document's frontmatter updated_at, which phase
+-- transitions bump.
+CREATE TABLE phase_history (
+    short_code TEXT NOT NULL,
+    document_type TEXT NOT NULL,
+    phase TEXT NOT NULL,
+    entered_at DOUBLE NOT NULL,
+    PRIMARY KEY (short_code, entered_at, phase)
+);
+
+CREATE INDEX idx_phase_history_type ON phase_history(document_type, phase, entered_at);
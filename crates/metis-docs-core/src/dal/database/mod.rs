@@ -1,5 +1,12 @@
+pub mod analytics;
+pub mod backup;
 pub mod configuration_repository;
+#[cfg(feature = "encryption")]
+pub mod encryption;
+pub mod maintenance;
+pub mod migration;
 pub mod models;
+pub mod properties;
 pub mod repository;
 pub mod schema;
 
@@ -7,11 +14,67 @@ use diesel::prelude::*;
 use diesel::sqlite::SqliteConnection;
 use diesel_migrations::{embed_migrations, EmbeddedMigrations, MigrationHarness};
 
+pub use analytics::FlowReport;
+pub use maintenance::MaintenanceReport;
+pub use migration::MigrationOutcome;
+pub use properties::{
+    parse_duration, parse_when, DocumentFilter, DocumentGroup, DocumentPage, DocumentSort,
+    PropertyFilter,
+};
+
 pub const MIGRATIONS: EmbeddedMigrations = embed_migrations!("src/dal/database/migrations");
 
 /// Database connection and migration management
 pub struct Database {
     connection_string: String,
+    /// Database key, looked up once rather than on every connection
+    key: Option<String>,
+}
+
+/// Establish a raw connection to a workspace database.
+///
+/// Use this instead of `SqliteConnection::establish` so that, when built with
+/// the `encryption` feature and a key is configured, the database is unlocked.
+pub fn establish(
+    connection_string: &str,
+) -> Result<SqliteConnection, Box<dyn std::error::Error + Send + Sync>> {
+    establish_with_key(connection_string, resolve_key(connection_string).as_deref())
+}
+
+/// Key used to unlock a workspace database, if it is encrypted
+fn resolve_key(_connection_string: &str) -> Option<String> {
+    #[cfg(feature = "encryption")]
+    {
+        encryption::resolve_key(_connection_string)
+    }
+    #[cfg(not(feature = "encryption"))]
+    {
+        None
+    }
+}
+
+#[cfg_attr(not(feature = "encryption"), allow(unused_variables))]
+fn establish_with_key(
+    connection_string: &str,
+    key: Option<&str>,
+) -> Result<SqliteConnection, Box<dyn std::error::Error + Send + Sync>> {
+    #[allow(unused_mut)]
+    let mut connection = SqliteConnection::establish(connection_string)?;
+    #[cfg(feature = "encryption")]
+    if let Some(key) = key {
+        encryption::apply_key(&mut connection, connection_string, key)?;
+    }
+    Ok(connection)
+}
+
+/// Open a connection and apply the standard pragmas
+fn open_connection(
+    connection_string: &str,
+    key: Option<&str>,
+) -> Result<SqliteConnection, Box<dyn std::error::Error + Send + Sync>> {
+    let mut connection = establish_with_key(connection_string, key)?;
+    configure_connection(&mut connection)?;
+    Ok(connection)
 }
 
 /// Configure SQLite connection for better concurrency
@@ -38,13 +101,15 @@ impl Database {
     /// # Arguments
     /// * `connection_string` - SQLite connection string (e.g., ":memory:", "database.db", "file:database.db?mode=rw")
     pub fn new(connection_string: &str) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
-        // Run migrations once to ensure the database is set up
-        let mut connection = SqliteConnection::establish(connection_string)?;
-        configure_connection(&mut connection)?;
-        connection.run_pending_migrations(MIGRATIONS)?;
+        // Run migrations once to ensure the database is set up. Fails if the
+        // database was written by a newer metis with migrations we don't know.
+        let key = resolve_key(connection_string);
+        let mut connection = open_connection(connection_string, key.as_deref())?;
+        migration::migrate_connection(&mut connection, connection_string)?;
 
         Ok(Self {
             connection_string: connection_string.to_string(),
+            key,
         })
     }
 
@@ -52,8 +117,7 @@ impl Database {
     pub fn get_connection(
         &self,
     ) -> Result<SqliteConnection, Box<dyn std::error::Error + Send + Sync>> {
-        let mut connection = SqliteConnection::establish(&self.connection_string)?;
-        configure_connection(&mut connection)?;
+        let mut connection = open_connection(&self.connection_string, self.key.as_deref())?;
 
         // For in-memory databases, we need to run migrations on each connection
         // since each connection is a separate database
@@ -1,5 +1,6 @@
 use diesel::prelude::*;
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 
 #[derive(
     Queryable,
@@ -30,6 +31,34 @@ pub struct Document {
     pub initiative_id: Option<String>,
     pub short_code: String,
     pub parent_id: Option<String>,
+    #[serde(default)]
+    pub workspace: String,
+}
+
+impl Document {
+    /// True for documents projected from a sibling workspace
+    pub fn is_foreign(&self) -> bool {
+        self.workspace != LOCAL_WORKSPACE
+    }
+}
+
+/// A full-text search match with a snippet of the matching content
+#[derive(QueryableByName, Debug, Clone, Serialize, Deserialize)]
+pub struct SearchHit {
+    #[diesel(embed)]
+    pub document: Document,
+    /// Content around the match, matched terms wrapped in `**`
+    #[diesel(sql_type = diesel::sql_types::Text)]
+    pub snippet: String,
+}
+
+/// Workspace value for documents that live in this workspace
+pub const LOCAL_WORKSPACE: &str = "";
+
+/// Database filepath for a document projected from a sibling workspace.
+/// The `@<workspace>/` prefix keeps it from colliding with local paths.
+pub fn foreign_filepath(workspace: &str, path: &str) -> String {
+    format!("@{}/{}", workspace, path.trim_start_matches('/'))
 }
 
 #[derive(Queryable, Selectable, Insertable, Debug, Clone, Serialize, Deserialize)]
@@ -50,6 +79,269 @@ pub struct DocumentTag {
     pub tag: String,
 }
 
+/// A tag with the number of documents carrying it
+#[derive(QueryableByName, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TagCount {
+    #[diesel(sql_type = diesel::sql_types::Text)]
+    pub tag: String,
+    #[diesel(sql_type = diesel::sql_types::BigInt)]
+    pub count: i64,
+}
+
+/// A value of a grouping field with the number of documents that have it.
+/// `value` is empty for documents without one (e.g. no assignee).
+#[derive(QueryableByName, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GroupCount {
+    #[diesel(sql_type = diesel::sql_types::Text)]
+    pub value: String,
+    #[diesel(sql_type = diesel::sql_types::BigInt)]
+    pub count: i64,
+}
+
+#[derive(
+    Queryable,
+    Selectable,
+    Insertable,
+    QueryableByName,
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    Serialize,
+    Deserialize,
+)]
+#[diesel(table_name = crate::dal::database::schema::document_edges)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct DocumentEdge {
+    pub from_code: String,
+    pub to_code: String,
+    pub kind: String,
+    pub origin_filepath: String,
+    /// Workspace the edge was declared in; `""` for the local workspace
+    pub workspace: String,
+}
+
+/// A document as a node of the exported project graph
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GraphNode {
+    pub short_code: String,
+    pub title: String,
+    pub document_type: String,
+    pub phase: String,
+    pub archived: bool,
+}
+
+/// A relationship between two documents in the exported project graph
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GraphEdge {
+    pub from: String,
+    pub to: String,
+    /// parent, blocks, references or supersedes
+    pub kind: String,
+}
+
+/// The local document graph: every document and the edges between them
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DocumentGraph {
+    pub nodes: Vec<GraphNode>,
+    pub edges: Vec<GraphEdge>,
+}
+
+impl DocumentGraph {
+    /// Serialize as `{"nodes": [...], "edges": [...]}`
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// Render as a Graphviz digraph, edges pointing from child/blocker to
+    /// parent/blocked document
+    pub fn to_dot(&self) -> String {
+        let escape = |s: &str| s.replace('\\', "\\\\").replace('"', "\\\"");
+        let quote = |s: &str| format!("\"{}\"", escape(s));
+
+        let mut dot = String::from("digraph metis {\n    node [shape=box];\n");
+        for node in &self.nodes {
+            // `\n` inside a quoted DOT label is a line break
+            dot.push_str(&format!(
+                "    {} [label=\"{}\\n{}\\n[{} / {}]\"];\n",
+                quote(&node.short_code),
+                escape(&node.short_code),
+                escape(&node.title),
+                escape(&node.document_type),
+                escape(&node.phase)
+            ));
+        }
+        for edge in &self.edges {
+            dot.push_str(&format!(
+                "    {} -> {} [label={}];\n",
+                quote(&edge.from),
+                quote(&edge.to),
+                quote(&edge.kind)
+            ));
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Keep only the vision -> initiative -> task hierarchy: those documents
+    /// and their parent edges, plus blocking edges when `include_blockers`
+    pub fn hierarchy(self, include_blockers: bool) -> Self {
+        let nodes: Vec<GraphNode> = self
+            .nodes
+            .into_iter()
+            .filter(|node| {
+                matches!(
+                    node.document_type.as_str(),
+                    "vision" | "initiative" | "task"
+                )
+            })
+            .collect();
+        let codes: HashSet<&str> = nodes.iter().map(|node| node.short_code.as_str()).collect();
+        let edges = self
+            .edges
+            .into_iter()
+            .filter(|edge| edge.kind == "parent" || (include_blockers && edge.kind == "blocks"))
+            .filter(|edge| codes.contains(edge.from.as_str()) && codes.contains(edge.to.as_str()))
+            .collect();
+        Self { nodes, edges }
+    }
+
+    /// Keep only `root` and the documents below it through parent edges,
+    /// with the edges between them. `None` if `root` isn't in the graph.
+    pub fn subtree(self, root: &str) -> Option<Self> {
+        if !self.nodes.iter().any(|node| node.short_code == root) {
+            return None;
+        }
+        let mut keep: HashSet<String> = HashSet::from([root.to_string()]);
+        let mut pending = vec![root.to_string()];
+        while let Some(parent) = pending.pop() {
+            for edge in &self.edges {
+                if edge.kind == "parent" && edge.to == parent && keep.insert(edge.from.clone()) {
+                    pending.push(edge.from.clone());
+                }
+            }
+        }
+
+        let nodes = self
+            .nodes
+            .into_iter()
+            .filter(|node| keep.contains(&node.short_code))
+            .collect();
+        let edges = self
+            .edges
+            .into_iter()
+            .filter(|edge| keep.contains(&edge.from) && keep.contains(&edge.to))
+            .collect();
+        Some(Self { nodes, edges })
+    }
+
+    /// Render as a Mermaid flowchart, top-down from parents to children;
+    /// blocking edges are dashed, from blocker to blocked document
+    pub fn to_mermaid(&self) -> String {
+        // Mermaid ids can't contain dashes; labels use HTML entities for quotes
+        let id = |code: &str| code.replace('-', "_");
+        let escape = |s: &str| s.replace('"', "#quot;");
+
+        let mut mermaid = String::from("flowchart TD\n");
+        for node in &self.nodes {
+            mermaid.push_str(&format!(
+                "    {}[\"{}<br/>{}<br/>({})\"]\n",
+                id(&node.short_code),
+                node.short_code,
+                escape(&node.title),
+                escape(&node.phase)
+            ));
+        }
+        for edge in &self.edges {
+            let line = match edge.kind.as_str() {
+                // Parent edges run child -> parent; draw them downwards
+                "parent" => format!("    {} --> {}\n", id(&edge.to), id(&edge.from)),
+                "blocks" => format!("    {} -. blocks .-> {}\n", id(&edge.from), id(&edge.to)),
+                kind => format!("    {} -- {} --> {}\n", id(&edge.from), kind, id(&edge.to)),
+            };
+            mermaid.push_str(&line);
+        }
+        mermaid
+    }
+}
+
+/// Cached task completion for a parent document
+#[derive(Queryable, Selectable, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[diesel(table_name = crate::dal::database::schema::document_rollups)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct DocumentRollup {
+    pub short_code: String,
+    pub total_tasks: i32,
+    pub completed_tasks: i32,
+    /// Sum of the `estimate` property over tasks not yet completed
+    pub estimate_remaining: f64,
+    pub refreshed_at: f64,
+}
+
+impl DocumentRollup {
+    /// Fraction of tasks completed, from 0.0 to 1.0 (0.0 when there are none)
+    pub fn completion(&self) -> f64 {
+        if self.total_tasks == 0 {
+            return 0.0;
+        }
+        self.completed_tasks as f64 / self.total_tasks as f64
+    }
+}
+
+/// A phase a document entered, and when
+#[derive(Queryable, Selectable, Insertable, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[diesel(table_name = crate::dal::database::schema::phase_history)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct PhaseChange {
+    pub short_code: String,
+    pub document_type: String,
+    pub phase: String,
+    /// Unix timestamp
+    pub entered_at: f64,
+}
+
+#[derive(Queryable, Selectable, Insertable, Debug, Clone, PartialEq)]
+#[diesel(table_name = crate::dal::database::schema::file_stats)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct FileStat {
+    pub filepath: String,
+    pub file_size: i64,
+    pub mtime: f64,
+}
+
+#[derive(
+    Queryable, Selectable, Insertable, Debug, Clone, PartialEq, Eq, Serialize, Deserialize,
+)]
+#[diesel(table_name = crate::dal::database::schema::document_properties)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct DocumentProperty {
+    pub document_filepath: String,
+    pub key: String,
+    pub value: String,
+}
+
+impl DocumentProperty {
+    /// Value parsed as a number, if it is one
+    pub fn as_f64(&self) -> Option<f64> {
+        self.value.trim().parse().ok()
+    }
+
+    /// Value parsed as a date (`YYYY-MM-DD`, optionally followed by a time)
+    pub fn as_date(&self) -> Option<chrono::NaiveDate> {
+        let value = self.value.trim();
+        chrono::NaiveDate::parse_from_str(value.get(..10)?, "%Y-%m-%d").ok()
+    }
+
+    /// Value parsed as a boolean (`true`/`false`, `yes`/`no`, `1`/`0`)
+    pub fn as_bool(&self) -> Option<bool> {
+        match self.value.trim().to_lowercase().as_str() {
+            "true" | "yes" | "1" => Some(true),
+            "false" | "no" | "0" => Some(false),
+            _ => None,
+        }
+    }
+}
+
 // Insertable version for creating new documents
 #[derive(Insertable)]
 #[diesel(table_name = crate::dal::database::schema::documents)]
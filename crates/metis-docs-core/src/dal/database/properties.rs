@@ -0,0 +1,523 @@
+//! Typed filters over `document_properties`.
+//!
+//! Properties are stored as text; each filter casts at query time so custom
+//! frontmatter fields can be compared as numbers, dates or booleans. Sorting
+//! and pagination are applied in SQL as well, so listings stay cheap on large
+//! workspaces.
+//!
+//! [`DocumentFilter::parse`] reads the same filters from a compact query
+//! string such as `type:task phase:completed updated<90d`.
+
+use super::models::Document;
+use chrono::{DateTime, Duration, NaiveDate, TimeZone, Utc};
+use serde::Serialize;
+
+/// A condition on a single custom property
+#[derive(Debug, Clone, PartialEq)]
+pub enum PropertyFilter {
+    /// Property exists, whatever its value
+    Exists { key: String },
+    /// Exact text match
+    Equals { key: String, value: String },
+    /// Numeric value within an inclusive range; either bound may be open
+    NumberRange {
+        key: String,
+        min: Option<f64>,
+        max: Option<f64>,
+    },
+    /// Date value within an inclusive range; either bound may be open
+    DateRange {
+        key: String,
+        from: Option<NaiveDate>,
+        to: Option<NaiveDate>,
+    },
+    /// Boolean value (`true`/`yes`/`1` or `false`/`no`/`0`)
+    Bool { key: String, value: bool },
+}
+
+/// Field to order filtered documents by. Ties are broken by short code.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DocumentSort {
+    /// Flight level order (vision, specification, initiative, task, adr)
+    #[default]
+    Type,
+    UpdatedAt,
+    Title,
+    /// `due_date` property; documents without one sort last
+    DueDate,
+    /// `priority` property: critical, high, medium, low, or a number where
+    /// lower is more urgent; documents without one sort last
+    Priority,
+}
+
+impl std::str::FromStr for DocumentSort {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "type" => Ok(Self::Type),
+            "updated_at" | "updated" => Ok(Self::UpdatedAt),
+            "title" => Ok(Self::Title),
+            "due_date" | "due" => Ok(Self::DueDate),
+            "priority" => Ok(Self::Priority),
+            _ => Err(format!(
+                "Invalid sort field: {}. Use type, updated_at, title, due_date or priority",
+                s
+            )),
+        }
+    }
+}
+
+/// Field to count documents by
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DocumentGroup {
+    Type,
+    Phase,
+    /// Tags other than phase tags; a document counts once per tag
+    Tag,
+    /// `assignee` property
+    Assignee,
+}
+
+impl DocumentGroup {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Type => "type",
+            Self::Phase => "phase",
+            Self::Tag => "tag",
+            Self::Assignee => "assignee",
+        }
+    }
+}
+
+/// One page of filtered documents
+#[derive(Debug, Clone, Serialize)]
+pub struct DocumentPage {
+    pub documents: Vec<Document>,
+    /// Number of documents matching the filter across all pages
+    pub total: usize,
+    pub offset: usize,
+}
+
+impl DocumentPage {
+    /// True when more documents follow this page
+    pub fn has_more(&self) -> bool {
+        self.offset + self.documents.len() < self.total
+    }
+}
+
+/// Composite document filter: type, phase, tags and any number of property
+/// conditions, all of which must hold
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DocumentFilter {
+    pub document_type: Option<String>,
+    pub phase: Option<String>,
+    pub include_archived: bool,
+    /// Tags the document must carry, e.g. `#team/api`
+    pub tags: Vec<String>,
+    pub properties: Vec<PropertyFilter>,
+    /// Only documents last updated at or after this Unix timestamp
+    pub updated_after: Option<f64>,
+    /// Only documents last updated before this Unix timestamp
+    pub updated_before: Option<f64>,
+    pub sort: DocumentSort,
+    pub descending: bool,
+    pub limit: Option<usize>,
+    pub offset: usize,
+}
+
+impl DocumentFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn document_type(mut self, document_type: impl Into<String>) -> Self {
+        self.document_type = Some(document_type.into());
+        self
+    }
+
+    pub fn phase(mut self, phase: impl Into<String>) -> Self {
+        self.phase = Some(phase.into());
+        self
+    }
+
+    pub fn include_archived(mut self, include: bool) -> Self {
+        self.include_archived = include;
+        self
+    }
+
+    pub fn tag(mut self, tag: impl Into<String>) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
+
+    pub fn property(mut self, filter: PropertyFilter) -> Self {
+        self.properties.push(filter);
+        self
+    }
+
+    /// Restrict to documents last updated in `[after, before)`; either
+    /// bound may be open
+    pub fn updated_between(mut self, after: Option<f64>, before: Option<f64>) -> Self {
+        self.updated_after = after;
+        self.updated_before = before;
+        self
+    }
+
+    pub fn sort_by(mut self, sort: DocumentSort, descending: bool) -> Self {
+        self.sort = sort;
+        self.descending = descending;
+        self
+    }
+
+    pub fn page(mut self, limit: usize, offset: usize) -> Self {
+        self.limit = Some(limit);
+        self.offset = offset;
+        self
+    }
+
+    /// Parse a query string of space-separated terms, all of which must hold:
+    ///
+    /// - `type:<type>` and `phase:<phase>`
+    /// - `tag:<tag>` (the leading `#` is optional)
+    /// - `updated<<when>` / `updated><when>`, where `<when>` is a date
+    ///   (`2025-01-31`) or an age such as `90d` or `2w`; `updated<90d` means
+    ///   not updated in the last 90 days
+    /// - `<key>:<value>` for any other custom property
+    pub fn parse(query: &str) -> Result<Self, String> {
+        Self::parse_at(query, Utc::now())
+    }
+
+    /// [`DocumentFilter::parse`] with ages measured back from `now`
+    pub fn parse_at(query: &str, now: DateTime<Utc>) -> Result<Self, String> {
+        let mut filter = Self::new();
+
+        for term in query.split_whitespace() {
+            if let Some((field, op, value)) = split_comparison(term) {
+                if field != "updated" {
+                    return Err(format!(
+                        "Cannot compare '{}' with '{}'; only updated supports < and >",
+                        field, op
+                    ));
+                }
+                let timestamp = parse_when(value, now)?;
+                if op == '<' {
+                    filter.updated_before = Some(timestamp);
+                } else {
+                    filter.updated_after = Some(timestamp);
+                }
+                continue;
+            }
+
+            let (key, value) = term
+                .split_once(':')
+                .filter(|(key, value)| !key.is_empty() && !value.is_empty())
+                .ok_or_else(|| {
+                    format!(
+                        "Invalid filter term '{}'; expected key:value, updated<when or updated>when",
+                        term
+                    )
+                })?;
+            filter = match key.to_lowercase().as_str() {
+                "type" => filter.document_type(value.to_lowercase()),
+                "phase" => filter.phase(value.to_lowercase()),
+                "tag" => {
+                    let tag = if value.starts_with('#') {
+                        value.to_string()
+                    } else {
+                        format!("#{}", value)
+                    };
+                    filter.tag(tag)
+                }
+                _ => filter.property(PropertyFilter::Equals {
+                    key: key.to_string(),
+                    value: value.to_string(),
+                }),
+            };
+        }
+
+        Ok(filter)
+    }
+
+    /// Build the SQL for this filter. All parameters are bound as text, in
+    /// order, and cast inside the query.
+    pub(crate) fn to_sql(&self) -> (String, Vec<String>) {
+        let (mut sql, binds) = self.where_sql("SELECT d.*");
+        sql.push_str(&self.order_sql());
+        if let Some(limit) = self.limit {
+            sql.push_str(&format!(" LIMIT {} OFFSET {}", limit, self.offset));
+        } else if self.offset > 0 {
+            sql.push_str(&format!(" LIMIT -1 OFFSET {}", self.offset));
+        }
+        (sql, binds)
+    }
+
+    /// Build SQL counting every document that matches, ignoring pagination
+    pub(crate) fn to_count_sql(&self) -> (String, Vec<String>) {
+        self.where_sql("SELECT COUNT(*) AS count")
+    }
+
+    /// Build an FTS query over the matching documents, best match first.
+    /// Each row carries a `snippet` of the content with matched terms
+    /// wrapped in `**`. The query text is the first bind. Sorting is by
+    /// relevance; `limit` and `offset` still apply.
+    pub(crate) fn to_search_sql(&self, query: &str) -> (String, Vec<String>) {
+        let (conditions, mut filter_binds) = self.conditions_sql();
+        let mut sql = format!(
+            "SELECT d.*, snippet(document_search, 1, '**', '**', '...', 12) AS snippet \
+             FROM document_search \
+             INNER JOIN documents d ON d.filepath = document_search.document_filepath \
+             WHERE document_search MATCH ? AND d.workspace = ''{} \
+             ORDER BY document_search.rank, d.short_code",
+            conditions
+        );
+        if let Some(limit) = self.limit {
+            sql.push_str(&format!(" LIMIT {} OFFSET {}", limit, self.offset));
+        } else if self.offset > 0 {
+            sql.push_str(&format!(" LIMIT -1 OFFSET {}", self.offset));
+        }
+
+        let mut binds = vec![query.to_string()];
+        binds.append(&mut filter_binds);
+        (sql, binds)
+    }
+
+    fn order_sql(&self) -> String {
+        let property = |key: &str| {
+            format!(
+                "(SELECT p.value FROM document_properties p \
+                 WHERE p.document_filepath = d.filepath AND p.key = '{}')",
+                key
+            )
+        };
+        let direction = if self.descending { "DESC" } else { "ASC" };
+
+        let key = match self.sort {
+            DocumentSort::Type => "CASE d.document_type WHEN 'vision' THEN 0 \
+                 WHEN 'specification' THEN 1 WHEN 'initiative' THEN 2 \
+                 WHEN 'task' THEN 3 WHEN 'adr' THEN 4 ELSE 5 END"
+                .to_string(),
+            DocumentSort::UpdatedAt => "d.updated_at".to_string(),
+            DocumentSort::Title => "d.title COLLATE NOCASE".to_string(),
+            DocumentSort::DueDate => format!("date(substr(trim({}), 1, 10))", property("due_date")),
+            DocumentSort::Priority => {
+                let value = format!("lower(trim({}))", property("priority"));
+                format!(
+                    "CASE WHEN {value} = 'critical' THEN 0 WHEN {value} = 'high' THEN 1 \
+                     WHEN {value} = 'medium' THEN 2 WHEN {value} = 'low' THEN 3 \
+                     WHEN {numeric} THEN CAST({value} AS REAL) END",
+                    numeric = is_numeric(&value),
+                    value = value
+                )
+            }
+        };
+
+        // Missing values sort last whichever way the list is ordered
+        format!(
+            " ORDER BY ({key}) IS NULL, {key} {direction}, d.short_code",
+            key = key,
+            direction = direction
+        )
+    }
+
+    fn where_sql(&self, select: &str) -> (String, Vec<String>) {
+        let (conditions, binds) = self.conditions_sql();
+        (
+            format!(
+                "{} FROM documents d WHERE d.workspace = ''{}",
+                select, conditions
+            ),
+            binds,
+        )
+    }
+
+    /// Conditions on `d`, each starting with ` AND`
+    fn conditions_sql(&self) -> (String, Vec<String>) {
+        let mut sql = String::new();
+        let mut binds = Vec::new();
+
+        if !self.include_archived {
+            sql.push_str(" AND d.archived = 0");
+        }
+        if let Some(document_type) = &self.document_type {
+            sql.push_str(" AND d.document_type = ?");
+            binds.push(document_type.clone());
+        }
+        if let Some(phase) = &self.phase {
+            sql.push_str(" AND d.phase = ?");
+            binds.push(phase.clone());
+        }
+        if let Some(after) = self.updated_after {
+            sql.push_str(" AND d.updated_at >= CAST(? AS REAL)");
+            binds.push(after.to_string());
+        }
+        if let Some(before) = self.updated_before {
+            sql.push_str(" AND d.updated_at < CAST(? AS REAL)");
+            binds.push(before.to_string());
+        }
+        for tag in &self.tags {
+            sql.push_str(
+                " AND EXISTS (SELECT 1 FROM document_tags t \
+                 WHERE t.document_filepath = d.filepath AND t.tag = ?)",
+            );
+            binds.push(tag.clone());
+        }
+
+        for filter in &self.properties {
+            sql.push_str(
+                " AND EXISTS (SELECT 1 FROM document_properties p \
+                 WHERE p.document_filepath = d.filepath AND p.key = ?",
+            );
+            match filter {
+                PropertyFilter::Exists { key } => binds.push(key.clone()),
+                PropertyFilter::Equals { key, value } => {
+                    binds.push(key.clone());
+                    sql.push_str(" AND p.value = ?");
+                    binds.push(value.clone());
+                }
+                PropertyFilter::NumberRange { key, min, max } => {
+                    binds.push(key.clone());
+                    // Only values that are numbers; CAST turns text into 0
+                    sql.push_str(&format!(" AND {}", is_numeric("trim(p.value)")));
+                    if let Some(min) = min {
+                        sql.push_str(" AND CAST(p.value AS REAL) >= CAST(? AS REAL)");
+                        binds.push(min.to_string());
+                    }
+                    if let Some(max) = max {
+                        sql.push_str(" AND CAST(p.value AS REAL) <= CAST(? AS REAL)");
+                        binds.push(max.to_string());
+                    }
+                }
+                PropertyFilter::DateRange { key, from, to } => {
+                    binds.push(key.clone());
+                    sql.push_str(" AND date(substr(trim(p.value), 1, 10)) IS NOT NULL");
+                    if let Some(from) = from {
+                        sql.push_str(" AND date(substr(trim(p.value), 1, 10)) >= date(?)");
+                        binds.push(from.format("%Y-%m-%d").to_string());
+                    }
+                    if let Some(to) = to {
+                        sql.push_str(" AND date(substr(trim(p.value), 1, 10)) <= date(?)");
+                        binds.push(to.format("%Y-%m-%d").to_string());
+                    }
+                }
+                PropertyFilter::Bool { key, value } => {
+                    binds.push(key.clone());
+                    if *value {
+                        sql.push_str(" AND lower(trim(p.value)) IN ('true', 'yes', '1')");
+                    } else {
+                        sql.push_str(" AND lower(trim(p.value)) IN ('false', 'no', '0')");
+                    }
+                }
+            }
+            sql.push(')');
+        }
+
+        (sql, binds)
+    }
+}
+
+/// Split `field<value` or `field>value` into its parts
+fn split_comparison(term: &str) -> Option<(&str, char, &str)> {
+    let index = term.find(['<', '>'])?;
+    // `key:a>b` is a property value that happens to contain `>`
+    if term[..index].contains(':') {
+        return None;
+    }
+    let op = term[index..].chars().next()?;
+    Some((&term[..index], op, &term[index + 1..]))
+}
+
+/// Unix timestamp for a date (`YYYY-MM-DD`, midnight UTC) or an age before
+/// `now` (`30d` days, `2w` weeks)
+pub fn parse_when(value: &str, now: DateTime<Utc>) -> Result<f64, String> {
+    if let Ok(date) = NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+        let midnight = date.and_hms_opt(0, 0, 0).expect("midnight is valid");
+        return Ok(Utc.from_utc_datetime(&midnight).timestamp() as f64);
+    }
+
+    let age = parse_duration(value).map_err(|_| {
+        format!(
+            "Invalid date or age '{}'; use YYYY-MM-DD or e.g. 90d, 2w",
+            value
+        )
+    })?;
+    Ok((now - age).timestamp() as f64)
+}
+
+/// SQL condition that holds when `value` is a plain decimal number, like
+/// `-4` or `2.5`. `GLOB '[0-9]*'` alone would let `5abc` through, which CAST
+/// reads as 5.
+pub(crate) fn is_numeric(value: &str) -> String {
+    format!(
+        "({value} GLOB '*[0-9]*' AND {value} NOT GLOB '*[^0-9.+-]*' \
+         AND substr({value}, 2) NOT GLOB '*[+-]*' AND {value} NOT GLOB '*.*.*')",
+        value = value
+    )
+}
+
+/// A length of time in days (`7d`) or weeks (`2w`)
+pub fn parse_duration(value: &str) -> Result<Duration, String> {
+    let invalid = || format!("Invalid duration '{}'; use e.g. 7d or 2w", value);
+    let unit = value.chars().last().ok_or_else(invalid)?;
+    let count: i64 = value[..value.len() - unit.len_utf8()]
+        .parse()
+        .map_err(|_| invalid())?;
+    match unit {
+        'd' => Ok(Duration::days(count)),
+        'w' => Ok(Duration::weeks(count)),
+        _ => Err(invalid()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_query() {
+        let now = Utc.with_ymd_and_hms(2025, 4, 1, 0, 0, 0).unwrap();
+        let filter =
+            DocumentFilter::parse_at("type:Task phase:completed tag:team/api updated<90d", now)
+                .unwrap();
+
+        assert_eq!(filter.document_type.as_deref(), Some("task"));
+        assert_eq!(filter.phase.as_deref(), Some("completed"));
+        assert_eq!(filter.tags, vec!["#team/api".to_string()]);
+        let cutoff = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        assert_eq!(filter.updated_before, Some(cutoff.timestamp() as f64));
+        assert_eq!(filter.updated_after, None);
+    }
+
+    #[test]
+    fn test_parse_query_properties_and_dates() {
+        let filter = DocumentFilter::parse("owner:alice updated>2025-02-01").unwrap();
+
+        assert_eq!(
+            filter.properties,
+            vec![PropertyFilter::Equals {
+                key: "owner".to_string(),
+                value: "alice".to_string()
+            }]
+        );
+        let feb = Utc.with_ymd_and_hms(2025, 2, 1, 0, 0, 0).unwrap();
+        assert_eq!(filter.updated_after, Some(feb.timestamp() as f64));
+    }
+
+    #[test]
+    fn test_parse_query_errors() {
+        assert!(DocumentFilter::parse("completed").is_err());
+        assert!(DocumentFilter::parse("estimate>3").is_err());
+        assert!(DocumentFilter::parse("updated<soon").is_err());
+        assert!(DocumentFilter::parse("updated<5m").is_err());
+        assert_eq!(DocumentFilter::parse("").unwrap(), DocumentFilter::new());
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("7d").unwrap(), Duration::days(7));
+        assert_eq!(parse_duration("2w").unwrap(), Duration::days(14));
+        assert!(parse_duration("7").is_err());
+        assert!(parse_duration("1m").is_err());
+        assert!(parse_duration("").is_err());
+    }
+}
@@ -1,10 +1,18 @@
+use crate::dal::database::analytics::FlowReport;
 use crate::dal::database::configuration_repository::ConfigurationRepository;
 use crate::dal::database::models::*;
+use crate::dal::database::properties::{is_numeric, DocumentFilter, DocumentGroup, DocumentPage};
 use crate::dal::database::schema;
 use crate::{MetisError, Result};
 use diesel::prelude::*;
 use diesel::sqlite::SqliteConnection;
 
+#[derive(QueryableByName)]
+struct CountRow {
+    #[diesel(sql_type = diesel::sql_types::BigInt)]
+    count: i64,
+}
+
 /// Data access repository for document operations
 pub struct DocumentRepository {
     connection: SqliteConnection,
@@ -42,6 +50,7 @@ impl DocumentRepository {
         use schema::documents::dsl::*;
 
         documents
+            .filter(workspace.eq(LOCAL_WORKSPACE))
             .filter(id.eq(document_id))
             .first(&mut self.connection)
             .optional()
@@ -79,6 +88,7 @@ impl DocumentRepository {
 
         documents
             .inner_join(document_relationships.on(id.eq(child_id)))
+            .filter(workspace.eq(LOCAL_WORKSPACE))
             .filter(rel_parent_id.eq(parent_document_id))
             .select(Document::as_select())
             .load(&mut self.connection)
@@ -94,6 +104,7 @@ impl DocumentRepository {
 
         documents
             .inner_join(document_relationships.on(id.eq(rel_parent_id)))
+            .filter(workspace.eq(LOCAL_WORKSPACE))
             .filter(child_id.eq(child_document_id))
             .select(Document::as_select())
             .first(&mut self.connection)
@@ -120,7 +131,7 @@ impl DocumentRepository {
             "
             SELECT d.* FROM documents d
             INNER JOIN document_search ds ON d.filepath = ds.document_filepath
-            WHERE document_search MATCH ?
+            WHERE document_search MATCH ? AND d.workspace = ''
         ",
         )
         .bind::<diesel::sql_types::Text, _>(query)
@@ -135,7 +146,7 @@ impl DocumentRepository {
             "
             SELECT d.* FROM documents d
             INNER JOIN document_search ds ON d.filepath = ds.document_filepath
-            WHERE document_search MATCH ? AND d.archived = 0
+            WHERE document_search MATCH ? AND d.archived = 0 AND d.workspace = ''
         ",
         )
         .bind::<diesel::sql_types::Text, _>(query)
@@ -148,6 +159,7 @@ impl DocumentRepository {
         use schema::documents::dsl::*;
 
         documents
+            .filter(workspace.eq(LOCAL_WORKSPACE))
             .filter(document_type.eq(doc_type))
             .order(updated_at.desc())
             .load(&mut self.connection)
@@ -159,6 +171,7 @@ impl DocumentRepository {
         use schema::documents::dsl::*;
 
         documents
+            .filter(workspace.eq(LOCAL_WORKSPACE))
             .filter(document_type.eq(doc_type))
             .filter(archived.eq(false))
             .order(updated_at.desc())
@@ -173,7 +186,9 @@ impl DocumentRepository {
 
         documents
             .inner_join(document_tags.on(filepath.eq(document_filepath)))
+            .filter(workspace.eq(LOCAL_WORKSPACE))
             .filter(tag.eq(tag_name))
+            .order((document_type.asc(), short_code.asc()))
             .select(Document::as_select())
             .load(&mut self.connection)
             .map_err(MetisError::Database)
@@ -184,6 +199,7 @@ impl DocumentRepository {
         use schema::documents::dsl::*;
 
         documents
+            .filter(workspace.eq(LOCAL_WORKSPACE))
             .filter(phase.eq(phase_name))
             .order(updated_at.desc())
             .load(&mut self.connection)
@@ -199,6 +215,7 @@ impl DocumentRepository {
         use schema::documents::dsl::*;
 
         documents
+            .filter(workspace.eq(LOCAL_WORKSPACE))
             .filter(document_type.eq(doc_type))
             .filter(phase.eq(phase_name))
             .order(updated_at.desc())
@@ -211,6 +228,7 @@ impl DocumentRepository {
         use schema::documents::dsl::*;
 
         documents
+            .filter(workspace.eq(LOCAL_WORKSPACE))
             .filter(initiative_id.eq(initiative_document_id))
             .order(updated_at.desc())
             .load(&mut self.connection)
@@ -228,6 +246,109 @@ impl DocumentRepository {
             .map_err(MetisError::Database)
     }
 
+    /// Replace the tags recorded for a document
+    pub fn replace_tags(&mut self, file_path: &str, tags: &[String]) -> Result<()> {
+        use schema::document_tags::dsl::*;
+
+        self.connection
+            .transaction::<_, diesel::result::Error, _>(|conn| {
+                diesel::delete(document_tags.filter(document_filepath.eq(file_path)))
+                    .execute(conn)?;
+                for tag_name in tags {
+                    diesel::insert_or_ignore_into(document_tags)
+                        .values((document_filepath.eq(file_path), tag.eq(tag_name)))
+                        .execute(conn)?;
+                }
+                Ok(())
+            })
+            .map_err(MetisError::Database)
+    }
+
+    /// Number of unarchived local documents carrying each tag, most used first
+    pub fn tag_counts(&mut self) -> Result<Vec<TagCount>> {
+        diesel::sql_query(
+            "
+            SELECT t.tag AS tag, COUNT(*) AS count
+            FROM document_tags t
+            INNER JOIN documents d ON d.filepath = t.document_filepath
+            WHERE d.workspace = '' AND d.archived = 0
+            GROUP BY t.tag
+            ORDER BY count DESC, t.tag
+        ",
+        )
+        .load::<TagCount>(&mut self.connection)
+        .map_err(MetisError::Database)
+    }
+
+    /// Number of unarchived local documents per value of `group`, most
+    /// common first
+    pub fn count_by(&mut self, group: DocumentGroup) -> Result<Vec<GroupCount>> {
+        let query = match group {
+            DocumentGroup::Type => {
+                "
+                SELECT d.document_type AS value, COUNT(*) AS count
+                FROM documents d
+                WHERE d.workspace = '' AND d.archived = 0
+                GROUP BY d.document_type
+                ORDER BY count DESC, value
+            "
+            }
+            DocumentGroup::Phase => {
+                "
+                SELECT d.phase AS value, COUNT(*) AS count
+                FROM documents d
+                WHERE d.workspace = '' AND d.archived = 0
+                GROUP BY d.phase
+                ORDER BY count DESC, value
+            "
+            }
+            DocumentGroup::Tag => {
+                "
+                SELECT t.tag AS value, COUNT(*) AS count
+                FROM document_tags t
+                INNER JOIN documents d ON d.filepath = t.document_filepath
+                WHERE d.workspace = '' AND d.archived = 0 AND t.tag NOT LIKE '#phase/%'
+                GROUP BY t.tag
+                ORDER BY count DESC, value
+            "
+            }
+            DocumentGroup::Assignee => {
+                "
+                SELECT COALESCE(trim(p.value), '') AS value, COUNT(*) AS count
+                FROM documents d
+                LEFT JOIN document_properties p
+                    ON p.document_filepath = d.filepath AND p.key = 'assignee'
+                WHERE d.workspace = '' AND d.archived = 0
+                GROUP BY 1
+                ORDER BY count DESC, value
+            "
+            }
+        };
+        diesel::sql_query(query)
+            .load::<GroupCount>(&mut self.connection)
+            .map_err(MetisError::Database)
+    }
+
+    /// Tags that appear alongside `tag_name` on unarchived local documents,
+    /// with the number of documents they share, most frequent first
+    pub fn co_occurring_tags(&mut self, tag_name: &str) -> Result<Vec<TagCount>> {
+        diesel::sql_query(
+            "
+            SELECT other.tag AS tag, COUNT(*) AS count
+            FROM document_tags t
+            INNER JOIN document_tags other
+                ON other.document_filepath = t.document_filepath AND other.tag != t.tag
+            INNER JOIN documents d ON d.filepath = t.document_filepath
+            WHERE t.tag = ? AND d.workspace = '' AND d.archived = 0
+            GROUP BY other.tag
+            ORDER BY count DESC, other.tag
+        ",
+        )
+        .bind::<diesel::sql_types::Text, _>(tag_name)
+        .load::<TagCount>(&mut self.connection)
+        .map_err(MetisError::Database)
+    }
+
     /// Get all documents in an initiative hierarchy (initiative + its tasks)
     pub fn find_initiative_hierarchy(
         &mut self,
@@ -236,6 +357,7 @@ impl DocumentRepository {
         use schema::documents::dsl::*;
 
         documents
+            .filter(workspace.eq(LOCAL_WORKSPACE))
             .filter(
                 id.eq(initiative_document_id)
                     .or(initiative_id.eq(initiative_document_id)),
@@ -253,6 +375,7 @@ impl DocumentRepository {
         use schema::documents::dsl::*;
 
         documents
+            .filter(workspace.eq(LOCAL_WORKSPACE))
             .filter(
                 short_code
                     .eq(initiative_short_code)
@@ -266,7 +389,7 @@ impl DocumentRepository {
     /// Generate a short code for a document type using the database configuration
     pub fn generate_short_code(&mut self, doc_type: &str, db_path: &str) -> Result<String> {
         let mut config_repo =
-            ConfigurationRepository::new(SqliteConnection::establish(db_path).map_err(|e| {
+            ConfigurationRepository::new(super::establish(db_path).map_err(|e| {
                 MetisError::ConfigurationError(
                     crate::domain::configuration::ConfigurationError::InvalidValue(e.to_string()),
                 )
@@ -280,6 +403,7 @@ impl DocumentRepository {
         use schema::documents::dsl::*;
 
         documents
+            .filter(workspace.eq(LOCAL_WORKSPACE))
             .filter(short_code.eq(code))
             .first(&mut self.connection)
             .optional()
@@ -307,6 +431,597 @@ impl DocumentRepository {
             ))),
         }
     }
+
+    /// Replace all relationship edges declared by the document at `origin`
+    pub fn replace_edges_for_origin(&mut self, origin: &str, edges: &[DocumentEdge]) -> Result<()> {
+        use schema::document_edges::dsl::*;
+
+        self.connection
+            .transaction::<_, diesel::result::Error, _>(|conn| {
+                diesel::delete(document_edges.filter(origin_filepath.eq(origin))).execute(conn)?;
+                for edge in edges {
+                    diesel::insert_or_ignore_into(document_edges)
+                        .values(edge)
+                        .execute(conn)?;
+                }
+                Ok(())
+            })
+            .map_err(MetisError::Database)
+    }
+
+    /// Create a single relationship edge, ignoring duplicates
+    pub fn create_edge(&mut self, edge: DocumentEdge) -> Result<()> {
+        use schema::document_edges::dsl::*;
+
+        diesel::insert_or_ignore_into(document_edges)
+            .values(&edge)
+            .execute(&mut self.connection)
+            .map_err(MetisError::Database)?;
+
+        Ok(())
+    }
+
+    /// Get all local edges originating at a short code
+    pub fn find_edges_from(&mut self, code: &str) -> Result<Vec<DocumentEdge>> {
+        use schema::document_edges::dsl::*;
+
+        document_edges
+            .filter(workspace.eq(LOCAL_WORKSPACE))
+            .filter(from_code.eq(code))
+            .order((kind.asc(), to_code.asc()))
+            .load(&mut self.connection)
+            .map_err(MetisError::Database)
+    }
+
+    /// Export the local document graph. Edges whose endpoints aren't both in
+    /// the exported set (e.g. pointing at archived or missing documents)
+    /// are left out.
+    pub fn export_graph(&mut self, include_archived: bool) -> Result<DocumentGraph> {
+        let docs = {
+            use schema::documents::dsl::*;
+
+            let mut query = documents
+                .filter(workspace.eq(LOCAL_WORKSPACE))
+                .order((document_type.asc(), short_code.asc()))
+                .into_boxed();
+            if !include_archived {
+                query = query.filter(archived.eq(false));
+            }
+            query
+                .load::<Document>(&mut self.connection)
+                .map_err(MetisError::Database)?
+        };
+
+        let edges = {
+            use schema::document_edges::dsl::*;
+
+            document_edges
+                .filter(workspace.eq(LOCAL_WORKSPACE))
+                .order((from_code.asc(), kind.asc(), to_code.asc()))
+                .load::<DocumentEdge>(&mut self.connection)
+                .map_err(MetisError::Database)?
+        };
+
+        let codes: std::collections::HashSet<&str> =
+            docs.iter().map(|doc| doc.short_code.as_str()).collect();
+        let edges = edges
+            .into_iter()
+            .filter(|edge| {
+                codes.contains(edge.from_code.as_str()) && codes.contains(edge.to_code.as_str())
+            })
+            .map(|edge| GraphEdge {
+                from: edge.from_code,
+                to: edge.to_code,
+                kind: edge.kind,
+            })
+            .collect();
+
+        let nodes = docs
+            .into_iter()
+            .map(|doc| GraphNode {
+                short_code: doc.short_code,
+                title: doc.title,
+                document_type: doc.document_type,
+                phase: doc.phase,
+                archived: doc.archived,
+            })
+            .collect();
+
+        Ok(DocumentGraph { nodes, edges })
+    }
+
+    /// Get all local edges pointing at a short code
+    pub fn find_edges_to(&mut self, code: &str) -> Result<Vec<DocumentEdge>> {
+        use schema::document_edges::dsl::*;
+
+        document_edges
+            .filter(workspace.eq(LOCAL_WORKSPACE))
+            .filter(to_code.eq(code))
+            .order((kind.asc(), from_code.asc()))
+            .load(&mut self.connection)
+            .map_err(MetisError::Database)
+    }
+
+    /// Get every document below a short code in the parent hierarchy
+    pub fn find_descendants(&mut self, code: &str) -> Result<Vec<Document>> {
+        self.load_graph_query(
+            "
+            WITH RECURSIVE reached(code) AS (
+                SELECT from_code FROM document_edges
+                WHERE workspace = '' AND kind = 'parent' AND to_code = ?
+                UNION
+                SELECT e.from_code FROM document_edges e
+                INNER JOIN reached r ON e.to_code = r.code
+                WHERE e.workspace = '' AND e.kind = 'parent'
+            )
+            ",
+            code,
+        )
+    }
+
+    /// Get every document above a short code in the parent hierarchy
+    pub fn find_ancestors(&mut self, code: &str) -> Result<Vec<Document>> {
+        self.load_graph_query(
+            "
+            WITH RECURSIVE reached(code) AS (
+                SELECT to_code FROM document_edges
+                WHERE workspace = '' AND kind = 'parent' AND from_code = ?
+                UNION
+                SELECT e.to_code FROM document_edges e
+                INNER JOIN reached r ON e.from_code = r.code
+                WHERE e.workspace = '' AND e.kind = 'parent'
+            )
+            ",
+            code,
+        )
+    }
+
+    /// Get every document that directly or indirectly blocks a short code
+    pub fn find_transitive_blockers(&mut self, code: &str) -> Result<Vec<Document>> {
+        self.load_graph_query(
+            "
+            WITH RECURSIVE reached(code) AS (
+                SELECT from_code FROM document_edges
+                WHERE workspace = '' AND kind = 'blocks' AND to_code = ?
+                UNION
+                SELECT e.from_code FROM document_edges e
+                INNER JOIN reached r ON e.to_code = r.code
+                WHERE e.workspace = '' AND e.kind = 'blocks'
+            )
+            ",
+            code,
+        )
+    }
+
+    /// Get every document affected by a change to a short code: its
+    /// descendants plus everything it (transitively) blocks
+    pub fn find_impact(&mut self, code: &str) -> Result<Vec<Document>> {
+        self.load_graph_query(
+            "
+            WITH RECURSIVE reached(code) AS (
+                SELECT ?
+                UNION
+                SELECT CASE WHEN e.kind = 'parent' THEN e.from_code ELSE e.to_code END
+                FROM document_edges e
+                INNER JOIN reached r
+                    ON (e.kind = 'parent' AND e.to_code = r.code)
+                    OR (e.kind = 'blocks' AND e.from_code = r.code)
+                WHERE e.workspace = ''
+            )
+            ",
+            code,
+        )
+    }
+
+    /// Record the filesystem stats a document was imported from
+    pub fn upsert_file_stat(&mut self, stat: FileStat) -> Result<()> {
+        use schema::file_stats::dsl::*;
+
+        diesel::replace_into(file_stats)
+            .values(&stat)
+            .execute(&mut self.connection)
+            .map_err(MetisError::Database)?;
+
+        Ok(())
+    }
+
+    /// Get the recorded filesystem stats for a document
+    pub fn find_file_stat(&mut self, file_path: &str) -> Result<Option<FileStat>> {
+        use schema::file_stats::dsl::*;
+
+        file_stats
+            .filter(filepath.eq(file_path))
+            .first(&mut self.connection)
+            .optional()
+            .map_err(MetisError::Database)
+    }
+
+    /// Replace every document projected from the sibling workspace `name`.
+    ///
+    /// Filepaths (and edge origins) are namespaced with [`foreign_filepath`]
+    /// so projected documents never collide with local ones.
+    pub fn replace_workspace_documents(
+        &mut self,
+        name: &str,
+        docs: &[Document],
+        edges: &[DocumentEdge],
+    ) -> Result<usize> {
+        if name == LOCAL_WORKSPACE {
+            return Err(MetisError::ValidationFailed {
+                message: "Projected documents need a workspace name".to_string(),
+            });
+        }
+
+        let prefix = foreign_filepath(name, "");
+        let namespaced = |path: &str| {
+            if path.starts_with(&prefix) {
+                path.to_string()
+            } else {
+                foreign_filepath(name, path)
+            }
+        };
+
+        self.connection
+            .transaction::<_, diesel::result::Error, _>(|conn| {
+                use schema::document_edges::dsl as e;
+                use schema::documents::dsl as d;
+
+                diesel::delete(d::documents.filter(d::workspace.eq(name))).execute(conn)?;
+
+                for doc in docs {
+                    let doc = Document {
+                        filepath: namespaced(&doc.filepath),
+                        workspace: name.to_string(),
+                        ..doc.clone()
+                    };
+                    diesel::insert_into(d::documents)
+                        .values(&doc)
+                        .execute(conn)?;
+                }
+
+                for edge in edges {
+                    let edge = DocumentEdge {
+                        origin_filepath: namespaced(&edge.origin_filepath),
+                        workspace: name.to_string(),
+                        ..edge.clone()
+                    };
+                    diesel::insert_or_ignore_into(e::document_edges)
+                        .values(&edge)
+                        .execute(conn)?;
+                }
+
+                Ok(docs.len())
+            })
+            .map_err(MetisError::Database)
+    }
+
+    /// Names of all sibling workspaces with projected documents
+    pub fn list_foreign_workspaces(&mut self) -> Result<Vec<String>> {
+        use schema::documents::dsl::*;
+
+        documents
+            .filter(workspace.ne(LOCAL_WORKSPACE))
+            .select(workspace)
+            .distinct()
+            .order(workspace.asc())
+            .load(&mut self.connection)
+            .map_err(MetisError::Database)
+    }
+
+    /// Get all documents belonging to a workspace
+    pub fn find_by_workspace(&mut self, name: &str) -> Result<Vec<Document>> {
+        use schema::documents::dsl::*;
+
+        documents
+            .filter(workspace.eq(name))
+            .order((document_type.asc(), short_code.asc()))
+            .load(&mut self.connection)
+            .map_err(MetisError::Database)
+    }
+
+    /// Find a document by short code within a specific workspace
+    pub fn find_by_short_code_in_workspace(
+        &mut self,
+        name: &str,
+        code: &str,
+    ) -> Result<Option<Document>> {
+        use schema::documents::dsl::*;
+
+        documents
+            .filter(workspace.eq(name))
+            .filter(short_code.eq(code))
+            .first(&mut self.connection)
+            .optional()
+            .map_err(MetisError::Database)
+    }
+
+    /// Search documents in a specific workspace using FTS
+    pub fn search_documents_in_workspace(
+        &mut self,
+        name: &str,
+        query: &str,
+    ) -> Result<Vec<Document>> {
+        diesel::sql_query(
+            "
+            SELECT d.* FROM documents d
+            INNER JOIN document_search ds ON d.filepath = ds.document_filepath
+            WHERE document_search MATCH ? AND d.workspace = ?
+        ",
+        )
+        .bind::<diesel::sql_types::Text, _>(query)
+        .bind::<diesel::sql_types::Text, _>(name)
+        .load::<Document>(&mut self.connection)
+        .map_err(MetisError::Database)
+    }
+
+    /// Get projected documents from other workspaces that block local documents
+    pub fn find_foreign_blockers(&mut self) -> Result<Vec<Document>> {
+        diesel::sql_query(
+            "
+            SELECT DISTINCT d.* FROM documents d
+            INNER JOIN document_edges e ON e.from_code = d.short_code AND e.kind = 'blocks'
+            INNER JOIN documents local ON local.short_code = e.to_code AND local.workspace = ''
+            WHERE d.workspace != ''
+            AND (
+                -- Declared in the blocker's workspace, naming a document it doesn't have
+                (e.workspace = d.workspace AND NOT EXISTS (
+                    SELECT 1 FROM documents o
+                    WHERE o.workspace = d.workspace AND o.short_code = e.to_code))
+                -- Declared locally, naming a blocker that isn't local
+                OR (e.workspace = '' AND NOT EXISTS (
+                    SELECT 1 FROM documents o
+                    WHERE o.workspace = '' AND o.short_code = e.from_code))
+            )
+            ORDER BY d.workspace, d.short_code
+        ",
+        )
+        .load::<Document>(&mut self.connection)
+        .map_err(MetisError::Database)
+    }
+
+    /// Local edges with an endpoint that matches no document, e.g. a parent
+    /// or blocker that was deleted or mistyped
+    pub fn find_dangling_edges(&mut self) -> Result<Vec<DocumentEdge>> {
+        diesel::sql_query(
+            "
+            SELECT e.* FROM document_edges e
+            WHERE e.workspace = ''
+            AND (NOT EXISTS (SELECT 1 FROM documents d WHERE d.short_code = e.from_code)
+                OR NOT EXISTS (SELECT 1 FROM documents d WHERE d.short_code = e.to_code))
+            ORDER BY e.origin_filepath, e.kind, e.from_code, e.to_code
+        ",
+        )
+        .load::<DocumentEdge>(&mut self.connection)
+        .map_err(MetisError::Database)
+    }
+
+    /// Replace the custom properties recorded for a document
+    pub fn replace_properties(
+        &mut self,
+        file_path: &str,
+        properties: &[DocumentProperty],
+    ) -> Result<()> {
+        use schema::document_properties::dsl::*;
+
+        self.connection
+            .transaction::<_, diesel::result::Error, _>(|conn| {
+                diesel::delete(document_properties.filter(document_filepath.eq(file_path)))
+                    .execute(conn)?;
+                for property in properties {
+                    diesel::insert_into(document_properties)
+                        .values(property)
+                        .execute(conn)?;
+                }
+                Ok(())
+            })
+            .map_err(MetisError::Database)
+    }
+
+    /// Get the custom properties recorded for a document
+    pub fn find_properties(&mut self, file_path: &str) -> Result<Vec<DocumentProperty>> {
+        use schema::document_properties::dsl::*;
+
+        document_properties
+            .filter(document_filepath.eq(file_path))
+            .order(key.asc())
+            .load(&mut self.connection)
+            .map_err(MetisError::Database)
+    }
+
+    /// Get local documents matching a composite type/phase/property filter
+    pub fn find_by_filter(&mut self, filter: &DocumentFilter) -> Result<Vec<Document>> {
+        let (sql, binds) = filter.to_sql();
+
+        let mut query = diesel::sql_query(sql).into_boxed::<diesel::sqlite::Sqlite>();
+        for value in binds {
+            query = query.bind::<diesel::sql_types::Text, _>(value);
+        }
+
+        query
+            .load::<Document>(&mut self.connection)
+            .map_err(MetisError::Database)
+    }
+
+    /// Full-text search restricted by `filter`, best match first, with a
+    /// highlighted snippet for each hit
+    pub fn search_filtered(
+        &mut self,
+        query: &str,
+        filter: &DocumentFilter,
+    ) -> Result<Vec<SearchHit>> {
+        let (sql, binds) = filter.to_search_sql(query);
+
+        let mut search = diesel::sql_query(sql).into_boxed::<diesel::sqlite::Sqlite>();
+        for value in binds {
+            search = search.bind::<diesel::sql_types::Text, _>(value);
+        }
+
+        search
+            .load::<SearchHit>(&mut self.connection)
+            .map_err(MetisError::Database)
+    }
+
+    /// Recompute every completion rollup from the current documents.
+    ///
+    /// Each parent collects all unarchived local tasks below it through
+    /// parent edges, so a vision counts the tasks of all its initiatives.
+    pub fn refresh_rollups(&mut self) -> Result<usize> {
+        let now = chrono::Utc::now().timestamp() as f64;
+
+        self.connection
+            .transaction::<_, diesel::result::Error, _>(|conn| {
+                diesel::sql_query("DELETE FROM document_rollups").execute(conn)?;
+                diesel::sql_query(format!(
+                    "
+                    WITH RECURSIVE tree(root, code) AS (
+                        SELECT to_code, from_code FROM document_edges
+                        WHERE kind = 'parent' AND workspace = ''
+                        UNION
+                        SELECT tree.root, e.from_code FROM tree
+                        INNER JOIN document_edges e
+                            ON e.to_code = tree.code AND e.kind = 'parent'
+                            AND e.workspace = ''
+                    )
+                    INSERT INTO document_rollups
+                        (short_code, total_tasks, completed_tasks, estimate_remaining, refreshed_at)
+                    SELECT
+                        tree.root,
+                        COUNT(*),
+                        SUM(d.phase = 'completed'),
+                        COALESCE(SUM(
+                            CASE WHEN d.phase != 'completed'
+                                AND {numeric}
+                            THEN CAST(p.value AS REAL) END
+                        ), 0.0),
+                        ?
+                    FROM tree
+                    INNER JOIN documents d
+                        ON d.short_code = tree.code AND d.workspace = ''
+                        AND d.document_type = 'task' AND d.archived = 0
+                    LEFT JOIN document_properties p
+                        ON p.document_filepath = d.filepath AND p.key = 'estimate'
+                    GROUP BY tree.root
+                ",
+                    numeric = is_numeric("trim(p.value)")
+                ))
+                .bind::<diesel::sql_types::Double, _>(now)
+                .execute(conn)
+            })
+            .map_err(MetisError::Database)
+    }
+
+    /// Get the cached completion rollup for a parent document
+    pub fn find_rollup(&mut self, code: &str) -> Result<Option<DocumentRollup>> {
+        use schema::document_rollups::dsl::*;
+
+        document_rollups
+            .filter(short_code.eq(code))
+            .first(&mut self.connection)
+            .optional()
+            .map_err(MetisError::Database)
+    }
+
+    /// Get all cached completion rollups
+    pub fn list_rollups(&mut self) -> Result<Vec<DocumentRollup>> {
+        use schema::document_rollups::dsl::*;
+
+        document_rollups
+            .order(short_code.asc())
+            .load(&mut self.connection)
+            .map_err(MetisError::Database)
+    }
+
+    /// Get one page of a filtered listing along with the total number of
+    /// matching documents
+    pub fn find_page(&mut self, filter: &DocumentFilter) -> Result<DocumentPage> {
+        let (sql, binds) = filter.to_count_sql();
+        let mut count_query = diesel::sql_query(sql).into_boxed::<diesel::sqlite::Sqlite>();
+        for value in binds {
+            count_query = count_query.bind::<diesel::sql_types::Text, _>(value);
+        }
+        let total = count_query
+            .get_result::<CountRow>(&mut self.connection)
+            .map_err(MetisError::Database)?
+            .count as usize;
+
+        Ok(DocumentPage {
+            documents: self.find_by_filter(filter)?,
+            total,
+            offset: filter.offset,
+        })
+    }
+
+    /// Record that a document entered a phase, unless that is already the
+    /// latest phase recorded for it. Returns whether a change was recorded.
+    pub fn record_phase(&mut self, change: &PhaseChange) -> Result<bool> {
+        use schema::phase_history::dsl::*;
+
+        let latest: Option<String> = phase_history
+            .filter(short_code.eq(&change.short_code))
+            .order(entered_at.desc())
+            .select(phase)
+            .first(&mut self.connection)
+            .optional()
+            .map_err(MetisError::Database)?;
+        if latest.as_deref() == Some(change.phase.as_str()) {
+            return Ok(false);
+        }
+
+        let inserted = diesel::insert_or_ignore_into(phase_history)
+            .values(change)
+            .execute(&mut self.connection)
+            .map_err(MetisError::Database)?;
+        Ok(inserted > 0)
+    }
+
+    /// Get the recorded phase history for a document, oldest first
+    pub fn find_phase_history(&mut self, code: &str) -> Result<Vec<PhaseChange>> {
+        use schema::phase_history::dsl::*;
+
+        phase_history
+            .filter(short_code.eq(code))
+            .order(entered_at.asc())
+            .load(&mut self.connection)
+            .map_err(MetisError::Database)
+    }
+
+    /// Compute flow metrics for one document type between two Unix timestamps
+    pub fn flow_report(&mut self, doc_type: &str, since: f64, until: f64) -> Result<FlowReport> {
+        let history: Vec<PhaseChange> = {
+            use schema::phase_history::dsl::*;
+
+            phase_history
+                .filter(document_type.eq(doc_type))
+                .order(entered_at.asc())
+                .load(&mut self.connection)
+                .map_err(MetisError::Database)?
+        };
+        let current = self.find_by_type_unarchived(doc_type)?;
+
+        Ok(FlowReport::compute(
+            doc_type, &history, &current, since, until,
+        ))
+    }
+
+    /// Run a recursive CTE over local edges that defines `reached(code)` and
+    /// load the matching local documents, excluding the starting short code.
+    /// `UNION` (not `UNION ALL`) keeps cycles from recursing forever.
+    fn load_graph_query(&mut self, cte: &str, code: &str) -> Result<Vec<Document>> {
+        let query = format!(
+            "{}
+            SELECT d.* FROM documents d
+            WHERE d.workspace = ''
+            AND d.short_code IN (SELECT code FROM reached) AND d.short_code != ?
+            ORDER BY d.document_type, d.short_code",
+            cte
+        );
+
+        diesel::sql_query(query)
+            .bind::<diesel::sql_types::Text, _>(code)
+            .bind::<diesel::sql_types::Text, _>(code)
+            .load::<Document>(&mut self.connection)
+            .map_err(MetisError::Database)
+    }
 }
 
 #[cfg(test)]
@@ -563,4 +1278,828 @@ mod tests {
             .expect("Failed to search for document");
         assert!(found_by_id.is_none());
     }
+
+    fn edge(from: &str, to: &str, kind: &str, origin: &str) -> DocumentEdge {
+        DocumentEdge {
+            from_code: from.to_string(),
+            to_code: to.to_string(),
+            kind: kind.to_string(),
+            origin_filepath: origin.to_string(),
+            workspace: LOCAL_WORKSPACE.to_string(),
+        }
+    }
+
+    fn create_coded_document(repo: &mut DocumentRepository, code: &str, doc_type: &str) {
+        let mut doc = create_test_document();
+        doc.filepath = format!("/test/{}.md", code);
+        doc.id = code.to_lowercase();
+        doc.short_code = code.to_string();
+        doc.document_type = doc_type.to_string();
+        repo.create_document(doc)
+            .expect("Failed to create document");
+    }
+
+    #[test]
+    fn test_graph_queries() {
+        let mut repo = setup_test_repository();
+
+        create_coded_document(&mut repo, "TEST-V-0001", "vision");
+        create_coded_document(&mut repo, "TEST-I-0001", "initiative");
+        create_coded_document(&mut repo, "TEST-T-0001", "task");
+        create_coded_document(&mut repo, "TEST-T-0002", "task");
+        create_coded_document(&mut repo, "TEST-T-0003", "task");
+
+        repo.replace_edges_for_origin(
+            "/test/TEST-I-0001.md",
+            &[edge(
+                "TEST-I-0001",
+                "TEST-V-0001",
+                "parent",
+                "/test/TEST-I-0001.md",
+            )],
+        )
+        .unwrap();
+        repo.replace_edges_for_origin(
+            "/test/TEST-T-0001.md",
+            &[edge(
+                "TEST-T-0001",
+                "TEST-I-0001",
+                "parent",
+                "/test/TEST-T-0001.md",
+            )],
+        )
+        .unwrap();
+        repo.replace_edges_for_origin(
+            "/test/TEST-T-0002.md",
+            &[
+                edge(
+                    "TEST-T-0002",
+                    "TEST-I-0001",
+                    "parent",
+                    "/test/TEST-T-0002.md",
+                ),
+                edge(
+                    "TEST-T-0001",
+                    "TEST-T-0002",
+                    "blocks",
+                    "/test/TEST-T-0002.md",
+                ),
+            ],
+        )
+        .unwrap();
+        // T-0003 is blocked by T-0002, and (cyclically) blocks T-0001
+        repo.replace_edges_for_origin(
+            "/test/TEST-T-0003.md",
+            &[edge(
+                "TEST-T-0002",
+                "TEST-T-0003",
+                "blocks",
+                "/test/TEST-T-0003.md",
+            )],
+        )
+        .unwrap();
+        repo.create_edge(edge(
+            "TEST-T-0003",
+            "TEST-T-0001",
+            "blocks",
+            "/test/TEST-T-0001.md",
+        ))
+        .unwrap();
+
+        let codes = |docs: Vec<Document>| -> Vec<String> {
+            docs.into_iter().map(|d| d.short_code).collect()
+        };
+
+        assert_eq!(
+            codes(repo.find_descendants("TEST-V-0001").unwrap()),
+            vec!["TEST-I-0001", "TEST-T-0001", "TEST-T-0002"]
+        );
+        assert_eq!(
+            codes(repo.find_ancestors("TEST-T-0001").unwrap()),
+            vec!["TEST-I-0001", "TEST-V-0001"]
+        );
+        assert_eq!(
+            codes(repo.find_transitive_blockers("TEST-T-0003").unwrap()),
+            vec!["TEST-T-0001", "TEST-T-0002"]
+        );
+        assert_eq!(
+            codes(repo.find_impact("TEST-T-0001").unwrap()),
+            vec!["TEST-T-0002", "TEST-T-0003"]
+        );
+        assert_eq!(
+            codes(repo.find_impact("TEST-I-0001").unwrap()),
+            vec!["TEST-T-0001", "TEST-T-0002", "TEST-T-0003"]
+        );
+
+        // Replacing edges for an origin drops the old ones
+        repo.replace_edges_for_origin("/test/TEST-T-0002.md", &[])
+            .unwrap();
+        assert!(repo.find_edges_to("TEST-T-0002").unwrap().is_empty());
+        assert_eq!(repo.find_edges_from("TEST-T-0001").unwrap().len(), 1);
+
+        // Deleting a document removes the edges it declared
+        repo.delete_document("/test/TEST-T-0003.md").unwrap();
+        assert!(repo.find_edges_to("TEST-T-0003").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_file_stats_follow_document_lifecycle() {
+        let mut repo = setup_test_repository();
+        repo.create_document(create_test_document())
+            .expect("Failed to create document");
+
+        let stat = FileStat {
+            filepath: "/test/doc.md".to_string(),
+            file_size: 42,
+            mtime: 1609459200.0,
+        };
+        repo.upsert_file_stat(stat.clone()).unwrap();
+        repo.upsert_file_stat(FileStat {
+            file_size: 43,
+            ..stat.clone()
+        })
+        .unwrap();
+
+        let found = repo.find_file_stat("/test/doc.md").unwrap().unwrap();
+        assert_eq!(found.file_size, 43);
+
+        repo.delete_document("/test/doc.md").unwrap();
+        assert!(repo.find_file_stat("/test/doc.md").unwrap().is_none());
+    }
+
+    #[test]
+    fn test_foreign_workspace_documents() {
+        let mut repo = setup_test_repository();
+        create_coded_document(&mut repo, "TEST-T-0001", "task");
+
+        // A sibling workspace with a colliding filepath and a blocker on us
+        let local = repo.find_by_short_code("TEST-T-0001").unwrap().unwrap();
+        let foreign = Document {
+            short_code: "OPS-T-0007".to_string(),
+            id: "ops-task".to_string(),
+            ..local.clone()
+        };
+        let blocks_us = edge("OPS-T-0007", "TEST-T-0001", "blocks", &local.filepath);
+        let count = repo
+            .replace_workspace_documents("ops", &[foreign], &[blocks_us])
+            .unwrap();
+        assert_eq!(count, 1);
+
+        assert_eq!(repo.list_foreign_workspaces().unwrap(), vec!["ops"]);
+        let projected = repo.find_by_workspace("ops").unwrap();
+        assert_eq!(projected.len(), 1);
+        assert!(projected[0].is_foreign());
+        assert_eq!(
+            projected[0].filepath,
+            foreign_filepath("ops", &local.filepath)
+        );
+
+        // Local queries don't see projected documents
+        assert_eq!(repo.find_by_type("task").unwrap().len(), 1);
+        assert!(repo.find_by_short_code("OPS-T-0007").unwrap().is_none());
+        assert!(repo
+            .find_by_short_code_in_workspace("ops", "OPS-T-0007")
+            .unwrap()
+            .is_some());
+
+        let blockers = repo.find_foreign_blockers().unwrap();
+        assert_eq!(blockers.len(), 1);
+        assert_eq!(blockers[0].short_code, "OPS-T-0007");
+
+        // Re-projecting replaces the previous snapshot, edges included
+        repo.replace_workspace_documents("ops", &[], &[]).unwrap();
+        assert!(repo.find_by_workspace("ops").unwrap().is_empty());
+        assert!(repo.find_foreign_blockers().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_foreign_workspace_sharing_prefix() {
+        let mut repo = setup_test_repository();
+        create_coded_document(&mut repo, "TEST-V-0001", "vision");
+        create_coded_document(&mut repo, "TEST-I-0001", "initiative");
+        repo.replace_edges_for_origin(
+            "/test/TEST-I-0001.md",
+            &[edge(
+                "TEST-I-0001",
+                "TEST-V-0001",
+                "parent",
+                "/test/TEST-I-0001.md",
+            )],
+        )
+        .unwrap();
+
+        // A sibling workspace on the same prefix, with the same edge between
+        // its own documents and a task below its initiative
+        let local = repo.find_by_short_code("TEST-I-0001").unwrap().unwrap();
+        let foreign = |code: &str, doc_type: &str| Document {
+            filepath: format!("/test/{}.md", code),
+            short_code: code.to_string(),
+            id: format!("ops-{}", code.to_lowercase()),
+            document_type: doc_type.to_string(),
+            ..local.clone()
+        };
+        repo.replace_workspace_documents(
+            "ops",
+            &[
+                foreign("TEST-V-0001", "vision"),
+                foreign("TEST-I-0001", "initiative"),
+                foreign("TEST-T-0009", "task"),
+            ],
+            &[
+                edge(
+                    "TEST-I-0001",
+                    "TEST-V-0001",
+                    "parent",
+                    "/test/TEST-I-0001.md",
+                ),
+                edge(
+                    "TEST-T-0009",
+                    "TEST-I-0001",
+                    "parent",
+                    "/test/TEST-T-0009.md",
+                ),
+            ],
+        )
+        .unwrap();
+
+        // Both workspaces keep their copy of the shared edge
+        let count = |repo: &mut DocumentRepository, name: &str| {
+            diesel::sql_query("SELECT COUNT(*) AS count FROM document_edges WHERE workspace = ?")
+                .bind::<diesel::sql_types::Text, _>(name)
+                .get_result::<CountRow>(&mut repo.connection)
+                .unwrap()
+                .count
+        };
+        assert_eq!(count(&mut repo, ""), 1);
+        assert_eq!(count(&mut repo, "ops"), 2);
+
+        // Local graph queries only follow local edges to local documents
+        let descendants = repo.find_descendants("TEST-V-0001").unwrap();
+        assert_eq!(descendants.len(), 1);
+        assert_eq!(descendants[0].filepath, "/test/TEST-I-0001.md");
+        assert!(repo.find_descendants("TEST-I-0001").unwrap().is_empty());
+        assert!(repo.find_ancestors("TEST-T-0009").unwrap().is_empty());
+        assert_eq!(repo.find_edges_to("TEST-I-0001").unwrap().len(), 0);
+        assert_eq!(repo.find_edges_from("TEST-I-0001").unwrap().len(), 1);
+        assert_eq!(repo.export_graph(false).unwrap().edges.len(), 1);
+        assert!(repo.find_foreign_blockers().unwrap().is_empty());
+
+        // Dropping the projection leaves the local edge in place
+        repo.replace_workspace_documents("ops", &[], &[]).unwrap();
+        assert_eq!(count(&mut repo, "ops"), 0);
+        assert_eq!(count(&mut repo, ""), 1);
+    }
+
+    fn property(file_path: &str, name: &str, value: &str) -> DocumentProperty {
+        DocumentProperty {
+            document_filepath: file_path.to_string(),
+            key: name.to_string(),
+            value: value.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_find_by_property_filters() {
+        use crate::dal::database::properties::PropertyFilter;
+        use chrono::NaiveDate;
+
+        let mut repo = setup_test_repository();
+        create_coded_document(&mut repo, "TEST-T-0001", "task");
+        create_coded_document(&mut repo, "TEST-T-0002", "task");
+        create_coded_document(&mut repo, "TEST-I-0001", "initiative");
+
+        repo.replace_properties(
+            "/test/TEST-T-0001.md",
+            &[
+                property("/test/TEST-T-0001.md", "estimate", "3"),
+                property("/test/TEST-T-0001.md", "due_date", "2025-01-15"),
+                property("/test/TEST-T-0001.md", "billable", "yes"),
+            ],
+        )
+        .unwrap();
+        repo.replace_properties(
+            "/test/TEST-T-0002.md",
+            &[
+                property("/test/TEST-T-0002.md", "estimate", "13"),
+                property("/test/TEST-T-0002.md", "due_date", "2025-03-01"),
+                property("/test/TEST-T-0002.md", "billable", "false"),
+            ],
+        )
+        .unwrap();
+        repo.replace_properties(
+            "/test/TEST-I-0001.md",
+            &[property("/test/TEST-I-0001.md", "estimate", "unknown")],
+        )
+        .unwrap();
+
+        let codes = |docs: Vec<Document>| -> Vec<String> {
+            docs.into_iter().map(|d| d.short_code).collect()
+        };
+
+        let small = DocumentFilter::new().property(PropertyFilter::NumberRange {
+            key: "estimate".to_string(),
+            min: None,
+            max: Some(5.0),
+        });
+        assert_eq!(
+            codes(repo.find_by_filter(&small).unwrap()),
+            vec!["TEST-T-0001"]
+        );
+
+        let due_soon =
+            DocumentFilter::new()
+                .document_type("task")
+                .property(PropertyFilter::DateRange {
+                    key: "due_date".to_string(),
+                    from: NaiveDate::from_ymd_opt(2025, 2, 1),
+                    to: None,
+                });
+        assert_eq!(
+            codes(repo.find_by_filter(&due_soon).unwrap()),
+            vec!["TEST-T-0002"]
+        );
+
+        let billable = DocumentFilter::new().property(PropertyFilter::Bool {
+            key: "billable".to_string(),
+            value: true,
+        });
+        assert_eq!(
+            codes(repo.find_by_filter(&billable).unwrap()),
+            vec!["TEST-T-0001"]
+        );
+
+        let has_estimate = DocumentFilter::new().property(PropertyFilter::Exists {
+            key: "estimate".to_string(),
+        });
+        assert_eq!(repo.find_by_filter(&has_estimate).unwrap().len(), 3);
+
+        let none = DocumentFilter::new().document_type("initiative").property(
+            PropertyFilter::NumberRange {
+                key: "estimate".to_string(),
+                min: Some(0.0),
+                max: None,
+            },
+        );
+        assert!(repo.find_by_filter(&none).unwrap().is_empty());
+
+        let props = repo.find_properties("/test/TEST-T-0002.md").unwrap();
+        assert_eq!(props.len(), 3);
+        assert_eq!(props[0].as_bool(), Some(false));
+        assert_eq!(props[1].as_date(), NaiveDate::from_ymd_opt(2025, 3, 1));
+        assert_eq!(props[2].as_f64(), Some(13.0));
+    }
+
+    #[test]
+    fn test_number_range_needs_whole_numbers() {
+        use crate::dal::database::properties::PropertyFilter;
+
+        let mut repo = setup_test_repository();
+        let values = [
+            ("TEST-T-0001", "-4"),
+            ("TEST-T-0002", " 2.5 "),
+            ("TEST-T-0003", "+1"),
+            ("TEST-T-0004", "5abc"),
+            ("TEST-T-0005", "1-2"),
+            ("TEST-T-0006", "1.2.3"),
+            ("TEST-T-0007", "-"),
+        ];
+        for (code, value) in values {
+            create_coded_document(&mut repo, code, "task");
+            let filepath = format!("/test/{}.md", code);
+            repo.replace_properties(&filepath, &[property(&filepath, "estimate", value)])
+                .unwrap();
+        }
+
+        let filter = DocumentFilter::new().property(PropertyFilter::NumberRange {
+            key: "estimate".to_string(),
+            min: None,
+            max: Some(10.0),
+        });
+        let codes: Vec<String> = repo
+            .find_by_filter(&filter)
+            .unwrap()
+            .into_iter()
+            .map(|d| d.short_code)
+            .collect();
+        assert_eq!(codes, vec!["TEST-T-0001", "TEST-T-0002", "TEST-T-0003"]);
+    }
+
+    #[test]
+    fn test_tag_queries() {
+        let mut repo = setup_test_repository();
+        create_coded_document(&mut repo, "TEST-T-0001", "task");
+        create_coded_document(&mut repo, "TEST-T-0002", "task");
+        create_coded_document(&mut repo, "TEST-T-0003", "task");
+
+        let tags =
+            |names: &[&str]| -> Vec<String> { names.iter().map(|t| t.to_string()).collect() };
+        repo.replace_tags(
+            "/test/TEST-T-0001.md",
+            &tags(&["#task", "#phase/todo", "backend"]),
+        )
+        .unwrap();
+        repo.replace_tags(
+            "/test/TEST-T-0002.md",
+            &tags(&["#task", "#phase/active", "backend"]),
+        )
+        .unwrap();
+        repo.replace_tags("/test/TEST-T-0003.md", &tags(&["#task", "#phase/todo"]))
+            .unwrap();
+
+        let backend: Vec<String> = repo
+            .find_by_tag("backend")
+            .unwrap()
+            .into_iter()
+            .map(|d| d.short_code)
+            .collect();
+        assert_eq!(backend, vec!["TEST-T-0001", "TEST-T-0002"]);
+
+        let counts = repo.tag_counts().unwrap();
+        assert_eq!(counts[0].tag, "#task");
+        assert_eq!(counts[0].count, 3);
+        assert!(counts
+            .iter()
+            .any(|c| c.tag == "#phase/todo" && c.count == 2));
+
+        let with_backend = repo.co_occurring_tags("backend").unwrap();
+        assert_eq!(with_backend[0].tag, "#task");
+        assert_eq!(with_backend[0].count, 2);
+        assert_eq!(with_backend.len(), 3);
+
+        // Replacing drops tags that are no longer present
+        repo.replace_tags("/test/TEST-T-0002.md", &tags(&["#task"]))
+            .unwrap();
+        assert_eq!(repo.find_by_tag("backend").unwrap().len(), 1);
+
+        // Deleting a document removes its tags
+        repo.delete_document("/test/TEST-T-0001.md").unwrap();
+        assert!(repo.find_by_tag("backend").unwrap().is_empty());
+        assert!(repo
+            .get_tags_for_document("/test/TEST-T-0001.md")
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_count_by() {
+        let mut repo = setup_test_repository();
+        create_coded_document(&mut repo, "TEST-T-0001", "task");
+        create_coded_document(&mut repo, "TEST-T-0002", "task");
+        create_coded_document(&mut repo, "TEST-I-0001", "initiative");
+
+        repo.replace_tags(
+            "/test/TEST-T-0001.md",
+            &["#task".to_string(), "#phase/todo".to_string()],
+        )
+        .unwrap();
+        repo.replace_tags("/test/TEST-T-0002.md", &["#task".to_string()])
+            .unwrap();
+        repo.replace_properties(
+            "/test/TEST-T-0001.md",
+            &[property("/test/TEST-T-0001.md", "assignee", "alice")],
+        )
+        .unwrap();
+
+        let types = repo.count_by(DocumentGroup::Type).unwrap();
+        assert_eq!(types[0].value, "task");
+        assert_eq!(types[0].count, 2);
+        assert_eq!(types[1].value, "initiative");
+
+        let tags = repo.count_by(DocumentGroup::Tag).unwrap();
+        assert_eq!(tags.len(), 1);
+        assert_eq!(tags[0].value, "#task");
+        assert_eq!(tags[0].count, 2);
+
+        let assignees = repo.count_by(DocumentGroup::Assignee).unwrap();
+        assert_eq!(assignees[0].value, "");
+        assert_eq!(assignees[0].count, 2);
+        assert_eq!(assignees[1].value, "alice");
+
+        let phases = repo.count_by(DocumentGroup::Phase).unwrap();
+        assert_eq!(phases.iter().map(|p| p.count).sum::<i64>(), 3);
+    }
+
+    #[test]
+    fn test_find_page_sorts_and_paginates() {
+        use crate::dal::database::properties::DocumentSort;
+
+        let mut repo = setup_test_repository();
+        for code in ["TEST-T-0001", "TEST-T-0002", "TEST-T-0003", "TEST-T-0004"] {
+            create_coded_document(&mut repo, code, "task");
+        }
+        create_coded_document(&mut repo, "TEST-V-0001", "vision");
+
+        repo.replace_properties(
+            "/test/TEST-T-0001.md",
+            &[property("/test/TEST-T-0001.md", "priority", "low")],
+        )
+        .unwrap();
+        repo.replace_properties(
+            "/test/TEST-T-0002.md",
+            &[property("/test/TEST-T-0002.md", "priority", "critical")],
+        )
+        .unwrap();
+        repo.replace_properties(
+            "/test/TEST-T-0003.md",
+            &[property("/test/TEST-T-0003.md", "priority", "high")],
+        )
+        .unwrap();
+
+        let codes = |page: &DocumentPage| -> Vec<String> {
+            page.documents
+                .iter()
+                .map(|d| d.short_code.clone())
+                .collect()
+        };
+
+        // Default order is by flight level, then short code
+        let first = repo.find_page(&DocumentFilter::new().page(2, 0)).unwrap();
+        assert_eq!(first.total, 5);
+        assert_eq!(codes(&first), vec!["TEST-V-0001", "TEST-T-0001"]);
+        assert!(first.has_more());
+
+        let last = repo.find_page(&DocumentFilter::new().page(2, 4)).unwrap();
+        assert_eq!(codes(&last), vec!["TEST-T-0004"]);
+        assert!(!last.has_more());
+
+        // Numeric priorities sort among the named ones by value
+        create_coded_document(&mut repo, "TEST-T-0005", "task");
+        repo.replace_properties(
+            "/test/TEST-T-0005.md",
+            &[property("/test/TEST-T-0005.md", "priority", "2.5")],
+        )
+        .unwrap();
+
+        // Documents without a priority sort last in either direction
+        let by_priority = DocumentFilter::new()
+            .document_type("task")
+            .sort_by(DocumentSort::Priority, false);
+        assert_eq!(
+            codes(&repo.find_page(&by_priority).unwrap()),
+            vec![
+                "TEST-T-0002",
+                "TEST-T-0003",
+                "TEST-T-0005",
+                "TEST-T-0001",
+                "TEST-T-0004"
+            ]
+        );
+        let by_priority_desc = by_priority.sort_by(DocumentSort::Priority, true);
+        assert_eq!(
+            codes(&repo.find_page(&by_priority_desc).unwrap()),
+            vec![
+                "TEST-T-0001",
+                "TEST-T-0005",
+                "TEST-T-0003",
+                "TEST-T-0002",
+                "TEST-T-0004"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_search_filtered_with_snippets() {
+        let mut repo = setup_test_repository();
+        for (code, doc_phase, body) in [
+            (
+                "TEST-T-0001",
+                "active",
+                "Add rate limiting to the public API",
+            ),
+            ("TEST-T-0002", "todo", "Document rate limiting for partners"),
+            ("TEST-T-0003", "active", "Rotate the signing keys"),
+        ] {
+            let mut doc = create_test_document();
+            doc.filepath = format!("/test/{}.md", code);
+            doc.id = code.to_lowercase();
+            doc.short_code = code.to_string();
+            doc.document_type = "task".to_string();
+            doc.phase = doc_phase.to_string();
+            doc.content = Some(body.to_string());
+            repo.create_document(doc).unwrap();
+        }
+        repo.replace_tags("/test/TEST-T-0001.md", &["#team/api".to_string()])
+            .unwrap();
+
+        let codes = |hits: &[SearchHit]| -> Vec<String> {
+            hits.iter().map(|h| h.document.short_code.clone()).collect()
+        };
+
+        let all = repo
+            .search_filtered("limiting", &DocumentFilter::new())
+            .unwrap();
+        assert_eq!(all.len(), 2);
+        assert!(all[0].snippet.contains("**limiting**"));
+
+        let active = DocumentFilter::new().document_type("task").phase("active");
+        assert_eq!(
+            codes(&repo.search_filtered("limiting", &active).unwrap()),
+            vec!["TEST-T-0001"]
+        );
+
+        let tagged = DocumentFilter::new().tag("#team/api");
+        assert_eq!(
+            codes(&repo.search_filtered("limiting", &tagged).unwrap()),
+            vec!["TEST-T-0001"]
+        );
+        assert!(repo.search_filtered("keys", &tagged).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_refresh_rollups() {
+        let mut repo = setup_test_repository();
+        create_coded_document(&mut repo, "TEST-V-0001", "vision");
+        create_coded_document(&mut repo, "TEST-I-0001", "initiative");
+        for (code, task_phase) in [
+            ("TEST-T-0001", "completed"),
+            ("TEST-T-0002", "todo"),
+            ("TEST-T-0003", "active"),
+        ] {
+            let mut doc = create_test_document();
+            doc.filepath = format!("/test/{}.md", code);
+            doc.id = code.to_lowercase();
+            doc.short_code = code.to_string();
+            doc.document_type = "task".to_string();
+            doc.phase = task_phase.to_string();
+            repo.create_document(doc).unwrap();
+        }
+
+        let parent_edge = |from: &str, to: &str| {
+            let origin = format!("/test/{}.md", from);
+            (origin.clone(), vec![edge(from, to, "parent", &origin)])
+        };
+        for (origin, edges) in [
+            parent_edge("TEST-I-0001", "TEST-V-0001"),
+            parent_edge("TEST-T-0001", "TEST-I-0001"),
+            parent_edge("TEST-T-0002", "TEST-I-0001"),
+            parent_edge("TEST-T-0003", "TEST-I-0001"),
+        ] {
+            repo.replace_edges_for_origin(&origin, &edges).unwrap();
+        }
+        repo.replace_properties(
+            "/test/TEST-T-0001.md",
+            &[property("/test/TEST-T-0001.md", "estimate", "3")],
+        )
+        .unwrap();
+        repo.replace_properties(
+            "/test/TEST-T-0002.md",
+            &[property("/test/TEST-T-0002.md", "estimate", "5")],
+        )
+        .unwrap();
+        repo.replace_properties(
+            "/test/TEST-T-0003.md",
+            &[property("/test/TEST-T-0003.md", "estimate", "2.5")],
+        )
+        .unwrap();
+
+        assert_eq!(repo.refresh_rollups().unwrap(), 2);
+
+        let initiative = repo.find_rollup("TEST-I-0001").unwrap().unwrap();
+        assert_eq!(initiative.total_tasks, 3);
+        assert_eq!(initiative.completed_tasks, 1);
+        assert_eq!(initiative.estimate_remaining, 7.5);
+        assert!((initiative.completion() - 1.0 / 3.0).abs() < f64::EPSILON);
+
+        // The vision rolls up the tasks of its initiatives
+        let vision = repo.find_rollup("TEST-V-0001").unwrap().unwrap();
+        assert_eq!(vision.total_tasks, 3);
+        assert_eq!(vision.completed_tasks, 1);
+
+        assert!(repo.find_rollup("TEST-T-0001").unwrap().is_none());
+
+        // Deleting a task is reflected on the next refresh
+        repo.delete_document("/test/TEST-T-0002.md").unwrap();
+        repo.refresh_rollups().unwrap();
+        let initiative = repo.find_rollup("TEST-I-0001").unwrap().unwrap();
+        assert_eq!(initiative.total_tasks, 2);
+        assert_eq!(initiative.estimate_remaining, 2.5);
+        assert_eq!(repo.list_rollups().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_refresh_rollups_skips_non_numeric_estimates() {
+        let mut repo = setup_test_repository();
+        create_coded_document(&mut repo, "TEST-I-0001", "initiative");
+        for (code, estimate) in [
+            ("TEST-T-0001", "3"),
+            ("TEST-T-0002", "5 days"),
+            ("TEST-T-0003", "2h"),
+        ] {
+            create_coded_document(&mut repo, code, "task");
+            let origin = format!("/test/{}.md", code);
+            repo.replace_edges_for_origin(&origin, &[edge(code, "TEST-I-0001", "parent", &origin)])
+                .unwrap();
+            repo.replace_properties(&origin, &[property(&origin, "estimate", estimate)])
+                .unwrap();
+        }
+
+        repo.refresh_rollups().unwrap();
+        let initiative = repo.find_rollup("TEST-I-0001").unwrap().unwrap();
+        assert_eq!(initiative.total_tasks, 3);
+        assert_eq!(initiative.estimate_remaining, 3.0);
+    }
+
+    #[test]
+    fn test_export_graph() {
+        let mut repo = setup_test_repository();
+        create_coded_document(&mut repo, "TEST-V-0001", "vision");
+        create_coded_document(&mut repo, "TEST-I-0001", "initiative");
+        create_coded_document(&mut repo, "TEST-T-0001", "task");
+
+        repo.replace_edges_for_origin(
+            "/test/TEST-I-0001.md",
+            &[edge(
+                "TEST-I-0001",
+                "TEST-V-0001",
+                "parent",
+                "/test/TEST-I-0001.md",
+            )],
+        )
+        .unwrap();
+        repo.replace_edges_for_origin(
+            "/test/TEST-T-0001.md",
+            &[
+                edge(
+                    "TEST-T-0001",
+                    "TEST-I-0001",
+                    "parent",
+                    "/test/TEST-T-0001.md",
+                ),
+                edge(
+                    "TEST-T-0001",
+                    "TEST-T-9999",
+                    "blocks",
+                    "/test/TEST-T-0001.md",
+                ),
+            ],
+        )
+        .unwrap();
+
+        let graph = repo.export_graph(false).unwrap();
+        assert_eq!(graph.nodes.len(), 3);
+        // The edge to a missing document is dropped
+        assert_eq!(graph.edges.len(), 2);
+        assert!(graph.edges.contains(&GraphEdge {
+            from: "TEST-T-0001".to_string(),
+            to: "TEST-I-0001".to_string(),
+            kind: "parent".to_string(),
+        }));
+
+        let json: serde_json::Value = serde_json::from_str(&graph.to_json().unwrap()).unwrap();
+        assert_eq!(json["nodes"].as_array().unwrap().len(), 3);
+        assert_eq!(json["edges"][0]["kind"], "parent");
+
+        let dot = graph.to_dot();
+        assert!(dot.starts_with("digraph metis {"));
+        assert!(dot.contains("\"TEST-T-0001\" -> \"TEST-I-0001\" [label=\"parent\"];"));
+    }
+
+    #[test]
+    fn test_graph_hierarchy_and_mermaid() {
+        let mut repo = setup_test_repository();
+        create_coded_document(&mut repo, "TEST-V-0001", "vision");
+        create_coded_document(&mut repo, "TEST-I-0001", "initiative");
+        create_coded_document(&mut repo, "TEST-I-0002", "initiative");
+        create_coded_document(&mut repo, "TEST-T-0001", "task");
+        create_coded_document(&mut repo, "TEST-T-0002", "task");
+        create_coded_document(&mut repo, "TEST-A-0001", "adr");
+
+        for (from, to, kind) in [
+            ("TEST-I-0001", "TEST-V-0001", "parent"),
+            ("TEST-I-0002", "TEST-V-0001", "parent"),
+            ("TEST-T-0001", "TEST-I-0001", "parent"),
+            ("TEST-T-0002", "TEST-I-0002", "parent"),
+            ("TEST-T-0002", "TEST-T-0001", "blocks"),
+            ("TEST-A-0001", "TEST-I-0001", "references"),
+        ] {
+            let origin = format!("/test/{}-{}.md", from, kind);
+            repo.replace_edges_for_origin(&origin, &[edge(from, to, kind, &origin)])
+                .unwrap();
+        }
+
+        let graph = repo.export_graph(false).unwrap().hierarchy(false);
+        // The ADR and its reference are left out, and so is the blocker
+        assert_eq!(graph.nodes.len(), 5);
+        assert!(graph.edges.iter().all(|edge| edge.kind == "parent"));
+
+        let with_blockers = repo.export_graph(false).unwrap().hierarchy(true);
+        assert_eq!(with_blockers.edges.len(), 5);
+
+        let subtree = with_blockers.clone().subtree("TEST-I-0001").unwrap();
+        let codes: Vec<&str> = subtree
+            .nodes
+            .iter()
+            .map(|node| node.short_code.as_str())
+            .collect();
+        assert_eq!(codes, vec!["TEST-I-0001", "TEST-T-0001"]);
+        // TEST-T-0002 is under the other initiative, so its blocker edge goes
+        assert_eq!(subtree.edges.len(), 1);
+        assert!(with_blockers.clone().subtree("TEST-I-9999").is_none());
+
+        let mermaid = with_blockers.to_mermaid();
+        assert!(mermaid.starts_with("flowchart TD\n"));
+        assert!(mermaid.contains("    TEST_V_0001 --> TEST_I_0001\n"));
+        assert!(mermaid.contains("    TEST_T_0002 -. blocks .-> TEST_T_0001\n"));
+        assert!(mermaid.contains("TEST_T_0001[\"TEST-T-0001<br/>"));
+    }
 }
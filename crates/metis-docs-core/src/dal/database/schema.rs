@@ -17,6 +17,7 @@ diesel::table! {
         initiative_id -> Nullable<Text>,
         short_code -> Text,
         parent_id -> Nullable<Text>,
+        workspace -> Text,
     }
 }
 
@@ -46,6 +47,51 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    document_edges (workspace, from_code, to_code, kind) {
+        from_code -> Text,
+        to_code -> Text,
+        kind -> Text,
+        origin_filepath -> Text,
+        workspace -> Text,
+    }
+}
+
+diesel::table! {
+    file_stats (filepath) {
+        filepath -> Text,
+        file_size -> BigInt,
+        mtime -> Double,
+    }
+}
+
+diesel::table! {
+    document_properties (document_filepath, key) {
+        document_filepath -> Text,
+        key -> Text,
+        value -> Text,
+    }
+}
+
+diesel::table! {
+    document_rollups (short_code) {
+        short_code -> Text,
+        total_tasks -> Integer,
+        completed_tasks -> Integer,
+        estimate_remaining -> Double,
+        refreshed_at -> Double,
+    }
+}
+
+diesel::table! {
+    phase_history (short_code, entered_at, phase) {
+        short_code -> Text,
+        document_type -> Text,
+        phase -> Text,
+        entered_at -> Double,
+    }
+}
+
 diesel::table! {
     configuration (key) {
         key -> Text,
@@ -62,5 +108,10 @@ diesel::allow_tables_to_appear_in_same_query!(
     document_relationships,
     document_search,
     document_tags,
+    document_edges,
+    file_stats,
+    document_properties,
+    document_rollups,
+    phase_history,
     configuration,
 );
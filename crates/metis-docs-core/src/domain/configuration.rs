@@ -1,5 +1,6 @@
 use crate::domain::documents::types::DocumentType;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fmt;
 
 /// Flight level configuration defining which levels are enabled
@@ -163,9 +164,24 @@ impl fmt::Display for ViewerBackend {
     }
 }
 
+impl std::str::FromStr for ViewerBackend {
+    type Err = ConfigurationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "sys_editor" => Ok(ViewerBackend::SysEditor),
+            "code" => Ok(ViewerBackend::Code),
+            "gui" => Ok(ViewerBackend::Gui),
+            other => Err(ConfigurationError::InvalidValue(format!(
+                "'{}' is not a viewer (expected sys_editor, code or gui)",
+                other
+            ))),
+        }
+    }
+}
+
 /// Viewer configuration section in config.toml
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
-#[derive(Default)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
 pub struct ViewerConfig {
     /// Default viewer backend. Falls back to $EDITOR if not set.
     pub default: Option<ViewerBackend>,
@@ -177,6 +193,318 @@ pub struct ViewerConfig {
     pub background: bool,
 }
 
+/// Transport the MCP server is served over
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum McpTransport {
+    /// A subprocess per editor, speaking JSON-RPC over stdin/stdout
+    #[default]
+    #[serde(rename = "stdio")]
+    Stdio,
+    /// A long-lived server on a port, speaking streamable HTTP with SSE
+    #[serde(rename = "http")]
+    Http,
+}
+
+impl fmt::Display for McpTransport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            McpTransport::Stdio => write!(f, "stdio"),
+            McpTransport::Http => write!(f, "http"),
+        }
+    }
+}
+
+impl std::str::FromStr for McpTransport {
+    type Err = ConfigurationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "stdio" => Ok(McpTransport::Stdio),
+            "http" => Ok(McpTransport::Http),
+            other => Err(ConfigurationError::InvalidValue(format!(
+                "'{}' is not an MCP transport (expected stdio or http)",
+                other
+            ))),
+        }
+    }
+}
+
+/// MCP server configuration section in config.toml
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct McpConfig {
+    /// Transport to serve on (default: stdio)
+    #[serde(default)]
+    pub transport: McpTransport,
+    /// Address the HTTP transport listens on; 127.0.0.1 when unset
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub host: Option<String>,
+    /// Port the HTTP transport listens on; 8080 when unset
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub port: Option<u16>,
+    /// Only offer tools that don't change the workspace (default: false)
+    #[serde(default)]
+    pub read_only: bool,
+}
+
+impl McpConfig {
+    pub const DEFAULT_HOST: &'static str = "127.0.0.1";
+    pub const DEFAULT_PORT: u16 = 8080;
+
+    /// Whether every setting is left at its default
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Address the HTTP transport listens on
+    pub fn host(&self) -> &str {
+        self.host.as_deref().unwrap_or(Self::DEFAULT_HOST)
+    }
+
+    /// Port the HTTP transport listens on
+    pub fn port(&self) -> u16 {
+        self.port.unwrap_or(Self::DEFAULT_PORT)
+    }
+
+    /// Parse a port number, refusing 0 since the server must be reachable
+    /// at a known port
+    pub fn parse_port(value: &str) -> Result<u16, ConfigurationError> {
+        match value.trim().parse::<u16>() {
+            Ok(port) if port > 0 => Ok(port),
+            _ => Err(ConfigurationError::InvalidValue(format!(
+                "'{}' is not a port (expected 1-65535)",
+                value
+            ))),
+        }
+    }
+}
+
+/// Name under which the `[sync] remote` is managed alongside named remotes
+pub const PRIMARY_REMOTE: &str = "origin";
+
+/// Sync configuration section in config.toml
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SyncConfig {
+    /// Git remote URL that workspace changes are pulled from
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remote: Option<String>,
+    /// Branch pulled from `remote`; the current branch when unset
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub branch: Option<String>,
+    /// Sibling workspaces whose documents are projected into this one, by name
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub remotes: BTreeMap<String, SyncRemote>,
+}
+
+/// A sibling workspace's remote, in a `[sync.remotes.<name>]` section
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SyncRemote {
+    pub url: String,
+    /// Workspace prefix its documents are projected under; the remote's name
+    /// when unset
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub workspace: Option<String>,
+    /// Branch to sync; the remote's default branch when unset
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub branch: Option<String>,
+}
+
+/// A configured remote as listed by `metis sync remote list`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemoteEntry {
+    pub name: String,
+    pub url: String,
+    /// `None` for the primary remote, which syncs into this workspace
+    pub workspace: Option<String>,
+    pub branch: Option<String>,
+}
+
+impl SyncConfig {
+    /// Whether no sync settings are configured
+    pub fn is_empty(&self) -> bool {
+        self.remote.is_none() && self.branch.is_none() && self.remotes.is_empty()
+    }
+
+    /// Every configured remote, the primary one first
+    pub fn entries(&self) -> Vec<RemoteEntry> {
+        let primary = self.remote.as_ref().map(|url| RemoteEntry {
+            name: PRIMARY_REMOTE.to_string(),
+            url: url.clone(),
+            workspace: None,
+            branch: self.branch.clone(),
+        });
+        primary
+            .into_iter()
+            .chain(self.remotes.iter().map(|(name, remote)| RemoteEntry {
+                name: name.clone(),
+                url: remote.url.clone(),
+                workspace: Some(remote.workspace.clone().unwrap_or_else(|| name.clone())),
+                branch: remote.branch.clone(),
+            }))
+            .collect()
+    }
+
+    /// Add a remote, or replace the one with the same name. `origin` is the
+    /// primary remote, which can't take a workspace prefix.
+    pub fn add_remote(
+        &mut self,
+        name: &str,
+        url: &str,
+        workspace: Option<&str>,
+        branch: Option<&str>,
+    ) -> Result<(), ConfigurationError> {
+        let url = url.trim();
+        Self::validate_remote(url)?;
+        if let Some(branch) = branch {
+            Self::validate_branch(branch)?;
+        }
+
+        if name == PRIMARY_REMOTE {
+            if workspace.is_some() {
+                return Err(ConfigurationError::InvalidValue(format!(
+                    "'{}' syncs into this workspace and can't have a workspace prefix",
+                    PRIMARY_REMOTE
+                )));
+            }
+            self.remote = Some(url.to_string());
+            self.branch = branch.map(str::to_string);
+            return Ok(());
+        }
+
+        Self::validate_name("Remote name", name)?;
+        if let Some(workspace) = workspace {
+            Self::validate_name("Workspace prefix", workspace)?;
+        }
+        let prefix = workspace.unwrap_or(name);
+        if let Some(other) = self
+            .entries()
+            .into_iter()
+            .find(|entry| entry.name != name && entry.workspace.as_deref() == Some(prefix))
+        {
+            return Err(ConfigurationError::InvalidValue(format!(
+                "Workspace prefix '{}' is already used by remote '{}'",
+                prefix, other.name
+            )));
+        }
+
+        self.remotes.insert(
+            name.to_string(),
+            SyncRemote {
+                url: url.to_string(),
+                workspace: workspace.map(str::to_string),
+                branch: branch.map(str::to_string),
+            },
+        );
+        Ok(())
+    }
+
+    /// Remove a remote by name
+    pub fn remove_remote(&mut self, name: &str) -> Result<(), ConfigurationError> {
+        if name == PRIMARY_REMOTE && self.remote.is_some() {
+            self.remote = None;
+            self.branch = None;
+            return Ok(());
+        }
+        self.remotes
+            .remove(name)
+            .map(|_| ())
+            .ok_or_else(|| Self::unknown_remote(name))
+    }
+
+    /// Set the branch a remote syncs, or go back to the default with `None`
+    pub fn set_branch(
+        &mut self,
+        name: &str,
+        branch: Option<&str>,
+    ) -> Result<(), ConfigurationError> {
+        if let Some(branch) = branch {
+            Self::validate_branch(branch)?;
+        }
+        let branch = branch.map(str::to_string);
+        if name == PRIMARY_REMOTE && self.remote.is_some() {
+            self.branch = branch;
+            return Ok(());
+        }
+        let remote = self
+            .remotes
+            .get_mut(name)
+            .ok_or_else(|| Self::unknown_remote(name))?;
+        remote.branch = branch;
+        Ok(())
+    }
+
+    fn unknown_remote(name: &str) -> ConfigurationError {
+        ConfigurationError::InvalidValue(format!("No remote named '{}'", name))
+    }
+
+    /// Validate a remote name or workspace prefix: lowercase letters, digits
+    /// and dashes
+    pub fn validate_name(what: &str, name: &str) -> Result<(), ConfigurationError> {
+        let valid = !name.is_empty()
+            && !name.starts_with('-')
+            && name
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-');
+        if !valid {
+            return Err(ConfigurationError::InvalidValue(format!(
+                "{} '{}' must be lowercase letters, digits and dashes",
+                what, name
+            )));
+        }
+        Ok(())
+    }
+
+    /// Validate a git branch name, rejecting what git itself would
+    pub fn validate_branch(branch: &str) -> Result<(), ConfigurationError> {
+        let valid = !branch.is_empty()
+            && !branch.starts_with('-')
+            && !branch.ends_with('/')
+            && !branch.contains("..")
+            && !branch
+                .chars()
+                .any(|c| c.is_whitespace() || c.is_control() || "~^:?*[\\".contains(c));
+        if !valid {
+            return Err(ConfigurationError::InvalidValue(format!(
+                "'{}' is not a valid branch name",
+                branch
+            )));
+        }
+        Ok(())
+    }
+
+    /// Validate a git remote URL (https, http, ssh, file or scp-style `git@host:path`)
+    pub fn validate_remote(url: &str) -> Result<(), ConfigurationError> {
+        let url = url.trim();
+        let rest = ["https://", "http://", "ssh://", "file://"]
+            .iter()
+            .find_map(|scheme| url.strip_prefix(scheme))
+            .or_else(|| url.strip_prefix("git@").filter(|rest| rest.contains(':')));
+
+        match rest {
+            Some(rest) if !rest.is_empty() && !rest.contains(char::is_whitespace) => Ok(()),
+            _ => Err(ConfigurationError::InvalidValue(format!(
+                "'{}' is not a git remote URL (expected https://, ssh://, file:// or git@host:path)",
+                url
+            ))),
+        }
+    }
+}
+
+/// Keys that `ConfigFile::get_value` and `ConfigFile::set_value` accept.
+/// `flight_levels.preset` is derived from `flight_levels.initiatives_enabled`.
+pub const CONFIG_KEYS: &[&str] = &[
+    "project.prefix",
+    "flight_levels.preset",
+    "flight_levels.initiatives_enabled",
+    "viewer.default",
+    "viewer.suppress_proactive_ticket_opening",
+    "viewer.background",
+    "sync.remote",
+    "sync.branch",
+    "mcp.transport",
+    "mcp.host",
+    "mcp.port",
+    "mcp.read_only",
+];
 
 /// Configuration file structure that persists to .metis/config.toml
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -185,6 +513,10 @@ pub struct ConfigFile {
     pub flight_levels: FlightLevelConfig,
     #[serde(default)]
     pub viewer: ViewerConfig,
+    #[serde(default, skip_serializing_if = "SyncConfig::is_empty")]
+    pub sync: SyncConfig,
+    #[serde(default, skip_serializing_if = "McpConfig::is_default")]
+    pub mcp: McpConfig,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -198,20 +530,27 @@ impl ConfigFile {
         prefix: String,
         flight_levels: FlightLevelConfig,
     ) -> Result<Self, ConfigurationError> {
-        // Validate prefix format: 2-8 uppercase letters
-        if !prefix.chars().all(|c| c.is_ascii_uppercase()) || prefix.len() < 2 || prefix.len() > 8 {
-            return Err(ConfigurationError::InvalidValue(
-                "Project prefix must be 2-8 uppercase letters".to_string(),
-            ));
-        }
+        Self::validate_prefix(&prefix)?;
 
         Ok(Self {
             project: ProjectConfig { prefix },
             flight_levels,
             viewer: ViewerConfig::default(),
+            sync: SyncConfig::default(),
+            mcp: McpConfig::default(),
         })
     }
 
+    /// Validate a project prefix: 2-8 uppercase letters
+    pub fn validate_prefix(prefix: &str) -> Result<(), ConfigurationError> {
+        if !prefix.chars().all(|c| c.is_ascii_uppercase()) || prefix.len() < 2 || prefix.len() > 8 {
+            return Err(ConfigurationError::InvalidValue(
+                "Project prefix must be 2-8 uppercase letters".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
     /// Load configuration from a TOML file
     pub fn load<P: AsRef<std::path::Path>>(path: P) -> Result<Self, ConfigurationError> {
         let content = std::fs::read_to_string(path.as_ref()).map_err(|e| {
@@ -255,6 +594,217 @@ impl ConfigFile {
     pub fn viewer(&self) -> &ViewerConfig {
         &self.viewer
     }
+
+    /// Get the sync configuration
+    pub fn sync(&self) -> &SyncConfig {
+        &self.sync
+    }
+
+    /// Get the MCP server configuration
+    pub fn mcp(&self) -> &McpConfig {
+        &self.mcp
+    }
+
+    /// Set the sync remote URL, validating it first
+    pub fn with_remote(mut self, remote: Option<String>) -> Result<Self, ConfigurationError> {
+        if let Some(url) = &remote {
+            SyncConfig::validate_remote(url)?;
+        }
+        self.sync.remote = remote.map(|url| url.trim().to_string());
+        Ok(self)
+    }
+
+    /// Get a setting by its dotted key (see [`CONFIG_KEYS`]); None when an
+    /// optional setting is unset
+    pub fn get_value(&self, key: &str) -> Result<Option<String>, ConfigurationError> {
+        let value = match key {
+            "project.prefix" => Some(self.project.prefix.clone()),
+            "flight_levels.preset" => Some(self.flight_levels.preset_name().to_string()),
+            "flight_levels.initiatives_enabled" => {
+                Some(self.flight_levels.initiatives_enabled.to_string())
+            }
+            "viewer.default" => self.viewer.default.as_ref().map(|v| v.to_string()),
+            "viewer.suppress_proactive_ticket_opening" => {
+                Some(self.viewer.suppress_proactive_ticket_opening.to_string())
+            }
+            "viewer.background" => Some(self.viewer.background.to_string()),
+            "sync.remote" => self.sync.remote.clone(),
+            "sync.branch" => self.sync.branch.clone(),
+            "mcp.transport" => Some(self.mcp.transport.to_string()),
+            "mcp.host" => self.mcp.host.clone(),
+            "mcp.port" => self.mcp.port.map(|port| port.to_string()),
+            "mcp.read_only" => Some(self.mcp.read_only.to_string()),
+            _ => return Err(Self::unknown_key(key)),
+        };
+        Ok(value)
+    }
+
+    /// Set a setting by its dotted key (see [`CONFIG_KEYS`]), validating the
+    /// value first. `None` unsets an optional setting.
+    pub fn set_value(&mut self, key: &str, value: Option<&str>) -> Result<(), ConfigurationError> {
+        let value = value.map(str::trim);
+        let required = || {
+            value.ok_or_else(|| {
+                ConfigurationError::InvalidValue(format!("'{}' can't be unset", key))
+            })
+        };
+        let boolean = |value: &str| {
+            value.parse::<bool>().map_err(|_| {
+                ConfigurationError::InvalidValue(format!(
+                    "'{}' must be true or false, not '{}'",
+                    key, value
+                ))
+            })
+        };
+
+        match key {
+            "project.prefix" => {
+                let prefix = required()?.to_ascii_uppercase();
+                Self::validate_prefix(&prefix)?;
+                self.project.prefix = prefix;
+            }
+            "flight_levels.preset" => {
+                self.flight_levels = match required()? {
+                    "streamlined" => FlightLevelConfig::streamlined(),
+                    "direct" => FlightLevelConfig::direct(),
+                    other => {
+                        return Err(ConfigurationError::InvalidValue(format!(
+                            "'{}' is not a preset (expected streamlined or direct)",
+                            other
+                        )))
+                    }
+                };
+            }
+            "flight_levels.initiatives_enabled" => {
+                self.flight_levels = FlightLevelConfig::new(boolean(required()?)?)?;
+            }
+            "viewer.default" => {
+                self.viewer.default = value.map(str::parse).transpose()?;
+            }
+            "viewer.suppress_proactive_ticket_opening" => {
+                self.viewer.suppress_proactive_ticket_opening = boolean(required()?)?;
+            }
+            "viewer.background" => {
+                self.viewer.background = boolean(required()?)?;
+            }
+            "sync.remote" => {
+                let remote = value.map(str::to_string);
+                *self = std::mem::take(self).with_remote(remote)?;
+            }
+            "sync.branch" => {
+                if let Some(branch) = value {
+                    SyncConfig::validate_branch(branch)?;
+                }
+                self.sync.branch = value.map(str::to_string);
+            }
+            "mcp.transport" => {
+                self.mcp.transport = value.map(str::parse).transpose()?.unwrap_or_default();
+            }
+            "mcp.host" => {
+                self.mcp.host = value.filter(|host| !host.is_empty()).map(str::to_string);
+            }
+            "mcp.port" => {
+                self.mcp.port = value.map(McpConfig::parse_port).transpose()?;
+            }
+            "mcp.read_only" => {
+                self.mcp.read_only = boolean(required()?)?;
+            }
+            _ => return Err(Self::unknown_key(key)),
+        }
+        Ok(())
+    }
+
+    fn unknown_key(key: &str) -> ConfigurationError {
+        ConfigurationError::InvalidValue(format!(
+            "Unknown setting '{}'. Known settings: {}",
+            key,
+            CONFIG_KEYS.join(", ")
+        ))
+    }
+
+    /// Explain what is wrong with the contents of a config.toml, in plain
+    /// language. An empty list means the file is valid.
+    ///
+    /// Unknown sections and keys are reported too, since serde would
+    /// otherwise silently ignore a misspelled setting.
+    pub fn problems(content: &str) -> Vec<String> {
+        let table = match content.parse::<toml::Table>() {
+            Ok(table) => table,
+            Err(e) => return vec![format!("config.toml is not valid TOML: {}", e)],
+        };
+
+        let mut problems = Vec::new();
+        for (section, value) in &table {
+            let known: Vec<&str> = CONFIG_KEYS
+                .iter()
+                .filter_map(|key| key.strip_prefix(section.as_str())?.strip_prefix('.'))
+                .filter(|key| *key != "preset")
+                // Named remotes are a table of their own, checked below
+                .chain((section == "sync").then_some("remotes"))
+                .collect();
+            if known.is_empty() {
+                problems.push(format!("Unknown section [{}]; it is ignored", section));
+                continue;
+            }
+            let Some(entries) = value.as_table() else {
+                problems.push(format!("'{}' must be a [{}] section", section, section));
+                continue;
+            };
+            for key in entries.keys() {
+                if !known.contains(&key.as_str()) {
+                    problems.push(format!(
+                        "Unknown setting '{}.{}' is ignored; [{}] accepts {}",
+                        section,
+                        key,
+                        section,
+                        known.join(", ")
+                    ));
+                }
+            }
+        }
+
+        match toml::from_str::<ConfigFile>(content) {
+            Ok(config) => {
+                if let Err(e) = Self::validate_prefix(config.prefix()) {
+                    problems.push(format!("project.prefix '{}': {}", config.prefix(), e));
+                }
+                if let Some(remote) = &config.sync.remote {
+                    if let Err(e) = SyncConfig::validate_remote(remote) {
+                        problems.push(format!("sync.remote: {}", e));
+                    }
+                }
+                if let Some(branch) = &config.sync.branch {
+                    if let Err(e) = SyncConfig::validate_branch(branch) {
+                        problems.push(format!("sync.branch: {}", e));
+                    }
+                }
+                if config.mcp.port == Some(0) {
+                    problems.push("mcp.port: 0 is not a port (expected 1-65535)".to_string());
+                }
+                for (name, remote) in &config.sync.remotes {
+                    let mut checks = vec![
+                        SyncConfig::validate_name("Remote name", name),
+                        SyncConfig::validate_remote(&remote.url),
+                    ];
+                    if let Some(workspace) = &remote.workspace {
+                        checks.push(SyncConfig::validate_name("Workspace prefix", workspace));
+                    }
+                    if let Some(branch) = &remote.branch {
+                        checks.push(SyncConfig::validate_branch(branch));
+                    }
+                    for e in checks.into_iter().filter_map(Result::err) {
+                        problems.push(format!("sync.remotes.{}: {}", name, e));
+                    }
+                }
+            }
+            Err(e) => problems.push(format!(
+                "config.toml doesn't match the expected layout: {}",
+                e
+            )),
+        }
+
+        problems
+    }
 }
 
 impl Default for ConfigFile {
@@ -265,6 +815,8 @@ impl Default for ConfigFile {
             },
             flight_levels: FlightLevelConfig::streamlined(),
             viewer: ViewerConfig::default(),
+            sync: SyncConfig::default(),
+            mcp: McpConfig::default(),
         }
     }
 }
@@ -498,4 +1050,187 @@ initiatives_enabled = true
         assert_eq!(config.prefix(), "CUSTOM");
         assert_eq!(config.flight_levels(), &FlightLevelConfig::streamlined());
     }
+
+    #[test]
+    fn test_sync_remote_validation() {
+        assert!(SyncConfig::validate_remote("https://github.com/org/plans.git").is_ok());
+        assert!(SyncConfig::validate_remote("ssh://git@host/plans.git").is_ok());
+        assert!(SyncConfig::validate_remote("git@github.com:org/plans.git").is_ok());
+        assert!(SyncConfig::validate_remote("file:///srv/plans.git").is_ok());
+        assert!(SyncConfig::validate_remote("github.com/org/plans").is_err());
+        assert!(SyncConfig::validate_remote("git@github.com").is_err());
+        assert!(SyncConfig::validate_remote("https://").is_err());
+    }
+
+    #[test]
+    fn test_config_file_sync_remote_round_trip() {
+        use tempfile::NamedTempFile;
+
+        let config = ConfigFile::new("METIS".to_string(), FlightLevelConfig::direct())
+            .unwrap()
+            .with_remote(Some("git@github.com:org/plans.git".to_string()))
+            .unwrap();
+        let temp_file = NamedTempFile::new().unwrap();
+        config.save(temp_file.path()).unwrap();
+
+        let content = std::fs::read_to_string(temp_file.path()).unwrap();
+        assert!(content.contains("[sync]"));
+        assert_eq!(ConfigFile::load(temp_file.path()).unwrap(), config);
+
+        // No [sync] section is written when no remote is configured
+        let plain = ConfigFile::default();
+        plain.save(temp_file.path()).unwrap();
+        let content = std::fs::read_to_string(temp_file.path()).unwrap();
+        assert!(!content.contains("[sync]"));
+        assert!(plain.with_remote(Some("not a url".to_string())).is_err());
+    }
+
+    #[test]
+    fn test_sync_remotes() {
+        let mut sync = SyncConfig::default();
+        sync.add_remote("origin", "git@github.com:org/plans.git", None, Some("main"))
+            .unwrap();
+        sync.add_remote("ops", "https://github.com/org/ops.git", None, None)
+            .unwrap();
+        sync.add_remote(
+            "web",
+            "https://github.com/org/web.git",
+            Some("frontend"),
+            None,
+        )
+        .unwrap();
+        assert!(sync
+            .add_remote(
+                "origin",
+                "git@github.com:org/plans.git",
+                Some("plans"),
+                None
+            )
+            .is_err());
+        assert!(sync
+            .add_remote("Ops", "https://github.com/org/ops.git", None, None)
+            .is_err());
+        // Another remote already projects into "ops"
+        assert!(sync
+            .add_remote("ops2", "https://github.com/org/ops2.git", Some("ops"), None)
+            .is_err());
+
+        let entries = sync.entries();
+        assert_eq!(entries.len(), 3);
+        assert_eq!(entries[0].name, "origin");
+        assert_eq!(entries[0].branch.as_deref(), Some("main"));
+        assert_eq!(entries[1].workspace.as_deref(), Some("ops"));
+        assert_eq!(entries[2].workspace.as_deref(), Some("frontend"));
+
+        sync.set_branch("ops", Some("release/1.0")).unwrap();
+        assert_eq!(sync.remotes["ops"].branch.as_deref(), Some("release/1.0"));
+        assert!(sync.set_branch("ops", Some("bad branch")).is_err());
+        assert!(sync.set_branch("nope", Some("main")).is_err());
+
+        sync.remove_remote("origin").unwrap();
+        sync.remove_remote("web").unwrap();
+        assert!(sync.remove_remote("web").is_err());
+        assert_eq!(sync.remote, None);
+        assert_eq!(sync.remotes.len(), 1);
+
+        let mut config = ConfigFile::default();
+        config.sync = sync;
+        let content = toml::to_string_pretty(&config).unwrap();
+        assert!(content.contains("[sync.remotes.ops]"));
+        assert!(ConfigFile::problems(&content).is_empty());
+        assert_eq!(toml::from_str::<ConfigFile>(&content).unwrap(), config);
+    }
+
+    #[test]
+    fn test_config_file_get_and_set_value() {
+        let mut config = ConfigFile::default();
+        assert_eq!(
+            config.get_value("project.prefix").unwrap().as_deref(),
+            Some("PROJ")
+        );
+        assert_eq!(config.get_value("sync.remote").unwrap(), None);
+        assert!(config.get_value("project.name").is_err());
+
+        config.set_value("project.prefix", Some("acme")).unwrap();
+        config
+            .set_value("flight_levels.preset", Some("direct"))
+            .unwrap();
+        config.set_value("viewer.default", Some("code")).unwrap();
+        config
+            .set_value("sync.remote", Some("git@github.com:org/plans.git"))
+            .unwrap();
+        assert_eq!(config.prefix(), "ACME");
+        assert!(!config.flight_levels().initiatives_enabled);
+        assert_eq!(config.viewer().default, Some(ViewerBackend::Code));
+        assert_eq!(
+            config.get_value("sync.remote").unwrap().as_deref(),
+            Some("git@github.com:org/plans.git")
+        );
+
+        config.set_value("sync.remote", None).unwrap();
+        assert_eq!(config.sync().remote, None);
+        assert!(config.set_value("project.prefix", None).is_err());
+        assert!(config.set_value("project.prefix", Some("A1")).is_err());
+        assert!(config
+            .set_value("viewer.background", Some("maybe"))
+            .is_err());
+        assert!(config.set_value("viewer.default", Some("emacs")).is_err());
+    }
+
+    #[test]
+    fn test_mcp_config() {
+        let mut config = ConfigFile::default();
+        assert_eq!(config.mcp().transport, McpTransport::Stdio);
+        assert_eq!(config.mcp().host(), "127.0.0.1");
+        assert_eq!(config.mcp().port(), 8080);
+
+        config.set_value("mcp.transport", Some("http")).unwrap();
+        config.set_value("mcp.host", Some("0.0.0.0")).unwrap();
+        config.set_value("mcp.port", Some("3917")).unwrap();
+        config.set_value("mcp.read_only", Some("true")).unwrap();
+        assert_eq!(config.mcp().transport, McpTransport::Http);
+        assert!(config.mcp().read_only);
+        assert_eq!(
+            config.get_value("mcp.port").unwrap().as_deref(),
+            Some("3917")
+        );
+        let toml = toml::to_string_pretty(&config).unwrap();
+        assert!(toml.contains("[mcp]"), "{}", toml);
+        assert!(ConfigFile::problems(&toml).is_empty());
+        let loaded: ConfigFile = toml::from_str(&toml).unwrap();
+        assert_eq!(loaded.mcp(), config.mcp());
+
+        assert!(config.set_value("mcp.transport", Some("sse")).is_err());
+        assert!(config.set_value("mcp.port", Some("0")).is_err());
+        assert!(config.set_value("mcp.port", Some("70000")).is_err());
+        assert!(config.set_value("mcp.read_only", Some("yes")).is_err());
+        assert!(config.set_value("mcp.read_only", None).is_err());
+
+        // Back to the defaults, the section is left out of config.toml
+        config.set_value("mcp.transport", None).unwrap();
+        config.set_value("mcp.host", None).unwrap();
+        config.set_value("mcp.port", None).unwrap();
+        config.set_value("mcp.read_only", Some("false")).unwrap();
+        assert!(!toml::to_string_pretty(&config).unwrap().contains("[mcp]"));
+    }
+
+    #[test]
+    fn test_config_file_problems() {
+        let valid = toml::to_string_pretty(&ConfigFile::default()).unwrap();
+        assert!(ConfigFile::problems(&valid).is_empty());
+
+        assert!(ConfigFile::problems("[project\nprefix = ")[0].contains("not valid TOML"));
+
+        let problems = ConfigFile::problems(
+            "[project]\nprefix = \"p1\"\n\n[flight_levels]\ninitiatives_enabled = true\n\n\
+             [viewer]\ndefualt = \"code\"\n\n[workflows]\n",
+        );
+        assert_eq!(problems.len(), 3, "{:?}", problems);
+        assert!(problems.iter().any(|p| p.contains("viewer.defualt")));
+        assert!(problems.iter().any(|p| p.contains("[workflows]")));
+        assert!(problems.iter().any(|p| p.contains("project.prefix")));
+
+        let problems = ConfigFile::problems("[project]\nprefix = \"ACME\"\n");
+        assert!(problems[0].contains("expected layout"));
+    }
 }
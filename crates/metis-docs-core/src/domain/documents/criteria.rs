@@ -0,0 +1,155 @@
+//! Exit and acceptance criteria: the checkbox lists under a
+//! `## Exit Criteria` or `## Acceptance Criteria` heading.
+
+/// One checkbox from a document's criteria section
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Criterion {
+    /// Position in the criteria list, starting at 1
+    pub number: usize,
+    pub text: String,
+    pub checked: bool,
+    /// Index of the line in the document
+    line: usize,
+}
+
+/// Criteria checkboxes in document order. Criteria sections end at the next
+/// heading of the same or a higher level; checkboxes in code blocks are
+/// ignored.
+pub fn parse_criteria(content: &str) -> Vec<Criterion> {
+    let mut criteria = Vec::new();
+    let mut section_level: Option<usize> = None;
+    let mut in_code = false;
+
+    for (line_index, line) in content.lines().enumerate() {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_code = !in_code;
+            continue;
+        }
+        if in_code {
+            continue;
+        }
+
+        if let Some((level, title)) = heading(trimmed) {
+            if section_level.is_some_and(|section| level <= section) {
+                section_level = None;
+            }
+            if is_criteria_heading(title) {
+                section_level = Some(level);
+            }
+            continue;
+        }
+
+        if section_level.is_some() {
+            if let Some((checked, text)) = checkbox(trimmed) {
+                criteria.push(Criterion {
+                    number: criteria.len() + 1,
+                    text: text.to_string(),
+                    checked,
+                    line: line_index,
+                });
+            }
+        }
+    }
+
+    criteria
+}
+
+/// Whether a document has criteria and all of them are checked
+pub fn all_met(criteria: &[Criterion]) -> bool {
+    !criteria.is_empty() && criteria.iter().all(|criterion| criterion.checked)
+}
+
+/// Check or uncheck criterion `number`, keeping everything else as is.
+/// `None` if there is no such criterion.
+pub fn set_criterion(content: &str, number: usize, checked: bool) -> Option<String> {
+    let criterion = parse_criteria(content)
+        .into_iter()
+        .find(|criterion| criterion.number == number)?;
+
+    let mut updated = String::with_capacity(content.len());
+    for (index, line) in content.split_inclusive('\n').enumerate() {
+        if index == criterion.line {
+            let box_start = line.find('[')?;
+            updated.push_str(&line[..box_start]);
+            updated.push_str(if checked { "[x]" } else { "[ ]" });
+            updated.push_str(&line[box_start + 3..]);
+        } else {
+            updated.push_str(line);
+        }
+    }
+    Some(updated)
+}
+
+/// Heading level and title of a markdown heading line
+fn heading(line: &str) -> Option<(usize, &str)> {
+    let level = line.chars().take_while(|c| *c == '#').count();
+    let title = line[level..].strip_prefix(' ')?;
+    (1..=6).contains(&level).then_some((level, title.trim()))
+}
+
+fn is_criteria_heading(title: &str) -> bool {
+    let title = title.to_lowercase();
+    title == "exit criteria" || title == "acceptance criteria"
+}
+
+/// Whether a line is a `- [ ]` or `- [x]` list item, and its text
+fn checkbox(line: &str) -> Option<(bool, &str)> {
+    let rest = line
+        .strip_prefix("- ")
+        .or_else(|| line.strip_prefix("* "))
+        .or_else(|| line.strip_prefix("+ "))?;
+    let checked = match rest.get(..3)? {
+        "[ ]" => false,
+        "[x]" | "[X]" => true,
+        _ => return None,
+    };
+    Some((checked, rest[3..].trim()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONTENT: &str = "---\nexit_criteria_met: false\n---\n\n# Ship it\n\n## Tasks\n\n- [ ] Not a criterion\n\n## Acceptance Criteria\n\n- [x] Implementation is complete\n- [ ] Tests pass\n  * [ ] Docs updated\n\n```\n- [ ] In a code block\n```\n\n### Notes\n\n- [ ] Nested under the criteria section\n\n## Follow-up\n\n- [ ] Not a criterion either\n";
+
+    #[test]
+    fn test_parse_criteria() {
+        let criteria = parse_criteria(CONTENT);
+        let texts: Vec<(usize, &str, bool)> = criteria
+            .iter()
+            .map(|criterion| (criterion.number, criterion.text.as_str(), criterion.checked))
+            .collect();
+        assert_eq!(
+            texts,
+            vec![
+                (1, "Implementation is complete", true),
+                (2, "Tests pass", false),
+                (3, "Docs updated", false),
+                (4, "Nested under the criteria section", false),
+            ]
+        );
+        assert!(!all_met(&criteria));
+        assert!(!all_met(&[]));
+        assert!(parse_criteria("## Exit Criteria\n\nNone yet\n").is_empty());
+    }
+
+    #[test]
+    fn test_set_criterion() {
+        let updated = set_criterion(CONTENT, 3, true).unwrap();
+        assert!(updated.contains("  * [x] Docs updated\n"));
+        assert_eq!(updated.len(), CONTENT.len());
+
+        let mut content = CONTENT.to_string();
+        for number in 1..=4 {
+            content = set_criterion(&content, number, true).unwrap();
+        }
+        assert!(all_met(&parse_criteria(&content)));
+        // Checkboxes outside the criteria sections are untouched
+        assert!(content.contains("- [ ] Not a criterion\n"));
+
+        let unchecked = set_criterion(&content, 1, false).unwrap();
+        assert!(unchecked.contains("- [ ] Implementation is complete\n"));
+        assert!(set_criterion(CONTENT, 5, true).is_none());
+    }
+}
@@ -138,6 +138,206 @@ impl FrontmatterParser {
     }
 }
 
+/// Set a top-level list field in a document's frontmatter, written in flow
+/// style (`key: [A, B]`). See [`replace_frontmatter_field`].
+pub fn set_frontmatter_list(content: &str, key: &str, values: &[String]) -> String {
+    let line = format!("{}: [{}]", key, values.join(", "));
+    replace_frontmatter_field(content, key, Some(&line))
+}
+
+/// Set a top-level list field in a document's frontmatter, written in block
+/// style with one double-quoted item per line, as the templates write `tags`.
+/// An empty list is written as `key: []`. See [`replace_frontmatter_field`].
+pub fn set_frontmatter_block_list(content: &str, key: &str, values: &[String]) -> String {
+    if values.is_empty() {
+        return set_frontmatter_list(content, key, values);
+    }
+    let newline = if content.contains("\r\n") {
+        "\r\n"
+    } else {
+        "\n"
+    };
+    let mut line = format!("{}:", key);
+    for value in values {
+        line.push_str(newline);
+        line.push_str(&format!(
+            "  - \"{}\"",
+            value.replace('\\', "\\\\").replace('"', "\\\"")
+        ));
+    }
+    replace_frontmatter_field(content, key, Some(&line))
+}
+
+/// Set a top-level string field in a document's frontmatter, written as a
+/// double-quoted YAML string, or remove the field when `value` is `None`.
+/// See [`replace_frontmatter_field`].
+pub fn set_frontmatter_value(content: &str, key: &str, value: Option<&str>) -> String {
+    let line = value.map(|value| {
+        format!(
+            "{}: \"{}\"",
+            key,
+            value.replace('\\', "\\\\").replace('"', "\\\"")
+        )
+    });
+    replace_frontmatter_field(content, key, line.as_deref())
+}
+
+/// Replace the top-level `key` of a document's frontmatter with `line`, or
+/// remove it when `line` is `None`. Indented or block-list lines under an
+/// empty-valued key are replaced along with it, a missing key is added at
+/// the end of the frontmatter, and the rest of the document is left
+/// byte-for-byte unchanged.
+pub fn replace_frontmatter_field(content: &str, key: &str, line: Option<&str>) -> String {
+    let mut result = String::with_capacity(content.len() + line.map_or(0, str::len));
+    let mut delimiters = 0;
+    let mut replaced = false;
+    let mut skipping_nested = false;
+
+    for raw in content.split_inclusive('\n') {
+        let trimmed = raw.trim_end_matches(['\r', '\n']);
+        let ending = &raw[trimmed.len()..];
+        if skipping_nested {
+            let nested = trimmed.starts_with(char::is_whitespace) && !trimmed.trim().is_empty();
+            if nested || (trimmed.starts_with('-') && trimmed != "---") {
+                continue;
+            }
+            skipping_nested = false;
+        }
+
+        if trimmed == "---" {
+            delimiters += 1;
+            if delimiters == 2 && !replaced {
+                if let Some(line) = line {
+                    result.push_str(line);
+                    result.push_str(if ending.is_empty() { "\n" } else { ending });
+                }
+                replaced = true;
+            }
+        } else if delimiters == 1 && !replaced {
+            if let Some(value) = trimmed
+                .strip_prefix(key)
+                .and_then(|rest| rest.strip_prefix(':'))
+            {
+                if let Some(line) = line {
+                    result.push_str(line);
+                    result.push_str(ending);
+                }
+                replaced = true;
+                skipping_nested = value.trim().is_empty();
+                continue;
+            }
+        }
+        result.push_str(raw);
+    }
+
+    result
+}
+
+/// Copy top-level frontmatter fields that `original` has and `updated`
+/// lacks into `updated`. Documents are rewritten from templates that only
+/// know their own fields, so this keeps custom fields such as `assignee` or
+/// `supersedes` when a document is re-rendered.
+pub fn carry_over_frontmatter_fields(original: &str, updated: &str) -> String {
+    let present: Vec<String> = frontmatter_fields(updated)
+        .into_iter()
+        .map(|(key, _)| key)
+        .collect();
+    frontmatter_fields(original)
+        .into_iter()
+        .filter(|(key, _)| !present.contains(key))
+        .fold(updated.to_string(), |content, (key, block)| {
+            replace_frontmatter_field(&content, &key, Some(&block))
+        })
+}
+
+/// Replace the body of a document, everything after the frontmatter, with
+/// `body`, leaving the frontmatter byte-for-byte unchanged. The body is
+/// separated from the frontmatter by one blank line and ends with a
+/// newline. A document without frontmatter is replaced entirely; `None` if
+/// its frontmatter is never closed, as the body can't be told apart from it.
+pub fn replace_document_body(content: &str, body: &str) -> Option<String> {
+    let newline = if content.contains("\r\n") {
+        "\r\n"
+    } else {
+        "\n"
+    };
+    let mut frontmatter_len = 0;
+    let mut delimiters = 0;
+    for raw in content.split_inclusive('\n') {
+        if delimiters == 0 && raw.trim_end_matches(['\r', '\n']) != "---" {
+            break;
+        }
+        frontmatter_len += raw.len();
+        if raw.trim_end_matches(['\r', '\n']) == "---" {
+            delimiters += 1;
+            if delimiters == 2 {
+                break;
+            }
+        }
+    }
+
+    let body = body.trim_matches(['\r', '\n']);
+    match delimiters {
+        0 => return Some(format!("{}{}", body, newline)),
+        1 => return None,
+        _ => {}
+    }
+    let frontmatter = &content[..frontmatter_len];
+    let separator = if frontmatter.ends_with('\n') {
+        ""
+    } else {
+        newline
+    };
+    if body.is_empty() {
+        return Some(format!("{}{}", frontmatter, separator));
+    }
+    Some(format!(
+        "{}{}{}{}{}",
+        frontmatter, separator, newline, body, newline
+    ))
+}
+
+/// Top-level frontmatter fields with their full text, including indented or
+/// block-list lines under them, without the final line ending
+fn frontmatter_fields(content: &str) -> Vec<(String, String)> {
+    let newline = if content.contains("\r\n") {
+        "\r\n"
+    } else {
+        "\n"
+    };
+    let mut fields: Vec<(String, String)> = Vec::new();
+    let mut delimiters = 0;
+
+    for raw in content.split_inclusive('\n') {
+        let trimmed = raw.trim_end_matches(['\r', '\n']);
+        if trimmed == "---" {
+            delimiters += 1;
+            if delimiters == 2 {
+                break;
+            }
+            continue;
+        }
+        if delimiters != 1 || trimmed.trim().is_empty() {
+            continue;
+        }
+        let nested = trimmed.starts_with(char::is_whitespace) || trimmed.starts_with('-');
+        match (nested, fields.last_mut()) {
+            (true, Some((_, block))) => {
+                block.push_str(newline);
+                block.push_str(trimmed);
+            }
+            (true, None) => {}
+            (false, _) => {
+                if let Some((key, _)) = trimmed.split_once(':') {
+                    fields.push((key.trim().to_string(), trimmed.to_string()));
+                }
+            }
+        }
+    }
+
+    fields
+}
+
 #[cfg(test)]
 mod tests {
     use super::super::types::{Phase, Tag};
@@ -291,4 +491,131 @@ mod tests {
         assert!(tags.contains(&Tag::Phase(Phase::Draft)));
         assert!(tags.contains(&Tag::Label("valid-tag".to_string())));
     }
+
+    #[test]
+    fn test_set_frontmatter_block_list() {
+        let values = vec!["#task".to_string(), "#phase/todo".to_string()];
+
+        let content = "---\ntags:\n  - \"#task\"\nid: a\n---\nbody\n";
+        assert_eq!(
+            set_frontmatter_block_list(content, "tags", &values),
+            "---\ntags:\n  - \"#task\"\n  - \"#phase/todo\"\nid: a\n---\nbody\n"
+        );
+
+        // Line endings follow the document
+        let content = "---\r\nid: a\r\n---\r\n";
+        assert_eq!(
+            set_frontmatter_block_list(content, "tags", &values[..1]),
+            "---\r\nid: a\r\ntags:\r\n  - \"#task\"\r\n---\r\n"
+        );
+
+        let content = "---\ntags:\n  - \"#task\"\n---\n";
+        assert_eq!(
+            set_frontmatter_block_list(content, "tags", &[]),
+            "---\ntags: []\n---\n"
+        );
+    }
+
+    #[test]
+    fn test_set_frontmatter_list() {
+        let values = vec!["TEST-T-0001".to_string(), "TEST-T-0002".to_string()];
+
+        // Flow-style value is replaced in place
+        let content = "---\nid: a\nblocked_by: []\narchived: false\n---\n\nblocked_by: body\n";
+        assert_eq!(
+            set_frontmatter_list(content, "blocked_by", &values),
+            "---\nid: a\nblocked_by: [TEST-T-0001, TEST-T-0002]\narchived: false\n---\n\nblocked_by: body\n"
+        );
+
+        // Block-style items are replaced too
+        let content = "---\nreferences:\n  - TEST-A-0001\n- TEST-A-0002\nid: a\n---\nbody\n";
+        assert_eq!(
+            set_frontmatter_list(content, "references", &[]),
+            "---\nreferences: []\nid: a\n---\nbody\n"
+        );
+
+        // A missing key is added before the closing delimiter
+        let content = "---\r\nid: a\r\n---\r\nbody\r\n";
+        assert_eq!(
+            set_frontmatter_list(content, "references", &values[..1]),
+            "---\r\nid: a\r\nreferences: [TEST-T-0001]\r\n---\r\nbody\r\n"
+        );
+
+        // Keys that merely share a prefix are left alone
+        let content = "---\nblocked_by_note: x\n---\n";
+        assert_eq!(
+            set_frontmatter_list(content, "blocked_by", &[]),
+            "---\nblocked_by_note: x\nblocked_by: []\n---\n"
+        );
+    }
+
+    #[test]
+    fn test_set_frontmatter_value() {
+        let content = "---\nid: a\nassignee: bob\narchived: false\n---\n\nassignee: body\n";
+        assert_eq!(
+            set_frontmatter_value(content, "assignee", Some("alice")),
+            "---\nid: a\nassignee: \"alice\"\narchived: false\n---\n\nassignee: body\n"
+        );
+        assert_eq!(
+            set_frontmatter_value(content, "assignee", None),
+            "---\nid: a\narchived: false\n---\n\nassignee: body\n"
+        );
+
+        // Missing keys are added, and removing one that isn't there is a no-op
+        let content = "---\nid: a\n---\n";
+        assert_eq!(
+            set_frontmatter_value(content, "due_date", Some("2025-03-01")),
+            "---\nid: a\ndue_date: \"2025-03-01\"\n---\n"
+        );
+        assert_eq!(set_frontmatter_value(content, "due_date", None), content);
+
+        // Quotes are escaped so the value stays a single YAML string
+        assert_eq!(
+            set_frontmatter_value(content, "owner", Some("a \"b\"")),
+            "---\nid: a\nowner: \"a \\\"b\\\"\"\n---\n"
+        );
+    }
+
+    #[test]
+    fn test_carry_over_frontmatter_fields() {
+        let original = "---\nid: a\nphase: draft\nassignee: \"alice\"\nsupersedes:\n  - TEST-A-0001\n---\n\nBody\n";
+        let updated = "---\nid: a\nphase: decided\n---\n\nBody\n";
+        assert_eq!(
+            carry_over_frontmatter_fields(original, updated),
+            "---\nid: a\nphase: decided\nassignee: \"alice\"\nsupersedes:\n  - TEST-A-0001\n---\n\nBody\n"
+        );
+
+        // Fields the updated document still has keep their new values
+        assert_eq!(carry_over_frontmatter_fields(updated, original), original);
+    }
+
+    #[test]
+    fn test_replace_document_body() {
+        let content = "---\nid: a\ntags:\n  - \"#phase/todo\"\n---\n\n# Old\n\nBody\n";
+        assert_eq!(
+            replace_document_body(content, "# New\n\nText").unwrap(),
+            "---\nid: a\ntags:\n  - \"#phase/todo\"\n---\n\n# New\n\nText\n"
+        );
+
+        // A body that looks like frontmatter stays in the body
+        assert_eq!(
+            replace_document_body(content, "---\nid: b\n---\n").unwrap(),
+            "---\nid: a\ntags:\n  - \"#phase/todo\"\n---\n\n---\nid: b\n---\n"
+        );
+
+        // Line endings follow the document
+        assert_eq!(
+            replace_document_body("---\r\nid: a\r\n---\r\nOld\r\n", "New").unwrap(),
+            "---\r\nid: a\r\n---\r\n\r\nNew\r\n"
+        );
+        assert_eq!(
+            replace_document_body("---\nid: a\n---", "").unwrap(),
+            "---\nid: a\n---\n"
+        );
+        assert_eq!(
+            replace_document_body("No frontmatter\n", "New").unwrap(),
+            "New\n"
+        );
+        assert_eq!(replace_document_body("---\nid: a\nBody\n", "New"), None);
+    }
 }
@@ -1,4 +1,5 @@
 pub mod content;
+pub mod criteria;
 pub mod factory;
 pub mod helpers;
 pub mod metadata;
@@ -307,7 +307,8 @@ impl Document for Specification {
     }
 
     fn exit_criteria_met(&self) -> bool {
-        false
+        // Kept in step with the criteria checkboxes by `CriteriaService`
+        self.metadata().exit_criteria_met
     }
 
     fn template(&self) -> DocumentTemplate {
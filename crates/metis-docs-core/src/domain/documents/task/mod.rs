@@ -386,10 +386,8 @@ impl Document for Task {
     }
 
     fn exit_criteria_met(&self) -> bool {
-        // Check if all acceptance criteria checkboxes are checked
-        // This would typically parse the content for checkbox completion
-        // For now, return false as a placeholder
-        false
+        // Kept in step with the criteria checkboxes by `CriteriaService`
+        self.metadata().exit_criteria_met
     }
 
     fn template(&self) -> DocumentTemplate {
@@ -268,6 +268,46 @@ impl DocumentType {
     }
 }
 
+/// Kind of relationship edge between two documents.
+///
+/// Edges are directed from `from` to `to`:
+/// - `Parent`: `from` is a child of `to`
+/// - `Blocks`: `from` blocks `to`
+/// - `References`: `from` references `to`
+/// - `Supersedes`: `from` supersedes `to`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum RelationshipKind {
+    Parent,
+    Blocks,
+    References,
+    Supersedes,
+}
+
+impl fmt::Display for RelationshipKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RelationshipKind::Parent => write!(f, "parent"),
+            RelationshipKind::Blocks => write!(f, "blocks"),
+            RelationshipKind::References => write!(f, "references"),
+            RelationshipKind::Supersedes => write!(f, "supersedes"),
+        }
+    }
+}
+
+impl FromStr for RelationshipKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "parent" => Ok(RelationshipKind::Parent),
+            "blocks" => Ok(RelationshipKind::Blocks),
+            "references" => Ok(RelationshipKind::References),
+            "supersedes" => Ok(RelationshipKind::Supersedes),
+            _ => Err(format!("Unknown relationship kind: {}", s)),
+        }
+    }
+}
+
 /// Document phase/status
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Phase {
@@ -570,6 +610,19 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_relationship_kind_roundtrip() {
+        for kind in [
+            RelationshipKind::Parent,
+            RelationshipKind::Blocks,
+            RelationshipKind::References,
+            RelationshipKind::Supersedes,
+        ] {
+            assert_eq!(kind.to_string().parse::<RelationshipKind>().unwrap(), kind);
+        }
+        assert!("depends".parse::<RelationshipKind>().is_err());
+    }
+
     #[test]
     fn test_document_type_phase_sequence() {
         assert_eq!(
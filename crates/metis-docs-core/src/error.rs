@@ -66,4 +66,10 @@ pub enum MetisError {
 
     #[error("Configuration error: {0}")]
     ConfigurationError(#[from] crate::domain::configuration::ConfigurationError),
+
+    #[error("Database schema version {database_version} is newer than this version of metis supports ({supported_version}). Upgrade metis to use this workspace.")]
+    SchemaTooNew {
+        database_version: String,
+        supported_version: String,
+    },
 }
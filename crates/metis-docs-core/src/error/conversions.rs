@@ -134,7 +134,9 @@ impl UserFriendlyError for MetisError {
             | MetisError::InvalidDocumentType { .. }
             | MetisError::TemplateNotFound { .. } => ErrorCategory::Document,
 
-            MetisError::Database(_) | MetisError::Connection(_) => ErrorCategory::Database,
+            MetisError::Database(_)
+            | MetisError::Connection(_)
+            | MetisError::SchemaTooNew { .. } => ErrorCategory::Database,
 
             MetisError::Io(_) => ErrorCategory::FileSystem,
 
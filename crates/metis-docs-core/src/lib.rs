@@ -19,7 +19,7 @@ pub use domain::documents::{
     specification::Specification,
     task::Task,
     traits::{Document, DocumentValidationError},
-    types::{DocumentId, DocumentType, Phase, Tag},
+    types::{DocumentId, DocumentType, Phase, RelationshipKind, Tag},
     vision::Vision,
 };
 pub use error::{MetisError, Result};
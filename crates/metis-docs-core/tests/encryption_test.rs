@@ -0,0 +1,47 @@
+//! Encrypted workspace databases; run with `cargo test --features encryption`
+#![cfg(feature = "encryption")]
+
+use diesel::prelude::*;
+use metis_core::dal::database::encryption::KEY_ENV_VAR;
+use metis_core::dal::Database;
+use tempfile::tempdir;
+
+#[test]
+fn test_encrypted_database_opens_with_its_key() {
+    let temp_dir = tempdir().unwrap();
+    let db_path = temp_dir.path().join("metis.db");
+    let db_path = db_path.to_str().unwrap();
+
+    // Creating the database with a key set encrypts it
+    std::env::set_var(KEY_ENV_VAR, "correct horse battery staple");
+    let db = Database::new(db_path).expect("Failed to create encrypted database");
+    assert!(db
+        .repository()
+        .unwrap()
+        .find_by_type("vision")
+        .unwrap()
+        .is_empty());
+
+    // The key is looked up when the database is opened, not per connection
+    std::env::remove_var(KEY_ENV_VAR);
+    assert!(db
+        .repository()
+        .unwrap()
+        .find_by_type("vision")
+        .unwrap()
+        .is_empty());
+
+    // Without the key the file isn't readable at all
+    let mut connection = SqliteConnection::establish(db_path).unwrap();
+    assert!(diesel::sql_query("SELECT count(*) FROM sqlite_master")
+        .execute(&mut connection)
+        .is_err());
+
+    // A wrong key is reported as such
+    std::env::set_var(KEY_ENV_VAR, "wrong key");
+    let error = Database::new(db_path)
+        .err()
+        .expect("Opened with a wrong key");
+    assert!(error.to_string().contains("Unable to decrypt"));
+    std::env::remove_var(KEY_ENV_VAR);
+}
@@ -3,15 +3,24 @@ use std::path::PathBuf;
 mod services;
 
 use services::{
-    archive_document, auto_install_cli, create_document, get_app_version, get_available_parents,
-    get_cli_install_status, get_project_config, initialize_project, install_cli,
-    install_cli_elevated, list_documents, load_project, read_document, search_documents,
-    sync_project, transition_phase, uninstall_cli, update_document,
+    add_document_tag, archive_document, auto_install_cli, bulk_update, create_document,
+    detect_launch_workspace, forget_workspace, get_app_version, get_available_parents,
+    get_board_settings, get_cli_install_status, get_document_detail, get_document_properties,
+    get_document_relations, get_document_tags, get_project_config, get_session_state,
+    initialize_project, install_cli, install_cli_elevated, list_archived_documents, list_documents,
+    list_recent_workspaces, list_workspace_tags, load_project, move_document, read_document,
+    remote_sync, remove_document_tag, save_session_state, search_documents, set_criterion,
+    set_document_property, sync_project, transition_phase, unarchive_document, uninstall_cli,
+    update_document, update_document_body,
 };
 
 // Application state
 pub struct AppState {
     current_project: Option<PathBuf>,
+    /// Set by `--read-only` on the command line, for every project opened
+    read_only_flag: bool,
+    /// Why the current project can't be changed, if it can't
+    read_only: Option<String>,
 }
 
 pub fn run() {
@@ -37,20 +46,43 @@ pub fn run() {
         .plugin(tauri_plugin_shell::init())
         .manage(std::sync::Mutex::new(AppState {
             current_project: None,
+            read_only_flag: std::env::args().skip(1).any(|arg| arg == "--read-only"),
+            read_only: None,
         }))
         .invoke_handler(tauri::generate_handler![
             initialize_project,
             load_project,
+            list_recent_workspaces,
+            forget_workspace,
+            detect_launch_workspace,
             list_documents,
+            list_archived_documents,
             read_document,
+            get_document_detail,
+            get_document_relations,
             search_documents,
             get_available_parents,
             create_document,
             update_document,
+            update_document_body,
+            get_document_properties,
+            set_document_property,
+            set_criterion,
+            get_document_tags,
+            list_workspace_tags,
+            add_document_tag,
+            remove_document_tag,
             archive_document,
+            unarchive_document,
+            bulk_update,
             transition_phase,
+            move_document,
             get_project_config,
+            get_board_settings,
+            get_session_state,
+            save_session_state,
             sync_project,
+            remote_sync,
             get_app_version,
             // CLI installer commands
             get_cli_install_status,
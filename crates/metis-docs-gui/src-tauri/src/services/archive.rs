@@ -1,3 +1,4 @@
+use crate::services::project::ensure_writable;
 use crate::AppState;
 use metis_core::{
     application::services::{workspace::ArchiveService, DatabaseService},
@@ -25,6 +26,7 @@ pub async fn archive_document(
     state: State<'_, std::sync::Mutex<AppState>>,
     short_code: String,
 ) -> Result<ArchiveResult, String> {
+    ensure_writable(&state)?;
     let project_path = {
         let app_state = state
             .lock()
@@ -92,6 +94,73 @@ pub async fn archive_document(
     })
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UnarchiveResult {
+    pub total_restored: usize,
+    pub restored_documents: Vec<ArchivedDocument>,
+}
+
+/// Restore an archived document, and for an initiative its tasks, to the
+/// boards
+#[tauri::command]
+pub async fn unarchive_document(
+    state: State<'_, std::sync::Mutex<AppState>>,
+    short_code: String,
+) -> Result<UnarchiveResult, String> {
+    ensure_writable(&state)?;
+    let project_path = {
+        let app_state = state
+            .lock()
+            .map_err(|e| format!("Failed to lock state: {}", e))?;
+        app_state
+            .current_project
+            .as_ref()
+            .ok_or("No project loaded")?
+            .clone()
+    };
+
+    let metis_dir = project_path.join(".metis");
+    let db_path = metis_dir.join("metis.db");
+
+    // Sync first so the database knows where the archived files are now
+    let database = Database::new(db_path.to_str().unwrap())
+        .map_err(|e| format!("Failed to open database for sync: {}", e))?;
+    Application::new(database)
+        .sync_directory(&metis_dir)
+        .await
+        .map_err(|e| format!("Failed to sync workspace: {}", e))?;
+
+    let db = Database::new(&db_path.to_string_lossy())
+        .map_err(|e| format!("Database initialization failed: {}", e))?;
+    let mut db_service = DatabaseService::new(db.into_repository());
+    let result = ArchiveService::new(&metis_dir)
+        .unarchive_document_by_short_code(&short_code, &mut db_service)
+        .await
+        .map_err(|e| format!("Failed to restore {}: {}", short_code, e))?;
+
+    // Auto-sync after restoring to update database
+    let database = Database::new(db_path.to_str().unwrap())
+        .map_err(|e| format!("Failed to open database for sync: {}", e))?;
+    Application::new(database)
+        .sync_directory(&metis_dir)
+        .await
+        .map_err(|e| format!("Failed to sync workspace: {}", e))?;
+
+    Ok(UnarchiveResult {
+        total_restored: result.total_restored,
+        restored_documents: result
+            .restored_documents
+            .iter()
+            .map(|doc| ArchivedDocument {
+                document_id: doc.document_id.clone(),
+                document_type: format!("{:?}", doc.document_type),
+                original_path: doc.original_path.to_string_lossy().to_string(),
+                archived_path: doc.archived_path.to_string_lossy().to_string(),
+            })
+            .collect(),
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
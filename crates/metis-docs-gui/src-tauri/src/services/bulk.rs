@@ -0,0 +1,152 @@
+use crate::services::project::ensure_writable;
+use crate::services::transition::parse_phase;
+use crate::AppState;
+use metis_core::{
+    application::services::{
+        workspace::{ArchiveService, PhaseTransitionService, PropertyService, TagService},
+        DatabaseService,
+    },
+    Application, Database,
+};
+use serde::{Deserialize, Serialize};
+use tauri::State;
+
+/// An operation applied to every marked card on a board
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum BulkAction {
+    Transition { phase: String },
+    Archive,
+    Tag { tag: String },
+    Assign { assignee: Option<String> },
+}
+
+/// The outcome for one card
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BulkItemResult {
+    pub short_code: String,
+    pub ok: bool,
+    pub message: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BulkResult {
+    pub succeeded: usize,
+    pub failed: usize,
+    pub items: Vec<BulkItemResult>,
+}
+
+/// Apply one action to several documents. Each document is handled on its
+/// own, so one failure doesn't stop the rest; the workspace is synced once
+/// at the end.
+#[tauri::command]
+pub async fn bulk_update(
+    state: State<'_, std::sync::Mutex<AppState>>,
+    short_codes: Vec<String>,
+    action: BulkAction,
+) -> Result<BulkResult, String> {
+    ensure_writable(&state)?;
+    let project_path = {
+        let app_state = state
+            .lock()
+            .map_err(|e| format!("Failed to lock state: {}", e))?;
+        app_state
+            .current_project
+            .as_ref()
+            .ok_or("No project loaded")?
+            .clone()
+    };
+
+    let metis_dir = project_path.join(".metis");
+    let db_path = metis_dir.join("metis.db");
+
+    let db = Database::new(&db_path.to_string_lossy())
+        .map_err(|e| format!("Database initialization failed: {}", e))?;
+    let mut db_service = DatabaseService::new(db.into_repository());
+
+    let mut items = Vec::with_capacity(short_codes.len());
+    for short_code in short_codes {
+        let outcome = match &action {
+            BulkAction::Transition { phase } => match parse_phase(phase) {
+                Ok(target) => PhaseTransitionService::new(&metis_dir)
+                    .transition_document(&short_code, target)
+                    .await
+                    .map(|result| format!("{} → {}", result.from_phase, result.to_phase))
+                    .map_err(|e| e.to_string()),
+                Err(e) => Err(e),
+            },
+            BulkAction::Archive => ArchiveService::new(&metis_dir)
+                .archive_document_by_short_code(&short_code, &mut db_service)
+                .await
+                .map(|result| format!("archived {} document(s)", result.total_archived))
+                .map_err(|e| e.to_string()),
+            BulkAction::Tag { tag } => TagService::new(&metis_dir)
+                .add_tag(&short_code, tag, &mut db_service)
+                .map(|added| match added {
+                    true => format!("tagged {}", tag),
+                    false => format!("already tagged {}", tag),
+                })
+                .map_err(|e| e.to_string()),
+            BulkAction::Assign { assignee } => {
+                let assignee = assignee
+                    .as_deref()
+                    .map(str::trim)
+                    .filter(|assignee| !assignee.is_empty());
+                PropertyService::new(&metis_dir)
+                    .set_property(&short_code, "assignee", assignee, &mut db_service)
+                    .map(|_| match assignee {
+                        Some(assignee) => format!("assigned to {}", assignee),
+                        None => "unassigned".to_string(),
+                    })
+                    .map_err(|e| e.to_string())
+            }
+        };
+
+        items.push(match outcome {
+            Ok(message) => BulkItemResult {
+                short_code,
+                ok: true,
+                message,
+            },
+            Err(message) => BulkItemResult {
+                short_code,
+                ok: false,
+                message,
+            },
+        });
+    }
+
+    // Sync once so the board reloads every change
+    let database = Database::new(db_path.to_str().unwrap())
+        .map_err(|e| format!("Failed to open database for sync: {}", e))?;
+    Application::new(database)
+        .sync_directory(&metis_dir)
+        .await
+        .map_err(|e| format!("Failed to sync workspace: {}", e))?;
+
+    let succeeded = items.iter().filter(|item| item.ok).count();
+    Ok(BulkResult {
+        succeeded,
+        failed: items.len() - succeeded,
+        items,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bulk_action_from_json() {
+        let action: BulkAction =
+            serde_json::from_str(r#"{"kind":"transition","phase":"active"}"#).unwrap();
+        assert!(matches!(action, BulkAction::Transition { phase } if phase == "active"));
+
+        let action: BulkAction = serde_json::from_str(r#"{"kind":"archive"}"#).unwrap();
+        assert!(matches!(action, BulkAction::Archive));
+
+        let action: BulkAction =
+            serde_json::from_str(r#"{"kind":"assign","assignee":null}"#).unwrap();
+        assert!(matches!(action, BulkAction::Assign { assignee: None }));
+    }
+}
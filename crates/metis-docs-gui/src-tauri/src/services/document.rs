@@ -1,10 +1,19 @@
+use crate::services::project::ensure_writable;
 use crate::AppState;
 use metis_core::{
     application::services::document::creation::{DocumentCreationConfig, DocumentCreationService},
+    application::services::workspace::{
+        AdrService, ContentService, CriteriaService, CriteriaStatus, LinkKind, LinkService,
+        PropertyService, TagService,
+    },
+    application::services::DatabaseService,
+    dal::database::{models::Document, DocumentFilter, DocumentSort},
     domain::documents::types::DocumentType,
     Application, Database,
 };
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use tauri::State;
 
@@ -22,6 +31,10 @@ pub struct DocumentInfo {
     pub tags: Vec<String>,
     pub initiative_id: Option<String>,
     pub parent_id: Option<String>,
+    pub assignee: Option<String>,
+    pub severity: Option<String>,
+    pub priority: Option<String>,
+    pub due_date: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -39,6 +52,12 @@ pub struct CreateDocumentRequest {
     pub parent_id: Option<String>,
     pub complexity: Option<String>,
     pub tags: Option<Vec<String>>,
+    /// Frontmatter fields to set on the new document, such as `severity`,
+    /// `priority` or `decision_maker`. Blank values are skipped.
+    pub properties: Option<BTreeMap<String, String>>,
+    /// For a new ADR, the decided ADR it replaces. The old ADR stays decided
+    /// until the new one is.
+    pub supersedes: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -53,6 +72,7 @@ pub async fn create_document(
     state: State<'_, std::sync::Mutex<AppState>>,
     request: CreateDocumentRequest,
 ) -> Result<CreateDocumentResult, String> {
+    ensure_writable(&state)?;
     let project_path = {
         let app_state = state
             .lock()
@@ -66,6 +86,24 @@ pub async fn create_document(
 
     // Create the creation service for the metis directory
     let metis_dir = project_path.join(".metis");
+
+    // Check the ADR being replaced before creating anything
+    let superseded = match request.supersedes.as_deref().map(str::trim) {
+        Some(reference) if !reference.is_empty() => {
+            if request.document_type != "adr" {
+                return Err("Only an ADR can supersede another ADR".to_string());
+            }
+            let database = Database::new(metis_dir.join("metis.db").to_str().unwrap())
+                .map_err(|e| format!("Failed to open database: {}", e))?;
+            let mut db_service = DatabaseService::new(database.into_repository());
+            let old = AdrService::new(&metis_dir)
+                .find_supersedable(reference, &mut db_service)
+                .map_err(|e| e.to_string())?;
+            Some(old.short_code)
+        }
+        _ => None,
+    };
+
     let creation_service = DocumentCreationService::new(&metis_dir);
 
     // Build the configuration
@@ -153,10 +191,14 @@ pub async fn create_document(
     // Add backlog category tag to file frontmatter if this is a backlog item
     if request.document_type == "task" && request.parent_id.is_none() {
         if let Some(tags) = &request.tags {
+            let database = Database::new(db_path.to_str().unwrap())
+                .map_err(|e| format!("Failed to open database for tagging: {}", e))?;
+            let mut db_service = DatabaseService::new(database.into_repository());
+            let tag_service = TagService::new(&metis_dir);
             for tag_str in tags {
                 if tag_str == "#bug" || tag_str == "#feature" || tag_str == "#tech-debt" {
-                    let file_path = &result.file_path;
-                    add_tag_to_frontmatter(file_path, tag_str)
+                    tag_service
+                        .add_tag(&result.short_code, tag_str, &mut db_service)
                         .map_err(|e| format!("Failed to add tag to frontmatter: {}", e))?;
                 }
             }
@@ -172,6 +214,53 @@ pub async fn create_document(
             .map_err(|e| format!("Failed to sync workspace after adding tags: {}", e))?;
     }
 
+    // Fields asked for in the dialog beyond the title, written the same way
+    // as edits to an existing document
+    let properties: Vec<(String, String)> = request
+        .properties
+        .unwrap_or_default()
+        .into_iter()
+        .map(|(key, value)| (key.trim().to_string(), value.trim().to_string()))
+        .filter(|(_, value)| !value.is_empty())
+        .collect();
+    if !properties.is_empty() {
+        let database = Database::new(db_path.to_str().unwrap())
+            .map_err(|e| format!("Failed to open database: {}", e))?;
+        let mut db_service = DatabaseService::new(database.into_repository());
+        let property_service = PropertyService::new(&metis_dir);
+        for (key, value) in &properties {
+            property_service
+                .set_property(&result.short_code, key, Some(value), &mut db_service)
+                .map_err(|e| {
+                    format!(
+                        "Created {}, but failed to set {}: {}",
+                        result.short_code, key, e
+                    )
+                })?;
+        }
+        sync_after_edit(&metis_dir, &db_path).await?;
+    }
+
+    if let Some(old) = superseded {
+        let database = Database::new(db_path.to_str().unwrap())
+            .map_err(|e| format!("Failed to open database: {}", e))?;
+        let mut db_service = DatabaseService::new(database.into_repository());
+        LinkService::new(&metis_dir)
+            .link(
+                &result.short_code,
+                &old,
+                LinkKind::Supersedes,
+                &mut db_service,
+            )
+            .map_err(|e| {
+                format!(
+                    "Created {}, but failed to link it to {}: {}",
+                    result.short_code, old, e
+                )
+            })?;
+        sync_after_edit(&metis_dir, &db_path).await?;
+    }
+
     Ok(CreateDocumentResult {
         id: result.document_id.to_string(),
         short_code: result.short_code,
@@ -230,35 +319,92 @@ pub async fn list_documents(
         })
         .map_err(|e| format!("Database error: {}", e))?;
 
-    let mut doc_infos: Vec<DocumentInfo> = Vec::new();
+    Ok(documents
+        .into_iter()
+        .filter(|doc| !doc.archived)
+        .map(|doc| document_info(&metis_dir, doc))
+        .collect())
+}
 
-    for doc in documents.into_iter().filter(|doc| !doc.archived) {
-        // Parse tags from file directly like TUI does
-        let tags = if doc.document_type == "task" {
-            // Convert relative path from DB to absolute path for file loading
-            let absolute_path = metis_dir.join(&doc.filepath);
-            extract_tags_from_task_file(&absolute_path.to_string_lossy()).unwrap_or_default()
-        } else {
-            vec![] // Other document types don't need tag extraction for backlog categorization
-        };
+/// List the archived documents of every type, newest first, for the
+/// archive view
+#[tauri::command]
+pub async fn list_archived_documents(
+    state: State<'_, std::sync::Mutex<AppState>>,
+) -> Result<Vec<DocumentInfo>, String> {
+    let (metis_dir, db_path) = {
+        let app_state = state
+            .lock()
+            .map_err(|e| format!("Failed to lock state: {}", e))?;
 
-        doc_infos.push(DocumentInfo {
-            id: doc.id,
-            title: doc.title,
-            document_type: doc.document_type,
-            short_code: doc.short_code,
-            filepath: doc.filepath,
-            phase: doc.phase,
-            archived: doc.archived,
-            created_at: doc.created_at,
-            updated_at: doc.updated_at,
-            tags,
-            initiative_id: doc.initiative_id.clone(),
-            parent_id: doc.parent_id.clone(),
-        });
-    }
+        let project_path = app_state
+            .current_project
+            .as_ref()
+            .ok_or("No project loaded")?;
 
-    Ok(doc_infos)
+        let metis_dir = project_path.join(".metis");
+        let db_path = metis_dir.join("metis.db");
+
+        (metis_dir, db_path)
+    };
+
+    // Sync first so documents archived from the CLI show up
+    let database = Database::new(db_path.to_str().unwrap())
+        .map_err(|e| format!("Failed to open database for sync: {}", e))?;
+    let app = Application::new(database);
+    app.sync_directory(&metis_dir)
+        .await
+        .map_err(|e| format!("Failed to sync workspace: {}", e))?;
+
+    let database = Database::new(db_path.to_str().unwrap())
+        .map_err(|e| format!("Failed to open database: {}", e))?;
+    let mut db_service = DatabaseService::new(database.into_repository());
+    let filter = DocumentFilter::new()
+        .include_archived(true)
+        .sort_by(DocumentSort::UpdatedAt, true);
+    let documents = db_service
+        .find_by_filter(&filter)
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    Ok(documents
+        .into_iter()
+        .filter(|doc| doc.archived)
+        .map(|doc| document_info(&metis_dir, doc))
+        .collect())
+}
+
+/// A database row as the boards see it
+fn document_info(metis_dir: &Path, doc: Document) -> DocumentInfo {
+    // Parse tags from file directly like TUI does
+    let frontmatter: serde_json::Value =
+        serde_json::from_str(&doc.frontmatter_json).unwrap_or_default();
+    let tags = if doc.document_type == "task" {
+        // Convert relative path from DB to absolute path for file loading
+        let absolute_path = metis_dir.join(&doc.filepath);
+        extract_tags_from_task_file(&absolute_path.to_string_lossy()).unwrap_or_default()
+    } else {
+        // Other document types only need their labels, for board search
+        labels_from_frontmatter(&frontmatter)
+    };
+
+    DocumentInfo {
+        id: doc.id,
+        title: doc.title,
+        document_type: doc.document_type,
+        short_code: doc.short_code,
+        filepath: doc.filepath,
+        phase: doc.phase,
+        archived: doc.archived,
+        created_at: doc.created_at,
+        updated_at: doc.updated_at,
+        tags,
+        initiative_id: doc.initiative_id,
+        parent_id: doc.parent_id,
+        assignee: frontmatter_property(&frontmatter, "assignee"),
+        severity: frontmatter_property(&frontmatter, "severity"),
+        priority: frontmatter_property(&frontmatter, "priority"),
+        due_date: frontmatter_property(&frontmatter, "due_date"),
+    }
 }
 
 #[tauri::command]
@@ -308,6 +454,105 @@ pub async fn read_document(
     })
 }
 
+/// What the board's detail panel shows beyond the card itself
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DocumentDetail {
+    pub short_code: String,
+    pub blocked_by: Vec<String>,
+    pub criteria_checked: usize,
+    pub criteria_total: usize,
+    pub criteria: Vec<CriterionInfo>,
+    pub sections: Vec<DocumentSection>,
+}
+
+/// An exit criterion checkbox, numbered from 1 in document order
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct CriterionInfo {
+    pub number: usize,
+    pub text: String,
+    pub checked: bool,
+}
+
+fn criterion_infos(status: &CriteriaStatus) -> Vec<CriterionInfo> {
+    status
+        .criteria
+        .iter()
+        .map(|criterion| CriterionInfo {
+            number: criterion.number,
+            text: criterion.text.clone(),
+            checked: criterion.checked,
+        })
+        .collect()
+}
+
+/// A `##` section of a document body, shortened for the detail panel
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct DocumentSection {
+    pub heading: String,
+    pub text: String,
+}
+
+/// Number of body sections the detail panel shows
+const DETAIL_SECTIONS: usize = 3;
+/// Characters of each section's text the detail panel shows
+const DETAIL_SECTION_CHARS: usize = 400;
+
+#[tauri::command]
+pub async fn get_document_detail(
+    state: State<'_, std::sync::Mutex<AppState>>,
+    short_code: String,
+) -> Result<DocumentDetail, String> {
+    let (metis_dir, db_path) = {
+        let app_state = state
+            .lock()
+            .map_err(|e| format!("Failed to lock state: {}", e))?;
+
+        let project_path = app_state
+            .current_project
+            .as_ref()
+            .ok_or("No project loaded")?;
+
+        let metis_dir = project_path.join(".metis");
+        let db_path = metis_dir.join("metis.db");
+
+        (metis_dir, db_path)
+    };
+
+    // No sync here: the panel follows the board, which has just synced
+    let database = Database::new(db_path.to_str().unwrap())
+        .map_err(|e| format!("Failed to open database: {}", e))?;
+    let mut app = Application::new(database);
+
+    app.with_database(|service| -> Result<DocumentDetail, String> {
+        let document = service
+            .find_by_short_code(&short_code)
+            .map_err(|e| format!("Database error: {}", e))?
+            .ok_or(format!("Document with short code {} not found", short_code))?;
+        let blocked_by = service
+            .find_edges_to(&short_code)
+            .map_err(|e| format!("Database error: {}", e))?
+            .into_iter()
+            .filter(|edge| edge.kind == "blocks")
+            .map(|edge| edge.from_code)
+            .collect();
+        let criteria = CriteriaService::new(&metis_dir)
+            .status(&short_code, service)
+            .map_err(|e| format!("Failed to read exit criteria: {}", e))?;
+
+        Ok(DocumentDetail {
+            short_code: document.short_code,
+            blocked_by,
+            criteria_checked: criteria.checked(),
+            criteria_total: criteria.criteria.len(),
+            criteria: criterion_infos(&criteria),
+            sections: content_sections(
+                document.content.as_deref().unwrap_or_default(),
+                DETAIL_SECTIONS,
+            ),
+        })
+    })
+}
+
 #[tauri::command]
 pub async fn search_documents(
     state: State<'_, std::sync::Mutex<AppState>>,
@@ -348,19 +593,27 @@ pub async fn search_documents(
 
     let doc_infos: Vec<DocumentInfo> = documents
         .into_iter()
-        .map(|doc| DocumentInfo {
-            id: doc.id,
-            title: doc.title,
-            document_type: doc.document_type,
-            short_code: doc.short_code,
-            filepath: doc.filepath,
-            phase: doc.phase,
-            archived: doc.archived,
-            created_at: doc.created_at,
-            updated_at: doc.updated_at,
-            tags: vec![], // Search results don't need tags for board categorization
-            initiative_id: doc.initiative_id.clone(),
-            parent_id: doc.parent_id.clone(),
+        .map(|doc| {
+            let frontmatter: serde_json::Value =
+                serde_json::from_str(&doc.frontmatter_json).unwrap_or_default();
+            DocumentInfo {
+                id: doc.id,
+                title: doc.title,
+                document_type: doc.document_type,
+                short_code: doc.short_code,
+                filepath: doc.filepath,
+                phase: doc.phase,
+                archived: doc.archived,
+                created_at: doc.created_at,
+                updated_at: doc.updated_at,
+                tags: vec![], // Search results don't need tags for board categorization
+                initiative_id: doc.initiative_id.clone(),
+                parent_id: doc.parent_id.clone(),
+                assignee: frontmatter_property(&frontmatter, "assignee"),
+                severity: frontmatter_property(&frontmatter, "severity"),
+                priority: frontmatter_property(&frontmatter, "priority"),
+                due_date: frontmatter_property(&frontmatter, "due_date"),
+            }
         })
         .collect();
 
@@ -373,6 +626,7 @@ pub async fn update_document(
     short_code: String,
     content: String,
 ) -> Result<(), String> {
+    ensure_writable(&state)?;
     let project_path = {
         let app_state = state
             .lock()
@@ -422,6 +676,243 @@ pub async fn update_document(
     Ok(())
 }
 
+/// The `.metis` directory and database of the loaded project
+fn project_paths(
+    state: &State<'_, std::sync::Mutex<AppState>>,
+) -> Result<(PathBuf, PathBuf), String> {
+    let app_state = state
+        .lock()
+        .map_err(|e| format!("Failed to lock state: {}", e))?;
+    let project_path = app_state
+        .current_project
+        .as_ref()
+        .ok_or("No project loaded")?;
+    let metis_dir = project_path.join(".metis");
+    let db_path = metis_dir.join("metis.db");
+    Ok((metis_dir, db_path))
+}
+
+async fn sync_after_edit(metis_dir: &Path, db_path: &Path) -> Result<(), String> {
+    let database = Database::new(db_path.to_str().unwrap())
+        .map_err(|e| format!("Failed to open database for sync: {}", e))?;
+    Application::new(database)
+        .sync_directory(metis_dir)
+        .await
+        .map_err(|e| format!("Failed to sync workspace: {}", e))?;
+    Ok(())
+}
+
+/// Replace a document's body, keeping the frontmatter currently on disk
+#[tauri::command]
+pub async fn update_document_body(
+    state: State<'_, std::sync::Mutex<AppState>>,
+    short_code: String,
+    body: String,
+) -> Result<(), String> {
+    ensure_writable(&state)?;
+    let (metis_dir, db_path) = project_paths(&state)?;
+
+    let database = Database::new(db_path.to_str().unwrap())
+        .map_err(|e| format!("Failed to open database: {}", e))?;
+    let changed = Application::new(database)
+        .with_database(|service| {
+            ContentService::new(&metis_dir).update_document_content(&short_code, &body, service)
+        })
+        .map_err(|e| format!("Failed to save {}: {}", short_code, e))?;
+
+    if changed {
+        sync_after_edit(&metis_dir, &db_path).await?;
+    }
+    Ok(())
+}
+
+/// A free-form frontmatter field, such as `assignee` or `due_date`
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DocumentPropertyInfo {
+    pub key: String,
+    pub value: String,
+}
+
+#[tauri::command]
+pub async fn get_document_properties(
+    state: State<'_, std::sync::Mutex<AppState>>,
+    short_code: String,
+) -> Result<Vec<DocumentPropertyInfo>, String> {
+    let (_, db_path) = project_paths(&state)?;
+
+    let database = Database::new(db_path.to_str().unwrap())
+        .map_err(|e| format!("Failed to open database: {}", e))?;
+    let properties = Application::new(database)
+        .with_database(|service| {
+            let document = service.find_by_short_code(&short_code)?.ok_or_else(|| {
+                metis_core::MetisError::NotFound(format!(
+                    "Document with short code {} not found",
+                    short_code
+                ))
+            })?;
+            service.find_properties(&document.filepath)
+        })
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    Ok(properties
+        .into_iter()
+        .map(|property| DocumentPropertyInfo {
+            key: property.key,
+            value: property.value,
+        })
+        .collect())
+}
+
+/// Set a free-form frontmatter field, or remove it when `value` is empty.
+/// Structural fields such as `id`, `parent` or `tags` are refused.
+#[tauri::command]
+pub async fn set_document_property(
+    state: State<'_, std::sync::Mutex<AppState>>,
+    short_code: String,
+    key: String,
+    value: Option<String>,
+) -> Result<(), String> {
+    ensure_writable(&state)?;
+    let (metis_dir, db_path) = project_paths(&state)?;
+    let value = value
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty());
+
+    let database = Database::new(db_path.to_str().unwrap())
+        .map_err(|e| format!("Failed to open database: {}", e))?;
+    let change = Application::new(database)
+        .with_database(|service| {
+            PropertyService::new(&metis_dir).set_property(&short_code, key.trim(), value, service)
+        })
+        .map_err(|e| format!("Failed to set {}: {}", key, e))?;
+
+    if change.changed() {
+        sync_after_edit(&metis_dir, &db_path).await?;
+    }
+    Ok(())
+}
+
+/// Check or uncheck exit criterion `number` of a document, keeping its
+/// `exit_criteria_met` field in step. Returns the criteria as they now are.
+#[tauri::command]
+pub async fn set_criterion(
+    state: State<'_, std::sync::Mutex<AppState>>,
+    short_code: String,
+    number: usize,
+    checked: bool,
+) -> Result<Vec<CriterionInfo>, String> {
+    ensure_writable(&state)?;
+    let (metis_dir, db_path) = project_paths(&state)?;
+
+    let database = Database::new(db_path.to_str().unwrap())
+        .map_err(|e| format!("Failed to open database: {}", e))?;
+    let status = Application::new(database)
+        .with_database(|service| {
+            CriteriaService::new(&metis_dir).set_checked(&short_code, number, checked, service)
+        })
+        .map_err(|e| format!("Failed to update criterion {}: {}", number, e))?;
+
+    sync_after_edit(&metis_dir, &db_path).await?;
+    Ok(criterion_infos(&status))
+}
+
+/// The tags a document declares, without the phase tag, in file order
+#[tauri::command]
+pub async fn get_document_tags(
+    state: State<'_, std::sync::Mutex<AppState>>,
+    short_code: String,
+) -> Result<Vec<String>, String> {
+    let (metis_dir, db_path) = project_paths(&state)?;
+    read_tags(&metis_dir, &db_path, &short_code)
+}
+
+/// Every tag used by a document in the workspace, for autocomplete. Phase
+/// tags are left out, and tags differing only in case are listed once.
+#[tauri::command]
+pub async fn list_workspace_tags(
+    state: State<'_, std::sync::Mutex<AppState>>,
+) -> Result<Vec<String>, String> {
+    let (_, db_path) = project_paths(&state)?;
+
+    let database = Database::new(db_path.to_str().unwrap())
+        .map_err(|e| format!("Failed to open database: {}", e))?;
+    let mut db_service = DatabaseService::new(database.into_repository());
+    let documents = db_service
+        .find_by_filter(&DocumentFilter::new().include_archived(true))
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    let mut tags: Vec<String> = Vec::new();
+    for doc in documents {
+        let frontmatter: serde_json::Value =
+            serde_json::from_str(&doc.frontmatter_json).unwrap_or_default();
+        for tag in labels_from_frontmatter(&frontmatter) {
+            if !tags.iter().any(|known| known.eq_ignore_ascii_case(&tag)) {
+                tags.push(tag);
+            }
+        }
+    }
+    tags.sort_by_key(|tag| tag.to_lowercase());
+    Ok(tags)
+}
+
+/// Add `tag` to a document's frontmatter. Returns its tags as they now are.
+#[tauri::command]
+pub async fn add_document_tag(
+    state: State<'_, std::sync::Mutex<AppState>>,
+    short_code: String,
+    tag: String,
+) -> Result<Vec<String>, String> {
+    ensure_writable(&state)?;
+    let (metis_dir, db_path) = project_paths(&state)?;
+
+    let database = Database::new(db_path.to_str().unwrap())
+        .map_err(|e| format!("Failed to open database: {}", e))?;
+    let added = Application::new(database)
+        .with_database(|service| TagService::new(&metis_dir).add_tag(&short_code, &tag, service))
+        .map_err(|e| format!("Failed to add {}: {}", tag, e))?;
+
+    if added {
+        sync_after_edit(&metis_dir, &db_path).await?;
+    }
+    read_tags(&metis_dir, &db_path, &short_code)
+}
+
+/// Remove `tag` from a document's frontmatter. Returns its tags as they now are.
+#[tauri::command]
+pub async fn remove_document_tag(
+    state: State<'_, std::sync::Mutex<AppState>>,
+    short_code: String,
+    tag: String,
+) -> Result<Vec<String>, String> {
+    ensure_writable(&state)?;
+    let (metis_dir, db_path) = project_paths(&state)?;
+
+    let database = Database::new(db_path.to_str().unwrap())
+        .map_err(|e| format!("Failed to open database: {}", e))?;
+    let removed = Application::new(database)
+        .with_database(|service| TagService::new(&metis_dir).remove_tag(&short_code, &tag, service))
+        .map_err(|e| format!("Failed to remove {}: {}", tag, e))?;
+
+    if removed {
+        sync_after_edit(&metis_dir, &db_path).await?;
+    }
+    read_tags(&metis_dir, &db_path, &short_code)
+}
+
+fn read_tags(metis_dir: &Path, db_path: &Path, short_code: &str) -> Result<Vec<String>, String> {
+    let database = Database::new(db_path.to_str().unwrap())
+        .map_err(|e| format!("Failed to open database: {}", e))?;
+    let mut db_service = DatabaseService::new(database.into_repository());
+    let tags = TagService::new(metis_dir)
+        .tags(short_code, &mut db_service)
+        .map_err(|e| format!("Failed to read tags of {}: {}", short_code, e))?;
+    Ok(tags
+        .into_iter()
+        .filter(|tag| !tag.starts_with("#phase/"))
+        .collect())
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ParentOption {
     pub short_code: String,
@@ -638,65 +1129,104 @@ mod tests {
         let create_result = task_result.unwrap();
         assert!(create_result.short_code.starts_with("TEST-T-"));
     }
-}
-
-/// Add a tag to the frontmatter of a document file
-fn add_tag_to_frontmatter(file_path: &std::path::Path, tag: &str) -> Result<(), String> {
-    let content =
-        std::fs::read_to_string(file_path).map_err(|e| format!("Failed to read file: {}", e))?;
-
-    let updated_content = if content.contains("tags:") {
-        // Find the tags section and add the tag
-        let lines: Vec<&str> = content.lines().collect();
-        let mut new_lines = Vec::new();
-        let mut in_tags_section = false;
-        let mut tags_section_ended = false;
-        let mut tag_added = false;
 
-        for line in lines {
-            new_lines.push(line.to_string());
+    #[test]
+    fn test_content_sections() {
+        let content = "---\nid: t\ntitle: T\n---\n\n# T\n\n## Objective\n\nShip it.\n\n### Detail\n\nMore.\n\n## Notes\n\n## Exit Criteria\n\n- [ ] Done\n";
+
+        let sections = content_sections(content, 2);
+        assert_eq!(
+            sections,
+            vec![
+                DocumentSection {
+                    heading: "Objective".to_string(),
+                    text: "Ship it.\n\n### Detail\n\nMore.".to_string(),
+                },
+                DocumentSection {
+                    heading: "Notes".to_string(),
+                    text: String::new(),
+                },
+            ]
+        );
 
-            if line.trim().starts_with("tags:") {
-                in_tags_section = true;
-                continue;
-            }
+        let long = format!("## Long\n{}", "x".repeat(DETAIL_SECTION_CHARS + 10));
+        let text = &content_sections(&long, 1)[0].text;
+        assert!(text.ends_with('…'));
+        assert_eq!(text.chars().count(), DETAIL_SECTION_CHARS + 1);
+    }
+}
 
-            if in_tags_section && !tags_section_ended {
-                if line.trim().starts_with("- \"") || line.trim().starts_with("- ") {
-                    // Still in tags section
-                    continue;
-                } else if line.trim().is_empty() {
-                    // Empty line, potentially end of tags section
-                    continue;
-                } else {
-                    // Found a non-tag line, end of tags section
-                    if !tag_added {
-                        new_lines.insert(new_lines.len() - 1, format!("  - \"{}\"", tag));
-                        tag_added = true;
-                    }
-                    tags_section_ended = true;
-                }
+/// The first `limit` `##` sections of a document, skipping its frontmatter,
+/// each shortened to [`DETAIL_SECTION_CHARS`]
+fn content_sections(content: &str, limit: usize) -> Vec<DocumentSection> {
+    let mut lines = content.lines().peekable();
+    if lines.peek().map(|line| line.trim()) == Some("---") {
+        lines.next();
+        for line in lines.by_ref() {
+            if line.trim() == "---" {
+                break;
             }
         }
+    }
 
-        // If we didn't add the tag yet (file ended while in tags section)
-        if in_tags_section && !tag_added {
-            new_lines.insert(new_lines.len() - 1, format!("  - \"{}\"", tag));
+    let mut sections: Vec<DocumentSection> = Vec::new();
+    let mut in_section = false;
+    for line in lines {
+        let trimmed = line.trim();
+        if let Some(heading) = trimmed.strip_prefix("## ") {
+            if sections.len() == limit {
+                break;
+            }
+            sections.push(DocumentSection {
+                heading: heading.trim().to_string(),
+                text: String::new(),
+            });
+            in_section = true;
+        } else if trimmed.starts_with("# ") {
+            in_section = false;
+        } else if in_section {
+            let text = &mut sections.last_mut().unwrap().text;
+            text.push_str(line);
+            text.push('\n');
         }
+    }
 
-        new_lines.join("\n")
-    } else {
-        // No tags section exists, this shouldn't happen with our template but handle it
-        content
-    };
+    for section in &mut sections {
+        let text = section.text.trim();
+        section.text = match text.char_indices().nth(DETAIL_SECTION_CHARS) {
+            Some((end, _)) => format!("{}…", text[..end].trim_end()),
+            None => text.to_string(),
+        };
+    }
+    sections
+}
 
-    std::fs::write(file_path, updated_content)
-        .map_err(|e| format!("Failed to write file: {}", e))?;
+/// Extract tags from a task file by parsing it like the TUI does
+/// Label tags (not `#phase/...`) from a document's stored frontmatter
+fn labels_from_frontmatter(frontmatter: &serde_json::Value) -> Vec<String> {
+    frontmatter
+        .get("tags")
+        .and_then(|tags| tags.as_array())
+        .map(|tags| {
+            tags.iter()
+                .filter_map(|tag| tag.as_str())
+                .filter(|tag| !tag.starts_with("#phase/"))
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default()
+}
 
-    Ok(())
+/// A free-form property such as `assignee` or `severity` from a document's
+/// stored frontmatter. Numbers, such as `priority: 1`, come back as text.
+fn frontmatter_property(frontmatter: &serde_json::Value, key: &str) -> Option<String> {
+    match frontmatter.get(key)? {
+        serde_json::Value::String(value) if !value.is_empty() => Some(value.clone()),
+        serde_json::Value::Number(value) => Some(value.to_string()),
+        _ => None,
+    }
 }
 
-/// Extract tags from a task file by parsing it like the TUI does
 fn extract_tags_from_task_file(filepath: &str) -> Result<Vec<String>, String> {
     use metis_core::{Document, Task};
     use std::path::Path;
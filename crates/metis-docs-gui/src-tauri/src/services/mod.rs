@@ -1,21 +1,33 @@
 pub mod archive;
+pub mod bulk;
 pub mod cli_installer;
 pub mod document;
 pub mod project;
+pub mod relations;
+pub mod session;
+pub mod settings;
 pub mod sync;
 pub mod transition;
 pub mod version;
+pub mod workspaces;
 
 // Re-export main service functions
-pub use archive::archive_document;
+pub use archive::{archive_document, unarchive_document};
+pub use bulk::bulk_update;
 pub use cli_installer::{
     auto_install_cli, get_cli_install_status, install_cli, install_cli_elevated, uninstall_cli,
 };
 pub use document::{
-    create_document, get_available_parents, list_documents, read_document, search_documents,
-    update_document,
+    add_document_tag, create_document, get_available_parents, get_document_detail,
+    get_document_properties, get_document_tags, list_archived_documents, list_documents,
+    list_workspace_tags, read_document, remove_document_tag, search_documents, set_criterion,
+    set_document_property, update_document, update_document_body,
 };
 pub use project::{get_project_config, initialize_project, load_project};
-pub use sync::sync_project;
-pub use transition::transition_phase;
+pub use relations::get_document_relations;
+pub use session::{get_session_state, save_session_state};
+pub use settings::get_board_settings;
+pub use sync::{remote_sync, sync_project};
+pub use transition::{move_document, transition_phase};
 pub use version::get_app_version;
+pub use workspaces::{detect_launch_workspace, forget_workspace, list_recent_workspaces};
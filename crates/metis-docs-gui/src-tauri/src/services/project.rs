@@ -4,7 +4,7 @@ use metis_core::{
     domain::configuration::FlightLevelConfig, Application, Database,
 };
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use tauri::State;
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -12,6 +12,9 @@ pub struct ProjectInfo {
     pub path: String,
     pub is_valid: bool,
     pub vision_exists: bool,
+    /// Why nothing in the project can be changed this session, or None
+    /// when it can
+    pub read_only: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -81,21 +84,81 @@ pub async fn load_project(
 
     let is_valid = WorkspaceInitializationService::is_workspace(&project_path);
     let vision_exists = metis_dir.join("vision.md").exists();
+    let mut read_only = None;
 
     if is_valid {
         let mut app_state = state
             .lock()
             .map_err(|e| format!("Failed to lock state: {}", e))?;
-        app_state.current_project = Some(project_path);
+        read_only = read_only_reason(app_state.read_only_flag, &metis_dir);
+        app_state.current_project = Some(project_path.clone());
+        app_state.read_only = read_only.clone();
+        drop(app_state);
+
+        // The project still opens if the recent list can't be saved
+        if let Err(e) = super::workspaces::remember_workspace(&project_path) {
+            log::warn!("{}", e);
+        }
     }
 
     Ok(ProjectInfo {
         path,
         is_valid,
         vision_exists,
+        read_only,
     })
 }
 
+/// Refuse a change while the current project is read-only. Every command
+/// that writes to the workspace calls this first.
+pub(crate) fn ensure_writable(state: &State<'_, std::sync::Mutex<AppState>>) -> Result<(), String> {
+    let app_state = state
+        .lock()
+        .map_err(|e| format!("Failed to lock state: {}", e))?;
+    match &app_state.read_only {
+        Some(reason) => Err(format!("Read-only session: {}", reason)),
+        None => Ok(()),
+    }
+}
+
+/// Why a project opens read-only: the app was started with `--read-only`,
+/// or the project is a snapshot written by `metis checkout`
+fn read_only_reason(read_only_flag: bool, metis_dir: &Path) -> Option<String> {
+    if read_only_flag {
+        Some("started with --read-only".to_string())
+    } else if is_checkout(metis_dir) {
+        Some("a historical checkout made by metis checkout".to_string())
+    } else {
+        None
+    }
+}
+
+/// `metis checkout` marks every document it writes read-only, so a
+/// workspace whose first document can't be written is such a snapshot
+fn is_checkout(metis_dir: &Path) -> bool {
+    fn first_document(dir: &Path) -> Option<PathBuf> {
+        let mut entries: Vec<PathBuf> = std::fs::read_dir(dir)
+            .ok()?
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .collect();
+        entries.sort();
+        entries
+            .iter()
+            .find(|path| path.extension().is_some_and(|ext| ext == "md"))
+            .cloned()
+            .or_else(|| {
+                entries
+                    .iter()
+                    .filter(|path| path.is_dir())
+                    .find_map(|path| first_document(path))
+            })
+    }
+
+    first_document(metis_dir)
+        .and_then(|path| std::fs::metadata(path).ok())
+        .is_some_and(|metadata| metadata.permissions().readonly())
+}
+
 #[tauri::command]
 pub async fn get_project_config(
     state: State<'_, std::sync::Mutex<AppState>>,
@@ -181,3 +244,31 @@ mod tests {
         );
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_read_only_reason() {
+        let temp_dir = tempdir().unwrap();
+        let metis_dir = temp_dir.path().join(".metis");
+        let vision = metis_dir.join("vision.md");
+        std::fs::create_dir_all(&metis_dir).unwrap();
+        std::fs::write(&vision, "# Vision").unwrap();
+
+        assert_eq!(read_only_reason(false, &metis_dir), None);
+        assert!(read_only_reason(true, &metis_dir)
+            .unwrap()
+            .contains("--read-only"));
+
+        // Documents written read-only, as metis checkout leaves them
+        let mut permissions = std::fs::metadata(&vision).unwrap().permissions();
+        permissions.set_readonly(true);
+        std::fs::set_permissions(&vision, permissions).unwrap();
+        assert!(read_only_reason(false, &metis_dir)
+            .unwrap()
+            .contains("checkout"));
+    }
+}
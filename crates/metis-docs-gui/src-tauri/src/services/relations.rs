@@ -0,0 +1,254 @@
+use crate::AppState;
+use metis_core::{
+    application::services::{DatabaseService, QueryService},
+    dal::database::models::Document,
+    Database,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use tauri::State;
+
+/// One document in the relationship view, indented by `depth`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RelationNode {
+    pub short_code: String,
+    pub title: String,
+    pub document_type: String,
+    pub phase: String,
+    pub depth: usize,
+}
+
+impl From<&Document> for RelationNode {
+    fn from(document: &Document) -> Self {
+        Self {
+            short_code: document.short_code.clone(),
+            title: document.title.clone(),
+            document_type: document.document_type.clone(),
+            phase: document.phase.clone(),
+            depth: 0,
+        }
+    }
+}
+
+/// A document with everything around it, each list flattened depth-first
+/// so the view can render it as an indented tree
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DocumentRelations {
+    pub document: RelationNode,
+    /// The parent chain, the top-level document first
+    pub ancestors: Vec<RelationNode>,
+    /// Children, with their own children nested below them
+    pub children: Vec<RelationNode>,
+    /// Documents blocking this one, with what blocks them nested below
+    pub blockers: Vec<RelationNode>,
+    /// Documents this one blocks directly
+    pub blocking: Vec<RelationNode>,
+}
+
+/// Load the ancestors, children and blockers of a document from the
+/// relationship graph
+#[tauri::command]
+pub async fn get_document_relations(
+    state: State<'_, std::sync::Mutex<AppState>>,
+    short_code: String,
+) -> Result<DocumentRelations, String> {
+    let db_path = {
+        let app_state = state
+            .lock()
+            .map_err(|e| format!("Failed to lock state: {}", e))?;
+        app_state
+            .current_project
+            .as_ref()
+            .ok_or("No project loaded")?
+            .join(".metis")
+            .join("metis.db")
+    };
+
+    let open = || {
+        Database::new(db_path.to_str().unwrap())
+            .map_err(|e| format!("Failed to open database: {}", e))
+    };
+    let mut db_service = DatabaseService::new(open()?.into_repository());
+    let mut query = QueryService::new(open()?.into_repository());
+    let db_error = |e: metis_core::MetisError| format!("Database error: {}", e);
+
+    let document = db_service
+        .find_by_short_code(&short_code)
+        .map_err(db_error)?
+        .ok_or(format!("Document with short code {} not found", short_code))?;
+
+    // Ancestors: follow parent links up from the document
+    let ancestors = query.ancestors_of(&short_code).map_err(db_error)?;
+    let by_code: HashMap<&str, &Document> = ancestors
+        .iter()
+        .map(|ancestor| (ancestor.short_code.as_str(), ancestor))
+        .collect();
+    let mut chain = Vec::new();
+    let mut next = document.parent_id.as_deref();
+    while let Some(parent) = next.and_then(|code| by_code.get(code)) {
+        if chain
+            .iter()
+            .any(|node: &RelationNode| node.short_code == parent.short_code)
+        {
+            break;
+        }
+        chain.push(RelationNode::from(*parent));
+        next = parent.parent_id.as_deref();
+    }
+    chain.reverse();
+    for (depth, node) in chain.iter_mut().enumerate() {
+        node.depth = depth;
+    }
+
+    // Children: the descendants arranged under their parents
+    let descendants = query.descendants_of(&short_code).map_err(db_error)?;
+    let mut children_of: HashMap<String, Vec<String>> = HashMap::new();
+    for descendant in &descendants {
+        if let Some(parent) = &descendant.parent_id {
+            children_of
+                .entry(parent.clone())
+                .or_default()
+                .push(descendant.short_code.clone());
+        }
+    }
+    let children = flatten_tree(&short_code, &children_of, &nodes_by_code(&descendants));
+
+    // Blockers: walk the blocks edges backwards from the document
+    let transitive = query.transitive_blockers(&short_code).map_err(db_error)?;
+    let mut blockers_of: HashMap<String, Vec<String>> = HashMap::new();
+    let mut pending = vec![short_code.clone()];
+    let mut seen = HashSet::new();
+    while let Some(code) = pending.pop() {
+        if !seen.insert(code.clone()) {
+            continue;
+        }
+        let blockers: Vec<String> = query
+            .edges_to(&code)
+            .map_err(db_error)?
+            .into_iter()
+            .filter(|edge| edge.kind == "blocks")
+            .map(|edge| edge.from_code)
+            .collect();
+        pending.extend(blockers.iter().cloned());
+        blockers_of.insert(code, blockers);
+    }
+    let blockers = flatten_tree(&short_code, &blockers_of, &nodes_by_code(&transitive));
+
+    let mut blocking = Vec::new();
+    for edge in query.edges_from(&short_code).map_err(db_error)? {
+        if edge.kind != "blocks" {
+            continue;
+        }
+        if let Some(blocked) = db_service
+            .find_by_short_code(&edge.to_code)
+            .map_err(db_error)?
+        {
+            blocking.push(RelationNode::from(&blocked));
+        }
+    }
+
+    Ok(DocumentRelations {
+        document: RelationNode::from(&document),
+        ancestors: chain,
+        children,
+        blockers,
+        blocking,
+    })
+}
+
+fn nodes_by_code(documents: &[Document]) -> HashMap<String, RelationNode> {
+    documents
+        .iter()
+        .map(|document| (document.short_code.clone(), RelationNode::from(document)))
+        .collect()
+}
+
+/// Flatten the tree below `root` depth-first, with `depth` 0 for its direct
+/// children. Codes without a known document are skipped, and each document
+/// appears once even if the data has a cycle.
+fn flatten_tree(
+    root: &str,
+    children: &HashMap<String, Vec<String>>,
+    nodes: &HashMap<String, RelationNode>,
+) -> Vec<RelationNode> {
+    fn visit(
+        code: &str,
+        depth: usize,
+        children: &HashMap<String, Vec<String>>,
+        nodes: &HashMap<String, RelationNode>,
+        seen: &mut HashSet<String>,
+        out: &mut Vec<RelationNode>,
+    ) {
+        let mut codes: Vec<&String> = children.get(code).into_iter().flatten().collect();
+        codes.sort();
+        for child in codes {
+            let Some(node) = nodes.get(child) else {
+                continue;
+            };
+            if !seen.insert(child.clone()) {
+                continue;
+            }
+            out.push(RelationNode {
+                depth,
+                ..node.clone()
+            });
+            visit(child, depth + 1, children, nodes, seen, out);
+        }
+    }
+
+    let mut seen = HashSet::from([root.to_string()]);
+    let mut out = Vec::new();
+    visit(root, 0, children, nodes, &mut seen, &mut out);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(short_code: &str) -> RelationNode {
+        RelationNode {
+            short_code: short_code.to_string(),
+            title: short_code.to_string(),
+            document_type: "task".to_string(),
+            phase: "todo".to_string(),
+            depth: 0,
+        }
+    }
+
+    #[test]
+    fn test_flatten_tree() {
+        let children: HashMap<String, Vec<String>> = [
+            ("I-1", vec!["T-2", "T-1"]),
+            ("T-1", vec!["T-3", "T-9"]),
+            // A cycle back to the root is ignored
+            ("T-3", vec!["I-1"]),
+        ]
+        .into_iter()
+        .map(|(code, children)| {
+            (
+                code.to_string(),
+                children.into_iter().map(str::to_string).collect(),
+            )
+        })
+        .collect();
+        let nodes: HashMap<String, RelationNode> = ["I-1", "T-1", "T-2", "T-3"]
+            .into_iter()
+            .map(|code| (code.to_string(), node(code)))
+            .collect();
+
+        let flattened: Vec<(String, usize)> = flatten_tree("I-1", &children, &nodes)
+            .into_iter()
+            .map(|node| (node.short_code, node.depth))
+            .collect();
+        // T-9 has no document and is skipped
+        assert_eq!(
+            flattened,
+            vec![
+                ("T-1".to_string(), 0),
+                ("T-3".to_string(), 1),
+                ("T-2".to_string(), 0),
+            ]
+        );
+    }
+}
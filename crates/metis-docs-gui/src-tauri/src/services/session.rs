@@ -0,0 +1,111 @@
+use crate::AppState;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use tauri::State;
+
+/// Name of the session file inside `.metis`. It is local to each checkout
+/// and ignored by git.
+pub const SESSION_FILE: &str = "gui-session.json";
+
+/// Where the boards were left when the project was last open, restored the
+/// next time it is opened. Every field is optional; documents that have
+/// gone since are skipped by the board.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SessionState {
+    /// The board shown, e.g. `task`
+    pub board: Option<String>,
+    /// Short code of the selected card
+    pub selected: Option<String>,
+    /// The card search on the board
+    pub search: Option<String>,
+    /// Short code of the initiative the task board is filtered to
+    pub initiative_filter: Option<String>,
+    /// Short code of the initiative every board is zoomed into
+    pub zoom: Option<String>,
+}
+
+fn session_path(state: &State<'_, std::sync::Mutex<AppState>>) -> Result<PathBuf, String> {
+    let app_state = state
+        .lock()
+        .map_err(|e| format!("Failed to lock state: {}", e))?;
+    Ok(app_state
+        .current_project
+        .as_ref()
+        .ok_or("No project loaded")?
+        .join(".metis")
+        .join(SESSION_FILE))
+}
+
+#[tauri::command]
+pub async fn get_session_state(
+    state: State<'_, std::sync::Mutex<AppState>>,
+) -> Result<SessionState, String> {
+    Ok(read_session(&session_path(&state)?))
+}
+
+/// Save where the boards are. A read-only session leaves the file alone, so
+/// a snapshot made by `metis checkout` stays as it was.
+#[tauri::command]
+pub async fn save_session_state(
+    state: State<'_, std::sync::Mutex<AppState>>,
+    session: SessionState,
+) -> Result<(), String> {
+    let read_only = state
+        .lock()
+        .map_err(|e| format!("Failed to lock state: {}", e))?
+        .read_only
+        .is_some();
+    if read_only {
+        return Ok(());
+    }
+    write_session(&session_path(&state)?, &session)
+}
+
+// A missing or damaged file starts the project afresh; it is rewritten as
+// soon as anything changes
+fn read_session(file: &Path) -> SessionState {
+    let Ok(content) = std::fs::read_to_string(file) else {
+        return SessionState::default();
+    };
+    serde_json::from_str(&content).unwrap_or_else(|e| {
+        log::warn!("Ignoring {}: {}", file.display(), e);
+        SessionState::default()
+    })
+}
+
+fn write_session(file: &Path, session: &SessionState) -> Result<(), String> {
+    let content = serde_json::to_string_pretty(session)
+        .map_err(|e| format!("Failed to save the session: {}", e))?;
+    std::fs::write(file, content).map_err(|e| format!("Failed to write {}: {}", file.display(), e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_session_round_trip() {
+        let temp_dir = tempdir().unwrap();
+        let file = temp_dir.path().join(SESSION_FILE);
+        assert_eq!(read_session(&file), SessionState::default());
+
+        let session = SessionState {
+            board: Some("task".to_string()),
+            selected: Some("TEST-T-0003".to_string()),
+            search: Some("login".to_string()),
+            initiative_filter: Some("TEST-I-0001".to_string()),
+            zoom: None,
+        };
+        write_session(&file, &session).unwrap();
+        assert_eq!(read_session(&file), session);
+
+        // Fields can be left out, and a damaged file is ignored
+        std::fs::write(&file, r#"{"board": "adr"}"#).unwrap();
+        assert_eq!(read_session(&file).board.as_deref(), Some("adr"));
+        assert_eq!(read_session(&file).selected, None);
+        std::fs::write(&file, "{not json").unwrap();
+        assert_eq!(read_session(&file), SessionState::default());
+    }
+}
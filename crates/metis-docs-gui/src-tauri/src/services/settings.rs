@@ -0,0 +1,311 @@
+use crate::AppState;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+use tauri::State;
+
+/// Name of the board settings file inside `.metis`
+pub const SETTINGS_FILE: &str = "board.toml";
+
+const THEMES: &[&str] = &["light", "dark", "hyper"];
+const BOARDS: &[&str] = &[
+    "vision",
+    "strategy",
+    "initiative",
+    "task",
+    "adr",
+    "backlog",
+    "specification",
+];
+const SEVERITIES: &[&str] = &["low", "medium", "high", "critical"];
+const MIN_COLUMN_WIDTH: u32 = 220;
+const MAX_COLUMN_WIDTH: u32 = 600;
+
+/// Board preferences read from `.metis/board.toml`. Every field is optional;
+/// anything left out falls back to the current theme and the built-in layout.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BoardSettings {
+    /// `light`, `dark` or `hyper`, applied when the project is opened
+    pub theme: Option<String>,
+    /// Board shown when the project is opened, e.g. `task`
+    pub default_board: Option<String>,
+    pub layout: LayoutSettings,
+    pub colors: ColorSettings,
+    /// Work-in-progress limits keyed by column (`active`), or by board and
+    /// column (`"task.active"`) when the same phase needs different limits
+    pub wip: BTreeMap<String, u32>,
+    /// Days without an update before a card in a phase where work is under
+    /// way is marked stale, keyed by board (`task`) or `default` for every
+    /// board. Zero turns the marker off
+    pub stale: BTreeMap<String, u32>,
+    /// Key bindings keyed by action (`details = "d"`), each a key or a list
+    /// of keys. The board checks the actions and conflicts
+    #[serde(deserialize_with = "one_or_many")]
+    pub keys: BTreeMap<String, Vec<String>>,
+}
+
+/// A table of values that are each either one string or a list of strings
+fn one_or_many<'de, D>(deserializer: D) -> Result<BTreeMap<String, Vec<String>>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany {
+        One(String),
+        Many(Vec<String>),
+    }
+
+    let table = BTreeMap::<String, OneOrMany>::deserialize(deserializer)?;
+    Ok(table
+        .into_iter()
+        .map(|(action, keys)| match keys {
+            OneOrMany::One(key) => (action, vec![key]),
+            OneOrMany::Many(keys) => (action, keys),
+        })
+        .collect())
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LayoutSettings {
+    /// Column width in pixels
+    pub column_width: Option<u32>,
+    /// Tighter cards that hide the created date
+    pub compact_cards: bool,
+    /// Open the detail panel when the board loads
+    pub detail_panel: bool,
+}
+
+/// Hex colors keyed by phase (`active`), board column (`bug` on the backlog
+/// board) or severity (`critical`)
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ColorSettings {
+    pub phase: BTreeMap<String, String>,
+    pub column: BTreeMap<String, String>,
+    pub severity: BTreeMap<String, String>,
+}
+
+/// Settings ready to apply, plus anything in the file that was ignored
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BoardSettingsResult {
+    pub settings: BoardSettings,
+    pub warnings: Vec<String>,
+}
+
+/// Load the board settings for the current project. A missing file gives
+/// the defaults; a malformed file or invalid entries give the defaults for
+/// those entries plus a warning, so a typo never stops a board from opening.
+#[tauri::command]
+pub async fn get_board_settings(
+    state: State<'_, std::sync::Mutex<AppState>>,
+) -> Result<BoardSettingsResult, String> {
+    let project_path = {
+        let app_state = state
+            .lock()
+            .map_err(|e| format!("Failed to lock state: {}", e))?;
+        app_state
+            .current_project
+            .as_ref()
+            .ok_or("No project loaded")?
+            .clone()
+    };
+
+    load_settings(&project_path.join(".metis").join(SETTINGS_FILE))
+}
+
+fn load_settings(path: &Path) -> Result<BoardSettingsResult, String> {
+    if !path.exists() {
+        return Ok(BoardSettingsResult {
+            settings: BoardSettings::default(),
+            warnings: vec![],
+        });
+    }
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+    Ok(parse_settings(&content))
+}
+
+fn parse_settings(content: &str) -> BoardSettingsResult {
+    let mut settings: BoardSettings = match toml::from_str(content) {
+        Ok(settings) => settings,
+        Err(e) => {
+            return BoardSettingsResult {
+                settings: BoardSettings::default(),
+                warnings: vec![format!(
+                    "{} is not valid, using defaults: {}",
+                    SETTINGS_FILE, e
+                )],
+            }
+        }
+    };
+
+    let mut warnings = Vec::new();
+    if let Some(theme) = settings.theme.take() {
+        match THEMES.contains(&theme.as_str()) {
+            true => settings.theme = Some(theme),
+            false => warnings.push(format!("Unknown theme '{}'", theme)),
+        }
+    }
+    if let Some(board) = settings.default_board.take() {
+        match BOARDS.contains(&board.as_str()) {
+            true => settings.default_board = Some(board),
+            false => warnings.push(format!("Unknown default_board '{}'", board)),
+        }
+    }
+    if let Some(width) = settings.layout.column_width {
+        let clamped = width.clamp(MIN_COLUMN_WIDTH, MAX_COLUMN_WIDTH);
+        if clamped != width {
+            warnings.push(format!(
+                "column_width {} is outside {}-{}, using {}",
+                width, MIN_COLUMN_WIDTH, MAX_COLUMN_WIDTH, clamped
+            ));
+            settings.layout.column_width = Some(clamped);
+        }
+    }
+
+    let colors = &mut settings.colors;
+    colors.severity.retain(
+        |severity, _| match SEVERITIES.contains(&severity.as_str()) {
+            true => true,
+            false => {
+                warnings.push(format!("Unknown severity '{}'", severity));
+                false
+            }
+        },
+    );
+    for (section, map) in [
+        ("phase", &mut colors.phase),
+        ("column", &mut colors.column),
+        ("severity", &mut colors.severity),
+    ] {
+        map.retain(|key, color| match is_hex_color(color) {
+            true => {
+                *color = expand_hex_color(color);
+                true
+            }
+            false => {
+                warnings.push(format!(
+                    "colors.{}.{} = '{}' is not a hex color like #3b82f6",
+                    section, key, color
+                ));
+                false
+            }
+        });
+    }
+
+    settings.wip.retain(|column, limit| match *limit {
+        0 => {
+            warnings.push(format!("wip.{} must be at least 1", column));
+            false
+        }
+        _ => true,
+    });
+
+    settings.stale.retain(|board, _| {
+        match board == "default" || BOARDS.contains(&board.as_str()) {
+            true => true,
+            false => {
+                warnings.push(format!("Unknown board 'stale.{}'", board));
+                false
+            }
+        }
+    });
+
+    BoardSettingsResult { settings, warnings }
+}
+
+/// `#rgb` or `#rrggbb`
+fn is_hex_color(color: &str) -> bool {
+    color
+        .strip_prefix('#')
+        .filter(|hex| hex.len() == 3 || hex.len() == 6)
+        .is_some_and(|hex| hex.chars().all(|c| c.is_ascii_hexdigit()))
+}
+
+/// Expand `#rgb` to `#rrggbb` so the board can append an alpha channel
+fn expand_hex_color(color: &str) -> String {
+    match color.len() {
+        4 => color
+            .chars()
+            .skip(1)
+            .fold(String::from("#"), |mut expanded, c| {
+                expanded.push(c);
+                expanded.push(c);
+                expanded
+            }),
+        _ => color.to_string(),
+    }
+    .to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_settings() {
+        let result = parse_settings(
+            r##"
+theme = "dark"
+default_board = "task"
+
+[layout]
+column_width = 900
+detail_panel = true
+
+[colors.phase]
+active = "#22c55e"
+blocked = "red"
+
+[colors.severity]
+critical = "#f00"
+urgent = "#ff0000"
+
+[wip]
+active = 3
+"task.blocked" = 0
+
+[stale]
+default = 14
+task = 0
+epic = 5
+
+[keys]
+details = "d"
+move_left = ["Shift+ArrowLeft", "H"]
+"##,
+        );
+
+        let settings = result.settings;
+        assert_eq!(settings.theme.as_deref(), Some("dark"));
+        assert_eq!(settings.default_board.as_deref(), Some("task"));
+        assert_eq!(settings.layout.column_width, Some(MAX_COLUMN_WIDTH));
+        assert!(settings.layout.detail_panel);
+        assert!(!settings.layout.compact_cards);
+        assert_eq!(settings.colors.phase.len(), 1);
+        assert_eq!(settings.colors.phase["active"], "#22c55e");
+        assert_eq!(settings.colors.severity.len(), 1);
+        assert_eq!(settings.colors.severity["critical"], "#ff0000");
+        assert_eq!(settings.wip.len(), 1);
+        assert_eq!(settings.wip["active"], 3);
+        assert_eq!(settings.stale.len(), 2);
+        assert_eq!(settings.stale["default"], 14);
+        assert_eq!(settings.stale["task"], 0);
+        assert_eq!(settings.keys["details"], vec!["d"]);
+        assert_eq!(settings.keys["move_left"], vec!["Shift+ArrowLeft", "H"]);
+        // Width, the non-hex color, the unknown severity, the zero limit and
+        // the unknown board
+        assert_eq!(result.warnings.len(), 5);
+
+        let result = parse_settings("theme = [");
+        assert_eq!(result.settings, BoardSettings::default());
+        assert_eq!(result.warnings.len(), 1);
+
+        let result = parse_settings("");
+        assert_eq!(result.settings, BoardSettings::default());
+        assert!(result.warnings.is_empty());
+    }
+}
@@ -1,7 +1,10 @@
+use crate::services::project::ensure_writable;
 use crate::AppState;
+use metis_core::application::services::workspace::{RemoteSyncService, RemoteSyncStage};
+use metis_core::domain::configuration::ConfigFile;
 use metis_core::{Application, Database};
 use serde::{Deserialize, Serialize};
-use tauri::State;
+use tauri::{AppHandle, Emitter, State};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct SyncResult {
@@ -117,3 +120,80 @@ pub async fn sync_project(
         messages,
     })
 }
+
+/// What a sync with the git remote pulled and pushed, by path relative to
+/// `.metis/`; when documents conflict, nothing was synced and they are listed
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RemoteSyncResult {
+    pub remote: String,
+    pub pull: Vec<String>,
+    pub push: Vec<String>,
+    pub conflicts: Vec<String>,
+}
+
+/// Sync with the remote in `[sync]` of config.toml, or the branch's
+/// upstream: pull its changes, re-sync the database, then commit and push
+/// local changes. Each stage is emitted as a `remote-sync-progress` event
+/// while git runs.
+#[tauri::command]
+pub async fn remote_sync(
+    app: AppHandle,
+    state: State<'_, std::sync::Mutex<AppState>>,
+) -> Result<RemoteSyncResult, String> {
+    ensure_writable(&state)?;
+    let project_path = {
+        let app_state = state
+            .lock()
+            .map_err(|e| format!("Failed to get app state: {}", e))?;
+        app_state.current_project.clone()
+    }
+    .ok_or("No project currently loaded")?;
+
+    let metis_dir = project_path.join(".metis");
+    if !metis_dir.exists() {
+        return Err("Not a valid Metis project directory".to_string());
+    }
+    let sync = ConfigFile::load(metis_dir.join("config.toml"))
+        .map(|config| config.sync().clone())
+        .unwrap_or_default();
+
+    // git blocks, so keep it off the async runtime
+    let workspace_dir = metis_dir.clone();
+    let progress = app.clone();
+    let (plan, remote) = tauri::async_runtime::spawn_blocking(move || {
+        RemoteSyncService::new(&workspace_dir).apply(&sync, |stage| {
+            let message = match stage {
+                RemoteSyncStage::Fetching(label) => format!("Fetching {}", label),
+                RemoteSyncStage::Comparing => "Comparing with the remote".to_string(),
+                RemoteSyncStage::ScanningUntracked => "Looking for new documents".to_string(),
+                RemoteSyncStage::Committing => "Committing local changes".to_string(),
+                RemoteSyncStage::Pulling(label) => format!("Pulling from {}", label),
+                RemoteSyncStage::Pushing(label) => format!("Pushing to {}", label),
+            };
+            progress.emit("remote-sync-progress", message).ok();
+        })
+    })
+    .await
+    .map_err(|e| format!("Remote sync stopped: {}", e))?
+    .map_err(|e| format!("Remote sync failed: {}", e))?;
+
+    // Bring the database up to date with what was pulled
+    if plan.conflicts.is_empty() && !plan.pull.is_empty() {
+        app.emit("remote-sync-progress", "Updating the database")
+            .ok();
+        let db_path = metis_dir.join("metis.db");
+        let database = Database::new(db_path.to_str().unwrap())
+            .map_err(|e| format!("Failed to initialize database: {}", e))?;
+        Application::new(database)
+            .sync_directory(&metis_dir)
+            .await
+            .map_err(|e| format!("Pulled, but the database sync failed: {}", e))?;
+    }
+
+    Ok(RemoteSyncResult {
+        remote,
+        pull: plan.pull.into_keys().collect(),
+        push: plan.push.into_keys().collect(),
+        conflicts: plan.conflicts.into_keys().collect(),
+    })
+}
@@ -1,11 +1,21 @@
+use crate::services::project::ensure_writable;
 use crate::AppState;
 use metis_core::{
-    application::services::workspace::transition::PhaseTransitionService,
-    domain::documents::types::Phase, Application, Database,
+    application::services::{
+        workspace::{transition::PhaseTransitionService, CriteriaService},
+        QueryService,
+    },
+    domain::documents::{
+        criteria::Criterion,
+        types::{DocumentType, Phase},
+    },
+    Application, Database,
 };
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
 use tauri::State;
 
-fn parse_phase(phase_str: &str) -> Result<Phase, String> {
+pub(crate) fn parse_phase(phase_str: &str) -> Result<Phase, String> {
     match phase_str.to_lowercase().as_str() {
         "draft" => Ok(Phase::Draft),
         "review" => Ok(Phase::Review),
@@ -27,39 +37,135 @@ fn parse_phase(phase_str: &str) -> Result<Phase, String> {
     }
 }
 
+fn metis_dir(state: &State<'_, std::sync::Mutex<AppState>>) -> Result<PathBuf, String> {
+    let app_state = state
+        .lock()
+        .map_err(|e| format!("Failed to lock state: {}", e))?;
+    let project_path = app_state
+        .current_project
+        .as_ref()
+        .ok_or("No project loaded")?;
+    Ok(project_path.join(".metis"))
+}
+
 #[tauri::command]
 pub async fn transition_phase(
     state: State<'_, std::sync::Mutex<AppState>>,
     short_code: String,
     new_phase: Option<String>,
 ) -> Result<String, String> {
-    let project_path = {
-        let app_state = state
-            .lock()
-            .map_err(|e| format!("Failed to lock state: {}", e))?;
-        app_state
-            .current_project
-            .as_ref()
-            .ok_or("No project loaded")?
-            .clone()
-    };
+    ensure_writable(&state)?;
+    let metis_dir = metis_dir(&state)?;
+    transition(&metis_dir, &short_code, new_phase).await
+}
+
+/// Move a card to an adjacent board column from the keyboard. Unlike a drag,
+/// the move is checked before anything is written: it has to be a valid
+/// transition, and moving forward needs the document's exit criteria met.
+#[tauri::command]
+pub async fn move_document(
+    state: State<'_, std::sync::Mutex<AppState>>,
+    short_code: String,
+    new_phase: String,
+) -> Result<String, String> {
+    ensure_writable(&state)?;
+    let metis_dir = metis_dir(&state)?;
+    let target_phase = parse_phase(&new_phase)?;
+
+    let db_path = metis_dir.join("metis.db");
+    let database = Database::new(db_path.to_str().unwrap())
+        .map_err(|e| format!("Failed to open database: {}", e))?;
+    let mut app = Application::new(database);
+    let (doc, criteria) = app.with_database(|service| {
+        let doc = service
+            .find_by_short_code(&short_code)
+            .map_err(|e| format!("Failed to find document: {}", e))?
+            .ok_or_else(|| format!("Document '{}' not found", short_code))?;
+        let status = CriteriaService::new(&metis_dir)
+            .status(&short_code, service)
+            .map_err(|e| format!("Failed to read exit criteria: {}", e))?;
+        Ok::<_, String>((doc, status.criteria))
+    })?;
 
-    let metis_dir = project_path.join(".metis");
+    let doc_type = DocumentType::from_str(&doc.document_type)
+        .map_err(|e| format!("Unknown document type: {}", e))?;
+    let current_phase = parse_phase(&doc.phase)?;
+    check_move(
+        &short_code,
+        doc_type,
+        current_phase,
+        target_phase,
+        &criteria,
+    )?;
 
-    let transition_service = PhaseTransitionService::new(&metis_dir);
+    transition(&metis_dir, &short_code, Some(new_phase)).await
+}
+
+/// Why a keyboard move isn't allowed, if it isn't
+fn check_move(
+    short_code: &str,
+    doc_type: DocumentType,
+    from: Phase,
+    to: Phase,
+    criteria: &[Criterion],
+) -> Result<(), String> {
+    if !doc_type.can_transition(from, to) {
+        let allowed = doc_type.valid_transitions_from(from);
+        return Err(match allowed.as_slice() {
+            [] => format!("{} can't move on from {}", short_code, from),
+            _ => format!(
+                "{} can't move from {} to {} (it can move to: {})",
+                short_code,
+                from,
+                to,
+                allowed
+                    .iter()
+                    .map(|phase| phase.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        });
+    }
+
+    let sequence = doc_type.phase_sequence();
+    let position = |phase: Phase| sequence.iter().position(|p| *p == phase);
+    let forward = matches!((position(from), position(to)), (Some(f), Some(t)) if t > f);
+    let checked = criteria
+        .iter()
+        .filter(|criterion| criterion.checked)
+        .count();
+    if forward && checked < criteria.len() {
+        return Err(format!(
+            "{} has {} of {} exit criteria checked; check them off before moving it to {}",
+            short_code,
+            checked,
+            criteria.len(),
+            to
+        ));
+    }
+
+    Ok(())
+}
+
+async fn transition(
+    metis_dir: &Path,
+    short_code: &str,
+    new_phase: Option<String>,
+) -> Result<String, String> {
+    let transition_service = PhaseTransitionService::new(metis_dir);
 
     // Perform the transition using short code directly
     let result = if let Some(phase_str) = new_phase {
         // Transition to specific phase
         let target_phase = parse_phase(&phase_str)?;
         transition_service
-            .transition_document(&short_code, target_phase)
+            .transition_document(short_code, target_phase)
             .await
             .map_err(|e| format!("Failed to transition phase: {}", e))?
     } else {
         // Auto-transition to next phase
         transition_service
-            .transition_to_next_phase(&short_code)
+            .transition_to_next_phase(short_code)
             .await
             .map_err(|e| format!("Failed to transition phase: {}", e))?
     };
@@ -71,7 +177,7 @@ pub async fn transition_phase(
     let app = Application::new(database);
 
     let sync_results = app
-        .sync_directory(&metis_dir)
+        .sync_directory(metis_dir)
         .await
         .map_err(|e| format!("Failed to sync workspace: {}", e))?;
 
@@ -88,7 +194,7 @@ pub async fn transition_phase(
 
     // Check if the document in the database has the correct phase
     let updated_doc = app_verify
-        .with_database(|service| service.find_by_short_code(&short_code))
+        .with_database(|service| service.find_by_short_code(short_code))
         .map_err(|e| format!("Failed to verify document update: {}", e))?;
 
     if let Some(doc) = updated_doc {
@@ -104,9 +210,64 @@ pub async fn transition_phase(
         tracing::warn!("Document not found in database after sync");
     }
 
+    if result.document_type == DocumentType::Adr && result.to_phase == Phase::Decided {
+        supersede_replaced(metis_dir, short_code).await?;
+    }
+
     Ok(result.to_phase.to_string())
 }
 
+/// Once a new ADR is decided, move the decisions it lists in `supersedes`
+/// to the superseded phase. ADRs that were already moved on are left alone.
+async fn supersede_replaced(metis_dir: &Path, short_code: &str) -> Result<(), String> {
+    let db_path = metis_dir.join("metis.db");
+    let open = || {
+        Database::new(db_path.to_str().unwrap())
+            .map_err(|e| format!("Failed to open database: {}", e))
+    };
+    let mut query = QueryService::new(open()?.into_repository());
+    let mut app = Application::new(open()?);
+
+    let mut replaced = Vec::new();
+    for edge in query
+        .edges_from(short_code)
+        .map_err(|e| format!("Failed to read the ADRs {} supersedes: {}", short_code, e))?
+    {
+        if edge.kind != "supersedes" {
+            continue;
+        }
+        let old = app
+            .with_database(|service| service.find_by_short_code(&edge.to_code))
+            .map_err(|e| format!("Failed to find document: {}", e))?;
+        if old.is_some_and(|doc| doc.phase == Phase::Decided.to_string()) {
+            replaced.push(edge.to_code);
+        }
+    }
+    if replaced.is_empty() {
+        return Ok(());
+    }
+
+    let transition_service = PhaseTransitionService::new(metis_dir);
+    for old in &replaced {
+        transition_service
+            .transition_document(old, Phase::Superseded)
+            .await
+            .map_err(|e| {
+                format!(
+                    "{} is decided, but {} couldn't be superseded: {}",
+                    short_code, old, e
+                )
+            })?;
+        tracing::info!("{} superseded by {}", old, short_code);
+    }
+
+    Application::new(open()?)
+        .sync_directory(metis_dir)
+        .await
+        .map_err(|e| format!("Failed to sync workspace: {}", e))?;
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -124,4 +285,55 @@ mod tests {
         assert!(result.is_err());
         assert!(result.unwrap_err().contains("Unknown phase"));
     }
+
+    fn criterion_list(checked: &[bool]) -> Vec<Criterion> {
+        let content: String = checked
+            .iter()
+            .map(|done| format!("- [{}] item\n", if *done { "x" } else { " " }))
+            .collect();
+        metis_core::domain::documents::criteria::parse_criteria(&format!(
+            "## Exit Criteria\n{}",
+            content
+        ))
+    }
+
+    #[test]
+    fn test_check_move() {
+        let task = DocumentType::Task;
+
+        // Forward moves need every exit criterion checked
+        assert!(check_move("T-1", task, Phase::Todo, Phase::Active, &[]).is_ok());
+        assert!(check_move(
+            "T-1",
+            task,
+            Phase::Todo,
+            Phase::Active,
+            &criterion_list(&[true, true])
+        )
+        .is_ok());
+        let unmet = check_move(
+            "T-1",
+            task,
+            Phase::Active,
+            Phase::Completed,
+            &criterion_list(&[true, false]),
+        );
+        assert!(unmet.unwrap_err().contains("1 of 2 exit criteria"));
+
+        // Moving back out of blocked isn't forward
+        assert!(check_move(
+            "T-1",
+            task,
+            Phase::Blocked,
+            Phase::Todo,
+            &criterion_list(&[false])
+        )
+        .is_ok());
+
+        // Moves the phase graph doesn't allow
+        let skip = check_move("T-1", task, Phase::Backlog, Phase::Active, &[]);
+        assert!(skip.unwrap_err().contains("it can move to: todo"));
+        let back = check_move("T-1", task, Phase::Completed, Phase::Active, &[]);
+        assert!(back.unwrap_err().contains("can't move on from completed"));
+    }
 }
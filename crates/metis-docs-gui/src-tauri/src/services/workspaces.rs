@@ -0,0 +1,167 @@
+use chrono::{DateTime, Utc};
+use metis_core::application::services::workspace::WorkspaceDetectionService;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// How many workspaces the recent list keeps
+const MAX_RECENT: usize = 20;
+
+/// A workspace opened before, as listed in the picker
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecentWorkspace {
+    /// The project directory holding `.metis/`
+    pub path: String,
+    /// The directory name, for display
+    pub name: String,
+    pub last_opened: DateTime<Utc>,
+    /// False when the directory or its `.metis/` has gone
+    pub available: bool,
+}
+
+/// `~/.config/metis/workspaces.toml`, next to the global templates
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct WorkspacesFile {
+    #[serde(default, rename = "workspace")]
+    workspaces: Vec<WorkspaceEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct WorkspaceEntry {
+    path: String,
+    last_opened: DateTime<Utc>,
+}
+
+/// The recently used workspaces, most recent first
+#[tauri::command]
+pub async fn list_recent_workspaces() -> Result<Vec<RecentWorkspace>, String> {
+    let file = workspaces_path().ok_or("No config directory to remember workspaces in")?;
+    Ok(read_workspaces(&file)
+        .workspaces
+        .into_iter()
+        .map(|entry| {
+            let path = Path::new(&entry.path);
+            RecentWorkspace {
+                name: path
+                    .file_name()
+                    .map(|name| name.to_string_lossy().into_owned())
+                    .unwrap_or_else(|| entry.path.clone()),
+                available: path.join(".metis").is_dir(),
+                path: entry.path,
+                last_opened: entry.last_opened,
+            }
+        })
+        .collect())
+}
+
+/// Drop a workspace from the recent list. The workspace itself is untouched.
+#[tauri::command]
+pub async fn forget_workspace(path: String) -> Result<(), String> {
+    let file = workspaces_path().ok_or("No config directory to remember workspaces in")?;
+    let mut workspaces = read_workspaces(&file);
+    workspaces.workspaces.retain(|entry| entry.path != path);
+    write_workspaces(&file, &workspaces)
+}
+
+/// The workspace the app was started in: the directory given on the command
+/// line, or else the current directory, or any directory above them that
+/// holds `.metis/`. None when started elsewhere, e.g. from a launcher.
+#[tauri::command]
+pub async fn detect_launch_workspace() -> Result<Option<String>, String> {
+    let start = match std::env::args().skip(1).find(|arg| !arg.starts_with('-')) {
+        Some(arg) => PathBuf::from(arg),
+        None => std::env::current_dir().map_err(|e| format!("No current directory: {}", e))?,
+    };
+    let root = WorkspaceDetectionService::new()
+        .get_workspace_root(&start)
+        .map_err(|e| format!("Failed to look for a workspace: {}", e))?;
+    Ok(root.map(|root| root.to_string_lossy().into_owned()))
+}
+
+/// Put a workspace at the top of the recent list. Called whenever a
+/// project is loaded.
+pub fn remember_workspace(project_path: &Path) -> Result<(), String> {
+    let file = workspaces_path().ok_or("No config directory to remember workspaces in")?;
+    let mut workspaces = read_workspaces(&file);
+    touch(&mut workspaces, &project_path.to_string_lossy(), Utc::now());
+    write_workspaces(&file, &workspaces)
+}
+
+fn touch(workspaces: &mut WorkspacesFile, path: &str, now: DateTime<Utc>) {
+    workspaces.workspaces.retain(|entry| entry.path != path);
+    workspaces.workspaces.insert(
+        0,
+        WorkspaceEntry {
+            path: path.to_string(),
+            last_opened: now,
+        },
+    );
+    workspaces.workspaces.truncate(MAX_RECENT);
+}
+
+fn workspaces_path() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join("metis").join("workspaces.toml"))
+}
+
+// A missing or unreadable file is an empty list; it is rewritten on the
+// next project load
+fn read_workspaces(file: &Path) -> WorkspacesFile {
+    std::fs::read_to_string(file)
+        .ok()
+        .and_then(|content| toml::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+fn write_workspaces(file: &Path, workspaces: &WorkspacesFile) -> Result<(), String> {
+    let content = toml::to_string(workspaces)
+        .map_err(|e| format!("Failed to save recent workspaces: {}", e))?;
+    if let Some(dir) = file.parent() {
+        std::fs::create_dir_all(dir)
+            .map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+    }
+    std::fs::write(file, content).map_err(|e| format!("Failed to write {}: {}", file.display(), e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_recent_workspaces_round_trip() {
+        let temp_dir = tempdir().unwrap();
+        let file = temp_dir.path().join("metis").join("workspaces.toml");
+        let mut workspaces = read_workspaces(&file);
+        assert!(workspaces.workspaces.is_empty());
+
+        let start = Utc::now();
+        for (offset, path) in ["/work/api", "/work/web", "/work/api"].iter().enumerate() {
+            touch(
+                &mut workspaces,
+                path,
+                start + chrono::Duration::seconds(offset as i64),
+            );
+        }
+        write_workspaces(&file, &workspaces).unwrap();
+
+        // Reopening a workspace moves it to the top instead of listing it twice
+        let paths: Vec<String> = read_workspaces(&file)
+            .workspaces
+            .into_iter()
+            .map(|entry| entry.path)
+            .collect();
+        assert_eq!(paths, vec!["/work/api", "/work/web"]);
+    }
+
+    #[test]
+    fn test_recent_workspaces_are_capped() {
+        let mut workspaces = WorkspacesFile::default();
+        for index in 0..MAX_RECENT + 5 {
+            touch(&mut workspaces, &format!("/work/{}", index), Utc::now());
+        }
+        assert_eq!(workspaces.workspaces.len(), MAX_RECENT);
+        assert_eq!(
+            workspaces.workspaces[0].path,
+            format!("/work/{}", MAX_RECENT + 4)
+        );
+    }
+}
@@ -0,0 +1,48 @@
+import { ref, readonly } from 'vue'
+
+export type ActivityType = 'success' | 'error' | 'info'
+
+export interface ActivityEntry {
+  id: number
+  time: Date
+  message: string
+  type: ActivityType
+}
+
+// Older entries are dropped past this many
+const MAX_ENTRIES = 200
+
+// What happened this session, newest first. Shared by the whole app and
+// not kept between sessions
+const entries = ref<ActivityEntry[]>([])
+const paneOpen = ref(false)
+// Errors logged since the pane was last open
+const unreadErrors = ref(0)
+let nextId = 1
+
+export function useActivityLog() {
+  const logActivity = (message: string, type: ActivityType = 'info') => {
+    entries.value = [{ id: nextId++, time: new Date(), message, type }, ...entries.value].slice(0, MAX_ENTRIES)
+    if (type === 'error' && !paneOpen.value) unreadErrors.value++
+  }
+
+  const setPaneOpen = (open: boolean) => {
+    paneOpen.value = open
+    if (open) unreadErrors.value = 0
+  }
+
+  const clearActivity = () => {
+    entries.value = []
+    unreadErrors.value = 0
+  }
+
+  return {
+    entries: readonly(entries),
+    paneOpen: readonly(paneOpen),
+    unreadErrors: readonly(unreadErrors),
+    logActivity,
+    setPaneOpen,
+    togglePane: () => setPaneOpen(!paneOpen.value),
+    clearActivity,
+  }
+}
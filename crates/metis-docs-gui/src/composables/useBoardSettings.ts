@@ -0,0 +1,87 @@
+import { ref, readonly } from 'vue'
+import type { BoardSettings } from '../lib/tauri-api'
+import { getBoardSettings } from '../lib/tauri-api'
+import { defaultKeymap, formatKey, matchesKey, resolveKeymap, type KeyAction } from '../lib/keymap'
+
+const defaultSettings = (): BoardSettings => ({
+  theme: null,
+  default_board: null,
+  layout: {
+    column_width: null,
+    compact_cards: false,
+    detail_panel: false,
+  },
+  colors: {
+    phase: {},
+    column: {},
+    severity: {},
+  },
+  wip: {},
+  stale: {},
+  keys: {},
+})
+
+// Shared by every board component for the current project
+const settings = ref<BoardSettings>(defaultSettings())
+const keymap = ref(defaultKeymap())
+
+export function useBoardSettings() {
+  // Load .metis/board.toml for the current project. Returns anything the
+  // backend ignored so the caller can tell the user; on failure the
+  // defaults stay in place
+  const loadBoardSettings = async (): Promise<string[]> => {
+    try {
+      const result = await getBoardSettings()
+      settings.value = result.settings
+      const resolved = resolveKeymap(result.settings.keys)
+      keymap.value = resolved.keymap
+      return [...result.warnings, ...resolved.warnings]
+    } catch (error) {
+      settings.value = defaultSettings()
+      keymap.value = defaultKeymap()
+      return [`${error}`]
+    }
+  }
+
+  // Configured colors, or null to fall back to the theme
+  const phaseColor = (phase: string): string | null =>
+    settings.value.colors.phase[phase] ?? null
+
+  const columnColor = (key: string): string | null =>
+    settings.value.colors.column[key] ?? settings.value.colors.phase[key] ?? null
+
+  const severityColor = (severity: string): string | null =>
+    settings.value.colors.severity[severity] ?? null
+
+  // A board-specific limit ("task.active") wins over one for the column name
+  const wipLimit = (board: string, column: string): number | null =>
+    settings.value.wip[`${board}.${column}`] ?? settings.value.wip[column] ?? null
+
+  // Days after which cards on a board count as stale, or null when the
+  // board has no limit or it is set to 0
+  const staleDays = (board: string): number | null =>
+    (settings.value.stale[board] ?? settings.value.stale.default) || null
+
+  // Whether a key press is bound to a board shortcut
+  const keyMatches = (action: KeyAction, event: KeyboardEvent): boolean =>
+    keymap.value[action].some(chord => matchesKey(chord, event))
+
+  // The first key bound to an action, for tooltips, e.g. " (i)"
+  const keyHint = (action: KeyAction): string => {
+    const chord = keymap.value[action][0]
+    return chord ? ` (${formatKey(chord)})` : ''
+  }
+
+  return {
+    settings: readonly(settings),
+    keymap: readonly(keymap),
+    loadBoardSettings,
+    phaseColor,
+    columnColor,
+    severityColor,
+    wipLimit,
+    staleDays,
+    keyMatches,
+    keyHint,
+  }
+}
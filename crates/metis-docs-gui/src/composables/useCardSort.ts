@@ -0,0 +1,35 @@
+import { ref, readonly } from 'vue'
+import { CARD_SORTS, type CardSort } from '../lib/board-config'
+import type { BoardType } from '../types/board'
+
+const STORAGE_KEY = 'metis-card-sort'
+
+// The sort chosen on each board; boards not in here are sorted manually
+const loadSorts = (): Partial<Record<BoardType, CardSort>> => {
+  try {
+    const stored = JSON.parse(localStorage.getItem(STORAGE_KEY) || '{}')
+    const known = CARD_SORTS.map(option => option.key as string)
+    return Object.fromEntries(
+      Object.entries(stored).filter(([, sort]) => known.includes(sort as string))
+    ) as Partial<Record<BoardType, CardSort>>
+  } catch {
+    return {}
+  }
+}
+
+const sorts = ref(loadSorts())
+
+export function useCardSort() {
+  const cardSort = (board: BoardType): CardSort => sorts.value[board] ?? 'manual'
+
+  const setCardSort = (board: BoardType, sort: CardSort) => {
+    sorts.value = { ...sorts.value, [board]: sort }
+    localStorage.setItem(STORAGE_KEY, JSON.stringify(sorts.value))
+  }
+
+  return {
+    cardSorts: readonly(sorts),
+    cardSort,
+    setCardSort,
+  }
+}
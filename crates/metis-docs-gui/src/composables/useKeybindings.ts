@@ -0,0 +1,21 @@
+import { ref, readonly } from 'vue'
+
+export type KeybindingMode = 'standard' | 'vim'
+
+const STORAGE_KEY = 'metis-keybindings'
+
+const keybindingMode = ref<KeybindingMode>(
+  localStorage.getItem(STORAGE_KEY) === 'vim' ? 'vim' : 'standard'
+)
+
+export function useKeybindings() {
+  const setKeybindingMode = (mode: KeybindingMode) => {
+    keybindingMode.value = mode
+    localStorage.setItem(STORAGE_KEY, mode)
+  }
+
+  return {
+    keybindingMode: readonly(keybindingMode),
+    setKeybindingMode,
+  }
+}
@@ -0,0 +1,62 @@
+import { ref, readonly } from 'vue'
+import type { BoardType } from '../types/board'
+
+const STORAGE_KEY = 'metis-layout'
+
+// The share of the document viewer's split given to the markdown source
+export const DEFAULT_SPLIT = 0.5
+const MIN_SPLIT = 0.2
+const MAX_SPLIT = 0.8
+
+interface Layout {
+  // The collapsed columns of each board, by phase key
+  collapsed: Partial<Record<BoardType, string[]>>
+  split: number
+}
+
+export const clampSplit = (split: number) => Math.min(MAX_SPLIT, Math.max(MIN_SPLIT, split))
+
+const loadLayout = (): Layout => {
+  try {
+    const stored = JSON.parse(localStorage.getItem(STORAGE_KEY) || '{}')
+    const collapsed = Object.fromEntries(
+      Object.entries(stored.collapsed ?? {}).filter(([, phases]) =>
+        Array.isArray(phases) && phases.every(phase => typeof phase === 'string')
+      )
+    ) as Partial<Record<BoardType, string[]>>
+    const split = typeof stored.split === 'number' ? clampSplit(stored.split) : DEFAULT_SPLIT
+    return { collapsed, split }
+  } catch {
+    return { collapsed: {}, split: DEFAULT_SPLIT }
+  }
+}
+
+const layout = ref(loadLayout())
+
+const save = () => localStorage.setItem(STORAGE_KEY, JSON.stringify(layout.value))
+
+export function useLayout() {
+  const isCollapsed = (board: BoardType, phase: string): boolean =>
+    layout.value.collapsed[board]?.includes(phase) ?? false
+
+  const toggleCollapsed = (board: BoardType, phase: string) => {
+    const current = layout.value.collapsed[board] ?? []
+    const phases = current.includes(phase)
+      ? current.filter(collapsed => collapsed !== phase)
+      : [...current, phase]
+    layout.value = { ...layout.value, collapsed: { ...layout.value.collapsed, [board]: phases } }
+    save()
+  }
+
+  const setSplit = (split: number) => {
+    layout.value = { ...layout.value, split: clampSplit(split) }
+    save()
+  }
+
+  return {
+    layout: readonly(layout),
+    isCollapsed,
+    toggleCollapsed,
+    setSplit,
+  }
+}
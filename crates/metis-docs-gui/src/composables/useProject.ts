@@ -1,5 +1,6 @@
-import { reactive, onMounted, readonly, toRef } from 'vue'
+import { reactive, onMounted, readonly, toRef, computed } from 'vue'
 import type { ProjectInfo } from '../lib/tauri-api'
+import { forgetWorkspace, listRecentWorkspaces } from '../lib/tauri-api'
 
 interface ProjectState {
   currentProject: ProjectInfo | null
@@ -15,6 +16,10 @@ const state = reactive<ProjectState>({
   error: null,
 })
 
+// Why the open project can't be changed, as reported when it was loaded;
+// null when it can
+const readOnly = computed(() => state.currentProject?.read_only ?? null)
+
 export function useProject() {
   // Load recent projects from localStorage on first use
   onMounted(() => {
@@ -27,8 +32,29 @@ export function useProject() {
         // Failed to load recent projects
       }
     }
+    mergeRecentWorkspaces()
   })
 
+  // Workspaces opened in any session are kept in ~/.config/metis, most
+  // recent first; ones only known to this window's storage follow them
+  const mergeRecentWorkspaces = async () => {
+    try {
+      const workspaces = await listRecentWorkspaces()
+      const remembered: ProjectInfo[] = workspaces.map(workspace => ({
+        path: workspace.path,
+        is_valid: workspace.available,
+        vision_exists: workspace.available,
+      }))
+      const rememberedPaths = new Set(remembered.map(project => project.path))
+      state.recentProjects = [
+        ...remembered,
+        ...state.recentProjects.filter(project => !rememberedPaths.has(project.path)),
+      ]
+    } catch (error) {
+      // Keep the list from localStorage
+    }
+  }
+
   const loadProject = async (path: string): Promise<void> => {
     state.isLoading = true
     state.error = null
@@ -81,6 +107,9 @@ export function useProject() {
   }
 
   const removeProject = (path: string) => {
+    forgetWorkspace(path).catch(() => {
+      // Not in the global list
+    })
     state.recentProjects = state.recentProjects.filter(p => p.path !== path)
     const updated = state.recentProjects.filter(p => p.path !== path)
     localStorage.setItem('metis-recent-projects', JSON.stringify(updated))
@@ -98,6 +127,7 @@ export function useProject() {
     recentProjects: readonly(toRef(state, 'recentProjects')),
     isLoading: readonly(toRef(state, 'isLoading')),
     error: readonly(toRef(state, 'error')),
+    readOnly,
     
     // Actions
     loadProject,
@@ -308,6 +308,56 @@ export function getAllInitiatives(documents: DocumentInfo[]): InitiativeFilterOp
     .sort((a, b) => a.title.localeCompare(b.title));
 }
 
+// Documents that head a stream of work, so the boards can zoom into them
+export const ZOOMABLE_TYPES = ['strategy', 'initiative'];
+
+// Only a decided ADR can be replaced by a new one
+export function isSupersedable(doc: DocumentInfo): boolean {
+  return doc.document_type === 'adr' && doc.phase === 'decided';
+}
+
+// The document a card hangs from: its parent, or the initiative of a task
+// whose parent isn't set
+function lineageParent(doc: DocumentInfo): string | null {
+  return doc.parent_id ?? doc.initiative_id ?? null;
+}
+
+/**
+ * The short codes of a document and everything below it, following parent
+ * links down from it
+ */
+export function descendantCodes(documents: DocumentInfo[], rootCode: string): Set<string> {
+  const codes = new Set([rootCode]);
+  let added = true;
+  while (added) {
+    added = false;
+    for (const doc of documents) {
+      const parent = lineageParent(doc);
+      if (parent && codes.has(parent) && !codes.has(doc.short_code)) {
+        codes.add(doc.short_code);
+        added = true;
+      }
+    }
+  }
+  return codes;
+}
+
+/**
+ * A document's ancestors, the top-level one first, ending with the
+ * document itself
+ */
+export function lineageOf(documents: DocumentInfo[], doc: DocumentInfo): DocumentInfo[] {
+  const chain = [doc];
+  let parent = lineageParent(doc);
+  while (parent && !chain.some(item => item.short_code === parent)) {
+    const found = documents.find(item => item.short_code === parent);
+    if (!found) break;
+    chain.unshift(found);
+    parent = lineageParent(found);
+  }
+  return chain;
+}
+
 /**
  * Get documents organized by phase for a board type
  * @param documents All documents
@@ -372,4 +422,238 @@ export function getDocumentsByPhase(
   });
 
   return documentsByPhase;
-}
\ No newline at end of file
+}
+
+/**
+ * The column next to a phase on a board, to move a card to from the
+ * keyboard: -1 for the column to the left, 1 for the one to the right.
+ * Null at either end, and on the backlog board, whose columns are
+ * categories rather than phases.
+ */
+export function adjacentPhase(boardType: BoardType, phase: string, direction: -1 | 1): PhaseConfig | null {
+  const config = getBoardConfig(boardType);
+  if (!config || boardType === 'backlog') return null;
+
+  const index = config.phases.findIndex(column => column.key === phase);
+  if (index === -1) return null;
+  return config.phases[index + direction] ?? null;
+}
+
+// Phases where nothing is under way yet, or the work is done, so a card in
+// them is never stale however old it is
+const RESTING_PHASES = ['draft', 'todo', 'backlog', 'published', 'completed', 'decided', 'superseded'];
+
+/**
+ * Whole days since a card was last updated, when that is more than the
+ * board's limit and the card is in a phase where work is under way.
+ * Null otherwise
+ */
+export function staleAge(doc: DocumentInfo, days: number | null, now: number = Date.now()): number | null {
+  if (!days || RESTING_PHASES.includes(doc.phase)) return null;
+  const age = Math.floor((now / 1000 - doc.updated_at) / 86400);
+  return age >= days ? age : null;
+}
+
+/**
+ * Order of the cards within each column. 'manual' keeps the order the
+ * documents load in, which drag and drop works with
+ */
+export type CardSort = 'manual' | 'updated_at' | 'priority' | 'due_date' | 'title';
+
+export const CARD_SORTS: { key: CardSort; title: string }[] = [
+  { key: 'manual', title: 'Manual' },
+  { key: 'updated_at', title: 'Recently updated' },
+  { key: 'priority', title: 'Priority' },
+  { key: 'due_date', title: 'Due date' },
+  { key: 'title', title: 'Title' },
+];
+
+const PRIORITY_RANKS: Record<string, number> = { critical: 0, high: 1, medium: 2, low: 3 };
+
+// The `priority` property ranked as `metis list --sort priority` does: named
+// levels first, then numbers where lower is more urgent
+function priorityRank(doc: DocumentInfo): number | null {
+  const value = doc.priority?.trim().toLowerCase();
+  if (!value) return null;
+  if (value in PRIORITY_RANKS) return PRIORITY_RANKS[value];
+  return /^[0-9]/.test(value) ? parseFloat(value) : null;
+}
+
+// The day of the `due_date` property, as sortable YYYY-MM-DD text
+function dueDate(doc: DocumentInfo): string | null {
+  const day = doc.due_date?.trim().slice(0, 10);
+  return day && /^\d{4}-\d{2}-\d{2}$/.test(day) ? day : null;
+}
+
+// Cards without the field go last, whatever the sort
+function compareMissingLast<T>(a: T | null, b: T | null, compare: (a: T, b: T) => number): number {
+  if (a === null || b === null) return a === null ? (b === null ? 0 : 1) : -1;
+  return compare(a, b);
+}
+
+/**
+ * Compare two cards for a column sort. The most urgent or most recently
+ * updated card comes first; ties fall back to the short code
+ */
+export function compareCards(a: DocumentInfo, b: DocumentInfo, sort: CardSort): number {
+  let order = 0;
+  switch (sort) {
+    case 'updated_at':
+      order = b.updated_at - a.updated_at;
+      break;
+    case 'priority':
+      order = compareMissingLast(priorityRank(a), priorityRank(b), (x, y) => x - y);
+      break;
+    case 'due_date':
+      order = compareMissingLast(dueDate(a), dueDate(b), (x, y) => x.localeCompare(y));
+      break;
+    case 'title':
+      order = a.title.localeCompare(b.title, undefined, { sensitivity: 'base' });
+      break;
+    case 'manual':
+      return 0;
+  }
+  return order || a.short_code.localeCompare(b.short_code);
+}
+
+/**
+ * Sort the cards of each column
+ */
+export function sortDocumentsByPhase(
+  documentsByPhase: Record<string, DocumentInfo[]>,
+  sort: CardSort
+): Record<string, DocumentInfo[]> {
+  if (sort === 'manual') return documentsByPhase;
+
+  const sorted: Record<string, DocumentInfo[]> = {};
+  Object.entries(documentsByPhase).forEach(([phase, documents]) => {
+    sorted[phase] = [...documents].sort((a, b) => compareCards(a, b, sort));
+  });
+  return sorted;
+}
+
+/**
+ * A run of text in a search-highlighted string
+ */
+export interface HighlightSegment {
+  text: string;
+  match: boolean;
+}
+
+/**
+ * Whether a card matches the board search: every space-separated term must
+ * appear in its title, short code, a tag or its assignee (case-insensitive)
+ */
+export function matchesBoardSearch(doc: DocumentInfo, query: string): boolean {
+  const terms = query.toLowerCase().split(/\s+/).filter(Boolean);
+  if (terms.length === 0) return true;
+
+  const fields = [doc.title, doc.short_code, doc.assignee ?? '', ...(doc.tags || [])]
+    .map(field => field.toLowerCase());
+  return terms.every(term => fields.some(field => field.includes(term)));
+}
+
+/**
+ * Filter each phase's documents down to the cards matching the board search
+ */
+export function filterDocumentsByPhase(
+  documentsByPhase: Record<string, DocumentInfo[]>,
+  query: string
+): Record<string, DocumentInfo[]> {
+  if (!query.trim()) return documentsByPhase;
+
+  const filtered: Record<string, DocumentInfo[]> = {};
+  Object.entries(documentsByPhase).forEach(([phase, documents]) => {
+    filtered[phase] = documents.filter(doc => matchesBoardSearch(doc, query));
+  });
+  return filtered;
+}
+
+/**
+ * Split text into segments, marking the parts that match a search term
+ */
+export function highlightSegments(text: string, query: string): HighlightSegment[] {
+  const terms = query.toLowerCase().split(/\s+/).filter(Boolean);
+  if (terms.length === 0 || !text) return [{ text, match: false }];
+
+  // Mark every character covered by a term, then merge runs
+  const lower = text.toLowerCase();
+  const marked = new Array<boolean>(text.length).fill(false);
+  terms.forEach(term => {
+    let index = lower.indexOf(term);
+    while (index !== -1) {
+      marked.fill(true, index, index + term.length);
+      index = lower.indexOf(term, index + 1);
+    }
+  });
+
+  const segments: HighlightSegment[] = [];
+  for (let i = 0; i < text.length; i++) {
+    const last = segments[segments.length - 1];
+    if (last && last.match === marked[i]) {
+      last.text += text[i];
+    } else {
+      segments.push({ text: text[i], match: marked[i] });
+    }
+  }
+  return segments;
+}
+
+/**
+ * The board a document is shown on, among the boards the project has.
+ * Null for a document no board lists, such as an archived one
+ */
+export function boardForDocument(doc: DocumentInfo, boards: BoardType[]): BoardType | null {
+  if (doc.archived) return null;
+  return boards.find(board => getBoardConfig(board)?.documentFilter(doc)) ?? null;
+}
+
+/**
+ * Documents whose short code matches what was typed at the jump prompt,
+ * best first. The project prefix and leading zeros can be left out, so
+ * `T-12`, `t12` and `12` all find PROJ-T-0012, and the letters of the
+ * short code may be typed with gaps, fuzzy style. Titles containing every
+ * typed word come last
+ */
+export function rankShortCodeMatches(documents: DocumentInfo[], query: string, limit = 10): DocumentInfo[] {
+  const typed = query.trim().replace(/^#/, '').toLowerCase();
+  if (!typed) return [];
+  const shorthand = /^(?:([a-z])-?)?0*(\d+)$/.exec(typed);
+  const terms = typed.split(/\s+/);
+
+  const score = (doc: DocumentInfo): number | null => {
+    const code = doc.short_code.toLowerCase();
+    if (code === typed) return 0;
+    const [, type, number] = /^.*-([a-z])-0*(\d+)$/.exec(code) ?? [];
+    if (shorthand && number === shorthand[2] && (!shorthand[1] || shorthand[1] === type)) {
+      return shorthand[1] ? 1 : 2;
+    }
+    if (code.startsWith(typed) || code.slice(code.indexOf('-') + 1).startsWith(typed)) return 3;
+    const gaps = subsequenceGaps(code, typed.replace(/\s+/g, ''));
+    if (gaps !== null) return 4 + gaps / 100;
+    const title = doc.title.toLowerCase();
+    if (terms.every(term => title.includes(term))) return 10;
+    return null;
+  };
+
+  return documents
+    .map(doc => ({ doc, score: score(doc) }))
+    .filter((entry): entry is { doc: DocumentInfo; score: number } => entry.score !== null)
+    .sort((a, b) => a.score - b.score || a.doc.short_code.localeCompare(b.doc.short_code))
+    .slice(0, limit)
+    .map(entry => entry.doc);
+}
+
+// How many characters are skipped to find `typed` in `text` in order, or
+// null if it can't be found
+function subsequenceGaps(text: string, typed: string): number | null {
+  let gaps = 0;
+  let position = 0;
+  for (const character of typed) {
+    const found = text.indexOf(character, position);
+    if (found === -1) return null;
+    gaps += found - position;
+    position = found + 1;
+  }
+  return gaps;
+}
@@ -0,0 +1,280 @@
+// Shortcuts and the keys bound to them, grouped by where they apply. The
+// defaults can be changed per project in the [keys] table of
+// .metis/board.toml, e.g.
+//
+//   [keys]
+//   refresh = "Ctrl+r"
+//   move_left = ["Shift+ArrowLeft", "H"]
+//   archive = []            # unbound
+
+export type KeyAction =
+  | 'search'
+  | 'clear'
+  | 'details'
+  | 'criteria'
+  | 'tags'
+  | 'graph'
+  | 'zoom'
+  | 'supersede'
+  | 'mark'
+  | 'move_left'
+  | 'move_right'
+  | 'refresh'
+  | 'remote_sync'
+  | 'archive'
+  | 'archived'
+  | 'history'
+  | 'jump'
+  | 'workspaces'
+  | 'help'
+  | 'edit'
+  | 'view'
+  | 'confirm'
+  | 'cancel'
+
+// Where an action applies. A key can be bound once in each context
+export type KeyContext = 'board' | 'editing' | 'confirmation'
+
+export const KEY_CONTEXTS: { name: KeyContext; title: string }[] = [
+  { name: 'board', title: 'Board' },
+  { name: 'editing', title: 'Document Viewer' },
+  { name: 'confirmation', title: 'Confirmation Dialogs' },
+]
+
+export interface KeyActionInfo {
+  name: KeyAction
+  context: KeyContext
+  description: string
+  defaults: string[]
+}
+
+export const KEY_ACTIONS: KeyActionInfo[] = [
+  { name: 'search', context: 'board', description: 'Search the cards on this board', defaults: ['/'] },
+  { name: 'clear', context: 'board', description: 'Clear the search, then the marks, then the zoom', defaults: ['Escape'] },
+  { name: 'details', context: 'board', description: 'Show or hide the detail panel', defaults: ['i'] },
+  { name: 'criteria', context: 'board', description: 'Check off the selected card\'s exit criteria with Space', defaults: ['c'] },
+  { name: 'tags', context: 'board', description: 'Add or remove the selected card\'s tags', defaults: ['t'] },
+  { name: 'graph', context: 'board', description: 'Show the selected card\'s relationships', defaults: ['g'] },
+  { name: 'zoom', context: 'board', description: 'Show only the selected initiative and what hangs from it, on every board', defaults: ['z'] },
+  { name: 'supersede', context: 'board', description: 'Write a new ADR that supersedes the selected decided ADR', defaults: ['s'] },
+  { name: 'mark', context: 'board', description: 'Mark or unmark the selected card', defaults: ['Space'] },
+  { name: 'move_left', context: 'board', description: 'Move the selected card to the previous column', defaults: ['Shift+ArrowLeft', '<'] },
+  { name: 'move_right', context: 'board', description: 'Move the selected card to the next column', defaults: ['Shift+ArrowRight', '>'] },
+  { name: 'refresh', context: 'board', description: 'Sync with the files on disk', defaults: ['r'] },
+  { name: 'remote_sync', context: 'board', description: 'Sync with the git remote: pull, push and report conflicts', defaults: ['S'] },
+  { name: 'archive', context: 'board', description: 'Archive the selected card, after confirmation', defaults: [] },
+  { name: 'archived', context: 'board', description: 'Browse, preview and restore archived documents', defaults: ['A'] },
+  { name: 'history', context: 'board', description: 'Show or hide the history of this session', defaults: ['H'] },
+  { name: 'jump', context: 'board', description: 'Jump to a card by its short code', defaults: ['#'] },
+  { name: 'workspaces', context: 'board', description: 'Switch to a recently used workspace', defaults: ['W'] },
+  { name: 'help', context: 'board', description: 'Show this list of keys', defaults: ['?'] },
+  { name: 'edit', context: 'editing', description: 'Switch between reading and editing', defaults: ['Ctrl+Shift+E', 'Meta+Shift+E'] },
+  { name: 'view', context: 'editing', description: 'Switch between rich text, split and markdown source', defaults: ['Ctrl+Shift+M', 'Meta+Shift+M'] },
+  { name: 'confirm', context: 'confirmation', description: 'Confirm, e.g. archive or move past a WIP limit', defaults: ['Enter'] },
+  { name: 'cancel', context: 'confirmation', description: 'Cancel and close the dialog', defaults: ['Escape'] },
+]
+
+// The fixed keys of vim mode on the boards. In vim mode they win over any
+// board shortcut bound to the same key
+export const VIM_BOARD_KEYS: { keys: string; description: string }[] = [
+  { keys: 'h / l', description: 'Select the card in the previous or next column' },
+  { keys: 'j / k', description: 'Select the card below or above' },
+  { keys: 'gg / G', description: 'Select the first or last card in the column' },
+  { keys: 'o / Enter', description: 'Open the selected card' },
+  { keys: ':', description: 'Open the command line' },
+]
+
+// The fixed keys of vim mode in the document editor, while editing
+export const VIM_EDITOR_KEYS: { keys: string; description: string }[] = [
+  { keys: 'h j k l', description: 'Move the cursor' },
+  { keys: 'w / b', description: 'Next or previous word in the paragraph' },
+  { keys: '0 / $', description: 'Start or end of the line' },
+  { keys: 'gg / G', description: 'Start or end of the document' },
+  { keys: 'x / dd', description: 'Delete a character or the paragraph' },
+  { keys: 'u / Ctrl+R', description: 'Undo or redo' },
+  { keys: 'i a I A o O', description: 'Enter insert mode' },
+  { keys: 'Esc', description: 'Back to normal mode' },
+]
+
+const VIM_RESERVED = ['h', 'j', 'k', 'l', 'g', 'G', 'o', 'Enter', ':']
+
+export interface KeyChord {
+  key: string
+  ctrl: boolean
+  alt: boolean
+  meta: boolean
+  shift: boolean
+}
+
+export type Keymap = Record<KeyAction, KeyChord[]>
+
+export interface ResolvedKeymap {
+  keymap: Keymap
+  warnings: string[]
+}
+
+// Names accepted for keys that aren't a single character, by lower case
+const NAMED_KEYS: Record<string, string> = {
+  escape: 'Escape',
+  esc: 'Escape',
+  enter: 'Enter',
+  return: 'Enter',
+  tab: 'Tab',
+  space: ' ',
+  backspace: 'Backspace',
+  delete: 'Delete',
+  del: 'Delete',
+  insert: 'Insert',
+  home: 'Home',
+  end: 'End',
+  pageup: 'PageUp',
+  pagedown: 'PageDown',
+  arrowleft: 'ArrowLeft',
+  left: 'ArrowLeft',
+  arrowright: 'ArrowRight',
+  right: 'ArrowRight',
+  arrowup: 'ArrowUp',
+  up: 'ArrowUp',
+  arrowdown: 'ArrowDown',
+  down: 'ArrowDown',
+}
+
+const KEY_LABELS: Record<string, string> = {
+  ' ': 'Space',
+  Escape: 'Esc',
+  ArrowLeft: '←',
+  ArrowRight: '→',
+  ArrowUp: '↑',
+  ArrowDown: '↓',
+}
+
+/**
+ * Parse a key such as `g`, `Space`, `Shift+ArrowLeft` or `Ctrl+k`. A single
+ * character is matched as typed, so `G` is Shift+g and `?` needs no Shift.
+ * Null if it isn't a key
+ */
+export function parseKey(spec: string): KeyChord | null {
+  const match = /^((?:(?:ctrl|control|alt|option|meta|cmd|shift)\+)*)(.+)$/i.exec(spec.trim())
+  if (!match) return null
+
+  const modifiers = match[1].toLowerCase().split('+').filter(Boolean)
+  let key = match[2]
+  if (key.length > 1) {
+    const named = NAMED_KEYS[key.toLowerCase()] ?? (/^f([1-9]|1[0-2])$/i.test(key) ? key.toUpperCase() : null)
+    if (!named) return null
+    key = named
+  }
+
+  const shift = modifiers.includes('shift')
+  const printable = key.length === 1 && key !== ' '
+  if (printable && shift) key = key.toUpperCase()
+  return {
+    key,
+    ctrl: modifiers.includes('ctrl') || modifiers.includes('control'),
+    alt: modifiers.includes('alt') || modifiers.includes('option'),
+    meta: modifiers.includes('meta') || modifiers.includes('cmd'),
+    // Shift is part of a printed character, so only named keys keep it
+    shift: shift && !printable,
+  }
+}
+
+/**
+ * How a key is shown in the cheat sheet and in warnings
+ */
+export function formatKey(chord: KeyChord): string {
+  const parts: string[] = []
+  if (chord.ctrl) parts.push('Ctrl')
+  if (chord.alt) parts.push('Alt')
+  if (chord.meta) parts.push('Meta')
+  if (chord.shift) parts.push('Shift')
+  parts.push(KEY_LABELS[chord.key] ?? chord.key)
+  return parts.join('+')
+}
+
+/**
+ * Whether a key press is the given key, with exactly its modifiers
+ */
+export function matchesKey(chord: KeyChord, event: KeyboardEvent): boolean {
+  const printable = chord.key.length === 1 && chord.key !== ' '
+  // With Ctrl, Alt or Meta held some platforms report a letter in lower
+  // case whatever Shift does, so the case of the letter comes from Shift
+  const letter = /^[a-z]$/i.test(chord.key) && (chord.ctrl || chord.alt || chord.meta)
+  const sameKey = letter
+    ? event.key.toLowerCase() === chord.key.toLowerCase() && event.shiftKey === (chord.key !== chord.key.toLowerCase())
+    : event.key === chord.key
+  return sameKey
+    && event.ctrlKey === chord.ctrl
+    && event.altKey === chord.alt
+    && event.metaKey === chord.meta
+    && (printable || event.shiftKey === chord.shift)
+}
+
+/**
+ * Whether vim mode takes this key press for itself
+ */
+export function isVimKey(event: KeyboardEvent): boolean {
+  return !event.ctrlKey && !event.metaKey && !event.altKey && VIM_RESERVED.includes(event.key)
+}
+
+/**
+ * Whether vim mode takes this key for itself, so a board shortcut bound to
+ * it only works in standard mode
+ */
+export function isVimChord(chord: KeyChord): boolean {
+  return !chord.ctrl && !chord.meta && !chord.alt && VIM_RESERVED.includes(chord.key)
+}
+
+export function defaultKeymap(): Keymap {
+  return resolveKeymap({}).keymap
+}
+
+/**
+ * Apply the [keys] table from board.toml to the defaults. A configured
+ * action replaces its default keys, and takes its keys away from any action
+ * in the same context left at its defaults. Unknown actions, keys that can't
+ * be parsed and a key configured for two actions of one context give a
+ * warning; the table arrives sorted by action, so the first action
+ * alphabetically keeps a contested key.
+ */
+export function resolveKeymap(config: Record<string, string[]>): ResolvedKeymap {
+  const warnings: string[] = []
+  const keymap = {} as Keymap
+  // Action holding each key, by context and key label
+  const claimed = new Map<string, KeyAction>()
+  const names = KEY_ACTIONS.map(action => action.name)
+
+  for (const [name, specs] of Object.entries(config)) {
+    const info = KEY_ACTIONS.find(action => action.name === name)
+    if (!info) {
+      warnings.push(`Unknown key action '${name}'; actions are ${names.join(', ')}`)
+      continue
+    }
+    const action = info.name
+    keymap[action] = []
+    for (const spec of specs) {
+      const chord = parseKey(spec)
+      if (!chord) {
+        warnings.push(`keys.${action}: '${spec}' is not a key`)
+        continue
+      }
+      const label = formatKey(chord)
+      const owner = claimed.get(`${info.context}:${label}`)
+      if (owner === action) continue
+      if (owner) {
+        warnings.push(`keys.${owner} and keys.${action} both use '${label}'; keeping it for ${owner}`)
+        continue
+      }
+      claimed.set(`${info.context}:${label}`, action)
+      keymap[action].push(chord)
+    }
+  }
+
+  for (const action of KEY_ACTIONS) {
+    if (keymap[action.name]) continue
+    keymap[action.name] = action.defaults
+      .map(spec => parseKey(spec)!)
+      .filter(chord => !claimed.has(`${action.context}:${formatKey(chord)}`))
+  }
+
+  return { keymap, warnings }
+}
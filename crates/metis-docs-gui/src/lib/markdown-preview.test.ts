@@ -0,0 +1,79 @@
+import { describe, expect, it } from 'vitest'
+import { renderMarkdown } from './markdown-preview'
+
+describe('renderMarkdown', () => {
+  describe('escaping', () => {
+    it('escapes raw HTML', () => {
+      expect(renderMarkdown('<script>alert("x")</script>')).toBe(
+        '<p>&lt;script&gt;alert(&quot;x&quot;)&lt;/script&gt;</p>',
+      )
+      expect(renderMarkdown('<img src=x onerror=alert(1)>')).not.toContain('<img')
+    })
+
+    it('escapes code spans without formatting them', () => {
+      expect(renderMarkdown('`<b>*not em*</b>`')).toBe(
+        '<p><code>&lt;b&gt;*not em*&lt;/b&gt;</code></p>',
+      )
+    })
+
+    it('shows links without making them followable', () => {
+      const html = renderMarkdown('[docs](javascript:alert(1))')
+      expect(html).not.toContain('<a')
+      expect(html).not.toContain('href')
+      expect(html).toContain('<span class="md-link" title="javascript:alert(1">docs</span>')
+    })
+
+    it('keeps quotes in link targets inside the title attribute', () => {
+      const html = renderMarkdown('[x](a"onmouseover="alert(1))')
+      expect(html).toContain('title="a&quot;onmouseover=&quot;alert(1"')
+    })
+  })
+
+  it('renders headings and inline formatting', () => {
+    expect(renderMarkdown('# Title\nsome *text* and **more** ~~gone~~')).toBe(
+      '<h1>Title</h1>\n<p>some <em>text</em> and <strong>more</strong> <del>gone</del></p>',
+    )
+  })
+
+  describe('lists', () => {
+    it('nests deeper items under the item before them', () => {
+      expect(renderMarkdown('- one\n  - nested\n- two')).toBe(
+        '<ul><li>one<ul><li>nested</li></ul></li><li>two</li></ul>',
+      )
+    })
+
+    it('renders ordered lists', () => {
+      expect(renderMarkdown('1. first\n2. second')).toBe('<ol><li>first</li><li>second</li></ol>')
+    })
+
+    it('renders task items as disabled checkboxes', () => {
+      expect(renderMarkdown('- [x] done\n- [ ] todo')).toBe(
+        '<ul>'
+          + '<li class="task-item"><input type="checkbox" disabled checked> <span>done</span></li>'
+          + '<li class="task-item"><input type="checkbox" disabled> <span>todo</span></li>'
+          + '</ul>',
+      )
+    })
+  })
+
+  it('renders tables with a header row', () => {
+    expect(renderMarkdown('| a | b |\n|---|:-:|\n| 1 | **2** |')).toBe(
+      '<table><thead><tr><th>a</th><th>b</th></tr></thead>'
+        + '<tbody><tr><td>1</td><td><strong>2</strong></td></tr></tbody></table>',
+    )
+  })
+
+  describe('fenced code', () => {
+    it('escapes the body and tags the language', () => {
+      expect(renderMarkdown('```rust\nlet x = a < b && *c*;\n```')).toBe(
+        '<pre><code class="language-rust">let x = a &lt; b &amp;&amp; *c*;</code></pre>',
+      )
+    })
+
+    it('leaves markdown inside the fence alone', () => {
+      expect(renderMarkdown('~~~\n# not a heading\n- not a list\n~~~')).toBe(
+        '<pre><code># not a heading\n- not a list</code></pre>',
+      )
+    })
+  })
+})
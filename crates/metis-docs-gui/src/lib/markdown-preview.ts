@@ -0,0 +1,182 @@
+/**
+ * Render a document body to HTML for the preview pane beside the markdown
+ * source. Covers what Metis documents use: headings, paragraphs, nested
+ * lists with `- [ ]` checkboxes, block quotes, fenced code, tables, rules
+ * and inline emphasis, strong, strikethrough, code and links.
+ *
+ * Everything from the source is escaped, and links are shown rather than
+ * followed so a click can't navigate the app away from the board.
+ */
+export function renderMarkdown(source: string): string {
+  return renderBlocks(source.replace(/\r\n?/g, '\n').split('\n'))
+}
+
+const FENCE = /^\s*(```|~~~)\s*([\w+-]*)/
+const HEADING = /^(#{1,6})\s+(.*?)(\s+#+)?\s*$/
+const RULE = /^\s*([-*_])(\s*\1){2,}\s*$/
+const QUOTE = /^\s*>/
+const LIST_ITEM = /^(\s*)([-*+]|\d+[.)])\s+(.*)$/
+const TABLE_DIVIDER = /^\s*\|?\s*:?-+:?\s*(\|\s*:?-+:?\s*)*\|?\s*$/
+
+function renderBlocks(lines: string[]): string {
+  const html: string[] = []
+  let i = 0
+  while (i < lines.length) {
+    const line = lines[i]
+    if (!line.trim()) {
+      i++
+      continue
+    }
+
+    const fence = FENCE.exec(line)
+    if (fence) {
+      const body: string[] = []
+      i++
+      while (i < lines.length && !lines[i].trim().startsWith(fence[1])) body.push(lines[i++])
+      i++
+      const language = fence[2] ? ` class="language-${escapeHtml(fence[2])}"` : ''
+      html.push(`<pre><code${language}>${escapeHtml(body.join('\n'))}</code></pre>`)
+      continue
+    }
+
+    const heading = HEADING.exec(line)
+    if (heading) {
+      const level = heading[1].length
+      html.push(`<h${level}>${renderInline(heading[2])}</h${level}>`)
+      i++
+      continue
+    }
+
+    if (RULE.test(line)) {
+      html.push('<hr>')
+      i++
+      continue
+    }
+
+    if (QUOTE.test(line)) {
+      const quoted: string[] = []
+      while (i < lines.length && QUOTE.test(lines[i])) quoted.push(lines[i++].replace(/^\s*>\s?/, ''))
+      html.push(`<blockquote>${renderBlocks(quoted)}</blockquote>`)
+      continue
+    }
+
+    if (LIST_ITEM.test(line)) {
+      const list = renderList(lines, i)
+      html.push(list.html)
+      i = list.next
+      continue
+    }
+
+    if (isTableStart(lines, i)) {
+      const table = renderTable(lines, i)
+      html.push(table.html)
+      i = table.next
+      continue
+    }
+
+    const paragraph: string[] = [line.trim()]
+    i++
+    while (i < lines.length && lines[i].trim() && !startsBlock(lines, i)) paragraph.push(lines[i++].trim())
+    html.push(`<p>${renderInline(paragraph.join(' '))}</p>`)
+  }
+  return html.join('\n')
+}
+
+function startsBlock(lines: string[], i: number): boolean {
+  const line = lines[i]
+  return FENCE.test(line) || HEADING.test(line) || RULE.test(line) || QUOTE.test(line)
+    || LIST_ITEM.test(line) || isTableStart(lines, i)
+}
+
+// A list and any lists nested under its items. Items are siblings while
+// they share an indent; a deeper item starts a list inside the last one
+function renderList(lines: string[], start: number): { html: string; next: number } {
+  const first = LIST_ITEM.exec(lines[start])!
+  const indent = first[1].length
+  const ordered = /\d/.test(first[2])
+  const items: string[] = []
+  const isSibling = (item: RegExpExecArray | null) =>
+    !!item && item[1].length === indent && /\d/.test(item[2]) === ordered
+
+  let i = start
+  while (i < lines.length) {
+    const item = LIST_ITEM.exec(lines[i])
+    if (!isSibling(item)) break
+    let text = item![3]
+    i++
+    // Indented lines that aren't items continue the item's text
+    while (i < lines.length && /^\s+\S/.test(lines[i]) && !LIST_ITEM.test(lines[i])) {
+      text += ` ${lines[i++].trim()}`
+    }
+
+    let nested = ''
+    while (i < lines.length) {
+      const child = LIST_ITEM.exec(lines[i])
+      if (!child || child[1].length <= indent) break
+      const list = renderList(lines, i)
+      nested += list.html
+      i = list.next
+    }
+    items.push(renderListItem(text, nested))
+
+    // A blank line between two items doesn't end the list
+    if (i < lines.length && !lines[i].trim() && isSibling(LIST_ITEM.exec(lines[i + 1] ?? ''))) i++
+  }
+  const tag = ordered ? 'ol' : 'ul'
+  return { html: `<${tag}>${items.join('')}</${tag}>`, next: i }
+}
+
+function renderListItem(text: string, nested: string): string {
+  const task = /^\[([ xX])\]\s+(.*)$/.exec(text)
+  if (!task) return `<li>${renderInline(text)}${nested}</li>`
+  const checked = task[1] !== ' ' ? ' checked' : ''
+  return `<li class="task-item"><input type="checkbox" disabled${checked}> <span>${renderInline(task[2])}</span>${nested}</li>`
+}
+
+function isTableStart(lines: string[], i: number): boolean {
+  return lines[i].includes('|') && i + 1 < lines.length && lines[i + 1].includes('-') && TABLE_DIVIDER.test(lines[i + 1])
+}
+
+function tableCells(line: string): string[] {
+  return line.trim().replace(/^\|/, '').replace(/\|$/, '').split('|').map(cell => cell.trim())
+}
+
+function renderTable(lines: string[], start: number): { html: string; next: number } {
+  const header = tableCells(lines[start]).map(cell => `<th>${renderInline(cell)}</th>`).join('')
+  const rows: string[] = []
+  let i = start + 2
+  while (i < lines.length && lines[i].includes('|') && lines[i].trim()) {
+    rows.push(`<tr>${tableCells(lines[i]).map(cell => `<td>${renderInline(cell)}</td>`).join('')}</tr>`)
+    i++
+  }
+  return { html: `<table><thead><tr>${header}</tr></thead><tbody>${rows.join('')}</tbody></table>`, next: i }
+}
+
+// Code spans first, so nothing inside backticks is formatted
+function renderInline(text: string): string {
+  return text
+    .split(/(`[^`]+`)/)
+    .map((part, index) => index % 2 === 1
+      ? `<code>${escapeHtml(part.slice(1, -1))}</code>`
+      : formatInline(escapeHtml(part)))
+    .join('')
+}
+
+function formatInline(text: string): string {
+  return text
+    .replace(/\[([^\]]+)\]\(([^)\s]+)\)/g, '<span class="md-link" title="$2">$1</span>')
+    .replace(/\*\*(.+?)\*\*/g, '<strong>$1</strong>')
+    .replace(/__(.+?)__/g, '<strong>$1</strong>')
+    .replace(/\*(?!\s)(.+?)\*/g, '<em>$1</em>')
+    .replace(/(^|[^\w])_(?!\s)(.+?)_(?=[^\w]|$)/g, '$1<em>$2</em>')
+    .replace(/~~(.+?)~~/g, '<del>$1</del>')
+}
+
+function escapeHtml(text: string): string {
+  return text
+    .replace(/&/g, '&amp;')
+    .replace(/</g, '&lt;')
+    .replace(/>/g, '&gt;')
+    .replace(/"/g, '&quot;')
+    .replace(/'/g, '&#39;')
+}
@@ -5,6 +5,15 @@ export interface ProjectInfo {
   path: string;
   is_valid: boolean;
   vision_exists: boolean;
+  // Why nothing can be changed this session, e.g. a historical checkout
+  read_only?: string | null;
+}
+
+export interface RecentWorkspace {
+  path: string;
+  name: string;
+  last_opened: string;
+  available: boolean;
 }
 
 export interface DocumentInfo {
@@ -20,6 +29,10 @@ export interface DocumentInfo {
   tags: string[];
   initiative_id: string | null;
   parent_id: string | null;
+  assignee: string | null;
+  severity: string | null;
+  priority: string | null;
+  due_date: string | null;
 }
 
 export interface DocumentContent {
@@ -29,6 +42,47 @@ export interface DocumentContent {
   frontmatter_json: string;
 }
 
+export interface DocumentSection {
+  heading: string;
+  text: string;
+}
+
+export interface Criterion {
+  number: number;
+  text: string;
+  checked: boolean;
+}
+
+export interface DocumentDetail {
+  short_code: string;
+  blocked_by: string[];
+  criteria_checked: number;
+  criteria_total: number;
+  criteria: Criterion[];
+  sections: DocumentSection[];
+}
+
+export interface RelationNode {
+  short_code: string;
+  title: string;
+  document_type: string;
+  phase: string;
+  depth: number;
+}
+
+export interface DocumentRelations {
+  document: RelationNode;
+  ancestors: RelationNode[];
+  children: RelationNode[];
+  blockers: RelationNode[];
+  blocking: RelationNode[];
+}
+
+export interface DocumentProperty {
+  key: string;
+  value: string;
+}
+
 export interface InitializationResult {
   metis_dir: string;
   database_path: string;
@@ -55,6 +109,27 @@ export class MetisAPI {
     return invoke('load_project', { path });
   }
 
+  /**
+   * List the recently used workspaces from ~/.config/metis/workspaces.toml
+   */
+  static async listRecentWorkspaces(): Promise<RecentWorkspace[]> {
+    return invoke('list_recent_workspaces');
+  }
+
+  /**
+   * Drop a workspace from the recent list
+   */
+  static async forgetWorkspace(path: string): Promise<void> {
+    return invoke('forget_workspace', { path });
+  }
+
+  /**
+   * The workspace the app was started in, if it was started inside one
+   */
+  static async detectLaunchWorkspace(): Promise<string | null> {
+    return invoke('detect_launch_workspace');
+  }
+
   /**
    * Get all documents in the current project
    */
@@ -62,6 +137,13 @@ export class MetisAPI {
     return invoke('list_documents');
   }
 
+  /**
+   * Get the archived documents in the current project, most recent first
+   */
+  static async listArchivedDocuments(): Promise<DocumentInfo[]> {
+    return invoke('list_archived_documents');
+  }
+
   /**
    * Read a specific document by its short code
    */
@@ -69,6 +151,55 @@ export class MetisAPI {
     return invoke('read_document', { shortCode: shortCode });
   }
 
+  /**
+   * Get the blockers, criteria progress and first sections of a document
+   */
+  static async getDocumentDetail(shortCode: string): Promise<DocumentDetail> {
+    return invoke('get_document_detail', { shortCode });
+  }
+
+  /**
+   * Check or uncheck an exit criterion (numbered from 1); returns them all
+   */
+  static async setCriterion(shortCode: string, number: number, checked: boolean): Promise<Criterion[]> {
+    return invoke('set_criterion', { shortCode, number, checked });
+  }
+
+  /**
+   * Get the tags a document declares, without its phase tag
+   */
+  static async getDocumentTags(shortCode: string): Promise<string[]> {
+    return invoke('get_document_tags', { shortCode });
+  }
+
+  /**
+   * List every tag used in the workspace, for autocomplete
+   */
+  static async listWorkspaceTags(): Promise<string[]> {
+    return invoke('list_workspace_tags');
+  }
+
+  /**
+   * Add a tag to a document's frontmatter; returns its tags
+   */
+  static async addDocumentTag(shortCode: string, tag: string): Promise<string[]> {
+    return invoke('add_document_tag', { shortCode, tag });
+  }
+
+  /**
+   * Remove a tag from a document's frontmatter; returns its tags
+   */
+  static async removeDocumentTag(shortCode: string, tag: string): Promise<string[]> {
+    return invoke('remove_document_tag', { shortCode, tag });
+  }
+
+  /**
+   * Get the ancestors, children and blockers of a document as indented trees
+   */
+  static async getDocumentRelations(shortCode: string): Promise<DocumentRelations> {
+    return invoke('get_document_relations', { shortCode });
+  }
+
   /**
    * Search documents by content
    */
@@ -83,6 +214,27 @@ export class MetisAPI {
     return invoke('get_project_config');
   }
 
+  /**
+   * Get the board settings from .metis/board.toml, with defaults for anything unset
+   */
+  static async getBoardSettings(): Promise<BoardSettingsResult> {
+    return invoke('get_board_settings');
+  }
+
+  /**
+   * Get where the boards were left, from .metis/gui-session.json
+   */
+  static async getSessionState(): Promise<SessionState> {
+    return invoke('get_session_state');
+  }
+
+  /**
+   * Save where the boards are, to restore on the next launch
+   */
+  static async saveSessionState(session: SessionState): Promise<void> {
+    return invoke('save_session_state', { session });
+  }
+
   /**
    * Sync project with database
    */
@@ -90,6 +242,14 @@ export class MetisAPI {
     return invoke('sync_project');
   }
 
+  /**
+   * Pull from and push to the git remote; progress arrives as
+   * 'remote-sync-progress' events
+   */
+  static async remoteSync(): Promise<RemoteSyncResult> {
+    return invoke('remote_sync');
+  }
+
   /**
    * Get available parents for a given child document type
    */
@@ -213,6 +373,10 @@ export interface CreateDocumentRequest {
   complexity?: string;
   risk_level?: string;
   tags?: string[];
+  // Frontmatter fields such as severity, priority or decision_maker
+  properties?: Record<string, string>;
+  // For an ADR, the decided ADR it replaces once it is decided itself
+  supersedes?: string;
 }
 
 export interface CreateDocumentResult {
@@ -234,11 +398,48 @@ export interface ProjectConfig {
   preset_name: string;
 }
 
+export interface BoardSettings {
+  theme: 'light' | 'dark' | 'hyper' | null;
+  default_board: string | null;
+  layout: {
+    column_width: number | null;
+    compact_cards: boolean;
+    detail_panel: boolean;
+  };
+  colors: {
+    phase: Record<string, string>;
+    column: Record<string, string>;
+    severity: Record<string, string>;
+  };
+  wip: Record<string, number>;
+  stale: Record<string, number>;
+  keys: Record<string, string[]>;
+}
+
+export interface BoardSettingsResult {
+  settings: BoardSettings;
+  warnings: string[];
+}
+
+// Where the boards were left when the project was last open
+export interface SessionState {
+  board?: string | null;
+  selected?: string | null;
+  search?: string | null;
+  initiative_filter?: string | null;
+  zoom?: string | null;
+}
+
 export interface ArchiveResult {
   total_archived: number;
   archived_documents: ArchivedDocument[];
 }
 
+export interface UnarchiveResult {
+  total_restored: number;
+  restored_documents: ArchivedDocument[];
+}
+
 export interface ArchivedDocument {
   document_id: string;
   document_type: string;
@@ -246,6 +447,24 @@ export interface ArchivedDocument {
   archived_path: string;
 }
 
+export type BulkAction =
+  | { kind: 'transition'; phase: string }
+  | { kind: 'archive' }
+  | { kind: 'tag'; tag: string }
+  | { kind: 'assign'; assignee: string | null };
+
+export interface BulkItemResult {
+  short_code: string;
+  ok: boolean;
+  message: string;
+}
+
+export interface BulkResult {
+  succeeded: number;
+  failed: number;
+  items: BulkItemResult[];
+}
+
 export interface SyncResult {
   imported: number;
   updated: number;
@@ -255,6 +474,13 @@ export interface SyncResult {
   messages: string[];
 }
 
+export interface RemoteSyncResult {
+  remote: string;
+  pull: string[];
+  push: string[];
+  conflicts: string[];
+}
+
 // API functions for document CRUD operations
 export class DocumentAPI {
   /**
@@ -271,6 +497,27 @@ export class DocumentAPI {
     return invoke('update_document', { shortCode, content });
   }
 
+  /**
+   * Replace a document's body, keeping the frontmatter on disk as it is
+   */
+  static async updateDocumentBody(shortCode: string, body: string): Promise<void> {
+    return invoke('update_document_body', { shortCode, body });
+  }
+
+  /**
+   * Get a document's free-form frontmatter fields, such as assignee
+   */
+  static async getDocumentProperties(shortCode: string): Promise<DocumentProperty[]> {
+    return invoke('get_document_properties', { shortCode });
+  }
+
+  /**
+   * Set a free-form frontmatter field, or remove it with a null value
+   */
+  static async setDocumentProperty(shortCode: string, key: string, value: string | null): Promise<void> {
+    return invoke('set_document_property', { shortCode, key, value });
+  }
+
   /**
    * Transition a document to a new phase
    */
@@ -278,25 +525,68 @@ export class DocumentAPI {
     return invoke('transition_phase', { shortCode, newPhase });
   }
 
+  /**
+   * Move a document to an adjacent phase, checking the transition and its
+   * exit criteria first
+   */
+  static async moveDocument(shortCode: string, newPhase: string): Promise<string> {
+    return invoke('move_document', { shortCode, newPhase });
+  }
+
+  /**
+   * Apply one action to several documents; each succeeds or fails on its own
+   */
+  static async bulkUpdate(shortCodes: string[], action: BulkAction): Promise<BulkResult> {
+    return invoke('bulk_update', { shortCodes, action });
+  }
+
   /**
    * Archive a document
    */
   static async archiveDocument(shortCode: string): Promise<ArchiveResult> {
     return invoke('archive_document', { shortCode });
   }
+
+  /**
+   * Restore an archived document, with its tasks if it is an initiative
+   */
+  static async unarchiveDocument(shortCode: string): Promise<UnarchiveResult> {
+    return invoke('unarchive_document', { shortCode });
+  }
 }
 
 // Standalone functions for direct import
+export const listRecentWorkspaces = MetisAPI.listRecentWorkspaces;
+export const forgetWorkspace = MetisAPI.forgetWorkspace;
+export const detectLaunchWorkspace = MetisAPI.detectLaunchWorkspace;
 export const listDocuments = MetisAPI.listDocuments;
+export const listArchivedDocuments = MetisAPI.listArchivedDocuments;
 export const readDocument = MetisAPI.readDocument;
+export const getDocumentDetail = MetisAPI.getDocumentDetail;
+export const setCriterion = MetisAPI.setCriterion;
+export const getDocumentTags = MetisAPI.getDocumentTags;
+export const listWorkspaceTags = MetisAPI.listWorkspaceTags;
+export const addDocumentTag = MetisAPI.addDocumentTag;
+export const removeDocumentTag = MetisAPI.removeDocumentTag;
+export const getDocumentRelations = MetisAPI.getDocumentRelations;
 export const searchDocuments = MetisAPI.searchDocuments;
 export const getProjectConfig = MetisAPI.getProjectConfig;
+export const getBoardSettings = MetisAPI.getBoardSettings;
+export const getSessionState = MetisAPI.getSessionState;
+export const saveSessionState = MetisAPI.saveSessionState;
 export const getAvailableParents = MetisAPI.getAvailableParents;
 export const createDocument = DocumentAPI.createDocument;
 export const updateDocument = DocumentAPI.updateDocument;
+export const updateDocumentBody = DocumentAPI.updateDocumentBody;
+export const getDocumentProperties = DocumentAPI.getDocumentProperties;
+export const setDocumentProperty = DocumentAPI.setDocumentProperty;
 export const transitionPhase = MetisAPI.transitionPhase;
+export const moveDocument = DocumentAPI.moveDocument;
 export const archiveDocument = DocumentAPI.archiveDocument;
+export const unarchiveDocument = DocumentAPI.unarchiveDocument;
+export const bulkUpdate = DocumentAPI.bulkUpdate;
 export const syncProject = MetisAPI.syncProject;
+export const remoteSync = MetisAPI.remoteSync;
 export const getAppVersion = MetisAPI.getAppVersion;
 export const installCli = MetisAPI.installCli;
 export const installCliElevated = MetisAPI.installCliElevated;
\ No newline at end of file
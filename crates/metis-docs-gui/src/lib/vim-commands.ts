@@ -0,0 +1,85 @@
+// Commands for the ':' command line on the boards in vim mode. Each one maps
+// to an action the board already offers through its buttons and shortcuts.
+
+export type VimCommandName =
+  | 'board'
+  | 'new'
+  | 'open'
+  | 'move'
+  | 'collapse'
+  | 'archive'
+  | 'details'
+  | 'graph'
+  | 'jump'
+  | 'search'
+  | 'sort'
+  | 'sync'
+  | 'theme'
+  | 'workspace'
+  | 'zoom'
+  | 'supersede'
+  | 'help'
+
+export interface VimCommand {
+  name: VimCommandName
+  aliases: string[]
+  usage: string
+  description: string
+}
+
+export const VIM_COMMANDS: VimCommand[] = [
+  { name: 'board', aliases: ['b'], usage: ':board <name>', description: 'Switch to a board, e.g. :b task' },
+  { name: 'new', aliases: ['n'], usage: ':new', description: 'Create a document on this board' },
+  { name: 'open', aliases: ['e', 'o'], usage: ':open', description: 'Open the selected card in the editor' },
+  { name: 'move', aliases: ['m'], usage: ':move <phase>', description: 'Move the selected card to a phase' },
+  { name: 'collapse', aliases: ['fold'], usage: ':collapse <phase>', description: 'Collapse a column on this board, or show it again' },
+  { name: 'archive', aliases: [], usage: ':archive', description: 'Archive the selected card' },
+  { name: 'details', aliases: ['d'], usage: ':details', description: 'Show or hide the detail panel' },
+  { name: 'graph', aliases: [], usage: ':graph', description: 'Show the selected card\'s ancestors, children and blockers' },
+  { name: 'jump', aliases: [], usage: ':jump <code>', description: 'Jump to the card with a short code, e.g. :jump T-12' },
+  { name: 'search', aliases: ['s'], usage: ':search <text>', description: 'Filter the cards on this board' },
+  { name: 'sort', aliases: [], usage: ':sort <field>', description: 'Sort the columns by manual, updated_at, priority, due_date or title' },
+  { name: 'sync', aliases: [], usage: ':sync', description: 'Sync the project with the files on disk' },
+  { name: 'theme', aliases: [], usage: ':theme <name>', description: 'Switch to the light, dark or hyper theme' },
+  { name: 'workspace', aliases: ['ws'], usage: ':workspace', description: 'Switch to a recently used workspace' },
+  { name: 'zoom', aliases: ['z'], usage: ':zoom [code|out]', description: 'Zoom every board into the selected or named initiative; :zoom out shows everything' },
+  { name: 'supersede', aliases: [], usage: ':supersede', description: 'Write a new ADR that supersedes the selected decided ADR' },
+  { name: 'help', aliases: ['h'], usage: ':help', description: 'Show the keys and commands' },
+]
+
+export interface ParsedVimCommand {
+  command: VimCommand
+  args: string
+}
+
+const findCommand = (word: string): VimCommand | undefined =>
+  VIM_COMMANDS.find(command => command.name === word || command.aliases.includes(word))
+
+/**
+ * Parse a command line such as `:m active`. The leading ':' is optional and
+ * a unique prefix of a command name is enough. Returns null when nothing
+ * matches.
+ */
+export function parseVimCommand(line: string): ParsedVimCommand | null {
+  const trimmed = line.trim().replace(/^:/, '').trim()
+  if (!trimmed) return null
+
+  const [word, ...rest] = trimmed.split(/\s+/)
+  const name = word.toLowerCase()
+  let command = findCommand(name)
+  if (!command) {
+    const matches = VIM_COMMANDS.filter(candidate => candidate.name.startsWith(name))
+    if (matches.length !== 1) return null
+    command = matches[0]
+  }
+  return { command, args: rest.join(' ') }
+}
+
+/** The commands whose name or alias starts with the first word typed */
+export function matchVimCommands(line: string): VimCommand[] {
+  const word = line.trim().replace(/^:/, '').split(/\s+/)[0]?.toLowerCase() ?? ''
+  if (!word) return VIM_COMMANDS
+  return VIM_COMMANDS.filter(command =>
+    command.name.startsWith(word) || command.aliases.some(alias => alias.startsWith(word))
+  )
+}
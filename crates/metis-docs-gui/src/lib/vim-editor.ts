@@ -0,0 +1,158 @@
+import type { Editor } from '@tiptap/vue-3'
+
+export type VimEditorMode = 'normal' | 'insert'
+
+/**
+ * Modal editing for the Tiptap editor. The editor starts in normal mode,
+ * where keys move the cursor and edit text, and i/a/o and friends switch to
+ * insert mode for typing; Esc goes back to normal mode.
+ *
+ * Normal mode covers the common motions and edits: h j k l, w b, 0 $, gg G,
+ * x, dd, u and Ctrl+R. Other printable keys are swallowed so nothing is
+ * typed by accident.
+ */
+export function createVimEditorKeys(
+  getEditor: () => Editor | undefined,
+  onModeChange: (mode: VimEditorMode) => void
+) {
+  let mode: VimEditorMode = 'normal'
+  // First key of a two-key command such as gg or dd
+  let pending = ''
+
+  const setMode = (next: VimEditorMode) => {
+    mode = next
+    pending = ''
+    onModeChange(next)
+  }
+
+  const handleKeyDown = (event: KeyboardEvent): boolean => {
+    const editor = getEditor()
+    if (!editor || !editor.isEditable) return false
+
+    if (mode === 'insert') {
+      if (event.key !== 'Escape') return false
+      setMode('normal')
+      return true
+    }
+
+    if (event.metaKey || event.altKey) return false
+    if (event.ctrlKey) {
+      if (event.key !== 'r') return false
+      editor.commands.redo()
+      return true
+    }
+
+    const { selection, doc } = editor.state
+    const $from = doc.resolve(selection.from)
+    const lineStart = $from.start()
+    const lineEnd = $from.end()
+    const text = $from.parent.textContent
+    const offset = $from.parentOffset
+
+    const moveTo = (pos: number) => {
+      editor.chain().setTextSelection(pos).scrollIntoView().run()
+    }
+
+    // One visual line up or down, keeping the horizontal position
+    const moveVertically = (direction: -1 | 1) => {
+      const view = editor.view
+      const coords = view.coordsAtPos(selection.from)
+      const lineHeight = coords.bottom - coords.top
+      const target = view.posAtCoords({
+        left: coords.left,
+        top: direction > 0 ? coords.bottom + lineHeight / 2 : coords.top - lineHeight / 2,
+      })
+      if (target) moveTo(target.pos)
+    }
+
+    if (pending) {
+      const combo = pending + event.key
+      pending = ''
+      if (combo === 'gg') {
+        editor.commands.focus('start')
+      } else if (combo === 'dd') {
+        editor.commands.deleteRange({ from: $from.before(), to: $from.after() })
+      }
+      return true
+    }
+
+    switch (event.key) {
+      case 'h':
+        moveTo(Math.max(lineStart, selection.from - 1))
+        break
+      case 'l':
+        moveTo(Math.min(lineEnd, selection.from + 1))
+        break
+      case 'j':
+        moveVertically(1)
+        break
+      case 'k':
+        moveVertically(-1)
+        break
+      case 'w': {
+        const next = text.slice(offset).search(/(?<=\s)\S|(?<=\w)[^\w\s]|(?<=[^\w\s])\w/)
+        moveTo(next > 0 ? selection.from + next : lineEnd)
+        break
+      }
+      case 'b': {
+        const before = text.slice(0, offset)
+        const previous = before.search(/\w+\W*$/)
+        moveTo(previous >= 0 ? lineStart + previous : lineStart)
+        break
+      }
+      case '0':
+        moveTo(lineStart)
+        break
+      case '$':
+        moveTo(lineEnd)
+        break
+      case 'G':
+        editor.commands.focus('end')
+        break
+      case 'g':
+      case 'd':
+        pending = event.key
+        break
+      case 'x':
+        if (selection.from < lineEnd) {
+          editor.commands.deleteRange({ from: selection.from, to: selection.from + 1 })
+        }
+        break
+      case 'u':
+        editor.commands.undo()
+        break
+      case 'i':
+        setMode('insert')
+        break
+      case 'a':
+        moveTo(Math.min(lineEnd, selection.from + 1))
+        setMode('insert')
+        break
+      case 'I':
+        moveTo(lineStart)
+        setMode('insert')
+        break
+      case 'A':
+        moveTo(lineEnd)
+        setMode('insert')
+        break
+      case 'o':
+        editor.chain().setTextSelection(lineEnd).splitBlock().run()
+        setMode('insert')
+        break
+      case 'O':
+        editor.chain().setTextSelection(lineStart).splitBlock().setTextSelection(lineStart).run()
+        setMode('insert')
+        break
+      default:
+        // Keep Esc, Tab, arrows and the like working; swallow anything that types
+        return event.key.length === 1
+    }
+    return true
+  }
+
+  return {
+    handleKeyDown,
+    reset: () => setMode('normal'),
+  }
+}
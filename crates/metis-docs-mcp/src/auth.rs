@@ -0,0 +1,87 @@
+//! Bearer token authentication for the HTTP transport.
+//!
+//! A network-reachable server accepts requests only when they carry
+//! `Authorization: Bearer <token>` with the token it was started with.
+//! There is no OAuth flow: the token is shared out of band, the same way as
+//! an API key.
+
+use async_trait::async_trait;
+use rust_mcp_sdk::auth::{AuthInfo, AuthProvider, AuthenticationError, OauthEndpoint};
+use std::collections::HashMap;
+
+/// Accepts the one token the server was configured with
+pub struct BearerTokenAuth {
+    token: String,
+}
+
+impl BearerTokenAuth {
+    pub fn new(token: impl Into<String>) -> Self {
+        Self {
+            token: token.into(),
+        }
+    }
+
+    /// Whether `candidate` is the configured token. Compares every byte so
+    /// the time taken doesn't reveal how much of a guess was right.
+    pub fn accepts(&self, candidate: &str) -> bool {
+        let expected = self.token.as_bytes();
+        let candidate = candidate.as_bytes();
+        if expected.len() != candidate.len() {
+            return false;
+        }
+        expected
+            .iter()
+            .zip(candidate)
+            .fold(0u8, |diff, (a, b)| diff | (a ^ b))
+            == 0
+    }
+}
+
+#[async_trait]
+impl AuthProvider for BearerTokenAuth {
+    async fn verify_token(&self, access_token: String) -> Result<AuthInfo, AuthenticationError> {
+        if !self.accepts(&access_token) {
+            return Err(AuthenticationError::InvalidToken {
+                description: "Invalid bearer token",
+            });
+        }
+        Ok(AuthInfo {
+            token_unique_id: "metis".to_string(),
+            client_id: None,
+            user_id: None,
+            scopes: None,
+            expires_at: None,
+            audience: None,
+            extra: None,
+        })
+    }
+
+    fn auth_endpoints(&self) -> Option<&HashMap<String, OauthEndpoint>> {
+        None
+    }
+
+    fn protected_resource_metadata_url(&self) -> Option<&str> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_accepts_only_the_configured_token() {
+        let auth = BearerTokenAuth::new("s3cret-token");
+        assert!(auth.accepts("s3cret-token"));
+        assert!(!auth.accepts("s3cret-tokem"));
+        assert!(!auth.accepts("s3cret"));
+        assert!(!auth.accepts(""));
+    }
+
+    #[tokio::test]
+    async fn test_verify_token() {
+        let auth = BearerTokenAuth::new("s3cret-token");
+        assert!(auth.verify_token("s3cret-token".to_string()).await.is_ok());
+        assert!(auth.verify_token("guess".to_string()).await.is_err());
+    }
+}
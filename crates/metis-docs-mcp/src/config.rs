@@ -1,18 +1,74 @@
 use anyhow::Result;
+use metis_core::{
+    application::services::workspace::WorkspaceDetectionService,
+    domain::configuration::{ConfigFile, McpConfig},
+};
 
-#[derive(Debug, Clone, Default)]
+#[derive(Clone, Default)]
 pub struct MetisServerConfig {
-    // Configuration is now minimal since we use direct paths
-    // Could add logging level, port bindings, etc. in future
+    /// Transport to serve on, where the HTTP transport listens, and whether
+    /// tools that change the workspace are offered
+    pub mcp: McpConfig,
+    /// Bearer token HTTP clients must send. Only read from the environment,
+    /// since config.toml is committed with the workspace
+    pub auth_token: Option<String>,
+}
+
+impl std::fmt::Debug for MetisServerConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MetisServerConfig")
+            .field("mcp", &self.mcp)
+            .field(
+                "auth_token",
+                &self.auth_token.as_ref().map(|_| "<redacted>"),
+            )
+            .finish()
+    }
 }
 
 impl MetisServerConfig {
+    /// The `[mcp]` section of the current workspace's config.toml, with
+    /// `METIS_MCP_TRANSPORT`, `METIS_MCP_HOST`, `METIS_MCP_PORT` and
+    /// `METIS_MCP_READ_ONLY` taking precedence, and the token from
+    /// `METIS_MCP_TOKEN`
     pub fn from_env() -> Result<Self> {
-        // No environment variables needed for stateless operation
-        Ok(Self {})
+        let mut mcp = WorkspaceDetectionService::new()
+            .find_workspace()
+            .ok()
+            .flatten()
+            .and_then(|metis_dir| ConfigFile::load(metis_dir.join("config.toml")).ok())
+            .map(|config| config.mcp().clone())
+            .unwrap_or_default();
+
+        if let Ok(transport) = std::env::var("METIS_MCP_TRANSPORT") {
+            mcp.transport = transport
+                .parse()
+                .map_err(|e| anyhow::anyhow!("METIS_MCP_TRANSPORT: {}", e))?;
+        }
+        if let Ok(host) = std::env::var("METIS_MCP_HOST") {
+            mcp.host = Some(host.trim().to_string()).filter(|host| !host.is_empty());
+        }
+        if let Ok(port) = std::env::var("METIS_MCP_PORT") {
+            mcp.port = Some(
+                McpConfig::parse_port(&port)
+                    .map_err(|e| anyhow::anyhow!("METIS_MCP_PORT: {}", e))?,
+            );
+        }
+
+        if let Ok(read_only) = std::env::var("METIS_MCP_READ_ONLY") {
+            mcp.read_only = read_only.trim().parse().map_err(|_| {
+                anyhow::anyhow!("METIS_MCP_READ_ONLY: '{}' must be true or false", read_only)
+            })?;
+        }
+        let auth_token = std::env::var("METIS_MCP_TOKEN")
+            .ok()
+            .map(|token| token.trim().to_string())
+            .filter(|token| !token.is_empty());
+
+        Ok(Self { mcp, auth_token })
     }
 
     pub fn new() -> Self {
-        Self {}
+        Self::default()
     }
 }
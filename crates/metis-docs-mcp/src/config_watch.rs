@@ -0,0 +1,163 @@
+//! Notices when a workspace's flight level configuration changes mid-session.
+//!
+//! The server instructions describe the configuration as it was at startup.
+//! When `metis config set` switches the preset or toggles a level while the
+//! server runs, the watcher reports the new configuration once to every
+//! client session, so the server can tell each client its tool list changed
+//! and hand the agent regenerated instructions. Every workspace a session's
+//! tools act on is watched, not only the one the server started in.
+
+use metis_core::domain::configuration::{ConfigFile, FlightLevelConfig};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+/// Tracks the configuration each client session last saw, per workspace
+#[derive(Debug)]
+pub struct ConfigWatcher {
+    /// The workspace the server was started in, which the instructions describe
+    metis_dir: Option<PathBuf>,
+    startup: Option<FlightLevelConfig>,
+    /// Last configuration reported, keyed by session id and workspace
+    seen: Mutex<HashMap<(String, PathBuf), FlightLevelConfig>>,
+}
+
+impl ConfigWatcher {
+    /// Watch workspaces for changes, taking the configuration of the one at
+    /// `metis_dir` now as the one clients already know
+    pub fn new(metis_dir: Option<PathBuf>) -> Self {
+        let metis_dir = metis_dir.map(|dir| canonical(&dir));
+        let startup = metis_dir.as_deref().and_then(load);
+        Self {
+            metis_dir,
+            startup,
+            seen: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// The startup workspace's configuration as config.toml has it now
+    pub fn current(&self) -> Option<FlightLevelConfig> {
+        self.metis_dir.as_deref().and_then(load)
+    }
+
+    /// The new configuration of the workspace at `metis_dir`, or of the
+    /// startup workspace, if it changed since `session` last saw it. A
+    /// session knows the startup workspace's configuration from the
+    /// instructions, and other workspaces' from its first call to them.
+    /// Each change is reported once per session.
+    pub fn changed(&self, session: &str, metis_dir: Option<&Path>) -> Option<FlightLevelConfig> {
+        let metis_dir = match metis_dir {
+            Some(dir) => canonical(dir),
+            None => self.metis_dir.clone()?,
+        };
+        let current = load(&metis_dir)?;
+        let known = match &self.startup {
+            Some(startup) if Some(&metis_dir) == self.metis_dir.as_ref() => startup.clone(),
+            _ => current.clone(),
+        };
+
+        let mut seen = self.seen.lock().unwrap();
+        let seen = seen
+            .entry((session.to_string(), metis_dir))
+            .or_insert(known);
+        if *seen == current {
+            return None;
+        }
+        *seen = current.clone();
+        Some(current)
+    }
+
+    /// Forget what closed sessions have seen, keeping the sessions for which
+    /// `open` holds
+    pub fn retain_sessions(&self, open: impl Fn(&str) -> bool) {
+        self.seen
+            .lock()
+            .unwrap()
+            .retain(|(session, _), _| open(session));
+    }
+}
+
+fn load(metis_dir: &Path) -> Option<FlightLevelConfig> {
+    ConfigFile::load(metis_dir.join("config.toml"))
+        .ok()
+        .map(|config| config.flight_levels().clone())
+}
+
+/// Workspaces are compared canonicalized, however a call spells the path
+fn canonical(path: &Path) -> PathBuf {
+    path.canonicalize().unwrap_or_else(|_| path.to_path_buf())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn write_config(dir: &TempDir, flight_levels: FlightLevelConfig) {
+        ConfigFile::new("TEST".to_string(), flight_levels)
+            .unwrap()
+            .save(dir.path().join("config.toml"))
+            .unwrap();
+    }
+
+    #[test]
+    fn test_reports_each_change_once() {
+        let dir = TempDir::new().unwrap();
+        write_config(&dir, FlightLevelConfig::streamlined());
+        let watcher = ConfigWatcher::new(Some(dir.path().to_path_buf()));
+        assert_eq!(watcher.changed("", None), None);
+
+        write_config(&dir, FlightLevelConfig::direct());
+        assert_eq!(watcher.changed("", None), Some(FlightLevelConfig::direct()));
+        assert_eq!(watcher.changed("", None), None);
+    }
+
+    #[test]
+    fn test_reports_changes_to_every_session() {
+        let dir = TempDir::new().unwrap();
+        write_config(&dir, FlightLevelConfig::streamlined());
+        let watcher = ConfigWatcher::new(Some(dir.path().to_path_buf()));
+
+        write_config(&dir, FlightLevelConfig::direct());
+        assert_eq!(
+            watcher.changed("a", Some(dir.path())),
+            Some(FlightLevelConfig::direct())
+        );
+        assert_eq!(watcher.changed("a", None), None);
+        // A session that hasn't called since still gets told
+        assert_eq!(
+            watcher.changed("b", None),
+            Some(FlightLevelConfig::direct())
+        );
+
+        // Closed sessions are forgotten; the open one keeps what it has seen
+        watcher.retain_sessions(|session| session == "b");
+        assert_eq!(watcher.seen.lock().unwrap().len(), 1);
+        assert_eq!(watcher.changed("b", None), None);
+    }
+
+    #[test]
+    fn test_watches_workspaces_tools_act_on() {
+        let startup = TempDir::new().unwrap();
+        let other = TempDir::new().unwrap();
+        write_config(&startup, FlightLevelConfig::streamlined());
+        write_config(&other, FlightLevelConfig::streamlined());
+        let watcher = ConfigWatcher::new(Some(startup.path().to_path_buf()));
+
+        // The first call to a workspace is where the session learns its setup
+        assert_eq!(watcher.changed("a", Some(other.path())), None);
+        write_config(&other, FlightLevelConfig::direct());
+        assert_eq!(
+            watcher.changed("a", Some(other.path())),
+            Some(FlightLevelConfig::direct())
+        );
+        assert_eq!(watcher.changed("a", None), None);
+    }
+
+    #[test]
+    fn test_no_workspace_never_changes() {
+        let watcher = ConfigWatcher::new(None);
+        assert_eq!(watcher.current(), None);
+        assert_eq!(watcher.changed("", None), None);
+    }
+}
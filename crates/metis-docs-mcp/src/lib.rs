@@ -1,32 +1,39 @@
 #![allow(clippy::redundant_closure)]
 #![allow(clippy::io_other_error)]
 
+pub mod auth;
 pub mod config;
+pub mod config_watch;
 pub mod error;
 pub mod error_utils;
 pub mod formatting;
+pub mod project_registry;
+pub mod prompts;
 pub mod read_tracker;
 pub mod server;
 pub mod tools;
 pub mod viewer;
 
+pub use auth::BearerTokenAuth;
 pub use config::MetisServerConfig;
 pub use error::{McpServerError, Result};
 pub use server::MetisServerHandler;
 
 use anyhow::Result as AnyhowResult;
 use metis_core::{
-    application::services::workspace::WorkspaceDetectionService, dal::database::Database,
-    domain::configuration::FlightLevelConfig,
+    application::services::workspace::WorkspaceDetectionService,
+    dal::database::Database,
+    domain::configuration::{FlightLevelConfig, McpTransport},
 };
 use rust_mcp_sdk::{
-    mcp_server::{server_runtime, McpServerOptions},
+    mcp_server::{hyper_server, server_runtime, HyperServerOptions, McpServerOptions},
     schema::{
-        Implementation, InitializeResult, ServerCapabilities, ServerCapabilitiesTools,
-        LATEST_PROTOCOL_VERSION,
+        Implementation, InitializeResult, ServerCapabilities, ServerCapabilitiesPrompts,
+        ServerCapabilitiesTools, LATEST_PROTOCOL_VERSION,
     },
     McpServer, StdioTransport, ToMcpServerHandler, TransportOptions,
 };
+use std::sync::Arc;
 use tracing::info;
 
 fn find_metis_log_path() -> Option<String> {
@@ -68,8 +75,25 @@ fn generate_dynamic_instructions() -> String {
     let static_instructions = include_str!("../instructions.md");
 
     if let Some(config) = config {
-        let config_section = format!(
+        format!("{}{}", configuration_section(&config), static_instructions)
+    } else {
+        format!(
             r#"
+## Configuration Status
+**Status**: No active Metis workspace detected. Initialize a project first using `initialize_project`.
+
+{}
+"#,
+            static_instructions
+        )
+    }
+}
+
+/// The part of the instructions describing the project's configuration,
+/// regenerated when the configuration changes mid-session
+pub(crate) fn configuration_section(config: &FlightLevelConfig) -> String {
+    format!(
+        r#"
 ## Current Project Configuration
 
 **Active Preset**: {}
@@ -84,29 +108,16 @@ fn generate_dynamic_instructions() -> String {
 - To enable/disable specific levels: Use `metis config set --initiatives <true/false>`
 
 "#,
-            config.preset_name(),
-            config
-                .enabled_document_types()
-                .iter()
-                .map(|t| t.to_string())
-                .collect::<Vec<_>>()
-                .join(", "),
-            config.hierarchy_display(),
-            generate_operation_notes(&config)
-        );
-
-        format!("{}{}", config_section, static_instructions)
-    } else {
-        format!(
-            r#"
-## Configuration Status
-**Status**: No active Metis workspace detected. Initialize a project first using `initialize_project`.
-
-{}
-"#,
-            static_instructions
-        )
-    }
+        config.preset_name(),
+        config
+            .enabled_document_types()
+            .iter()
+            .map(|t| t.to_string())
+            .collect::<Vec<_>>()
+            .join(", "),
+        config.hierarchy_display(),
+        generate_operation_notes(config)
+    )
 }
 
 fn generate_operation_notes(config: &FlightLevelConfig) -> String {
@@ -149,7 +160,7 @@ pub async fn run() -> AnyhowResult<()> {
             .try_init()
     };
 
-    // Load configuration (minimal for now)
+    // Load configuration (transport from config.toml and the environment)
     let config = MetisServerConfig::from_env()?;
 
     info!("Starting Metis MCP Server");
@@ -168,7 +179,11 @@ pub async fn run() -> AnyhowResult<()> {
             website_url: None,
         },
         capabilities: ServerCapabilities {
-            tools: Some(ServerCapabilitiesTools { list_changed: None }),
+            // The tool list follows the configuration, which can change mid-session
+            tools: Some(ServerCapabilitiesTools {
+                list_changed: Some(true),
+            }),
+            prompts: Some(ServerCapabilitiesPrompts { list_changed: None }),
             ..Default::default()
         },
         meta: None,
@@ -176,27 +191,66 @@ pub async fn run() -> AnyhowResult<()> {
         protocol_version: LATEST_PROTOCOL_VERSION.to_string(),
     };
 
-    // Create transport
-    let transport = StdioTransport::new(TransportOptions::default())
-        .map_err(|e| anyhow::anyhow!("Failed to create transport: {}", e))?;
-
     // Create handler
+    let transport_config = config.mcp.clone();
+    let auth_token = config.auth_token.clone();
+    if transport_config.read_only {
+        info!("Read-only mode: tools that change the workspace are disabled");
+    }
     let handler = MetisServerHandler::new(config).to_mcp_server_handler();
 
-    // Create and start server
-    let server = server_runtime::create_server(McpServerOptions {
-        server_details,
-        transport,
-        handler,
-        task_store: None,
-        client_task_store: None,
-    });
-
-    info!("MCP Server starting on stdio transport");
-    server
-        .start()
-        .await
-        .map_err(|e| anyhow::anyhow!("MCP server failed to start: {}", e))?;
+    match transport_config.transport {
+        McpTransport::Stdio => {
+            // Create transport
+            let transport = StdioTransport::new(TransportOptions::default())
+                .map_err(|e| anyhow::anyhow!("Failed to create transport: {}", e))?;
+
+            // Create and start server
+            let server = server_runtime::create_server(McpServerOptions {
+                server_details,
+                transport,
+                handler,
+                task_store: None,
+                client_task_store: None,
+            });
+
+            info!("MCP Server starting on stdio transport");
+            server
+                .start()
+                .await
+                .map_err(|e| anyhow::anyhow!("MCP server failed to start: {}", e))?;
+        }
+        McpTransport::Http => {
+            // Anyone who can reach the port could otherwise edit the workspace
+            let token = auth_token.ok_or_else(|| {
+                anyhow::anyhow!(
+                    "The http transport needs a bearer token: set METIS_MCP_TOKEN (or pass --token to `metis mcp`)"
+                )
+            })?;
+
+            // One long-lived server; each client gets its own session
+            let host = transport_config.host().to_string();
+            let port = transport_config.port();
+            let server = hyper_server::create_server(
+                server_details,
+                handler,
+                HyperServerOptions {
+                    host: host.clone(),
+                    port,
+                    auth: Some(Arc::new(BearerTokenAuth::new(token))),
+                    ..Default::default()
+                },
+            );
+
+            info!("MCP Server starting on http://{}:{}/mcp", host, port);
+            // The terminal isn't the protocol channel here, so say where to connect
+            eprintln!("Metis MCP server listening on http://{}:{}/mcp", host, port);
+            server
+                .start()
+                .await
+                .map_err(|e| anyhow::anyhow!("MCP server failed to start: {}", e))?;
+        }
+    }
 
     Ok(())
 }
@@ -0,0 +1,251 @@
+//! Workspaces an agent has registered with the server.
+//!
+//! The MCP server keeps an in-memory registry per client session, so one
+//! server can serve several Metis workspaces without one client's
+//! registrations restricting another's. While a session's registry is empty
+//! every `project_path` is accepted as before. Once a project is registered,
+//! each of that session's tool calls must name one of its registered
+//! workspaces, given either as its path or by its name.
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+/// A registered workspace
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RegisteredProject {
+    pub name: String,
+    /// The `.metis` directory, canonicalized
+    pub metis_dir: PathBuf,
+}
+
+/// In-memory registry of workspaces, keyed by name.
+/// Thread-safe via internal `Mutex`.
+#[derive(Debug)]
+pub struct ProjectRegistry {
+    projects: Mutex<BTreeMap<String, PathBuf>>,
+}
+
+impl ProjectRegistry {
+    pub fn new() -> Self {
+        Self {
+            projects: Mutex::new(BTreeMap::new()),
+        }
+    }
+
+    /// Register a workspace under `name`, replacing any earlier registration
+    /// of the same name or the same workspace
+    pub fn register(&self, name: &str, metis_dir: &Path) -> RegisteredProject {
+        let metis_dir = canonical(metis_dir);
+        let mut projects = self.projects.lock().unwrap();
+        projects.retain(|_, path| *path != metis_dir);
+        projects.insert(name.to_string(), metis_dir.clone());
+        RegisteredProject {
+            name: name.to_string(),
+            metis_dir,
+        }
+    }
+
+    /// Every registered workspace, by name
+    pub fn list(&self) -> Vec<RegisteredProject> {
+        self.projects
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(name, metis_dir)| RegisteredProject {
+                name: name.clone(),
+                metis_dir: metis_dir.clone(),
+            })
+            .collect()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.projects.lock().unwrap().is_empty()
+    }
+
+    /// The `.metis` directory a tool call's `project_path` refers to: a
+    /// registered name or path. While nothing is registered any path is
+    /// accepted as given.
+    pub fn resolve(&self, project_path: &str) -> Result<PathBuf, UnregisteredProject> {
+        let projects = self.projects.lock().unwrap();
+        if projects.is_empty() {
+            return Ok(PathBuf::from(project_path));
+        }
+        if let Some(metis_dir) = projects.get(project_path) {
+            return Ok(metis_dir.clone());
+        }
+        let metis_dir = canonical(Path::new(project_path));
+        if projects.values().any(|path| *path == metis_dir) {
+            return Ok(metis_dir);
+        }
+        Err(UnregisteredProject {
+            project_path: project_path.to_string(),
+            registered: projects.keys().cloned().collect(),
+        })
+    }
+}
+
+impl Default for ProjectRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A registry per client session. Sessions are told apart by their MCP
+/// session id; stdio serves a single client, without one. A session's
+/// registry is dropped once the runtime serving it is gone, so a long-running
+/// HTTP server doesn't keep the registrations of every client it has seen.
+#[derive(Default)]
+pub struct SessionRegistries {
+    sessions: Mutex<HashMap<String, Session>>,
+}
+
+struct Session {
+    registry: Arc<ProjectRegistry>,
+    /// Whether the runtime serving the session is still alive
+    alive: Box<dyn Fn() -> bool + Send + Sync>,
+}
+
+impl SessionRegistries {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The registry of the session with `session_id`, created empty on the
+    /// session's first call. `runtime` is what serves the session; registries
+    /// of sessions whose runtime has since been dropped are cleared out.
+    pub fn for_session<T>(&self, session_id: Option<&str>, runtime: &Arc<T>) -> Arc<ProjectRegistry>
+    where
+        T: ?Sized + Send + Sync + 'static,
+    {
+        let mut sessions = self.sessions.lock().unwrap();
+        sessions.retain(|_, session| (session.alive)());
+        sessions
+            .entry(session_id.unwrap_or_default().to_string())
+            .or_insert_with(|| {
+                let runtime = Arc::downgrade(runtime);
+                Session {
+                    registry: Arc::default(),
+                    alive: Box::new(move || runtime.strong_count() > 0),
+                }
+            })
+            .registry
+            .clone()
+    }
+
+    /// Whether `session_id` is a session still being served
+    pub fn contains(&self, session_id: &str) -> bool {
+        self.sessions.lock().unwrap().contains_key(session_id)
+    }
+}
+
+/// A `project_path` that names no registered workspace
+#[derive(Debug)]
+pub struct UnregisteredProject {
+    pub project_path: String,
+    pub registered: Vec<String>,
+}
+
+impl std::fmt::Display for UnregisteredProject {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} is not a registered project. Registered: {}",
+            self.project_path,
+            self.registered.join(", ")
+        )
+    }
+}
+
+/// Registered paths are compared canonicalized, so `a/.metis` and
+/// `a/./.metis` are the same workspace
+fn canonical(path: &Path) -> PathBuf {
+    path.canonicalize().unwrap_or_else(|_| path.to_path_buf())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_empty_registry_accepts_any_path() {
+        let registry = ProjectRegistry::new();
+        assert_eq!(
+            registry.resolve("/anywhere/.metis").unwrap(),
+            PathBuf::from("/anywhere/.metis")
+        );
+    }
+
+    #[test]
+    fn test_resolves_registered_names_and_paths() {
+        let dir = TempDir::new().unwrap();
+        let api = dir.path().join("api/.metis");
+        let web = dir.path().join("web/.metis");
+        std::fs::create_dir_all(&api).unwrap();
+        std::fs::create_dir_all(&web).unwrap();
+
+        let registry = ProjectRegistry::new();
+        registry.register("api", &api);
+        registry.register("web", &web);
+
+        assert_eq!(
+            registry.resolve("api").unwrap(),
+            api.canonicalize().unwrap()
+        );
+        let dotted = dir.path().join("web/./.metis");
+        assert_eq!(
+            registry.resolve(dotted.to_str().unwrap()).unwrap(),
+            web.canonicalize().unwrap()
+        );
+
+        let error = registry.resolve("/elsewhere/.metis").unwrap_err();
+        assert_eq!(error.registered, vec!["api", "web"]);
+    }
+
+    #[test]
+    fn test_sessions_have_their_own_registry() {
+        let dir = TempDir::new().unwrap();
+        let runtime = Arc::new(());
+        let sessions = SessionRegistries::new();
+        sessions
+            .for_session(Some("a"), &runtime)
+            .register("api", dir.path());
+
+        assert!(!sessions.for_session(Some("a"), &runtime).is_empty());
+        assert!(sessions.for_session(Some("b"), &runtime).is_empty());
+        assert!(sessions.for_session(None, &runtime).is_empty());
+        assert!(sessions
+            .for_session(Some("b"), &runtime)
+            .resolve("/elsewhere/.metis")
+            .is_ok());
+    }
+
+    #[test]
+    fn test_closed_sessions_registries_are_dropped() {
+        let dir = TempDir::new().unwrap();
+        let sessions = SessionRegistries::new();
+        let closed = Arc::new(());
+        let registry = Arc::downgrade(&sessions.for_session(Some("a"), &closed));
+        registry.upgrade().unwrap().register("api", dir.path());
+
+        // Once the session's runtime is gone, the next call clears it out
+        drop(closed);
+        let open = Arc::new(());
+        sessions.for_session(Some("b"), &open);
+        assert!(registry.upgrade().is_none());
+        assert!(!sessions.contains("a"));
+        assert!(sessions.for_session(Some("a"), &open).is_empty());
+    }
+
+    #[test]
+    fn test_reregistering_a_workspace_renames_it() {
+        let dir = TempDir::new().unwrap();
+        let registry = ProjectRegistry::new();
+        registry.register("old", dir.path());
+        registry.register("new", dir.path());
+
+        let names: Vec<String> = registry.list().into_iter().map(|p| p.name).collect();
+        assert_eq!(names, vec!["new"]);
+    }
+}
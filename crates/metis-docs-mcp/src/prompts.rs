@@ -0,0 +1,331 @@
+//! Prompts for common Metis workflows.
+//!
+//! Each prompt is rendered from the workspace as it is now: the documents it
+//! is about are read from disk and injected into the message, so a client
+//! gets the same scaffolding with current content every time.
+
+use metis_core::{
+    application::services::{
+        workspace::{BacklogCategory, BacklogService, WorkspaceDetectionService},
+        DatabaseService,
+    },
+    dal::database::{models::Document, DocumentFilter},
+};
+use rust_mcp_sdk::schema::{
+    GetPromptResult, Prompt, PromptArgument, PromptMessage, RpcError, TextContent,
+};
+use std::collections::HashMap;
+use std::path::Path;
+
+struct PromptArg {
+    name: &'static str,
+    description: &'static str,
+    required: bool,
+}
+
+struct PromptDef {
+    name: &'static str,
+    title: &'static str,
+    description: &'static str,
+    arguments: &'static [PromptArg],
+}
+
+const PROJECT_PATH: PromptArg = PromptArg {
+    name: "project_path",
+    description: "Path to the .metis folder (e.g., \"/Users/me/my-project/.metis\")",
+    required: true,
+};
+
+const PROMPTS: &[PromptDef] = &[
+    PromptDef {
+        name: "decompose_initiative",
+        title: "Decompose initiative into tasks",
+        description: "Break an initiative into tasks, with its content and existing tasks included",
+        arguments: &[
+            PROJECT_PATH,
+            PromptArg {
+                name: "short_code",
+                description: "Short code of the initiative (e.g., PROJ-I-0001)",
+                required: true,
+            },
+        ],
+    },
+    PromptDef {
+        name: "draft_adr",
+        title: "Draft ADR from discussion",
+        description: "Draft an architecture decision record from a discussion about a document, with the document and the decisions already in effect included",
+        arguments: &[
+            PROJECT_PATH,
+            PromptArg {
+                name: "short_code",
+                description: "Short code of the document the decision is about (e.g., PROJ-I-0001)",
+                required: true,
+            },
+            PromptArg {
+                name: "discussion",
+                description: "Notes or transcript of the discussion that reached the decision",
+                required: false,
+            },
+        ],
+    },
+    PromptDef {
+        name: "triage_backlog",
+        title: "Triage backlog",
+        description: "Categorize, rank and place the backlog items, with every item and the initiatives taking work included",
+        arguments: &[
+            PROJECT_PATH,
+            PromptArg {
+                name: "category",
+                description: "Only triage this category: bug, feature or tech-debt",
+                required: false,
+            },
+        ],
+    },
+];
+
+/// Every prompt the server offers
+pub fn list_prompts() -> Vec<Prompt> {
+    PROMPTS
+        .iter()
+        .map(|prompt| Prompt {
+            name: prompt.name.to_string(),
+            title: Some(prompt.title.to_string()),
+            description: Some(prompt.description.to_string()),
+            arguments: prompt
+                .arguments
+                .iter()
+                .map(|argument| PromptArgument {
+                    name: argument.name.to_string(),
+                    title: None,
+                    description: Some(argument.description.to_string()),
+                    required: Some(argument.required),
+                })
+                .collect(),
+            icons: vec![],
+            meta: None,
+        })
+        .collect()
+}
+
+/// Render a prompt with the workspace's current content
+pub async fn get_prompt(
+    name: &str,
+    arguments: &HashMap<String, String>,
+) -> Result<GetPromptResult, RpcError> {
+    let prompt = PROMPTS
+        .iter()
+        .find(|prompt| prompt.name == name)
+        .ok_or_else(|| {
+            RpcError::invalid_params().with_message(format!("Unknown prompt: {}", name))
+        })?;
+    for argument in prompt.arguments.iter().filter(|argument| argument.required) {
+        if !arguments
+            .get(argument.name)
+            .is_some_and(|value| !value.trim().is_empty())
+        {
+            return Err(RpcError::invalid_params().with_message(format!(
+                "Prompt {} needs the {} argument",
+                name, argument.name
+            )));
+        }
+    }
+
+    let metis_dir = Path::new(&arguments["project_path"]);
+    let db = WorkspaceDetectionService::new()
+        .prepare_workspace(metis_dir)
+        .await
+        .map_err(|e| RpcError::invalid_params().with_message(e.to_string()))?;
+    let mut db_service = DatabaseService::new(db.into_repository());
+    let argument = |key: &str| {
+        arguments
+            .get(key)
+            .map(|value| value.trim())
+            .filter(|value| !value.is_empty())
+    };
+
+    let text = match name {
+        "decompose_initiative" => {
+            decompose_initiative(metis_dir, &mut db_service, argument("short_code").unwrap())
+        }
+        "draft_adr" => draft_adr(
+            metis_dir,
+            &mut db_service,
+            argument("short_code").unwrap(),
+            argument("discussion"),
+        ),
+        _ => triage_backlog(metis_dir, &mut db_service, argument("category")),
+    }
+    .map_err(|e| RpcError::invalid_params().with_message(e))?;
+
+    Ok(GetPromptResult {
+        description: Some(prompt.description.to_string()),
+        messages: vec![PromptMessage {
+            role: rust_mcp_sdk::schema::Role::User,
+            content: TextContent::new(text, None, None).into(),
+        }],
+        meta: None,
+    })
+}
+
+fn decompose_initiative(
+    metis_dir: &Path,
+    db_service: &mut DatabaseService,
+    short_code: &str,
+) -> Result<String, String> {
+    let initiative = find(db_service, short_code)?;
+    if initiative.document_type != "initiative" {
+        return Err(format!(
+            "{} is a {}, not an initiative",
+            short_code, initiative.document_type
+        ));
+    }
+    let mut tasks: Vec<Document> = db_service
+        .find_children(&initiative.id)
+        .map_err(|e| e.to_string())?
+        .into_iter()
+        .filter(|task| !task.archived)
+        .collect();
+    tasks.sort_by(|a, b| a.short_code.cmp(&b.short_code));
+
+    let mut text = format!(
+        "Decompose the initiative {} \"{}\" (phase: {}) into tasks.\n\n",
+        initiative.short_code, initiative.title, initiative.phase
+    );
+    text.push_str(&document_section(metis_dir, &initiative)?);
+    text.push_str("\n## Existing tasks\n\n");
+    text.push_str(&document_list(&tasks, "None yet."));
+    text.push_str(&format!(
+        "\n## How to decompose\n\n\
+         1. Read the design above and list the pieces of work it needs. Skip anything an existing task already covers.\n\
+         2. Make each task independently completable in 1-14 days, with a clear objective and acceptance criteria.\n\
+         3. Show the proposed tasks to the human and get approval before creating anything.\n\
+         4. Create each task with `create_document` (document_type: \"task\", parent_id: \"{code}\"). Decomposing belongs in the decompose phase; if the initiative is in an earlier one, ask before moving it on with `transition_phase`.\n\
+         5. Fill in each task's objective and acceptance criteria with `edit_document`.\n\
+         6. Open the result for review with `open_document` (short_code: \"{code}\", include_children: true).\n",
+        code = initiative.short_code
+    ));
+    Ok(text)
+}
+
+fn draft_adr(
+    metis_dir: &Path,
+    db_service: &mut DatabaseService,
+    short_code: &str,
+    discussion: Option<&str>,
+) -> Result<String, String> {
+    let subject = find(db_service, short_code)?;
+    let decided = db_service
+        .find_by_filter(&DocumentFilter::new().document_type("adr").phase("decided"))
+        .map_err(|e| e.to_string())?;
+
+    let mut text = format!(
+        "Draft an architecture decision record for the decision reached about {} \"{}\".\n\n",
+        subject.short_code, subject.title
+    );
+    text.push_str(&document_section(metis_dir, &subject)?);
+    text.push_str("\n## Discussion\n\n");
+    text.push_str(discussion.unwrap_or(
+        "No notes were given. Ask the human for the options discussed and the outcome before drafting.",
+    ));
+    text.push_str("\n\n## Decisions in effect\n\n");
+    text.push_str(&document_list(&decided, "None."));
+    text.push_str(
+        "\n## How to draft\n\n\
+         1. If the decision replaces one of the decisions in effect, say so and name it in the Context.\n\
+         2. Create the ADR with `create_document` (document_type: \"adr\", with decision_maker if the discussion names one).\n\
+         3. Fill in Context, Decision, Rationale and Consequences with `edit_document`, using only what the discussion settled. Keep Alternatives Analysis for the options actually weighed, and delete the conditional sections that don't apply.\n\
+         4. Leave the ADR in draft and ask the human to review it; only move it to discussion with `transition_phase` when they agree.\n",
+    );
+    Ok(text)
+}
+
+fn triage_backlog(
+    metis_dir: &Path,
+    db_service: &mut DatabaseService,
+    category: Option<&str>,
+) -> Result<String, String> {
+    let category = category
+        .map(|category| {
+            BacklogCategory::from_str(category).ok_or_else(|| {
+                format!(
+                    "Invalid category: {}. Use bug, feature or tech-debt",
+                    category
+                )
+            })
+        })
+        .transpose()?;
+    let items = BacklogService::new(metis_dir)
+        .items(category, db_service)
+        .map_err(|e| e.to_string())?;
+    let mut initiatives = Vec::new();
+    for phase in ["decompose", "active"] {
+        initiatives.extend(
+            db_service
+                .find_by_filter(
+                    &DocumentFilter::new()
+                        .document_type("initiative")
+                        .phase(phase),
+                )
+                .map_err(|e| e.to_string())?,
+        );
+    }
+
+    let mut text = match category {
+        Some(category) => format!("Triage the {} items in the backlog.\n\n", category.label()),
+        None => "Triage the backlog.\n\n".to_string(),
+    };
+    text.push_str("## Backlog items\n\n");
+    if items.is_empty() {
+        text.push_str("The backlog is empty.\n");
+    }
+    for item in &items {
+        text.push_str(&format!(
+            "- {} \"{}\" (phase: {}, category: {}, severity: {})\n",
+            item.document.short_code,
+            item.document.title,
+            item.document.phase,
+            item.category.map_or("none", |category| category.label()),
+            item.severity.map_or("none", |severity| severity.as_str()),
+        ));
+    }
+    text.push_str("\n## Initiatives taking work\n\n");
+    text.push_str(&document_list(&initiatives, "None."));
+    text.push_str(
+        "\n## How to triage\n\n\
+         1. Read each item that is missing a category or severity with `read_document`.\n\
+         2. Propose for every item: a category (bug, feature, tech-debt), a severity (critical, high, medium, low), and whether it belongs in one of the initiatives above, should stay in the backlog, or is a duplicate or no longer needed.\n\
+         3. Present the proposal as a table and get the human's approval before changing anything.\n\
+         4. Apply it: set `severity` in the frontmatter and category tags with `edit_document`, and archive duplicates with `archive_document`. Items going into an initiative are moved with `metis backlog promote <SHORT_CODE> --to <INITIATIVE>`; list those commands for the human to run.\n",
+    );
+    Ok(text)
+}
+
+fn find(db_service: &mut DatabaseService, short_code: &str) -> Result<Document, String> {
+    db_service
+        .find_by_short_code(short_code)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| format!("No document with short code {}", short_code))
+}
+
+/// The document's file, frontmatter and all, under a heading
+fn document_section(metis_dir: &Path, doc: &Document) -> Result<String, String> {
+    let path = metis_dir.join(&doc.filepath);
+    let content = std::fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+    Ok(format!(
+        "## {} {}\n\n```markdown\n{}\n```\n",
+        doc.short_code,
+        doc.title,
+        content.trim_end()
+    ))
+}
+
+/// One line per document, or `empty` when there are none
+fn document_list(docs: &[Document], empty: &str) -> String {
+    if docs.is_empty() {
+        return format!("{}\n", empty);
+    }
+    docs.iter()
+        .map(|doc| format!("- {} \"{}\" ({})\n", doc.short_code, doc.title, doc.phase))
+        .collect()
+}
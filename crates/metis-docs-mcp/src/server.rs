@@ -1,28 +1,40 @@
+use crate::config_watch::ConfigWatcher;
+use crate::formatting::error_result;
+use crate::project_registry::{ProjectRegistry, SessionRegistries};
 use crate::read_tracker::DocumentReadTracker;
 use crate::tools::{
-    ArchiveDocumentTool, CreateDocumentTool, EditDocumentTool, IndexCodeTool,
-    InitializeProjectTool, ListDocumentsTool, MetisTools, OpenDocumentTool, ReadDocumentTool,
-    SearchDocumentsTool, TransitionPhaseTool,
+    AddBlockerTool, AddTagsTool, ArchiveDocumentTool, BoardSnapshotTool, BulkUpdateTool,
+    CreateDocumentTool, DeleteDocumentTool, EditDocumentTool, GetBlockersTool, HealthTool,
+    IndexCodeTool, InitializeProjectTool, ListDocumentsTool, ListProjectsTool, ListTagsTool,
+    MetisTools, OpenDocumentTool, PollChangesTool, QueryRelationshipsTool, ReadDocumentTool,
+    RegisterProjectTool, RemoveBlockerTool, RemoveTagsTool, RestoreDocumentTool,
+    SearchDocumentsTool, SearchSymbolsTool, SyncRemoteTool, ToggleExitCriterionTool,
+    TransitionPhaseTool, ValidateDocumentTool,
 };
 use crate::viewer::ViewerDispatcher;
 use crate::MetisServerConfig;
 use async_trait::async_trait;
+use metis_core::application::services::workspace::WorkspaceDetectionService;
 use metis_core::domain::configuration::ViewerConfig;
 use rust_mcp_sdk::{
     mcp_server::ServerHandler,
     schema::{
-        CallToolRequestParams, CallToolResult, ListToolsResult, PaginatedRequestParams, RpcError,
+        CallToolRequestParams, CallToolResult, GetPromptRequestParams, GetPromptResult,
+        ListPromptsResult, ListToolsResult, PaginatedRequestParams, RpcError, TextContent, Tool,
     },
     McpServer,
 };
 use std::sync::Arc;
-use tracing::info;
+use std::time::Instant;
+use tracing::{info, warn};
 
 pub struct MetisServerHandler {
-    #[allow(dead_code)]
     config: Arc<MetisServerConfig>,
     read_tracker: Arc<DocumentReadTracker>,
+    projects: Arc<SessionRegistries>,
+    config_watcher: Arc<ConfigWatcher>,
     viewer_dispatcher: Arc<ViewerDispatcher>,
+    started: Instant,
 }
 
 impl MetisServerHandler {
@@ -33,6 +45,12 @@ impl MetisServerHandler {
     pub fn with_viewer_config(config: MetisServerConfig, viewer_config: ViewerConfig) -> Self {
         info!("Initializing Metis MCP Server");
 
+        // The workspace the startup instructions describe
+        let metis_dir = WorkspaceDetectionService::new()
+            .find_workspace()
+            .ok()
+            .flatten();
+
         // Build viewer backends
         let backends: Vec<Box<dyn crate::viewer::DocumentViewer>> = vec![
             Box::new(crate::viewer::VscodeViewer::new()),
@@ -42,37 +60,109 @@ impl MetisServerHandler {
         Self {
             config: Arc::new(config),
             read_tracker: Arc::new(DocumentReadTracker::new()),
+            projects: Arc::new(SessionRegistries::new()),
+            config_watcher: Arc::new(ConfigWatcher::new(metis_dir)),
             viewer_dispatcher: Arc::new(ViewerDispatcher::new(viewer_config, backends)),
+            started: Instant::now(),
         }
     }
-}
 
-#[async_trait]
-impl ServerHandler for MetisServerHandler {
-    async fn handle_list_tools_request(
-        &self,
-        _params: Option<PaginatedRequestParams>,
-        _runtime: Arc<dyn McpServer>,
-    ) -> Result<ListToolsResult, RpcError> {
-        Ok(ListToolsResult {
-            tools: MetisTools::tools(),
-            meta: None,
-            next_cursor: None,
-        })
+    /// The tools to offer, described for the project's current configuration
+    fn tools(&self) -> Vec<Tool> {
+        let mut tools = if self.config.mcp.read_only {
+            MetisTools::read_only_tools()
+        } else {
+            MetisTools::tools()
+        };
+        if let Some(config) = self.config_watcher.current() {
+            let enabled: Vec<String> = config
+                .enabled_document_types()
+                .iter()
+                .map(|t| t.to_string())
+                .collect();
+            for tool in tools
+                .iter_mut()
+                .filter(|tool| tool.name == "create_document")
+            {
+                let description = tool.description.get_or_insert_with(String::new);
+                description.push_str(&format!(
+                    " This project ({} preset) can create: {}.",
+                    config.preset_name(),
+                    enabled.join(", ")
+                ));
+            }
+        }
+        tools
     }
 
-    async fn handle_call_tool_request(
+    async fn call_tool(
         &self,
         params: CallToolRequestParams,
-        _runtime: Arc<dyn McpServer>,
+        projects: Arc<ProjectRegistry>,
     ) -> Result<CallToolResult, rust_mcp_sdk::schema::schema_utils::CallToolError> {
-        let args = serde_json::Value::Object(params.arguments.unwrap_or_default());
+        let mut arguments = params.arguments.unwrap_or_default();
+        if self.config.mcp.read_only
+            && MetisTools::tools()
+                .iter()
+                .any(|tool| tool.name == params.name)
+            && !MetisTools::is_read_only_call(&params.name, &arguments)
+        {
+            return Ok(error_result(
+                "Read-only server",
+                &format!(
+                    "{} changes the workspace, and this server only allows reading it.",
+                    params.name
+                ),
+                Some("Ask whoever runs the server to turn off mcp.read_only, or make the change locally."),
+            ));
+        }
+
+        // Once projects are registered, calls may only reach those workspaces
+        if !matches!(
+            params.name.as_str(),
+            "initialize_project" | "register_project" | "list_projects"
+        ) {
+            if let Some(serde_json::Value::String(project_path)) = arguments.get("project_path") {
+                match projects.resolve(project_path) {
+                    Ok(metis_dir) => {
+                        let metis_dir = metis_dir.to_string_lossy().to_string();
+                        arguments.insert("project_path".to_string(), metis_dir.into());
+                    }
+                    Err(e) => {
+                        return Ok(error_result(
+                            "Unregistered project",
+                            &e.to_string(),
+                            Some("Use `list_projects` to see the registered projects, or `register_project` to add this one."),
+                        ))
+                    }
+                }
+            }
+        }
+        let args = serde_json::Value::Object(arguments);
 
         match params.name.as_str() {
             "initialize_project" => {
                 let tool: InitializeProjectTool = serde_json::from_value(args)
                     .map_err(rust_mcp_sdk::schema::schema_utils::CallToolError::new)?;
-                tool.call_tool().await
+                let result = tool.call_tool().await?;
+                // A new workspace joins the registry when one is in use
+                if !projects.is_empty() && !result.is_error.unwrap_or(false) {
+                    let project_path = std::path::Path::new(&tool.project_path);
+                    if let Some(name) = project_path.file_name() {
+                        projects.register(&name.to_string_lossy(), &project_path.join(".metis"));
+                    }
+                }
+                Ok(result)
+            }
+            "register_project" => {
+                let tool: RegisterProjectTool = serde_json::from_value(args)
+                    .map_err(rust_mcp_sdk::schema::schema_utils::CallToolError::new)?;
+                tool.call_tool_with_registry(projects).await
+            }
+            "list_projects" => {
+                let tool: ListProjectsTool = serde_json::from_value(args)
+                    .map_err(rust_mcp_sdk::schema::schema_utils::CallToolError::new)?;
+                tool.call_tool_with_registry(projects).await
             }
             "list_documents" => {
                 let tool: ListDocumentsTool = serde_json::from_value(args)
@@ -99,6 +189,11 @@ impl ServerHandler for MetisServerHandler {
                     .map_err(rust_mcp_sdk::schema::schema_utils::CallToolError::new)?;
                 tool.call_tool().await
             }
+            "validate_document" => {
+                let tool: ValidateDocumentTool = serde_json::from_value(args)
+                    .map_err(rust_mcp_sdk::schema::schema_utils::CallToolError::new)?;
+                tool.call_tool().await
+            }
             "edit_document" => {
                 let tool: EditDocumentTool = serde_json::from_value(args)
                     .map_err(rust_mcp_sdk::schema::schema_utils::CallToolError::new)?;
@@ -108,16 +203,96 @@ impl ServerHandler for MetisServerHandler {
                 )
                 .await
             }
+            "toggle_exit_criterion" => {
+                let tool: ToggleExitCriterionTool = serde_json::from_value(args)
+                    .map_err(rust_mcp_sdk::schema::schema_utils::CallToolError::new)?;
+                tool.call_tool().await
+            }
+            "add_blocker" => {
+                let tool: AddBlockerTool = serde_json::from_value(args)
+                    .map_err(rust_mcp_sdk::schema::schema_utils::CallToolError::new)?;
+                tool.call_tool().await
+            }
+            "remove_blocker" => {
+                let tool: RemoveBlockerTool = serde_json::from_value(args)
+                    .map_err(rust_mcp_sdk::schema::schema_utils::CallToolError::new)?;
+                tool.call_tool().await
+            }
+            "get_blockers" => {
+                let tool: GetBlockersTool = serde_json::from_value(args)
+                    .map_err(rust_mcp_sdk::schema::schema_utils::CallToolError::new)?;
+                tool.call_tool().await
+            }
+            "add_tags" => {
+                let tool: AddTagsTool = serde_json::from_value(args)
+                    .map_err(rust_mcp_sdk::schema::schema_utils::CallToolError::new)?;
+                tool.call_tool().await
+            }
+            "remove_tags" => {
+                let tool: RemoveTagsTool = serde_json::from_value(args)
+                    .map_err(rust_mcp_sdk::schema::schema_utils::CallToolError::new)?;
+                tool.call_tool().await
+            }
+            "list_tags" => {
+                let tool: ListTagsTool = serde_json::from_value(args)
+                    .map_err(rust_mcp_sdk::schema::schema_utils::CallToolError::new)?;
+                tool.call_tool().await
+            }
+            "query_relationships" => {
+                let tool: QueryRelationshipsTool = serde_json::from_value(args)
+                    .map_err(rust_mcp_sdk::schema::schema_utils::CallToolError::new)?;
+                tool.call_tool().await
+            }
+            "board_snapshot" => {
+                let tool: BoardSnapshotTool = serde_json::from_value(args)
+                    .map_err(rust_mcp_sdk::schema::schema_utils::CallToolError::new)?;
+                tool.call_tool().await
+            }
+            "poll_changes" => {
+                let tool: PollChangesTool = serde_json::from_value(args)
+                    .map_err(rust_mcp_sdk::schema::schema_utils::CallToolError::new)?;
+                tool.call_tool().await
+            }
+            "health" => {
+                let tool: HealthTool = serde_json::from_value(args)
+                    .map_err(rust_mcp_sdk::schema::schema_utils::CallToolError::new)?;
+                tool.call_tool_with_uptime(self.started.elapsed()).await
+            }
+            "sync_remote" => {
+                let tool: SyncRemoteTool = serde_json::from_value(args)
+                    .map_err(rust_mcp_sdk::schema::schema_utils::CallToolError::new)?;
+                tool.call_tool().await
+            }
             "archive_document" => {
                 let tool: ArchiveDocumentTool = serde_json::from_value(args)
                     .map_err(rust_mcp_sdk::schema::schema_utils::CallToolError::new)?;
                 tool.call_tool().await
             }
+            "restore_document" => {
+                let tool: RestoreDocumentTool = serde_json::from_value(args)
+                    .map_err(rust_mcp_sdk::schema::schema_utils::CallToolError::new)?;
+                tool.call_tool().await
+            }
+            "delete_document" => {
+                let tool: DeleteDocumentTool = serde_json::from_value(args)
+                    .map_err(rust_mcp_sdk::schema::schema_utils::CallToolError::new)?;
+                tool.call_tool().await
+            }
+            "bulk_update" => {
+                let tool: BulkUpdateTool = serde_json::from_value(args)
+                    .map_err(rust_mcp_sdk::schema::schema_utils::CallToolError::new)?;
+                tool.call_tool().await
+            }
             "index_code" => {
                 let tool: IndexCodeTool = serde_json::from_value(args)
                     .map_err(rust_mcp_sdk::schema::schema_utils::CallToolError::new)?;
                 tool.call_tool().await
             }
+            "search_symbols" => {
+                let tool: SearchSymbolsTool = serde_json::from_value(args)
+                    .map_err(rust_mcp_sdk::schema::schema_utils::CallToolError::new)?;
+                tool.call_tool().await
+            }
             "open_document" => {
                 let tool: OpenDocumentTool = serde_json::from_value(args)
                     .map_err(rust_mcp_sdk::schema::schema_utils::CallToolError::new)?;
@@ -128,3 +303,80 @@ impl ServerHandler for MetisServerHandler {
         }
     }
 }
+
+#[async_trait]
+impl ServerHandler for MetisServerHandler {
+    async fn handle_list_tools_request(
+        &self,
+        _params: Option<PaginatedRequestParams>,
+        _runtime: Arc<dyn McpServer>,
+    ) -> Result<ListToolsResult, RpcError> {
+        Ok(ListToolsResult {
+            tools: self.tools(),
+            meta: None,
+            next_cursor: None,
+        })
+    }
+
+    async fn handle_list_prompts_request(
+        &self,
+        _params: Option<PaginatedRequestParams>,
+        _runtime: Arc<dyn McpServer>,
+    ) -> Result<ListPromptsResult, RpcError> {
+        Ok(ListPromptsResult {
+            prompts: crate::prompts::list_prompts(),
+            meta: None,
+            next_cursor: None,
+        })
+    }
+
+    async fn handle_get_prompt_request(
+        &self,
+        params: GetPromptRequestParams,
+        _runtime: Arc<dyn McpServer>,
+    ) -> Result<GetPromptResult, RpcError> {
+        crate::prompts::get_prompt(&params.name, &params.arguments.unwrap_or_default()).await
+    }
+
+    async fn handle_call_tool_request(
+        &self,
+        params: CallToolRequestParams,
+        runtime: Arc<dyn McpServer>,
+    ) -> Result<CallToolResult, rust_mcp_sdk::schema::schema_utils::CallToolError> {
+        // Each client session registers its own projects; state kept for
+        // sessions that have since closed is dropped
+        let session = runtime.session_id().unwrap_or_default();
+        let projects = self.projects.for_session(Some(session.as_str()), &runtime);
+        self.config_watcher
+            .retain_sessions(|session| self.projects.contains(session));
+        let workspace = params
+            .arguments
+            .as_ref()
+            .and_then(|arguments| arguments.get("project_path"))
+            .and_then(serde_json::Value::as_str)
+            .and_then(|project_path| projects.resolve(project_path).ok());
+        let mut result = self.call_tool(params, projects).await?;
+
+        // Instructions were sent at startup; if the configuration of the
+        // workspace this call acted on moved on since this session last saw
+        // it, refresh the client's tool list and hand the agent the
+        // regenerated configuration section with this result
+        if let Some(config) = self.config_watcher.changed(&session, workspace.as_deref()) {
+            info!(
+                "Configuration changed to the {} preset; notifying the client",
+                config.preset_name()
+            );
+            if let Err(e) = runtime.send_tool_list_changed(None).await {
+                warn!("Failed to send tools/list_changed: {}", e);
+            }
+            let notice = format!(
+                "**Note**: The project configuration changed during this session. Use these instructions from now on, in place of the configuration given at startup:\n{}",
+                crate::configuration_section(&config)
+            );
+            result
+                .content
+                .push(TextContent::new(notice, None, None).into());
+        }
+        Ok(result)
+    }
+}
@@ -0,0 +1,84 @@
+use crate::formatting::{error_result, ToolOutput};
+use metis_core::{
+    application::services::{
+        workspace::{LinkKind, LinkService, WorkspaceDetectionService},
+        DatabaseService,
+    },
+    MetisError,
+};
+use rust_mcp_sdk::{
+    macros::{mcp_tool, JsonSchema},
+    schema::{schema_utils::CallToolError, CallToolResult},
+};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+#[mcp_tool(
+    name = "add_blocker",
+    description = "Record that a task or initiative can't finish until another document does, by adding it to blocked_by (e.g., PROJ-T-0002 blocked by PROJ-T-0001). Both short codes must exist. A blocker that would create a cycle, directly or through other blockers, is refused with the chain that closes it. Use get_blockers to see everything a document is waiting on.",
+    idempotent_hint = true,
+    destructive_hint = false,
+    open_world_hint = false,
+    read_only_hint = false
+)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct AddBlockerTool {
+    /// Path to the .metis folder (e.g., "/Users/me/my-project/.metis"). Must end with .metis
+    pub project_path: String,
+    /// Short code (e.g., PROJ-T-0002) of the task or initiative that is blocked
+    pub short_code: String,
+    /// Short code (e.g., PROJ-T-0001) of the document that blocks it
+    pub blocked_by: String,
+}
+
+impl AddBlockerTool {
+    pub async fn call_tool(&self) -> std::result::Result<CallToolResult, CallToolError> {
+        let metis_dir = Path::new(&self.project_path);
+
+        // Prepare workspace (validates, creates/updates database, syncs)
+        let detection_service = WorkspaceDetectionService::new();
+        let db = detection_service
+            .prepare_workspace(metis_dir)
+            .await
+            .map_err(|e| {
+                CallToolError::new(std::io::Error::new(
+                    std::io::ErrorKind::Other,
+                    e.to_string(),
+                ))
+            })?;
+        let mut db_service = DatabaseService::new(db.into_repository());
+
+        let added = match LinkService::new(metis_dir).link(
+            &self.short_code,
+            &self.blocked_by,
+            LinkKind::BlockedBy,
+            &mut db_service,
+        ) {
+            Ok(added) => added,
+            Err(MetisError::NotFound(message)) => {
+                return Ok(error_result(
+                    "Document not found",
+                    &message,
+                    Some("Use `list_documents` to see available documents."),
+                ))
+            }
+            Err(MetisError::ValidationFailed { message }) => {
+                return Ok(error_result("Blocker refused", &message, None))
+            }
+            Err(e) => return Err(CallToolError::new(e)),
+        };
+
+        let message = if added {
+            format!(
+                "✓ {} is now blocked by {}",
+                self.short_code, self.blocked_by
+            )
+        } else {
+            format!(
+                "{} is already blocked by {}",
+                self.short_code, self.blocked_by
+            )
+        };
+        Ok(ToolOutput::new().text(&message).build_result())
+    }
+}
@@ -0,0 +1,114 @@
+use crate::formatting::{error_result, ToolOutput};
+use metis_core::{
+    application::services::{
+        workspace::{TagService, WorkspaceDetectionService},
+        DatabaseService,
+    },
+    MetisError,
+};
+use rust_mcp_sdk::{
+    macros::{mcp_tool, JsonSchema},
+    schema::{schema_utils::CallToolError, CallToolResult},
+};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+#[mcp_tool(
+    name = "add_tags",
+    description = "Add tags to a document (e.g., ['#api', 'sprint-14']). Only the tags list in the frontmatter is rewritten; the rest of the file is left as it is. Tags the document already has are skipped. Phase tags (#phase/...) can't be added: they change with transition_phase.",
+    idempotent_hint = true,
+    destructive_hint = false,
+    open_world_hint = false,
+    read_only_hint = false
+)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct AddTagsTool {
+    /// Path to the .metis folder (e.g., "/Users/me/my-project/.metis"). Must end with .metis
+    pub project_path: String,
+    /// Short code of the document to tag (e.g., PROJ-T-0001)
+    pub short_code: String,
+    /// Tags to add, with or without the leading '#'
+    pub tags: Vec<String>,
+}
+
+impl AddTagsTool {
+    pub async fn call_tool(&self) -> std::result::Result<CallToolResult, CallToolError> {
+        let metis_dir = Path::new(&self.project_path);
+
+        // Check every tag first so a bad one doesn't leave a partial update
+        let tags = match normalize_tags(&self.tags) {
+            Ok(tags) => tags,
+            Err(result) => return Ok(result),
+        };
+
+        // Prepare workspace (validates, creates/updates database, syncs)
+        let detection_service = WorkspaceDetectionService::new();
+        let db = detection_service
+            .prepare_workspace(metis_dir)
+            .await
+            .map_err(|e| {
+                CallToolError::new(std::io::Error::new(
+                    std::io::ErrorKind::Other,
+                    e.to_string(),
+                ))
+            })?;
+        let mut db_service = DatabaseService::new(db.into_repository());
+
+        let service = TagService::new(metis_dir);
+        let mut added = Vec::new();
+        let mut skipped = Vec::new();
+        for tag in tags {
+            match service.add_tag(&self.short_code, &tag, &mut db_service) {
+                Ok(true) => added.push(tag),
+                Ok(false) => skipped.push(tag),
+                Err(MetisError::NotFound(message)) => {
+                    return Ok(error_result(
+                        "Document not found",
+                        &message,
+                        Some("Use `list_documents` to see available documents."),
+                    ))
+                }
+                Err(e) => return Err(CallToolError::new(e)),
+            }
+        }
+
+        let mut output = ToolOutput::new();
+        if !added.is_empty() {
+            output = output.text(&format!(
+                "✓ Tagged {} with {}",
+                self.short_code,
+                added.join(", ")
+            ));
+        }
+        if !skipped.is_empty() {
+            output = output.text(&format!(
+                "{} already has {}",
+                self.short_code,
+                skipped.join(", ")
+            ));
+        }
+        Ok(output.build_result())
+    }
+}
+
+/// Normalize the tags given to a tag tool, or the error result to return
+pub(crate) fn normalize_tags(tags: &[String]) -> Result<Vec<String>, CallToolResult> {
+    if tags.is_empty() {
+        return Err(error_result(
+            "No tags given",
+            "Pass at least one tag.",
+            None,
+        ));
+    }
+    tags.iter()
+        .map(|tag| {
+            TagService::normalize(tag).map_err(|e| {
+                error_result(
+                    "Invalid tag",
+                    &e.to_string(),
+                    Some("Phase tags change with `transition_phase`."),
+                )
+            })
+        })
+        .collect()
+}
@@ -1,10 +1,18 @@
 use super::{
-    archive_document::ArchiveDocumentTool, create_document::CreateDocumentTool,
-    edit_document::EditDocumentTool, index_code::IndexCodeTool,
-    initialize_project::InitializeProjectTool, list_documents::ListDocumentsTool,
-    open_document::OpenDocumentTool, read_document::ReadDocumentTool,
-    reassign_parent::ReassignParentTool, search_documents::SearchDocumentsTool,
-    transition_phase::TransitionPhaseTool,
+    add_blocker::AddBlockerTool, add_tags::AddTagsTool, archive_document::ArchiveDocumentTool,
+    board_snapshot::BoardSnapshotTool, bulk_update::BulkUpdateTool,
+    create_document::CreateDocumentTool, delete_document::DeleteDocumentTool,
+    edit_document::EditDocumentTool, get_blockers::GetBlockersTool, health::HealthTool,
+    index_code::IndexCodeTool, initialize_project::InitializeProjectTool,
+    list_documents::ListDocumentsTool, list_projects::ListProjectsTool, list_tags::ListTagsTool,
+    open_document::OpenDocumentTool, poll_changes::PollChangesTool,
+    query_relationships::QueryRelationshipsTool, read_document::ReadDocumentTool,
+    reassign_parent::ReassignParentTool, register_project::RegisterProjectTool,
+    remove_blocker::RemoveBlockerTool, remove_tags::RemoveTagsTool,
+    restore_document::RestoreDocumentTool, search_documents::SearchDocumentsTool,
+    search_symbols::SearchSymbolsTool, sync_remote::SyncRemoteTool,
+    toggle_exit_criterion::ToggleExitCriterionTool, transition_phase::TransitionPhaseTool,
+    validate_document::ValidateDocumentTool,
 };
 use rust_mcp_sdk::tool_box;
 
@@ -13,15 +21,87 @@ tool_box!(
     MetisTools,
     [
         InitializeProjectTool,
+        RegisterProjectTool,
+        ListProjectsTool,
         ListDocumentsTool,
         SearchDocumentsTool,
         ReadDocumentTool,
         CreateDocumentTool,
         EditDocumentTool,
         TransitionPhaseTool,
+        ValidateDocumentTool,
+        ToggleExitCriterionTool,
+        AddBlockerTool,
+        RemoveBlockerTool,
+        GetBlockersTool,
+        AddTagsTool,
+        RemoveTagsTool,
+        ListTagsTool,
+        QueryRelationshipsTool,
+        BoardSnapshotTool,
+        PollChangesTool,
+        HealthTool,
         ArchiveDocumentTool,
+        RestoreDocumentTool,
+        DeleteDocumentTool,
+        BulkUpdateTool,
         ReassignParentTool,
+        SyncRemoteTool,
         IndexCodeTool,
+        SearchSymbolsTool,
         OpenDocumentTool
     ]
 );
+
+/// Tools that change the workspace but only read it when the named flag
+/// argument is true; read-only servers offer them for those calls
+const READ_ONLY_MODES: &[(&str, &str)] = &[("sync_remote", "dry_run")];
+
+/// Tools that change only the calling session's state, never a workspace.
+/// Registering projects narrows which workspaces the session may reach, so
+/// read-only servers offer them too.
+const SESSION_TOOLS: &[&str] = &["register_project"];
+
+impl MetisTools {
+    /// Tools offered when the server is read-only: those annotated as not
+    /// changing the workspace, those with a read-only mode, and those that
+    /// only change the session
+    pub fn read_only_tools() -> Vec<rust_mcp_sdk::schema::Tool> {
+        Self::tools()
+            .into_iter()
+            .filter(|tool| {
+                annotated_read_only(tool)
+                    || READ_ONLY_MODES.iter().any(|(name, _)| tool.name == *name)
+                    || SESSION_TOOLS.contains(&tool.name.as_str())
+            })
+            .collect()
+    }
+
+    /// Whether the named tool leaves the workspace unchanged
+    pub fn is_read_only(name: &str) -> bool {
+        Self::tools()
+            .iter()
+            .any(|tool| tool.name == name && annotated_read_only(tool))
+    }
+
+    /// Whether a call leaves every workspace unchanged: the tool never
+    /// changes one, only changes the session, or the arguments select its
+    /// read-only mode
+    pub fn is_read_only_call(
+        name: &str,
+        arguments: &serde_json::Map<String, serde_json::Value>,
+    ) -> bool {
+        Self::is_read_only(name)
+            || SESSION_TOOLS.contains(&name)
+            || READ_ONLY_MODES.iter().any(|(tool, flag)| {
+                *tool == name && arguments.get(*flag) == Some(&serde_json::Value::Bool(true))
+            })
+    }
+}
+
+fn annotated_read_only(tool: &rust_mcp_sdk::schema::Tool) -> bool {
+    tool.annotations
+        .as_ref()
+        .and_then(|annotations| annotations.read_only_hint)
+        .unwrap_or(false)
+}
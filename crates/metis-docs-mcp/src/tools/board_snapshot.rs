@@ -0,0 +1,73 @@
+use crate::formatting::{error_result, ToolOutput};
+use metis_core::application::services::{
+    workspace::{BoardKind, BoardService, WorkspaceDetectionService},
+    DatabaseService,
+};
+use rust_mcp_sdk::{
+    macros::{mcp_tool, JsonSchema},
+    schema::{schema_utils::CallToolError, CallToolResult},
+};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+#[mcp_tool(
+    name = "board_snapshot",
+    description = "Get the kanban boards as JSON, the same view humans see with `metis board`: boards (vision, initiative, task, backlog) → columns in phase order → cards with code, title, phase, assignee and exit criteria progress (checked/total). Use it to see where all work stands in one call. Set board to get a single board; empty boards are left out otherwise.",
+    idempotent_hint = true,
+    destructive_hint = false,
+    open_world_hint = false,
+    read_only_hint = true
+)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct BoardSnapshotTool {
+    /// Path to the .metis folder (e.g., "/Users/me/my-project/.metis"). Must end with .metis
+    pub project_path: String,
+    /// Only this board: vision, initiative, task or backlog (default: all boards)
+    pub board: Option<String>,
+    /// Include archived documents on the boards (default: false)
+    pub include_archived: Option<bool>,
+}
+
+impl BoardSnapshotTool {
+    pub async fn call_tool(&self) -> std::result::Result<CallToolResult, CallToolError> {
+        let metis_dir = Path::new(&self.project_path);
+
+        let kinds = match self.board.as_deref().map(str::parse::<BoardKind>) {
+            None => BoardKind::ALL.to_vec(),
+            Some(Ok(kind)) => vec![kind],
+            Some(Err(e)) => return Ok(error_result("Invalid board", &e.to_string(), None)),
+        };
+
+        // Prepare workspace (validates, creates/updates database, syncs)
+        let detection_service = WorkspaceDetectionService::new();
+        let db = detection_service
+            .prepare_workspace(metis_dir)
+            .await
+            .map_err(|e| {
+                CallToolError::new(std::io::Error::new(
+                    std::io::ErrorKind::Other,
+                    e.to_string(),
+                ))
+            })?;
+        let mut db_service = DatabaseService::new(db.into_repository());
+
+        let boards = BoardService::boards(
+            &kinds,
+            self.include_archived.unwrap_or(false),
+            &mut db_service,
+        )
+        .map_err(CallToolError::new)?;
+        let json = serde_json::to_string_pretty(&boards).map_err(CallToolError::new)?;
+
+        let cards: usize = boards
+            .iter()
+            .flat_map(|board| &board.columns)
+            .map(|column| column.cards.len())
+            .sum();
+        Ok(ToolOutput::new()
+            .header("Board Snapshot")
+            .text(&format!("{} board(s), {} card(s)", boards.len(), cards))
+            .code_block(&json, Some("json"))
+            .build_result())
+    }
+}
@@ -0,0 +1,288 @@
+use crate::formatting::{error_result, ToolOutput};
+use crate::tools::TransitionPhaseTool;
+use metis_core::{
+    application::services::{
+        workspace::{
+            PhaseTransitionService, PropertyService, TagService, WorkspaceDetectionService,
+        },
+        DatabaseService,
+    },
+    dal::database::{models::Document, DocumentFilter},
+    domain::documents::types::{DocumentType, Phase},
+};
+use rust_mcp_sdk::{
+    macros::{mcp_tool, JsonSchema},
+    schema::{schema_utils::CallToolError, CallToolResult},
+};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use tracing::warn;
+
+#[mcp_tool(
+    name = "bulk_update",
+    description = "Update every document matching a filter in one call, e.g. to assign a sprint's tasks or close out finished work. The filter is a list of space-separated terms that must all hold: type:<type>, phase:<phase>, tag:<tag>, updated<90d / updated>2025-01-31, or <property>:<value> (e.g., 'type:task tag:#sprint-14 assignee:alice'); archived documents are never matched. Patches: assignee and priority (an empty string removes the field), add_tags / remove_tags, and phase. The update is all or nothing: every phase change is checked before anything is written, and if any document fails the files already changed are put back. The result lists what happened to each document.",
+    idempotent_hint = false,
+    destructive_hint = false,
+    open_world_hint = false,
+    read_only_hint = false
+)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct BulkUpdateTool {
+    /// Path to the .metis folder (e.g., "/Users/me/my-project/.metis"). Must end with .metis
+    pub project_path: String,
+    /// Documents to update, e.g. 'type:task tag:#sprint-14'. An empty filter matches every document.
+    pub filter: String,
+    /// Assignee to set; an empty string unassigns
+    pub assignee: Option<String>,
+    /// Priority to set (e.g., high, P1); an empty string removes it
+    pub priority: Option<String>,
+    /// Tags to add (e.g., ["#sprint-15"])
+    pub add_tags: Option<Vec<String>>,
+    /// Tags to remove (e.g., ["#sprint-14"])
+    pub remove_tags: Option<Vec<String>>,
+    /// Phase to move every document to; it must be an adjacent phase for each of them
+    pub phase: Option<String>,
+}
+
+impl BulkUpdateTool {
+    pub async fn call_tool(&self) -> std::result::Result<CallToolResult, CallToolError> {
+        let metis_dir = Path::new(&self.project_path);
+
+        // Check the filter and patches before touching the workspace
+        let filter = match DocumentFilter::parse(&self.filter) {
+            Ok(filter) => filter,
+            Err(e) => {
+                let hint =
+                    "Use terms like type:task, phase:todo, tag:#sprint-14 or assignee:alice.";
+                return Ok(error_result("Invalid filter", &e, Some(hint)));
+            }
+        };
+        let tag_error = |e: &dyn std::fmt::Display| {
+            error_result(
+                "Invalid tag",
+                &e.to_string(),
+                Some("Phase tags change with phase."),
+            )
+        };
+        let mut add_tags = Vec::new();
+        for tag in self.add_tags.iter().flatten() {
+            match TagService::normalize(tag) {
+                Ok(tag) => add_tags.push(tag),
+                Err(e) => return Ok(tag_error(&e)),
+            }
+        }
+        let mut remove_tags = Vec::new();
+        for tag in self.remove_tags.iter().flatten() {
+            match TagService::normalize(tag) {
+                Ok(tag) => remove_tags.push(tag),
+                Err(e) => return Ok(tag_error(&e)),
+            }
+        }
+        let phase = self
+            .phase
+            .as_deref()
+            .map(TransitionPhaseTool::parse_phase)
+            .transpose()?;
+        if self.assignee.is_none()
+            && self.priority.is_none()
+            && add_tags.is_empty()
+            && remove_tags.is_empty()
+            && phase.is_none()
+        {
+            return Ok(error_result(
+                "Nothing to update",
+                "No patch was given.",
+                Some("Set at least one of assignee, priority, add_tags, remove_tags or phase."),
+            ));
+        }
+
+        // Prepare workspace (validates, creates/updates database, syncs)
+        let detection_service = WorkspaceDetectionService::new();
+        let db = detection_service
+            .prepare_workspace(metis_dir)
+            .await
+            .map_err(|e| {
+                CallToolError::new(std::io::Error::new(
+                    std::io::ErrorKind::Other,
+                    e.to_string(),
+                ))
+            })?;
+        let mut db_service = DatabaseService::new(db.into_repository());
+
+        let docs = db_service.find_by_filter(&filter).map_err(|e| {
+            CallToolError::new(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!("Database error: {}", e),
+            ))
+        })?;
+        if docs.is_empty() {
+            return Ok(ToolOutput::new()
+                .text("No documents match the filter.")
+                .build_result());
+        }
+
+        // Refuse the whole update if any document can't make the phase change
+        if let Some(target) = phase {
+            let transitions = PhaseTransitionService::new(metis_dir);
+            let refused: Vec<String> = docs
+                .iter()
+                .filter(|doc| doc.phase != target.to_string())
+                .filter(|doc| !can_transition(&transitions, doc, target))
+                .map(|doc| format!("{} ({} -> {})", doc.short_code, doc.phase, target))
+                .collect();
+            if !refused.is_empty() {
+                return Ok(error_result(
+                    "Update refused",
+                    &format!(
+                        "Nothing was changed; these documents can't move to {}:\n{}",
+                        target,
+                        refused.join("\n")
+                    ),
+                    Some("Narrow the filter (e.g., add phase:<current>) or move them one phase at a time."),
+                ));
+            }
+        }
+
+        // Keep every file as it was so a failure can be undone
+        let originals: Vec<(PathBuf, String)> = docs
+            .iter()
+            .map(|doc| {
+                let path = metis_dir.join(&doc.filepath);
+                std::fs::read_to_string(&path)
+                    .map(|content| (path.clone(), content))
+                    .map_err(|e| {
+                        CallToolError::new(std::io::Error::new(
+                            std::io::ErrorKind::Other,
+                            format!("Failed to read {}: {}", path.display(), e),
+                        ))
+                    })
+            })
+            .collect::<Result<_, _>>()?;
+
+        let patch = Patch {
+            assignee: self.assignee.as_deref(),
+            priority: self.priority.as_deref(),
+            add_tags: &add_tags,
+            remove_tags: &remove_tags,
+            phase,
+        };
+        let mut rows = Vec::with_capacity(docs.len());
+        for doc in &docs {
+            match patch.apply(metis_dir, doc, &mut db_service).await {
+                Ok(changes) => rows.push(vec![
+                    doc.short_code.clone(),
+                    doc.title.clone(),
+                    if changes.is_empty() {
+                        "unchanged".to_string()
+                    } else {
+                        changes.join(", ")
+                    },
+                ]),
+                Err(e) => {
+                    for (path, content) in &originals {
+                        if let Err(restore) = std::fs::write(path, content) {
+                            warn!("Failed to restore {}: {}", path.display(), restore);
+                        }
+                    }
+                    for row in &mut rows {
+                        row[2] = "rolled back".to_string();
+                    }
+                    rows.push(vec![
+                        doc.short_code.clone(),
+                        doc.title.clone(),
+                        format!("failed: {}", e),
+                    ]);
+                    let table = ToolOutput::new()
+                        .table(&["Code", "Title", "Result"], rows)
+                        .build();
+                    return Ok(error_result(
+                        "Update rolled back",
+                        &format!(
+                            "{} could not be updated, so no document was changed.\n\n{}",
+                            doc.short_code, table
+                        ),
+                        None,
+                    ));
+                }
+            }
+        }
+
+        let updated = rows.iter().filter(|row| row[2] != "unchanged").count();
+        Ok(ToolOutput::new()
+            .header("Bulk Update")
+            .text(&format!(
+                "✓ Updated {} of {} document(s)",
+                updated,
+                docs.len()
+            ))
+            .blank()
+            .table(&["Code", "Title", "Result"], rows)
+            .build_result())
+    }
+}
+
+/// The field updates applied to each matched document
+struct Patch<'a> {
+    assignee: Option<&'a str>,
+    priority: Option<&'a str>,
+    add_tags: &'a [String],
+    remove_tags: &'a [String],
+    phase: Option<Phase>,
+}
+
+impl Patch<'_> {
+    /// Apply every update to one document, returning what changed
+    async fn apply(
+        &self,
+        metis_dir: &Path,
+        doc: &Document,
+        db_service: &mut DatabaseService,
+    ) -> metis_core::Result<Vec<String>> {
+        let mut changes = Vec::new();
+
+        let properties = PropertyService::new(metis_dir);
+        for (key, value) in [("assignee", self.assignee), ("priority", self.priority)] {
+            let Some(value) = value else { continue };
+            let value = Some(value.trim()).filter(|value| !value.is_empty());
+            let change = properties.set_property(&doc.short_code, key, value, db_service)?;
+            if change.changed() {
+                changes.push(match value {
+                    Some(value) => format!("{} {}", key, value),
+                    None => format!("{} removed", key),
+                });
+            }
+        }
+
+        let tags = TagService::new(metis_dir);
+        for tag in self.add_tags {
+            if tags.add_tag(&doc.short_code, tag, db_service)? {
+                changes.push(format!("+{}", tag));
+            }
+        }
+        for tag in self.remove_tags {
+            if tags.remove_tag(&doc.short_code, tag, db_service)? {
+                changes.push(format!("-{}", tag));
+            }
+        }
+
+        if let Some(target) = self.phase {
+            if doc.phase != target.to_string() {
+                let result = PhaseTransitionService::new(metis_dir)
+                    .transition_document(&doc.short_code, target)
+                    .await?;
+                changes.push(format!("{} -> {}", result.from_phase, result.to_phase));
+            }
+        }
+
+        Ok(changes)
+    }
+}
+
+fn can_transition(transitions: &PhaseTransitionService, doc: &Document, target: Phase) -> bool {
+    let doc_type = doc.document_type.parse::<DocumentType>();
+    let current = TransitionPhaseTool::parse_phase(&doc.phase);
+    match (doc_type, current) {
+        (Ok(doc_type), Ok(current)) => transitions.is_valid_transition(doc_type, current, target),
+        _ => false,
+    }
+}
@@ -3,6 +3,7 @@ use metis_core::{
     application::services::{
         document::{creation::DocumentCreationConfig, DocumentCreationService},
         workspace::WorkspaceDetectionService,
+        QueryService,
     },
     domain::documents::types::DocumentType,
 };
@@ -96,6 +97,17 @@ impl CreateDocumentTool {
             )));
         }
 
+        // Look for likely duplicates before the new document exists (warning only)
+        let duplicates = database
+            .repository()
+            .ok()
+            .and_then(|repo| {
+                QueryService::new(repo)
+                    .possible_duplicates(&self.title)
+                    .ok()
+            })
+            .unwrap_or_default();
+
         // Create the document creation service
         let creation_service = DocumentCreationService::new(metis_dir);
 
@@ -221,7 +233,7 @@ impl CreateDocumentTool {
 
         let parent_display = self.parent_id.as_deref().unwrap_or("-");
 
-        let result_output = ToolOutput::new()
+        let mut result_output = ToolOutput::new()
             .header("Document Created")
             .text(&format!("{} created successfully", result.short_code))
             .table(
@@ -233,9 +245,17 @@ impl CreateDocumentTool {
                     vec!["Parent".to_string(), parent_display.to_string()],
                 ],
             )
-            .text(&format!("Path: `{}`", result.file_path.to_string_lossy()))
-            .build_result();
+            .text(&format!("Path: `{}`", result.file_path.to_string_lossy()));
+
+        for duplicate in &duplicates {
+            result_output = result_output.warning(&format!(
+                "Possible duplicate of {}: {} ({:.0}% similar)",
+                duplicate.document.short_code,
+                duplicate.document.title,
+                duplicate.score * 100.0
+            ));
+        }
 
-        Ok(result_output)
+        Ok(result_output.build_result())
     }
 }
@@ -0,0 +1,162 @@
+use crate::formatting::{error_result, ToolOutput};
+use metis_core::application::services::{
+    document::DeletionService, workspace::WorkspaceDetectionService, QueryService,
+};
+use rust_mcp_sdk::{
+    macros::{mcp_tool, JsonSchema},
+    schema::{schema_utils::CallToolError, CallToolResult},
+};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+#[mcp_tool(
+    name = "delete_document",
+    description = "Permanently delete a document using its short code (e.g., PROJ-T-0001). Unlike archive_document this can't be undone, so prefer archiving finished work. Deleting is refused while the document has children that aren't archived, or while other documents list it in blocked_by; the error lists their short codes. Set force to delete anyway: an initiative's tasks are deleted with it, and the blocked_by entries are left pointing at nothing.",
+    idempotent_hint = false,
+    destructive_hint = true,
+    open_world_hint = false,
+    read_only_hint = false
+)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct DeleteDocumentTool {
+    /// Path to the .metis folder (e.g., "/Users/me/my-project/.metis"). Must end with .metis
+    pub project_path: String,
+    /// Document short code (e.g., PROJ-T-0001) to identify the document
+    pub short_code: String,
+    /// Delete even with children that aren't archived or documents blocked by it (default: false)
+    pub force: Option<bool>,
+}
+
+impl DeleteDocumentTool {
+    pub async fn call_tool(&self) -> std::result::Result<CallToolResult, CallToolError> {
+        let metis_dir = Path::new(&self.project_path);
+
+        // Prepare workspace (validates, creates/updates database, syncs)
+        let detection_service = WorkspaceDetectionService::new();
+        let db = detection_service
+            .prepare_workspace(metis_dir)
+            .await
+            .map_err(|e| {
+                CallToolError::new(std::io::Error::new(
+                    std::io::ErrorKind::Other,
+                    e.to_string(),
+                ))
+            })?;
+
+        let db_error = |e: &dyn std::fmt::Display| {
+            CallToolError::new(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!("Database error: {}", e),
+            ))
+        };
+        let mut repo = db.repository().map_err(|e| db_error(&e))?;
+        let mut query = QueryService::new(db.repository().map_err(|e| db_error(&e))?);
+
+        let doc = match repo
+            .find_by_short_code(&self.short_code)
+            .map_err(|e| db_error(&e))?
+        {
+            Some(doc) => doc,
+            None => {
+                return Ok(error_result(
+                    "Document not found",
+                    &format!("No document with short code {}", self.short_code),
+                    Some("Use `list_documents` to see available documents."),
+                ))
+            }
+        };
+
+        // What deleting would leave behind: children that are still in use,
+        // and documents still waiting on this one
+        let mut children: Vec<String> = query
+            .descendants_of(&doc.short_code)
+            .map_err(|e| db_error(&e))?
+            .into_iter()
+            .filter(|child| !child.archived)
+            .map(|child| child.short_code)
+            .collect();
+        children.sort();
+        let mut blocked = Vec::new();
+        for edge in query
+            .edges_from(&doc.short_code)
+            .map_err(|e| db_error(&e))?
+        {
+            if edge.kind != "blocks" {
+                continue;
+            }
+            let waiting = repo
+                .find_by_short_code(&edge.to_code)
+                .map_err(|e| db_error(&e))?;
+            if waiting.is_some_and(|waiting| !waiting.archived) {
+                blocked.push(edge.to_code);
+            }
+        }
+        blocked.sort();
+
+        let force = self.force.unwrap_or(false);
+        if !force && (!children.is_empty() || !blocked.is_empty()) {
+            let mut reasons = Vec::new();
+            if !children.is_empty() {
+                reasons.push(format!(
+                    "Children that aren't archived: {}",
+                    children.join(", ")
+                ));
+            }
+            if !blocked.is_empty() {
+                reasons.push(format!("Listed in blocked_by of: {}", blocked.join(", ")));
+            }
+            return Ok(error_result(
+                "Delete refused",
+                &format!(
+                    "Deleting {} would orphan other documents.\n{}",
+                    doc.short_code,
+                    reasons.join("\n")
+                ),
+                Some("Archive or reassign the children and remove the blocked_by entries first, or set force to delete anyway."),
+            ));
+        }
+
+        let deletion = DeletionService::new()
+            .delete_document_recursive(&metis_dir.join(&doc.filepath).to_string_lossy())
+            .await
+            .map_err(|e| {
+                CallToolError::new(std::io::Error::new(
+                    std::io::ErrorKind::Other,
+                    format!("Failed to delete document: {}", e),
+                ))
+            })?;
+        if deletion.deleted_files.is_empty() {
+            return Err(CallToolError::new(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                format!(
+                    "{} has no file to delete at {}",
+                    doc.short_code, doc.filepath
+                ),
+            )));
+        }
+
+        let total = deletion.deleted_files.len();
+        let count_msg = if total == 1 {
+            String::new()
+        } else {
+            format!(" ({} files)", total)
+        };
+        let mut output =
+            ToolOutput::new().text(&format!("✓ {} deleted{}", doc.short_code, count_msg));
+        // An initiative's tasks live in its directory and went with it
+        if !children.is_empty() && doc.document_type != "initiative" {
+            output = output.warning(&format!(
+                "Left without their parent: {}",
+                children.join(", ")
+            ));
+        }
+        if !blocked.is_empty() {
+            output = output.warning(&format!(
+                "Still listed in blocked_by of: {}",
+                blocked.join(", ")
+            ));
+        }
+
+        Ok(output.build_result())
+    }
+}
@@ -0,0 +1,92 @@
+use crate::formatting::{error_result, ToolOutput};
+use metis_core::{
+    application::services::{
+        workspace::{Blocker, LinkService, WorkspaceDetectionService},
+        DatabaseService,
+    },
+    MetisError,
+};
+use rust_mcp_sdk::{
+    macros::{mcp_tool, JsonSchema},
+    schema::{schema_utils::CallToolError, CallToolResult},
+};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+#[mcp_tool(
+    name = "get_blockers",
+    description = "List everything a document is waiting on: its blockers, their blockers, and so on (e.g., PROJ-T-0003). Each row gives the blocker's depth (1 for a direct blocker), phase, and the document it blocks. Completed and archived blockers are marked done, and the result counts the ones still open.",
+    idempotent_hint = true,
+    destructive_hint = false,
+    open_world_hint = false,
+    read_only_hint = true
+)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct GetBlockersTool {
+    /// Path to the .metis folder (e.g., "/Users/me/my-project/.metis"). Must end with .metis
+    pub project_path: String,
+    /// Short code (e.g., PROJ-T-0003) of the blocked document
+    pub short_code: String,
+}
+
+impl GetBlockersTool {
+    pub async fn call_tool(&self) -> std::result::Result<CallToolResult, CallToolError> {
+        let metis_dir = Path::new(&self.project_path);
+
+        // Prepare workspace (validates, creates/updates database, syncs)
+        let detection_service = WorkspaceDetectionService::new();
+        let db = detection_service
+            .prepare_workspace(metis_dir)
+            .await
+            .map_err(|e| {
+                CallToolError::new(std::io::Error::new(
+                    std::io::ErrorKind::Other,
+                    e.to_string(),
+                ))
+            })?;
+        let mut db_service = DatabaseService::new(db.into_repository());
+
+        let blockers = match LinkService::new(metis_dir).blockers(&self.short_code, &mut db_service)
+        {
+            Ok(blockers) => blockers,
+            Err(MetisError::NotFound(message)) => {
+                return Ok(error_result(
+                    "Document not found",
+                    &message,
+                    Some("Use `list_documents` to see available documents."),
+                ))
+            }
+            Err(e) => return Err(CallToolError::new(e)),
+        };
+
+        let output = ToolOutput::new().header(&format!("Blockers: {}", self.short_code));
+        if blockers.is_empty() {
+            return Ok(output
+                .text(&format!("{} has no blockers", self.short_code))
+                .build_result());
+        }
+
+        let open = blockers.iter().filter(|blocker| !is_done(blocker)).count();
+        let rows = blockers
+            .iter()
+            .map(|blocker| {
+                vec![
+                    blocker.depth.to_string(),
+                    blocker.document.short_code.clone(),
+                    blocker.document.title.clone(),
+                    blocker.document.phase.clone(),
+                    if is_done(blocker) { "yes" } else { "no" }.to_string(),
+                    blocker.blocks.clone(),
+                ]
+            })
+            .collect();
+        Ok(output
+            .table(&["Depth", "Code", "Title", "Phase", "Done", "Blocks"], rows)
+            .field("Open", &format!("{} of {}", open, blockers.len()))
+            .build_result())
+    }
+}
+
+fn is_done(blocker: &Blocker) -> bool {
+    blocker.document.phase == "completed" || blocker.document.archived
+}
@@ -0,0 +1,159 @@
+use crate::formatting::ToolOutput;
+use metis_core::{
+    application::services::{
+        workspace::{RemoteSyncService, WorkspaceDetectionService},
+        QueryService,
+    },
+    dal::database::DocumentGroup,
+    domain::configuration::ConfigFile,
+};
+use rust_mcp_sdk::{
+    macros::{mcp_tool, JsonSchema},
+    schema::{schema_utils::CallToolError, CallToolResult},
+};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::time::Duration;
+
+#[mcp_tool(
+    name = "health",
+    description = "Report the server's health for a workspace as JSON: workspace path, database schema version, document counts by type, the last commit that changed the workspace (the last sync others can pull), documents that would conflict with the remote branch as last fetched, and server uptime. Meant for monitoring server instances; nothing is fetched or changed.",
+    idempotent_hint = true,
+    destructive_hint = false,
+    open_world_hint = false,
+    read_only_hint = true
+)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct HealthTool {
+    /// Path to the .metis folder (e.g., "/Users/me/my-project/.metis"). Must end with .metis
+    pub project_path: String,
+}
+
+#[derive(Debug, Serialize)]
+struct Health {
+    workspace: String,
+    schema_version: Option<String>,
+    /// Unarchived documents by type
+    documents: BTreeMap<String, i64>,
+    last_sync: Option<LastSync>,
+    /// The remote branch compared against for pending conflicts
+    remote: Option<String>,
+    pending_conflicts: Option<usize>,
+    uptime_seconds: u64,
+}
+
+#[derive(Debug, Serialize)]
+struct LastSync {
+    sha: String,
+    at: String,
+}
+
+impl HealthTool {
+    pub async fn call_tool_with_uptime(
+        &self,
+        uptime: Duration,
+    ) -> std::result::Result<CallToolResult, CallToolError> {
+        let metis_dir = Path::new(&self.project_path);
+
+        // Prepare workspace (validates, creates/updates database, syncs)
+        let detection_service = WorkspaceDetectionService::new();
+        let db = detection_service
+            .prepare_workspace(metis_dir)
+            .await
+            .map_err(|e| {
+                CallToolError::new(std::io::Error::new(
+                    std::io::ErrorKind::Other,
+                    e.to_string(),
+                ))
+            })?;
+        let schema_version = db.schema_version().ok().flatten();
+        let documents = QueryService::new(db.into_repository())
+            .count_by(DocumentGroup::Type)
+            .map_err(CallToolError::new)?
+            .into_iter()
+            .map(|group| (group.value, group.count))
+            .collect();
+
+        // git blocks, so keep it off the async runtime. Either half fails on
+        // its own: a workspace outside git has no sync, one without a
+        // remote still has commits.
+        let sync = ConfigFile::load(metis_dir.join("config.toml"))
+            .map(|config| config.sync().clone())
+            .unwrap_or_default();
+        let workspace_dir = metis_dir.to_path_buf();
+        let (last_commit, planned) = tokio::task::spawn_blocking(move || {
+            let remote = RemoteSyncService::new(&workspace_dir);
+            (remote.last_commit(), remote.plan_fetched(&sync))
+        })
+        .await
+        .map_err(CallToolError::new)?;
+
+        let mut notes = Vec::new();
+        let last_sync = match last_commit {
+            Ok(commit) => commit.map(|commit| LastSync {
+                sha: commit.sha,
+                at: commit.committed_at.to_rfc3339(),
+            }),
+            Err(e) => {
+                notes.push(format!("Last sync unknown: {}", e));
+                None
+            }
+        };
+        let (remote, pending_conflicts) = match planned {
+            Ok((plan, remote)) => (Some(remote), Some(plan.conflicts.len())),
+            Err(e) => {
+                notes.push(format!("Pending conflicts unknown: {}", e));
+                (None, None)
+            }
+        };
+
+        let health = Health {
+            workspace: metis_dir.display().to_string(),
+            schema_version,
+            documents,
+            last_sync,
+            remote,
+            pending_conflicts,
+            uptime_seconds: uptime.as_secs(),
+        };
+        let json = serde_json::to_string_pretty(&health).map_err(CallToolError::new)?;
+
+        let total: i64 = health.documents.values().sum();
+        let mut output = ToolOutput::new()
+            .header("Health")
+            .text(&format!(
+                "{} document(s), up {}",
+                total,
+                format_uptime(uptime)
+            ))
+            .code_block(&json, Some("json"));
+        for note in notes {
+            output = output.text(&note);
+        }
+        Ok(output.build_result())
+    }
+}
+
+/// Uptime as e.g. `2h 5m 12s`
+fn format_uptime(uptime: Duration) -> String {
+    let seconds = uptime.as_secs();
+    let (hours, minutes, seconds) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+    match (hours, minutes) {
+        (0, 0) => format!("{}s", seconds),
+        (0, _) => format!("{}m {}s", minutes, seconds),
+        _ => format!("{}h {}m {}s", hours, minutes, seconds),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_uptime() {
+        assert_eq!(format_uptime(Duration::from_secs(42)), "42s");
+        assert_eq!(format_uptime(Duration::from_secs(125)), "2m 5s");
+        assert_eq!(format_uptime(Duration::from_secs(7512)), "2h 5m 12s");
+    }
+}
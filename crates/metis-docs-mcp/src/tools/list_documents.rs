@@ -1,16 +1,19 @@
 use crate::formatting::ToolOutput;
 use metis_core::application::services::workspace::WorkspaceDetectionService;
+use metis_core::dal::database::{
+    models::Document, repository::DocumentRepository, DocumentFilter, DocumentSort, PropertyFilter,
+};
 use rust_mcp_sdk::{
     macros::{mcp_tool, JsonSchema},
     schema::{schema_utils::CallToolError, CallToolResult},
 };
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
 use std::path::Path;
+use std::str::FromStr;
 
 #[mcp_tool(
     name = "list_documents",
-    description = "List documents in a project with optional filtering. Returns document details including unique short codes (format: PREFIX-TYPE-NNNN).",
+    description = "List documents in a project with optional filtering, sorting and pagination. Returns document details including unique short codes (format: PREFIX-TYPE-NNNN). On large workspaces, filter by document_type, phase, tag or assignee, pick only the columns you need with fields, and page with limit plus the cursor returned for the next page.",
     idempotent_hint = true,
     destructive_hint = false,
     open_world_hint = false,
@@ -23,12 +26,57 @@ pub struct ListDocumentsTool {
     /// Include archived documents in results (defaults to false)
     #[serde(default)]
     pub include_archived: Option<bool>,
+    /// Sort by: type (default), updated_at, title, due_date, priority
+    #[serde(default)]
+    pub sort_by: Option<String>,
+    /// Sort in descending order (defaults to false)
+    #[serde(default)]
+    pub descending: Option<bool>,
+    /// Maximum number of documents to return (defaults to all)
+    #[serde(default)]
+    pub limit: Option<u32>,
+    /// Number of documents to skip before the first returned (defaults to 0)
+    #[serde(default)]
+    pub offset: Option<u32>,
+    /// Cursor from the previous page's "Next cursor" to continue from; can't be combined with offset
+    #[serde(default)]
+    pub cursor: Option<String>,
+    /// Only this document type: vision, initiative, task, adr, specification
+    #[serde(default)]
+    pub document_type: Option<String>,
+    /// Only documents in this phase (e.g., active)
+    #[serde(default)]
+    pub phase: Option<String>,
+    /// Only documents with this tag (e.g., #sprint-14; the # is optional)
+    #[serde(default)]
+    pub tag: Option<String>,
+    /// Only documents assigned to this person
+    #[serde(default)]
+    pub assignee: Option<String>,
+    /// Columns to return, in order: type, code, title, phase, updated, assignee, priority, due_date, tags (defaults to type, code, title, phase)
+    #[serde(default)]
+    pub fields: Option<Vec<String>>,
 }
 
+/// Columns returned when no fields are asked for
+const DEFAULT_FIELDS: &[&str] = &["type", "code", "title", "phase"];
+const FIELDS: &[&str] = &[
+    "type", "code", "title", "phase", "updated", "assignee", "priority", "due_date", "tags",
+];
+
 impl ListDocumentsTool {
     pub async fn call_tool(&self) -> std::result::Result<CallToolResult, CallToolError> {
         let metis_dir = Path::new(&self.project_path);
 
+        let sort = match &self.sort_by {
+            Some(sort_by) => DocumentSort::from_str(sort_by).map_err(|e| {
+                CallToolError::new(std::io::Error::new(std::io::ErrorKind::InvalidInput, e))
+            })?,
+            None => DocumentSort::default(),
+        };
+        let offset = self.start()?;
+        let fields = self.fields()?;
+
         // Prepare workspace (validates, creates/updates database, syncs)
         let detection_service = WorkspaceDetectionService::new();
         let db = detection_service
@@ -43,85 +91,153 @@ impl ListDocumentsTool {
 
         let mut repo = db.into_repository();
 
-        // List all documents (respecting include_archived flag, defaults to false)
-        let include_archived = self.include_archived.unwrap_or(false);
-        let mut documents = self.list_all_documents(&mut repo, include_archived)?;
-        let total_count = documents.len();
+        // Sorting and pagination happen in SQL (archived excluded by default)
+        let mut filter = DocumentFilter::new()
+            .include_archived(self.include_archived.unwrap_or(false))
+            .sort_by(sort, self.descending.unwrap_or(false));
+        filter.document_type = self.document_type.as_ref().map(|t| t.to_lowercase());
+        filter.phase = self.phase.as_ref().map(|phase| phase.to_lowercase());
+        if let Some(tag) = &self.tag {
+            let tag = if tag.starts_with('#') {
+                tag.clone()
+            } else {
+                format!("#{}", tag)
+            };
+            filter = filter.tag(tag);
+        }
+        if let Some(assignee) = &self.assignee {
+            filter = filter.property(PropertyFilter::Equals {
+                key: "assignee".to_string(),
+                value: assignee.clone(),
+            });
+        }
+        filter.limit = self.limit.map(|limit| limit as usize);
+        filter.offset = offset;
+
+        let page = repo.find_page(&filter).map_err(|e| {
+            CallToolError::new(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!("Failed to query documents: {}", e),
+            ))
+        })?;
 
         // Build formatted output
-        let mut output = ToolOutput::new().header(&format!("Documents ({} total)", total_count));
+        let mut output = ToolOutput::new().header(&format!("Documents ({} total)", page.total));
 
-        if total_count == 0 {
+        if page.documents.is_empty() {
             output = output.text("No documents found.");
         } else {
-            // Sort by type order, then by short_code
-            let type_order_map: HashMap<&str, usize> = [
-                ("vision", 0),
-                ("specification", 1),
-                ("initiative", 2),
-                ("task", 3),
-                ("adr", 4),
-            ]
-            .into_iter()
-            .collect();
-
-            documents.sort_by(|a, b| {
-                let a_order = type_order_map.get(a.document_type.as_str()).unwrap_or(&999);
-                let b_order = type_order_map.get(b.document_type.as_str()).unwrap_or(&999);
-                a_order
-                    .cmp(b_order)
-                    .then_with(|| a.short_code.cmp(&b.short_code))
-            });
+            if page.documents.len() < page.total {
+                output = output.text(&format!(
+                    "Showing {}-{} of {}",
+                    page.offset + 1,
+                    page.offset + page.documents.len(),
+                    page.total
+                ));
+            }
+
+            // Build single table with the requested columns
+            let mut rows = Vec::with_capacity(page.documents.len());
+            for doc in &page.documents {
+                let mut row = Vec::with_capacity(fields.len());
+                for field in &fields {
+                    row.push(Self::column(&mut repo, doc, field).map_err(|e| {
+                        CallToolError::new(std::io::Error::new(
+                            std::io::ErrorKind::Other,
+                            format!("Failed to query documents: {}", e),
+                        ))
+                    })?);
+                }
+                rows.push(row);
+            }
+            let headers: Vec<&str> = fields.iter().map(|field| Self::header(field)).collect();
+            output = output.table(&headers, rows);
 
-            // Build single table with all documents
-            let rows: Vec<Vec<String>> = documents
-                .iter()
-                .map(|doc| {
-                    vec![
-                        doc.document_type.clone(),
-                        doc.short_code.clone(),
-                        doc.title.clone(),
-                        doc.phase.clone(),
-                    ]
-                })
-                .collect();
-
-            output = output.table(&["Type", "Code", "Title", "Phase"], rows);
+            if page.has_more() {
+                let next = (page.offset + page.documents.len()).to_string();
+                output = output
+                    .field("Next cursor", &next)
+                    .hint("Pass the next cursor as cursor to see the next page.");
+            }
         }
 
         Ok(output.build_result())
     }
 
-    fn list_all_documents(
-        &self,
-        repo: &mut metis_core::dal::database::repository::DocumentRepository,
-        include_archived: bool,
-    ) -> Result<Vec<metis_core::dal::database::models::Document>, CallToolError> {
-        let mut all_docs = Vec::new();
-
-        // Collect all document types
-        for doc_type in ["vision", "initiative", "task", "adr", "specification"] {
-            let mut docs = if include_archived {
-                repo.find_by_type(doc_type)
-            } else {
-                repo.find_by_type_unarchived(doc_type)
-            }
-            .map_err(|e| {
-                CallToolError::new(std::io::Error::new(
-                    std::io::ErrorKind::Other,
-                    format!("Failed to query {} documents: {}", doc_type, e),
-                ))
-            })?;
-            all_docs.append(&mut docs);
+    /// Where the page starts: the cursor from the previous page, or offset
+    fn start(&self) -> Result<usize, CallToolError> {
+        let invalid = |message: String| {
+            CallToolError::new(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                message,
+            ))
+        };
+        match (&self.cursor, self.offset) {
+            (Some(_), Some(_)) => Err(invalid("Use either cursor or offset, not both".to_string())),
+            (Some(cursor), None) => cursor
+                .parse()
+                .map_err(|_| invalid(format!("Invalid cursor: {}", cursor))),
+            (None, offset) => Ok(offset.unwrap_or(0) as usize),
         }
+    }
 
-        // Sort by updated_at descending
-        all_docs.sort_by(|a, b| {
-            b.updated_at
-                .partial_cmp(&a.updated_at)
-                .unwrap_or(std::cmp::Ordering::Equal)
-        });
+    /// The requested columns, checked against the known fields
+    fn fields(&self) -> Result<Vec<String>, CallToolError> {
+        let Some(fields) = &self.fields else {
+            return Ok(DEFAULT_FIELDS.iter().map(|f| f.to_string()).collect());
+        };
+        let fields: Vec<String> = fields.iter().map(|f| f.trim().to_lowercase()).collect();
+        if let Some(unknown) = fields.iter().find(|f| !FIELDS.contains(&f.as_str())) {
+            return Err(CallToolError::new(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("Unknown field: {}. Use {}", unknown, FIELDS.join(", ")),
+            )));
+        }
+        if fields.is_empty() {
+            return Ok(DEFAULT_FIELDS.iter().map(|f| f.to_string()).collect());
+        }
+        Ok(fields)
+    }
+
+    fn header(field: &str) -> &'static str {
+        match field {
+            "type" => "Type",
+            "code" => "Code",
+            "title" => "Title",
+            "phase" => "Phase",
+            "updated" => "Updated",
+            "assignee" => "Assignee",
+            "priority" => "Priority",
+            "due_date" => "Due",
+            _ => "Tags",
+        }
+    }
 
-        Ok(all_docs)
+    fn column(
+        repo: &mut DocumentRepository,
+        doc: &Document,
+        field: &str,
+    ) -> metis_core::Result<String> {
+        Ok(match field {
+            "type" => doc.document_type.clone(),
+            "code" => doc.short_code.clone(),
+            "title" => doc.title.clone(),
+            "phase" => doc.phase.clone(),
+            "updated" => chrono::DateTime::from_timestamp(doc.updated_at as i64, 0)
+                .map(|updated| updated.format("%Y-%m-%d").to_string())
+                .unwrap_or_default(),
+            "tags" => repo
+                .get_tags_for_document(&doc.filepath)?
+                .into_iter()
+                .filter(|tag| !tag.starts_with("#phase/"))
+                .collect::<Vec<_>>()
+                .join(" "),
+            key => repo
+                .find_properties(&doc.filepath)?
+                .into_iter()
+                .find(|property| property.key == key)
+                .map(|property| property.value)
+                .unwrap_or_default(),
+        })
     }
 }
@@ -0,0 +1,54 @@
+use crate::formatting::ToolOutput;
+use crate::project_registry::ProjectRegistry;
+use metis_core::domain::configuration::ConfigFile;
+use rust_mcp_sdk::{
+    macros::{mcp_tool, JsonSchema},
+    schema::{schema_utils::CallToolError, CallToolResult},
+};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+#[mcp_tool(
+    name = "list_projects",
+    description = "List the Metis workspaces registered with register_project, with the name, short code prefix and path of each. The name or the path can be passed as project_path to any tool.",
+    idempotent_hint = true,
+    destructive_hint = false,
+    open_world_hint = false,
+    read_only_hint = true
+)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct ListProjectsTool {}
+
+impl ListProjectsTool {
+    pub async fn call_tool_with_registry(
+        &self,
+        registry: Arc<ProjectRegistry>,
+    ) -> std::result::Result<CallToolResult, CallToolError> {
+        let projects = registry.list();
+        let output = ToolOutput::new().header("Projects");
+        if projects.is_empty() {
+            return Ok(output
+                .text("No projects registered; any project_path is accepted.")
+                .hint("Use `register_project` to manage several workspaces from this session.")
+                .build_result());
+        }
+
+        let rows = projects
+            .iter()
+            .map(|project| {
+                let prefix = ConfigFile::load(project.metis_dir.join("config.toml"))
+                    .map(|config| config.prefix().to_string())
+                    .unwrap_or_else(|_| "-".to_string());
+                vec![
+                    project.name.clone(),
+                    prefix,
+                    project.metis_dir.display().to_string(),
+                ]
+            })
+            .collect();
+        Ok(output
+            .text(&format!("{} project(s) registered", projects.len()))
+            .table(&["Name", "Prefix", "Path"], rows)
+            .build_result())
+    }
+}
@@ -0,0 +1,87 @@
+use crate::formatting::{error_result, ToolOutput};
+use metis_core::{
+    application::services::{
+        workspace::{TagService, WorkspaceDetectionService},
+        DatabaseService, QueryService,
+    },
+    MetisError,
+};
+use rust_mcp_sdk::{
+    macros::{mcp_tool, JsonSchema},
+    schema::{schema_utils::CallToolError, CallToolResult},
+};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+#[mcp_tool(
+    name = "list_tags",
+    description = "List tags. With short_code, the tags on that document, phase tag included. Without it, every tag in use across the workspace with how many unarchived documents carry it, so agents reuse existing tags rather than inventing near-duplicates.",
+    idempotent_hint = true,
+    destructive_hint = false,
+    open_world_hint = false,
+    read_only_hint = true
+)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct ListTagsTool {
+    /// Path to the .metis folder (e.g., "/Users/me/my-project/.metis"). Must end with .metis
+    pub project_path: String,
+    /// List this document's tags instead of the workspace's (e.g., PROJ-T-0001)
+    pub short_code: Option<String>,
+    /// Include phase tags (#phase/...) in the workspace counts (default: false)
+    pub include_phase: Option<bool>,
+}
+
+impl ListTagsTool {
+    pub async fn call_tool(&self) -> std::result::Result<CallToolResult, CallToolError> {
+        let metis_dir = Path::new(&self.project_path);
+
+        // Prepare workspace (validates, creates/updates database, syncs)
+        let detection_service = WorkspaceDetectionService::new();
+        let db = detection_service
+            .prepare_workspace(metis_dir)
+            .await
+            .map_err(|e| {
+                CallToolError::new(std::io::Error::new(
+                    std::io::ErrorKind::Other,
+                    e.to_string(),
+                ))
+            })?;
+
+        if let Some(short_code) = &self.short_code {
+            let mut db_service = DatabaseService::new(db.into_repository());
+            let tags = match TagService::new(metis_dir).tags(short_code, &mut db_service) {
+                Ok(tags) => tags,
+                Err(MetisError::NotFound(message)) => {
+                    return Ok(error_result(
+                        "Document not found",
+                        &message,
+                        Some("Use `list_documents` to see available documents."),
+                    ))
+                }
+                Err(e) => return Err(CallToolError::new(e)),
+            };
+            let output = ToolOutput::new().header(&format!("Tags: {}", short_code));
+            if tags.is_empty() {
+                return Ok(output.text("No tags").build_result());
+            }
+            return Ok(output.text(&tags.join(", ")).build_result());
+        }
+
+        let include_phase = self.include_phase.unwrap_or(false);
+        let counts: Vec<Vec<String>> = QueryService::new(db.into_repository())
+            .tag_counts()
+            .map_err(CallToolError::new)?
+            .into_iter()
+            .filter(|count| include_phase || !count.tag.starts_with("#phase/"))
+            .map(|count| vec![count.tag, count.count.to_string()])
+            .collect();
+        let output = ToolOutput::new().header("Tags");
+        if counts.is_empty() {
+            return Ok(output.text("No tags in use.").build_result());
+        }
+        Ok(output
+            .text(&format!("{} tag(s) in use", counts.len()))
+            .table(&["Tag", "Documents"], counts)
+            .build_result())
+    }
+}
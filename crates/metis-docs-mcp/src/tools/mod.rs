@@ -1,25 +1,63 @@
+pub mod add_blocker;
+pub mod add_tags;
 pub mod all_tools;
 pub mod archive_document;
+pub mod board_snapshot;
+pub mod bulk_update;
 pub mod create_document;
+pub mod delete_document;
 pub mod edit_document;
+pub mod get_blockers;
+pub mod health;
 pub mod index_code;
 pub mod initialize_project;
 pub mod list_documents;
+pub mod list_projects;
+pub mod list_tags;
 pub mod open_document;
+pub mod poll_changes;
+pub mod query_relationships;
 pub mod read_document;
 pub mod reassign_parent;
+pub mod register_project;
+pub mod remove_blocker;
+pub mod remove_tags;
+pub mod restore_document;
 pub mod search_documents;
+pub mod search_symbols;
+pub mod sync_remote;
+pub mod toggle_exit_criterion;
 pub mod transition_phase;
+pub mod validate_document;
 
+pub use add_blocker::*;
+pub use add_tags::*;
 pub use all_tools::MetisTools;
 pub use archive_document::*;
+pub use board_snapshot::*;
+pub use bulk_update::*;
 pub use create_document::*;
+pub use delete_document::*;
 pub use edit_document::*;
+pub use get_blockers::*;
+pub use health::*;
 pub use index_code::*;
 pub use initialize_project::*;
 pub use list_documents::*;
+pub use list_projects::*;
+pub use list_tags::*;
 pub use open_document::*;
+pub use poll_changes::*;
+pub use query_relationships::*;
 pub use read_document::*;
 pub use reassign_parent::*;
+pub use register_project::*;
+pub use remove_blocker::*;
+pub use remove_tags::*;
+pub use restore_document::*;
 pub use search_documents::*;
+pub use search_symbols::*;
+pub use sync_remote::*;
+pub use toggle_exit_criterion::*;
 pub use transition_phase::*;
+pub use validate_document::*;
@@ -0,0 +1,212 @@
+use crate::formatting::{error_result, ToolOutput};
+use metis_core::{
+    application::services::{workspace::WorkspaceDetectionService, DatabaseService},
+    dal::database::{models::Document, DocumentFilter, DocumentSort},
+};
+use rust_mcp_sdk::{
+    macros::{mcp_tool, JsonSchema},
+    schema::{schema_utils::CallToolError, CallToolResult},
+};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+const DEFAULT_LIMIT: usize = 100;
+
+#[mcp_tool(
+    name = "poll_changes",
+    description = "Find what changed in the workspace since a cursor: documents created, edited, transitioned to another phase, archived, or synced in from another workspace, whether by a human, another agent or a sync. Call it first without cursor to get a starting cursor, then pass the returned Next cursor each time to see only newer changes, instead of re-listing every document. Deleted documents aren't reported.",
+    idempotent_hint = true,
+    destructive_hint = false,
+    open_world_hint = false,
+    read_only_hint = true
+)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct PollChangesTool {
+    /// Path to the .metis folder (e.g., "/Users/me/my-project/.metis"). Must end with .metis
+    pub project_path: String,
+    /// Next cursor from the previous call; omit to get a starting cursor
+    pub cursor: Option<String>,
+    /// Maximum number of changes to return (default: 100)
+    pub limit: Option<u32>,
+}
+
+impl PollChangesTool {
+    pub async fn call_tool(&self) -> std::result::Result<CallToolResult, CallToolError> {
+        let metis_dir = Path::new(&self.project_path);
+
+        let since = match self.cursor.as_deref().map(str::trim) {
+            None | Some("") => None,
+            Some(cursor) => match Cursor::parse(cursor) {
+                Some(since) => Some(since),
+                None => {
+                    return Ok(error_result(
+                        "Invalid cursor",
+                        &format!("'{}' is not a cursor returned by poll_changes", cursor),
+                        Some("Omit cursor to start over from the current state."),
+                    ))
+                }
+            },
+        };
+
+        // Prepare workspace (validates, creates/updates database, syncs), so
+        // edits made outside the server show up as changes
+        let detection_service = WorkspaceDetectionService::new();
+        let db = detection_service
+            .prepare_workspace(metis_dir)
+            .await
+            .map_err(|e| {
+                CallToolError::new(std::io::Error::new(
+                    std::io::ErrorKind::Other,
+                    e.to_string(),
+                ))
+            })?;
+        let mut db_service = DatabaseService::new(db.into_repository());
+
+        // Oldest first, ties by filepath, so a page that hits the limit can be
+        // continued from its last change even when files share a timestamp
+        let mut changed = db_service
+            .find_by_filter(
+                &DocumentFilter::new()
+                    .include_archived(true)
+                    .updated_between(since.as_ref().map(|since| since.updated_at), None)
+                    .sort_by(DocumentSort::UpdatedAt, false),
+            )
+            .map_err(CallToolError::new)?;
+        changed.sort_by(|a, b| {
+            a.updated_at
+                .total_cmp(&b.updated_at)
+                .then_with(|| a.filepath.cmp(&b.filepath))
+        });
+
+        let Some(since) = since else {
+            let cursor = changed.last().map_or_else(Cursor::default, Cursor::at);
+            return Ok(ToolOutput::new()
+                .header("Changes")
+                .text("Starting point recorded; nothing is reported on the first call.")
+                .field("Next cursor", &cursor.to_string())
+                .hint("Pass the next cursor as cursor to see what changes from now on.")
+                .build_result());
+        };
+
+        // The filter's bound is inclusive; the cursor's own change, and those
+        // sharing its timestamp before it, were already reported
+        let changed: Vec<Document> = changed
+            .into_iter()
+            .filter(|doc| since.is_before(doc))
+            .collect();
+        let limit = self.limit.map_or(DEFAULT_LIMIT, |limit| limit as usize);
+        let more = changed.len() > limit;
+        let page = &changed[..changed.len().min(limit)];
+        let cursor = page.last().map_or_else(|| since.clone(), Cursor::at);
+
+        let output = ToolOutput::new().header("Changes");
+        if page.is_empty() {
+            return Ok(output
+                .text("No changes since the cursor.")
+                .field("Next cursor", &cursor.to_string())
+                .build_result());
+        }
+
+        let mut rows = Vec::with_capacity(page.len());
+        for doc in page {
+            let (change, details) = Self::describe(doc, since.updated_at, &mut db_service)?;
+            rows.push(vec![
+                change.to_string(),
+                doc.short_code.clone(),
+                doc.document_type.clone(),
+                doc.phase.clone(),
+                doc.title.clone(),
+                details,
+            ]);
+        }
+        let mut output = output
+            .text(&format!("{} document(s) changed", page.len()))
+            .table(
+                &["Change", "Code", "Type", "Phase", "Title", "Details"],
+                rows,
+            )
+            .field("Next cursor", &cursor.to_string());
+        if more {
+            output = output.hint("More changes follow; call again with the next cursor.");
+        }
+        Ok(output.build_result())
+    }
+
+    /// What happened to a document since the cursor, most telling first
+    fn describe(
+        doc: &Document,
+        since: f64,
+        db_service: &mut DatabaseService,
+    ) -> Result<(&'static str, String), CallToolError> {
+        if !doc.workspace.is_empty() {
+            return Ok(("synced", format!("from {}", doc.workspace)));
+        }
+        if doc.archived {
+            return Ok(("archived", String::new()));
+        }
+        // Creation and phase times are recorded to the second, so count the
+        // cursor's whole second as after it
+        let since = since.floor();
+        if doc.created_at >= since {
+            return Ok(("created", String::new()));
+        }
+
+        let history = db_service
+            .find_phase_history(&doc.short_code)
+            .map_err(CallToolError::new)?;
+        let (before, after): (Vec<_>, Vec<_>) = history
+            .into_iter()
+            .partition(|change| change.entered_at < since);
+        if after.is_empty() {
+            return Ok(("edited", String::new()));
+        }
+        let mut phases: Vec<String> = before
+            .last()
+            .map(|change| change.phase.clone())
+            .into_iter()
+            .collect();
+        phases.extend(after.into_iter().map(|change| change.phase));
+        Ok(("transitioned", phases.join(" → ")))
+    }
+}
+
+/// Position in the change feed: the last change reported, ordered by
+/// `updated_at` and then filepath. Written as `<updated_at>:<filepath>`.
+#[derive(Debug, Clone, Default, PartialEq)]
+struct Cursor {
+    updated_at: f64,
+    /// Empty for cursors without a filepath, which are before every
+    /// document changed at `updated_at`
+    filepath: String,
+}
+
+impl Cursor {
+    fn at(doc: &Document) -> Self {
+        Self {
+            updated_at: doc.updated_at,
+            filepath: doc.filepath.clone(),
+        }
+    }
+
+    /// Parse a cursor; a bare timestamp is accepted too
+    fn parse(cursor: &str) -> Option<Self> {
+        let (updated_at, filepath) = cursor.split_once(':').unwrap_or((cursor, ""));
+        let updated_at = updated_at.parse::<f64>().ok().filter(|t| t.is_finite())?;
+        Some(Self {
+            updated_at,
+            filepath: filepath.to_string(),
+        })
+    }
+
+    /// Whether a document changed after this position
+    fn is_before(&self, doc: &Document) -> bool {
+        doc.updated_at > self.updated_at
+            || (doc.updated_at == self.updated_at && doc.filepath.as_str() > self.filepath.as_str())
+    }
+}
+
+impl std::fmt::Display for Cursor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.updated_at, self.filepath)
+    }
+}
@@ -0,0 +1,191 @@
+use crate::formatting::{error_result, ToolOutput};
+use metis_core::{
+    application::services::{workspace::WorkspaceDetectionService, DatabaseService},
+    dal::database::models::{Document, DocumentEdge},
+    domain::documents::types::RelationshipKind,
+};
+use rust_mcp_sdk::{
+    macros::{mcp_tool, JsonSchema},
+    schema::{schema_utils::CallToolError, CallToolResult},
+};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+#[mcp_tool(
+    name = "query_relationships",
+    description = "Get every document related to one short code (e.g., PROJ-I-0001) in a single call: its parent, children, the documents blocking it and the ones it blocks, references in both directions, and linked ADRs (decisions that reference it, and for an ADR the decisions it supersedes or is superseded by). Each related document comes with its type, phase and title, so context can be gathered without reading documents one by one.",
+    idempotent_hint = true,
+    destructive_hint = false,
+    open_world_hint = false,
+    read_only_hint = true
+)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct QueryRelationshipsTool {
+    /// Path to the .metis folder (e.g., "/Users/me/my-project/.metis"). Must end with .metis
+    pub project_path: String,
+    /// Document short code (e.g., PROJ-I-0001) to identify the document
+    pub short_code: String,
+}
+
+/// The groups related documents are reported in, in output order
+const GROUPS: &[&str] = &[
+    "Parent",
+    "Children",
+    "Blocked by",
+    "Blocks",
+    "References",
+    "Referenced by",
+    "ADRs",
+];
+
+impl QueryRelationshipsTool {
+    pub async fn call_tool(&self) -> std::result::Result<CallToolResult, CallToolError> {
+        let metis_dir = Path::new(&self.project_path);
+
+        // Prepare workspace (validates, creates/updates database, syncs)
+        let detection_service = WorkspaceDetectionService::new();
+        let db = detection_service
+            .prepare_workspace(metis_dir)
+            .await
+            .map_err(|e| {
+                CallToolError::new(std::io::Error::new(
+                    std::io::ErrorKind::Other,
+                    e.to_string(),
+                ))
+            })?;
+        let mut db_service = DatabaseService::new(db.into_repository());
+        let db_error = |e: metis_core::MetisError| {
+            CallToolError::new(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!("Database error: {}", e),
+            ))
+        };
+
+        let Some(doc) = db_service
+            .find_by_short_code(&self.short_code)
+            .map_err(db_error)?
+        else {
+            return Ok(error_result(
+                "Document not found",
+                &format!("No document with short code {}", self.short_code),
+                Some("Use `list_documents` to see available documents."),
+            ));
+        };
+
+        let outgoing = db_service
+            .find_edges_from(&doc.short_code)
+            .map_err(db_error)?;
+        let incoming = db_service
+            .find_edges_to(&doc.short_code)
+            .map_err(db_error)?;
+
+        // Every edge touching the document, with the document at its other end
+        let mut related: Vec<Related> = Vec::new();
+        for (edge, outgoing) in outgoing
+            .iter()
+            .map(|edge| (edge, true))
+            .chain(incoming.iter().map(|edge| (edge, false)))
+        {
+            let code = if outgoing {
+                &edge.to_code
+            } else {
+                &edge.from_code
+            };
+            let other = db_service.find_by_short_code(code).map_err(db_error)?;
+            let other_is_adr = other
+                .as_ref()
+                .is_some_and(|other| other.document_type == "adr");
+            if let Some((group, relation)) = classify(edge, outgoing, other_is_adr) {
+                related.push(Related {
+                    group,
+                    relation,
+                    code: code.clone(),
+                    document: other,
+                });
+            }
+        }
+        related.sort_by(|a, b| a.code.cmp(&b.code));
+
+        let mut output = ToolOutput::new()
+            .header(&format!("Relationships: {} {}", doc.short_code, doc.title))
+            .field("Type", &doc.document_type)
+            .field("Phase", &doc.phase);
+        if related.is_empty() {
+            return Ok(output.text("No related documents.").build_result());
+        }
+
+        for group in GROUPS {
+            let rows: Vec<Vec<String>> = related
+                .iter()
+                .filter(|entry| entry.group == *group)
+                .map(Related::row)
+                .collect();
+            if rows.is_empty() {
+                continue;
+            }
+            let headers: &[&str] = if *group == "ADRs" {
+                &["Relation", "Code", "Type", "Phase", "Title"]
+            } else {
+                &["Code", "Type", "Phase", "Title"]
+            };
+            output = output
+                .subheader(&format!("{} ({})", group, rows.len()))
+                .table(headers, rows);
+        }
+
+        Ok(output.build_result())
+    }
+}
+
+/// A document at the other end of an edge
+struct Related {
+    group: &'static str,
+    relation: Option<&'static str>,
+    code: String,
+    /// None when the edge points at a document that no longer exists
+    document: Option<Document>,
+}
+
+impl Related {
+    fn row(&self) -> Vec<String> {
+        let mut row: Vec<String> = self.relation.iter().map(|r| r.to_string()).collect();
+        match &self.document {
+            Some(doc) => row.extend([
+                doc.short_code.clone(),
+                doc.document_type.clone(),
+                doc.phase.clone(),
+                doc.title.clone(),
+            ]),
+            None => row.extend([
+                self.code.clone(),
+                "-".to_string(),
+                "-".to_string(),
+                "(missing)".to_string(),
+            ]),
+        }
+        row
+    }
+}
+
+/// The group an edge is reported in, and for ADR links how the document
+/// relates to the ADR.
+/// `outgoing` is true when the edge starts at the queried document.
+fn classify(
+    edge: &DocumentEdge,
+    outgoing: bool,
+    other_is_adr: bool,
+) -> Option<(&'static str, Option<&'static str>)> {
+    let kind = edge.kind.parse::<RelationshipKind>().ok()?;
+    Some(match (kind, outgoing) {
+        (RelationshipKind::Parent, true) => ("Parent", None),
+        (RelationshipKind::Parent, false) => ("Children", None),
+        (RelationshipKind::Blocks, true) => ("Blocks", None),
+        (RelationshipKind::Blocks, false) => ("Blocked by", None),
+        (RelationshipKind::Supersedes, true) => ("ADRs", Some("supersedes")),
+        (RelationshipKind::Supersedes, false) => ("ADRs", Some("superseded by")),
+        (RelationshipKind::References, true) if other_is_adr => ("ADRs", Some("references")),
+        (RelationshipKind::References, false) if other_is_adr => ("ADRs", Some("referenced by")),
+        (RelationshipKind::References, true) => ("References", None),
+        (RelationshipKind::References, false) => ("Referenced by", None),
+    })
+}
@@ -0,0 +1,88 @@
+use crate::formatting::{error_result, ToolOutput};
+use crate::project_registry::ProjectRegistry;
+use metis_core::{
+    application::services::workspace::WorkspaceDetectionService, domain::configuration::ConfigFile,
+};
+use rust_mcp_sdk::{
+    macros::{mcp_tool, JsonSchema},
+    schema::{schema_utils::CallToolError, CallToolResult},
+};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::sync::Arc;
+
+#[mcp_tool(
+    name = "register_project",
+    description = "Register a Metis workspace with this server so several projects can be managed in one session. Once any project is registered, every tool's project_path must be a registered workspace, given either by its path or by its name (e.g., project_path: \"api\"); other paths are refused, which keeps changes from landing in the wrong project. The name defaults to the directory containing .metis. Registering a workspace again renames it. Use list_projects to see what is registered. Registrations only apply to this client's session.",
+    idempotent_hint = true,
+    destructive_hint = false,
+    open_world_hint = false,
+    read_only_hint = false
+)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct RegisterProjectTool {
+    /// Path to the .metis folder (e.g., "/Users/me/my-project/.metis"). Must end with .metis
+    pub project_path: String,
+    /// Name to refer to the project by (default: the directory containing .metis)
+    pub name: Option<String>,
+}
+
+impl RegisterProjectTool {
+    pub async fn call_tool_with_registry(
+        &self,
+        registry: Arc<ProjectRegistry>,
+    ) -> std::result::Result<CallToolResult, CallToolError> {
+        let metis_dir = Path::new(&self.project_path);
+
+        // Prepare workspace (validates, creates/updates database, syncs)
+        let detection_service = WorkspaceDetectionService::new();
+        if let Err(e) = detection_service.prepare_workspace(metis_dir).await {
+            return Ok(error_result(
+                "Not a Metis workspace",
+                &e.to_string(),
+                Some("Pass the path of an existing .metis folder, or create one with `initialize_project`."),
+            ));
+        }
+
+        let name = match self.name.as_deref().map(str::trim) {
+            Some(name) if !name.is_empty() => name.to_string(),
+            _ => default_name(metis_dir),
+        };
+        if name.contains(['/', '\\']) || name.ends_with(".metis") {
+            return Ok(error_result(
+                "Invalid name",
+                &format!("'{}' looks like a path", name),
+                Some("Use a short name without slashes, e.g. \"api\"."),
+            ));
+        }
+
+        let project = registry.register(&name, metis_dir);
+        let prefix = ConfigFile::load(project.metis_dir.join("config.toml"))
+            .map(|config| config.prefix().to_string())
+            .unwrap_or_else(|_| "-".to_string());
+
+        Ok(ToolOutput::new()
+            .header("Project Registered")
+            .field("Name", &project.name)
+            .field("Path", &project.metis_dir.display().to_string())
+            .field("Prefix", &prefix)
+            .field("Registered projects", &registry.list().len().to_string())
+            .hint(&format!(
+                "Pass project_path: \"{}\" (or the path) to work in this project.",
+                project.name
+            ))
+            .build_result())
+    }
+}
+
+/// The directory containing `.metis`, e.g. `api` for `/src/api/.metis`
+fn default_name(metis_dir: &Path) -> String {
+    let metis_dir = metis_dir
+        .canonicalize()
+        .unwrap_or_else(|_| metis_dir.to_path_buf());
+    metis_dir
+        .parent()
+        .and_then(|parent| parent.file_name())
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_else(|| "metis".to_string())
+}
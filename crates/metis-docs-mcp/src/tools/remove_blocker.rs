@@ -0,0 +1,78 @@
+use crate::formatting::{error_result, ToolOutput};
+use metis_core::{
+    application::services::{
+        workspace::{LinkKind, LinkService, WorkspaceDetectionService},
+        DatabaseService,
+    },
+    MetisError,
+};
+use rust_mcp_sdk::{
+    macros::{mcp_tool, JsonSchema},
+    schema::{schema_utils::CallToolError, CallToolResult},
+};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+#[mcp_tool(
+    name = "remove_blocker",
+    description = "Remove a document from another's blocked_by list (e.g., PROJ-T-0002 no longer blocked by PROJ-T-0001). The blocker doesn't have to exist any more, so links to deleted documents can be cleaned up.",
+    idempotent_hint = true,
+    destructive_hint = false,
+    open_world_hint = false,
+    read_only_hint = false
+)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct RemoveBlockerTool {
+    /// Path to the .metis folder (e.g., "/Users/me/my-project/.metis"). Must end with .metis
+    pub project_path: String,
+    /// Short code (e.g., PROJ-T-0002) of the blocked document
+    pub short_code: String,
+    /// Short code (e.g., PROJ-T-0001) of the blocker to remove
+    pub blocked_by: String,
+}
+
+impl RemoveBlockerTool {
+    pub async fn call_tool(&self) -> std::result::Result<CallToolResult, CallToolError> {
+        let metis_dir = Path::new(&self.project_path);
+
+        // Prepare workspace (validates, creates/updates database, syncs)
+        let detection_service = WorkspaceDetectionService::new();
+        let db = detection_service
+            .prepare_workspace(metis_dir)
+            .await
+            .map_err(|e| {
+                CallToolError::new(std::io::Error::new(
+                    std::io::ErrorKind::Other,
+                    e.to_string(),
+                ))
+            })?;
+        let mut db_service = DatabaseService::new(db.into_repository());
+
+        let removed = match LinkService::new(metis_dir).unlink(
+            &self.short_code,
+            &self.blocked_by,
+            LinkKind::BlockedBy,
+            &mut db_service,
+        ) {
+            Ok(removed) => removed,
+            Err(MetisError::NotFound(message)) => {
+                return Ok(error_result(
+                    "Document not found",
+                    &message,
+                    Some("Use `list_documents` to see available documents."),
+                ))
+            }
+            Err(e) => return Err(CallToolError::new(e)),
+        };
+
+        let message = if removed {
+            format!(
+                "✓ {} is no longer blocked by {}",
+                self.short_code, self.blocked_by
+            )
+        } else {
+            format!("{} is not blocked by {}", self.short_code, self.blocked_by)
+        };
+        Ok(ToolOutput::new().text(&message).build_result())
+    }
+}
@@ -0,0 +1,93 @@
+use crate::formatting::{error_result, ToolOutput};
+use crate::tools::add_tags::normalize_tags;
+use metis_core::{
+    application::services::{
+        workspace::{TagService, WorkspaceDetectionService},
+        DatabaseService,
+    },
+    MetisError,
+};
+use rust_mcp_sdk::{
+    macros::{mcp_tool, JsonSchema},
+    schema::{schema_utils::CallToolError, CallToolResult},
+};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+#[mcp_tool(
+    name = "remove_tags",
+    description = "Remove tags from a document. Tags match case-insensitively, with or without the leading '#'. Only the tags list in the frontmatter is rewritten. Phase tags (#phase/...) can't be removed: they drive the boards and change with transition_phase.",
+    idempotent_hint = true,
+    destructive_hint = false,
+    open_world_hint = false,
+    read_only_hint = false
+)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct RemoveTagsTool {
+    /// Path to the .metis folder (e.g., "/Users/me/my-project/.metis"). Must end with .metis
+    pub project_path: String,
+    /// Short code of the document (e.g., PROJ-T-0001)
+    pub short_code: String,
+    /// Tags to remove, with or without the leading '#'
+    pub tags: Vec<String>,
+}
+
+impl RemoveTagsTool {
+    pub async fn call_tool(&self) -> std::result::Result<CallToolResult, CallToolError> {
+        let metis_dir = Path::new(&self.project_path);
+
+        // Check every tag first so a bad one doesn't leave a partial update
+        let tags = match normalize_tags(&self.tags) {
+            Ok(tags) => tags,
+            Err(result) => return Ok(result),
+        };
+
+        // Prepare workspace (validates, creates/updates database, syncs)
+        let detection_service = WorkspaceDetectionService::new();
+        let db = detection_service
+            .prepare_workspace(metis_dir)
+            .await
+            .map_err(|e| {
+                CallToolError::new(std::io::Error::new(
+                    std::io::ErrorKind::Other,
+                    e.to_string(),
+                ))
+            })?;
+        let mut db_service = DatabaseService::new(db.into_repository());
+
+        let service = TagService::new(metis_dir);
+        let mut removed = Vec::new();
+        let mut missing = Vec::new();
+        for tag in tags {
+            match service.remove_tag(&self.short_code, &tag, &mut db_service) {
+                Ok(true) => removed.push(tag),
+                Ok(false) => missing.push(tag),
+                Err(MetisError::NotFound(message)) => {
+                    return Ok(error_result(
+                        "Document not found",
+                        &message,
+                        Some("Use `list_documents` to see available documents."),
+                    ))
+                }
+                Err(e) => return Err(CallToolError::new(e)),
+            }
+        }
+
+        let mut output = ToolOutput::new();
+        if !removed.is_empty() {
+            output = output.text(&format!(
+                "✓ Removed {} from {}",
+                removed.join(", "),
+                self.short_code
+            ));
+        }
+        if !missing.is_empty() {
+            output = output.text(&format!(
+                "{} doesn't have {}",
+                self.short_code,
+                missing.join(", ")
+            ));
+        }
+        Ok(output.build_result())
+    }
+}
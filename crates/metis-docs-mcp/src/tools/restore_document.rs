@@ -0,0 +1,65 @@
+use crate::formatting::ToolOutput;
+use metis_core::application::services::workspace::{ArchiveService, WorkspaceDetectionService};
+use rust_mcp_sdk::{
+    macros::{mcp_tool, JsonSchema},
+    schema::{schema_utils::CallToolError, CallToolResult},
+};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+#[mcp_tool(
+    name = "restore_document",
+    description = "Restore an archived document using its short code (e.g., PROJ-I-0001), the inverse of archive_document. The document moves back to where it was archived from; an initiative comes back with everything archived with it. A document whose parent is still archived can't be restored until the parent is.",
+    idempotent_hint = false,
+    destructive_hint = false,
+    open_world_hint = false,
+    read_only_hint = false
+)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct RestoreDocumentTool {
+    /// Path to the .metis folder (e.g., "/Users/me/my-project/.metis"). Must end with .metis
+    pub project_path: String,
+    /// Short code (e.g., PROJ-I-0001) of the archived document to restore
+    pub short_code: String,
+}
+
+impl RestoreDocumentTool {
+    pub async fn call_tool(&self) -> std::result::Result<CallToolResult, CallToolError> {
+        let metis_dir = Path::new(&self.project_path);
+
+        // Prepare workspace (validates, creates/updates database, syncs)
+        let detection_service = WorkspaceDetectionService::new();
+        let db = detection_service
+            .prepare_workspace(metis_dir)
+            .await
+            .map_err(|e| {
+                CallToolError::new(std::io::Error::new(
+                    std::io::ErrorKind::Other,
+                    e.to_string(),
+                ))
+            })?;
+
+        let mut db_service =
+            metis_core::application::services::DatabaseService::new(db.into_repository());
+        let restore_result = ArchiveService::new(metis_dir)
+            .unarchive_document_by_short_code(&self.short_code, &mut db_service)
+            .await
+            .map_err(|e| {
+                CallToolError::new(std::io::Error::new(
+                    std::io::ErrorKind::Other,
+                    format!("Failed to restore document: {}", e),
+                ))
+            })?;
+
+        let mut output = ToolOutput::new().text(&format!("✓ {} restored", self.short_code));
+        for restored in &restore_result.restored_documents {
+            let path = restored
+                .original_path
+                .strip_prefix(metis_dir)
+                .unwrap_or(&restored.original_path);
+            output = output.field("Restored to", &path.display().to_string());
+        }
+
+        Ok(output.build_result())
+    }
+}
@@ -1,5 +1,8 @@
-use crate::formatting::ToolOutput;
-use metis_core::{application::services::workspace::WorkspaceDetectionService, Application};
+use crate::formatting::{error_result, ToolOutput};
+use metis_core::{
+    application::services::{workspace::WorkspaceDetectionService, QueryService},
+    dal::database::{parse_when, DocumentFilter, PropertyFilter},
+};
 use rust_mcp_sdk::{
     macros::{mcp_tool, JsonSchema},
     schema::{schema_utils::CallToolError, CallToolResult},
@@ -7,9 +10,12 @@ use rust_mcp_sdk::{
 use serde::{Deserialize, Serialize};
 use std::path::Path;
 
+/// Results per page when no limit is given
+const DEFAULT_LIMIT: usize = 20;
+
 #[mcp_tool(
     name = "search_documents",
-    description = "Search documents by content with optional filtering. Returns matching documents with their unique short codes (format: PREFIX-TYPE-NNNN).",
+    description = "Full-text search over document titles and content, best match first, each result with a snippet showing the matched terms in **bold**. Narrow the search with document_type, phase, tag, assignee and updated_after / updated_before (a date like 2025-01-31 or an age like 30d), and page through results with limit plus the cursor returned for the next page. Returns unique short codes (format: PREFIX-TYPE-NNNN).",
     idempotent_hint = true,
     destructive_hint = false,
     open_world_hint = false,
@@ -19,15 +25,36 @@ use std::path::Path;
 pub struct SearchDocumentsTool {
     /// Path to the .metis folder (e.g., "/Users/me/my-project/.metis"). Must end with .metis
     pub project_path: String,
-    /// Search query to match against document content
+    /// Search query to match against document titles and content
     pub query: String,
     /// Filter by document type (vision, initiative, task, adr, specification)
     pub document_type: Option<String>,
-    /// Maximum number of results to return
+    /// Maximum number of results to return (defaults to 20)
     pub limit: Option<u32>,
     /// Include archived documents in results (defaults to false)
     #[serde(default)]
     pub include_archived: Option<bool>,
+    /// Only documents in this phase (e.g., active)
+    #[serde(default)]
+    pub phase: Option<String>,
+    /// Only documents with this tag (e.g., #sprint-14; the # is optional)
+    #[serde(default)]
+    pub tag: Option<String>,
+    /// Only documents assigned to this person
+    #[serde(default)]
+    pub assignee: Option<String>,
+    /// Only documents updated on or after this date (YYYY-MM-DD) or within this age (e.g., 30d, 2w)
+    #[serde(default)]
+    pub updated_after: Option<String>,
+    /// Only documents last updated before this date (YYYY-MM-DD) or age (e.g., 90d)
+    #[serde(default)]
+    pub updated_before: Option<String>,
+    /// Number of results to skip before the first returned (defaults to 0)
+    #[serde(default)]
+    pub offset: Option<u32>,
+    /// Cursor from the previous page's "Next cursor" to continue from; can't be combined with offset
+    #[serde(default)]
+    pub cursor: Option<String>,
 }
 
 impl SearchDocumentsTool {
@@ -51,6 +78,21 @@ impl SearchDocumentsTool {
     pub async fn call_tool(&self) -> std::result::Result<CallToolResult, CallToolError> {
         let metis_dir = Path::new(&self.project_path);
 
+        let offset = self.start()?;
+        let limit = self.limit.map_or(DEFAULT_LIMIT, |limit| limit as usize);
+        let mut filter = match self.filter() {
+            Ok(filter) => filter,
+            Err(e) => {
+                return Ok(error_result(
+                    "Invalid date",
+                    &e,
+                    Some("Use a date like 2025-01-31 or an age like 30d or 2w."),
+                ))
+            }
+        };
+        // One extra result tells whether another page follows
+        filter = filter.page(limit + 1, offset);
+
         // Prepare workspace (validates, creates/updates database, syncs)
         let detection_service = WorkspaceDetectionService::new();
         let db = detection_service
@@ -63,48 +105,21 @@ impl SearchDocumentsTool {
                 ))
             })?;
 
-        let mut app = Application::new(db);
-
-        // Sanitize query for FTS search - escape special characters and handle edge cases
+        // Ranked full-text search, filtered and paged in SQL
         let sanitized_query = self.sanitize_search_query(&self.query);
-        let include_archived = self.include_archived.unwrap_or(false);
-
-        // Perform full-text search (respecting include_archived flag, defaults to false)
-        let results = app
-            .with_database(|db_service| {
-                if include_archived {
-                    db_service.search_documents(&sanitized_query)
-                } else {
-                    db_service.search_documents_unarchived(&sanitized_query)
-                }
-            })
+        let mut hits = QueryService::new(db.into_repository())
+            .search(&sanitized_query, &filter)
             .map_err(|e| {
                 CallToolError::new(std::io::Error::new(
                     std::io::ErrorKind::Other,
                     format!("Search failed: {}. Try using simpler search terms without special characters.", e),
                 ))
             })?;
-
-        // Apply document type filter if specified
-        let filtered_results: Vec<_> = if let Some(doc_type) = &self.document_type {
-            results
-                .into_iter()
-                .filter(|doc| doc.document_type == *doc_type)
-                .collect()
-        } else {
-            results
-        };
-
-        // Apply limit if specified
-        let limited_results: Vec<_> = if let Some(limit) = self.limit {
-            filtered_results.into_iter().take(limit as usize).collect()
-        } else {
-            filtered_results
-        };
+        let more = hits.len() > limit;
+        hits.truncate(limit);
 
         // Build formatted output
-        let result_count = limited_results.len();
-
+        let result_count = hits.len();
         let mut output = ToolOutput::new()
             .header(&format!("Search Results for \"{}\"", self.query))
             .text(&format!(
@@ -114,20 +129,97 @@ impl SearchDocumentsTool {
             ));
 
         if result_count > 0 {
-            let rows: Vec<Vec<String>> = limited_results
+            if offset > 0 || more {
+                output = output.text(&format!(
+                    "Showing results {}-{}",
+                    offset + 1,
+                    offset + result_count
+                ));
+            }
+            let rows: Vec<Vec<String>> = hits
                 .iter()
-                .map(|doc| {
+                .map(|hit| {
                     vec![
-                        doc.short_code.clone(),
-                        doc.title.clone(),
-                        doc.document_type.clone(),
+                        hit.document.short_code.clone(),
+                        hit.document.title.clone(),
+                        hit.document.document_type.clone(),
+                        hit.document.phase.clone(),
+                        single_line(&hit.snippet),
                     ]
                 })
                 .collect();
 
-            output = output.table(&["Code", "Title", "Type"], rows);
+            output = output.table(&["Code", "Title", "Type", "Phase", "Snippet"], rows);
+        }
+
+        if more {
+            output = output
+                .field("Next cursor", &(offset + result_count).to_string())
+                .hint("Pass the next cursor as cursor to see the next page.");
         }
 
         Ok(output.build_result())
     }
+
+    /// The field filters, checked before touching the workspace
+    fn filter(&self) -> Result<DocumentFilter, String> {
+        let now = chrono::Utc::now();
+        let updated_after = self
+            .updated_after
+            .as_deref()
+            .map(|when| parse_when(when.trim(), now))
+            .transpose()?;
+        let updated_before = self
+            .updated_before
+            .as_deref()
+            .map(|when| parse_when(when.trim(), now))
+            .transpose()?;
+
+        let mut filter = DocumentFilter::new()
+            .include_archived(self.include_archived.unwrap_or(false))
+            .updated_between(updated_after, updated_before);
+        filter.document_type = self.document_type.as_ref().map(|t| t.to_lowercase());
+        filter.phase = self.phase.as_ref().map(|phase| phase.to_lowercase());
+        if let Some(tag) = &self.tag {
+            let tag = if tag.starts_with('#') {
+                tag.clone()
+            } else {
+                format!("#{}", tag)
+            };
+            filter = filter.tag(tag);
+        }
+        if let Some(assignee) = &self.assignee {
+            filter = filter.property(PropertyFilter::Equals {
+                key: "assignee".to_string(),
+                value: assignee.clone(),
+            });
+        }
+        Ok(filter)
+    }
+
+    /// Where the page starts: the cursor from the previous page, or offset
+    fn start(&self) -> Result<usize, CallToolError> {
+        let invalid = |message: String| {
+            CallToolError::new(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                message,
+            ))
+        };
+        match (&self.cursor, self.offset) {
+            (Some(_), Some(_)) => Err(invalid("Use either cursor or offset, not both".to_string())),
+            (Some(cursor), None) => cursor
+                .parse()
+                .map_err(|_| invalid(format!("Invalid cursor: {}", cursor))),
+            (None, offset) => Ok(offset.unwrap_or(0) as usize),
+        }
+    }
+}
+
+/// Snippets span lines; a table cell can't
+fn single_line(snippet: &str) -> String {
+    snippet
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .replace('|', "\\|")
 }
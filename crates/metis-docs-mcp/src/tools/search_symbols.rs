@@ -0,0 +1,161 @@
+//! `search_symbols` MCP tool — query the symbols found by `index_code`.
+//!
+//! Reads the symbol cache `index_code` leaves in `.metis/`, so lookups don't
+//! re-parse the source tree.
+
+use crate::formatting::{error_result, ToolOutput};
+use metis_code_index::symbols::{compact_signature, Symbol, SymbolKind};
+use metis_code_index::SymbolCache;
+use rust_mcp_sdk::{
+    macros::{mcp_tool, JsonSchema},
+    schema::{schema_utils::CallToolError, CallToolResult},
+};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+const DEFAULT_LIMIT: usize = 50;
+
+#[mcp_tool(
+    name = "search_symbols",
+    description = "Find functions, types and other symbols in the project's source code, using the index built by index_code. Filter by name (case-insensitive substring, exact matches first), kind (module, class, struct, interface, enum, function, method, variable, type, macro) and file (path substring, e.g. 'src/auth'). Each match gives its file:line location and signature, ready to cite in a task or ADR. Run index_code first, and again after the code changes.",
+    idempotent_hint = true,
+    destructive_hint = false,
+    open_world_hint = false,
+    read_only_hint = true
+)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct SearchSymbolsTool {
+    /// Path to the .metis folder (e.g., "/Users/me/my-project/.metis"). Must end with .metis
+    pub project_path: String,
+    /// Part of the symbol name to look for (case-insensitive)
+    pub name: Option<String>,
+    /// Symbol kind (e.g., function, struct, method)
+    pub kind: Option<String>,
+    /// Part of the file path, relative to the project root (e.g., src/auth)
+    pub file: Option<String>,
+    /// Maximum number of symbols to return (default: 50)
+    pub limit: Option<u32>,
+}
+
+impl SearchSymbolsTool {
+    pub async fn call_tool(&self) -> std::result::Result<CallToolResult, CallToolError> {
+        let metis_dir = Path::new(&self.project_path);
+
+        let kind = match self.kind.as_deref().map(str::parse::<SymbolKind>) {
+            None => None,
+            Some(Ok(kind)) => Some(kind),
+            Some(Err(e)) => {
+                let kinds: Vec<&str> = SymbolKind::ALL.iter().map(SymbolKind::as_str).collect();
+                return Ok(error_result(
+                    "Invalid kind",
+                    &e,
+                    Some(&format!("Use one of: {}", kinds.join(", "))),
+                ));
+            }
+        };
+        let name = non_empty(&self.name).map(str::to_lowercase);
+        let file = non_empty(&self.file);
+        if name.is_none() && kind.is_none() && file.is_none() {
+            return Ok(error_result(
+                "Nothing to search for",
+                "No filter was given.",
+                Some("Set at least one of name, kind or file."),
+            ));
+        }
+
+        let cache_path = metis_dir.join("code-index-symbols.json");
+        let cache = SymbolCache::load(&cache_path).map_err(|e| {
+            CallToolError::new(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!("Failed to load symbol cache: {}", e),
+            ))
+        })?;
+        if cache.files.is_empty() {
+            return Ok(error_result(
+                "No code index",
+                "No symbols have been indexed for this project.",
+                Some("Run `index_code` to index the source tree, then search again."),
+            ));
+        }
+
+        let mut matches: Vec<(u8, &Symbol)> = cache
+            .files
+            .iter()
+            .filter(|(path, _)| match file {
+                Some(file) => path.contains(file),
+                None => true,
+            })
+            .flat_map(|(_, symbols)| symbols)
+            .filter(|symbol| kind.is_none() || kind == Some(symbol.kind))
+            .filter_map(|symbol| match &name {
+                Some(name) => name_rank(&symbol.name, name).map(|rank| (rank, symbol)),
+                None => Some((0, symbol)),
+            })
+            .collect();
+        matches.sort_by(|(rank_a, a), (rank_b, b)| {
+            rank_a
+                .cmp(rank_b)
+                .then_with(|| a.file_path.cmp(&b.file_path))
+                .then_with(|| a.start_line.cmp(&b.start_line))
+        });
+
+        let total = matches.len();
+        let limit = self.limit.map_or(DEFAULT_LIMIT, |limit| limit as usize);
+        let output = ToolOutput::new().header("Symbols");
+        if total == 0 {
+            let hint =
+                "Loosen the filters, or run `index_code` if the code changed since it was indexed.";
+            return Ok(output.text("No symbols match.").hint(hint).build_result());
+        }
+
+        let rows = matches
+            .iter()
+            .take(limit)
+            .map(|(_, symbol)| {
+                vec![
+                    symbol.name.clone(),
+                    symbol.kind.as_str().to_string(),
+                    format!("{}:{}", symbol.file_path, symbol.start_line),
+                    symbol
+                        .signature
+                        .as_deref()
+                        // Closures and unions would otherwise split the cell
+                        .map(|signature| compact_signature(signature, 80).replace('|', "\\|"))
+                        .unwrap_or_default(),
+                ]
+            })
+            .collect();
+        let mut output = output
+            .text(&format!("Found {} symbol(s)", total))
+            .table(&["Name", "Kind", "Location", "Signature"], rows);
+        if total > limit {
+            output = output.hint(&format!(
+                "Showing the first {} of {}. Narrow the search or raise the limit.",
+                limit, total
+            ));
+        }
+        Ok(output.build_result())
+    }
+}
+
+fn non_empty(value: &Option<String>) -> Option<&str> {
+    value
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+}
+
+/// How well a symbol name matches the (lowercased) query: exact matches
+/// first, then prefixes, then anywhere in the name
+fn name_rank(symbol_name: &str, query: &str) -> Option<u8> {
+    let symbol_name = symbol_name.to_lowercase();
+    if symbol_name == query {
+        Some(0)
+    } else if symbol_name.starts_with(query) {
+        Some(1)
+    } else if symbol_name.contains(query) {
+        Some(2)
+    } else {
+        None
+    }
+}
@@ -0,0 +1,170 @@
+use crate::formatting::{error_result, ToolOutput};
+use metis_core::{
+    application::services::{
+        workspace::{Change, RemoteSyncService, SyncPlan, WorkspaceDetectionService},
+        DatabaseService,
+    },
+    domain::configuration::ConfigFile,
+    MetisError,
+};
+use rust_mcp_sdk::{
+    macros::{mcp_tool, JsonSchema},
+    schema::{schema_utils::CallToolError, CallToolResult},
+};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+#[mcp_tool(
+    name = "sync_remote",
+    description = "Sync the workspace with the git remote it shares documents through: fetch the remote in [sync] of config.toml (or the branch's upstream), pull the documents changed there, then commit and push the ones changed locally. Nothing is changed when a document changed on both sides; the conflicts are listed to resolve with git first. Use dry_run=true to only list what would be pulled, pushed or conflict, e.g. before starting work on shared documents; dry runs are allowed on read-only servers.",
+    idempotent_hint = false,
+    destructive_hint = false,
+    open_world_hint = true,
+    read_only_hint = false
+)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct SyncRemoteTool {
+    /// Path to the .metis folder (e.g., "/Users/me/my-project/.metis"). Must end with .metis
+    pub project_path: String,
+    /// Only report what would be pulled, pushed or conflict (default: false)
+    pub dry_run: Option<bool>,
+}
+
+impl SyncRemoteTool {
+    pub async fn call_tool(&self) -> std::result::Result<CallToolResult, CallToolError> {
+        let metis_dir = Path::new(&self.project_path);
+        let dry_run = self.dry_run.unwrap_or(false);
+
+        let sync = ConfigFile::load(metis_dir.join("config.toml"))
+            .map(|config| config.sync().clone())
+            .unwrap_or_default();
+        // git blocks, so keep it off the async runtime
+        let workspace_dir = metis_dir.to_path_buf();
+        let synced = tokio::task::spawn_blocking(move || {
+            let remote = RemoteSyncService::new(&workspace_dir);
+            if dry_run {
+                remote.plan(&sync, |_| {})
+            } else {
+                remote.apply(&sync, |_| {})
+            }
+        })
+        .await
+        .map_err(CallToolError::new)?;
+        let (plan, remote) = match synced {
+            Ok(synced) => synced,
+            Err(MetisError::ValidationFailed { message }) => {
+                return Ok(error_result("Remote sync unavailable", &message, None))
+            }
+            Err(e) => return Ok(error_result("Remote sync failed", &e.to_string(), None)),
+        };
+
+        // Prepare workspace (validates, creates/updates database, syncs),
+        // importing whatever was pulled
+        let detection_service = WorkspaceDetectionService::new();
+        let db = detection_service
+            .prepare_workspace(metis_dir)
+            .await
+            .map_err(|e| {
+                CallToolError::new(std::io::Error::new(
+                    std::io::ErrorKind::Other,
+                    e.to_string(),
+                ))
+            })?;
+        let mut db_service = DatabaseService::new(db.into_repository());
+
+        let mut output = ToolOutput::new()
+            .header("Remote Sync")
+            .field("Remote", &remote);
+        if plan.is_empty() {
+            return Ok(output.text("✓ Already in sync").build_result());
+        }
+        output = output
+            .field("Pull", &plan.pull.len().to_string())
+            .field("Push", &plan.push.len().to_string())
+            .field("Conflicts", &plan.conflicts.len().to_string());
+        output = Self::plan_sections(output, &plan, &mut db_service);
+
+        if dry_run {
+            return Ok(output.text("Dry run: nothing was changed.").build_result());
+        }
+        if !plan.conflicts.is_empty() {
+            return Ok(error_result(
+                "Sync not applied",
+                &format!(
+                    "Some documents changed both locally and on the remote, so nothing was pulled or pushed.\n\n{}",
+                    output.build()
+                ),
+                Some("Resolve the conflicting documents with git, then run sync_remote again."),
+            ));
+        }
+        Ok(output
+            .text(&format!(
+                "✓ Pulled {} and pushed {} document(s)",
+                plan.pull.len(),
+                plan.push.len()
+            ))
+            .build_result())
+    }
+
+    /// A table per side of the plan, with the short code of each document
+    /// the workspace knows
+    fn plan_sections(
+        mut output: ToolOutput,
+        plan: &SyncPlan,
+        db_service: &mut DatabaseService,
+    ) -> ToolOutput {
+        let mut short_code = |path: &str| {
+            db_service
+                .find_by_filepath(path)
+                .ok()
+                .flatten()
+                .map_or_else(|| "-".to_string(), |doc| doc.short_code)
+        };
+
+        for (title, changes) in [("Pull", &plan.pull), ("Push", &plan.push)] {
+            if changes.is_empty() {
+                continue;
+            }
+            let rows = changes
+                .iter()
+                .map(|(path, change)| {
+                    vec![
+                        change_label(*change).to_string(),
+                        short_code(path),
+                        path.clone(),
+                    ]
+                })
+                .collect();
+            output = output
+                .subheader(&format!("{} ({})", title, changes.len()))
+                .table(&["Change", "Code", "Path"], rows);
+        }
+        if !plan.conflicts.is_empty() {
+            let rows = plan
+                .conflicts
+                .iter()
+                .map(|(path, (theirs, ours))| {
+                    vec![
+                        change_label(*theirs).to_string(),
+                        change_label(*ours).to_string(),
+                        short_code(path),
+                        path.clone(),
+                    ]
+                })
+                .collect();
+            output = output
+                .subheader(&format!("Conflicts ({})", plan.conflicts.len()))
+                .table(&["Remote", "Local", "Code", "Path"], rows)
+                .warning("These documents changed both locally and on the remote.");
+        }
+        output
+    }
+}
+
+fn change_label(change: Change) -> &'static str {
+    match change {
+        Change::Added => "added",
+        Change::Modified => "modified",
+        Change::Deleted => "deleted",
+    }
+}
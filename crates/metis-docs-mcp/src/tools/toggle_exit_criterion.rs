@@ -0,0 +1,133 @@
+use crate::formatting::{error_result, ToolOutput};
+use metis_core::{
+    application::services::{
+        workspace::{CriteriaService, CriteriaStatus, WorkspaceDetectionService},
+        DatabaseService,
+    },
+    MetisError,
+};
+use rust_mcp_sdk::{
+    macros::{mcp_tool, JsonSchema},
+    schema::{schema_utils::CallToolError, CallToolResult},
+};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+#[mcp_tool(
+    name = "toggle_exit_criterion",
+    description = "List a document's exit/acceptance criteria with their numbers, or check off a single one by number (e.g., PROJ-T-0001, number 2). Without number the criteria are only listed. With number the criterion is toggled, or set to checked when given. exit_criteria_met is kept in step and becomes true once every criterion is checked. Use this instead of rewriting the criteria section with edit_document.",
+    idempotent_hint = false,
+    destructive_hint = false,
+    open_world_hint = false,
+    read_only_hint = false
+)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct ToggleExitCriterionTool {
+    /// Path to the .metis folder (e.g., "/Users/me/my-project/.metis"). Must end with .metis
+    pub project_path: String,
+    /// Document short code (e.g., PROJ-T-0001) to identify the document
+    pub short_code: String,
+    /// Criterion to change, starting at 1 (omit to list the criteria)
+    pub number: Option<u32>,
+    /// Check (true) or uncheck (false) the criterion; toggles it when omitted
+    pub checked: Option<bool>,
+}
+
+impl ToggleExitCriterionTool {
+    pub async fn call_tool(&self) -> std::result::Result<CallToolResult, CallToolError> {
+        let metis_dir = Path::new(&self.project_path);
+
+        // Prepare workspace (validates, creates/updates database, syncs)
+        let detection_service = WorkspaceDetectionService::new();
+        let db = detection_service
+            .prepare_workspace(metis_dir)
+            .await
+            .map_err(|e| {
+                CallToolError::new(std::io::Error::new(
+                    std::io::ErrorKind::Other,
+                    e.to_string(),
+                ))
+            })?;
+        let mut db_service = DatabaseService::new(db.into_repository());
+
+        let service = CriteriaService::new(metis_dir);
+        let status = match service.status(&self.short_code, &mut db_service) {
+            Ok(status) => status,
+            Err(MetisError::NotFound(message)) => {
+                return Ok(error_result(
+                    "Document not found",
+                    &message,
+                    Some("Use `list_documents` to see available documents."),
+                ))
+            }
+            Err(e) => return Err(CallToolError::new(e)),
+        };
+
+        let Some(number) = self.number else {
+            return Ok(Self::criteria_output(&status, None));
+        };
+        let number = number as usize;
+        let Some(current) = status
+            .criteria
+            .iter()
+            .find(|criterion| criterion.number == number)
+        else {
+            let message = match status.criteria.len() {
+                0 => format!("{} has no exit criteria", status.short_code),
+                count => format!(
+                    "{} has {} criteria; there is no criterion {}",
+                    status.short_code, count, number
+                ),
+            };
+            return Ok(error_result(
+                "No such criterion",
+                &message,
+                Some("Omit number to list the criteria with their numbers."),
+            ));
+        };
+
+        let checked = self.checked.unwrap_or(!current.checked);
+        let status = service
+            .set_checked(&self.short_code, number, checked, &mut db_service)
+            .map_err(CallToolError::new)?;
+
+        Ok(Self::criteria_output(&status, Some((number, checked))))
+    }
+
+    fn criteria_output(status: &CriteriaStatus, changed: Option<(usize, bool)>) -> CallToolResult {
+        let mut output = ToolOutput::new().header(&format!(
+            "Exit Criteria: {} {}",
+            status.short_code, status.title
+        ));
+        if let Some((number, checked)) = changed {
+            output = output.text(&format!(
+                "✓ Criterion {} {}",
+                number,
+                if checked { "checked" } else { "unchecked" }
+            ));
+        }
+
+        if status.criteria.is_empty() {
+            return output.text("No exit criteria found.").build_result();
+        }
+        let rows = status
+            .criteria
+            .iter()
+            .map(|criterion| {
+                vec![
+                    criterion.number.to_string(),
+                    if criterion.checked { "[x]" } else { "[ ]" }.to_string(),
+                    criterion.text.clone(),
+                ]
+            })
+            .collect();
+        output
+            .table(&["#", "Done", "Criterion"], rows)
+            .field(
+                "Met",
+                &format!("{}/{}", status.checked(), status.criteria.len()),
+            )
+            .field("exit_criteria_met", &status.met.to_string())
+            .build_result()
+    }
+}
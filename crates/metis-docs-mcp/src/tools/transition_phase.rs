@@ -1,6 +1,11 @@
 use crate::formatting::ToolOutput;
 use metis_core::{
-    application::services::workspace::{PhaseTransitionService, WorkspaceDetectionService},
+    application::services::{
+        workspace::{
+            CascadePlan, CascadeService, PhaseTransitionService, WorkspaceDetectionService,
+        },
+        DatabaseService,
+    },
     domain::documents::types::{DocumentType, Phase},
 };
 use rust_mcp_sdk::{
@@ -12,7 +17,7 @@ use std::path::Path;
 
 #[mcp_tool(
     name = "transition_phase",
-    description = "Transition a document to a new phase using its short code (e.g., PROJ-V-0001). If phase is not provided, transitions to the next valid phase automatically. IMPORTANT: You can only transition to adjacent phases - you cannot skip phases (e.g., todo->completed is invalid; must go todo->active->completed).",
+    description = "Transition a document to a new phase using its short code (e.g., PROJ-V-0001). If phase is not provided, transitions to the next valid phase automatically. IMPORTANT: You can only transition to adjacent phases - you cannot skip phases (e.g., todo->completed is invalid; must go todo->active->completed). Set cascade to also move the documents that depend on this one (e.g., complete an initiative's open tasks), and dry_run to list every document that would change phase without changing anything.",
     idempotent_hint = false,
     destructive_hint = false,
     open_world_hint = false,
@@ -28,6 +33,10 @@ pub struct TransitionPhaseTool {
     pub phase: Option<String>,
     /// Force transition even if exit criteria aren't met
     pub force: Option<bool>,
+    /// Also transition dependent documents, e.g. complete an initiative's open tasks (default: false)
+    pub cascade: Option<bool>,
+    /// List the documents that would change phase without changing anything (default: false)
+    pub dry_run: Option<bool>,
 }
 
 impl TransitionPhaseTool {
@@ -36,7 +45,7 @@ impl TransitionPhaseTool {
 
         // Prepare workspace (validates, creates/updates database, syncs)
         let detection_service = WorkspaceDetectionService::new();
-        let _db = detection_service
+        let db = detection_service
             .prepare_workspace(metis_dir)
             .await
             .map_err(|e| {
@@ -45,14 +54,42 @@ impl TransitionPhaseTool {
                     e.to_string(),
                 ))
             })?;
+        let mut db_service = DatabaseService::new(db.into_repository());
 
         // Create the phase transition service
         let transition_service = PhaseTransitionService::new(metis_dir);
+        let cascade_service = CascadeService::new(metis_dir);
+
+        // Work out the dependent transitions before changing anything
+        let cascade = self.cascade.unwrap_or(false);
+        let dry_run = self.dry_run.unwrap_or(false);
+        let plan = if cascade || dry_run {
+            let (title, doc_type, from_phase, target_phase) = self.target(&mut db_service)?;
+            let plan = cascade_service
+                .plan(&self.short_code, target_phase, &mut db_service)
+                .map_err(|e| CallToolError::new(e))?;
+            if dry_run {
+                if !transition_service.is_valid_transition(doc_type, from_phase, target_phase) {
+                    return Err(CallToolError::new(std::io::Error::new(
+                        std::io::ErrorKind::InvalidInput,
+                        format!(
+                            "Invalid phase transition from {} to {} for {}",
+                            from_phase, target_phase, doc_type
+                        ),
+                    )));
+                }
+                return Ok(self.dry_run_output(&title, from_phase, target_phase, &plan, cascade));
+            }
+
+            Some(plan)
+        } else {
+            None
+        };
 
         // Perform the transition using short code directly
         let result = if let Some(phase_str) = &self.phase {
             // Transition to specific phase
-            let target_phase = self.parse_phase(phase_str)?;
+            let target_phase = Self::parse_phase(phase_str)?;
             transition_service
                 .transition_document(&self.short_code, target_phase)
                 .await
@@ -65,6 +102,14 @@ impl TransitionPhaseTool {
                 .map_err(|e| CallToolError::new(e))?
         };
 
+        let cascaded = match &plan {
+            Some(plan) => cascade_service
+                .apply(plan)
+                .await
+                .map_err(|e| CallToolError::new(e))?,
+            None => Vec::new(),
+        };
+
         // Get phase progression for visual display
         let doc_type_str = result.document_type.to_string();
         let phases = self.get_phase_sequence(&doc_type_str);
@@ -91,10 +136,133 @@ impl TransitionPhaseTool {
             output = output.blank().text(guidance_text);
         }
 
+        if !cascaded.is_empty() {
+            let rows = cascaded
+                .iter()
+                .map(|step| {
+                    vec![
+                        step.document_id.clone(),
+                        step.from_phase.to_string(),
+                        step.to_phase.to_string(),
+                    ]
+                })
+                .collect();
+            output = output
+                .blank()
+                .subheader("Cascaded")
+                .table(&["Code", "From", "To"], rows);
+        }
+        match &plan {
+            Some(plan) if !plan.stuck.is_empty() => {
+                output = output.warning(&format!(
+                    "Could not reach {}: {}",
+                    result.to_phase,
+                    plan.stuck.join(", ")
+                ));
+            }
+            Some(_) => {}
+            // Point out dependents left behind
+            None => {
+                let open = cascade_service
+                    .plan(&self.short_code, result.to_phase, &mut db_service)
+                    .map(|plan| plan.steps.len() + plan.stuck.len())
+                    .unwrap_or(0);
+                if open > 0 {
+                    output = output.blank().hint(&format!(
+                        "{} dependent document(s) are not {}. Transition them too, or set cascade to do it in the same call next time.",
+                        open, result.to_phase
+                    ));
+                }
+            }
+        }
+
         Ok(output.build_result())
     }
 
-    fn parse_phase(&self, phase_str: &str) -> Result<Phase, CallToolError> {
+    /// The document's title, type and current phase, and the phase it is
+    /// moving to: the requested phase, or the next one
+    fn target(
+        &self,
+        db_service: &mut DatabaseService,
+    ) -> Result<(String, DocumentType, Phase, Phase), CallToolError> {
+        let doc = db_service
+            .find_by_short_code(&self.short_code)
+            .map_err(|e| CallToolError::new(e))?
+            .ok_or_else(|| {
+                CallToolError::new(std::io::Error::new(
+                    std::io::ErrorKind::NotFound,
+                    format!("Document '{}' not found", self.short_code),
+                ))
+            })?;
+        let doc_type: DocumentType = doc.document_type.parse().map_err(|e: String| {
+            CallToolError::new(std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+        })?;
+        let current = Self::parse_phase(&doc.phase)?;
+        let target = match &self.phase {
+            Some(phase_str) => Self::parse_phase(phase_str)?,
+            None => doc_type.next_phase(current).ok_or_else(|| {
+                CallToolError::new(std::io::Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    format!("{} '{}' has no next phase", doc_type, self.short_code),
+                ))
+            })?,
+        };
+        Ok((doc.title, doc_type, current, target))
+    }
+
+    /// Every document that would change phase, the requested one first
+    fn dry_run_output(
+        &self,
+        title: &str,
+        from_phase: Phase,
+        target_phase: Phase,
+        plan: &CascadePlan,
+        cascade: bool,
+    ) -> CallToolResult {
+        let mut rows = vec![vec![
+            self.short_code.clone(),
+            title.to_string(),
+            from_phase.to_string(),
+            target_phase.to_string(),
+        ]];
+        if cascade {
+            rows.extend(plan.steps.iter().map(|step| {
+                let path: Vec<String> = step.path.iter().map(|p| p.to_string()).collect();
+                vec![
+                    step.short_code.clone(),
+                    step.title.clone(),
+                    step.from_phase.to_string(),
+                    path.join(" -> "),
+                ]
+            }));
+        }
+
+        let mut output = ToolOutput::new()
+            .header("Phase Transition (dry run)")
+            .text(&format!(
+                "{} document(s) would change phase; nothing was changed.",
+                rows.len()
+            ))
+            .blank()
+            .table(&["Code", "Title", "From", "To"], rows);
+        if cascade && !plan.stuck.is_empty() {
+            output = output.warning(&format!(
+                "Could not reach {}: {}",
+                target_phase,
+                plan.stuck.join(", ")
+            ));
+        }
+        if !cascade && !plan.is_empty() {
+            output = output.hint(&format!(
+                "{} dependent document(s) are not {}. Set cascade to move them too.",
+                plan.steps.len() + plan.stuck.len(),
+                target_phase
+            ));
+        }
+        output.build_result()
+    }
+
+    pub(crate) fn parse_phase(phase_str: &str) -> Result<Phase, CallToolError> {
         match phase_str.to_lowercase().as_str() {
             "draft" => Ok(Phase::Draft),
             "review" => Ok(Phase::Review),
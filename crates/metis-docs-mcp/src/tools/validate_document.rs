@@ -0,0 +1,110 @@
+use crate::formatting::{error_result, ToolOutput};
+use metis_core::{
+    application::services::{
+        workspace::{WorkspaceDetectionService, WorkspaceValidationService},
+        DatabaseService,
+    },
+    MetisError,
+};
+use rust_mcp_sdk::{
+    macros::{mcp_tool, JsonSchema},
+    schema::{schema_utils::CallToolError, CallToolResult},
+};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+#[mcp_tool(
+    name = "validate_document",
+    description = "Check a document, or with no short_code the whole workspace, before moving it on. Reports schema errors (frontmatter or content that doesn't parse), unmet exit criteria, dangling references (blockers or references to documents that don't exist) and orphaned parents (missing or archived). Unmet criteria are warnings; the rest are errors. Returns a table plus the full report as JSON. Call it before transition_phase to self-check.",
+    idempotent_hint = true,
+    destructive_hint = false,
+    open_world_hint = false,
+    read_only_hint = true
+)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct ValidateDocumentTool {
+    /// Path to the .metis folder (e.g., "/Users/me/my-project/.metis"). Must end with .metis
+    pub project_path: String,
+    /// Document to validate (e.g., PROJ-T-0001); omit to validate every unarchived document
+    pub short_code: Option<String>,
+}
+
+impl ValidateDocumentTool {
+    pub async fn call_tool(&self) -> std::result::Result<CallToolResult, CallToolError> {
+        let metis_dir = Path::new(&self.project_path);
+
+        // Prepare workspace (validates, creates/updates database, syncs)
+        let detection_service = WorkspaceDetectionService::new();
+        let db = detection_service
+            .prepare_workspace(metis_dir)
+            .await
+            .map_err(|e| {
+                CallToolError::new(std::io::Error::new(
+                    std::io::ErrorKind::Other,
+                    e.to_string(),
+                ))
+            })?;
+        let mut db_service = DatabaseService::new(db.into_repository());
+
+        let service = WorkspaceValidationService::new(metis_dir);
+        let report = match &self.short_code {
+            Some(short_code) => service.validate_document(short_code, &mut db_service),
+            None => service.validate_workspace(&mut db_service),
+        };
+        let report = match report {
+            Ok(report) => report,
+            Err(MetisError::NotFound(message)) => {
+                return Ok(error_result(
+                    "Document not found",
+                    &message,
+                    Some("Use `list_documents` to see available documents."),
+                ))
+            }
+            Err(e) => return Err(CallToolError::new(e)),
+        };
+        let json = serde_json::to_string_pretty(&report).map_err(CallToolError::new)?;
+
+        let subject = self.short_code.as_deref().unwrap_or("Workspace");
+        let mut output = ToolOutput::new().header(&format!("Validation: {}", subject));
+        if report.documents.is_empty() {
+            output = output.text(&format!(
+                "✓ No problems in {} document(s)",
+                report.documents_checked
+            ));
+        } else {
+            output = output.text(&format!(
+                "{}{} error(s), {} warning(s) in {} of {} document(s)",
+                if report.is_valid() { "" } else { "✗ " },
+                report.errors,
+                report.warnings,
+                report.documents.len(),
+                report.documents_checked
+            ));
+            let rows = report
+                .documents
+                .iter()
+                .flat_map(|doc| {
+                    doc.problems.iter().map(move |problem| {
+                        vec![
+                            doc.short_code.clone().unwrap_or_else(|| "-".to_string()),
+                            doc.filepath.clone(),
+                            if problem.is_error() {
+                                "error"
+                            } else {
+                                "warning"
+                            }
+                            .to_string(),
+                            problem.to_string(),
+                        ]
+                    })
+                })
+                .collect();
+            output = output.table(&["Code", "File", "Severity", "Problem"], rows);
+        }
+        output = output.code_block(&json, Some("json"));
+        if !report.is_valid() {
+            output = output.hint("Fix the errors before transitioning; `query_relationships` shows a document's links.");
+        }
+        Ok(output.build_result())
+    }
+}
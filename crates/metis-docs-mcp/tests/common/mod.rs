@@ -3,11 +3,27 @@
 use anyhow::Result;
 use metis_core::dal::Database;
 use metis_core::domain::configuration::{ConfigFile, FlightLevelConfig};
-use metis_mcp_server::tools::InitializeProjectTool;
+use metis_mcp_server::tools::{CreateDocumentTool, InitializeProjectTool};
+use regex::Regex;
+use rust_mcp_sdk::schema::{CallToolResult, ContentBlock, EmbeddedResourceResource};
 
 // Re-export the shared test helper from core
 pub use metis_core::tests::common::MetisTestHelper;
 
+/// The text of a tool result's first content block, or an empty string
+pub fn extract_text(result: &CallToolResult) -> String {
+    match result.content.first() {
+        Some(ContentBlock::TextContent(text_content)) => text_content.text.clone(),
+        Some(ContentBlock::EmbeddedResource(embedded)) => match &embedded.resource {
+            EmbeddedResourceResource::TextResourceContents(text_resource) => {
+                text_resource.text.clone()
+            }
+            _ => String::new(),
+        },
+        _ => String::new(),
+    }
+}
+
 /// MCP-specific test helper that wraps the core helper
 pub struct McpTestHelper {
     core_helper: MetisTestHelper,
@@ -40,6 +56,37 @@ impl McpTestHelper {
         Ok(())
     }
 
+    /// Create a document through the MCP tool and return its short code.
+    /// Initiatives are created as small, and backlog tasks as bugs.
+    pub async fn create_document(
+        &self,
+        document_type: &str,
+        title: &str,
+        parent_id: Option<&str>,
+    ) -> String {
+        let result = CreateDocumentTool {
+            project_path: self.metis_dir(),
+            document_type: document_type.to_string(),
+            title: title.to_string(),
+            parent_id: parent_id.map(str::to_string),
+            complexity: (document_type == "initiative").then(|| "s".to_string()),
+            stakeholders: None,
+            decision_maker: None,
+            backlog_category: (document_type == "task" && parent_id.is_none())
+                .then(|| "bug".to_string()),
+        }
+        .call_tool()
+        .await
+        .expect("Create should succeed");
+
+        Regex::new(r"([A-Z]+-[VITA]-\d{4})")
+            .unwrap()
+            .captures(&extract_text(&result))
+            .and_then(|captures| captures.get(1))
+            .map(|m| m.as_str().to_string())
+            .expect("Could not extract short_code from result")
+    }
+
     pub fn get_database(&self) -> Result<Database> {
         self.core_helper.get_database()
     }
@@ -66,6 +66,16 @@ async fn get_vision_short_code(metis_path: &str) -> String {
     let list_tool = ListDocumentsTool {
         project_path: metis_path.to_string(),
         include_archived: None,
+        sort_by: None,
+        descending: None,
+        limit: None,
+        offset: None,
+        cursor: None,
+        document_type: None,
+        phase: None,
+        tag: None,
+        assignee: None,
+        fields: None,
     };
     let result = list_tool.call_tool().await.unwrap();
 
@@ -140,6 +150,8 @@ async fn test_streamlined_configuration_workflow() {
         short_code: initiative_short_code.clone(),
         phase: Some("design".to_string()),
         force: None,
+        cascade: None,
+        dry_run: None,
     };
     let result = transition_initiative.call_tool().await;
     assert!(result.is_ok(), "Initiative phase transition should succeed");
@@ -167,6 +179,8 @@ async fn test_streamlined_configuration_workflow() {
         short_code: task_short_code,
         phase: None, // Auto-transition to next phase
         force: None,
+        cascade: None,
+        dry_run: None,
     };
     let result = transition_task.call_tool().await;
     assert!(result.is_ok(), "Task phase transition should succeed");
@@ -194,6 +208,8 @@ async fn test_streamlined_configuration_workflow() {
         short_code: adr_short_code,
         phase: Some("discussion".to_string()),
         force: None,
+        cascade: None,
+        dry_run: None,
     };
     let result = transition_adr.call_tool().await;
     assert!(result.is_ok(), "ADR phase transition should succeed");
@@ -203,6 +219,16 @@ async fn test_streamlined_configuration_workflow() {
     let list_tool = ListDocumentsTool {
         project_path: metis_path.clone(),
         include_archived: None,
+        sort_by: None,
+        descending: None,
+        limit: None,
+        offset: None,
+        cursor: None,
+        document_type: None,
+        phase: None,
+        tag: None,
+        assignee: None,
+        fields: None,
     };
     let final_list = list_tool.call_tool().await;
     assert!(final_list.is_ok(), "Final document listing should succeed");
@@ -255,6 +281,8 @@ async fn test_direct_configuration_workflow() {
         short_code: task1_short_code,
         phase: None, // Auto-transition to next phase
         force: None,
+        cascade: None,
+        dry_run: None,
     };
     let result = transition_task1.call_tool().await;
     assert!(result.is_ok(), "Task 1 phase transition should succeed");
@@ -282,6 +310,8 @@ async fn test_direct_configuration_workflow() {
         short_code: task2_short_code,
         phase: None, // Auto-transition to next phase
         force: None,
+        cascade: None,
+        dry_run: None,
     };
     let result = transition_task2.call_tool().await;
     assert!(result.is_ok(), "Task 2 phase transition should succeed");
@@ -309,6 +339,8 @@ async fn test_direct_configuration_workflow() {
         short_code: adr_short_code,
         phase: Some("discussion".to_string()),
         force: None,
+        cascade: None,
+        dry_run: None,
     };
     let result = transition_adr.call_tool().await;
     assert!(result.is_ok(), "ADR phase transition should succeed");
@@ -318,6 +350,16 @@ async fn test_direct_configuration_workflow() {
     let list_tool = ListDocumentsTool {
         project_path: metis_path.clone(),
         include_archived: None,
+        sort_by: None,
+        descending: None,
+        limit: None,
+        offset: None,
+        cursor: None,
+        document_type: None,
+        phase: None,
+        tag: None,
+        assignee: None,
+        fields: None,
     };
     let final_list = list_tool.call_tool().await;
     assert!(final_list.is_ok(), "Final document listing should succeed");
@@ -360,6 +402,16 @@ async fn test_specification_workflow() {
     let list_tool = ListDocumentsTool {
         project_path: metis_path.clone(),
         include_archived: None,
+        sort_by: None,
+        descending: None,
+        limit: None,
+        offset: None,
+        cursor: None,
+        document_type: None,
+        phase: None,
+        tag: None,
+        assignee: None,
+        fields: None,
     };
     let list_result = list_tool.call_tool().await.unwrap();
     let list_text = extract_text_from_result(&list_result).unwrap();
@@ -380,6 +432,8 @@ async fn test_specification_workflow() {
             short_code: spec_short_code.clone(),
             phase: None, // Auto-advance
             force: None,
+            cascade: None,
+            dry_run: None,
         };
         let result = transition.call_tool().await;
         assert!(
@@ -420,6 +474,13 @@ async fn test_specification_workflow() {
         document_type: Some("specification".to_string()),
         limit: None,
         include_archived: None,
+        phase: None,
+        tag: None,
+        assignee: None,
+        updated_after: None,
+        updated_before: None,
+        offset: None,
+        cursor: None,
     };
     let search_result = search_tool.call_tool().await;
     assert!(search_result.is_ok(), "Search should succeed");
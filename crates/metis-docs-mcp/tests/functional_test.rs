@@ -41,6 +41,16 @@ async fn get_vision_short_code(metis_path: &str) -> String {
     let list_tool = ListDocumentsTool {
         project_path: metis_path.to_string(),
         include_archived: None,
+        sort_by: None,
+        descending: None,
+        limit: None,
+        offset: None,
+        cursor: None,
+        document_type: None,
+        phase: None,
+        tag: None,
+        assignee: None,
+        fields: None,
     };
     let result = list_tool.call_tool().await.unwrap();
 
@@ -123,6 +133,8 @@ async fn test_initialize_and_create_documents() {
         short_code: initiative_short_code,
         phase: Some("design".to_string()),
         force: None,
+        cascade: None,
+        dry_run: None,
     };
 
     let result = transition_tool.call_tool().await;
@@ -214,6 +226,13 @@ async fn test_search_with_short_code_hyphen() {
         document_type: None,
         limit: None,
         include_archived: None,
+        phase: None,
+        tag: None,
+        assignee: None,
+        updated_after: None,
+        updated_before: None,
+        offset: None,
+        cursor: None,
     };
 
     let result = search_tool.call_tool().await;
@@ -269,6 +288,16 @@ async fn test_list_and_search_include_archived() {
     let list_tool = ListDocumentsTool {
         project_path: metis_path.clone(),
         include_archived: None,
+        sort_by: None,
+        descending: None,
+        limit: None,
+        offset: None,
+        cursor: None,
+        document_type: None,
+        phase: None,
+        tag: None,
+        assignee: None,
+        fields: None,
     };
     let result = list_tool.call_tool().await.unwrap();
     let text = extract_text_from_result(&result).unwrap();
@@ -284,6 +313,13 @@ async fn test_list_and_search_include_archived() {
         document_type: None,
         limit: None,
         include_archived: None,
+        phase: None,
+        tag: None,
+        assignee: None,
+        updated_after: None,
+        updated_before: None,
+        offset: None,
+        cursor: None,
     };
     let result = search_tool.call_tool().await.unwrap();
     let text = extract_text_from_result(&result).unwrap();
@@ -303,6 +339,16 @@ async fn test_list_and_search_include_archived() {
     let list_tool = ListDocumentsTool {
         project_path: metis_path.clone(),
         include_archived: None,
+        sort_by: None,
+        descending: None,
+        limit: None,
+        offset: None,
+        cursor: None,
+        document_type: None,
+        phase: None,
+        tag: None,
+        assignee: None,
+        fields: None,
     };
     let result = list_tool.call_tool().await.unwrap();
     let text = extract_text_from_result(&result).unwrap();
@@ -315,6 +361,16 @@ async fn test_list_and_search_include_archived() {
     let list_tool = ListDocumentsTool {
         project_path: metis_path.clone(),
         include_archived: Some(true),
+        sort_by: None,
+        descending: None,
+        limit: None,
+        offset: None,
+        cursor: None,
+        document_type: None,
+        phase: None,
+        tag: None,
+        assignee: None,
+        fields: None,
     };
     let result = list_tool.call_tool().await.unwrap();
     let text = extract_text_from_result(&result).unwrap();
@@ -330,6 +386,13 @@ async fn test_list_and_search_include_archived() {
         document_type: None,
         limit: None,
         include_archived: None,
+        phase: None,
+        tag: None,
+        assignee: None,
+        updated_after: None,
+        updated_before: None,
+        offset: None,
+        cursor: None,
     };
     let result = search_tool.call_tool().await.unwrap();
     let text = extract_text_from_result(&result).unwrap();
@@ -345,6 +408,13 @@ async fn test_list_and_search_include_archived() {
         document_type: None,
         limit: None,
         include_archived: Some(true),
+        phase: None,
+        tag: None,
+        assignee: None,
+        updated_after: None,
+        updated_before: None,
+        offset: None,
+        cursor: None,
     };
     let result = search_tool.call_tool().await.unwrap();
     let text = extract_text_from_result(&result).unwrap();
@@ -483,6 +553,16 @@ async fn test_create_backlog_items() {
     let list_tool = ListDocumentsTool {
         project_path: metis_path.clone(),
         include_archived: None,
+        sort_by: None,
+        descending: None,
+        limit: None,
+        offset: None,
+        cursor: None,
+        document_type: None,
+        phase: None,
+        tag: None,
+        assignee: None,
+        fields: None,
     };
     let result = list_tool.call_tool().await.unwrap();
     let text = extract_text_from_result(&result).unwrap();
@@ -316,3 +316,97 @@ async fn test_mcp_archive_error_handling() -> Result<()> {
 
     Ok(())
 }
+
+/// Test restoring archived documents through the MCP server
+#[tokio::test]
+async fn test_mcp_restore_document() -> Result<()> {
+    let helper = McpTestHelper::new().await?;
+    helper.initialize_project().await?;
+
+    let create_initiative = CreateDocumentTool {
+        project_path: helper.metis_dir().clone(),
+        document_type: "initiative".to_string(),
+        title: "Retire the Old Importer".to_string(),
+        parent_id: None,
+        complexity: Some("s".to_string()),
+        stakeholders: None,
+        decision_maker: None,
+        backlog_category: None,
+    };
+    let result = create_initiative.call_tool().await;
+    assert!(result.is_ok(), "Create initiative should succeed");
+    let initiative_short_code = extract_short_code(&result.unwrap());
+
+    let create_task = CreateDocumentTool {
+        project_path: helper.metis_dir().clone(),
+        document_type: "task".to_string(),
+        title: "Remove the importer flags".to_string(),
+        parent_id: Some(initiative_short_code.clone()),
+        complexity: None,
+        stakeholders: None,
+        decision_maker: None,
+        backlog_category: None,
+    };
+    let result = create_task.call_tool().await;
+    assert!(result.is_ok(), "Create task should succeed");
+    let task_short_code = extract_short_code(&result.unwrap());
+
+    // A document that isn't archived can't be restored
+    let restore_initiative = RestoreDocumentTool {
+        project_path: helper.metis_dir().clone(),
+        short_code: initiative_short_code.clone(),
+    };
+    assert!(
+        restore_initiative.call_tool().await.is_err(),
+        "Restoring a document that isn't archived should fail"
+    );
+
+    let archive_initiative = ArchiveDocumentTool {
+        project_path: helper.metis_dir().clone(),
+        short_code: initiative_short_code.clone(),
+    };
+    assert!(archive_initiative.call_tool().await.is_ok());
+
+    // The task can't come back while its initiative is archived
+    let restore_task = RestoreDocumentTool {
+        project_path: helper.metis_dir().clone(),
+        short_code: task_short_code.clone(),
+    };
+    assert!(
+        restore_task.call_tool().await.is_err(),
+        "Restoring a task under an archived initiative should fail"
+    );
+
+    // Restoring the initiative brings the task back with it
+    let result = restore_initiative.call_tool().await;
+    assert!(result.is_ok(), "Restore should succeed: {:?}", result);
+    let text = extract_text_from_result(&result.unwrap()).unwrap();
+    assert!(text.contains(&format!("{} restored", initiative_short_code)));
+
+    use metis_core::Application;
+    let db = helper.get_database()?;
+    Application::new(db)
+        .sync_directory(&helper.metis_dir())
+        .await?;
+    let db = helper.get_database()?;
+    let mut repo = db
+        .repository()
+        .map_err(|e| anyhow::anyhow!("Repository error: {}", e))?;
+    for short_code in [&initiative_short_code, &task_short_code] {
+        let doc = repo
+            .find_by_short_code(short_code)
+            .map_err(|e| anyhow::anyhow!("Find error: {}", e))?
+            .expect("document should exist");
+        assert!(!doc.archived, "{} should be restored", short_code);
+        assert!(
+            !doc.filepath.starts_with("archived"),
+            "{} should be back out of the archived folder",
+            short_code
+        );
+    }
+
+    // And the restored initiative can be archived again
+    assert!(archive_initiative.call_tool().await.is_ok());
+
+    Ok(())
+}
@@ -0,0 +1,91 @@
+//! Managing blocked_by links through the MCP blocker tools
+
+mod common;
+
+use anyhow::Result;
+use common::{extract_text, McpTestHelper};
+use metis_mcp_server::tools::*;
+
+fn add(helper: &McpTestHelper, short_code: &str, blocked_by: &str) -> AddBlockerTool {
+    AddBlockerTool {
+        project_path: helper.metis_dir(),
+        short_code: short_code.to_string(),
+        blocked_by: blocked_by.to_string(),
+    }
+}
+
+fn blockers(helper: &McpTestHelper, short_code: &str) -> GetBlockersTool {
+    GetBlockersTool {
+        project_path: helper.metis_dir(),
+        short_code: short_code.to_string(),
+    }
+}
+
+#[tokio::test]
+async fn test_mcp_blockers() -> Result<()> {
+    let helper = McpTestHelper::new().await?;
+    helper.initialize_project().await?;
+
+    let initiative = helper
+        .create_document("initiative", "Billing Revamp", None)
+        .await;
+    let schema = helper
+        .create_document("task", "Invoice schema", Some(&initiative))
+        .await;
+    let split = helper
+        .create_document("task", "Split invoices", Some(&initiative))
+        .await;
+    let retry = helper
+        .create_document("task", "Retry failed charges", Some(&initiative))
+        .await;
+
+    // retry waits on split, which waits on schema
+    for (short_code, blocked_by) in [(&split, &schema), (&retry, &split)] {
+        let result = add(&helper, short_code, blocked_by)
+            .call_tool()
+            .await
+            .unwrap();
+        assert_ne!(result.is_error, Some(true), "{}", extract_text(&result));
+    }
+    let result = add(&helper, &retry, &split).call_tool().await.unwrap();
+    assert!(extract_text(&result).contains("already blocked by"));
+
+    // The chain is transitive, with depths
+    let result = blockers(&helper, &retry).call_tool().await.unwrap();
+    let text = extract_text(&result);
+    assert!(text.contains(&split) && text.contains(&schema), "{}", text);
+    assert!(text.contains("2 of 2"), "{}", text);
+
+    // Closing the loop, directly or through the chain, is refused
+    let result = add(&helper, &schema, &retry).call_tool().await.unwrap();
+    assert_eq!(result.is_error, Some(true));
+    assert!(extract_text(&result).contains("cycle"));
+    let result = add(&helper, &retry, &retry).call_tool().await.unwrap();
+    assert_eq!(result.is_error, Some(true));
+
+    // Unknown short codes are rejected
+    let result = add(&helper, &retry, "TEST-T-9999")
+        .call_tool()
+        .await
+        .unwrap();
+    assert_eq!(result.is_error, Some(true));
+    let result = blockers(&helper, "TEST-T-9999").call_tool().await.unwrap();
+    assert_eq!(result.is_error, Some(true));
+
+    // Removing a link shortens the chain
+    let result = RemoveBlockerTool {
+        project_path: helper.metis_dir(),
+        short_code: split.clone(),
+        blocked_by: schema.clone(),
+    }
+    .call_tool()
+    .await
+    .unwrap();
+    assert!(extract_text(&result).contains("no longer blocked by"));
+    let text = extract_text(&blockers(&helper, &retry).call_tool().await.unwrap());
+    assert!(text.contains(&split) && !text.contains(&schema), "{}", text);
+    let text = extract_text(&blockers(&helper, &schema).call_tool().await.unwrap());
+    assert!(text.contains("has no blockers"), "{}", text);
+
+    Ok(())
+}
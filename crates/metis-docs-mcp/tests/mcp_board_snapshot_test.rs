@@ -0,0 +1,116 @@
+//! The kanban boards as JSON through the board_snapshot tool
+
+mod common;
+
+use anyhow::Result;
+use common::{extract_text, McpTestHelper};
+use metis_mcp_server::tools::*;
+use serde_json::Value;
+
+/// The JSON in the tool's fenced block
+async fn snapshot(helper: &McpTestHelper, board: Option<&str>) -> Value {
+    let result = BoardSnapshotTool {
+        project_path: helper.metis_dir(),
+        board: board.map(str::to_string),
+        include_archived: None,
+    }
+    .call_tool()
+    .await
+    .unwrap();
+    let text = extract_text(&result);
+    assert_ne!(result.is_error, Some(true), "{}", text);
+    let json = text
+        .split("```json\n")
+        .nth(1)
+        .and_then(|rest| rest.split("\n```").next())
+        .expect("JSON block");
+    serde_json::from_str(json).unwrap()
+}
+
+fn find_card<'a>(board: &'a Value, code: &str) -> &'a Value {
+    board["columns"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .flat_map(|column| column["cards"].as_array().unwrap())
+        .find(|card| card["code"] == code)
+        .unwrap_or_else(|| panic!("{} not on the board", code))
+}
+
+#[tokio::test]
+async fn test_mcp_board_snapshot() -> Result<()> {
+    let helper = McpTestHelper::new().await?;
+    helper.initialize_project().await?;
+
+    let initiative = helper
+        .create_document("initiative", "Billing Revamp", None)
+        .await;
+    let schema = helper
+        .create_document("task", "Invoice schema", Some(&initiative))
+        .await;
+    let split = helper
+        .create_document("task", "Split invoices", Some(&initiative))
+        .await;
+
+    let result = BulkUpdateTool {
+        project_path: helper.metis_dir(),
+        filter: "type:task".to_string(),
+        assignee: Some("alice".to_string()),
+        priority: None,
+        add_tags: None,
+        remove_tags: None,
+        phase: None,
+    }
+    .call_tool()
+    .await
+    .unwrap();
+    assert_ne!(result.is_error, Some(true), "{}", extract_text(&result));
+    ToggleExitCriterionTool {
+        project_path: helper.metis_dir(),
+        short_code: schema.clone(),
+        number: Some(1),
+        checked: Some(true),
+    }
+    .call_tool()
+    .await
+    .unwrap();
+
+    // Every non-empty board, in order
+    let boards = snapshot(&helper, None).await;
+    let names: Vec<&str> = boards
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|board| board["board"].as_str().unwrap())
+        .collect();
+    assert_eq!(names, vec!["vision", "initiative", "task"]);
+
+    let boards = snapshot(&helper, Some("task")).await;
+    let board = &boards[0];
+    let phases: Vec<&str> = board["columns"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|column| column["phase"].as_str().unwrap())
+        .collect();
+    assert_eq!(phases, vec!["backlog", "todo", "active", "completed"]);
+
+    let card = find_card(board, &schema);
+    assert_eq!(card["title"], "Invoice schema");
+    assert_eq!(card["assignee"], "alice");
+    assert_eq!(card["criteria"]["checked"], 1);
+    assert!(card["criteria"]["total"].as_u64().unwrap() > 1);
+    assert_eq!(find_card(board, &split)["criteria"]["checked"], 0);
+
+    let result = BoardSnapshotTool {
+        project_path: helper.metis_dir(),
+        board: Some("sprint".to_string()),
+        include_archived: None,
+    }
+    .call_tool()
+    .await
+    .unwrap();
+    assert_eq!(result.is_error, Some(true));
+
+    Ok(())
+}
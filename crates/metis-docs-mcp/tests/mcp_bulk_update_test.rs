@@ -0,0 +1,107 @@
+//! Filtered, all-or-nothing updates through the MCP bulk_update tool
+
+mod common;
+
+use anyhow::Result;
+use common::{extract_text, McpTestHelper};
+use metis_mcp_server::tools::*;
+use std::path::Path;
+
+fn bulk_tool(helper: &McpTestHelper, filter: &str) -> BulkUpdateTool {
+    BulkUpdateTool {
+        project_path: helper.metis_dir(),
+        filter: filter.to_string(),
+        assignee: None,
+        priority: None,
+        add_tags: None,
+        remove_tags: None,
+        phase: None,
+    }
+}
+
+/// The file content of a document, looked up after a sync
+async fn read_file(helper: &McpTestHelper, short_code: &str) -> Result<String> {
+    metis_core::Application::new(helper.get_database()?)
+        .sync_directory(&helper.metis_dir())
+        .await?;
+    let db = helper.get_database()?;
+    let mut repo = db
+        .repository()
+        .map_err(|e| anyhow::anyhow!("Repository error: {}", e))?;
+    let doc = repo
+        .find_by_short_code(short_code)
+        .map_err(|e| anyhow::anyhow!("Find error: {}", e))?
+        .expect("document should exist");
+    Ok(std::fs::read_to_string(
+        Path::new(&helper.metis_dir()).join(doc.filepath),
+    )?)
+}
+
+#[tokio::test]
+async fn test_mcp_bulk_update() -> Result<()> {
+    let helper = McpTestHelper::new().await?;
+    helper.initialize_project().await?;
+
+    let initiative = helper
+        .create_document("initiative", "Billing Revamp", None)
+        .await;
+    let first = helper
+        .create_document("task", "Split invoices", Some(&initiative))
+        .await;
+    let second = helper
+        .create_document("task", "Retry failed charges", Some(&initiative))
+        .await;
+    let backlog = helper
+        .create_document("task", "Totals round twice", None)
+        .await;
+
+    // The backlog item can't go straight to active, so nothing is written
+    let mut tool = bulk_tool(&helper, "type:task");
+    tool.assignee = Some("alice".to_string());
+    tool.phase = Some("active".to_string());
+    let result = tool.call_tool().await.unwrap();
+    assert_eq!(result.is_error, Some(true));
+    assert!(extract_text(&result).contains(&backlog));
+    assert!(!read_file(&helper, &first).await?.contains("alice"));
+
+    // Narrowed to the planned tasks, every patch lands on both
+    let mut tool = bulk_tool(&helper, "type:task phase:todo");
+    tool.assignee = Some("alice".to_string());
+    tool.priority = Some("high".to_string());
+    tool.add_tags = Some(vec!["sprint-1".to_string()]);
+    tool.phase = Some("active".to_string());
+    let result = tool.call_tool().await.unwrap();
+    assert_ne!(result.is_error, Some(true), "{}", extract_text(&result));
+    let text = extract_text(&result);
+    assert!(text.contains(&first) && text.contains(&second));
+    assert!(!text.contains(&backlog));
+    for short_code in [&first, &second] {
+        let content = read_file(&helper, short_code).await?;
+        assert!(content.contains("assignee: alice"), "{}", content);
+        assert!(content.contains("priority: high"), "{}", content);
+        assert!(content.contains("#sprint-1"), "{}", content);
+        assert!(content.contains("#phase/active"), "{}", content);
+    }
+
+    // An empty assignee unassigns, and tags can be taken off again
+    let mut tool = bulk_tool(&helper, "tag:sprint-1");
+    tool.assignee = Some(String::new());
+    tool.remove_tags = Some(vec!["#sprint-1".to_string()]);
+    let result = tool.call_tool().await.unwrap();
+    assert_ne!(result.is_error, Some(true), "{}", extract_text(&result));
+    let content = read_file(&helper, &first).await?;
+    assert!(!content.contains("assignee:"), "{}", content);
+    assert!(!content.contains("#sprint-1"), "{}", content);
+
+    // Bad input is reported before anything is looked up
+    let result = bulk_tool(&helper, "estimate>3").call_tool().await.unwrap();
+    assert_eq!(result.is_error, Some(true));
+    let result = bulk_tool(&helper, "type:task").call_tool().await.unwrap();
+    assert_eq!(result.is_error, Some(true));
+    let mut tool = bulk_tool(&helper, "type:task");
+    tool.add_tags = Some(vec!["#phase/completed".to_string()]);
+    let result = tool.call_tool().await.unwrap();
+    assert_eq!(result.is_error, Some(true));
+
+    Ok(())
+}
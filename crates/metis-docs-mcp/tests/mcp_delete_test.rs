@@ -0,0 +1,132 @@
+//! Safety checks of the MCP delete_document tool
+
+mod common;
+
+use anyhow::Result;
+use common::{extract_text, McpTestHelper};
+use metis_core::application::services::{
+    workspace::{LinkKind, LinkService},
+    DatabaseService,
+};
+use metis_mcp_server::tools::*;
+use regex::Regex;
+
+fn extract_short_code(result: &rust_mcp_sdk::schema::CallToolResult) -> String {
+    let re = Regex::new(r"([A-Z]+-[VITA]-\d{4})").unwrap();
+    re.captures(&extract_text(result))
+        .and_then(|captures| captures.get(1))
+        .map(|m| m.as_str().to_string())
+        .expect("Could not extract short_code from result")
+}
+
+fn create_tool(
+    helper: &McpTestHelper,
+    document_type: &str,
+    title: &str,
+    parent_id: Option<&str>,
+) -> CreateDocumentTool {
+    CreateDocumentTool {
+        project_path: helper.metis_dir(),
+        document_type: document_type.to_string(),
+        title: title.to_string(),
+        parent_id: parent_id.map(str::to_string),
+        complexity: (document_type == "initiative").then(|| "s".to_string()),
+        stakeholders: None,
+        decision_maker: None,
+        backlog_category: (document_type == "task" && parent_id.is_none())
+            .then(|| "bug".to_string()),
+    }
+}
+
+fn delete_tool(helper: &McpTestHelper, short_code: &str, force: bool) -> DeleteDocumentTool {
+    DeleteDocumentTool {
+        project_path: helper.metis_dir(),
+        short_code: short_code.to_string(),
+        force: force.then_some(true),
+    }
+}
+
+#[tokio::test]
+async fn test_mcp_delete_document_safety_checks() -> Result<()> {
+    let helper = McpTestHelper::new().await?;
+    helper.initialize_project().await?;
+
+    let result = create_tool(&helper, "initiative", "Replace the Scheduler", None)
+        .call_tool()
+        .await
+        .expect("Create initiative should succeed");
+    let initiative = extract_short_code(&result);
+    let result = create_tool(&helper, "task", "Port the cron jobs", Some(&initiative))
+        .call_tool()
+        .await
+        .expect("Create task should succeed");
+    let task = extract_short_code(&result);
+    let result = create_tool(&helper, "task", "Jobs run twice after restart", None)
+        .call_tool()
+        .await
+        .expect("Create backlog item should succeed");
+    let backlog = extract_short_code(&result);
+
+    // The backlog item waits on the task
+    metis_core::Application::new(helper.get_database()?)
+        .sync_directory(&helper.metis_dir())
+        .await?;
+    let mut db_service = DatabaseService::new(helper.get_database()?.into_repository());
+    LinkService::new(helper.metis_dir())
+        .link(&backlog, &task, LinkKind::BlockedBy, &mut db_service)
+        .map_err(|e| anyhow::anyhow!("{}", e))?;
+
+    // An initiative with a task still in it is refused, naming the task
+    let result = delete_tool(&helper, &initiative, false).call_tool().await;
+    let result = result.expect("A refusal is a tool result, not a failure");
+    assert_eq!(result.is_error, Some(true));
+    assert!(extract_text(&result).contains(&task));
+
+    // So is a task another document lists in blocked_by
+    let result = delete_tool(&helper, &task, false)
+        .call_tool()
+        .await
+        .unwrap();
+    assert_eq!(result.is_error, Some(true));
+    assert!(extract_text(&result).contains(&backlog));
+
+    // Forcing it deletes the file and warns about the dangling reference
+    let result = delete_tool(&helper, &task, true).call_tool().await.unwrap();
+    assert_ne!(result.is_error, Some(true), "{}", extract_text(&result));
+    let text = extract_text(&result);
+    assert!(text.contains(&format!("{} deleted", task)));
+    assert!(text.contains(&backlog));
+
+    // With the task gone, the initiative can be deleted without force
+    let result = delete_tool(&helper, &initiative, false)
+        .call_tool()
+        .await
+        .unwrap();
+    assert_ne!(result.is_error, Some(true), "{}", extract_text(&result));
+
+    metis_core::Application::new(helper.get_database()?)
+        .sync_directory(&helper.metis_dir())
+        .await?;
+    let db = helper.get_database()?;
+    let mut repo = db
+        .repository()
+        .map_err(|e| anyhow::anyhow!("Repository error: {}", e))?;
+    for short_code in [&initiative, &task] {
+        assert!(
+            repo.find_by_short_code(short_code)
+                .map_err(|e| anyhow::anyhow!("Find error: {}", e))?
+                .is_none(),
+            "{} should be gone",
+            short_code
+        );
+    }
+
+    // Unknown short codes are reported
+    let result = delete_tool(&helper, "PROJ-T-9999", false)
+        .call_tool()
+        .await
+        .unwrap();
+    assert_eq!(result.is_error, Some(true));
+
+    Ok(())
+}
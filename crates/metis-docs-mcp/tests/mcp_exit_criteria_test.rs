@@ -0,0 +1,114 @@
+//! Listing and checking off exit criteria through the MCP toggle_exit_criterion tool
+
+mod common;
+
+use anyhow::Result;
+use common::{extract_text, McpTestHelper};
+use metis_mcp_server::tools::*;
+use std::path::Path;
+
+fn toggle_tool(
+    helper: &McpTestHelper,
+    short_code: &str,
+    number: Option<u32>,
+    checked: Option<bool>,
+) -> ToggleExitCriterionTool {
+    ToggleExitCriterionTool {
+        project_path: helper.metis_dir(),
+        short_code: short_code.to_string(),
+        number,
+        checked,
+    }
+}
+
+/// The file content of a document, looked up after a sync
+async fn read_file(helper: &McpTestHelper, short_code: &str) -> Result<String> {
+    metis_core::Application::new(helper.get_database()?)
+        .sync_directory(&helper.metis_dir())
+        .await?;
+    let db = helper.get_database()?;
+    let mut repo = db
+        .repository()
+        .map_err(|e| anyhow::anyhow!("Repository error: {}", e))?;
+    let doc = repo
+        .find_by_short_code(short_code)
+        .map_err(|e| anyhow::anyhow!("Find error: {}", e))?
+        .expect("document should exist");
+    Ok(std::fs::read_to_string(
+        Path::new(&helper.metis_dir()).join(doc.filepath),
+    )?)
+}
+
+#[tokio::test]
+async fn test_mcp_toggle_exit_criterion() -> Result<()> {
+    let helper = McpTestHelper::new().await?;
+    helper.initialize_project().await?;
+
+    let initiative = helper
+        .create_document("initiative", "Billing Revamp", None)
+        .await;
+    let task = helper
+        .create_document("task", "Split invoices", Some(&initiative))
+        .await;
+
+    // Without a number the criteria are listed and nothing changes
+    let result = toggle_tool(&helper, &task, None, None)
+        .call_tool()
+        .await
+        .unwrap();
+    assert_ne!(result.is_error, Some(true), "{}", extract_text(&result));
+    let text = extract_text(&result);
+    assert!(text.contains("exit_criteria_met"), "{}", text);
+    assert!(text.contains("[ ]"), "{}", text);
+    let count = text.matches("[ ]").count() as u32;
+    assert!(count > 1, "{}", text);
+
+    // Toggling checks the first criterion, and toggling again unchecks it
+    let result = toggle_tool(&helper, &task, Some(1), None)
+        .call_tool()
+        .await
+        .unwrap();
+    assert!(extract_text(&result).contains("Criterion 1 checked"));
+    assert!(read_file(&helper, &task).await?.contains("- [x]"));
+    let result = toggle_tool(&helper, &task, Some(1), None)
+        .call_tool()
+        .await
+        .unwrap();
+    assert!(extract_text(&result).contains("Criterion 1 unchecked"));
+    assert!(!read_file(&helper, &task).await?.contains("- [x]"));
+
+    // Checking every criterion sets exit_criteria_met
+    for number in 1..=count {
+        let result = toggle_tool(&helper, &task, Some(number), Some(true))
+            .call_tool()
+            .await
+            .unwrap();
+        assert_ne!(result.is_error, Some(true), "{}", extract_text(&result));
+    }
+    let content = read_file(&helper, &task).await?;
+    assert!(content.contains("exit_criteria_met: true"), "{}", content);
+    assert!(!content.contains("- [ ]"), "{}", content);
+
+    // Checking an already checked criterion leaves it checked
+    let result = toggle_tool(&helper, &task, Some(1), Some(true))
+        .call_tool()
+        .await
+        .unwrap();
+    let text = extract_text(&result);
+    assert!(text.contains("Criterion 1 checked"), "{}", text);
+    assert!(text.contains("**exit_criteria_met**: true"), "{}", text);
+
+    // Out-of-range numbers and unknown documents are reported
+    let result = toggle_tool(&helper, &task, Some(count + 1), None)
+        .call_tool()
+        .await
+        .unwrap();
+    assert_eq!(result.is_error, Some(true));
+    let result = toggle_tool(&helper, "TEST-T-9999", None, None)
+        .call_tool()
+        .await
+        .unwrap();
+    assert_eq!(result.is_error, Some(true));
+
+    Ok(())
+}
@@ -0,0 +1,83 @@
+//! Monitoring a workspace through the MCP health tool
+
+mod common;
+
+use anyhow::Result;
+use common::{extract_text, McpTestHelper};
+use metis_mcp_server::tools::*;
+use std::path::Path;
+use std::process::Command;
+use std::time::Duration;
+
+fn git(dir: &Path, args: &[&str]) -> String {
+    let output = Command::new("git")
+        .args(["-c", "user.name=Test", "-c", "user.email=test@example.com"])
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .expect("git should run");
+    assert!(
+        output.status.success(),
+        "git {:?} failed: {}",
+        args,
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8_lossy(&output.stdout).trim().to_string()
+}
+
+/// The JSON block of the tool's output
+async fn health(helper: &McpTestHelper) -> (String, serde_json::Value) {
+    let result = HealthTool {
+        project_path: helper.metis_dir(),
+    }
+    .call_tool_with_uptime(Duration::from_secs(90))
+    .await
+    .unwrap();
+    let text = extract_text(&result);
+    assert_ne!(result.is_error, Some(true), "{}", text);
+    let json = text
+        .split("```json")
+        .nth(1)
+        .and_then(|rest| rest.split("```").next())
+        .unwrap_or_else(|| panic!("no JSON in {}", text));
+    (text.clone(), serde_json::from_str(json).unwrap())
+}
+
+#[tokio::test]
+async fn test_mcp_health() -> Result<()> {
+    let helper = McpTestHelper::new().await?;
+    helper.initialize_project().await?;
+
+    // Outside git the workspace still reports, without sync details
+    let (text, report) = health(&helper).await;
+    assert!(text.contains("up 1m 30s"), "{}", text);
+    assert_eq!(report["uptime_seconds"], 90);
+    assert_eq!(report["documents"]["vision"], 1);
+    assert!(report["schema_version"].is_string(), "{}", report);
+    assert!(report["last_sync"].is_null());
+    assert!(text.contains("Pending conflicts unknown"), "{}", text);
+
+    // Shared through a bare remote, the last commit and conflicts show up
+    let remote = tempfile::tempdir()?;
+    git(remote.path(), &["init", "--quiet", "--bare"]);
+    let project = Path::new(&helper.metis_dir())
+        .parent()
+        .unwrap()
+        .to_path_buf();
+    git(&project, &["init", "--quiet"]);
+    git(&project, &["add", "."]);
+    git(&project, &["commit", "--quiet", "-m", "Initial workspace"]);
+    let url = remote.path().to_string_lossy().to_string();
+    git(&project, &["remote", "add", "origin", &url]);
+    git(&project, &["push", "--quiet", "-u", "origin", "HEAD"]);
+
+    let (_, report) = health(&helper).await;
+    assert_eq!(
+        report["last_sync"]["sha"],
+        git(&project, &["rev-parse", "HEAD"])
+    );
+    assert_eq!(report["pending_conflicts"], 0);
+    assert!(report["remote"].as_str().unwrap().starts_with("origin/"));
+
+    Ok(())
+}
@@ -0,0 +1,116 @@
+//! Filtering, column selection and cursor paging in the MCP list_documents tool
+
+mod common;
+
+use anyhow::Result;
+use common::{extract_text, McpTestHelper};
+use metis_mcp_server::tools::*;
+use regex::Regex;
+
+fn list_tool(helper: &McpTestHelper) -> ListDocumentsTool {
+    ListDocumentsTool {
+        project_path: helper.metis_dir(),
+        include_archived: None,
+        sort_by: None,
+        descending: None,
+        limit: None,
+        offset: None,
+        cursor: None,
+        document_type: None,
+        phase: None,
+        tag: None,
+        assignee: None,
+        fields: None,
+    }
+}
+
+/// The value after `Next cursor` in a page, if there is another page
+fn next_cursor(text: &str) -> Option<String> {
+    Regex::new(r"Next cursor\W+(\d+)")
+        .unwrap()
+        .captures(text)
+        .map(|captures| captures[1].to_string())
+}
+
+#[tokio::test]
+async fn test_mcp_list_documents_filters_fields_and_cursor() -> Result<()> {
+    let helper = McpTestHelper::new().await?;
+    helper.initialize_project().await?;
+
+    let initiative = helper
+        .create_document("initiative", "Search Relevance", None)
+        .await;
+    let mut tasks = Vec::new();
+    for title in ["Tune boosts", "Add synonyms", "Log zero results"] {
+        tasks.push(
+            helper
+                .create_document("task", title, Some(&initiative))
+                .await,
+        );
+    }
+    BulkUpdateTool {
+        project_path: helper.metis_dir(),
+        filter: "type:task".to_string(),
+        assignee: Some("alice".to_string()),
+        priority: None,
+        add_tags: Some(vec!["#search".to_string()]),
+        remove_tags: None,
+        phase: None,
+    }
+    .call_tool()
+    .await
+    .unwrap();
+
+    // Filters narrow the list and fields pick the columns
+    let mut tool = list_tool(&helper);
+    tool.document_type = Some("task".to_string());
+    tool.tag = Some("search".to_string());
+    tool.assignee = Some("alice".to_string());
+    tool.fields = Some(vec!["code".to_string(), "assignee".to_string()]);
+    let text = extract_text(&tool.call_tool().await.unwrap());
+    assert!(text.contains("Documents (3 total)"), "{}", text);
+    assert!(
+        text.contains("| Code") && text.contains("Assignee"),
+        "{}",
+        text
+    );
+    assert!(text.contains("alice"), "{}", text);
+    assert!(
+        !text.contains("Title") && !text.contains(&initiative),
+        "{}",
+        text
+    );
+
+    // Walking the cursor visits every task once
+    let mut seen = Vec::new();
+    let mut cursor = None;
+    loop {
+        let mut tool = list_tool(&helper);
+        tool.document_type = Some("task".to_string());
+        tool.limit = Some(2);
+        tool.cursor = cursor.take();
+        let text = extract_text(&tool.call_tool().await.unwrap());
+        seen.extend(tasks.iter().filter(|code| text.contains(*code)).cloned());
+        match next_cursor(&text) {
+            Some(next) => cursor = Some(next),
+            None => break,
+        }
+    }
+    seen.sort();
+    assert_eq!(seen, tasks);
+
+    // Nobody else is assigned, and bad parameters are rejected
+    let mut tool = list_tool(&helper);
+    tool.assignee = Some("bob".to_string());
+    let text = extract_text(&tool.call_tool().await.unwrap());
+    assert!(text.contains("No documents found."), "{}", text);
+    let mut tool = list_tool(&helper);
+    tool.fields = Some(vec!["owner".to_string()]);
+    assert!(tool.call_tool().await.is_err());
+    let mut tool = list_tool(&helper);
+    tool.cursor = Some("2".to_string());
+    tool.offset = Some(2);
+    assert!(tool.call_tool().await.is_err());
+
+    Ok(())
+}
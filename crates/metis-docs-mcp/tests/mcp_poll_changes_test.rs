@@ -0,0 +1,161 @@
+//! Following workspace changes with a cursor through the poll_changes tool
+
+mod common;
+
+use anyhow::Result;
+use common::{extract_text, McpTestHelper};
+use metis_mcp_server::tools::*;
+use regex::Regex;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+/// The tool's text and its Next cursor
+async fn poll(helper: &McpTestHelper, cursor: Option<&str>) -> (String, String) {
+    poll_page(helper, cursor, None).await
+}
+
+async fn poll_page(
+    helper: &McpTestHelper,
+    cursor: Option<&str>,
+    limit: Option<u32>,
+) -> (String, String) {
+    let result = PollChangesTool {
+        project_path: helper.metis_dir(),
+        cursor: cursor.map(str::to_string),
+        limit,
+    }
+    .call_tool()
+    .await
+    .unwrap();
+    let text = extract_text(&result);
+    assert_ne!(result.is_error, Some(true), "{}", text);
+    let cursor = Regex::new(r"\*\*Next cursor\*\*: (\S+)")
+        .unwrap()
+        .captures(&text)
+        .map(|captures| captures[1].to_string())
+        .unwrap_or_else(|| panic!("no cursor in {}", text));
+    (text, cursor)
+}
+
+/// The file of a document, found by its short code
+fn document_file(dir: &Path, short_code: &str) -> Option<PathBuf> {
+    for entry in std::fs::read_dir(dir).ok()?.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            if let Some(found) = document_file(&path, short_code) {
+                return Some(found);
+            }
+        } else if path.file_name() == Some(format!("{}.md", short_code).as_ref()) {
+            return Some(path);
+        }
+    }
+    None
+}
+
+/// The table row for a short code
+fn row<'a>(text: &'a str, short_code: &str) -> &'a str {
+    text.lines()
+        .find(|line| line.contains(short_code))
+        .unwrap_or_else(|| panic!("{} not in {}", short_code, text))
+}
+
+#[tokio::test]
+async fn test_mcp_poll_changes() -> Result<()> {
+    let helper = McpTestHelper::new().await?;
+    helper.initialize_project().await?;
+    let initiative = helper
+        .create_document("initiative", "Billing Revamp", None)
+        .await;
+
+    // Creation and phase times are recorded to the second
+    tokio::time::sleep(std::time::Duration::from_millis(1100)).await;
+    let (text, cursor) = poll(&helper, None).await;
+    assert!(text.contains("Starting point recorded"), "{}", text);
+
+    let (text, cursor) = poll(&helper, Some(&cursor)).await;
+    assert!(text.contains("No changes since the cursor"), "{}", text);
+
+    let task = helper
+        .create_document("task", "Invoice schema", Some(&initiative))
+        .await;
+    let result = TransitionPhaseTool {
+        project_path: helper.metis_dir(),
+        short_code: initiative.clone(),
+        phase: Some("design".to_string()),
+        force: None,
+        cascade: None,
+        dry_run: None,
+    }
+    .call_tool()
+    .await
+    .unwrap();
+    assert_ne!(result.is_error, Some(true), "{}", extract_text(&result));
+
+    let (text, next) = poll(&helper, Some(&cursor)).await;
+    assert!(row(&text, &task).contains("created"), "{}", text);
+    let transitioned = row(&text, &initiative);
+    assert!(transitioned.contains("transitioned"), "{}", text);
+    assert!(transitioned.contains("discovery → design"), "{}", text);
+
+    // Changes already reported don't come back
+    let (text, _) = poll(&helper, Some(&next)).await;
+    assert!(text.contains("No changes since the cursor"), "{}", text);
+
+    let result = PollChangesTool {
+        project_path: helper.metis_dir(),
+        cursor: Some("yesterday".to_string()),
+        limit: None,
+    }
+    .call_tool()
+    .await
+    .unwrap();
+    assert_eq!(result.is_error, Some(true));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_mcp_poll_changes_pages_through_shared_timestamps() -> Result<()> {
+    let helper = McpTestHelper::new().await?;
+    helper.initialize_project().await?;
+    let initiative = helper
+        .create_document("initiative", "Billing Revamp", None)
+        .await;
+    let first = helper
+        .create_document("task", "Invoice schema", Some(&initiative))
+        .await;
+    let second = helper
+        .create_document("task", "Payment retries", Some(&initiative))
+        .await;
+    let (_, cursor) = poll(&helper, None).await;
+
+    // A git checkout writes both files in the same instant
+    let mtime = SystemTime::now() + Duration::from_secs(5);
+    for code in [&first, &second] {
+        let path = document_file(Path::new(&helper.metis_dir()), code)
+            .unwrap_or_else(|| panic!("no file for {}", code));
+        std::fs::write(&path, std::fs::read_to_string(&path)? + "\nEdited.\n")?;
+        std::fs::File::options()
+            .write(true)
+            .open(&path)?
+            .set_modified(mtime)?;
+    }
+
+    // One per page, and neither is skipped at the page boundary
+    let (page, cursor) = poll_page(&helper, Some(&cursor), Some(1)).await;
+    assert!(page.contains("More changes follow"), "{}", page);
+    let (next, cursor) = poll_page(&helper, Some(&cursor), Some(1)).await;
+    for code in [&first, &second] {
+        assert!(
+            page.contains(code.as_str()) != next.contains(code.as_str()),
+            "{} not reported exactly once:\n{}\n{}",
+            code,
+            page,
+            next
+        );
+    }
+    let (text, _) = poll_page(&helper, Some(&cursor), Some(1)).await;
+    assert!(text.contains("No changes since the cursor"), "{}", text);
+
+    Ok(())
+}
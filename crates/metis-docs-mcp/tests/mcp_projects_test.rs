@@ -0,0 +1,83 @@
+//! Registering several workspaces with one server through the project tools
+
+mod common;
+
+use anyhow::Result;
+use common::{extract_text, McpTestHelper};
+use metis_mcp_server::project_registry::ProjectRegistry;
+use metis_mcp_server::tools::*;
+use std::sync::Arc;
+
+#[tokio::test]
+async fn test_mcp_projects() -> Result<()> {
+    let api = McpTestHelper::new().await?;
+    api.initialize_project().await?;
+    let web = McpTestHelper::new().await?;
+    web.initialize_project().await?;
+    let registry = Arc::new(ProjectRegistry::new());
+
+    let result = ListProjectsTool {}
+        .call_tool_with_registry(registry.clone())
+        .await
+        .unwrap();
+    assert!(extract_text(&result).contains("No projects registered"));
+
+    // Named explicitly, and by the directory containing .metis
+    let result = RegisterProjectTool {
+        project_path: api.metis_dir(),
+        name: Some("api".to_string()),
+    }
+    .call_tool_with_registry(registry.clone())
+    .await
+    .unwrap();
+    assert_ne!(result.is_error, Some(true), "{}", extract_text(&result));
+    assert!(extract_text(&result).contains("**Name**: api"));
+    let result = RegisterProjectTool {
+        project_path: web.metis_dir(),
+        name: None,
+    }
+    .call_tool_with_registry(registry.clone())
+    .await
+    .unwrap();
+    let text = extract_text(&result);
+    assert!(
+        text.contains(&format!("**Name**: {}", web.get_project_name())),
+        "{}",
+        text
+    );
+    assert!(text.contains("**Registered projects**: 2"), "{}", text);
+
+    let text = extract_text(
+        &ListProjectsTool {}
+            .call_tool_with_registry(registry.clone())
+            .await
+            .unwrap(),
+    );
+    assert!(text.contains("2 project(s) registered"), "{}", text);
+    assert!(
+        text.lines().any(|line| line.starts_with("| api ")),
+        "{}",
+        text
+    );
+
+    // Names resolve to their workspace; other paths are refused
+    assert_eq!(
+        registry.resolve("api").unwrap(),
+        std::path::Path::new(&api.metis_dir()).canonicalize()?
+    );
+    assert!(registry.resolve(&web.metis_dir()).is_ok());
+    assert!(registry.resolve("/tmp/elsewhere/.metis").is_err());
+
+    // A folder that isn't a workspace can't be registered
+    let result = RegisterProjectTool {
+        project_path: "/tmp/does-not-exist/.metis".to_string(),
+        name: Some("ghost".to_string()),
+    }
+    .call_tool_with_registry(registry.clone())
+    .await
+    .unwrap();
+    assert_eq!(result.is_error, Some(true));
+    assert_eq!(registry.list().len(), 2);
+
+    Ok(())
+}
@@ -0,0 +1,112 @@
+//! Workspace-aware prompts served by the MCP server
+
+mod common;
+
+use anyhow::Result;
+use common::McpTestHelper;
+use metis_mcp_server::prompts::{get_prompt, list_prompts};
+use std::collections::HashMap;
+
+/// The text of a rendered prompt's message
+async fn render(name: &str, arguments: &[(&str, &str)]) -> Result<String, String> {
+    let arguments: HashMap<String, String> = arguments
+        .iter()
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect();
+    let result = get_prompt(name, &arguments)
+        .await
+        .map_err(|e| format!("{:?}", e))?;
+    match &result.messages[0].content {
+        rust_mcp_sdk::schema::ContentBlock::TextContent(text_content) => {
+            Ok(text_content.text.clone())
+        }
+        _ => Err("prompt message isn't text".to_string()),
+    }
+}
+
+#[test]
+fn test_prompts_listed() {
+    let names: Vec<String> = list_prompts()
+        .into_iter()
+        .map(|prompt| prompt.name)
+        .collect();
+    assert_eq!(
+        names,
+        vec!["decompose_initiative", "draft_adr", "triage_backlog"]
+    );
+}
+
+#[tokio::test]
+async fn test_prompts_inject_workspace_content() -> Result<()> {
+    let helper = McpTestHelper::new().await?;
+    helper.initialize_project().await?;
+    let project_path = helper.metis_dir();
+
+    let initiative = helper
+        .create_document("initiative", "Offline Mode", None)
+        .await;
+    let task = helper
+        .create_document("task", "Cache the last sync", Some(&initiative))
+        .await;
+    let backlog = helper
+        .create_document("task", "Banner flickers offline", None)
+        .await;
+
+    // The initiative's file and its existing tasks are included
+    let text = render(
+        "decompose_initiative",
+        &[("project_path", &project_path), ("short_code", &initiative)],
+    )
+    .await
+    .unwrap();
+    assert!(text.contains("Offline Mode"), "{}", text);
+    assert!(text.contains("```markdown"), "{}", text);
+    assert!(text.contains(&task), "{}", text);
+    assert!(text.contains(&format!("parent_id: \"{}\"", initiative)));
+
+    // The discussion is passed through alongside the document
+    let text = render(
+        "draft_adr",
+        &[
+            ("project_path", &project_path),
+            ("short_code", &initiative),
+            ("discussion", "We agreed on IndexedDB over localStorage."),
+        ],
+    )
+    .await
+    .unwrap();
+    assert!(text.contains("IndexedDB over localStorage"), "{}", text);
+    assert!(text.contains("Offline Mode"), "{}", text);
+
+    // Backlog items are listed with their category, initiatives taking work too
+    let text = render("triage_backlog", &[("project_path", &project_path)])
+        .await
+        .unwrap();
+    assert!(text.contains(&backlog), "{}", text);
+    assert!(text.contains("category: bug"), "{}", text);
+    assert!(!text.contains(&task), "{}", text);
+
+    // Missing arguments and the wrong kind of document are rejected
+    assert!(
+        render("decompose_initiative", &[("project_path", &project_path)])
+            .await
+            .is_err()
+    );
+    assert!(render(
+        "decompose_initiative",
+        &[("project_path", &project_path), ("short_code", &task)],
+    )
+    .await
+    .is_err());
+    assert!(render(
+        "triage_backlog",
+        &[("project_path", &project_path), ("category", "chores")],
+    )
+    .await
+    .is_err());
+    assert!(render("summarize", &[("project_path", &project_path)])
+        .await
+        .is_err());
+
+    Ok(())
+}
@@ -0,0 +1,78 @@
+use metis_mcp_server::tools::MetisTools;
+
+#[test]
+fn test_read_only_tools_leave_out_writes() {
+    let names: Vec<String> = MetisTools::read_only_tools()
+        .into_iter()
+        .map(|tool| tool.name)
+        .collect();
+
+    for reading in [
+        "list_documents",
+        "search_documents",
+        "read_document",
+        "get_blockers",
+        "query_relationships",
+        "search_symbols",
+    ] {
+        assert!(names.iter().any(|name| name == reading), "{}", reading);
+        assert!(MetisTools::is_read_only(reading), "{}", reading);
+    }
+    for writing in [
+        "initialize_project",
+        "create_document",
+        "edit_document",
+        "transition_phase",
+        "delete_document",
+        "bulk_update",
+    ] {
+        assert!(!names.iter().any(|name| name == writing), "{}", writing);
+        assert!(!MetisTools::is_read_only(writing), "{}", writing);
+    }
+    assert!(!MetisTools::is_read_only("no_such_tool"));
+}
+
+#[test]
+fn test_read_only_mode_allows_dry_runs() {
+    let dry_run = |value: bool| {
+        let mut arguments = serde_json::Map::new();
+        arguments.insert("dry_run".to_string(), value.into());
+        arguments
+    };
+
+    // Offered to read-only clients, but only callable as a dry run
+    assert!(MetisTools::read_only_tools()
+        .iter()
+        .any(|tool| tool.name == "sync_remote"));
+    assert!(!MetisTools::is_read_only("sync_remote"));
+    assert!(MetisTools::is_read_only_call("sync_remote", &dry_run(true)));
+    assert!(!MetisTools::is_read_only_call(
+        "sync_remote",
+        &dry_run(false)
+    ));
+    assert!(!MetisTools::is_read_only_call(
+        "sync_remote",
+        &serde_json::Map::new()
+    ));
+    assert!(!MetisTools::is_read_only_call(
+        "bulk_update",
+        &dry_run(true)
+    ));
+    assert!(MetisTools::is_read_only_call(
+        "list_documents",
+        &serde_json::Map::new()
+    ));
+}
+
+#[test]
+fn test_read_only_mode_allows_registering_projects() {
+    // Registering only narrows the calling session's routing
+    assert!(MetisTools::read_only_tools()
+        .iter()
+        .any(|tool| tool.name == "register_project"));
+    assert!(!MetisTools::is_read_only("register_project"));
+    assert!(MetisTools::is_read_only_call(
+        "register_project",
+        &serde_json::Map::new()
+    ));
+}
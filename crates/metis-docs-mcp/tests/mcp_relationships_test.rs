@@ -0,0 +1,92 @@
+//! Gathering a document's relationships through the MCP query_relationships tool
+
+mod common;
+
+use anyhow::Result;
+use common::{extract_text, McpTestHelper};
+use metis_mcp_server::tools::*;
+
+async fn relationships(helper: &McpTestHelper, short_code: &str) -> String {
+    let result = QueryRelationshipsTool {
+        project_path: helper.metis_dir(),
+        short_code: short_code.to_string(),
+    }
+    .call_tool()
+    .await
+    .unwrap();
+    assert_ne!(result.is_error, Some(true), "{}", extract_text(&result));
+    extract_text(&result)
+}
+
+/// The lines of one group's section, up to the next section
+fn section<'a>(text: &'a str, group: &str) -> &'a str {
+    let start = text
+        .find(&format!("### {} (", group))
+        .unwrap_or_else(|| panic!("no {} section in:\n{}", group, text));
+    let rest = &text[start + 4..];
+    &rest[..rest.find("### ").unwrap_or(rest.len())]
+}
+
+#[tokio::test]
+async fn test_mcp_query_relationships() -> Result<()> {
+    let helper = McpTestHelper::new().await?;
+    helper.initialize_project().await?;
+
+    let initiative = helper
+        .create_document("initiative", "Billing Revamp", None)
+        .await;
+    let schema = helper
+        .create_document("task", "Invoice schema", Some(&initiative))
+        .await;
+    let split = helper
+        .create_document("task", "Split invoices", Some(&initiative))
+        .await;
+    let adr = helper
+        .create_document("adr", "Store invoices as events", Some(&initiative))
+        .await;
+    let result = AddBlockerTool {
+        project_path: helper.metis_dir(),
+        short_code: split.clone(),
+        blocked_by: schema.clone(),
+    }
+    .call_tool()
+    .await
+    .unwrap();
+    assert_ne!(result.is_error, Some(true), "{}", extract_text(&result));
+
+    // The initiative sees its tasks and the decision made about it
+    let text = relationships(&helper, &initiative).await;
+    let children = section(&text, "Children");
+    assert!(
+        children.contains(&schema) && children.contains(&split),
+        "{}",
+        text
+    );
+    assert!(!children.contains(&adr), "{}", text);
+    let adrs = section(&text, "ADRs");
+    assert!(
+        adrs.contains(&adr) && adrs.contains("referenced by"),
+        "{}",
+        text
+    );
+
+    // A task sees its parent and both directions of blocking
+    let text = relationships(&helper, &split).await;
+    assert!(section(&text, "Parent").contains(&initiative), "{}", text);
+    assert!(section(&text, "Blocked by").contains(&schema), "{}", text);
+    assert!(!text.contains("### Blocks ("), "{}", text);
+    let text = relationships(&helper, &schema).await;
+    assert!(section(&text, "Blocks").contains(&split), "{}", text);
+
+    // Unknown short codes are reported
+    let result = QueryRelationshipsTool {
+        project_path: helper.metis_dir(),
+        short_code: "TEST-T-9999".to_string(),
+    }
+    .call_tool()
+    .await
+    .unwrap();
+    assert_eq!(result.is_error, Some(true));
+
+    Ok(())
+}
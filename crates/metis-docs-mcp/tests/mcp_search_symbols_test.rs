@@ -0,0 +1,85 @@
+//! Indexing the source tree and querying its symbols through the MCP tools
+
+mod common;
+
+use anyhow::Result;
+use common::{extract_text, McpTestHelper};
+use metis_mcp_server::tools::*;
+use std::path::Path;
+
+fn search(helper: &McpTestHelper) -> SearchSymbolsTool {
+    SearchSymbolsTool {
+        project_path: helper.metis_dir(),
+        name: None,
+        kind: None,
+        file: None,
+        limit: None,
+    }
+}
+
+#[tokio::test]
+async fn test_mcp_search_symbols() -> Result<()> {
+    let helper = McpTestHelper::new().await?;
+    helper.initialize_project().await?;
+
+    let project = Path::new(&helper.metis_dir())
+        .parent()
+        .unwrap()
+        .to_path_buf();
+    std::fs::create_dir_all(project.join("src/billing"))?;
+    std::fs::write(
+        project.join("src/billing/invoice.rs"),
+        "pub struct Invoice {\n    pub total: u64,\n}\n\nimpl Invoice {\n    pub fn split(&self, parts: u64) -> Vec<Invoice> {\n        vec![]\n    }\n}\n\npub fn invoice_total(invoice: &Invoice) -> u64 {\n    invoice.total\n}\n",
+    )?;
+    std::fs::write(
+        project.join("src/lib.rs"),
+        "pub mod billing;\n\npub fn start() {}\n",
+    )?;
+
+    // Before indexing there is nothing to search
+    let mut tool = search(&helper);
+    tool.name = Some("invoice".to_string());
+    let result = tool.call_tool().await.unwrap();
+    assert_eq!(result.is_error, Some(true));
+
+    let result = IndexCodeTool {
+        project_path: helper.metis_dir(),
+        structure_only: None,
+        incremental: None,
+    }
+    .call_tool()
+    .await
+    .unwrap();
+    assert_ne!(result.is_error, Some(true), "{}", extract_text(&result));
+
+    // Exact name matches come before partial ones, with their location
+    let mut tool = search(&helper);
+    tool.name = Some("invoice".to_string());
+    let text = extract_text(&tool.call_tool().await.unwrap());
+    assert!(text.contains("src/billing/invoice.rs:1"), "{}", text);
+    let exact = text.find("| Invoice").expect("exact match listed");
+    let partial = text.find("| invoice_total").expect("partial match listed");
+    assert!(exact < partial, "{}", text);
+
+    // Kind and file narrow the results
+    let mut tool = search(&helper);
+    tool.kind = Some("function".to_string());
+    tool.file = Some("src/lib".to_string());
+    let text = extract_text(&tool.call_tool().await.unwrap());
+    assert!(
+        text.contains("start") && !text.contains("invoice_total"),
+        "{}",
+        text
+    );
+
+    // Bad input is reported
+    let mut tool = search(&helper);
+    tool.kind = Some("fn".to_string());
+    assert_eq!(tool.call_tool().await.unwrap().is_error, Some(true));
+    assert_eq!(
+        search(&helper).call_tool().await.unwrap().is_error,
+        Some(true)
+    );
+
+    Ok(())
+}
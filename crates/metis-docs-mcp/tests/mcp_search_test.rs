@@ -0,0 +1,163 @@
+//! Ranked, filtered and paged search through the MCP search_documents tool
+
+mod common;
+
+use anyhow::Result;
+use common::{extract_text, McpTestHelper};
+use metis_mcp_server::tools::*;
+use regex::Regex;
+
+fn extract_short_code(result: &rust_mcp_sdk::schema::CallToolResult) -> String {
+    let re = Regex::new(r"([A-Z]+-[VITA]-\d{4})").unwrap();
+    re.captures(&extract_text(result))
+        .and_then(|captures| captures.get(1))
+        .map(|m| m.as_str().to_string())
+        .expect("Could not extract short_code from result")
+}
+
+async fn create_backlog_task(helper: &McpTestHelper, title: &str) -> String {
+    let result = CreateDocumentTool {
+        project_path: helper.metis_dir(),
+        document_type: "task".to_string(),
+        title: title.to_string(),
+        parent_id: None,
+        complexity: None,
+        stakeholders: None,
+        decision_maker: None,
+        backlog_category: Some("feature".to_string()),
+    }
+    .call_tool()
+    .await
+    .expect("Create should succeed");
+    extract_short_code(&result)
+}
+
+fn search(helper: &McpTestHelper, query: &str) -> SearchDocumentsTool {
+    SearchDocumentsTool {
+        project_path: helper.metis_dir(),
+        query: query.to_string(),
+        document_type: None,
+        limit: None,
+        include_archived: None,
+        phase: None,
+        tag: None,
+        assignee: None,
+        updated_after: None,
+        updated_before: None,
+        offset: None,
+        cursor: None,
+    }
+}
+
+async fn run(tool: SearchDocumentsTool) -> String {
+    let result = tool.call_tool().await.unwrap();
+    let text = extract_text(&result);
+    assert_ne!(result.is_error, Some(true), "{}", text);
+    text
+}
+
+#[tokio::test]
+async fn test_mcp_search_filters_and_pages() -> Result<()> {
+    let helper = McpTestHelper::new().await?;
+    helper.initialize_project().await?;
+    let warmup = create_backlog_task(&helper, "Cache warmup on deploy").await;
+    let eviction = create_backlog_task(&helper, "Cache eviction policy").await;
+    let unrelated = create_backlog_task(&helper, "Rotate signing keys").await;
+
+    // Matches come with a snippet, and only matching documents appear
+    let text = run(search(&helper, "cache")).await;
+    assert!(
+        text.contains(&warmup) && text.contains(&eviction),
+        "{}",
+        text
+    );
+    assert!(!text.contains(&unrelated), "{}", text);
+    assert!(text.contains("Snippet") && text.contains("**"), "{}", text);
+
+    // Phase and tag filters narrow the matches
+    let result = TransitionPhaseTool {
+        project_path: helper.metis_dir(),
+        short_code: warmup.clone(),
+        phase: Some("todo".to_string()),
+        force: None,
+        cascade: None,
+        dry_run: None,
+    }
+    .call_tool()
+    .await
+    .unwrap();
+    assert_ne!(result.is_error, Some(true), "{}", extract_text(&result));
+    let text = run(SearchDocumentsTool {
+        phase: Some("todo".to_string()),
+        ..search(&helper, "cache")
+    })
+    .await;
+    assert!(
+        text.contains(&warmup) && !text.contains(&eviction),
+        "{}",
+        text
+    );
+
+    AddTagsTool {
+        project_path: helper.metis_dir(),
+        short_code: eviction.clone(),
+        tags: vec!["perf".to_string()],
+    }
+    .call_tool()
+    .await
+    .unwrap();
+    let text = run(SearchDocumentsTool {
+        tag: Some("perf".to_string()),
+        ..search(&helper, "cache")
+    })
+    .await;
+    assert!(
+        text.contains(&eviction) && !text.contains(&warmup),
+        "{}",
+        text
+    );
+
+    // Nothing was updated before 2000
+    let text = run(SearchDocumentsTool {
+        updated_before: Some("2000-01-01".to_string()),
+        ..search(&helper, "cache")
+    })
+    .await;
+    assert!(text.contains("Found 0 matches"), "{}", text);
+    let result = SearchDocumentsTool {
+        updated_after: Some("last week".to_string()),
+        ..search(&helper, "cache")
+    }
+    .call_tool()
+    .await
+    .unwrap();
+    assert_eq!(result.is_error, Some(true));
+
+    // Paging: one result per page, the cursor leading to the other
+    let first = run(SearchDocumentsTool {
+        limit: Some(1),
+        ..search(&helper, "cache")
+    })
+    .await;
+    let cursor = Regex::new(r"\*\*Next cursor\*\*: (\d+)")
+        .unwrap()
+        .captures(&first)
+        .map(|captures| captures[1].to_string())
+        .unwrap_or_else(|| panic!("no cursor in {}", first));
+    let second = run(SearchDocumentsTool {
+        limit: Some(1),
+        cursor: Some(cursor),
+        ..search(&helper, "cache")
+    })
+    .await;
+    assert!(!second.contains("Next cursor"), "{}", second);
+    for code in [&warmup, &eviction] {
+        assert!(
+            first.contains(code.as_str()) != second.contains(code.as_str()),
+            "{} should be on exactly one page",
+            code
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,108 @@
+//! Comparing the workspace with its git remote through the MCP sync_remote tool
+
+mod common;
+
+use anyhow::Result;
+use common::{extract_text, McpTestHelper};
+use metis_mcp_server::tools::*;
+use std::path::Path;
+use std::process::Command;
+
+fn git(dir: &Path, args: &[&str]) {
+    let output = Command::new("git")
+        .args(["-c", "user.name=Test", "-c", "user.email=test@example.com"])
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .expect("git should run");
+    assert!(
+        output.status.success(),
+        "git {:?} failed: {}",
+        args,
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+fn sync_tool(helper: &McpTestHelper, dry_run: bool) -> SyncRemoteTool {
+    SyncRemoteTool {
+        project_path: helper.metis_dir(),
+        dry_run: Some(dry_run),
+    }
+}
+
+#[tokio::test]
+async fn test_mcp_sync_remote() -> Result<()> {
+    let helper = McpTestHelper::new().await?;
+    helper.initialize_project().await?;
+
+    // Outside a git repository there is nothing to sync with
+    let result = sync_tool(&helper, true).call_tool().await.unwrap();
+    assert_eq!(result.is_error, Some(true));
+    assert!(extract_text(&result).contains("Remote sync unavailable"));
+
+    // Share the workspace through a bare remote
+    let remote = tempfile::tempdir()?;
+    git(remote.path(), &["init", "--quiet", "--bare"]);
+    let project = Path::new(&helper.metis_dir())
+        .parent()
+        .unwrap()
+        .to_path_buf();
+    git(&project, &["init", "--quiet"]);
+    git(&project, &["add", "."]);
+    git(&project, &["commit", "--quiet", "-m", "Initial workspace"]);
+    let url = remote.path().to_string_lossy().to_string();
+    git(&project, &["remote", "add", "origin", &url]);
+    git(&project, &["push", "--quiet", "-u", "origin", "HEAD"]);
+
+    let result = sync_tool(&helper, true).call_tool().await.unwrap();
+    assert_ne!(result.is_error, Some(true), "{}", extract_text(&result));
+    assert!(!extract_text(&result).contains("### Pull ("));
+
+    // A new local document is listed as a push
+    let backlog = helper
+        .create_document("task", "Totals round twice", None)
+        .await;
+    let result = sync_tool(&helper, true).call_tool().await.unwrap();
+    let text = extract_text(&result);
+    assert_ne!(result.is_error, Some(true), "{}", text);
+    assert!(
+        text.contains("Push (1)") && text.contains(&backlog),
+        "{}",
+        text
+    );
+    assert!(text.contains("nothing was changed"), "{}", text);
+
+    // Without dry_run the document is committed and pushed
+    git(&project, &["config", "user.name", "Test"]);
+    git(&project, &["config", "user.email", "test@example.com"]);
+    let result = sync_tool(&helper, false).call_tool().await.unwrap();
+    let text = extract_text(&result);
+    assert_ne!(result.is_error, Some(true), "{}", text);
+    assert!(text.contains("pushed 1 document(s)"), "{}", text);
+    let result = sync_tool(&helper, true).call_tool().await.unwrap();
+    assert!(extract_text(&result).contains("Already in sync"));
+
+    // A change pushed from another clone is pulled into the workspace
+    let clone = tempfile::tempdir()?;
+    git(clone.path(), &["clone", "--quiet", &url, "."]);
+    let vision = clone.path().join(".metis").join("vision.md");
+    std::fs::write(
+        &vision,
+        std::fs::read_to_string(&vision)? + "\nEdited on the remote.\n",
+    )?;
+    git(clone.path(), &["commit", "--quiet", "-am", "Edit vision"]);
+    git(clone.path(), &["push", "--quiet"]);
+
+    let result = sync_tool(&helper, false).call_tool().await.unwrap();
+    let text = extract_text(&result);
+    assert_ne!(result.is_error, Some(true), "{}", text);
+    assert!(
+        text.contains("Pull (1)") && text.contains("vision.md"),
+        "{}",
+        text
+    );
+    let pulled = std::fs::read_to_string(Path::new(&helper.metis_dir()).join("vision.md"))?;
+    assert!(pulled.contains("Edited on the remote."));
+
+    Ok(())
+}
@@ -0,0 +1,133 @@
+//! Managing document tags through the add_tags, remove_tags and list_tags tools
+
+mod common;
+
+use anyhow::Result;
+use common::{extract_text, McpTestHelper};
+use metis_mcp_server::tools::*;
+use regex::Regex;
+
+fn extract_short_code(result: &rust_mcp_sdk::schema::CallToolResult) -> String {
+    let re = Regex::new(r"([A-Z]+-[VITA]-\d{4})").unwrap();
+    re.captures(&extract_text(result))
+        .and_then(|captures| captures.get(1))
+        .map(|m| m.as_str().to_string())
+        .expect("Could not extract short_code from result")
+}
+
+fn tags(values: &[&str]) -> Vec<String> {
+    values.iter().map(|tag| tag.to_string()).collect()
+}
+
+async fn list_tags(helper: &McpTestHelper, short_code: Option<&str>) -> String {
+    let result = ListTagsTool {
+        project_path: helper.metis_dir(),
+        short_code: short_code.map(str::to_string),
+        include_phase: None,
+    }
+    .call_tool()
+    .await
+    .unwrap();
+    let text = extract_text(&result);
+    assert_ne!(result.is_error, Some(true), "{}", text);
+    text
+}
+
+#[tokio::test]
+async fn test_mcp_tags() -> Result<()> {
+    let helper = McpTestHelper::new().await?;
+    helper.initialize_project().await?;
+    let result = CreateDocumentTool {
+        project_path: helper.metis_dir(),
+        document_type: "task".to_string(),
+        title: "Rate limit the API".to_string(),
+        parent_id: None,
+        complexity: None,
+        stakeholders: None,
+        decision_maker: None,
+        backlog_category: Some("tech-debt".to_string()),
+    }
+    .call_tool()
+    .await
+    .expect("Create should succeed");
+    let task = extract_short_code(&result);
+
+    let result = AddTagsTool {
+        project_path: helper.metis_dir(),
+        short_code: task.clone(),
+        tags: tags(&["api", "#sprint-14"]),
+    }
+    .call_tool()
+    .await
+    .unwrap();
+    let text = extract_text(&result);
+    assert_ne!(result.is_error, Some(true), "{}", text);
+    assert!(
+        text.contains("Tagged") && text.contains("#api, #sprint-14"),
+        "{}",
+        text
+    );
+
+    let text = list_tags(&helper, Some(&task)).await;
+    assert!(
+        text.contains("#api") && text.contains("#phase/backlog"),
+        "{}",
+        text
+    );
+    let text = list_tags(&helper, None).await;
+    assert!(
+        Regex::new(r"\| #sprint-14\s*\| 1\s*\|")
+            .unwrap()
+            .is_match(&text),
+        "{}",
+        text
+    );
+    assert!(!text.contains("#phase/"), "{}", text);
+
+    // Phase tags drive the boards, in any spelling
+    for phase_tag in ["#phase/active", "Phase/Backlog"] {
+        let result = RemoveTagsTool {
+            project_path: helper.metis_dir(),
+            short_code: task.clone(),
+            tags: tags(&["api", phase_tag]),
+        }
+        .call_tool()
+        .await
+        .unwrap();
+        assert_eq!(result.is_error, Some(true), "{}", phase_tag);
+        assert!(extract_text(&result).contains("phase tag"));
+    }
+    let result = AddTagsTool {
+        project_path: helper.metis_dir(),
+        short_code: task.clone(),
+        tags: tags(&["#phase/completed"]),
+    }
+    .call_tool()
+    .await
+    .unwrap();
+    assert_eq!(result.is_error, Some(true));
+
+    // Nothing was removed by the refused calls
+    let text = list_tags(&helper, Some(&task)).await;
+    assert!(
+        text.contains("#api") && text.contains("#phase/backlog"),
+        "{}",
+        text
+    );
+
+    let result = RemoveTagsTool {
+        project_path: helper.metis_dir(),
+        short_code: task.clone(),
+        tags: tags(&["API", "missing"]),
+    }
+    .call_tool()
+    .await
+    .unwrap();
+    let text = extract_text(&result);
+    assert!(text.contains("Removed #API"), "{}", text);
+    assert!(text.contains("doesn't have #missing"), "{}", text);
+    let text = list_tags(&helper, Some(&task)).await;
+    assert!(!text.contains("#api"), "{}", text);
+
+    Ok(())
+}
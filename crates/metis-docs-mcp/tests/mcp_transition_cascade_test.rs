@@ -0,0 +1,103 @@
+//! Cascaded and dry-run transitions through the MCP transition_phase tool
+
+mod common;
+
+use anyhow::Result;
+use common::{extract_text, McpTestHelper};
+use metis_mcp_server::tools::*;
+
+fn transition_tool(helper: &McpTestHelper, short_code: &str, phase: &str) -> TransitionPhaseTool {
+    TransitionPhaseTool {
+        project_path: helper.metis_dir(),
+        short_code: short_code.to_string(),
+        phase: Some(phase.to_string()),
+        force: None,
+        cascade: None,
+        dry_run: None,
+    }
+}
+
+/// The phase a document is in, after a sync
+async fn phase_of(helper: &McpTestHelper, short_code: &str) -> Result<String> {
+    metis_core::Application::new(helper.get_database()?)
+        .sync_directory(&helper.metis_dir())
+        .await?;
+    let db = helper.get_database()?;
+    let mut repo = db
+        .repository()
+        .map_err(|e| anyhow::anyhow!("Repository error: {}", e))?;
+    Ok(repo
+        .find_by_short_code(short_code)
+        .map_err(|e| anyhow::anyhow!("Find error: {}", e))?
+        .expect("document should exist")
+        .phase)
+}
+
+#[tokio::test]
+async fn test_mcp_transition_cascade_and_dry_run() -> Result<()> {
+    let helper = McpTestHelper::new().await?;
+    helper.initialize_project().await?;
+
+    let initiative = helper
+        .create_document("initiative", "Export to CSV", None)
+        .await;
+    let first = helper
+        .create_document("task", "Write the exporter", Some(&initiative))
+        .await;
+    let second = helper
+        .create_document("task", "Add the download button", Some(&initiative))
+        .await;
+    for phase in ["design", "ready", "decompose", "active"] {
+        transition_tool(&helper, &initiative, phase)
+            .call_tool()
+            .await
+            .expect("Initiative transition should succeed");
+    }
+    transition_tool(&helper, &first, "active")
+        .call_tool()
+        .await
+        .expect("Task transition should succeed");
+
+    // A dry run lists every document that would move, and moves none
+    let mut tool = transition_tool(&helper, &initiative, "completed");
+    tool.cascade = Some(true);
+    tool.dry_run = Some(true);
+    let text = extract_text(&tool.call_tool().await.unwrap());
+    assert!(
+        text.contains("3 document(s) would change phase"),
+        "{}",
+        text
+    );
+    assert!(text.contains(&first) && text.contains(&second), "{}", text);
+    assert!(text.contains("active -> completed"), "{}", text);
+    assert_eq!(phase_of(&helper, &initiative).await?, "active");
+    assert_eq!(phase_of(&helper, &second).await?, "todo");
+
+    // Without cascade the dry run names only the document, and counts the rest
+    let mut tool = transition_tool(&helper, &initiative, "completed");
+    tool.dry_run = Some(true);
+    let text = extract_text(&tool.call_tool().await.unwrap());
+    assert!(
+        text.contains("1 document(s) would change phase"),
+        "{}",
+        text
+    );
+    assert!(text.contains("2 dependent document(s)"), "{}", text);
+
+    // An invalid transition is rejected by the dry run too
+    let mut tool = transition_tool(&helper, &second, "completed");
+    tool.dry_run = Some(true);
+    assert!(tool.call_tool().await.is_err());
+
+    // Cascading completes the open tasks along with the initiative
+    let mut tool = transition_tool(&helper, &initiative, "completed");
+    tool.cascade = Some(true);
+    let result = tool.call_tool().await.unwrap();
+    let text = extract_text(&result);
+    assert!(text.contains("Cascaded"), "{}", text);
+    for short_code in [&initiative, &first, &second] {
+        assert_eq!(phase_of(&helper, short_code).await?, "completed");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,122 @@
+//! Self-checking documents through the MCP validate_document tool
+
+mod common;
+
+use anyhow::Result;
+use common::{extract_text, McpTestHelper};
+use metis_mcp_server::tools::*;
+use regex::Regex;
+use std::path::{Path, PathBuf};
+
+fn extract_short_code(result: &rust_mcp_sdk::schema::CallToolResult) -> String {
+    let re = Regex::new(r"([A-Z]+-[VITA]-\d{4})").unwrap();
+    re.captures(&extract_text(result))
+        .and_then(|captures| captures.get(1))
+        .map(|m| m.as_str().to_string())
+        .expect("Could not extract short_code from result")
+}
+
+async fn validate(helper: &McpTestHelper, short_code: Option<&str>) -> String {
+    let result = ValidateDocumentTool {
+        project_path: helper.metis_dir(),
+        short_code: short_code.map(str::to_string),
+    }
+    .call_tool()
+    .await
+    .unwrap();
+    let text = extract_text(&result);
+    assert_ne!(result.is_error, Some(true), "{}", text);
+    text
+}
+
+/// The markdown file under `dir` holding a short code
+fn document_file(dir: &Path, short_code: &str) -> Option<PathBuf> {
+    for entry in std::fs::read_dir(dir).ok()?.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            if let Some(found) = document_file(&path, short_code) {
+                return Some(found);
+            }
+        } else if path.extension().is_some_and(|ext| ext == "md")
+            && std::fs::read_to_string(&path)
+                .is_ok_and(|content| content.contains(&format!("short_code: \"{}\"", short_code)))
+        {
+            return Some(path);
+        }
+    }
+    None
+}
+
+#[tokio::test]
+async fn test_mcp_validate_document() -> Result<()> {
+    let helper = McpTestHelper::new().await?;
+    helper.initialize_project().await?;
+    let result = CreateDocumentTool {
+        project_path: helper.metis_dir(),
+        document_type: "task".to_string(),
+        title: "Retry failed webhooks".to_string(),
+        parent_id: None,
+        complexity: None,
+        stakeholders: None,
+        decision_maker: None,
+        backlog_category: Some("bug".to_string()),
+    }
+    .call_tool()
+    .await
+    .expect("Create should succeed");
+    let task = extract_short_code(&result);
+
+    // Fresh from the template, only the unchecked criteria are reported
+    let text = validate(&helper, Some(&task)).await;
+    assert!(text.contains("0 error(s)"), "{}", text);
+    assert!(
+        text.contains("warning") && text.contains("\"unmet_criteria\""),
+        "{}",
+        text
+    );
+
+    // A blocker that doesn't exist is an error
+    let metis_dir = PathBuf::from(helper.metis_dir());
+    let missing = format!("{}9999", &task[..task.len() - 4]);
+    let path = document_file(&metis_dir, &task).expect("task file");
+    let content = std::fs::read_to_string(&path)?;
+    let content = Regex::new(r"(?m)^blocked_by:.*$")
+        .unwrap()
+        .replace(&content, format!("blocked_by: [\"{}\"]", missing).as_str())
+        .to_string();
+    std::fs::write(&path, content)?;
+
+    let text = validate(&helper, Some(&task)).await;
+    assert!(text.contains("✗ 1 error(s)"), "{}", text);
+    assert!(
+        text.contains(&format!("{} doesn't exist", missing)),
+        "{}",
+        text
+    );
+    assert!(text.contains("\"dangling_reference\""), "{}", text);
+
+    // The workspace report also covers files that don't parse
+    std::fs::write(
+        metis_dir.join("backlog").join("scratch.md"),
+        "# Notes\n\nNo frontmatter here.",
+    )?;
+    let text = validate(&helper, None).await;
+    assert!(text.contains("Validation: Workspace"), "{}", text);
+    assert!(
+        text.contains("scratch.md") && text.contains("\"schema\""),
+        "{}",
+        text
+    );
+    assert!(text.contains(&task), "{}", text);
+
+    let result = ValidateDocumentTool {
+        project_path: helper.metis_dir(),
+        short_code: Some(missing),
+    }
+    .call_tool()
+    .await
+    .unwrap();
+    assert_eq!(result.is_error, Some(true));
+
+    Ok(())
+}